@@ -39,9 +39,15 @@ pub async fn sentry_and_analytics(mut request: Request, next: Next) -> Response
         });
 
         let user_id = auth.claims.sub.clone();
+        let plan = if auth.claims.is_pro() {
+            hypr_llm_proxy::UsagePlan::Pro
+        } else {
+            hypr_llm_proxy::UsagePlan::Free
+        };
         request
             .extensions_mut()
             .insert(hypr_analytics::AuthenticatedUserId(user_id));
+        request.extensions_mut().insert(plan);
     }
 
     if let Some(fingerprint) = device_fingerprint {
@@ -29,6 +29,10 @@ pub struct Env {
     #[serde(flatten)]
     pub chatwoot: hypr_api_support::ChatwootEnv,
 
+    /// Meeting bots and auto-join are only served when Recall is configured.
+    #[serde(flatten)]
+    pub bot: Option<hypr_api_bot::BotConfig>,
+
     pub exa_api_key: String,
     pub jina_api_key: String,
 
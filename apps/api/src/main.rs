@@ -25,6 +25,22 @@ use env::env;
 
 pub const DEVICE_FINGERPRINT_HEADER: &str = "x-device-fingerprint";
 
+const AUTO_JOIN_INTERVAL: Duration = Duration::from_secs(30);
+
+fn usage_ledger(env: &env::Env) -> hypr_llm_proxy::UsageLedger {
+    let store = hypr_llm_proxy::SupabaseUsageStore::new(
+        &env.supabase.supabase_url,
+        &env.supabase.supabase_service_role_key,
+    );
+    let ledger =
+        hypr_llm_proxy::UsageLedger::new(Arc::new(store)).with_env_budgets(&env.llm.budgets);
+
+    match &env.llm.lago_api_key {
+        Some(key) => ledger.with_meter(Arc::new(hypr_llm_proxy::LagoMeter::from_api_key(key))),
+        None => ledger,
+    }
+}
+
 async fn app() -> Router {
     let env = env();
 
@@ -36,8 +52,10 @@ async fn app() -> Router {
         Arc::new(builder.build())
     };
 
-    let llm_config =
-        hypr_llm_proxy::LlmProxyConfig::new(&env.llm).with_analytics(analytics.clone());
+    let llm_config = hypr_llm_proxy::LlmProxyConfig::from_env(&env.llm)
+        .with_analytics(analytics.clone())
+        .with_usage_ledger(usage_ledger(env))
+        .with_response_cache(hypr_llm_proxy::ResponseCache::in_memory());
     let stt_config = hypr_transcribe_proxy::SttProxyConfig::new(&env.stt, &env.supabase)
         .with_hyprnote_routing(hypr_transcribe_proxy::HyprnoteRoutingConfig::default())
         .with_analytics(analytics.clone());
@@ -83,7 +101,7 @@ async fn app() -> Router {
             .with_analytics(analytics.clone());
     let support_config = hypr_api_support::SupportConfig::new(
         &env.github_app,
-        &env.llm,
+        &env.llm.openrouter,
         &env.support_database,
         &env.stripe,
         &env.supabase,
@@ -95,7 +113,31 @@ async fn app() -> Router {
         jina_api_key: env.jina_api_key.clone(),
     };
 
-    let webhook_routes = Router::new()
+    let bot_state = env.bot.clone().map(|config| {
+        let state = hypr_api_bot::BotState::new(config)
+            .expect("failed to build BotState")
+            .with_auto_join_store(Arc::new(hypr_api_bot::SupabaseAutoJoinStore::new(
+                &env.supabase.supabase_url,
+                &env.supabase.supabase_service_role_key,
+            )))
+            .with_session_store(Arc::new(hypr_api_bot::SupabaseBotSessionStore::new(
+                &env.supabase.supabase_url,
+                &env.supabase.supabase_service_role_key,
+            )));
+
+        let scheduler = hypr_api_bot::AutoJoinScheduler::new(
+            state.auto_join_rules(),
+            Arc::new(hypr_api_bot::NangoCalendarSource::new(
+                nango_connection_state.clone(),
+            )),
+            Arc::new(state.dispatcher()),
+        );
+        tokio::spawn(scheduler.run(AUTO_JOIN_INTERVAL));
+
+        state
+    });
+
+    let mut webhook_routes = Router::new()
         .nest(
             "/nango",
             hypr_api_nango::webhook_router(nango_config.clone()),
@@ -104,6 +146,12 @@ async fn app() -> Router {
             "/stt",
             hypr_transcribe_proxy::callback_router(stt_config.clone()),
         );
+    if let Some(state) = &bot_state {
+        webhook_routes = webhook_routes.nest(
+            "/bot",
+            hypr_api_bot::webhook_router_with_state(state.clone()),
+        );
+    }
 
     let auth_state_integration = AuthState::new(&env.supabase.supabase_url);
 
@@ -120,9 +168,15 @@ async fn app() -> Router {
         ..Default::default()
     };
 
-    let integration_routes = Router::new()
+    let mut integration_routes = Router::new()
         .nest("/calendar", hypr_api_calendar::router(calendar_config))
-        .nest("/nango", hypr_api_nango::router(nango_config.clone()))
+        .nest("/messenger", hypr_api_messenger::router())
+        .nest("/nango", hypr_api_nango::router(nango_config.clone()));
+    if let Some(state) = bot_state {
+        integration_routes =
+            integration_routes.nest("/bot", hypr_api_bot::router_with_state(state));
+    }
+    let integration_routes = integration_routes
         .layer(axum::Extension(nango_connection_state))
         .route_layer(middleware::from_fn(auth::sentry_and_analytics))
         .route_layer(middleware::from_fn_with_state(
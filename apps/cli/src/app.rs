@@ -112,6 +112,15 @@ impl App {
             SessionProgressEvent::Connected { adapter, .. } => {
                 self.status = format!("Connected via {adapter}");
             }
+            SessionProgressEvent::AutoStopPending { .. } => {
+                self.status = "Still recording — stopping soon".into();
+            }
+            SessionProgressEvent::AutoStopCancelled { .. } => {
+                self.status = "Listening".into();
+            }
+            SessionProgressEvent::AutoStopped { .. } => {
+                self.status = "Stopping automatically...".into();
+            }
         }
     }
 
@@ -57,6 +57,8 @@ pub async fn run(args: Args) {
         base_url: args.base_url,
         api_key: args.api_key,
         keywords: vec![],
+        auto_stop: Default::default(),
+        event_ends_at: None,
     };
 
     let started = ractor::call!(root_ref, RootMsg::StartSession, params)
@@ -1,7 +1,8 @@
 import { tool } from "ai";
 import { z } from "zod";
 
-import type { SessionContext } from "@hypr/plugin-template";
+import type { RetrieveResult } from "@hypr/plugin-tantivy";
+import type { RetrievalChunk, SessionContext } from "@hypr/plugin-template";
 import { commands as templateCommands } from "@hypr/plugin-template";
 
 import { searchFiltersSchema } from "../contexts/search/engine/types";
@@ -14,6 +15,10 @@ export interface ToolDependencies {
     filters?: SearchFilters | null,
   ) => Promise<SearchHit[]>;
   resolveSessionContext: (sessionId: string) => Promise<SessionContext | null>;
+  retrieve: (
+    query: string,
+    filters?: SearchFilters | null,
+  ) => Promise<RetrieveResult>;
 }
 
 const buildSearchSessionsTool = (deps: ToolDependencies) =>
@@ -59,8 +64,47 @@ const buildSearchSessionsTool = (deps: ToolDependencies) =>
     },
   });
 
+const buildSearchVaultTool = (deps: ToolDependencies) =>
+  tool({
+    description: `
+  Search all meeting notes and transcripts for passages relevant to a question.
+  Use this for questions spanning several meetings, like past decisions.
+  Returns numbered excerpts; cite them in the answer as [1], [2], ...
+  `.trim(),
+    inputSchema: z.object({
+      query: z
+        .string()
+        .describe("Keywords describing what the user is asking about"),
+      filters: searchFiltersSchema
+        .optional()
+        .describe("Optional filters for the search query"),
+    }),
+    execute: async (params: { query: string; filters?: SearchFilters }) => {
+      const result = await deps.retrieve(params.query, params.filters || null);
+
+      const chunks: RetrievalChunk[] = result.chunks.map(({ chunk }) => ({
+        sessionId: chunk.session_id,
+        sessionTitle: chunk.title || null,
+        source: chunk.source,
+        text: chunk.content,
+        createdAt: chunk.created_at || null,
+        startMs: chunk.start_ms ?? null,
+        endMs: chunk.end_ms ?? null,
+      }));
+
+      const rendered = await templateCommands.render({
+        toolSearchVault: { query: params.query, chunks },
+      });
+
+      const contextText = rendered.status === "ok" ? rendered.data : null;
+
+      return { chunks, contextText };
+    },
+  });
+
 export const buildChatTools = (deps: ToolDependencies) => ({
   search_sessions: buildSearchSessionsTool(deps),
+  search_vault: buildSearchVaultTool(deps),
 });
 
 type LocalTools = {
@@ -78,6 +122,13 @@ type LocalTools = {
       contextText: string | null;
     };
   };
+  search_vault: {
+    input: { query: string; filters?: SearchFilters };
+    output: {
+      chunks: RetrievalChunk[];
+      contextText: string | null;
+    };
+  };
 };
 
 export type Tools = LocalTools & SupportMcpTools;
@@ -0,0 +1,90 @@
+import { useQuery } from "@tanstack/react-query";
+import { useCallback } from "react";
+
+import {
+  type Citation,
+  type RetrievalChunk,
+  commands as templateCommands,
+} from "@hypr/plugin-template";
+
+import type { HyprUIMessage } from "../../../chat/types";
+import { useTabs } from "../../../store/zustand/tabs";
+import { formatLocation } from "./tool/search-vault";
+import type { Part } from "./types";
+
+type SearchVaultPart = Extract<Part, { type: "tool-search_vault" }>;
+
+// Markers in the answer refer to the excerpts of the latest vault search.
+function getRetrievedChunks(message: HyprUIMessage): RetrievalChunk[] {
+  const parts = (message.parts as Part[]).filter(
+    (part): part is SearchVaultPart =>
+      part.type === "tool-search_vault" && part.state === "output-available",
+  );
+
+  return parts[parts.length - 1]?.output.chunks ?? [];
+}
+
+function getAnswer(message: HyprUIMessage): string | null {
+  const parts = (message.parts as Part[]).filter(
+    (part): part is Extract<Part, { type: "text" }> => part.type === "text",
+  );
+
+  if (parts.length === 0 || parts.some((part) => part.state !== "done")) {
+    return null;
+  }
+
+  return parts.map((part) => part.text).join("\n");
+}
+
+export function Citations({ message }: { message: HyprUIMessage }) {
+  const chunks = getRetrievedChunks(message);
+  const answer = getAnswer(message);
+
+  const { data: citations = [] } = useQuery({
+    enabled: answer !== null && chunks.length > 0,
+    queryKey: ["chat-citations", message.id, answer],
+    staleTime: Infinity,
+    queryFn: async () => {
+      const result = await templateCommands.parseCitations(answer!, chunks);
+      if (result.status === "error") {
+        throw new Error(result.error);
+      }
+      return result.data;
+    },
+  });
+
+  if (citations.length === 0) {
+    return null;
+  }
+
+  return (
+    <ol className="flex flex-col gap-0.5 mt-1 pt-1 border-t border-neutral-100">
+      {citations.map((citation) => (
+        <li key={citation.marker}>
+          <CitationLink citation={citation} />
+        </li>
+      ))}
+    </ol>
+  );
+}
+
+function CitationLink({ citation }: { citation: Citation }) {
+  const openNew = useTabs((state) => state.openNew);
+
+  const handleClick = useCallback(() => {
+    openNew({ type: "sessions", id: citation.sessionId });
+  }, [openNew, citation.sessionId]);
+
+  return (
+    <button
+      type="button"
+      onClick={handleClick}
+      className="text-xs flex gap-2 w-full text-left text-neutral-500 hover:text-neutral-800"
+    >
+      <span>[{citation.marker}]</span>
+      <span className="truncate">
+        {citation.sessionTitle || "Untitled"} · {formatLocation(citation)}
+      </span>
+    </button>
+  );
+}
@@ -4,6 +4,7 @@ import { Streamdown } from "streamdown";
 
 import type { HyprUIMessage } from "../../../chat/types";
 import { hasRenderableContent } from "../shared";
+import { Citations } from "./citations";
 import { Disclosure, MessageBubble, MessageContainer } from "./shared";
 import { Tool } from "./tool";
 import type { Part } from "./types";
@@ -64,6 +65,7 @@ export function NormalMessage({
           {message.parts.map((part, i) => (
             <Part key={i} part={part as Part} />
           ))}
+          {!isUser && <Citations message={message} />}
         </MessageBubble>
         {!isUser && (
           <div className="flex items-center gap-1 mt-1 opacity-0 group-hover:opacity-100 transition-opacity">
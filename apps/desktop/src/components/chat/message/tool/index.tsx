@@ -6,12 +6,16 @@ import { ToolGeneric } from "./generic";
 import { ToolListSubscriptions } from "./list-subscriptions";
 import { ToolSearchSessions } from "./search";
 import { ToolSearchIssues } from "./search-issues";
+import { ToolSearchVault } from "./search-vault";
 
 const toolRegistry: Record<string, (props: { part: Part }) => React.ReactNode> =
   {
     "tool-search_sessions": ToolSearchSessions as (props: {
       part: Part;
     }) => React.ReactNode,
+    "tool-search_vault": ToolSearchVault as (props: {
+      part: Part;
+    }) => React.ReactNode,
     "tool-create_issue": ToolCreateIssue as (props: {
       part: Part;
     }) => React.ReactNode,
@@ -0,0 +1,139 @@
+import { LibraryIcon } from "lucide-react";
+import { useCallback } from "react";
+
+import type { RetrievalChunk } from "@hypr/plugin-template";
+
+import { useTabs } from "../../../../store/zustand/tabs";
+import { Disclosure } from "../shared";
+import { ToolRenderer } from "../types";
+import { useToolState } from "./shared";
+
+type Renderer = ToolRenderer<"tool-search_vault">;
+type Part = Parameters<Renderer>[0]["part"];
+
+function parseChunks(output: unknown): RetrievalChunk[] {
+  if (!output || typeof output !== "object" || !("chunks" in output)) {
+    return [];
+  }
+
+  const { chunks } = output as { chunks?: unknown };
+  return Array.isArray(chunks) ? (chunks as RetrievalChunk[]) : [];
+}
+
+function formatOffset(ms: number): string {
+  const total = Math.max(0, Math.floor(ms / 1000));
+  const hours = Math.floor(total / 3600);
+  const minutes = String(Math.floor(total / 60) % 60).padStart(2, "0");
+  const seconds = String(total % 60).padStart(2, "0");
+  return hours > 0
+    ? `${hours}:${minutes}:${seconds}`
+    : `${minutes}:${seconds}`;
+}
+
+export function formatLocation(
+  chunk: Pick<RetrievalChunk, "source" | "startMs" | "endMs">,
+): string {
+  if (chunk.source === "note" || chunk.startMs === null) {
+    return chunk.source;
+  }
+
+  const range =
+    chunk.endMs === null
+      ? formatOffset(chunk.startMs)
+      : `${formatOffset(chunk.startMs)}-${formatOffset(chunk.endMs)}`;
+  return `${chunk.source} ${range}`;
+}
+
+export const ToolSearchVault: Renderer = ({ part }) => {
+  const { running: disabled } = useToolState(part);
+
+  return (
+    <Disclosure
+      icon={<LibraryIcon className="w-3 h-3" />}
+      title={getTitle(part)}
+      disabled={disabled}
+    >
+      <RenderContent part={part} />
+    </Disclosure>
+  );
+};
+
+const getTitle = (part: Part) => {
+  if (part.state === "input-streaming") {
+    return "Preparing search...";
+  }
+  if (part.state === "input-available") {
+    return `Searching notes for: ${part.input.query}`;
+  }
+  if (part.state === "output-available") {
+    return `Searched notes for: ${part.input.query}`;
+  }
+  if (part.state === "output-error") {
+    return part.input ? `Search failed: ${part.input.query}` : "Search failed";
+  }
+  return "Search";
+};
+
+function RenderContent({ part }: { part: Part }) {
+  if (part.state === "output-available") {
+    const chunks = parseChunks(part.output);
+
+    if (chunks.length === 0) {
+      return (
+        <div className="text-xs text-muted-foreground flex justify-center items-center py-2">
+          No results found
+        </div>
+      );
+    }
+
+    return (
+      <ol className="flex flex-col gap-1">
+        {chunks.map((chunk, index) => (
+          <li key={`${chunk.sessionId}-${index}`}>
+            <RenderCitation marker={index + 1} chunk={chunk} />
+          </li>
+        ))}
+      </ol>
+    );
+  }
+
+  if (part.state === "output-error") {
+    return <div className="text-sm text-red-500">Error: {part.errorText}</div>;
+  }
+
+  return null;
+}
+
+function RenderCitation({
+  marker,
+  chunk,
+}: {
+  marker: number;
+  chunk: RetrievalChunk;
+}) {
+  const openNew = useTabs((state) => state.openNew);
+
+  const handleClick = useCallback(() => {
+    openNew({ type: "sessions", id: chunk.sessionId });
+  }, [openNew, chunk.sessionId]);
+
+  return (
+    <button
+      type="button"
+      onClick={handleClick}
+      className="text-xs flex gap-2 w-full text-left"
+    >
+      <span className="text-muted-foreground">[{marker}]</span>
+      <span className="flex flex-col min-w-0">
+        <span className="font-medium truncate">
+          {chunk.sessionTitle || "Untitled"}
+          <span className="font-normal text-muted-foreground">
+            {" "}
+            · {formatLocation(chunk)}
+          </span>
+        </span>
+        <span className="text-muted-foreground truncate">{chunk.text}</span>
+      </span>
+    </button>
+  );
+}
@@ -1,7 +1,11 @@
 import { type UnlistenFn } from "@tauri-apps/api/event";
 import { useEffect, useRef } from "react";
 
-import { events as notificationEvents } from "@hypr/plugin-notification";
+import { commands as listenerCommands } from "@hypr/plugin-listener";
+import {
+  commands as notificationCommands,
+  events as notificationEvents,
+} from "@hypr/plugin-notification";
 import {
   commands as updaterCommands,
   events as updaterEvents,
@@ -85,10 +89,22 @@ function useNotificationEvents() {
 
     void notificationEvents.notificationEvent
       .listen(({ payload }) => {
+        if (payload.type === "notification_snooze") {
+          if (payload.source?.type === "calendar_event") {
+            void notificationCommands.snoozeReminder(payload.key, null);
+          }
+          return;
+        }
+
         if (
           payload.type === "notification_confirm" ||
           payload.type === "notification_accept"
         ) {
+          if (payload.source?.type === "auto_stop") {
+            void listenerCommands.autoStopSignal("keep_recording");
+            return;
+          }
+
           const eventId =
             payload.source?.type === "calendar_event"
               ? payload.source.event_id
@@ -0,0 +1,113 @@
+import { useMutation, useQuery, useQueryClient } from "@tanstack/react-query";
+import { useEffect, useState } from "react";
+
+import {
+  commands as appleCalendarCommands,
+  type CalDavConfig,
+} from "@hypr/plugin-apple-calendar";
+import { Button } from "@hypr/ui/components/ui/button";
+import { Input } from "@hypr/ui/components/ui/input";
+import { Textarea } from "@hypr/ui/components/ui/textarea";
+
+const QUERY_KEY = ["caldav-config"];
+
+export function useCalDavConfig() {
+  return useQuery({
+    queryKey: QUERY_KEY,
+    queryFn: async () => {
+      const result = await appleCalendarCommands.caldavConfig();
+      if (result.status === "error") {
+        throw new Error(result.error);
+      }
+      return result.data;
+    },
+  });
+}
+
+export function isCalDavConfigured(config: CalDavConfig | null | undefined) {
+  return !!config && (!!config.account || config.feeds.length > 0);
+}
+
+export function CalDavAccountForm() {
+  const queryClient = useQueryClient();
+  const { data: config } = useCalDavConfig();
+
+  const [serverUrl, setServerUrl] = useState("");
+  const [username, setUsername] = useState("");
+  const [password, setPassword] = useState("");
+  const [feeds, setFeeds] = useState("");
+
+  useEffect(() => {
+    if (!config) return;
+    setServerUrl(config.account?.server_url ?? "");
+    setUsername(config.account?.username ?? "");
+    setPassword("");
+    setFeeds(config.feeds.join("\n"));
+  }, [config]);
+
+  const save = useMutation({
+    mutationFn: async () => {
+      const account = serverUrl.trim()
+        ? { server_url: serverUrl.trim(), username: username.trim() }
+        : null;
+      // Left empty, the password already stored for the account is kept.
+      const result = await appleCalendarCommands.configureCaldav(
+        {
+          account,
+          feeds: feeds
+            .split("\n")
+            .map((url) => url.trim())
+            .filter(Boolean),
+        },
+        password || null,
+      );
+      if (result.status === "error") {
+        throw new Error(result.error);
+      }
+    },
+    onSuccess: () => {
+      queryClient.invalidateQueries({ queryKey: QUERY_KEY });
+    },
+  });
+
+  return (
+    <form
+      className="flex flex-col gap-2 px-1 pt-1"
+      onSubmit={(e) => {
+        e.preventDefault();
+        save.mutate();
+      }}
+    >
+      <Input
+        placeholder="Server URL, e.g. https://caldav.fastmail.com"
+        value={serverUrl}
+        onChange={(e) => setServerUrl(e.target.value)}
+      />
+      <Input
+        placeholder="Username"
+        value={username}
+        onChange={(e) => setUsername(e.target.value)}
+      />
+      <Input
+        type="password"
+        placeholder={
+          config?.account ? "App password (unchanged)" : "App password"
+        }
+        value={password}
+        onChange={(e) => setPassword(e.target.value)}
+      />
+      <Textarea
+        placeholder="ICS feed URLs, one per line"
+        value={feeds}
+        onChange={(e) => setFeeds(e.target.value)}
+        rows={2}
+      />
+      {save.error && (
+        <p className="text-xs text-red-500">{save.error.message}</p>
+      )}
+      <Button type="submit" size="sm" disabled={save.isPending}>
+        {save.isPending ? "Connecting..." : "Save"}
+      </Button>
+    </form>
+  );
+}
@@ -9,7 +9,7 @@ export type CalendarProvider = {
   displayName: string;
   icon: ReactNode;
   badge?: string | null;
-  platform?: "macos" | "all";
+  platform?: "macos" | "linux" | "all";
   docsPath: string;
   nangoIntegrationId?: string;
 };
@@ -25,6 +25,16 @@ const _PROVIDERS = [
     docsPath: "https://hyprnote.com/docs/calendar/apple",
     nangoIntegrationId: undefined,
   },
+  {
+    disabled: false,
+    id: "caldav",
+    displayName: "CalDAV",
+    badge: "Beta",
+    icon: <Icon icon="mdi:calendar-sync-outline" width={20} height={20} />,
+    platform: "linux",
+    docsPath: "https://hyprnote.com/docs/calendar/caldav",
+    nangoIntegrationId: undefined,
+  },
   {
     disabled: false,
     id: "google",
@@ -11,20 +11,30 @@ import { usePermission } from "../../../../hooks/usePermissions";
 import { AppleCalendarSelection } from "./apple/calendar-selection";
 import { SyncProvider } from "./apple/context";
 import { AccessPermissionRow } from "./apple/permission";
+import {
+  CalDavAccountForm,
+  isCalDavConfigured,
+  useCalDavConfig,
+} from "./caldav/account-form";
 import { OAuthProviderContent } from "./oauth/provider-content";
 import { PROVIDERS } from "./shared";
 
 export function CalendarSidebarContent() {
-  const isMacos = platform() === "macos";
+  const currentPlatform = platform();
   const calendar = usePermission("calendar");
   const contacts = usePermission("contacts");
+  const { data: caldavConfig } = useCalDavConfig();
 
   const visibleProviders = PROVIDERS.filter(
-    (p) => p.platform === "all" || (p.platform === "macos" && isMacos),
+    (p) => p.platform === "all" || p.platform === currentPlatform,
   );
 
   return (
-    <Accordion type="single" collapsible defaultValue="apple">
+    <Accordion
+      type="single"
+      collapsible
+      defaultValue={visibleProviders[0]?.id}
+    >
       {visibleProviders.map((provider) =>
         provider.disabled ? (
           <div
@@ -93,6 +103,16 @@ export function CalendarSidebarContent() {
                   )}
                 </div>
               )}
+              {provider.id === "caldav" && (
+                <div className="flex flex-col gap-3">
+                  <CalDavAccountForm />
+                  {isCalDavConfigured(caldavConfig) && (
+                    <SyncProvider>
+                      <AppleCalendarSelection />
+                    </SyncProvider>
+                  )}
+                </div>
+              )}
               {provider.nangoIntegrationId && (
                 <OAuthProviderContent config={provider} />
               )}
@@ -0,0 +1,36 @@
+import { useMutation } from "@tanstack/react-query";
+import { Loader2Icon, MailIcon } from "lucide-react";
+
+import { commands as fsDbCommands } from "@hypr/plugin-fs-db";
+import { commands as openerCommands } from "@hypr/plugin-opener2";
+import { DropdownMenuItem } from "@hypr/ui/components/ui/dropdown-menu";
+
+export function DraftFollowup({ sessionId }: { sessionId: string }) {
+  const { mutate, isPending } = useMutation({
+    mutationFn: async () => {
+      const result = await fsDbCommands.draftFollowup(sessionId);
+      if (result.status === "error") {
+        throw new Error(result.error);
+      }
+      return result.data;
+    },
+    onSuccess: (path) => {
+      openerCommands.openPath(path, null);
+    },
+    onError: console.error,
+  });
+
+  return (
+    <DropdownMenuItem
+      onClick={(e) => {
+        e.preventDefault();
+        mutate();
+      }}
+      disabled={isPending}
+      className="cursor-pointer"
+    >
+      {isPending ? <Loader2Icon className="animate-spin" /> : <MailIcon />}
+      <span>{isPending ? "Drafting..." : "Draft Follow-up Email"}</span>
+    </DropdownMenuItem>
+  );
+}
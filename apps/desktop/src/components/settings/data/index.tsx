@@ -60,6 +60,11 @@ export function Data() {
         throw new Error(importResult.error);
       }
 
+      const commitResult = await commands.commitImport(source);
+      if (commitResult.status === "error") {
+        throw new Error(commitResult.error);
+      }
+
       return result.data.stats;
     },
     onSuccess: () => {
@@ -13,10 +13,10 @@ import {
   syncCalendarEvents,
 } from "../services/apple-calendar";
 import {
-  checkEventNotifications,
   EVENT_NOTIFICATION_INTERVAL,
   EVENT_NOTIFICATION_TASK_ID,
-  type NotifiedEventsMap,
+  type ReminderSyncState,
+  syncEventReminders,
 } from "../services/event-notification";
 import * as main from "../store/tinybase/store/main";
 import * as settings from "../store/tinybase/store/settings";
@@ -28,7 +28,10 @@ export function TaskManager() {
   const queries = main.UI.useQueries(main.STORE_ID);
 
   const settingsStore = settings.UI.useStore(settings.STORE_ID);
-  const notifiedEventsRef = useRef<NotifiedEventsMap>(new Map());
+  const reminderSyncRef = useRef<ReminderSyncState>({
+    events: "",
+    settings: "",
+  });
 
   useSetTask(CALENDAR_SYNC_TASK_ID, async () => {
     await syncCalendarEvents(
@@ -60,10 +63,10 @@ export function TaskManager() {
 
   useSetTask(EVENT_NOTIFICATION_TASK_ID, async () => {
     if (!store || !settingsStore) return;
-    checkEventNotifications(
+    syncEventReminders(
       store as main.Store,
       settingsStore as settings.Store,
-      notifiedEventsRef.current,
+      reminderSyncRef.current,
     );
   }, [store, settingsStore]);
 
@@ -19,6 +19,11 @@ export type ConfigKey =
   | "ai_language"
   | "spoken_languages"
   | "save_recordings"
+  | "auto_stop"
+  | "auto_stop_silence_minutes"
+  | "auto_stop_ended_silence_secs"
+  | "auto_stop_event_overrun_minutes"
+  | "auto_stop_grace_period_secs"
   | "telemetry_consent"
   | "current_llm_provider"
   | "current_llm_model"
@@ -136,6 +141,31 @@ export const CONFIG_REGISTRY = {
     default: true,
   },
 
+  auto_stop: {
+    key: "auto_stop",
+    default: true,
+  },
+
+  auto_stop_silence_minutes: {
+    key: "auto_stop_silence_minutes",
+    default: 15,
+  },
+
+  auto_stop_ended_silence_secs: {
+    key: "auto_stop_ended_silence_secs",
+    default: 60,
+  },
+
+  auto_stop_event_overrun_minutes: {
+    key: "auto_stop_event_overrun_minutes",
+    default: 5,
+  },
+
+  auto_stop_grace_period_secs: {
+    key: "auto_stop_grace_period_secs",
+    default: 60,
+  },
+
   telemetry_consent: {
     key: "telemetry_consent",
     default: true,
@@ -2,7 +2,11 @@ import React, { createContext, useContext, useEffect, useRef } from "react";
 import { useStore } from "zustand";
 import { useShallow } from "zustand/shallow";
 
-import { events as detectEvents } from "@hypr/plugin-detect";
+import {
+  events as detectEvents,
+  type InstalledApp,
+} from "@hypr/plugin-detect";
+import { commands as listenerCommands } from "@hypr/plugin-listener";
 import { commands as notificationCommands } from "@hypr/plugin-notification";
 
 import { useConfigValue } from "../config/use-config";
@@ -52,12 +56,18 @@ const useHandleDetectEvents = (store: ListenerStore) => {
   const stop = useStore(store, (state) => state.stop);
   const setMuted = useStore(store, (state) => state.setMuted);
   const notificationDetectEnabled = useConfigValue("notification_detect");
+  const autoStopEnabled = useConfigValue("auto_stop");
 
   const notificationDetectEnabledRef = useRef(notificationDetectEnabled);
   useEffect(() => {
     notificationDetectEnabledRef.current = notificationDetectEnabled;
   }, [notificationDetectEnabled]);
 
+  const autoStopEnabledRef = useRef(autoStopEnabled);
+  useEffect(() => {
+    autoStopEnabledRef.current = autoStopEnabled;
+  }, [autoStopEnabled]);
+
   useEffect(() => {
     let unlisten: (() => void) | undefined;
     let cancelled = false;
@@ -65,11 +75,19 @@ const useHandleDetectEvents = (store: ListenerStore) => {
     detectEvents.detectEvent
       .listen(({ payload }) => {
         if (payload.type === "micDetected") {
-          if (!notificationDetectEnabledRef.current) {
+          const { live, setMeetingApps } = store.getState();
+          if (live.status === "active") {
+            // Started before joining the meeting: the first app to take the
+            // mic is the one the session belongs to.
+            if (live.meetingApps.length === 0) {
+              setMeetingApps(payload.apps.map((a) => a.id));
+            } else if (usesMeetingApp(payload.apps, live.meetingApps)) {
+              void listenerCommands.autoStopSignal("meeting_app_started");
+            }
             return;
           }
 
-          if (store.getState().live.status === "active") {
+          if (!notificationDetectEnabledRef.current) {
             return;
           }
 
@@ -87,9 +105,21 @@ const useHandleDetectEvents = (store: ListenerStore) => {
             participants: null,
             event_details: null,
             action_label: null,
+            snooze_label: null,
           });
         } else if (payload.type === "micStopped") {
-          stop();
+          // With auto-stop off, any app releasing the mic ends the session
+          // right away, as before. With it on, only the session's own
+          // meeting app counts, and the policy prompts before stopping.
+          if (!autoStopEnabledRef.current) {
+            stop();
+            return;
+          }
+
+          const { live } = store.getState();
+          if (usesMeetingApp(payload.apps, live.meetingApps)) {
+            void listenerCommands.autoStopSignal("meeting_app_stopped");
+          }
         } else if (payload.type === "sleepStateChanged") {
           if (payload.value) {
             stop();
@@ -115,3 +145,7 @@ const useHandleDetectEvents = (store: ListenerStore) => {
     };
   }, [stop, setMuted]);
 };
+
+function usesMeetingApp(apps: InstalledApp[], meetingApps: string[]) {
+  return apps.some((app) => meetingApps.includes(app.id));
+}
@@ -0,0 +1,217 @@
+import { type ChunkDocument, commands as tantivy } from "@hypr/plugin-tantivy";
+
+import { type Store as MainStore } from "../../../store/tinybase/store/main";
+import { extractPlainText, toEpochMs, toTrimmedString } from "./utils";
+
+// ~300 tokens per chunk, so a few sessions' worth fit in the chat prompt.
+const MAX_CHUNK_CHARS = 1200;
+
+interface TranscriptWord {
+  text: string;
+  start_ms: number;
+  end_ms: number;
+}
+
+type TranscriptChunk = TranscriptWord;
+
+function splitWords(text: string): string[] {
+  const chunks: string[] = [];
+  let current = "";
+
+  for (const word of text.split(/\s+/).filter(Boolean)) {
+    if (current && current.length + word.length + 1 > MAX_CHUNK_CHARS) {
+      chunks.push(current);
+      current = "";
+    }
+    current = current ? `${current} ${word}` : word;
+  }
+
+  if (current) {
+    chunks.push(current);
+  }
+
+  return chunks;
+}
+
+function parseWords(value: unknown): TranscriptWord[] {
+  if (typeof value !== "string" || !value) {
+    return [];
+  }
+
+  try {
+    const parsed = JSON.parse(value) as unknown;
+    return Array.isArray(parsed)
+      ? parsed.filter(
+          (word): word is TranscriptWord =>
+            typeof word?.text === "string" &&
+            typeof word?.start_ms === "number" &&
+            typeof word?.end_ms === "number",
+        )
+      : [];
+  } catch {
+    return [];
+  }
+}
+
+/** Row ids of a session's transcripts and enhanced notes. */
+export interface SessionRows {
+  transcripts: string[];
+  enhancedNotes: string[];
+}
+
+export function emptySessionRows(): SessionRows {
+  return { transcripts: [], enhancedNotes: [] };
+}
+
+/** Groups transcript and enhanced note rows by session, in one pass each. */
+export function groupSessionRows(store: MainStore): Map<string, SessionRows> {
+  const rowsBySession = new Map<string, SessionRows>();
+
+  const rowsOf = (sessionId: unknown) => {
+    if (typeof sessionId !== "string" || !sessionId) {
+      return null;
+    }
+    let rows = rowsBySession.get(sessionId);
+    if (!rows) {
+      rows = emptySessionRows();
+      rowsBySession.set(sessionId, rows);
+    }
+    return rows;
+  };
+
+  store.forEachRow("transcripts", (rowId, _forEachCell) => {
+    rowsOf(store.getCell("transcripts", rowId, "session_id"))?.transcripts.push(
+      rowId,
+    );
+  });
+  store.forEachRow("enhanced_notes", (rowId, _forEachCell) => {
+    rowsOf(
+      store.getCell("enhanced_notes", rowId, "session_id"),
+    )?.enhancedNotes.push(rowId);
+  });
+
+  return rowsBySession;
+}
+
+function collectSessionRows(store: MainStore, sessionId: string): SessionRows {
+  const rows = emptySessionRows();
+
+  store.forEachRow("transcripts", (rowId, _forEachCell) => {
+    if (store.getCell("transcripts", rowId, "session_id") === sessionId) {
+      rows.transcripts.push(rowId);
+    }
+  });
+  store.forEachRow("enhanced_notes", (rowId, _forEachCell) => {
+    if (store.getCell("enhanced_notes", rowId, "session_id") === sessionId) {
+      rows.enhancedNotes.push(rowId);
+    }
+  });
+
+  return rows;
+}
+
+function collectNoteTexts(
+  store: MainStore,
+  sessionId: string,
+  enhancedNoteIds: string[],
+): string[] {
+  const texts = [
+    extractPlainText(store.getCell("sessions", sessionId, "raw_md")),
+    ...enhancedNoteIds.map((rowId) =>
+      extractPlainText(store.getCell("enhanced_notes", rowId, "content")),
+    ),
+  ];
+
+  return texts.filter(Boolean);
+}
+
+function collectTranscriptWords(
+  store: MainStore,
+  transcriptIds: string[],
+): TranscriptWord[] {
+  return transcriptIds
+    .flatMap((rowId) =>
+      parseWords(store.getCell("transcripts", rowId, "words")),
+    )
+    .sort((a, b) => a.start_ms - b.start_ms);
+}
+
+function chunkTranscript(words: TranscriptWord[]): TranscriptChunk[] {
+  const chunks: TranscriptChunk[] = [];
+  let current: TranscriptWord[] = [];
+  let length = 0;
+
+  const flush = () => {
+    if (current.length > 0) {
+      chunks.push({
+        text: current.map((word) => word.text.trim()).join(" "),
+        start_ms: current[0].start_ms,
+        end_ms: current[current.length - 1].end_ms,
+      });
+    }
+    current = [];
+    length = 0;
+  };
+
+  for (const word of words) {
+    if (length + word.text.length + 1 > MAX_CHUNK_CHARS) {
+      flush();
+    }
+    current.push(word);
+    length += word.text.length + 1;
+  }
+  flush();
+
+  return chunks;
+}
+
+export function createSessionChunks(
+  store: MainStore,
+  sessionId: string,
+  rows: SessionRows = collectSessionRows(store, sessionId),
+): ChunkDocument[] {
+  const title =
+    toTrimmedString(store.getCell("sessions", sessionId, "title")) ||
+    "Untitled";
+  const created_at = toEpochMs(
+    store.getCell("sessions", sessionId, "created_at"),
+  );
+
+  const notes = collectNoteTexts(store, sessionId, rows.enhancedNotes)
+    .flatMap(splitWords)
+    .map((content, index) => ({
+      id: `${sessionId}:note:${index}`,
+      session_id: sessionId,
+      source: "note" as const,
+      title,
+      content,
+      created_at,
+      start_ms: null,
+      end_ms: null,
+    }));
+
+  const words = collectTranscriptWords(store, rows.transcripts);
+  const transcript = chunkTranscript(words).map((chunk, index) => ({
+    id: `${sessionId}:transcript:${index}`,
+    session_id: sessionId,
+    source: "transcript" as const,
+    title,
+    content: chunk.text,
+    created_at,
+    start_ms: chunk.start_ms,
+    end_ms: chunk.end_ms,
+  }));
+
+  return [...notes, ...transcript];
+}
+
+export async function indexSessionChunks(
+  store: MainStore,
+  sessionId: string,
+  rows?: SessionRows,
+): Promise<void> {
+  await tantivy.updateChunks(
+    sessionId,
+    createSessionChunks(store, sessionId, rows),
+  );
+}
@@ -7,15 +7,20 @@ import {
   useState,
 } from "react";
 
-import { commands as tantivy } from "@hypr/plugin-tantivy";
+import {
+  type RetrieveResult,
+  commands as tantivy,
+} from "@hypr/plugin-tantivy";
 
 import { type Store as MainStore } from "../../../store/tinybase/store/main";
 import { buildTantivyFilters } from "./filters";
 import { indexHumans, indexOrganizations, indexSessions } from "./indexing";
 import {
+  createEnhancedNoteListener,
   createHumanListener,
   createOrganizationListener,
   createSessionListener,
+  createTranscriptListener,
 } from "./listeners";
 import type { SearchEntityType, SearchFilters, SearchHit } from "./types";
 import { normalizeQuery } from "./utils";
@@ -32,6 +37,10 @@ const SearchEngineContext = createContext<{
     query: string,
     filters?: SearchFilters | null,
   ) => Promise<SearchHit[]>;
+  retrieve: (
+    query: string,
+    filters?: SearchFilters | null,
+  ) => Promise<RetrieveResult>;
   isIndexing: boolean;
 } | null>(null);
 
@@ -74,7 +83,24 @@ export function SearchEngineProvider({
           createOrganizationListener(),
         );
 
-        listenerIds.current = [listener1, listener2, listener3];
+        const listener4 = store.addRowListener(
+          "transcripts",
+          null,
+          createTranscriptListener(),
+        );
+        const listener5 = store.addRowListener(
+          "enhanced_notes",
+          null,
+          createEnhancedNoteListener(),
+        );
+
+        listenerIds.current = [
+          listener1,
+          listener2,
+          listener3,
+          listener4,
+          listener5,
+        ];
       } catch (error) {
         console.error("Failed to create search index:", error);
       } finally {
@@ -129,8 +155,36 @@ export function SearchEngineProvider({
     [],
   );
 
+  const retrieve = useCallback(
+    async (
+      query: string,
+      filters: SearchFilters | null = null,
+    ): Promise<RetrieveResult> => {
+      const empty: RetrieveResult = { chunks: [], token_count: 0 };
+
+      try {
+        const result = await tantivy.retrieve({
+          query: normalizeQuery(query),
+          created_at: buildTantivyFilters(filters)?.created_at ?? null,
+        });
+
+        if (result.status === "error") {
+          console.error("Retrieval failed:", result.error);
+          return empty;
+        }
+
+        return result.data;
+      } catch (error) {
+        console.error("Retrieval failed:", error);
+        return empty;
+      }
+    },
+    [],
+  );
+
   const value = {
     search,
+    retrieve,
     isIndexing,
   };
 
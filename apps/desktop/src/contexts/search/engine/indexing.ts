@@ -1,6 +1,11 @@
 import { type SearchDocument, commands as tantivy } from "@hypr/plugin-tantivy";
 
 import { type Store as MainStore } from "../../../store/tinybase/store/main";
+import {
+  emptySessionRows,
+  groupSessionRows,
+  indexSessionChunks,
+} from "./chunks";
 import {
   createHumanSearchableContent,
   createSessionSearchableContent,
@@ -44,6 +49,15 @@ export async function indexSessions(store: MainStore): Promise<void> {
   if (documents.length > 0) {
     await tantivy.updateDocuments(documents, null);
   }
+
+  const rowsBySession = groupSessionRows(store);
+  for (const document of documents) {
+    await indexSessionChunks(
+      store,
+      document.id,
+      rowsBySession.get(document.id) ?? emptySessionRows(),
+    );
+  }
 }
 
 export async function indexHumans(store: MainStore): Promise<void> {
@@ -4,6 +4,7 @@ import { commands as tantivy } from "@hypr/plugin-tantivy";
 
 import { Schemas } from "../../../store/tinybase/store/main";
 import { type Store as MainStore } from "../../../store/tinybase/store/main";
+import { indexSessionChunks } from "./chunks";
 import {
   createHumanSearchableContent,
   createSessionSearchableContent,
@@ -15,6 +16,63 @@ import {
   toTrimmedString,
 } from "./utils";
 
+// Transcripts change word by word while recording, so chunks are rebuilt
+// once a session's rows settle rather than on every write.
+const CHUNK_REINDEX_DELAY_MS = 2000;
+const pendingChunkReindex = new Map<string, ReturnType<typeof setTimeout>>();
+
+function scheduleChunkReindex(store: MainStore, sessionId: string) {
+  clearTimeout(pendingChunkReindex.get(sessionId));
+  pendingChunkReindex.set(
+    sessionId,
+    setTimeout(() => {
+      pendingChunkReindex.delete(sessionId);
+      void indexSessionChunks(store, sessionId);
+    }, CHUNK_REINDEX_DELAY_MS),
+  );
+}
+
+function indexSession(store: MainStore, sessionId: string) {
+  const rowExists = store.getRow("sessions", sessionId);
+
+  if (!rowExists) {
+    clearTimeout(pendingChunkReindex.get(sessionId));
+    pendingChunkReindex.delete(sessionId);
+    void tantivy.removeDocument(sessionId, null);
+    void tantivy.removeChunks(sessionId);
+    return;
+  }
+
+  const fields = ["user_id", "created_at", "title", "raw_md", "transcript"];
+  const row = collectCells(store, "sessions", sessionId, fields);
+  row.enhanced_notes_content = collectEnhancedNotesContent(store, sessionId);
+  const title = toTrimmedString(row.title) || "Untitled";
+
+  void tantivy.updateDocument(
+    {
+      id: sessionId,
+      doc_type: "session",
+      language: null,
+      title,
+      content: createSessionSearchableContent(row),
+      created_at: toEpochMs(row.created_at),
+      facets: [],
+    },
+    null,
+  );
+  scheduleChunkReindex(store, sessionId);
+}
+
+// Both the current and the previous session, so a deleted or moved row
+// drops out of the session it used to belong to.
+function indexAffectedSessions(store: MainStore, sessionIds: unknown[]) {
+  for (const sessionId of new Set(sessionIds)) {
+    if (typeof sessionId === "string" && sessionId) {
+      indexSession(store, sessionId);
+    }
+  }
+}
+
 export function createSessionListener(): RowListener<
   Schemas,
   "sessions",
@@ -23,37 +81,49 @@ export function createSessionListener(): RowListener<
 > {
   return (store, _, rowId) => {
     try {
-      const rowExists = store.getRow("sessions", rowId);
+      indexSession(store, rowId);
+    } catch (error) {
+      console.error("Failed to update session in search index:", error);
+    }
+  };
+}
 
-      if (!rowExists) {
-        void tantivy.removeDocument(rowId, null);
-      } else {
-        const fields = [
-          "user_id",
-          "created_at",
-          "title",
-          "raw_md",
-          "transcript",
-        ];
-        const row = collectCells(store, "sessions", rowId, fields);
-        row.enhanced_notes_content = collectEnhancedNotesContent(store, rowId);
-        const title = toTrimmedString(row.title) || "Untitled";
+export function createTranscriptListener(): RowListener<
+  Schemas,
+  "transcripts",
+  null,
+  MainStore
+> {
+  return (store, _, rowId, getCellChange) => {
+    try {
+      const [, previous] =
+        getCellChange?.("transcripts", rowId, "session_id") ?? [];
+      indexAffectedSessions(store, [
+        store.getCell("transcripts", rowId, "session_id"),
+        previous,
+      ]);
+    } catch (error) {
+      console.error("Failed to update transcript in search index:", error);
+    }
+  };
+}
 
-        void tantivy.updateDocument(
-          {
-            id: rowId,
-            doc_type: "session",
-            language: null,
-            title,
-            content: createSessionSearchableContent(row),
-            created_at: toEpochMs(row.created_at),
-            facets: [],
-          },
-          null,
-        );
-      }
+export function createEnhancedNoteListener(): RowListener<
+  Schemas,
+  "enhanced_notes",
+  null,
+  MainStore
+> {
+  return (store, _, rowId, getCellChange) => {
+    try {
+      const [, previous] =
+        getCellChange?.("enhanced_notes", rowId, "session_id") ?? [];
+      indexAffectedSessions(store, [
+        store.getCell("enhanced_notes", rowId, "session_id"),
+        previous,
+      ]);
     } catch (error) {
-      console.error("Failed to update session in search index:", error);
+      console.error("Failed to update enhanced note in search index:", error);
     }
   };
 }
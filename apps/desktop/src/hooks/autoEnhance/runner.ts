@@ -31,6 +31,7 @@ export function useAutoEnhanceRunner(
 } {
   const sessionId = tab.id;
   const model = useLanguageModel("enhance");
+  const extractModel = useLanguageModel("extract");
   const { conn: llmConn } = useLLMConnection();
   const { updateSessionTabState } = useTabs();
   const createEnhancedNote = useCreateEnhancedNote();
@@ -84,6 +85,11 @@ export function useAutoEnhanceRunner(
     onSuccess: handleTitleSuccess,
   });
 
+  const extractTask = useAITaskTask(
+    createTaskId(sessionId, "extract"),
+    "extract",
+  );
+
   const handleEnhanceSuccess = useCallback(
     (text: string) => {
       const noteId = currentNoteIdRef.current;
@@ -102,11 +108,22 @@ export function useAutoEnhanceRunner(
         if (!trimmedTitle && model) {
           void titleTask.start({ model, args: { sessionId } });
         }
+
+        if (extractModel) {
+          void extractTask.start({ model: extractModel, args: { sessionId } });
+        }
       } catch (error) {
         console.error("Failed to convert markdown to JSON:", error);
       }
     },
-    [store, sessionId, model, titleTask.start],
+    [
+      store,
+      sessionId,
+      model,
+      titleTask.start,
+      extractModel,
+      extractTask.start,
+    ],
   );
 
   const prevEnhanceStatusRef = useRef<string>("idle");
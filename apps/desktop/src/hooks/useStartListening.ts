@@ -1,8 +1,10 @@
 import { useCallback } from "react";
 
 import { commands as analyticsCommands } from "@hypr/plugin-analytics";
+import type { SessionEvent } from "@hypr/store";
+import { safeParseDate } from "@hypr/utils";
 
-import { useConfigValue } from "../config/use-config";
+import { useConfigValue, useConfigValues } from "../config/use-config";
 import { useListener } from "../contexts/listener";
 import * as main from "../store/tinybase/store/main";
 import type { SpeakerHintWithId, WordWithId } from "../store/transcript/types";
@@ -24,6 +26,19 @@ export function useStartListening(sessionId: string) {
 
   const record_enabled = useConfigValue("save_recordings");
   const languages = useConfigValue("spoken_languages");
+  const {
+    auto_stop,
+    auto_stop_silence_minutes,
+    auto_stop_ended_silence_secs,
+    auto_stop_event_overrun_minutes,
+    auto_stop_grace_period_secs,
+  } = useConfigValues([
+    "auto_stop",
+    "auto_stop_silence_minutes",
+    "auto_stop_ended_silence_secs",
+    "auto_stop_event_overrun_minutes",
+    "auto_stop_grace_period_secs",
+  ] as const);
 
   const start = useListener((state) => state.start);
   const { conn } = useSTTConnection();
@@ -38,6 +53,7 @@ export function useStartListening(sessionId: string) {
 
     const transcriptId = id();
     const startedAt = Date.now();
+    const event = getSessionEventById(store, sessionId);
 
     store.setRow("transcripts", transcriptId, {
       session_id: sessionId,
@@ -50,7 +66,7 @@ export function useStartListening(sessionId: string) {
 
     void analyticsCommands.event({
       event: "session_started",
-      has_calendar_event: !!getSessionEventById(store, sessionId),
+      has_calendar_event: !!event,
       stt_provider: conn.provider,
       stt_model: conn.model,
     });
@@ -129,6 +145,14 @@ export function useStartListening(sessionId: string) {
         base_url: conn.baseUrl,
         api_key: conn.apiKey,
         keywords,
+        auto_stop: {
+          enabled: auto_stop,
+          silence_minutes: auto_stop_silence_minutes,
+          ended_silence_secs: auto_stop_ended_silence_secs,
+          event_overrun_minutes: auto_stop_event_overrun_minutes,
+          grace_period_secs: auto_stop_grace_period_secs,
+        },
+        event_ends_at: eventEndsAt(event),
       },
       {
         handlePersist,
@@ -143,7 +167,20 @@ export function useStartListening(sessionId: string) {
     user_id,
     record_enabled,
     languages,
+    auto_stop,
+    auto_stop_silence_minutes,
+    auto_stop_ended_silence_secs,
+    auto_stop_event_overrun_minutes,
+    auto_stop_grace_period_secs,
   ]);
 
   return startListening;
 }
+
+function eventEndsAt(event: SessionEvent | null): string | null {
+  if (!event || event.is_all_day) {
+    return null;
+  }
+
+  return safeParseDate(event.ended_at)?.toISOString() ?? null;
+}
@@ -131,7 +131,7 @@ function Component() {
 }
 
 function ToolRegistration() {
-  const { search } = useSearchEngine();
+  const { search, retrieve } = useSearchEngine();
   const store = main.UI.useStore(main.STORE_ID);
 
   useRegisterTools(
@@ -141,8 +141,9 @@ function ToolRegistration() {
         search,
         resolveSessionContext: (sessionId) =>
           hydrateSessionContextFromFs(store, sessionId),
+        retrieve,
       }),
-    [search, store],
+    [search, retrieve, store],
   );
 
   return null;
@@ -1,7 +1,7 @@
 import {
-  type EventDetails,
   commands as notificationCommands,
   type Participant,
+  type UpcomingEvent,
 } from "@hypr/plugin-notification";
 import { format, TZDate } from "@hypr/utils";
 
@@ -12,10 +12,14 @@ import { findSessionByEventId } from "../../utils/session-event";
 export const EVENT_NOTIFICATION_TASK_ID = "eventNotification";
 export const EVENT_NOTIFICATION_INTERVAL = 30 * 1000; // 30 sec
 
-const NOTIFY_WINDOW_MS = 5 * 60 * 1000; // 5 minutes before
-const NOTIFIED_EVENTS_TTL_MS = 10 * 60 * 1000; // 10 minutes TTL for cleanup
+// The scheduler in the notification plugin times the reminders; it only
+// needs events that may come due before the next refresh.
+const LOOKAHEAD_MS = 24 * 60 * 60 * 1000;
+const DEFAULT_LEAD_MINUTES = [5];
+const SNOOZE_MINUTES = 2;
 
-export type NotifiedEventsMap = Map<string, number>;
+// What was last sent to the scheduler, to skip unchanged updates.
+export type ReminderSyncState = { events: string; settings: string };
 
 function getParticipantsForSession(
   store: main.Store,
@@ -43,24 +47,46 @@ function getParticipantsForSession(
   return participants;
 }
 
-export function checkEventNotifications(
+function getLeadMinutes(settingsStore: settings.Store): number[] {
+  try {
+    const raw = settingsStore.getValue("notification_event_lead_minutes");
+    if (raw) {
+      const parsed = JSON.parse(String(raw)) as unknown;
+      if (Array.isArray(parsed)) {
+        const minutes = parsed.filter(
+          (m): m is number => typeof m === "number" && m > 0,
+        );
+        if (minutes.length > 0) return minutes;
+      }
+    }
+  } catch {}
+
+  return DEFAULT_LEAD_MINUTES;
+}
+
+export function syncEventReminders(
   store: main.Store,
   settingsStore: settings.Store,
-  notifiedEvents: NotifiedEventsMap,
+  state: ReminderSyncState,
 ) {
-  const notificationEnabled = settingsStore?.getValue("notification_event");
-  if (!notificationEnabled || !store) {
+  if (!store || !settingsStore) {
     return;
   }
 
-  const now = Date.now();
-
-  for (const [key, timestamp] of notifiedEvents) {
-    if (now - timestamp > NOTIFIED_EVENTS_TTL_MS) {
-      notifiedEvents.delete(key);
-    }
+  const enabled = Boolean(settingsStore.getValue("notification_event"));
+  const reminderSettings = JSON.stringify({
+    enabled,
+    lead_minutes: getLeadMinutes(settingsStore),
+    snooze_minutes: SNOOZE_MINUTES,
+  });
+  if (reminderSettings !== state.settings) {
+    state.settings = reminderSettings;
+    void notificationCommands.setReminderSettings(JSON.parse(reminderSettings));
   }
 
+  const now = Date.now();
+  const upcoming: UpcomingEvent[] = [];
+
   const ignoredNonRecurrentIds = new Set<string>();
   const ignoredRecurrentMap = new Map<string, Set<string>>();
   const ignoredSeriesIds = new Set<string>();
@@ -95,7 +121,7 @@ export function checkEventNotifications(
     }
   } catch {}
 
-  const timezone = settingsStore?.getValue("timezone") as string | undefined;
+  const timezone = settingsStore.getValue("timezone") as string | undefined;
 
   store.forEachRow("events", (eventId, _forEachCell) => {
     const event = store.getRow("events", eventId);
@@ -103,7 +129,7 @@ export function checkEventNotifications(
 
     const startTime = new Date(String(event.started_at));
     const timeUntilStart = startTime.getTime() - now;
-    const notificationKey = `event-${eventId}-${startTime.getTime()}`;
+    if (timeUntilStart <= 0 || timeUntilStart > LOOKAHEAD_MS) return;
 
     const trackingId = event.tracking_id_event as string | undefined;
     const recurrenceSeriesId = event.recurrence_series_id as string | undefined;
@@ -121,45 +147,28 @@ export function checkEventNotifications(
       }
     }
 
-    if (timeUntilStart > 0 && timeUntilStart <= NOTIFY_WINDOW_MS) {
-      if (notifiedEvents.has(notificationKey)) {
-        return;
-      }
-
-      notifiedEvents.set(notificationKey, now);
-
-      const title = String(event.title || "Upcoming Event");
-      const minutesUntil = Math.ceil(timeUntilStart / 60000);
-
-      const eventDetails: EventDetails = {
-        what: title,
-        timezone: null,
-        location:
-          (event.meeting_link as string) || (event.location as string) || null,
-      };
+    const sessionId = findSessionByEventId(store, eventId, timezone);
+
+    upcoming.push({
+      id: eventId,
+      title: String(event.title || "Upcoming Event"),
+      start: startTime.toISOString(),
+      timezone: null,
+      location: (event.location as string) || null,
+      meeting_url: (event.meeting_link as string) || null,
+      participants: sessionId
+        ? getParticipantsForSession(store, sessionId)
+        : [],
+    });
+  });
 
-      let participants: Participant[] | null = null;
-      const sessionId = findSessionByEventId(store, eventId, timezone);
-      if (sessionId) {
-        const sessionParticipants = getParticipantsForSession(store, sessionId);
-        if (sessionParticipants.length > 0) {
-          participants = sessionParticipants;
-        }
-      }
+  upcoming.sort(
+    (a, b) => a.start.localeCompare(b.start) || a.id.localeCompare(b.id),
+  );
 
-      void notificationCommands.showNotification({
-        key: notificationKey,
-        title: title,
-        message: `Starting in ${minutesUntil} minute${minutesUntil !== 1 ? "s" : ""}`,
-        timeout: { secs: 30, nanos: 0 },
-        source: { type: "calendar_event", event_id: eventId },
-        start_time: Math.floor(startTime.getTime() / 1000),
-        participants: participants,
-        event_details: eventDetails,
-        action_label: "Start listening",
-      });
-    } else if (timeUntilStart <= 0) {
-      notifiedEvents.delete(notificationKey);
-    }
-  });
+  const events = JSON.stringify(upcoming);
+  if (events !== state.events) {
+    state.events = events;
+    void notificationCommands.setUpcomingEvents(upcoming);
+  }
 }
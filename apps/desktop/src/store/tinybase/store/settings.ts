@@ -23,10 +23,34 @@ export const SETTINGS_MAPPING = {
       type: "boolean",
       path: ["general", "save_recordings"],
     },
+    auto_stop: {
+      type: "boolean",
+      path: ["general", "auto_stop"],
+    },
+    auto_stop_silence_minutes: {
+      type: "number",
+      path: ["general", "auto_stop_silence_minutes"],
+    },
+    auto_stop_ended_silence_secs: {
+      type: "number",
+      path: ["general", "auto_stop_ended_silence_secs"],
+    },
+    auto_stop_event_overrun_minutes: {
+      type: "number",
+      path: ["general", "auto_stop_event_overrun_minutes"],
+    },
+    auto_stop_grace_period_secs: {
+      type: "number",
+      path: ["general", "auto_stop_grace_period_secs"],
+    },
     notification_event: {
       type: "boolean",
       path: ["notification", "event"],
     },
+    notification_event_lead_minutes: {
+      type: "string",
+      path: ["notification", "event_lead_minutes"],
+    },
     notification_detect: {
       type: "boolean",
       path: ["notification", "detect"],
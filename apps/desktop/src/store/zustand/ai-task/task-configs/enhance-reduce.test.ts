@@ -0,0 +1,64 @@
+import { describe, expect, it } from "vitest";
+
+import { estimateTokens, groupNotes, reduceToFit } from "./enhance-reduce";
+
+// 100 tokens each.
+const note = (label: string) => label.padEnd(400, ".");
+
+describe("groupNotes", () => {
+  it("packs notes up to the budget", () => {
+    const notes = ["a", "b", "c", "d", "e"].map(note);
+
+    expect(groupNotes(notes, 250).map((g) => g.length)).toEqual([2, 2, 1]);
+  });
+
+  it("takes two notes per group even when each is over budget", () => {
+    const notes = ["a", "b", "c"].map(note);
+
+    expect(groupNotes(notes, 50).map((g) => g.length)).toEqual([2, 1]);
+  });
+});
+
+describe("reduceToFit", () => {
+  const render = async (notes: string[]) => `# Notes\n${notes.join("\n")}`;
+
+  it("returns the prompt as is when it fits", async () => {
+    const notes = ["a", "b"].map(note);
+    let merges = 0;
+
+    const prompt = await reduceToFit({
+      notes,
+      maxTokens: 1_000,
+      render,
+      merge: async () => {
+        merges += 1;
+        return "";
+      },
+    });
+
+    expect(prompt).toBe(await render(notes));
+    expect(merges).toBe(0);
+  });
+
+  it("merges in passes until the prompt fits", async () => {
+    const notes = ["a", "b", "c", "d", "e", "f", "g", "h"].map(note);
+    const passes: number[] = [];
+    let merges = 0;
+
+    const prompt = await reduceToFit({
+      notes,
+      maxTokens: 250,
+      render,
+      merge: async () => note(`m${(merges += 1)}`),
+      onMerge: (index, total) => {
+        if (index === 0) {
+          passes.push(total);
+        }
+      },
+    });
+
+    expect(passes).toEqual([4, 2]);
+    expect(prompt).toBe(await render([note("m5"), note("m6")]));
+    expect(estimateTokens(prompt)).toBeLessThanOrEqual(250);
+  });
+});
@@ -0,0 +1,68 @@
+// Same rough estimate the transcript chunker uses (template-app's
+// `estimate_tokens`), so reduce prompts are budgeted like the chunks were.
+const CHARS_PER_TOKEN = 4;
+
+export function estimateTokens(text: string): number {
+  return Math.ceil(text.length / CHARS_PER_TOKEN);
+}
+
+// Packs consecutive notes into groups of at most `budget` tokens. Every group
+// but a trailing one takes at least two notes, so each pass shrinks the list
+// even when single notes are close to the budget.
+export function groupNotes(notes: string[], budget: number): string[][] {
+  const groups: string[][] = [];
+  let group: string[] = [];
+  let tokens = 0;
+
+  for (const note of notes) {
+    const noteTokens = estimateTokens(note);
+    if (group.length >= 2 && tokens + noteTokens > budget) {
+      groups.push(group);
+      group = [];
+      tokens = 0;
+    }
+    group.push(note);
+    tokens += noteTokens;
+  }
+  if (group.length > 0) {
+    groups.push(group);
+  }
+
+  return groups;
+}
+
+// Renders the reduce prompt for `notes`, merging them in groups first for as
+// many passes as it takes to bring the prompt within `maxTokens`.
+export async function reduceToFit(params: {
+  notes: string[];
+  maxTokens: number;
+  render: (notes: string[]) => Promise<string>;
+  merge: (prompt: string) => Promise<string>;
+  onMerge?: (index: number, total: number) => void;
+}): Promise<string> {
+  const { maxTokens, render, merge, onMerge } = params;
+
+  let notes = params.notes;
+  while (true) {
+    const prompt = await render(notes);
+    if (notes.length <= 1 || estimateTokens(prompt) <= maxTokens) {
+      return prompt;
+    }
+
+    const overhead = estimateTokens(prompt) - sumTokens(notes);
+    const groups = groupNotes(notes, Math.max(maxTokens - overhead, 0));
+
+    const merged: string[] = [];
+    for (const [index, group] of groups.entries()) {
+      onMerge?.(index, groups.length);
+      merged.push(
+        group.length > 1 ? await merge(await render(group)) : group[0],
+      );
+    }
+    notes = merged;
+  }
+}
+
+function sumTokens(notes: string[]): number {
+  return notes.reduce((sum, note) => sum + estimateTokens(note), 0);
+}
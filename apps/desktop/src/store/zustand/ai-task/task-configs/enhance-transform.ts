@@ -129,7 +129,7 @@ function getLanguage(settingsStore: SettingsStore): string | null {
   return typeof value === "string" && value.length > 0 ? value : null;
 }
 
-function getSessionContext(sessionId: string, store: MainStore) {
+export function getSessionContext(sessionId: string, store: MainStore) {
   const transcriptsMeta = collectTranscripts(sessionId, store);
   return {
     rawMd: getStringCell(store, "sessions", sessionId, "raw_md"),
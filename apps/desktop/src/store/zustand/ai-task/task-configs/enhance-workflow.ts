@@ -8,8 +8,10 @@ import {
 import { z } from "zod";
 
 import {
+  type ChunkOptions,
   commands as templateCommands,
   type TemplateSection,
+  type Transcript,
 } from "@hypr/plugin-template";
 import { templateSectionSchema } from "@hypr/store";
 
@@ -18,6 +20,7 @@ import type { Store } from "../../../tinybase/store/main";
 import { getCustomPrompt } from "../../../tinybase/store/prompts";
 import { normalizeBulletPoints } from "../shared/transform_impl";
 import { withEarlyValidationRetry } from "../shared/validate";
+import { estimateTokens, reduceToFit } from "./enhance-reduce";
 import { createEnhanceValidator } from "./enhance-validator";
 
 export const enhanceWorkflow: Pick<
@@ -53,7 +56,19 @@ async function* executeWorkflow(params: {
   };
 
   const system = await getSystemPrompt(argsWithTemplate);
-  const prompt = await getUserPrompt(argsWithTemplate, store);
+  const chunks = getCustomPrompt(store, "enhance")
+    ? null
+    : await chunkTranscriptsIfNeeded(model, argsWithTemplate.transcripts);
+  const prompt = chunks
+    ? await getReducePrompt({
+        model,
+        args: argsWithTemplate,
+        system,
+        chunks,
+        onProgress,
+        signal,
+      })
+    : await getUserPrompt(argsWithTemplate, store);
 
   yield* generateSummary({
     model,
@@ -117,6 +132,129 @@ async function getUserPrompt(
   return result.data;
 }
 
+const CLOUD_PROVIDERS = [
+  "gateway",
+  "openrouter",
+  "anthropic",
+  "openai",
+  "google",
+];
+
+// Local models typically run with an 8k context; cloud models take far more,
+// but splitting very long sessions still keeps cost and latency predictable.
+// Plain model ids go through the AI SDK gateway, which is a cloud provider.
+function getChunkOptions(model: LanguageModel): ChunkOptions {
+  const provider = typeof model === "string" ? "gateway" : model.provider;
+  const isLocal = !CLOUD_PROVIDERS.some((p) => provider.startsWith(p));
+
+  return isLocal
+    ? { maxTokens: 6_000, overlapTokens: 300 }
+    : { maxTokens: 48_000, overlapTokens: 1_000 };
+}
+
+async function chunkTranscriptsIfNeeded(
+  model: LanguageModel,
+  transcripts: Transcript[],
+): Promise<Transcript[] | null> {
+  const result = await templateCommands.chunkTranscripts(
+    transcripts,
+    getChunkOptions(model),
+  );
+
+  if (result.status === "error") {
+    throw new Error(result.error);
+  }
+
+  return result.data.length > 1 ? result.data : null;
+}
+
+async function getReducePrompt(params: {
+  model: LanguageModel;
+  args: TaskArgsMapTransformed["enhance"];
+  system: string;
+  chunks: Transcript[];
+  onProgress: (step: any) => void;
+  signal: AbortSignal;
+}) {
+  const { model, args, system, chunks, onProgress, signal } = params;
+  const { session, participants, template } = args;
+
+  const partialNotes: string[] = [];
+  for (const [index, transcript] of chunks.entries()) {
+    onProgress({ type: "summarizing", index, total: chunks.length });
+
+    const chunkPrompt = await templateCommands.render({
+      enhanceChunkUser: {
+        session,
+        participants,
+        template,
+        transcript,
+        chunkIndex: index + 1,
+        chunkCount: chunks.length,
+      },
+    });
+    if (chunkPrompt.status === "error") {
+      throw new Error(chunkPrompt.error);
+    }
+
+    const result = await generateText({
+      model,
+      system,
+      prompt: chunkPrompt.data,
+      abortSignal: signal,
+    });
+    partialNotes.push(result.text);
+  }
+
+  // The merged notes are prompted like the chunks were, so they get the same
+  // budget, less the system prompt that goes along with them.
+  return reduceToFit({
+    notes: partialNotes,
+    maxTokens: getChunkOptions(model).maxTokens - estimateTokens(system),
+    render: (notes) => renderReducePrompt(args, notes),
+    merge: async (prompt) => {
+      const result = await generateText({
+        model,
+        system,
+        prompt,
+        abortSignal: signal,
+      });
+      return result.text;
+    },
+    onMerge: (index, total) => {
+      onProgress({ type: "merging", index, total });
+    },
+  });
+}
+
+async function renderReducePrompt(
+  args: TaskArgsMapTransformed["enhance"],
+  partialNotes: string[],
+) {
+  const { session, participants, template } = args;
+
+  const actionItems = await templateCommands.collectActionItems(partialNotes);
+  if (actionItems.status === "error") {
+    throw new Error(actionItems.error);
+  }
+
+  const result = await templateCommands.render({
+    enhanceReduceUser: {
+      session,
+      participants,
+      template,
+      partialNotes,
+      actionItems: actionItems.data,
+    },
+  });
+
+  if (result.status === "error") {
+    throw new Error(result.error);
+  }
+
+  return result.data;
+}
+
 async function generateTemplateIfNeeded(params: {
   model: LanguageModel;
   args: TaskArgsMapTransformed["enhance"];
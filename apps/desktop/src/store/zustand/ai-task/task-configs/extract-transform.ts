@@ -0,0 +1,46 @@
+import type { TaskArgsMap, TaskArgsMapTransformed, TaskConfig } from ".";
+import type { Store as MainStore } from "../../../tinybase/store/main";
+import type { Store as SettingsStore } from "../../../tinybase/store/settings";
+import { getSessionContext } from "./enhance-transform";
+
+export const extractTransform: Pick<TaskConfig<"extract">, "transformArgs"> = {
+  transformArgs,
+};
+
+async function transformArgs(
+  args: TaskArgsMap["extract"],
+  store: MainStore,
+  settingsStore: SettingsStore,
+): Promise<TaskArgsMapTransformed["extract"]> {
+  const { sessionId } = args;
+  const sessionContext = getSessionContext(sessionId, store);
+
+  return {
+    sessionId,
+    language: getLanguage(settingsStore),
+    session: sessionContext.session,
+    participants: sessionContext.participants,
+    segments: sessionContext.segments.map((s) => ({
+      speaker: s.speaker_label,
+      text: s.text,
+      startMs: s.start_ms,
+    })),
+    eventDate: getEventDate(sessionId, sessionContext.session.startedAt, store),
+  };
+}
+
+function getEventDate(
+  sessionId: string,
+  startedAt: string | null,
+  store: MainStore,
+): string | null {
+  const createdAt = store.getCell("sessions", sessionId, "created_at");
+  const value =
+    startedAt ?? (typeof createdAt === "string" ? createdAt : undefined);
+  return value ? value.slice(0, 10) : null;
+}
+
+function getLanguage(settingsStore: SettingsStore): string | null {
+  const value = settingsStore.getValue("ai_language");
+  return typeof value === "string" && value.length > 0 ? value : null;
+}
@@ -0,0 +1,126 @@
+import { sep } from "@tauri-apps/api/path";
+import {
+  generateId,
+  generateText,
+  jsonSchema,
+  type LanguageModel,
+  Output,
+} from "ai";
+
+import {
+  commands as fsSyncCommands,
+  type JsonValue,
+} from "@hypr/plugin-fs-sync";
+import {
+  commands as templateCommands,
+  type Extraction,
+} from "@hypr/plugin-template";
+
+import type { TaskArgsMapTransformed, TaskConfig } from ".";
+import { SESSION_EXTRACTION_FILE } from "../../../tinybase/persister/shared/paths";
+import type { Store } from "../../../tinybase/store/main";
+
+export const extractWorkflow: Pick<
+  TaskConfig<"extract">,
+  "executeWorkflow" | "transforms"
+> = {
+  executeWorkflow,
+  transforms: [],
+};
+
+async function* executeWorkflow(params: {
+  model: LanguageModel;
+  args: TaskArgsMapTransformed["extract"];
+  onProgress: (step: any) => void;
+  signal: AbortSignal;
+  store: Store;
+}) {
+  const { model, args, onProgress, signal } = params;
+  const { sessionId, language, ...ctx } = args;
+
+  const system = await render({ extractSystem: { language } });
+  const prompt = await render({ extractUser: ctx });
+
+  onProgress({ type: "generating" });
+
+  const output = await generateOutput({ model, system, prompt, signal });
+
+  const result = await templateCommands.resolveExtraction(output, ctx);
+  if (result.status === "error") {
+    throw new Error(result.error);
+  }
+
+  await saveExtraction(sessionId, result.data);
+
+  yield {
+    type: "text-delta" as const,
+    id: generateId(),
+    text: JSON.stringify(result.data),
+  };
+}
+
+async function render(
+  tpl: Parameters<typeof templateCommands.render>[0],
+): Promise<string> {
+  const result = await templateCommands.render(tpl);
+  if (result.status === "error") {
+    throw new Error(result.error);
+  }
+  return result.data;
+}
+
+async function generateOutput(params: {
+  model: LanguageModel;
+  system: string;
+  prompt: string;
+  signal: AbortSignal;
+}): Promise<string> {
+  const { model, system, prompt, signal } = params;
+
+  const schema = await templateCommands.extractionSchema();
+  if (schema.status === "error") {
+    throw new Error(schema.error);
+  }
+
+  try {
+    const result = await generateText({
+      model,
+      temperature: 0,
+      system,
+      prompt,
+      output: Output.object({ schema: jsonSchema(schema.data as any) }),
+      abortSignal: signal,
+    });
+    return result.text;
+  } catch (error) {
+    if (signal.aborted) {
+      throw error;
+    }
+
+    // Not every provider supports structured output; the schema is also in
+    // the system prompt and the result is validated when resolving.
+    const result = await generateText({
+      model,
+      temperature: 0,
+      system,
+      prompt,
+      abortSignal: signal,
+    });
+    return result.text;
+  }
+}
+
+async function saveExtraction(sessionId: string, extraction: Extraction) {
+  const dir = await fsSyncCommands.sessionDir(sessionId);
+  if (dir.status === "error") {
+    throw new Error(dir.error);
+  }
+
+  const path = [dir.data, SESSION_EXTRACTION_FILE].join(sep());
+  const result = await fsSyncCommands.writeJsonBatch([
+    [extraction as unknown as JsonValue, path],
+  ]);
+  if (result.status === "error") {
+    throw new Error(result.error);
+  }
+}
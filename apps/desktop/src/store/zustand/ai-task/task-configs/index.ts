@@ -3,6 +3,8 @@ import type { LanguageModel, TextStreamPart } from "ai";
 import type {
   EnhanceSystem,
   EnhanceUser,
+  ExtractSystem,
+  ExtractUser,
   TitleSystem,
   TitleUser,
 } from "@hypr/plugin-template";
@@ -13,18 +15,22 @@ import { StreamTransform } from "../shared/transform_infra";
 import type { TaskStepInfo } from "../tasks";
 import { enhanceTransform } from "./enhance-transform";
 import { enhanceWorkflow } from "./enhance-workflow";
+import { extractTransform } from "./extract-transform";
+import { extractWorkflow } from "./extract-workflow";
 import { titleTransform } from "./title-transform";
 import { titleWorkflow } from "./title-workflow";
 
-export type TaskType = "enhance" | "title";
+export type TaskType = "enhance" | "extract" | "title";
 
 export interface TaskArgsMap {
   enhance: { sessionId: string; enhancedNoteId: string; templateId?: string };
+  extract: { sessionId: string };
   title: { sessionId: string };
 }
 
 export interface TaskArgsMapTransformed {
   enhance: EnhanceSystem & EnhanceUser;
+  extract: ExtractSystem & ExtractUser & { sessionId: string };
   title: TitleSystem & TitleUser;
 }
 
@@ -62,6 +68,10 @@ export const TASK_CONFIGS: TaskConfigMap = {
     ...enhanceWorkflow,
     ...enhanceTransform,
   },
+  extract: {
+    ...extractWorkflow,
+    ...extractTransform,
+  },
   title: {
     ...titleWorkflow,
     ...titleTransform,
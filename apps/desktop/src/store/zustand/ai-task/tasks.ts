@@ -34,6 +34,8 @@ export type TasksActions = {
 export type TaskStepInfo<T extends TaskType = TaskType> = T extends "enhance"
   ?
       | { type: "analyzing" }
+      | { type: "summarizing"; index: number; total: number }
+      | { type: "merging"; index: number; total: number }
       | { type: "generating" }
       | { type: "retrying"; attempt: number; reason: string }
   : T extends "title"
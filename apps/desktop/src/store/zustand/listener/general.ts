@@ -7,6 +7,7 @@ import { commands as detectCommands } from "@hypr/plugin-detect";
 import { commands as hooksCommands } from "@hypr/plugin-hooks";
 import { commands as iconCommands } from "@hypr/plugin-icon";
 import {
+  type AutoStopReason,
   type DegradedError,
   commands as listenerCommands,
   events as listenerEvents,
@@ -22,6 +23,7 @@ import {
   commands as listener2Commands,
   events as listener2Events,
 } from "@hypr/plugin-listener2";
+import { commands as notificationCommands } from "@hypr/plugin-notification";
 import { commands as settingsCommands } from "@hypr/plugin-settings";
 
 import { fromResult } from "../../../effect";
@@ -53,6 +55,8 @@ export type GeneralState = {
     lastError: string | null;
     device: string | null;
     degraded: DegradedError | null;
+    /** Bundle IDs of the apps using the mic when the session started. */
+    meetingApps: string[];
   };
 };
 
@@ -63,6 +67,7 @@ export type GeneralActions = {
   ) => void;
   stop: () => void;
   setMuted: (value: boolean) => void;
+  setMeetingApps: (appIds: string[]) => void;
   runBatch: (
     params: BatchParams,
     options?: { handlePersist?: HandlePersistCallback; sessionId?: string },
@@ -82,6 +87,7 @@ const initialState: GeneralState = {
     lastError: null,
     device: null,
     degraded: null,
+    meetingApps: [],
   },
 };
 
@@ -116,6 +122,40 @@ const listenToAllSessionEvents = (
     catch: (error) => error,
   });
 
+const AUTO_STOP_MESSAGES: Record<AutoStopReason, string> = {
+  silence: "No one has spoken for a while.",
+  meeting_app_stopped: "The meeting app is no longer using the microphone.",
+  event_ended: "The scheduled meeting has ended.",
+};
+
+const showAutoStopPrompt = (
+  sessionId: string,
+  reason: AutoStopReason,
+  stopAt: string,
+) => {
+  const secs = Math.max(
+    1,
+    Math.round((new Date(stopAt).getTime() - Date.now()) / 1000),
+  );
+
+  void notificationCommands.showNotification({
+    key: `auto-stop:${sessionId}`,
+    title: "Still recording — stop?",
+    message: `${AUTO_STOP_MESSAGES[reason]} Recording stops in ${secs} seconds.`,
+    timeout: { secs, nanos: 0 },
+    source: { type: "auto_stop", session_id: sessionId },
+    start_time: null,
+    participants: null,
+    event_details: null,
+    action_label: "Keep recording",
+    snooze_label: null,
+  });
+};
+
+const dismissAutoStopPrompt = (sessionId: string) => {
+  void notificationCommands.dismissNotification(`auto-stop:${sessionId}`);
+};
+
 const startSessionEffect = (params: SessionParams) =>
   fromResult(listenerCommands.startSession(params));
 const stopSessionEffect = () => fromResult(listenerCommands.stopSession());
@@ -215,6 +255,8 @@ export const createGeneralSlice = <
           }),
         );
       } else if (payload.type === "inactive") {
+        dismissAutoStopPrompt(targetSessionId);
+
         const currentState = get();
         if (currentState.live.eventUnlisteners) {
           currentState.live.eventUnlisteners.forEach((fn) => fn());
@@ -233,6 +275,7 @@ export const createGeneralSlice = <
             draft.live.device = null;
             draft.live.degraded = null;
             draft.live.muted = initialState.live.muted;
+            draft.live.meetingApps = [];
           }),
         );
 
@@ -271,6 +314,13 @@ export const createGeneralSlice = <
             draft.live.loadingPhase = "connected";
           }),
         );
+      } else if (payload.type === "auto_stop_pending") {
+        showAutoStopPrompt(targetSessionId, payload.reason, payload.stop_at);
+      } else if (
+        payload.type === "auto_stop_cancelled" ||
+        payload.type === "auto_stopped"
+      ) {
+        dismissAutoStopPrompt(targetSessionId);
       }
     };
 
@@ -357,6 +407,12 @@ export const createGeneralSlice = <
       const sessionPath = buildSessionPath(dataDirPath, targetSessionId);
       const app_meeting = micUsingApps?.[0] ?? null;
 
+      set((state) =>
+        mutate(state, (draft) => {
+          draft.live.meetingApps = micUsingApps ?? [];
+        }),
+      );
+
       yield* Effect.tryPromise({
         try: () =>
           hooksCommands.runEventHooks({
@@ -409,6 +465,7 @@ export const createGeneralSlice = <
               draft.live.lastError = null;
               draft.live.device = null;
               draft.live.degraded = null;
+              draft.live.meetingApps = [];
             }),
           );
         },
@@ -470,6 +527,13 @@ export const createGeneralSlice = <
       }),
     );
   },
+  setMeetingApps: (appIds) => {
+    set((state) =>
+      mutate(state, (draft) => {
+        draft.live.meetingApps = appIds;
+      }),
+    );
+  },
   runBatch: async (params, options) => {
     const sessionId = options?.sessionId;
 
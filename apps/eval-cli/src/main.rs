@@ -1,3 +1,4 @@
+use std::path::Path;
 use std::process::ExitCode;
 use std::sync::Arc;
 
@@ -5,12 +6,15 @@ use clap::{Parser, Subcommand};
 use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
 
 mod report;
+mod stt;
 mod submissions;
 
 use hypr_eval::{
-    DEFAULT_MODELS, EvalResult, Executor, ExecutorProgress, OpenRouterClient, parse_config,
+    DEFAULT_MODELS, EvalResult, Executor, ExecutorProgress, OpenRouterClient, TranscriptCache,
+    discover_fixtures, load_grader_config, parse_config,
 };
-use report::{render_json, render_results};
+use report::{compare_baseline, render_json, render_results, render_stt_json, render_stt_results};
+use stt::{DEFAULT_FIXTURES_DIR, SttTarget, run_stt};
 use submissions::{all_cases, filter_cases};
 
 #[derive(Parser)]
@@ -38,6 +42,37 @@ enum Commands {
 
         #[arg(long)]
         cache_dir: Option<String>,
+
+        /// JSON file of declarative rubrics to add to the selected cases.
+        #[arg(long)]
+        graders: Option<String>,
+
+        /// Report from a previous `--output json` run. Exits non-zero if any
+        /// rubric's pass rate dropped significantly.
+        #[arg(long)]
+        baseline: Option<String>,
+    },
+    /// Compare STT providers on the audio fixtures in `crates/data`.
+    Stt {
+        /// `name[:model][=api_base]`, e.g. `deepgram`, `soniox`,
+        /// `cactus=http://127.0.0.1:50060/v1` or `whisper=http://127.0.0.1:50060/v1`.
+        #[arg(short, long, value_delimiter = ',', required = true)]
+        providers: Vec<String>,
+
+        #[arg(short, long, value_delimiter = ',')]
+        fixtures: Option<Vec<String>>,
+
+        #[arg(long, default_value = DEFAULT_FIXTURES_DIR)]
+        fixtures_dir: String,
+
+        #[arg(short, long, default_value = "table")]
+        output: String,
+
+        #[arg(long)]
+        no_cache: bool,
+
+        #[arg(long)]
+        cache_dir: Option<String>,
     },
     List,
     Completion {
@@ -65,8 +100,32 @@ fn main() -> ExitCode {
             models,
             no_cache,
             cache_dir,
+            graders,
+            baseline,
+        } => {
+            if let Err(e) = run_evals(
+                tasks, output, models, no_cache, cache_dir, graders, baseline,
+            ) {
+                eprintln!("Error: {}", e);
+                return ExitCode::FAILURE;
+            }
+        }
+        Commands::Stt {
+            providers,
+            fixtures,
+            fixtures_dir,
+            output,
+            no_cache,
+            cache_dir,
         } => {
-            if let Err(e) = run_evals(tasks, output, models, no_cache, cache_dir) {
+            if let Err(e) = run_stt_evals(
+                providers,
+                fixtures,
+                fixtures_dir,
+                output,
+                no_cache,
+                cache_dir,
+            ) {
                 eprintln!("Error: {}", e);
                 return ExitCode::FAILURE;
             }
@@ -88,6 +147,8 @@ fn run_evals(
     model_override: Option<Vec<String>>,
     no_cache: bool,
     cache_dir: Option<String>,
+    graders: Option<String>,
+    baseline: Option<String>,
 ) -> Result<(), String> {
     let cfg = parse_config();
 
@@ -96,7 +157,13 @@ fn run_evals(
     }
 
     let all = all_cases();
-    let selected_cases = filter_cases(&all, task_filter.as_deref());
+    let mut selected_cases = filter_cases(&all, task_filter.as_deref());
+
+    if let Some(path) = graders {
+        load_grader_config(Path::new(&path))
+            .map_err(|e| e.to_string())?
+            .apply(&mut selected_cases);
+    }
 
     if selected_cases.is_empty() {
         return Err("no cases matched the filter".to_string());
@@ -116,7 +183,8 @@ fn run_evals(
     if output_format == "json" {
         let mut results = executor.execute(&selected_cases, &models);
         resolve_usage(&client, &mut results);
-        return render_json(&results).map_err(|e| e.to_string());
+        render_json(&results)?;
+        return compare_to_baseline(&results, baseline.as_deref());
     }
 
     let gen_total = executor.total_generations(&selected_cases, &models);
@@ -151,7 +219,63 @@ fn run_evals(
 
     resolve_usage(&client, &mut results);
 
-    render_results(&results).map_err(|e| e.to_string())
+    render_results(&results)?;
+    compare_to_baseline(&results, baseline.as_deref())
+}
+
+fn compare_to_baseline(results: &[EvalResult], baseline: Option<&str>) -> Result<(), String> {
+    match baseline {
+        Some(path) => compare_baseline(results, path),
+        None => Ok(()),
+    }
+}
+
+fn run_stt_evals(
+    providers: Vec<String>,
+    fixture_filter: Option<Vec<String>>,
+    fixtures_dir: String,
+    output_format: String,
+    no_cache: bool,
+    cache_dir: Option<String>,
+) -> Result<(), String> {
+    let targets = providers
+        .iter()
+        .map(|p| p.parse::<SttTarget>())
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut fixtures =
+        discover_fixtures(std::path::Path::new(&fixtures_dir)).map_err(|e| e.to_string())?;
+    if let Some(filter) = &fixture_filter {
+        fixtures.retain(|f| filter.contains(&f.name));
+    }
+    if fixtures.is_empty() {
+        return Err("no fixtures matched the filter".to_string());
+    }
+
+    let cache = if no_cache {
+        TranscriptCache::disabled()
+    } else {
+        TranscriptCache::new(cache_dir)
+    };
+
+    if output_format == "json" {
+        let results = run_stt(&targets, &fixtures, &cache, || {})?;
+        return render_stt_json(&results);
+    }
+
+    let bar = ProgressBar::new((targets.len() * fixtures.len()) as u64);
+    bar.set_style(
+        ProgressStyle::default_bar()
+            .template("{prefix:>12} [{bar:30.white}] {pos}/{len}")
+            .unwrap()
+            .progress_chars("=> "),
+    );
+    bar.set_prefix("Transcripts");
+
+    let results = run_stt(&targets, &fixtures, &cache, || bar.inc(1))?;
+    bar.finish();
+
+    render_stt_results(&results)
 }
 
 fn resolve_usage(client: &OpenRouterClient, results: &mut [EvalResult]) {
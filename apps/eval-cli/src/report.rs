@@ -1,6 +1,10 @@
+use std::collections::BTreeMap;
+
 use comfy_table::{Cell, Color, ContentArrangement, Table, presets::UTF8_FULL_CONDENSED};
 
-use hypr_eval::EvalResult;
+use hypr_eval::{
+    ErrorRate, EvalResult, PassStats, SttResult, calc_pass_stats, is_significant_regression,
+};
 
 pub fn render_json(results: &[EvalResult]) -> std::result::Result<(), String> {
     let json = serde_json::to_string_pretty(
@@ -21,6 +25,7 @@ pub fn render_json(results: &[EvalResult]) -> std::result::Result<(), String> {
                             "grader_model": s.grader_model,
                             "pass_rate": s.pass_rate,
                             "samples": s.samples,
+                            "pass_count": s.pass_count,
                         })
                     }).collect::<Vec<_>>(),
                     "error": r.error,
@@ -133,6 +138,331 @@ pub fn render_results(results: &[EvalResult]) -> std::result::Result<(), String>
     Ok(())
 }
 
+pub fn render_stt_json(results: &[SttResult]) -> std::result::Result<(), String> {
+    let json = serde_json::to_string_pretty(
+        &results
+            .iter()
+            .map(|r| {
+                serde_json::json!({
+                    "fixture": r.fixture,
+                    "language": r.language,
+                    "provider": r.provider,
+                    "model": r.model,
+                    "wer": r.score.as_ref().map(|s| error_rate_json(&s.wer)),
+                    "cer": r.score.as_ref().map(|s| error_rate_json(&s.cer)),
+                    "speaker_wer": r.score.as_ref().and_then(|s| s.speaker_wer.as_ref()).map(error_rate_json),
+                    "timing": r.score.as_ref().map(|s| serde_json::json!({
+                        "matched_words": s.timing.matched_words,
+                        "mean_ms": s.timing.mean_ms,
+                        "mean_abs_ms": s.timing.mean_abs_ms,
+                        "max_abs_ms": s.timing.max_abs_ms,
+                    })),
+                    "error": r.error,
+                    "elapsed_ms": r.elapsed_ms,
+                    "cached": r.cached,
+                })
+            })
+            .collect::<Vec<_>>(),
+    )
+    .map_err(|e| format!("Failed to encode JSON: {}", e))?;
+
+    println!("{}", json);
+
+    if results.iter().any(|r| r.error.is_some()) {
+        return Err("evaluation failed".to_string());
+    }
+
+    Ok(())
+}
+
+fn error_rate_json(rate: &ErrorRate) -> serde_json::Value {
+    serde_json::json!({
+        "rate": rate.rate(),
+        "substitutions": rate.substitutions,
+        "deletions": rate.deletions,
+        "insertions": rate.insertions,
+        "reference_len": rate.reference_len,
+    })
+}
+
+/// Prints one row per fixture, then one per provider with error counts pooled
+/// across fixtures.
+pub fn render_stt_results(results: &[SttResult]) -> std::result::Result<(), String> {
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL_CONDENSED)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(vec![
+            "Provider", "Model", "Fixture", "WER", "CER", "cpWER", "Drift", "Time",
+        ]);
+
+    let mut error_details: Vec<String> = Vec::new();
+
+    for r in results {
+        let mut row = vec![
+            Cell::new(&r.provider),
+            Cell::new(&r.model),
+            Cell::new(format!("{} ({})", r.fixture, r.language)),
+        ];
+
+        match (&r.score, &r.error) {
+            (Some(score), _) => {
+                row.push(Cell::new(format_rate(&score.wer)));
+                row.push(Cell::new(format_rate(&score.cer)));
+                row.push(Cell::new(
+                    score
+                        .speaker_wer
+                        .as_ref()
+                        .map_or("-".to_string(), format_rate),
+                ));
+                row.push(Cell::new(format!("{:.0}ms", score.timing.mean_abs_ms)));
+                row.push(Cell::new(if r.cached {
+                    "cached".to_string()
+                } else {
+                    format!("{:.1}s", r.elapsed_ms as f64 / 1000.0)
+                }));
+            }
+            (None, err) => {
+                for _ in 0..4 {
+                    row.push(Cell::new("-"));
+                }
+                row.push(Cell::new("error").fg(Color::Red));
+                error_details.push(format!(
+                    "{} / {}: {}",
+                    r.provider,
+                    r.fixture,
+                    err.as_deref().unwrap_or("unknown error")
+                ));
+            }
+        }
+        table.add_row(row);
+    }
+
+    println!("{}", table);
+
+    let mut summary = Table::new();
+    summary
+        .load_preset(UTF8_FULL_CONDENSED)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(vec![
+            "Provider", "Model", "WER", "CER", "cpWER", "Drift", "Failed",
+        ]);
+
+    let mut providers: Vec<(&str, &str)> = Vec::new();
+    for r in results {
+        if !providers.contains(&(r.provider.as_str(), r.model.as_str())) {
+            providers.push((r.provider.as_str(), r.model.as_str()));
+        }
+    }
+
+    for (provider, model) in providers {
+        let runs: Vec<&SttResult> = results
+            .iter()
+            .filter(|r| r.provider == provider && r.model == model)
+            .collect();
+        let scores: Vec<_> = runs.iter().filter_map(|r| r.score.as_ref()).collect();
+
+        let mut wer = ErrorRate::default();
+        let mut cer = ErrorRate::default();
+        let mut speaker_wer: Option<ErrorRate> = None;
+        let mut drift_total = 0.0;
+        let mut drift_words = 0;
+        for score in &scores {
+            wer += score.wer;
+            cer += score.cer;
+            if let Some(rate) = score.speaker_wer {
+                *speaker_wer.get_or_insert_default() += rate;
+            }
+            drift_total += score.timing.mean_abs_ms * score.timing.matched_words as f64;
+            drift_words += score.timing.matched_words;
+        }
+
+        let failed = runs.len() - scores.len();
+        summary.add_row(vec![
+            Cell::new(provider),
+            Cell::new(model),
+            Cell::new(format_rate(&wer)),
+            Cell::new(format_rate(&cer)),
+            Cell::new(speaker_wer.as_ref().map_or("-".to_string(), format_rate)),
+            Cell::new(if drift_words == 0 {
+                "-".to_string()
+            } else {
+                format!("{:.0}ms", drift_total / drift_words as f64)
+            }),
+            if failed == 0 {
+                Cell::new("0")
+            } else {
+                Cell::new(failed).fg(Color::Red)
+            },
+        ]);
+    }
+
+    println!("{}", summary);
+
+    if !error_details.is_empty() {
+        eprintln!();
+        eprintln!("\x1b[31mErrors:\x1b[0m");
+        for detail in &error_details {
+            eprintln!("\x1b[31m  - {}\x1b[0m", detail);
+        }
+        return Err("evaluation failed".to_string());
+    }
+
+    Ok(())
+}
+
+fn format_rate(rate: &ErrorRate) -> String {
+    format!("{:.1}%", rate.rate() * 100.0)
+}
+
+/// Pass/total grading samples keyed by (case, model, rubric).
+type PassCounts = BTreeMap<(String, String, String), (i32, i32)>;
+
+/// Single-sample graders leave `samples` and `pass_count` unset, so `passed`
+/// is the only signal for them.
+fn sample_counts(passed: bool, samples: i64, pass_count: i64) -> (i32, i32) {
+    if samples <= 1 {
+        (passed as i32, 1)
+    } else {
+        (pass_count as i32, samples as i32)
+    }
+}
+
+fn tally_results(results: &[EvalResult]) -> PassCounts {
+    let mut counts = PassCounts::new();
+    for r in results {
+        for s in &r.scores {
+            let (pass, total) = sample_counts(s.passed, s.samples as i64, s.pass_count as i64);
+            let entry = counts
+                .entry((r.case_id.clone(), r.model.clone(), s.rubric_name.clone()))
+                .or_default();
+            entry.0 += pass;
+            entry.1 += total;
+        }
+    }
+    counts
+}
+
+/// Reads a report written by `evals run --output json`.
+fn tally_baseline(path: &str) -> std::result::Result<PassCounts, String> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| format!("failed to read baseline {}: {}", path, e))?;
+    let report: Vec<serde_json::Value> = serde_json::from_str(&content)
+        .map_err(|e| format!("failed to parse baseline {}: {}", path, e))?;
+
+    let mut counts = PassCounts::new();
+    for r in &report {
+        let case_id = r["case_id"].as_str().unwrap_or_default();
+        let model = r["model"].as_str().unwrap_or_default();
+        for s in r["scores"].as_array().into_iter().flatten() {
+            let (pass, total) = sample_counts(
+                s["passed"].as_bool().unwrap_or(false),
+                s["samples"].as_i64().unwrap_or(0),
+                s["pass_count"].as_i64().unwrap_or_else(|| {
+                    (s["pass_rate"].as_f64().unwrap_or(0.0) * s["samples"].as_f64().unwrap_or(0.0))
+                        .round() as i64
+                }),
+            );
+            let entry = counts
+                .entry((
+                    case_id.to_string(),
+                    model.to_string(),
+                    s["rubric_name"].as_str().unwrap_or_default().to_string(),
+                ))
+                .or_default();
+            entry.0 += pass;
+            entry.1 += total;
+        }
+    }
+    Ok(counts)
+}
+
+/// Sums pass/total counts over the cases both reports graded, per
+/// (model, rubric). A handful of samples per case can't show a regression on
+/// its own, so the gate compares the pooled rates.
+fn pool_shared_cases(
+    baseline: &PassCounts,
+    current: &PassCounts,
+) -> BTreeMap<(String, String), ((i32, i32), (i32, i32))> {
+    let mut pooled: BTreeMap<(String, String), ((i32, i32), (i32, i32))> = BTreeMap::new();
+    for (key, &(pass, total)) in current {
+        let Some(&(base_pass, base_total)) = baseline.get(key) else {
+            continue;
+        };
+        let (_, model, rubric) = key;
+        let (base, cur) = pooled.entry((model.clone(), rubric.clone())).or_default();
+        base.0 += base_pass;
+        base.1 += base_total;
+        cur.0 += pass;
+        cur.1 += total;
+    }
+    pooled
+}
+
+/// Compares pass rates against a baseline report and fails when any
+/// (model, rubric) pass rate dropped significantly (Newcombe interval for the
+/// difference excludes zero). Printed to stderr so JSON output on stdout
+/// stays parseable.
+pub fn compare_baseline(
+    results: &[EvalResult],
+    baseline_path: &str,
+) -> std::result::Result<(), String> {
+    let baseline = tally_baseline(baseline_path)?;
+    let current = tally_results(results);
+
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL_CONDENSED)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(vec!["Model", "Rubric", "Baseline", "Current", "Status"]);
+
+    let mut regressions = 0;
+    for ((model, rubric), ((base_pass, base_total), (pass, total))) in
+        pool_shared_cases(&baseline, &current)
+    {
+        let base_stats = calc_pass_stats(base_pass, base_total);
+        let stats = calc_pass_stats(pass, total);
+
+        let status = if is_significant_regression(&base_stats, &stats) {
+            regressions += 1;
+            Cell::new("regressed").fg(Color::Red)
+        } else if is_significant_regression(&stats, &base_stats) {
+            Cell::new("improved").fg(Color::Green)
+        } else {
+            Cell::new("ok")
+        };
+
+        table.add_row(vec![
+            Cell::new(model),
+            Cell::new(rubric),
+            Cell::new(format_pass_stats(&base_stats)),
+            Cell::new(format_pass_stats(&stats)),
+            status,
+        ]);
+    }
+
+    eprintln!("{}", table);
+
+    if regressions > 0 {
+        return Err(format!(
+            "{} rubric(s) regressed against {}",
+            regressions, baseline_path
+        ));
+    }
+
+    Ok(())
+}
+
+fn format_pass_stats(stats: &PassStats) -> String {
+    format!(
+        "{}/{} [{:.0}%, {:.0}%]",
+        stats.pass_count,
+        stats.samples,
+        stats.confidence_interval.lower * 100.0,
+        stats.confidence_interval.upper * 100.0
+    )
+}
+
 fn extract_rubric_names(results: &[EvalResult]) -> Vec<String> {
     for r in results {
         if r.error.is_none() && !r.scores.is_empty() {
@@ -0,0 +1,278 @@
+use std::path::Path;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use futures_util::StreamExt;
+use hypr_audio_utils::AudioFormatExt;
+use hypr_eval::{SttFixture, SttResult, SttWord, TranscriptCache, score_transcript};
+use owhisper_client::{
+    AssemblyAIAdapter, BatchClient, DashScopeAdapter, DeepgramAdapter, ElevenLabsAdapter,
+    FireworksAdapter, GladiaAdapter, ListenClient, MistralAdapter, OpenAIAdapter, Provider,
+    SonioxAdapter,
+};
+use owhisper_interface::stream::StreamResponse;
+use owhisper_interface::{ControlMessage, ListenParams, MixedMessage};
+
+pub const DEFAULT_FIXTURES_DIR: &str =
+    concat!(env!("CARGO_MANIFEST_DIR"), "/../../crates/data/src");
+
+/// How long to wait for the next streaming response before giving up.
+const STREAM_IDLE_TIMEOUT: Duration = Duration::from_secs(30);
+
+enum Backend {
+    Cloud(Provider),
+    /// Local cactus server. Accepts Deepgram-style batch requests.
+    Cactus,
+    /// Local whisper server. Streaming only, so the file is sent as
+    /// realtime audio.
+    Whisper,
+}
+
+/// A provider to evaluate, parsed from `name[:model][=api_base]`, e.g.
+/// `deepgram`, `deepgram:nova-2`, `cactus=http://127.0.0.1:50060/v1`.
+pub struct SttTarget {
+    pub label: String,
+    backend: Backend,
+    api_base: String,
+    api_key: String,
+    model: Option<String>,
+}
+
+impl FromStr for SttTarget {
+    type Err = String;
+
+    fn from_str(spec: &str) -> Result<Self, Self::Err> {
+        let (head, api_base) = match spec.split_once('=') {
+            Some((head, api_base)) => (head, Some(api_base.to_string())),
+            None => (spec, None),
+        };
+        let (name, model) = match head.split_once(':') {
+            Some((name, model)) => (name, Some(model.to_string())),
+            None => (head, None),
+        };
+
+        let (backend, api_base, api_key) = match name {
+            "cactus" | "whisper" => {
+                let api_base = api_base
+                    .ok_or_else(|| format!("{} needs a server url: {}=<url>", name, name))?;
+                let backend = if name == "cactus" {
+                    Backend::Cactus
+                } else {
+                    Backend::Whisper
+                };
+                (backend, api_base, String::new())
+            }
+            _ => {
+                let provider = Provider::from_str(name)
+                    .map_err(|_| format!("unknown STT provider: {}", name))?;
+                let api_key = std::env::var(provider.env_key_name())
+                    .map_err(|_| format!("{} is not set", provider.env_key_name()))?;
+                let api_base = api_base.unwrap_or_else(|| provider.default_api_base().to_string());
+                (Backend::Cloud(provider), api_base, api_key)
+            }
+        };
+
+        Ok(Self {
+            label: head.to_string(),
+            backend,
+            api_base,
+            api_key,
+            model,
+        })
+    }
+}
+
+impl SttTarget {
+    fn model_name(&self) -> String {
+        match (&self.model, &self.backend) {
+            (Some(model), _) => model.clone(),
+            (None, Backend::Cloud(provider)) => provider.default_batch_model().to_string(),
+            (None, _) => "default".to_string(),
+        }
+    }
+
+    fn listen_params(&self, language: &str) -> ListenParams {
+        ListenParams {
+            model: self.model.clone(),
+            languages: hypr_language::Language::from_str(language)
+                .ok()
+                .into_iter()
+                .collect(),
+            ..Default::default()
+        }
+    }
+}
+
+pub fn run_stt(
+    targets: &[SttTarget],
+    fixtures: &[SttFixture],
+    cache: &TranscriptCache,
+    on_result: impl Fn(),
+) -> Result<Vec<SttResult>, String> {
+    let runtime = tokio::runtime::Runtime::new().map_err(|e| e.to_string())?;
+
+    let mut results = Vec::with_capacity(targets.len() * fixtures.len());
+    for target in targets {
+        for fixture in fixtures {
+            results.push(runtime.block_on(evaluate(target, fixture, cache)));
+            on_result();
+        }
+    }
+    Ok(results)
+}
+
+async fn evaluate(target: &SttTarget, fixture: &SttFixture, cache: &TranscriptCache) -> SttResult {
+    let mut result = SttResult {
+        fixture: fixture.name.clone(),
+        language: fixture.language.clone(),
+        provider: target.label.clone(),
+        model: target.model_name(),
+        ..Default::default()
+    };
+
+    let audio = match std::fs::read(&fixture.audio_path) {
+        Ok(audio) => audio,
+        Err(e) => {
+            result.error = Some(format!("failed to read audio: {}", e));
+            return result;
+        }
+    };
+    let key = cache.key(
+        &format!("{}@{}", target.label, target.api_base),
+        &result.model,
+        &fixture.language,
+        &audio,
+    );
+
+    let started = Instant::now();
+    let words = match cache.get(&key) {
+        Some(words) => {
+            result.cached = true;
+            Ok(words)
+        }
+        None => transcribe(target, fixture)
+            .await
+            .inspect(|words| cache.set(&key, words)),
+    };
+    result.elapsed_ms = started.elapsed().as_millis() as u64;
+
+    match words {
+        Ok(words) => result.score = Some(score_transcript(&fixture.reference, &words)),
+        Err(e) => result.error = Some(e),
+    }
+    result
+}
+
+async fn transcribe(target: &SttTarget, fixture: &SttFixture) -> Result<Vec<SttWord>, String> {
+    let params = target.listen_params(&fixture.language);
+    let file_path = fixture.audio_path.as_path();
+
+    macro_rules! batch_transcribe {
+        ($adapter:ty) => {
+            BatchClient::<$adapter>::builder()
+                .api_base(&target.api_base)
+                .api_key(&target.api_key)
+                .params(params)
+                .build()
+                .transcribe_file(file_path)
+                .await
+        };
+    }
+
+    let response = match &target.backend {
+        Backend::Cloud(provider) => match provider {
+            Provider::Deepgram => batch_transcribe!(DeepgramAdapter),
+            Provider::AssemblyAI => batch_transcribe!(AssemblyAIAdapter),
+            Provider::Soniox => batch_transcribe!(SonioxAdapter),
+            Provider::Fireworks => batch_transcribe!(FireworksAdapter),
+            Provider::OpenAI => batch_transcribe!(OpenAIAdapter),
+            Provider::Gladia => batch_transcribe!(GladiaAdapter),
+            Provider::ElevenLabs => batch_transcribe!(ElevenLabsAdapter),
+            Provider::DashScope => batch_transcribe!(DashScopeAdapter),
+            Provider::Mistral => batch_transcribe!(MistralAdapter),
+        },
+        Backend::Cactus => batch_transcribe!(DeepgramAdapter),
+        Backend::Whisper => return transcribe_streaming(&target.api_base, params, file_path).await,
+    };
+
+    let response = response.map_err(|e| format!("{:?}", e))?;
+    let words = response
+        .results
+        .channels
+        .into_iter()
+        .next()
+        .and_then(|c| c.alternatives.into_iter().next())
+        .map(|a| a.words)
+        .unwrap_or_default();
+
+    Ok(words
+        .into_iter()
+        .map(|w| SttWord {
+            text: w.punctuated_word.unwrap_or(w.word),
+            start_ms: seconds_to_ms(w.start),
+            end_ms: seconds_to_ms(w.end),
+            speaker: w.speaker.map(|s| s.to_string()),
+        })
+        .collect())
+}
+
+async fn transcribe_streaming(
+    api_base: &str,
+    params: ListenParams,
+    file_path: &Path,
+) -> Result<Vec<SttWord>, String> {
+    let file = std::fs::File::open(file_path).map_err(|e| e.to_string())?;
+    let audio = rodio::Decoder::try_from(file)
+        .map_err(|e| e.to_string())?
+        .to_i16_le_chunks(params.sample_rate, 512);
+    let input = audio
+        .map(MixedMessage::Audio)
+        .chain(futures_util::stream::iter([MixedMessage::Control(
+            ControlMessage::Finalize,
+        )]));
+
+    let client = ListenClient::builder()
+        .api_base(api_base)
+        .params(params)
+        .build_single()
+        .await;
+    let (stream, _handle) = client
+        .from_realtime_audio(input)
+        .await
+        .map_err(|e| format!("{:?}", e))?;
+    futures_util::pin_mut!(stream);
+
+    let mut words = Vec::new();
+    while let Ok(Some(response)) = tokio::time::timeout(STREAM_IDLE_TIMEOUT, stream.next()).await {
+        let response = response.map_err(|e| format!("{:?}", e))?;
+        let StreamResponse::TranscriptResponse {
+            is_final,
+            from_finalize,
+            channel,
+            ..
+        } = response
+        else {
+            continue;
+        };
+
+        if is_final {
+            if let Some(alternative) = channel.alternatives.into_iter().next() {
+                words.extend(alternative.words.into_iter().map(|w| SttWord {
+                    text: w.punctuated_word.unwrap_or(w.word),
+                    start_ms: seconds_to_ms(w.start),
+                    end_ms: seconds_to_ms(w.end),
+                    speaker: w.speaker.map(|s| s.to_string()),
+                }));
+            }
+        }
+        if from_finalize {
+            break;
+        }
+    }
+
+    Ok(words)
+}
+
+fn seconds_to_ms(seconds: f64) -> u64 {
+    (seconds.max(0.0) * 1000.0).round() as u64
+}
@@ -0,0 +1,329 @@
+use chrono::{DateTime, Utc};
+use futures_util::future::BoxFuture;
+use hypr_api_nango::{GoogleCalendar, NangoConnectionError, NangoConnectionState, OutlookCalendar};
+use hypr_google_calendar::GoogleCalendarClient;
+use hypr_outlook_calendar::OutlookCalendarClient;
+
+use super::{CalendarEvent, CalendarSource};
+use crate::error::{BotError, Result};
+
+const PAGE_SIZE: u32 = 100;
+
+/// Reads events through the user's Google or Outlook connection, whichever
+/// they have, like the calendar API does for requests.
+#[derive(Clone)]
+pub struct NangoCalendarSource {
+    nango: NangoConnectionState,
+}
+
+impl NangoCalendarSource {
+    /// `nango` needs a service role key, since there is no user token to
+    /// look connections up with.
+    pub fn new(nango: NangoConnectionState) -> Self {
+        Self { nango }
+    }
+
+    async fn list_google(
+        &self,
+        user_id: &str,
+        calendar_id: &str,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Option<Vec<CalendarEvent>>> {
+        let client = match self.nango.connect::<GoogleCalendar>(user_id).await {
+            Ok(conn) => GoogleCalendarClient::new(conn.into_http()),
+            Err(NangoConnectionError::NotConnected(_)) => return Ok(None),
+            Err(e) => return Err(BotError::Internal(e.to_string())),
+        };
+
+        let mut events = Vec::new();
+        let mut page_token = None;
+        loop {
+            let response = client
+                .list_events(hypr_google_calendar::ListEventsRequest {
+                    calendar_id: calendar_id.to_string(),
+                    time_min: Some(from),
+                    time_max: Some(to),
+                    max_results: Some(PAGE_SIZE),
+                    page_token: page_token.take(),
+                    single_events: Some(true),
+                    show_deleted: Some(true),
+                    ..Default::default()
+                })
+                .await
+                .map_err(|e| BotError::Internal(e.to_string()))?;
+
+            events.extend(
+                response
+                    .items
+                    .iter()
+                    .filter_map(|event| CalendarEvent::from_google(calendar_id, event)),
+            );
+
+            match response.next_page_token {
+                Some(token) => page_token = Some(token),
+                None => return Ok(Some(events)),
+            }
+        }
+    }
+
+    async fn list_outlook(
+        &self,
+        user_id: &str,
+        calendar_id: &str,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Option<Vec<CalendarEvent>>> {
+        let client = match self.nango.connect::<OutlookCalendar>(user_id).await {
+            Ok(conn) => OutlookCalendarClient::new(conn.into_http()),
+            Err(NangoConnectionError::NotConnected(_)) => return Ok(None),
+            Err(e) => return Err(BotError::Internal(e.to_string())),
+        };
+
+        // The calendar view expands series into occurrences and pages with
+        // `$skip`, so the next link isn't needed beyond telling we're done.
+        let mut events = Vec::new();
+        let mut skip = 0;
+        loop {
+            let response = client
+                .list_events(hypr_outlook_calendar::ListEventsRequest {
+                    calendar_id: calendar_id.to_string(),
+                    start_date_time: Some(from),
+                    end_date_time: Some(to),
+                    top: Some(PAGE_SIZE),
+                    skip: Some(skip),
+                    ..Default::default()
+                })
+                .await
+                .map_err(|e| BotError::Internal(e.to_string()))?;
+
+            skip += response.value.len() as u32;
+            events.extend(
+                response
+                    .value
+                    .iter()
+                    .filter_map(|event| CalendarEvent::from_outlook(calendar_id, event)),
+            );
+
+            if response.odata_next_link.is_none() || response.value.is_empty() {
+                return Ok(Some(events));
+            }
+        }
+    }
+}
+
+impl CalendarSource for NangoCalendarSource {
+    fn list_events<'a>(
+        &'a self,
+        user_id: &'a str,
+        calendar_id: &'a str,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> BoxFuture<'a, Result<Vec<CalendarEvent>>> {
+        Box::pin(async move {
+            // A failing provider shouldn't hide a working one.
+            let mut errors = Vec::new();
+
+            match self.list_google(user_id, calendar_id, from, to).await {
+                Ok(Some(events)) => return Ok(events),
+                Ok(None) => {}
+                Err(e) => errors.push(format!("google: {e}")),
+            }
+            match self.list_outlook(user_id, calendar_id, from, to).await {
+                Ok(Some(events)) => return Ok(events),
+                Ok(None) => {}
+                Err(e) => errors.push(format!("outlook: {e}")),
+            }
+
+            if errors.is_empty() {
+                Err(BotError::NotFound("no calendar provider connected".into()))
+            } else {
+                Err(BotError::Internal(errors.join("; ")))
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+    use wiremock::matchers::{header, method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use super::*;
+
+    async fn source(server: &MockServer, connected: &[&str]) -> NangoCalendarSource {
+        for integration in ["google-calendar", "outlook-calendar"] {
+            let rows = if connected.contains(&integration) {
+                serde_json::json!([{ "connection_id": "conn_1" }])
+            } else {
+                serde_json::json!([])
+            };
+            Mock::given(method("GET"))
+                .and(path("/rest/v1/nango_connections"))
+                .and(query_param("integration_id", format!("eq.{integration}")))
+                .and(query_param("user_id", "eq.user_a"))
+                .and(header("apikey", "service-key"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(rows))
+                .mount(server)
+                .await;
+        }
+
+        let nango = hypr_nango::NangoClient::builder()
+            .api_key("nango-key")
+            .api_base(server.uri())
+            .build()
+            .unwrap();
+        NangoCalendarSource::new(
+            NangoConnectionState::new(nango, server.uri(), "anon-key")
+                .with_service_role_key("service-key"),
+        )
+    }
+
+    fn window() -> (DateTime<Utc>, DateTime<Utc>) {
+        let from = Utc.with_ymd_and_hms(2026, 3, 4, 14, 50, 0).unwrap();
+        (from, from + chrono::Duration::minutes(31))
+    }
+
+    #[tokio::test]
+    async fn test_google_pages() {
+        let server = MockServer::start().await;
+        let source = source(&server, &["google-calendar"]).await;
+
+        let event = |id: &str| {
+            serde_json::json!({
+                "id": id,
+                "status": "confirmed",
+                "summary": "Sync",
+                "start": { "dateTime": "2026-03-04T15:00:00Z" },
+                "end": { "dateTime": "2026-03-04T15:30:00Z" },
+                "hangoutLink": "https://meet.google.com/abc-defg-hij",
+            })
+        };
+        Mock::given(method("GET"))
+            .and(path("/proxy/calendar/v3/calendars/work/events"))
+            .and(query_param("pageToken", "next"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({ "items": [event("b")] })),
+            )
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/proxy/calendar/v3/calendars/work/events"))
+            .and(query_param("singleEvents", "true"))
+            .and(query_param("showDeleted", "true"))
+            .and(header("Connection-Id", "conn_1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "items": [event("a")],
+                "nextPageToken": "next",
+            })))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+
+        let (from, to) = window();
+        let events = source
+            .list_events("user_a", "work", from, to)
+            .await
+            .unwrap();
+        let ids: Vec<&str> = events.iter().map(|e| e.id.as_str()).collect();
+        assert_eq!(ids, ["a", "b"]);
+    }
+
+    #[tokio::test]
+    async fn test_falls_back_to_outlook() {
+        let server = MockServer::start().await;
+        let source = source(&server, &["outlook-calendar"]).await;
+
+        Mock::given(method("GET"))
+            .and(path("/proxy/me/calendars/work/calendarView"))
+            .and(header("Provider-Config-Key", "outlook-calendar"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "value": [{
+                    "id": "AAMkAGI2",
+                    "subject": "Vendor call",
+                    "start": { "dateTime": "2026-03-04T15:00:00.0000000", "timeZone": "UTC" },
+                    "end": { "dateTime": "2026-03-04T15:30:00.0000000", "timeZone": "UTC" },
+                }],
+            })))
+            .mount(&server)
+            .await;
+
+        let (from, to) = window();
+        let events = source
+            .list_events("user_a", "work", from, to)
+            .await
+            .unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].title, "Vendor call");
+    }
+
+    #[tokio::test]
+    async fn test_google_failure_falls_back_to_outlook() {
+        let server = MockServer::start().await;
+        let source = source(&server, &["google-calendar", "outlook-calendar"]).await;
+
+        Mock::given(method("GET"))
+            .and(path("/proxy/calendar/v3/calendars/work/events"))
+            .respond_with(ResponseTemplate::new(500))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/proxy/me/calendars/work/calendarView"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "value": [{
+                    "id": "AAMkAGI2",
+                    "subject": "Vendor call",
+                    "start": { "dateTime": "2026-03-04T15:00:00.0000000", "timeZone": "UTC" },
+                    "end": { "dateTime": "2026-03-04T15:30:00.0000000", "timeZone": "UTC" },
+                }],
+            })))
+            .mount(&server)
+            .await;
+
+        let (from, to) = window();
+        let events = source
+            .list_events("user_a", "work", from, to)
+            .await
+            .unwrap();
+        assert_eq!(events[0].title, "Vendor call");
+    }
+
+    #[tokio::test]
+    async fn test_reports_every_provider_error() {
+        let server = MockServer::start().await;
+        let source = source(&server, &["google-calendar", "outlook-calendar"]).await;
+
+        for endpoint in [
+            "/proxy/calendar/v3/calendars/work/events",
+            "/proxy/me/calendars/work/calendarView",
+        ] {
+            Mock::given(method("GET"))
+                .and(path(endpoint))
+                .respond_with(ResponseTemplate::new(500))
+                .mount(&server)
+                .await;
+        }
+
+        let (from, to) = window();
+        let Err(BotError::Internal(message)) = source.list_events("user_a", "work", from, to).await
+        else {
+            panic!("expected an internal error");
+        };
+        assert!(message.contains("google:"));
+        assert!(message.contains("outlook:"));
+    }
+
+    #[tokio::test]
+    async fn test_not_connected() {
+        let server = MockServer::start().await;
+        let source = source(&server, &[]).await;
+
+        let (from, to) = window();
+        assert!(matches!(
+            source.list_events("user_a", "work", from, to).await,
+            Err(BotError::NotFound(_))
+        ));
+    }
+}
@@ -0,0 +1,146 @@
+use chrono::{DateTime, Utc};
+use hypr_calendar_interface as interface;
+use hypr_google_calendar as google;
+use hypr_outlook_calendar as outlook;
+
+/// A timed calendar event, reduced to what the auto-join rules look at.
+#[derive(Debug, Clone)]
+pub struct CalendarEvent {
+    pub id: String,
+    pub calendar_id: String,
+    /// Shared by every copy of a series or invite, e.g. the same meeting on
+    /// a personal and a shared calendar.
+    pub series_id: String,
+    pub title: String,
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    /// Where the occurrence was originally scheduled, when it was moved.
+    pub original_start: Option<DateTime<Utc>>,
+    pub organizer_email: Option<String>,
+    pub attending: bool,
+    pub cancelled: bool,
+    pub meeting_url: Option<String>,
+}
+
+impl CalendarEvent {
+    /// Identifies one occurrence across calendars and polls, so each
+    /// instance of a recurring meeting gets at most one bot.
+    pub fn occurrence_key(&self) -> String {
+        let start = self.original_start.unwrap_or(self.start);
+        format!("{}@{}", self.series_id, start.timestamp())
+    }
+
+    /// All-day events are skipped; nobody joins those with a bot.
+    pub fn from_event(event: &interface::CalendarEvent) -> Option<Self> {
+        if event.is_all_day {
+            return None;
+        }
+
+        Some(Self {
+            id: event.id.clone(),
+            calendar_id: event.calendar_id.clone(),
+            series_id: event
+                .ical_uid
+                .clone()
+                .or_else(|| event.series_id.clone())
+                .unwrap_or_else(|| event.id.clone()),
+            title: event.title.clone(),
+            start: event.start,
+            end: event.end,
+            original_start: event.original_start,
+            organizer_email: event.organizer.as_ref().and_then(|o| o.email.clone()),
+            attending: event.self_response == Some(interface::ResponseStatus::Accepted),
+            cancelled: event.is_cancelled(),
+            meeting_url: event.meeting_url.clone(),
+        })
+    }
+
+    pub fn from_google(calendar_id: &str, event: &google::Event) -> Option<Self> {
+        Self::from_event(&event.to_calendar_event(calendar_id)?)
+    }
+
+    pub fn from_outlook(calendar_id: &str, event: &outlook::Event) -> Option<Self> {
+        Self::from_event(&event.to_calendar_event(calendar_id)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_google_recurring_instance() {
+        let event: google::Event = serde_json::from_value(serde_json::json!({
+            "id": "4kq7c1_20260304T150000Z",
+            "status": "confirmed",
+            "summary": "Weekly sync",
+            "iCalUID": "4kq7c1@google.com",
+            "recurringEventId": "4kq7c1",
+            "originalStartTime": { "dateTime": "2026-03-04T15:00:00Z" },
+            "start": { "dateTime": "2026-03-04T16:00:00+01:00" },
+            "end": { "dateTime": "2026-03-04T16:30:00+01:00" },
+            "organizer": { "email": "lead@acme.com" },
+            "attendees": [
+                { "email": "lead@acme.com", "responseStatus": "accepted" },
+                { "email": "me@example.com", "self": true, "responseStatus": "declined" }
+            ],
+            "conferenceData": {
+                "entryPoints": [
+                    { "entryPointType": "phone", "uri": "tel:+1-555-0100" },
+                    { "entryPointType": "video", "uri": "https://meet.google.com/abc-defg-hij" }
+                ]
+            }
+        }))
+        .unwrap();
+
+        let event = CalendarEvent::from_google("primary", &event).unwrap();
+        assert_eq!(event.occurrence_key(), "4kq7c1@google.com@1772636400");
+        assert_eq!(event.start.to_rfc3339(), "2026-03-04T15:00:00+00:00");
+        assert_eq!(event.organizer_email.as_deref(), Some("lead@acme.com"));
+        assert!(!event.attending);
+        assert!(!event.cancelled);
+        assert_eq!(
+            event.meeting_url.as_deref(),
+            Some("https://meet.google.com/abc-defg-hij")
+        );
+    }
+
+    #[test]
+    fn test_from_outlook_link_in_body() {
+        let event: outlook::Event = serde_json::from_value(serde_json::json!({
+            "id": "AAMkAGI2",
+            "iCalUId": "040000008200E00074C5B7101A82E008",
+            "subject": "Vendor call",
+            "start": { "dateTime": "2026-03-04T15:00:00.0000000", "timeZone": "UTC" },
+            "end": { "dateTime": "2026-03-04T15:30:00.0000000", "timeZone": "UTC" },
+            "isCancelled": true,
+            "responseStatus": { "response": "accepted" },
+            "body": {
+                "contentType": "text",
+                "content": "Join: https://acme.zoom.us/j/87636383039 or dial in"
+            }
+        }))
+        .unwrap();
+
+        let event = CalendarEvent::from_outlook("work", &event).unwrap();
+        assert_eq!(event.start.to_rfc3339(), "2026-03-04T15:00:00+00:00");
+        assert!(event.attending);
+        assert!(event.cancelled);
+        assert_eq!(
+            event.meeting_url.as_deref(),
+            Some("https://acme.zoom.us/j/87636383039")
+        );
+    }
+
+    #[test]
+    fn test_all_day_events_are_skipped() {
+        let event: google::Event = serde_json::from_value(serde_json::json!({
+            "id": "offsite",
+            "start": { "date": "2026-03-04" },
+            "end": { "date": "2026-03-05" }
+        }))
+        .unwrap();
+
+        assert!(CalendarEvent::from_google("primary", &event).is_none());
+    }
+}
@@ -0,0 +1,316 @@
+mod calendar;
+mod event;
+mod rules;
+mod supabase;
+
+pub use calendar::NangoCalendarSource;
+pub use event::CalendarEvent;
+pub use rules::{
+    AutoJoinClaim, AutoJoinRule, AutoJoinRules, AutoJoinSettings, AutoJoinStore,
+    InMemoryAutoJoinStore,
+};
+pub use supabase::SupabaseAutoJoinStore;
+
+use rules::MAX_MINUTES_BEFORE;
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use futures_util::future::BoxFuture;
+use hypr_recall::RecallClient;
+
+use crate::config::BotConfig;
+use crate::error::Result;
+use crate::routes::bot::transcription_bot;
+use crate::store::TranscriptStore;
+
+/// Far enough ahead to see every event a rule may join early for.
+const LOOKAHEAD: chrono::Duration = chrono::Duration::minutes(MAX_MINUTES_BEFORE as i64 + 1);
+
+pub trait Clock: Send + Sync {
+    fn now(&self) -> DateTime<Utc>;
+}
+
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+pub trait CalendarSource: Send + Sync {
+    /// Timed events on `calendar_id` overlapping `from..to`, including
+    /// cancelled occurrences the provider still reports.
+    fn list_events<'a>(
+        &'a self,
+        user_id: &'a str,
+        calendar_id: &'a str,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> BoxFuture<'a, Result<Vec<CalendarEvent>>>;
+}
+
+pub trait BotDispatcher: Send + Sync {
+    /// Returns the ID of the bot sent to `meeting_url`.
+    fn send_bot<'a>(
+        &'a self,
+        user_id: &'a str,
+        meeting_url: &'a str,
+        bot_name: Option<&'a str>,
+    ) -> BoxFuture<'a, Result<String>>;
+
+    fn remove_bot<'a>(&'a self, bot_id: &'a str) -> BoxFuture<'a, Result<()>>;
+}
+
+/// Sends bots through Recall and registers them like `POST /bot` does, so
+/// their transcripts reach the user.
+pub struct RecallDispatcher {
+    config: Arc<BotConfig>,
+    client: Arc<RecallClient>,
+    store: TranscriptStore,
+}
+
+impl RecallDispatcher {
+    pub(crate) fn new(
+        config: Arc<BotConfig>,
+        client: Arc<RecallClient>,
+        store: TranscriptStore,
+    ) -> Self {
+        Self {
+            config,
+            client,
+            store,
+        }
+    }
+}
+
+impl BotDispatcher for RecallDispatcher {
+    fn send_bot<'a>(
+        &'a self,
+        user_id: &'a str,
+        meeting_url: &'a str,
+        bot_name: Option<&'a str>,
+    ) -> BoxFuture<'a, Result<String>> {
+        Box::pin(async move {
+            let bot = self
+                .client
+                .create_bot(transcription_bot(
+                    &self.config,
+                    meeting_url.to_string(),
+                    bot_name.map(str::to_string),
+                    user_id,
+                ))
+                .await?;
+            // Transcript webhooks recover the session from the bot's
+            // metadata, so the bot is kept even if this fails.
+            if let Err(e) = self.store.register(&bot.id, user_id).await {
+                tracing::warn!(bot_id = %bot.id, error = %e, "bot_session_register_failed");
+            }
+            Ok(bot.id)
+        })
+    }
+
+    fn remove_bot<'a>(&'a self, bot_id: &'a str) -> BoxFuture<'a, Result<()>> {
+        Box::pin(async move {
+            self.client.remove_bot(bot_id).await?;
+            Ok(())
+        })
+    }
+}
+
+/// Sends bots to upcoming calendar events that match a user's auto-join
+/// rules, and takes them back out when the event is cancelled.
+///
+/// Every sent bot is claimed in the [`AutoJoinStore`] before Recall is
+/// called, so several instances (or a restarted one) never send a second
+/// bot to the same occurrence.
+pub struct AutoJoinScheduler {
+    rules: AutoJoinRules,
+    calendar: Arc<dyn CalendarSource>,
+    bots: Arc<dyn BotDispatcher>,
+    clock: Arc<dyn Clock>,
+}
+
+impl AutoJoinScheduler {
+    pub fn new(
+        rules: AutoJoinRules,
+        calendar: Arc<dyn CalendarSource>,
+        bots: Arc<dyn BotDispatcher>,
+    ) -> Self {
+        Self {
+            rules,
+            calendar,
+            bots,
+            clock: Arc::new(SystemClock),
+        }
+    }
+
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    pub async fn run(self, interval: Duration) {
+        let mut ticker = tokio::time::interval(interval);
+        loop {
+            ticker.tick().await;
+            self.tick().await;
+        }
+    }
+
+    pub async fn tick(&self) {
+        let now = self.clock.now();
+
+        let enabled = match self.rules.enabled().await {
+            Ok(enabled) => enabled,
+            Err(e) => {
+                tracing::warn!(error = %e, "auto_join_rules_unavailable");
+                return;
+            }
+        };
+
+        for (user_id, settings) in enabled {
+            self.tick_user(now, &user_id, &settings).await;
+        }
+    }
+
+    async fn tick_user(&self, now: DateTime<Utc>, user_id: &str, settings: &AutoJoinSettings) {
+        let store = self.rules.store();
+        let claims: HashMap<String, AutoJoinClaim> = match store.claims(user_id, now).await {
+            Ok(claims) => claims
+                .into_iter()
+                .map(|claim| (claim.occurrence_key.clone(), claim))
+                .collect(),
+            Err(e) => {
+                tracing::warn!(user_id = %user_id, error = %e, "auto_join_claims_unavailable");
+                return;
+            }
+        };
+
+        let mut fetched = HashSet::new();
+        let mut occurrences: HashMap<String, CalendarEvent> = HashMap::new();
+
+        for calendar_id in settings.calendar_ids() {
+            let events = match self
+                .calendar
+                .list_events(user_id, calendar_id, now, now + LOOKAHEAD)
+                .await
+            {
+                Ok(events) => events,
+                Err(e) => {
+                    tracing::warn!(user_id = %user_id, calendar_id = %calendar_id, error = %e, "auto_join_list_events_failed");
+                    continue;
+                }
+            };
+            fetched.insert(calendar_id.to_string());
+
+            // The same occurrence can show up on several calendars; it only
+            // counts as cancelled if every copy is.
+            for event in events {
+                occurrences
+                    .entry(event.occurrence_key())
+                    .and_modify(|existing| {
+                        if existing.cancelled && !event.cancelled {
+                            *existing = event.clone();
+                        }
+                    })
+                    .or_insert(event);
+            }
+        }
+
+        let gone = claims.values().filter(|claim| {
+            fetched.contains(&claim.calendar_id)
+                && occurrences
+                    .get(&claim.occurrence_key)
+                    .is_none_or(|event| event.cancelled)
+        });
+        for claim in gone {
+            self.remove(user_id, &claim.occurrence_key).await;
+        }
+
+        for (key, event) in occurrences {
+            if event.cancelled || claims.contains_key(&key) || now >= event.end {
+                continue;
+            }
+            let Some(meeting_url) = event.meeting_url.as_deref() else {
+                continue;
+            };
+            let Some(minutes) = settings.minutes_before(&event) else {
+                continue;
+            };
+            if now < event.start - chrono::Duration::minutes(minutes.into()) {
+                continue;
+            }
+
+            let claim = AutoJoinClaim {
+                occurrence_key: key.clone(),
+                calendar_id: event.calendar_id.clone(),
+                end: event.end,
+                bot_id: None,
+            };
+            match store.claim(user_id, &claim).await {
+                Ok(true) => {}
+                // Another instance got there first.
+                Ok(false) => continue,
+                Err(e) => {
+                    tracing::warn!(user_id = %user_id, event_id = %event.id, error = %e, "auto_join_claim_failed");
+                    continue;
+                }
+            }
+
+            match self
+                .bots
+                .send_bot(user_id, meeting_url, settings.bot_name.as_deref())
+                .await
+            {
+                Ok(bot_id) => {
+                    tracing::info!(user_id = %user_id, bot_id = %bot_id, event_id = %event.id, "auto_join_bot_sent");
+                    if let Err(e) = store.set_claim_bot(user_id, &key, &bot_id).await {
+                        tracing::warn!(user_id = %user_id, bot_id = %bot_id, error = %e, "auto_join_claim_update_failed");
+                    }
+                }
+                // Released so the next tick retries.
+                Err(e) => {
+                    tracing::warn!(user_id = %user_id, event_id = %event.id, error = %e, "auto_join_send_failed");
+                    if let Err(e) = store.release(user_id, &key).await {
+                        tracing::warn!(user_id = %user_id, event_id = %event.id, error = %e, "auto_join_release_failed");
+                    }
+                }
+            }
+        }
+    }
+
+    /// Takes the bot of a cancelled occurrence back out. Whoever releases
+    /// the claim removes the bot; if that fails the claim is restored so a
+    /// later tick tries again.
+    async fn remove(&self, user_id: &str, occurrence_key: &str) {
+        let store = self.rules.store();
+        let claim = match store.release(user_id, occurrence_key).await {
+            Ok(Some(claim)) => claim,
+            Ok(None) => return,
+            Err(e) => {
+                tracing::warn!(user_id = %user_id, error = %e, "auto_join_release_failed");
+                return;
+            }
+        };
+        // Sent by an instance that stopped before recording the bot.
+        let Some(bot_id) = claim.bot_id.as_deref() else {
+            return;
+        };
+
+        match self.bots.remove_bot(bot_id).await {
+            Ok(()) => {
+                tracing::info!(user_id = %user_id, bot_id = %bot_id, "auto_join_bot_removed");
+            }
+            Err(e) => {
+                tracing::warn!(user_id = %user_id, bot_id = %bot_id, error = %e, "auto_join_remove_failed");
+                if let Err(e) = store.claim(user_id, &claim).await {
+                    tracing::warn!(user_id = %user_id, bot_id = %bot_id, error = %e, "auto_join_claim_failed");
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,397 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use chrono::{DateTime, Utc};
+use futures_util::future::BoxFuture;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use super::CalendarEvent;
+use crate::error::{BotError, Result};
+
+pub(super) const MAX_MINUTES_BEFORE: u32 = 30;
+
+fn default_minutes_before() -> u32 {
+    1
+}
+
+/// One opt-in rule. Every filter that is set has to match; an event is
+/// joined if any of the user's rules matches it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct AutoJoinRule {
+    pub calendar_id: String,
+    /// Only events organized by someone at one of these domains.
+    #[serde(default)]
+    pub organizer_domains: Vec<String>,
+    /// Only events whose title contains one of these, ignoring case.
+    #[serde(default)]
+    pub keywords: Vec<String>,
+    /// Only events the user hasn't accepted, so the bot goes instead.
+    #[serde(default)]
+    pub only_if_not_attending: bool,
+    #[serde(default = "default_minutes_before")]
+    pub minutes_before: u32,
+}
+
+impl AutoJoinRule {
+    pub fn matches(&self, event: &CalendarEvent) -> bool {
+        if event.calendar_id != self.calendar_id {
+            return false;
+        }
+        if self.only_if_not_attending && event.attending {
+            return false;
+        }
+
+        if !self.organizer_domains.is_empty() {
+            let domain = event
+                .organizer_email
+                .as_deref()
+                .and_then(|email| email.rsplit_once('@'))
+                .map(|(_, domain)| domain);
+            let matched = domain.is_some_and(|domain| {
+                self.organizer_domains
+                    .iter()
+                    .any(|d| d.trim_start_matches('@').eq_ignore_ascii_case(domain))
+            });
+            if !matched {
+                return false;
+            }
+        }
+
+        if !self.keywords.is_empty() {
+            let title = event.title.to_lowercase();
+            if !self
+                .keywords
+                .iter()
+                .any(|k| title.contains(&k.to_lowercase()))
+            {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct AutoJoinSettings {
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default)]
+    pub bot_name: Option<String>,
+    #[serde(default)]
+    pub rules: Vec<AutoJoinRule>,
+}
+
+impl AutoJoinSettings {
+    pub fn validate(&self) -> Result<()> {
+        for rule in &self.rules {
+            if rule.calendar_id.is_empty() {
+                return Err(BotError::BadRequest("calendar_id is required".into()));
+            }
+            if rule.minutes_before > MAX_MINUTES_BEFORE {
+                return Err(BotError::BadRequest(format!(
+                    "minutes_before must be at most {MAX_MINUTES_BEFORE}"
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// How many minutes before `event` starts the bot should join, or
+    /// `None` if no rule wants it. Overlapping rules join at the earliest.
+    pub fn minutes_before(&self, event: &CalendarEvent) -> Option<u32> {
+        self.rules
+            .iter()
+            .filter(|rule| rule.matches(event))
+            .map(|rule| rule.minutes_before)
+            .max()
+    }
+
+    pub fn calendar_ids(&self) -> Vec<&str> {
+        let mut ids: Vec<&str> = self.rules.iter().map(|r| r.calendar_id.as_str()).collect();
+        ids.sort_unstable();
+        ids.dedup();
+        ids
+    }
+}
+
+/// One occurrence a user's bot was sent to. Claims are shared by every
+/// instance running the scheduler, so each occurrence gets one bot.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AutoJoinClaim {
+    /// [`CalendarEvent::occurrence_key`] of the event.
+    pub occurrence_key: String,
+    pub calendar_id: String,
+    #[serde(rename = "end_at")]
+    pub end: DateTime<Utc>,
+    /// `None` until Recall has accepted the bot.
+    pub bot_id: Option<String>,
+}
+
+/// Where auto-join settings and claims live, so they outlast the process.
+pub trait AutoJoinStore: Send + Sync {
+    fn get<'a>(&'a self, user_id: &'a str) -> BoxFuture<'a, Result<Option<AutoJoinSettings>>>;
+
+    fn set<'a>(
+        &'a self,
+        user_id: &'a str,
+        settings: &'a AutoJoinSettings,
+    ) -> BoxFuture<'a, Result<()>>;
+
+    /// Settings of every user who has auto-join turned on.
+    fn enabled(&self) -> BoxFuture<'_, Result<Vec<(String, AutoJoinSettings)>>>;
+
+    /// Records `claim` unless the occurrence is already claimed. Returns
+    /// whether this call made the claim.
+    fn claim<'a>(
+        &'a self,
+        user_id: &'a str,
+        claim: &'a AutoJoinClaim,
+    ) -> BoxFuture<'a, Result<bool>>;
+
+    fn set_claim_bot<'a>(
+        &'a self,
+        user_id: &'a str,
+        occurrence_key: &'a str,
+        bot_id: &'a str,
+    ) -> BoxFuture<'a, Result<()>>;
+
+    /// Deletes the claim and returns it, or `None` if it was already gone.
+    fn release<'a>(
+        &'a self,
+        user_id: &'a str,
+        occurrence_key: &'a str,
+    ) -> BoxFuture<'a, Result<Option<AutoJoinClaim>>>;
+
+    /// Claims of `user_id` for events ending after `after`.
+    fn claims<'a>(
+        &'a self,
+        user_id: &'a str,
+        after: DateTime<Utc>,
+    ) -> BoxFuture<'a, Result<Vec<AutoJoinClaim>>>;
+}
+
+#[derive(Default)]
+pub struct InMemoryAutoJoinStore {
+    settings: Mutex<HashMap<String, AutoJoinSettings>>,
+    claims: Mutex<HashMap<(String, String), AutoJoinClaim>>,
+}
+
+impl AutoJoinStore for InMemoryAutoJoinStore {
+    fn get<'a>(&'a self, user_id: &'a str) -> BoxFuture<'a, Result<Option<AutoJoinSettings>>> {
+        let settings = self.settings.lock().unwrap().get(user_id).cloned();
+        Box::pin(async move { Ok(settings) })
+    }
+
+    fn set<'a>(
+        &'a self,
+        user_id: &'a str,
+        settings: &'a AutoJoinSettings,
+    ) -> BoxFuture<'a, Result<()>> {
+        self.settings
+            .lock()
+            .unwrap()
+            .insert(user_id.to_string(), settings.clone());
+        Box::pin(async { Ok(()) })
+    }
+
+    fn enabled(&self) -> BoxFuture<'_, Result<Vec<(String, AutoJoinSettings)>>> {
+        let enabled = self
+            .settings
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|(_, s)| s.enabled)
+            .map(|(user_id, s)| (user_id.clone(), s.clone()))
+            .collect();
+        Box::pin(async move { Ok(enabled) })
+    }
+
+    fn claim<'a>(
+        &'a self,
+        user_id: &'a str,
+        claim: &'a AutoJoinClaim,
+    ) -> BoxFuture<'a, Result<bool>> {
+        let mut claims = self.claims.lock().unwrap();
+        let key = (user_id.to_string(), claim.occurrence_key.clone());
+        let claimed = !claims.contains_key(&key);
+        if claimed {
+            claims.insert(key, claim.clone());
+        }
+        Box::pin(async move { Ok(claimed) })
+    }
+
+    fn set_claim_bot<'a>(
+        &'a self,
+        user_id: &'a str,
+        occurrence_key: &'a str,
+        bot_id: &'a str,
+    ) -> BoxFuture<'a, Result<()>> {
+        let key = (user_id.to_string(), occurrence_key.to_string());
+        if let Some(claim) = self.claims.lock().unwrap().get_mut(&key) {
+            claim.bot_id = Some(bot_id.to_string());
+        }
+        Box::pin(async { Ok(()) })
+    }
+
+    fn release<'a>(
+        &'a self,
+        user_id: &'a str,
+        occurrence_key: &'a str,
+    ) -> BoxFuture<'a, Result<Option<AutoJoinClaim>>> {
+        let key = (user_id.to_string(), occurrence_key.to_string());
+        let released = self.claims.lock().unwrap().remove(&key);
+        Box::pin(async move { Ok(released) })
+    }
+
+    fn claims<'a>(
+        &'a self,
+        user_id: &'a str,
+        after: DateTime<Utc>,
+    ) -> BoxFuture<'a, Result<Vec<AutoJoinClaim>>> {
+        let claims = self
+            .claims
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|((owner, _), claim)| owner == user_id && claim.end > after)
+            .map(|(_, claim)| claim.clone())
+            .collect();
+        Box::pin(async move { Ok(claims) })
+    }
+}
+
+/// Per-user auto-join settings, shared by the routes and the scheduler.
+#[derive(Clone)]
+pub struct AutoJoinRules {
+    store: Arc<dyn AutoJoinStore>,
+}
+
+impl Default for AutoJoinRules {
+    fn default() -> Self {
+        Self::new(Arc::new(InMemoryAutoJoinStore::default()))
+    }
+}
+
+impl AutoJoinRules {
+    pub fn new(store: Arc<dyn AutoJoinStore>) -> Self {
+        Self { store }
+    }
+
+    pub async fn get(&self, user_id: &str) -> Result<AutoJoinSettings> {
+        Ok(self.store.get(user_id).await?.unwrap_or_default())
+    }
+
+    pub async fn set(&self, user_id: &str, settings: AutoJoinSettings) -> Result<()> {
+        self.store.set(user_id, &settings).await
+    }
+
+    pub async fn enabled(&self) -> Result<Vec<(String, AutoJoinSettings)>> {
+        let mut enabled = self.store.enabled().await?;
+        enabled.retain(|(_, s)| s.enabled && !s.rules.is_empty());
+        Ok(enabled)
+    }
+
+    pub(crate) fn store(&self) -> &dyn AutoJoinStore {
+        self.store.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+
+    use super::*;
+
+    fn event(title: &str, organizer: &str, attending: bool) -> CalendarEvent {
+        let start = Utc.with_ymd_and_hms(2026, 3, 4, 15, 0, 0).unwrap();
+        CalendarEvent {
+            id: "e1".into(),
+            calendar_id: "work".into(),
+            series_id: "e1".into(),
+            title: title.into(),
+            start,
+            end: start + chrono::Duration::minutes(30),
+            original_start: None,
+            organizer_email: Some(organizer.into()),
+            attending,
+            cancelled: false,
+            meeting_url: Some("https://meet.google.com/abc-defg-hij".into()),
+        }
+    }
+
+    fn rule() -> AutoJoinRule {
+        AutoJoinRule {
+            calendar_id: "work".into(),
+            organizer_domains: vec![],
+            keywords: vec![],
+            only_if_not_attending: false,
+            minutes_before: 2,
+        }
+    }
+
+    #[test]
+    fn test_rule_filters() {
+        let domain = AutoJoinRule {
+            organizer_domains: vec!["@Acme.com".into()],
+            ..rule()
+        };
+        assert!(domain.matches(&event("Sync", "lead@acme.com", true)));
+        assert!(!domain.matches(&event("Sync", "lead@other.com", true)));
+
+        let keyword = AutoJoinRule {
+            keywords: vec!["standup".into()],
+            ..rule()
+        };
+        assert!(keyword.matches(&event("Team Standup", "a@b.com", true)));
+        assert!(!keyword.matches(&event("1:1", "a@b.com", true)));
+
+        let absent = AutoJoinRule {
+            only_if_not_attending: true,
+            ..rule()
+        };
+        assert!(absent.matches(&event("Sync", "a@b.com", false)));
+        assert!(!absent.matches(&event("Sync", "a@b.com", true)));
+
+        let other_calendar = AutoJoinRule {
+            calendar_id: "personal".into(),
+            ..rule()
+        };
+        assert!(!other_calendar.matches(&event("Sync", "a@b.com", true)));
+    }
+
+    #[test]
+    fn test_earliest_matching_rule_wins() {
+        let settings = AutoJoinSettings {
+            enabled: true,
+            bot_name: None,
+            rules: vec![
+                rule(),
+                AutoJoinRule {
+                    keywords: vec!["board".into()],
+                    minutes_before: 5,
+                    ..rule()
+                },
+            ],
+        };
+
+        assert_eq!(
+            settings.minutes_before(&event("Board review", "a@b.com", true)),
+            Some(5)
+        );
+        assert_eq!(
+            settings.minutes_before(&event("Sync", "a@b.com", true)),
+            Some(2)
+        );
+
+        let too_early = AutoJoinSettings {
+            rules: vec![AutoJoinRule {
+                minutes_before: 90,
+                ..rule()
+            }],
+            ..settings
+        };
+        assert!(matches!(too_early.validate(), Err(BotError::BadRequest(_))));
+    }
+}
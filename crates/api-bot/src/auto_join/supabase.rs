@@ -0,0 +1,344 @@
+use chrono::{DateTime, Utc};
+use futures_util::future::BoxFuture;
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use super::{AutoJoinClaim, AutoJoinSettings, AutoJoinStore};
+use crate::error::Result;
+use crate::supabase::SupabaseRest;
+
+const SETTINGS_TABLE: &str = "bot_auto_join_settings";
+const CLAIMS_TABLE: &str = "bot_auto_join_claims";
+
+#[derive(Serialize, Deserialize)]
+struct SettingsRow {
+    user_id: String,
+    enabled: bool,
+    settings: AutoJoinSettings,
+}
+
+#[derive(Serialize)]
+struct ClaimRow<'a> {
+    user_id: &'a str,
+    #[serde(flatten)]
+    claim: &'a AutoJoinClaim,
+}
+
+fn claim_filter(user_id: &str, occurrence_key: &str) -> String {
+    format!(
+        "user_id=eq.{}&occurrence_key=eq.{}",
+        urlencoding::encode(user_id),
+        urlencoding::encode(occurrence_key)
+    )
+}
+
+/// Keeps auto-join settings and claims in the `bot_auto_join_settings` and
+/// `bot_auto_join_claims` tables, using the service role since the
+/// scheduler reads them for every user.
+#[derive(Clone)]
+pub struct SupabaseAutoJoinStore {
+    rest: SupabaseRest,
+}
+
+impl SupabaseAutoJoinStore {
+    pub fn new(supabase_url: &str, service_role_key: &str) -> Self {
+        Self {
+            rest: SupabaseRest::new(supabase_url, service_role_key),
+        }
+    }
+
+    async fn select(&self, filter: &str) -> Result<Vec<SettingsRow>> {
+        let query = format!("{filter}&select=user_id,enabled,settings");
+        self.rest
+            .rows(
+                self.rest.request(Method::GET, SETTINGS_TABLE, &query),
+                "read auto-join settings",
+            )
+            .await
+    }
+}
+
+impl AutoJoinStore for SupabaseAutoJoinStore {
+    fn get<'a>(&'a self, user_id: &'a str) -> BoxFuture<'a, Result<Option<AutoJoinSettings>>> {
+        Box::pin(async move {
+            let filter = format!("user_id=eq.{}", urlencoding::encode(user_id));
+            let rows = self.select(&filter).await?;
+            Ok(rows.into_iter().next().map(|row| row.settings))
+        })
+    }
+
+    fn set<'a>(
+        &'a self,
+        user_id: &'a str,
+        settings: &'a AutoJoinSettings,
+    ) -> BoxFuture<'a, Result<()>> {
+        Box::pin(async move {
+            let row = SettingsRow {
+                user_id: user_id.to_string(),
+                enabled: settings.enabled,
+                settings: settings.clone(),
+            };
+
+            self.rest
+                .send(
+                    self.rest
+                        .request(Method::POST, SETTINGS_TABLE, "on_conflict=user_id")
+                        .header("Prefer", "resolution=merge-duplicates,return=minimal")
+                        .json(&row),
+                    "save auto-join settings",
+                )
+                .await?;
+
+            Ok(())
+        })
+    }
+
+    fn enabled(&self) -> BoxFuture<'_, Result<Vec<(String, AutoJoinSettings)>>> {
+        Box::pin(async move {
+            let rows = self.select("enabled=is.true").await?;
+            Ok(rows
+                .into_iter()
+                .map(|row| (row.user_id, row.settings))
+                .collect())
+        })
+    }
+
+    fn claim<'a>(
+        &'a self,
+        user_id: &'a str,
+        claim: &'a AutoJoinClaim,
+    ) -> BoxFuture<'a, Result<bool>> {
+        Box::pin(async move {
+            // The primary key on (user_id, occurrence_key) makes this the
+            // lock: a duplicate insert is ignored and returns no row.
+            let inserted: Vec<serde_json::Value> = self
+                .rest
+                .rows(
+                    self.rest
+                        .request(Method::POST, CLAIMS_TABLE, "")
+                        .header(
+                            "Prefer",
+                            "resolution=ignore-duplicates,return=representation",
+                        )
+                        .json(&ClaimRow { user_id, claim }),
+                    "claim auto-join occurrence",
+                )
+                .await?;
+            Ok(!inserted.is_empty())
+        })
+    }
+
+    fn set_claim_bot<'a>(
+        &'a self,
+        user_id: &'a str,
+        occurrence_key: &'a str,
+        bot_id: &'a str,
+    ) -> BoxFuture<'a, Result<()>> {
+        Box::pin(async move {
+            self.rest
+                .send(
+                    self.rest
+                        .request(
+                            Method::PATCH,
+                            CLAIMS_TABLE,
+                            &claim_filter(user_id, occurrence_key),
+                        )
+                        .header("Prefer", "return=minimal")
+                        .json(&serde_json::json!({ "bot_id": bot_id })),
+                    "record auto-join bot",
+                )
+                .await?;
+            Ok(())
+        })
+    }
+
+    fn release<'a>(
+        &'a self,
+        user_id: &'a str,
+        occurrence_key: &'a str,
+    ) -> BoxFuture<'a, Result<Option<AutoJoinClaim>>> {
+        Box::pin(async move {
+            let deleted: Vec<AutoJoinClaim> = self
+                .rest
+                .rows(
+                    self.rest
+                        .request(
+                            Method::DELETE,
+                            CLAIMS_TABLE,
+                            &claim_filter(user_id, occurrence_key),
+                        )
+                        .header("Prefer", "return=representation"),
+                    "release auto-join claim",
+                )
+                .await?;
+            Ok(deleted.into_iter().next())
+        })
+    }
+
+    fn claims<'a>(
+        &'a self,
+        user_id: &'a str,
+        after: DateTime<Utc>,
+    ) -> BoxFuture<'a, Result<Vec<AutoJoinClaim>>> {
+        Box::pin(async move {
+            let query = format!(
+                "user_id=eq.{}&end_at=gt.{}&select=occurrence_key,calendar_id,end_at,bot_id",
+                urlencoding::encode(user_id),
+                urlencoding::encode(&after.to_rfc3339()),
+            );
+            self.rest
+                .rows(
+                    self.rest.request(Method::GET, CLAIMS_TABLE, &query),
+                    "read auto-join claims",
+                )
+                .await
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wiremock::matchers::{body_json, header, method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use super::*;
+    use crate::error::BotError;
+
+    fn settings() -> serde_json::Value {
+        serde_json::json!({
+            "enabled": true,
+            "bot_name": null,
+            "rules": [{
+                "calendar_id": "work",
+                "organizer_domains": [],
+                "keywords": ["sync"],
+                "only_if_not_attending": false,
+                "minutes_before": 2,
+            }],
+        })
+    }
+
+    #[tokio::test]
+    async fn test_round_trip() {
+        let server = MockServer::start().await;
+        let store = SupabaseAutoJoinStore::new(&format!("{}/", server.uri()), "service-key");
+
+        Mock::given(method("POST"))
+            .and(path("/rest/v1/bot_auto_join_settings"))
+            .and(query_param("on_conflict", "user_id"))
+            .and(header("apikey", "service-key"))
+            .and(body_json(serde_json::json!({
+                "user_id": "user_a",
+                "enabled": true,
+                "settings": settings(),
+            })))
+            .respond_with(ResponseTemplate::new(201))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/rest/v1/bot_auto_join_settings"))
+            .and(query_param("enabled", "is.true"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                { "user_id": "user_a", "enabled": true, "settings": settings() },
+            ])))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/rest/v1/bot_auto_join_settings"))
+            .and(query_param("user_id", "eq.user_b"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([])))
+            .mount(&server)
+            .await;
+
+        let settings: AutoJoinSettings = serde_json::from_value(settings()).unwrap();
+        store.set("user_a", &settings).await.unwrap();
+
+        assert_eq!(
+            store.enabled().await.unwrap(),
+            vec![("user_a".to_string(), settings)]
+        );
+        assert_eq!(store.get("user_b").await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn test_claim_once() {
+        let server = MockServer::start().await;
+        let store = SupabaseAutoJoinStore::new(&server.uri(), "service-key");
+
+        let claim = AutoJoinClaim {
+            occurrence_key: "weekly@google.com@1772636400".to_string(),
+            calendar_id: "work".to_string(),
+            end: "2026-03-04T15:30:00Z".parse().unwrap(),
+            bot_id: None,
+        };
+        let row = serde_json::json!({
+            "user_id": "user_a",
+            "occurrence_key": "weekly@google.com@1772636400",
+            "calendar_id": "work",
+            "end_at": "2026-03-04T15:30:00Z",
+            "bot_id": null,
+        });
+
+        // The first insert wins; the duplicate is ignored by the primary key.
+        Mock::given(method("POST"))
+            .and(path("/rest/v1/bot_auto_join_claims"))
+            .and(header(
+                "Prefer",
+                "resolution=ignore-duplicates,return=representation",
+            ))
+            .and(body_json(&row))
+            .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!([row])))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/rest/v1/bot_auto_join_claims"))
+            .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!([])))
+            .mount(&server)
+            .await;
+        Mock::given(method("DELETE"))
+            .and(path("/rest/v1/bot_auto_join_claims"))
+            .and(query_param("user_id", "eq.user_a"))
+            .and(query_param(
+                "occurrence_key",
+                "eq.weekly@google.com@1772636400",
+            ))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!([{
+                    "occurrence_key": "weekly@google.com@1772636400",
+                    "calendar_id": "work",
+                    "end_at": "2026-03-04T15:30:00Z",
+                    "bot_id": "bot_1",
+                }])),
+            )
+            .mount(&server)
+            .await;
+
+        assert!(store.claim("user_a", &claim).await.unwrap());
+        assert!(!store.claim("user_a", &claim).await.unwrap());
+
+        let released = store
+            .release("user_a", &claim.occurrence_key)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(released.bot_id.as_deref(), Some("bot_1"));
+    }
+
+    #[tokio::test]
+    async fn test_error_status() {
+        let server = MockServer::start().await;
+        let store = SupabaseAutoJoinStore::new(&server.uri(), "service-key");
+
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(500))
+            .mount(&server)
+            .await;
+
+        assert!(matches!(
+            store.get("user_a").await,
+            Err(BotError::Internal(_))
+        ));
+    }
+}
@@ -3,6 +3,11 @@ use serde::Deserialize;
 #[derive(Clone, Deserialize)]
 pub struct BotConfig {
     pub recall_api_key: String,
+    /// Signing secret of the Recall webhook endpoint, starting with `whsec_`.
+    pub recall_webhook_secret: String,
+    /// Overrides the Recall API base URL, e.g. for another region.
+    #[serde(default)]
+    pub recall_api_base: Option<String>,
     /// Publicly reachable base URL for this API server, used to build webhook URLs.
     /// e.g. "https://api.hyprnote.com"
     pub public_url: String,
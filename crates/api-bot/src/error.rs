@@ -22,9 +22,17 @@ struct ErrorDetails {
 #[derive(Debug, Error)]
 pub enum BotError {
     #[error("Invalid request: {0}")]
-    #[allow(dead_code)]
     BadRequest(String),
 
+    #[error("Authentication error: {0}")]
+    Auth(String),
+
+    #[error("Not found: {0}")]
+    NotFound(String),
+
+    #[error("Conflict: {0}")]
+    Conflict(String),
+
     #[error("Recall API error: {0}")]
     Recall(#[from] hypr_recall::Error),
 
@@ -36,6 +44,9 @@ impl IntoResponse for BotError {
     fn into_response(self) -> Response {
         let (status, code, message) = match self {
             Self::BadRequest(msg) => (StatusCode::BAD_REQUEST, "bad_request", msg),
+            Self::Auth(msg) => (StatusCode::UNAUTHORIZED, "unauthorized", msg),
+            Self::NotFound(msg) => (StatusCode::NOT_FOUND, "not_found", msg),
+            Self::Conflict(msg) => (StatusCode::CONFLICT, "conflict", msg),
             Self::Recall(err) => {
                 let msg = err.to_string();
                 tracing::error!(error = %msg, "recall_error");
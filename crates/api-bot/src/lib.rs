@@ -1,8 +1,21 @@
+mod auto_join;
 mod config;
 mod error;
 mod openapi;
 mod routes;
+mod state;
+mod store;
+mod supabase;
+mod transcript;
 
+pub use auto_join::{
+    AutoJoinClaim, AutoJoinRule, AutoJoinRules, AutoJoinScheduler, AutoJoinSettings, AutoJoinStore,
+    BotDispatcher, CalendarEvent, CalendarSource, Clock, InMemoryAutoJoinStore,
+    NangoCalendarSource, RecallDispatcher, SupabaseAutoJoinStore, SystemClock,
+};
 pub use config::BotConfig;
+pub use error::BotError;
 pub use openapi::openapi;
-pub use routes::router;
+pub use routes::{router, router_with_state, webhook_router_with_state};
+pub use state::BotState;
+pub use store::{BotSession, BotSessionStore, InMemoryBotSessionStore, SupabaseBotSessionStore};
@@ -7,6 +7,10 @@ use utoipa::OpenApi;
         crate::routes::bot::remove_bot,
         crate::routes::bot::start_demo,
         crate::routes::bot::demo_status,
+        crate::routes::transcript::events,
+        crate::routes::transcript::transcript,
+        crate::routes::auto_join::get_settings,
+        crate::routes::auto_join::put_settings,
     ),
     components(
         schemas(
@@ -15,6 +19,9 @@ use utoipa::OpenApi;
             crate::routes::bot::StartDemoRequest,
             crate::routes::bot::StartDemoResponse,
             crate::routes::bot::DemoStatusResponse,
+            crate::routes::transcript::BotTranscriptResponse,
+            crate::auto_join::AutoJoinSettings,
+            crate::auto_join::AutoJoinRule,
         )
     ),
     tags(
@@ -0,0 +1,41 @@
+use axum::{Extension, Json};
+use hypr_api_auth::AuthContext;
+
+use crate::auto_join::{AutoJoinRules, AutoJoinSettings};
+use crate::error::Result;
+
+#[utoipa::path(
+    get,
+    path = "/auto-join",
+    responses(
+        (status = 200, description = "Auto-join settings of the current user", body = AutoJoinSettings),
+        (status = 401, description = "Unauthorized"),
+    )
+)]
+pub async fn get_settings(
+    Extension(auth): Extension<AuthContext>,
+    Extension(rules): Extension<AutoJoinRules>,
+) -> Result<Json<AutoJoinSettings>> {
+    Ok(Json(rules.get(&auth.claims.sub).await?))
+}
+
+#[utoipa::path(
+    put,
+    path = "/auto-join",
+    request_body = AutoJoinSettings,
+    responses(
+        (status = 200, description = "Auto-join settings saved", body = AutoJoinSettings),
+        (status = 400, description = "Bad request"),
+        (status = 401, description = "Unauthorized"),
+    )
+)]
+pub async fn put_settings(
+    Extension(auth): Extension<AuthContext>,
+    Extension(rules): Extension<AutoJoinRules>,
+    Json(settings): Json<AutoJoinSettings>,
+) -> Result<Json<AutoJoinSettings>> {
+    settings.validate()?;
+    rules.set(&auth.claims.sub, settings.clone()).await?;
+
+    Ok(Json(settings))
+}
@@ -1,6 +1,8 @@
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use axum::{Extension, Json, extract::Path, response::Html};
+use hypr_api_auth::AuthContext;
 use hypr_recall::{
     AutomaticLeaveConfig, BotStatusCode, BotVariant, CreateBotRequest, EveryoneLeftConfig,
     OutputMedia, OutputMediaConfig, OutputMediaKind, OutputMediaWebpageConfig,
@@ -11,7 +13,8 @@ use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
 use crate::config::BotConfig;
-use crate::error::Result;
+use crate::error::{BotError, Result};
+use crate::store::TranscriptStore;
 
 #[derive(Debug, Deserialize, ToSchema)]
 pub struct SendBotRequest {
@@ -32,38 +35,77 @@ pub struct SendBotResponse {
     responses(
         (status = 200, description = "Bot sent to meeting", body = SendBotResponse),
         (status = 400, description = "Bad request"),
+        (status = 401, description = "Unauthorized"),
         (status = 502, description = "Recall API error"),
     )
 )]
 pub async fn send_bot(
+    Extension(auth): Extension<AuthContext>,
     Extension(config): Extension<Arc<BotConfig>>,
     Extension(client): Extension<Arc<RecallClient>>,
+    Extension(store): Extension<TranscriptStore>,
     Json(req): Json<SendBotRequest>,
 ) -> Result<Json<SendBotResponse>> {
-    let transcript_url = format!("{}/bot/webhook/transcript", config.public_url);
+    let user_id = auth.claims.sub;
 
     let bot = client
-        .create_bot(CreateBotRequest {
-            meeting_url: req.meeting_url,
-            bot_name: req.bot_name.unwrap_or_else(|| "Hyprnote".into()),
-            transcription_options: Some(TranscriptionOptions {
-                provider: TranscriptionProvider::MeetingCaptions,
-            }),
-            real_time_transcription: Some(RealTimeTranscriptionConfig {
-                destination_url: transcript_url,
-                partial_results: false,
-            }),
-            output_media: None,
-            metadata: None,
-            automatic_leave: None,
-            recording_config: None,
-            variant: None,
-        })
+        .create_bot(transcription_bot(
+            &config,
+            req.meeting_url,
+            req.bot_name,
+            &user_id,
+        ))
         .await?;
 
+    // Transcript webhooks recover the session from the bot's metadata, so
+    // the bot is kept even if this fails.
+    if let Err(e) = store.register(&bot.id, &user_id).await {
+        tracing::warn!(bot_id = %bot.id, error = %e, "bot_session_register_failed");
+    }
+
     Ok(Json(SendBotResponse { bot_id: bot.id }))
 }
 
+/// The user a bot was sent for, from the metadata [`transcription_bot`]
+/// tags it with. Covers bots whose session never made it into the store.
+pub(crate) async fn recall_owner(client: &RecallClient, bot_id: &str) -> Option<String> {
+    let bot = client
+        .get_bot(bot_id)
+        .await
+        .inspect_err(|e| tracing::warn!(bot_id = %bot_id, error = %e, "recall_get_bot_failed"))
+        .ok()?;
+    bot.metadata?.get("user_id")?.as_str().map(str::to_string)
+}
+
+/// A bot that streams meeting-caption transcripts back to our webhook,
+/// tagged with the user it was sent for.
+pub(crate) fn transcription_bot(
+    config: &BotConfig,
+    meeting_url: String,
+    bot_name: Option<String>,
+    user_id: &str,
+) -> CreateBotRequest {
+    CreateBotRequest {
+        meeting_url,
+        bot_name: bot_name.unwrap_or_else(|| "Hyprnote".into()),
+        transcription_options: Some(TranscriptionOptions {
+            provider: TranscriptionProvider::MeetingCaptions,
+        }),
+        real_time_transcription: Some(RealTimeTranscriptionConfig {
+            destination_url: format!("{}/bot/webhook/transcript", config.public_url),
+            partial_results: false,
+        }),
+        output_media: None,
+        metadata: Some(HashMap::from([(
+            "user_id".to_string(),
+            serde_json::Value::String(user_id.to_string()),
+        )])),
+        automatic_leave: None,
+        recording_config: None,
+        variant: None,
+    }
+}
+
 #[derive(Debug, Deserialize, ToSchema)]
 pub struct StartDemoRequest {
     pub meeting_url: String,
@@ -95,7 +137,7 @@ pub async fn start_demo(
     Extension(client): Extension<Arc<RecallClient>>,
     Json(req): Json<StartDemoRequest>,
 ) -> Result<Json<StartDemoResponse>> {
-    let player_url = format!("{}/onboarding/player", config.public_url);
+    let player_url = format!("{}/bot/onboarding/player", config.public_url);
 
     let bot = client
         .create_bot(CreateBotRequest {
@@ -175,13 +217,26 @@ pub async fn demo_status(
     params(("bot_id" = String, Path, description = "Recall bot ID")),
     responses(
         (status = 200, description = "Bot removed from call"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Bot not found"),
         (status = 502, description = "Recall API error"),
     )
 )]
 pub async fn remove_bot(
+    Extension(auth): Extension<AuthContext>,
     Extension(client): Extension<Arc<RecallClient>>,
+    Extension(store): Extension<TranscriptStore>,
     Path(bot_id): Path<String>,
 ) -> Result<()> {
+    let user_id = auth.claims.sub;
+    if let Err(e) = store.authorize(&bot_id, &user_id).await {
+        // Bots sent before sessions were stored are still the sender's.
+        if !matches!(e, BotError::NotFound(_))
+            || recall_owner(&client, &bot_id).await.as_deref() != Some(user_id.as_str())
+        {
+            return Err(e);
+        }
+    }
     client.remove_bot(&bot_id).await?;
 
     Ok(())
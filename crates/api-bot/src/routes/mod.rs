@@ -1,32 +1,49 @@
+pub(crate) mod auto_join;
 pub(crate) mod bot;
+pub(crate) mod transcript;
 pub(crate) mod webhook;
 
-use std::sync::Arc;
-
 use axum::{
     Router,
     routing::{delete, get, post},
 };
 
-use hypr_recall::RecallClient;
-
 use crate::config::BotConfig;
 use crate::error::BotError;
+use crate::state::BotState;
 
 pub fn router(config: BotConfig) -> Result<Router, BotError> {
-    let client =
-        RecallClient::new(&config.recall_api_key).map_err(|e| BotError::Internal(e.to_string()))?;
+    let state = BotState::new(config)?;
+    Ok(router_with_state(state.clone()).merge(webhook_router_with_state(state)))
+}
 
-    let router = Router::new()
+/// Routes for signed-in users; the host app puts them behind its auth.
+pub fn router_with_state(state: BotState) -> Router {
+    Router::new()
         .route("/bot", post(bot::send_bot))
-        .route("/bot/:bot_id", delete(bot::remove_bot))
+        .route("/bot/{bot_id}", delete(bot::remove_bot))
+        .route("/bot/{bot_id}/events", get(transcript::events))
+        .route("/bot/{bot_id}/transcript", get(transcript::transcript))
+        .route(
+            "/auto-join",
+            get(auto_join::get_settings).put(auto_join::put_settings),
+        )
         .route("/onboarding/demo", post(bot::start_demo))
-        .route("/onboarding/demo/:bot_id", get(bot::demo_status))
+        .route("/onboarding/demo/{bot_id}", get(bot::demo_status))
+        .layer(axum::Extension(state.auto_join))
+        .layer(axum::Extension(state.store))
+        .layer(axum::Extension(state.client))
+        .layer(axum::Extension(state.config))
+}
+
+/// Routes Recall calls: webhooks, which are verified by signature, and the
+/// page the onboarding bot plays. These must stay outside the auth layer.
+pub fn webhook_router_with_state(state: BotState) -> Router {
+    Router::new()
         .route("/onboarding/player", get(bot::player))
         .route("/webhook", post(webhook::status_change))
         .route("/webhook/transcript", post(webhook::transcript))
-        .layer(axum::Extension(Arc::new(client)))
-        .layer(axum::Extension(Arc::new(config)));
-
-    Ok(router)
+        .layer(axum::Extension(state.store))
+        .layer(axum::Extension(state.client))
+        .layer(axum::Extension(state.config))
 }
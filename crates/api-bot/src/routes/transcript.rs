@@ -0,0 +1,87 @@
+use std::convert::Infallible;
+
+use axum::{
+    Extension, Json,
+    extract::Path,
+    response::sse::{Event, KeepAlive, Sse},
+};
+use futures_util::Stream;
+use hypr_api_auth::AuthContext;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use crate::error::Result;
+use crate::store::{TranscriptEvent, TranscriptStore};
+use crate::transcript::to_batch_response;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct BotTranscriptResponse {
+    pub bot_id: String,
+    /// Speaker names, indexed by the `speaker` field of each word.
+    pub speakers: Vec<String>,
+    /// Deepgram-shaped batch response with the final words of the call.
+    #[schema(value_type = Object)]
+    pub response: owhisper_interface::batch::Response,
+}
+
+fn transcript_event(event: &TranscriptEvent) -> Event {
+    Event::default()
+        .event("transcript")
+        .json_data(event)
+        .unwrap_or_else(|_| Event::default().event("transcript"))
+}
+
+#[utoipa::path(
+    get,
+    path = "/bot/{bot_id}/events",
+    params(("bot_id" = String, Path, description = "Recall bot ID")),
+    responses(
+        (status = 200, description = "Server-sent `transcript` events, then `ended`", content_type = "text/event-stream"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Bot not found"),
+    )
+)]
+pub async fn events(
+    Extension(auth): Extension<AuthContext>,
+    Extension(store): Extension<TranscriptStore>,
+    Path(bot_id): Path<String>,
+) -> Result<Sse<impl Stream<Item = std::result::Result<Event, Infallible>>>> {
+    let mut subscription = store.subscribe(&bot_id, &auth.claims.sub).await?;
+
+    let stream = async_stream::stream! {
+        while let Some(events) = subscription.next().await {
+            for event in &events {
+                yield Ok(transcript_event(event));
+            }
+        }
+
+        yield Ok(Event::default().event("ended").data(""));
+    };
+
+    Ok(Sse::new(stream).keep_alive(KeepAlive::default()))
+}
+
+#[utoipa::path(
+    get,
+    path = "/bot/{bot_id}/transcript",
+    params(("bot_id" = String, Path, description = "Recall bot ID")),
+    responses(
+        (status = 200, description = "Transcript of the ended call", body = BotTranscriptResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Bot not found"),
+        (status = 409, description = "Call has not ended yet"),
+    )
+)]
+pub async fn transcript(
+    Extension(auth): Extension<AuthContext>,
+    Extension(store): Extension<TranscriptStore>,
+    Path(bot_id): Path<String>,
+) -> Result<Json<BotTranscriptResponse>> {
+    let stored = store.transcript(&bot_id, &auth.claims.sub).await?;
+
+    Ok(Json(BotTranscriptResponse {
+        bot_id,
+        speakers: stored.speakers,
+        response: to_batch_response(&stored.responses),
+    }))
+}
@@ -1,14 +1,67 @@
 use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
 
-use axum::{Extension, Json};
-use hypr_recall::{BotStatusCode, BotStatusWebhook, RecallClient, TranscriptWebhook};
+use axum::{Extension, http::HeaderMap};
+use hypr_recall::{
+    BotStatusCode, BotStatusWebhook, RecallClient, TranscriptWebhook, WEBHOOK_ID_HEADER,
+    WEBHOOK_SIGNATURE_HEADER, WEBHOOK_TIMESTAMP_HEADER,
+};
 
-use crate::error::Result;
+use crate::config::BotConfig;
+use crate::error::{BotError, Result};
+use crate::routes::bot::recall_owner;
+use crate::store::TranscriptStore;
+
+/// Deliveries signed further than this from our clock are rejected as replays.
+const TIMESTAMP_TOLERANCE_SECS: i64 = 5 * 60;
+
+fn header<'a>(headers: &'a HeaderMap, name: &str) -> Result<&'a str> {
+    headers
+        .get(name)
+        .and_then(|h| h.to_str().ok())
+        .ok_or_else(|| BotError::Auth(format!("Missing {name} header")))
+}
+
+fn verify_webhook(config: &BotConfig, headers: &HeaderMap, body: &str) -> Result<()> {
+    let id = header(headers, WEBHOOK_ID_HEADER)?;
+    let timestamp = header(headers, WEBHOOK_TIMESTAMP_HEADER)?;
+    let signature = header(headers, WEBHOOK_SIGNATURE_HEADER)?;
+
+    let sent_at: i64 = timestamp
+        .parse()
+        .map_err(|_| BotError::Auth("Invalid webhook timestamp".to_string()))?;
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or_default();
+    if (now - sent_at).abs() > TIMESTAMP_TOLERANCE_SECS {
+        return Err(BotError::Auth("Stale webhook timestamp".to_string()));
+    }
+
+    if !hypr_recall::verify_webhook_signature(
+        &config.recall_webhook_secret,
+        id,
+        timestamp,
+        body.as_bytes(),
+        signature,
+    ) {
+        return Err(BotError::Auth("Invalid webhook signature".to_string()));
+    }
+
+    Ok(())
+}
 
 pub async fn status_change(
+    Extension(config): Extension<Arc<BotConfig>>,
     Extension(client): Extension<Arc<RecallClient>>,
-    Json(event): Json<BotStatusWebhook>,
+    Extension(store): Extension<TranscriptStore>,
+    headers: HeaderMap,
+    body: String,
 ) -> Result<()> {
+    verify_webhook(&config, &headers, &body)?;
+    let event: BotStatusWebhook =
+        serde_json::from_str(&body).map_err(|e| BotError::BadRequest(e.to_string()))?;
+
     let bot_id = &event.data.bot_id;
     let code = &event.data.status.code;
 
@@ -17,6 +70,7 @@ pub async fn status_change(
     match code {
         BotStatusCode::CallEnded => {
             tracing::info!(bot_id = %bot_id, "bot_call_ended");
+            store.finish(bot_id).await?;
         }
         BotStatusCode::Fatal => {
             let message = event.data.status.message.as_deref().unwrap_or("unknown");
@@ -25,6 +79,7 @@ pub async fn status_change(
                 &format!("Recall bot {bot_id} fatal: {message}"),
                 sentry::Level::Error,
             );
+            store.finish(bot_id).await?;
             // Best-effort removal — the bot may already be gone, but this ensures cleanup
             // if it somehow got stuck in a recoverable state.
             let _ = client.remove_bot(bot_id).await;
@@ -35,22 +90,36 @@ pub async fn status_change(
     Ok(())
 }
 
-pub async fn transcript(Json(payload): Json<TranscriptWebhook>) -> Result<()> {
-    let text = payload
-        .transcript
-        .words
-        .iter()
-        .map(|w| w.text.as_str())
-        .collect::<Vec<_>>()
-        .join(" ");
+pub async fn transcript(
+    Extension(config): Extension<Arc<BotConfig>>,
+    Extension(client): Extension<Arc<RecallClient>>,
+    Extension(store): Extension<TranscriptStore>,
+    headers: HeaderMap,
+    body: String,
+) -> Result<()> {
+    verify_webhook(&config, &headers, &body)?;
+    let payload: TranscriptWebhook =
+        serde_json::from_str(&body).map_err(|e| BotError::BadRequest(e.to_string()))?;
 
     tracing::info!(
         bot_id = %payload.bot_id,
         speaker = %payload.transcript.speaker,
         is_final = payload.transcript.is_final,
-        text = %text,
+        words = payload.transcript.words.len(),
         "transcript_received"
     );
 
+    let bot_id = &payload.bot_id;
+    let mut placed = store.append(bot_id, &payload.transcript).await?;
+    if !placed && let Some(user_id) = recall_owner(&client, bot_id).await {
+        store.register(bot_id, &user_id).await?;
+        placed = store.append(bot_id, &payload.transcript).await?;
+    }
+
+    // Acknowledge anyway so Recall doesn't keep retrying a delivery we can't place.
+    if !placed {
+        tracing::warn!(bot_id = %bot_id, "transcript_for_unknown_bot");
+    }
+
     Ok(())
 }
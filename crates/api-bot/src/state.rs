@@ -0,0 +1,55 @@
+use std::sync::Arc;
+
+use hypr_recall::RecallClient;
+
+use crate::auto_join::{AutoJoinRules, AutoJoinStore, RecallDispatcher};
+use crate::config::BotConfig;
+use crate::error::{BotError, Result};
+use crate::store::{BotSessionStore, TranscriptStore};
+
+/// Everything the routes share, so an [`crate::AutoJoinScheduler`] can run
+/// against the same bots and rules as the HTTP API.
+#[derive(Clone)]
+pub struct BotState {
+    pub(crate) config: Arc<BotConfig>,
+    pub(crate) client: Arc<RecallClient>,
+    pub(crate) store: TranscriptStore,
+    pub(crate) auto_join: AutoJoinRules,
+}
+
+impl BotState {
+    pub fn new(config: BotConfig) -> Result<Self> {
+        let mut client = RecallClient::new(&config.recall_api_key)
+            .map_err(|e| BotError::Internal(e.to_string()))?;
+        if let Some(base_url) = &config.recall_api_base {
+            client = client.with_base_url(base_url);
+        }
+
+        Ok(Self {
+            config: Arc::new(config),
+            client: Arc::new(client),
+            store: TranscriptStore::default(),
+            auto_join: AutoJoinRules::default(),
+        })
+    }
+
+    /// Keeps auto-join settings in `store` instead of in memory.
+    pub fn with_auto_join_store(mut self, store: Arc<dyn AutoJoinStore>) -> Self {
+        self.auto_join = AutoJoinRules::new(store);
+        self
+    }
+
+    /// Keeps bot sessions and transcripts in `store` instead of in memory.
+    pub fn with_session_store(mut self, store: Arc<dyn BotSessionStore>) -> Self {
+        self.store = TranscriptStore::new(store);
+        self
+    }
+
+    pub fn auto_join_rules(&self) -> AutoJoinRules {
+        self.auto_join.clone()
+    }
+
+    pub fn dispatcher(&self) -> RecallDispatcher {
+        RecallDispatcher::new(self.config.clone(), self.client.clone(), self.store.clone())
+    }
+}
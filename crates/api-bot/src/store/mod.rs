@@ -0,0 +1,457 @@
+mod supabase;
+
+pub use supabase::SupabaseBotSessionStore;
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use futures_util::future::BoxFuture;
+use hypr_recall::TranscriptSegment;
+use owhisper_interface::stream::StreamResponse;
+use serde::Serialize;
+use tokio::sync::broadcast;
+
+use crate::error::{BotError, Result};
+use crate::transcript::to_stream_response;
+
+/// How long an ended session stays in memory before it is pruned.
+const ENDED_RETENTION: Duration = Duration::from_secs(24 * 60 * 60);
+/// Subscribers check the store this often, to pick up segments that
+/// reached another instance.
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
+const WAKE_BUFFER: usize = 16;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TranscriptEvent {
+    pub speaker: String,
+    pub response: StreamResponse,
+}
+
+pub struct StoredTranscript {
+    pub speakers: Vec<String>,
+    pub responses: Vec<StreamResponse>,
+}
+
+/// A persisted bot session: who sent the bot, and whether its call ended.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BotSession {
+    pub user_id: String,
+    pub ended: bool,
+}
+
+/// Where bot sessions and their final transcript segments live, so they
+/// outlast the process and are seen by every instance.
+pub trait BotSessionStore: Send + Sync {
+    /// Records the session unless the bot already has one.
+    fn create<'a>(&'a self, bot_id: &'a str, user_id: &'a str) -> BoxFuture<'a, Result<()>>;
+
+    fn session<'a>(&'a self, bot_id: &'a str) -> BoxFuture<'a, Result<Option<BotSession>>>;
+
+    fn append<'a>(
+        &'a self,
+        bot_id: &'a str,
+        segment: &'a TranscriptSegment,
+    ) -> BoxFuture<'a, Result<()>>;
+
+    /// Marks the call as ended; does nothing if it already is.
+    fn finish<'a>(&'a self, bot_id: &'a str) -> BoxFuture<'a, Result<()>>;
+
+    /// Segments stored after sequence number `after`, in order, each with
+    /// its sequence number.
+    fn segments<'a>(
+        &'a self,
+        bot_id: &'a str,
+        after: Option<i64>,
+    ) -> BoxFuture<'a, Result<Vec<(i64, TranscriptSegment)>>>;
+}
+
+struct MemorySession {
+    user_id: String,
+    ended_at: Option<Instant>,
+    segments: Vec<TranscriptSegment>,
+}
+
+/// Keeps sessions in memory until a day after the call ends.
+#[derive(Default)]
+pub struct InMemoryBotSessionStore {
+    sessions: Mutex<HashMap<String, MemorySession>>,
+}
+
+impl BotSessionStore for InMemoryBotSessionStore {
+    fn create<'a>(&'a self, bot_id: &'a str, user_id: &'a str) -> BoxFuture<'a, Result<()>> {
+        let mut sessions = self.sessions.lock().unwrap();
+        sessions.retain(|_, session| {
+            session
+                .ended_at
+                .is_none_or(|ended_at| ended_at.elapsed() < ENDED_RETENTION)
+        });
+        sessions
+            .entry(bot_id.to_string())
+            .or_insert_with(|| MemorySession {
+                user_id: user_id.to_string(),
+                ended_at: None,
+                segments: Vec::new(),
+            });
+        Box::pin(async { Ok(()) })
+    }
+
+    fn session<'a>(&'a self, bot_id: &'a str) -> BoxFuture<'a, Result<Option<BotSession>>> {
+        let session = self
+            .sessions
+            .lock()
+            .unwrap()
+            .get(bot_id)
+            .map(|session| BotSession {
+                user_id: session.user_id.clone(),
+                ended: session.ended_at.is_some(),
+            });
+        Box::pin(async move { Ok(session) })
+    }
+
+    fn append<'a>(
+        &'a self,
+        bot_id: &'a str,
+        segment: &'a TranscriptSegment,
+    ) -> BoxFuture<'a, Result<()>> {
+        if let Some(session) = self.sessions.lock().unwrap().get_mut(bot_id) {
+            session.segments.push(segment.clone());
+        }
+        Box::pin(async { Ok(()) })
+    }
+
+    fn finish<'a>(&'a self, bot_id: &'a str) -> BoxFuture<'a, Result<()>> {
+        if let Some(session) = self.sessions.lock().unwrap().get_mut(bot_id) {
+            session.ended_at.get_or_insert_with(Instant::now);
+        }
+        Box::pin(async { Ok(()) })
+    }
+
+    fn segments<'a>(
+        &'a self,
+        bot_id: &'a str,
+        after: Option<i64>,
+    ) -> BoxFuture<'a, Result<Vec<(i64, TranscriptSegment)>>> {
+        let skip = after.map_or(0, |after| after + 1) as usize;
+        let segments = self
+            .sessions
+            .lock()
+            .unwrap()
+            .get(bot_id)
+            .map(|session| {
+                session
+                    .segments
+                    .iter()
+                    .enumerate()
+                    .skip(skip)
+                    .map(|(seq, segment)| (seq as i64, segment.clone()))
+                    .collect()
+            })
+            .unwrap_or_default();
+        Box::pin(async move { Ok(segments) })
+    }
+}
+
+/// Numbers speakers in order of first appearance, the same way for every
+/// reader of a session.
+#[derive(Default)]
+struct Speakers(Vec<String>);
+
+impl Speakers {
+    fn event(&mut self, segment: &TranscriptSegment) -> Option<TranscriptEvent> {
+        let index = match self.0.iter().position(|s| *s == segment.speaker) {
+            Some(index) => index,
+            None => {
+                self.0.push(segment.speaker.clone());
+                self.0.len() - 1
+            }
+        };
+
+        Some(TranscriptEvent {
+            speaker: segment.speaker.clone(),
+            response: to_stream_response(segment, index as i32)?,
+        })
+    }
+}
+
+/// Bot sessions keyed by Recall bot ID, each owned by the user who sent
+/// the bot. Sessions and final segments are kept in a [`BotSessionStore`];
+/// only the signals that wake local subscribers live in memory.
+#[derive(Clone)]
+pub struct TranscriptStore {
+    sessions: Arc<dyn BotSessionStore>,
+    wakers: Arc<Mutex<HashMap<String, broadcast::Sender<()>>>>,
+}
+
+impl Default for TranscriptStore {
+    fn default() -> Self {
+        Self::new(Arc::new(InMemoryBotSessionStore::default()))
+    }
+}
+
+impl TranscriptStore {
+    pub fn new(sessions: Arc<dyn BotSessionStore>) -> Self {
+        Self {
+            sessions,
+            wakers: Arc::default(),
+        }
+    }
+
+    pub async fn register(&self, bot_id: &str, user_id: &str) -> Result<()> {
+        self.sessions.create(bot_id, user_id).await
+    }
+
+    async fn owned_session(&self, bot_id: &str, user_id: &str) -> Result<BotSession> {
+        self.sessions
+            .session(bot_id)
+            .await?
+            .filter(|session| session.user_id == user_id)
+            .ok_or_else(|| BotError::NotFound(format!("bot {bot_id}")))
+    }
+
+    /// Fails with `NotFound` for unknown bots and for bots owned by someone
+    /// else, so callers can't probe for other users' bot IDs.
+    pub async fn authorize(&self, bot_id: &str, user_id: &str) -> Result<()> {
+        self.owned_session(bot_id, user_id).await.map(|_| ())
+    }
+
+    /// Returns `false` if the bot has no session. Only final segments are
+    /// kept; bots are sent without partial results.
+    pub async fn append(&self, bot_id: &str, segment: &TranscriptSegment) -> Result<bool> {
+        if self.sessions.session(bot_id).await?.is_none() {
+            return Ok(false);
+        }
+
+        if segment.is_final {
+            self.sessions.append(bot_id, segment).await?;
+            self.wake(bot_id);
+        }
+
+        Ok(true)
+    }
+
+    pub async fn finish(&self, bot_id: &str) -> Result<()> {
+        self.sessions.finish(bot_id).await?;
+        self.wake(bot_id);
+        self.wakers.lock().unwrap().remove(bot_id);
+        Ok(())
+    }
+
+    pub async fn subscribe(&self, bot_id: &str, user_id: &str) -> Result<Subscription> {
+        // Subscribed before the first read, so nothing stored after it is
+        // missed until the next poll.
+        let wake = self.waker(bot_id).subscribe();
+        self.owned_session(bot_id, user_id).await?;
+
+        Ok(Subscription {
+            store: self.clone(),
+            bot_id: bot_id.to_string(),
+            wake,
+            speakers: Speakers::default(),
+            cursor: None,
+            ended: false,
+        })
+    }
+
+    pub async fn transcript(&self, bot_id: &str, user_id: &str) -> Result<StoredTranscript> {
+        let session = self.owned_session(bot_id, user_id).await?;
+        if !session.ended {
+            return Err(BotError::Conflict(format!(
+                "bot {bot_id} is still in the call"
+            )));
+        }
+
+        let mut speakers = Speakers::default();
+        let responses = self
+            .sessions
+            .segments(bot_id, None)
+            .await?
+            .iter()
+            .filter_map(|(_, segment)| speakers.event(segment))
+            .map(|event| event.response)
+            .collect();
+
+        Ok(StoredTranscript {
+            speakers: speakers.0,
+            responses,
+        })
+    }
+
+    fn waker(&self, bot_id: &str) -> broadcast::Sender<()> {
+        let mut wakers = self.wakers.lock().unwrap();
+        wakers.retain(|_, sender| sender.receiver_count() > 0);
+        wakers
+            .entry(bot_id.to_string())
+            .or_insert_with(|| broadcast::channel(WAKE_BUFFER).0)
+            .clone()
+    }
+
+    fn wake(&self, bot_id: &str) {
+        if let Some(sender) = self.wakers.lock().unwrap().get(bot_id) {
+            // No receivers just means the desktop isn't listening right now.
+            let _ = sender.send(());
+        }
+    }
+}
+
+/// Follows one session's transcript from the start: segments that reach
+/// this instance wake it right away, others are picked up by polling.
+pub struct Subscription {
+    store: TranscriptStore,
+    bot_id: String,
+    wake: broadcast::Receiver<()>,
+    speakers: Speakers,
+    cursor: Option<i64>,
+    ended: bool,
+}
+
+impl Subscription {
+    /// Waits for the next segments. Returns `None` once the call ended and
+    /// everything stored for it was returned.
+    pub async fn next(&mut self) -> Option<Vec<TranscriptEvent>> {
+        loop {
+            if self.ended {
+                return None;
+            }
+
+            match self.poll().await {
+                Ok(events) if !events.is_empty() => return Some(events),
+                Ok(_) => {}
+                Err(e) => {
+                    tracing::warn!(bot_id = %self.bot_id, error = %e, "transcript_events_unavailable");
+                }
+            }
+            if self.ended {
+                return None;
+            }
+
+            if let Ok(Err(broadcast::error::RecvError::Closed)) =
+                tokio::time::timeout(POLL_INTERVAL, self.wake.recv()).await
+            {
+                tokio::time::sleep(POLL_INTERVAL).await;
+            }
+        }
+    }
+
+    async fn poll(&mut self) -> Result<Vec<TranscriptEvent>> {
+        // Checked before reading segments, so once the call is seen as
+        // ended every segment stored before that is in the read below.
+        let ended = self
+            .store
+            .sessions
+            .session(&self.bot_id)
+            .await?
+            .is_none_or(|session| session.ended);
+
+        let mut events = Vec::new();
+        for (seq, segment) in self
+            .store
+            .sessions
+            .segments(&self.bot_id, self.cursor)
+            .await?
+        {
+            self.cursor = Some(seq);
+            events.extend(self.speakers.event(&segment));
+        }
+
+        self.ended = ended;
+        Ok(events)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn segment(speaker: &str, text: &str, start: f64) -> TranscriptSegment {
+        serde_json::from_value(serde_json::json!({
+            "speaker": speaker,
+            "is_final": true,
+            "original_transcript_id": null,
+            "words": [{"text": text, "start_time": start, "end_time": start + 0.5}],
+        }))
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_sessions_are_scoped_to_owner() {
+        let store = TranscriptStore::default();
+        store.register("bot_1", "user_a").await.unwrap();
+
+        assert!(store.authorize("bot_1", "user_a").await.is_ok());
+        assert!(matches!(
+            store.authorize("bot_1", "user_b").await,
+            Err(BotError::NotFound(_))
+        ));
+        assert!(matches!(
+            store.subscribe("bot_2", "user_a").await,
+            Err(BotError::NotFound(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_transcript_available_after_finish() {
+        let store = TranscriptStore::default();
+        store.register("bot_1", "user_a").await.unwrap();
+
+        assert!(
+            store
+                .append("bot_1", &segment("Alice", "hi", 0.0))
+                .await
+                .unwrap()
+        );
+        assert!(
+            store
+                .append("bot_1", &segment("Bob", "hey", 1.0))
+                .await
+                .unwrap()
+        );
+        assert!(
+            store
+                .append("bot_1", &segment("Alice", "bye", 2.0))
+                .await
+                .unwrap()
+        );
+        assert!(
+            !store
+                .append("bot_2", &segment("Alice", "lost", 0.0))
+                .await
+                .unwrap()
+        );
+
+        assert!(matches!(
+            store.transcript("bot_1", "user_a").await,
+            Err(BotError::Conflict(_))
+        ));
+
+        store.finish("bot_1").await.unwrap();
+        let transcript = store.transcript("bot_1", "user_a").await.unwrap();
+        assert_eq!(transcript.speakers, vec!["Alice", "Bob"]);
+        assert_eq!(transcript.responses.len(), 3);
+
+        let mut subscription = store.subscribe("bot_1", "user_a").await.unwrap();
+        assert_eq!(subscription.next().await.unwrap().len(), 3);
+        assert!(subscription.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_instances_share_sessions() {
+        let sessions: Arc<dyn BotSessionStore> = Arc::new(InMemoryBotSessionStore::default());
+        let receiving = TranscriptStore::new(sessions.clone());
+        let serving = TranscriptStore::new(sessions);
+
+        receiving.register("bot_1", "user_a").await.unwrap();
+        let mut subscription = serving.subscribe("bot_1", "user_a").await.unwrap();
+
+        receiving
+            .append("bot_1", &segment("Alice", "hi", 0.0))
+            .await
+            .unwrap();
+        let events = subscription.next().await.unwrap();
+        assert_eq!(events[0].speaker, "Alice");
+
+        receiving.finish("bot_1").await.unwrap();
+        assert!(subscription.next().await.is_none());
+        assert!(serving.transcript("bot_1", "user_a").await.is_ok());
+    }
+}
@@ -0,0 +1,224 @@
+use chrono::{DateTime, Utc};
+use futures_util::future::BoxFuture;
+use hypr_recall::TranscriptSegment;
+use reqwest::Method;
+use serde::Deserialize;
+
+use super::{BotSession, BotSessionStore};
+use crate::error::Result;
+use crate::supabase::SupabaseRest;
+
+const SESSIONS_TABLE: &str = "bot_sessions";
+const SEGMENTS_TABLE: &str = "bot_transcript_segments";
+
+#[derive(Deserialize)]
+struct SessionRow {
+    user_id: String,
+    ended_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Deserialize)]
+struct SegmentRow {
+    id: i64,
+    segment: TranscriptSegment,
+}
+
+/// Keeps bot sessions in `bot_sessions` and their final segments in
+/// `bot_transcript_segments`, using the service role since webhooks carry
+/// no user token.
+#[derive(Clone)]
+pub struct SupabaseBotSessionStore {
+    rest: SupabaseRest,
+}
+
+impl SupabaseBotSessionStore {
+    pub fn new(supabase_url: &str, service_role_key: &str) -> Self {
+        Self {
+            rest: SupabaseRest::new(supabase_url, service_role_key),
+        }
+    }
+}
+
+fn bot_filter(bot_id: &str) -> String {
+    format!("bot_id=eq.{}", urlencoding::encode(bot_id))
+}
+
+impl BotSessionStore for SupabaseBotSessionStore {
+    fn create<'a>(&'a self, bot_id: &'a str, user_id: &'a str) -> BoxFuture<'a, Result<()>> {
+        Box::pin(async move {
+            self.rest
+                .send(
+                    self.rest
+                        .request(Method::POST, SESSIONS_TABLE, "")
+                        .header("Prefer", "resolution=ignore-duplicates,return=minimal")
+                        .json(&serde_json::json!({ "bot_id": bot_id, "user_id": user_id })),
+                    "create bot session",
+                )
+                .await?;
+            Ok(())
+        })
+    }
+
+    fn session<'a>(&'a self, bot_id: &'a str) -> BoxFuture<'a, Result<Option<BotSession>>> {
+        Box::pin(async move {
+            let query = format!("{}&select=user_id,ended_at", bot_filter(bot_id));
+            let rows: Vec<SessionRow> = self
+                .rest
+                .rows(
+                    self.rest.request(Method::GET, SESSIONS_TABLE, &query),
+                    "read bot session",
+                )
+                .await?;
+
+            Ok(rows.into_iter().next().map(|row| BotSession {
+                user_id: row.user_id,
+                ended: row.ended_at.is_some(),
+            }))
+        })
+    }
+
+    fn append<'a>(
+        &'a self,
+        bot_id: &'a str,
+        segment: &'a TranscriptSegment,
+    ) -> BoxFuture<'a, Result<()>> {
+        Box::pin(async move {
+            self.rest
+                .send(
+                    self.rest
+                        .request(Method::POST, SEGMENTS_TABLE, "")
+                        .header("Prefer", "return=minimal")
+                        .json(&serde_json::json!({ "bot_id": bot_id, "segment": segment })),
+                    "store transcript segment",
+                )
+                .await?;
+            Ok(())
+        })
+    }
+
+    fn finish<'a>(&'a self, bot_id: &'a str) -> BoxFuture<'a, Result<()>> {
+        Box::pin(async move {
+            let query = format!("{}&ended_at=is.null", bot_filter(bot_id));
+            self.rest
+                .send(
+                    self.rest
+                        .request(Method::PATCH, SESSIONS_TABLE, &query)
+                        .header("Prefer", "return=minimal")
+                        .json(&serde_json::json!({ "ended_at": Utc::now() })),
+                    "end bot session",
+                )
+                .await?;
+            Ok(())
+        })
+    }
+
+    fn segments<'a>(
+        &'a self,
+        bot_id: &'a str,
+        after: Option<i64>,
+    ) -> BoxFuture<'a, Result<Vec<(i64, TranscriptSegment)>>> {
+        Box::pin(async move {
+            let mut query = format!("{}&select=id,segment&order=id.asc", bot_filter(bot_id));
+            if let Some(after) = after {
+                query.push_str(&format!("&id=gt.{after}"));
+            }
+
+            let rows: Vec<SegmentRow> = self
+                .rest
+                .rows(
+                    self.rest.request(Method::GET, SEGMENTS_TABLE, &query),
+                    "read transcript segments",
+                )
+                .await?;
+            Ok(rows.into_iter().map(|row| (row.id, row.segment)).collect())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wiremock::matchers::{body_partial_json, header, method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use super::*;
+
+    fn segment() -> serde_json::Value {
+        serde_json::json!({
+            "speaker": "Alice Chen",
+            "is_final": true,
+            "original_transcript_id": 1,
+            "words": [{ "text": "Morning", "start_time": 12.48, "end_time": 12.91 }],
+        })
+    }
+
+    #[tokio::test]
+    async fn test_session_round_trip() {
+        let server = MockServer::start().await;
+        let store = SupabaseBotSessionStore::new(&server.uri(), "service-key");
+
+        Mock::given(method("POST"))
+            .and(path("/rest/v1/bot_sessions"))
+            .and(header("apikey", "service-key"))
+            .and(header(
+                "Prefer",
+                "resolution=ignore-duplicates,return=minimal",
+            ))
+            .and(body_partial_json(
+                serde_json::json!({ "bot_id": "bot_1", "user_id": "user_a" }),
+            ))
+            .respond_with(ResponseTemplate::new(201))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/rest/v1/bot_sessions"))
+            .and(query_param("bot_id", "eq.bot_1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                { "user_id": "user_a", "ended_at": "2026-03-04T15:30:00Z" },
+            ])))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/rest/v1/bot_transcript_segments"))
+            .and(body_partial_json(
+                serde_json::json!({ "bot_id": "bot_1", "segment": segment() }),
+            ))
+            .respond_with(ResponseTemplate::new(201))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("PATCH"))
+            .and(path("/rest/v1/bot_sessions"))
+            .and(query_param("ended_at", "is.null"))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/rest/v1/bot_transcript_segments"))
+            .and(query_param("id", "gt.6"))
+            .and(query_param("order", "id.asc"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                { "id": 7, "segment": segment() },
+            ])))
+            .mount(&server)
+            .await;
+
+        let segment: TranscriptSegment = serde_json::from_value(segment()).unwrap();
+        store.create("bot_1", "user_a").await.unwrap();
+        store.append("bot_1", &segment).await.unwrap();
+        store.finish("bot_1").await.unwrap();
+
+        assert_eq!(
+            store.session("bot_1").await.unwrap(),
+            Some(BotSession {
+                user_id: "user_a".to_string(),
+                ended: true,
+            })
+        );
+        let segments = store.segments("bot_1", Some(6)).await.unwrap();
+        assert_eq!(segments.len(), 1);
+        assert_eq!(segments[0].0, 7);
+        assert_eq!(segments[0].1.speaker, "Alice Chen");
+    }
+}
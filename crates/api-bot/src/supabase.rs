@@ -0,0 +1,68 @@
+use reqwest::{Method, RequestBuilder, Response};
+use serde::de::DeserializeOwned;
+
+use crate::error::{BotError, Result};
+
+/// PostgREST access with the service role, for the stores that keep bot
+/// state in Supabase so it outlasts the process and is shared by every
+/// instance.
+#[derive(Clone)]
+pub(crate) struct SupabaseRest {
+    client: reqwest::Client,
+    base_url: String,
+    service_role_key: String,
+}
+
+impl SupabaseRest {
+    pub fn new(supabase_url: &str, service_role_key: &str) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            base_url: supabase_url.trim_end_matches('/').to_string(),
+            service_role_key: service_role_key.to_string(),
+        }
+    }
+
+    pub fn request(&self, method: Method, table: &str, query: &str) -> RequestBuilder {
+        let mut url = format!("{}/rest/v1/{table}", self.base_url);
+        if !query.is_empty() {
+            url.push('?');
+            url.push_str(query);
+        }
+
+        self.client
+            .request(method, url)
+            .header("Authorization", format!("Bearer {}", self.service_role_key))
+            .header("apikey", &self.service_role_key)
+    }
+
+    /// Sends `builder`, turning an error status into `BotError::Internal`
+    /// that says what failed to `action`.
+    pub async fn send(&self, builder: RequestBuilder, action: &str) -> Result<Response> {
+        let response = builder
+            .send()
+            .await
+            .map_err(|e| BotError::Internal(e.to_string()))?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            return Err(BotError::Internal(format!(
+                "failed to {action}: {status} {body}"
+            )));
+        }
+
+        Ok(response)
+    }
+
+    pub async fn rows<T: DeserializeOwned>(
+        &self,
+        builder: RequestBuilder,
+        action: &str,
+    ) -> Result<Vec<T>> {
+        self.send(builder.header("Accept", "application/json"), action)
+            .await?
+            .json()
+            .await
+            .map_err(|e| BotError::Internal(e.to_string()))
+    }
+}
@@ -0,0 +1,141 @@
+use hypr_recall::TranscriptSegment;
+use owhisper_interface::{batch, stream};
+
+/// Converts one Recall transcript delivery into the Deepgram-shaped
+/// response the desktop app already consumes. Recall sends a single
+/// speaker's words per delivery, so every word gets the same `speaker`
+/// index; the names behind the indices are tracked by the caller.
+pub(crate) fn to_stream_response(
+    segment: &TranscriptSegment,
+    speaker: i32,
+) -> Option<stream::StreamResponse> {
+    let first = segment.words.first()?;
+    let last = segment.words.last()?;
+
+    let words: Vec<stream::Word> = segment
+        .words
+        .iter()
+        .map(|w| stream::Word {
+            word: w.text.trim().to_string(),
+            start: w.start_time,
+            end: w.end_time,
+            confidence: 1.0,
+            speaker: Some(speaker),
+            punctuated_word: Some(w.text.trim().to_string()),
+            language: None,
+        })
+        .collect();
+
+    let transcript = words
+        .iter()
+        .map(|w| w.word.as_str())
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    Some(stream::StreamResponse::TranscriptResponse {
+        start: first.start_time,
+        duration: (last.end_time - first.start_time).max(0.0),
+        is_final: segment.is_final,
+        speech_final: segment.is_final,
+        from_finalize: false,
+        channel: stream::Channel {
+            alternatives: vec![stream::Alternatives {
+                transcript,
+                words,
+                confidence: 1.0,
+                languages: vec![],
+            }],
+        },
+        metadata: stream::Metadata::default(),
+        channel_index: vec![0, 1],
+    })
+}
+
+/// Merges the final responses of a call into a single-channel batch
+/// response, ordered by start time.
+pub(crate) fn to_batch_response(responses: &[stream::StreamResponse]) -> batch::Response {
+    let mut words: Vec<batch::Word> = responses
+        .iter()
+        .filter_map(|response| match response {
+            stream::StreamResponse::TranscriptResponse { channel, .. } => {
+                channel.alternatives.first()
+            }
+            _ => None,
+        })
+        .flat_map(|alternatives| alternatives.words.iter().cloned().map(batch::Word::from))
+        .collect();
+    words.sort_by(|a, b| a.start.total_cmp(&b.start));
+
+    let transcript = words
+        .iter()
+        .map(|w| w.word.as_str())
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    batch::Response {
+        metadata: serde_json::json!({}),
+        results: batch::Results {
+            channels: vec![batch::Channel {
+                alternatives: vec![batch::Alternatives {
+                    transcript,
+                    confidence: 1.0,
+                    words,
+                }],
+            }],
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn segment(speaker: &str, words: &[(&str, f64, f64)]) -> TranscriptSegment {
+        serde_json::from_value(serde_json::json!({
+            "speaker": speaker,
+            "is_final": true,
+            "original_transcript_id": null,
+            "words": words
+                .iter()
+                .map(|(text, start, end)| {
+                    serde_json::json!({"text": text, "start_time": start, "end_time": end})
+                })
+                .collect::<Vec<_>>(),
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn stream_response_is_deepgram_shaped() {
+        let response = to_stream_response(
+            &segment("Alice", &[("Hello", 1.0, 1.4), ("all", 1.5, 1.9)]),
+            2,
+        )
+        .unwrap();
+
+        let json = serde_json::to_value(&response).unwrap();
+        assert_eq!(json["type"], "Results");
+        assert_eq!(
+            json["channel"]["alternatives"][0]["transcript"],
+            "Hello all"
+        );
+        assert_eq!(json["channel"]["alternatives"][0]["words"][1]["speaker"], 2);
+        assert_eq!(json["start"], 1.0);
+
+        assert!(to_stream_response(&segment("Alice", &[]), 0).is_none());
+    }
+
+    #[test]
+    fn batch_response_orders_words() {
+        let responses = vec![
+            to_stream_response(&segment("Bob", &[("second", 3.0, 3.5)]), 1).unwrap(),
+            to_stream_response(&segment("Alice", &[("first", 1.0, 1.5)]), 0).unwrap(),
+        ];
+
+        let batch = to_batch_response(&responses);
+        let alternatives = &batch.results.channels[0].alternatives[0];
+        assert_eq!(alternatives.transcript, "first second");
+        assert_eq!(alternatives.words[0].speaker, Some(0));
+        assert_eq!(alternatives.words[1].speaker, Some(1));
+    }
+}
@@ -0,0 +1,311 @@
+mod common;
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use api_bot::{
+    AutoJoinRule, AutoJoinScheduler, AutoJoinSettings, BotError, CalendarEvent, CalendarSource,
+    Clock,
+};
+use axum::{body::Body, extract::Request, http::StatusCode};
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use futures_util::future::BoxFuture;
+
+use common::harness::{BOT_ID, TestHarness, USER_HEADER};
+
+#[derive(Clone)]
+struct MockClock(Arc<Mutex<DateTime<Utc>>>);
+
+impl MockClock {
+    fn at(hour: u32, minute: u32) -> Self {
+        Self(Arc::new(Mutex::new(time(hour, minute))))
+    }
+
+    fn set(&self, hour: u32, minute: u32) {
+        *self.0.lock().unwrap() = time(hour, minute);
+    }
+}
+
+impl Clock for MockClock {
+    fn now(&self) -> DateTime<Utc> {
+        *self.0.lock().unwrap()
+    }
+}
+
+#[derive(Clone, Default)]
+struct MockCalendar {
+    events: Arc<Mutex<HashMap<String, Vec<CalendarEvent>>>>,
+    failing: Arc<Mutex<bool>>,
+}
+
+impl MockCalendar {
+    fn set(&self, calendar_id: &str, events: Vec<CalendarEvent>) {
+        self.events
+            .lock()
+            .unwrap()
+            .insert(calendar_id.to_string(), events);
+    }
+
+    fn fail(&self, failing: bool) {
+        *self.failing.lock().unwrap() = failing;
+    }
+}
+
+impl CalendarSource for MockCalendar {
+    fn list_events<'a>(
+        &'a self,
+        _user_id: &'a str,
+        calendar_id: &'a str,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> BoxFuture<'a, Result<Vec<CalendarEvent>, BotError>> {
+        Box::pin(async move {
+            if *self.failing.lock().unwrap() {
+                return Err(BotError::Internal("calendar unavailable".into()));
+            }
+            Ok(self
+                .events
+                .lock()
+                .unwrap()
+                .get(calendar_id)
+                .into_iter()
+                .flatten()
+                .filter(|e| e.end > from && e.start < to)
+                .cloned()
+                .collect())
+        })
+    }
+}
+
+fn time(hour: u32, minute: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 3, 4, hour, minute, 0).unwrap()
+}
+
+fn event(id: &str, title: &str, hour: u32) -> CalendarEvent {
+    CalendarEvent {
+        id: id.to_string(),
+        calendar_id: "work".to_string(),
+        series_id: format!("{id}@google.com"),
+        title: title.to_string(),
+        start: time(hour, 0),
+        end: time(hour, 0) + Duration::minutes(30),
+        original_start: None,
+        organizer_email: Some("lead@acme.com".to_string()),
+        attending: true,
+        cancelled: false,
+        meeting_url: Some("https://meet.google.com/abc-defg-hij".to_string()),
+    }
+}
+
+fn settings(rules: Vec<AutoJoinRule>) -> AutoJoinSettings {
+    AutoJoinSettings {
+        enabled: true,
+        bot_name: Some("Notetaker".to_string()),
+        rules,
+    }
+}
+
+fn rule() -> AutoJoinRule {
+    AutoJoinRule {
+        calendar_id: "work".to_string(),
+        organizer_domains: vec![],
+        keywords: vec![],
+        only_if_not_attending: false,
+        minutes_before: 2,
+    }
+}
+
+fn scheduler(
+    harness: &TestHarness,
+    calendar: &MockCalendar,
+    clock: &MockClock,
+) -> AutoJoinScheduler {
+    AutoJoinScheduler::new(
+        harness.state.auto_join_rules(),
+        Arc::new(calendar.clone()),
+        Arc::new(harness.state.dispatcher()),
+    )
+    .with_clock(Arc::new(clock.clone()))
+}
+
+#[tokio::test]
+async fn test_joins_once_before_start_and_leaves_on_cancel() {
+    let harness = TestHarness::new().await;
+    harness
+        .state
+        .auto_join_rules()
+        .set("user_a", settings(vec![rule()]))
+        .await
+        .unwrap();
+
+    let calendar = MockCalendar::default();
+    let clock = MockClock::at(14, 50);
+    let scheduler = scheduler(&harness, &calendar, &clock);
+
+    // One recurring instance, listed twice as on a shared calendar.
+    let mut instance = event("weekly_20260304", "Weekly sync", 15);
+    instance.series_id = "weekly@google.com".to_string();
+    let mut copy = instance.clone();
+    copy.id = "weekly_copy".to_string();
+    calendar.set("work", vec![instance.clone(), copy]);
+
+    scheduler.tick().await;
+    assert_eq!(harness.recall_requests("/bot").await, 0);
+
+    clock.set(14, 58);
+    scheduler.tick().await;
+    clock.set(14, 59);
+    scheduler.tick().await;
+    assert_eq!(harness.recall_requests("/bot").await, 1);
+
+    let create = &harness.recall.received_requests().await.unwrap()[0];
+    let body: serde_json::Value = serde_json::from_slice(&create.body).unwrap();
+    assert_eq!(body["bot_name"], "Notetaker");
+    assert_eq!(body["metadata"]["user_id"], "user_a");
+
+    // The bot belongs to the user whose calendar it joined.
+    let response = harness
+        .get(&format!("/bot/{BOT_ID}/transcript"), "user_a")
+        .await;
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+
+    instance.cancelled = true;
+    calendar.set("work", vec![instance]);
+    scheduler.tick().await;
+    scheduler.tick().await;
+    assert_eq!(harness.recall_requests("/leave_call").await, 1);
+    assert_eq!(harness.recall_requests("/bot").await, 1);
+}
+
+#[tokio::test]
+async fn test_instances_share_claims() {
+    let harness = TestHarness::new().await;
+    harness
+        .state
+        .auto_join_rules()
+        .set("user_a", settings(vec![rule()]))
+        .await
+        .unwrap();
+
+    let calendar = MockCalendar::default();
+    let clock = MockClock::at(14, 59);
+    calendar.set("work", vec![event("standup", "Standup", 15)]);
+
+    // Two machines during a deploy, then a third after a restart.
+    let first = scheduler(&harness, &calendar, &clock);
+    let second = scheduler(&harness, &calendar, &clock);
+    first.tick().await;
+    second.tick().await;
+    scheduler(&harness, &calendar, &clock).tick().await;
+    assert_eq!(harness.recall_requests("/bot").await, 1);
+
+    // Whichever instance sees the cancellation first removes the bot.
+    calendar.set("work", vec![]);
+    second.tick().await;
+    first.tick().await;
+    assert_eq!(harness.recall_requests("/leave_call").await, 1);
+}
+
+#[tokio::test]
+async fn test_deleted_event_removes_bot_unless_calendar_fails() {
+    let harness = TestHarness::new().await;
+    harness
+        .state
+        .auto_join_rules()
+        .set("user_a", settings(vec![rule()]))
+        .await
+        .unwrap();
+
+    let calendar = MockCalendar::default();
+    let clock = MockClock::at(14, 59);
+    let scheduler = scheduler(&harness, &calendar, &clock);
+
+    calendar.set("work", vec![event("standup", "Standup", 15)]);
+    scheduler.tick().await;
+    assert_eq!(harness.recall_requests("/bot").await, 1);
+
+    calendar.set("work", vec![]);
+    calendar.fail(true);
+    scheduler.tick().await;
+    assert_eq!(harness.recall_requests("/leave_call").await, 0);
+
+    calendar.fail(false);
+    scheduler.tick().await;
+    assert_eq!(harness.recall_requests("/leave_call").await, 1);
+}
+
+#[tokio::test]
+async fn test_rules_filter_events() {
+    let harness = TestHarness::new().await;
+    harness
+        .state
+        .auto_join_rules()
+        .set(
+            "user_a",
+            settings(vec![AutoJoinRule {
+                keywords: vec!["customer".to_string()],
+                only_if_not_attending: true,
+                ..rule()
+            }]),
+        )
+        .await
+        .unwrap();
+
+    let calendar = MockCalendar::default();
+    let clock = MockClock::at(14, 59);
+    let scheduler = scheduler(&harness, &calendar, &clock);
+
+    let attending = event("a", "Customer call", 15);
+    let mut no_link = event("b", "Customer call", 15);
+    no_link.attending = false;
+    no_link.meeting_url = None;
+    let mut other_title = event("c", "Lunch", 15);
+    other_title.attending = false;
+    calendar.set("work", vec![attending, no_link, other_title]);
+
+    scheduler.tick().await;
+    assert_eq!(harness.recall_requests("/bot").await, 0);
+
+    let mut declined = event("d", "Customer call", 15);
+    declined.attending = false;
+    calendar.set("work", vec![declined]);
+    scheduler.tick().await;
+    assert_eq!(harness.recall_requests("/bot").await, 1);
+}
+
+#[tokio::test]
+async fn test_settings_routes() {
+    let harness = TestHarness::new().await;
+
+    let put = |body: serde_json::Value| {
+        Request::put("/auto-join")
+            .header(USER_HEADER, "user_a")
+            .header("content-type", "application/json")
+            .body(Body::from(body.to_string()))
+            .unwrap()
+    };
+
+    let invalid = serde_json::json!({
+        "enabled": true,
+        "rules": [{ "calendar_id": "work", "minutes_before": 120 }],
+    });
+    assert_eq!(
+        harness.send(put(invalid)).await.status(),
+        StatusCode::BAD_REQUEST
+    );
+
+    let valid = serde_json::json!({
+        "enabled": true,
+        "rules": [{ "calendar_id": "work", "keywords": ["sync"] }],
+    });
+    assert_eq!(harness.send(put(valid)).await.status(), StatusCode::OK);
+
+    let saved = harness.state.auto_join_rules().get("user_a").await.unwrap();
+    assert!(saved.enabled);
+    assert_eq!(saved.rules[0].minutes_before, 1);
+    assert_eq!(saved.rules[0].keywords, vec!["sync"]);
+    assert_eq!(
+        harness.state.auto_join_rules().get("user_b").await.unwrap(),
+        AutoJoinSettings::default()
+    );
+}
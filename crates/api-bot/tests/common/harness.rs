@@ -0,0 +1,174 @@
+#![allow(dead_code)]
+
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use api_bot::{BotConfig, BotState, InMemoryBotSessionStore};
+use axum::{
+    Router,
+    body::Body,
+    extract::Request,
+    http::{Response, StatusCode},
+    middleware::{self, Next},
+};
+use hypr_api_auth::{AuthContext, Claims};
+use tower::ServiceExt;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+pub const BOT_ID: &str = "8d1a6f2c-3b1e-4c3a-9a57-2f0f4c1d9e11";
+pub const WEBHOOK_SECRET: &str = "whsec_MfKQ9r8GKYqrTwjUPD8ILPZIo2LaLaSw";
+pub const USER_HEADER: &str = "x-test-user";
+
+pub fn fixture(name: &str) -> String {
+    let path = format!("{}/tests/fixtures/{name}", env!("CARGO_MANIFEST_DIR"));
+    std::fs::read_to_string(&path).unwrap_or_else(|e| panic!("{path}: {e}"))
+}
+
+/// Stands in for the auth middleware of the host app: the user comes
+/// from a plain header instead of a verified token.
+async fn fake_auth(mut req: Request, next: Next) -> axum::response::Response {
+    if let Some(user) = req.headers().get(USER_HEADER).and_then(|h| h.to_str().ok()) {
+        let claims: Claims = serde_json::from_value(serde_json::json!({ "sub": user })).unwrap();
+        req.extensions_mut().insert(AuthContext {
+            token: "test-token".to_string(),
+            claims,
+        });
+    }
+    next.run(req).await
+}
+
+pub struct TestHarness {
+    pub recall: MockServer,
+    pub state: BotState,
+    pub router: Router,
+    sessions: Arc<InMemoryBotSessionStore>,
+}
+
+fn build(recall: &MockServer, sessions: Arc<InMemoryBotSessionStore>) -> (BotState, Router) {
+    let config = BotConfig {
+        recall_api_key: "test-recall-key".to_string(),
+        recall_webhook_secret: WEBHOOK_SECRET.to_string(),
+        recall_api_base: Some(recall.uri()),
+        public_url: "https://api.example.com".to_string(),
+        demo_video_url: "https://example.com/demo.mp4".to_string(),
+    };
+    let state = BotState::new(config).unwrap().with_session_store(sessions);
+    let router = api_bot::router_with_state(state.clone())
+        .layer(middleware::from_fn(fake_auth))
+        .merge(api_bot::webhook_router_with_state(state.clone()));
+
+    (state, router)
+}
+
+impl TestHarness {
+    pub async fn new() -> Self {
+        let recall = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/bot"))
+            .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+                "id": BOT_ID,
+                "status": { "code": "ready", "message": null },
+                "metadata": {},
+            })))
+            .mount(&recall)
+            .await;
+        Mock::given(method("POST"))
+            .and(path(format!("/bot/{BOT_ID}/leave_call")))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&recall)
+            .await;
+
+        let sessions = Arc::new(InMemoryBotSessionStore::default());
+        let (state, router) = build(&recall, sessions.clone());
+
+        Self {
+            recall,
+            state,
+            router,
+            sessions,
+        }
+    }
+
+    /// Starts over with fresh in-process state, keeping what was persisted,
+    /// like a restart or another instance would.
+    pub fn restart(&mut self) {
+        (self.state, self.router) = build(&self.recall, self.sessions.clone());
+    }
+
+    /// Starts over without the persisted sessions either, like for bots
+    /// sent before sessions were stored.
+    pub fn forget_sessions(&mut self) {
+        self.sessions = Arc::new(InMemoryBotSessionStore::default());
+        self.restart();
+    }
+
+    pub async fn send(&self, req: Request<Body>) -> Response<Body> {
+        self.router.clone().oneshot(req).await.unwrap()
+    }
+
+    pub async fn send_bot(&self, user: &str) -> StatusCode {
+        let req = Request::post("/bot")
+            .header(USER_HEADER, user)
+            .header("content-type", "application/json")
+            .body(Body::from(
+                r#"{"meeting_url":"https://meet.google.com/abc-defg-hij"}"#,
+            ))
+            .unwrap();
+        self.send(req).await.status()
+    }
+
+    pub async fn get(&self, uri: &str, user: &str) -> Response<Body> {
+        let req = Request::get(uri)
+            .header(USER_HEADER, user)
+            .body(Body::empty())
+            .unwrap();
+        self.send(req).await
+    }
+
+    pub async fn deliver(&self, uri: &str, body: &str) -> StatusCode {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs()
+            .to_string();
+        self.deliver_signed(uri, body, &timestamp, WEBHOOK_SECRET)
+            .await
+    }
+
+    pub async fn deliver_signed(
+        &self,
+        uri: &str,
+        body: &str,
+        timestamp: &str,
+        secret: &str,
+    ) -> StatusCode {
+        let signature = hypr_recall::sign_webhook(secret, "msg_test", timestamp, body.as_bytes());
+        let req = Request::post(uri)
+            .header(hypr_recall::WEBHOOK_ID_HEADER, "msg_test")
+            .header(hypr_recall::WEBHOOK_TIMESTAMP_HEADER, timestamp)
+            .header(hypr_recall::WEBHOOK_SIGNATURE_HEADER, signature)
+            .header("content-type", "application/json")
+            .body(Body::from(body.to_string()))
+            .unwrap();
+        self.send(req).await.status()
+    }
+
+    pub async fn recall_requests(&self, suffix: &str) -> usize {
+        self.recall
+            .received_requests()
+            .await
+            .unwrap()
+            .iter()
+            .filter(|r| r.url.path().ends_with(suffix))
+            .count()
+    }
+}
+
+pub async fn body_string(response: Response<Body>) -> String {
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    String::from_utf8(bytes.to_vec()).unwrap()
+}
@@ -0,0 +1,278 @@
+mod common;
+
+use axum::{body::Body, extract::Request, http::StatusCode};
+use common::harness::{BOT_ID, TestHarness, body_string, fixture};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, ResponseTemplate};
+
+#[tokio::test]
+async fn test_transcript_after_call_ended() {
+    let harness = TestHarness::new().await;
+    assert_eq!(harness.send_bot("user_a").await, StatusCode::OK);
+
+    for name in ["transcript_alice.json", "transcript_bob.json"] {
+        let status = harness.deliver("/webhook/transcript", &fixture(name)).await;
+        assert_eq!(status, StatusCode::OK);
+    }
+
+    let uri = format!("/bot/{BOT_ID}/transcript");
+    let response = harness.get(&uri, "user_a").await;
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+
+    let status = harness
+        .deliver("/webhook", &fixture("status_call_ended.json"))
+        .await;
+    assert_eq!(status, StatusCode::OK);
+
+    let response = harness.get(&uri, "user_b").await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    let response = harness.get(&uri, "user_a").await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let body: serde_json::Value = serde_json::from_str(&body_string(response).await).unwrap();
+
+    assert_eq!(body["bot_id"], BOT_ID);
+    assert_eq!(
+        body["speakers"],
+        serde_json::json!(["Alice Chen", "Bob Okafor"])
+    );
+
+    let alternatives = &body["response"]["results"]["channels"][0]["alternatives"][0];
+    assert_eq!(
+        alternatives["transcript"],
+        "Morning everyone, shall we start? Sure, go ahead."
+    );
+    let words = alternatives["words"].as_array().unwrap();
+    assert_eq!(words.len(), 8);
+    assert_eq!(words[0]["speaker"], 0);
+    assert_eq!(words[0]["start"], 12.48);
+    assert_eq!(words[5]["speaker"], 1);
+}
+
+#[tokio::test]
+async fn test_events_stream_live_transcript() {
+    let harness = TestHarness::new().await;
+    assert_eq!(harness.send_bot("user_a").await, StatusCode::OK);
+
+    let status = harness
+        .deliver("/webhook/transcript", &fixture("transcript_alice.json"))
+        .await;
+    assert_eq!(status, StatusCode::OK);
+
+    let uri = format!("/bot/{BOT_ID}/events");
+    assert_eq!(
+        harness.get(&uri, "user_b").await.status(),
+        StatusCode::NOT_FOUND
+    );
+
+    let response = harness.get(&uri, "user_a").await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()["content-type"], "text/event-stream");
+
+    harness
+        .deliver("/webhook/transcript", &fixture("transcript_bob.json"))
+        .await;
+    harness
+        .deliver("/webhook", &fixture("status_call_ended.json"))
+        .await;
+
+    let body = body_string(response).await;
+    let events: Vec<&str> = body
+        .lines()
+        .filter_map(|line| line.strip_prefix("event: "))
+        .collect();
+    assert_eq!(events, vec!["transcript", "transcript", "ended"]);
+
+    let payloads: Vec<serde_json::Value> = body
+        .lines()
+        .filter_map(|line| line.strip_prefix("data: "))
+        .filter_map(|data| serde_json::from_str(data).ok())
+        .collect();
+    assert_eq!(payloads[0]["speaker"], "Alice Chen");
+    assert_eq!(payloads[1]["speaker"], "Bob Okafor");
+    assert_eq!(payloads[1]["response"]["type"], "Results");
+    assert_eq!(
+        payloads[1]["response"]["channel"]["alternatives"][0]["transcript"],
+        "Sure, go ahead."
+    );
+    assert_eq!(
+        payloads[1]["response"]["channel"]["alternatives"][0]["words"][0]["speaker"],
+        1
+    );
+}
+
+#[tokio::test]
+async fn test_webhook_signature_rejected() {
+    let harness = TestHarness::new().await;
+    assert_eq!(harness.send_bot("user_a").await, StatusCode::OK);
+    let body = fixture("transcript_alice.json");
+
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+
+    let status = harness
+        .deliver_signed(
+            "/webhook/transcript",
+            &body,
+            &now.to_string(),
+            "whsec_c29tZS1vdGhlci1zZWNyZXQ=",
+        )
+        .await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+    let status = harness
+        .deliver_signed(
+            "/webhook/transcript",
+            &body,
+            &(now - 3600).to_string(),
+            common::harness::WEBHOOK_SECRET,
+        )
+        .await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+    let req = Request::post("/webhook")
+        .header("content-type", "application/json")
+        .body(Body::from(fixture("status_call_ended.json")))
+        .unwrap();
+    assert_eq!(harness.send(req).await.status(), StatusCode::UNAUTHORIZED);
+
+    // None of the rejected deliveries may end the call.
+    let response = harness
+        .get(&format!("/bot/{BOT_ID}/transcript"), "user_a")
+        .await;
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn test_transcript_for_unknown_bot_is_acknowledged() {
+    let harness = TestHarness::new().await;
+
+    let status = harness
+        .deliver("/webhook/transcript", &fixture("transcript_alice.json"))
+        .await;
+    assert_eq!(status, StatusCode::OK);
+
+    let response = harness
+        .get(&format!("/bot/{BOT_ID}/transcript"), "user_a")
+        .await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_remove_bot_requires_owner() {
+    let harness = TestHarness::new().await;
+    assert_eq!(harness.send_bot("user_a").await, StatusCode::OK);
+
+    let remove = |user: &'static str| {
+        Request::delete(format!("/bot/{BOT_ID}"))
+            .header(common::harness::USER_HEADER, user)
+            .body(Body::empty())
+            .unwrap()
+    };
+
+    assert_eq!(
+        harness.send(remove("user_b")).await.status(),
+        StatusCode::NOT_FOUND
+    );
+    assert_eq!(
+        harness.send(remove("user_a")).await.status(),
+        StatusCode::OK
+    );
+
+    let requests = harness.recall.received_requests().await.unwrap();
+    let create = requests
+        .iter()
+        .find(|r| r.url.path() == "/bot")
+        .expect("bot created through mock Recall");
+    let body: serde_json::Value = serde_json::from_slice(&create.body).unwrap();
+    assert_eq!(body["metadata"]["user_id"], "user_a");
+    assert_eq!(
+        body["real_time_transcription"]["destination_url"],
+        "https://api.example.com/bot/webhook/transcript"
+    );
+    assert_eq!(
+        requests
+            .iter()
+            .filter(|r| r.url.path().ends_with("/leave_call"))
+            .count(),
+        1
+    );
+}
+
+#[tokio::test]
+async fn test_session_survives_restart() {
+    let mut harness = TestHarness::new().await;
+    assert_eq!(harness.send_bot("user_a").await, StatusCode::OK);
+    harness
+        .deliver("/webhook/transcript", &fixture("transcript_alice.json"))
+        .await;
+
+    harness.restart();
+    harness
+        .deliver("/webhook/transcript", &fixture("transcript_bob.json"))
+        .await;
+    harness
+        .deliver("/webhook", &fixture("status_call_ended.json"))
+        .await;
+
+    let response = harness
+        .get(&format!("/bot/{BOT_ID}/transcript"), "user_a")
+        .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let body: serde_json::Value = serde_json::from_str(&body_string(response).await).unwrap();
+    assert_eq!(
+        body["speakers"],
+        serde_json::json!(["Alice Chen", "Bob Okafor"])
+    );
+
+    let req = Request::delete(format!("/bot/{BOT_ID}"))
+        .header(common::harness::USER_HEADER, "user_a")
+        .body(Body::empty())
+        .unwrap();
+    assert_eq!(harness.send(req).await.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_unknown_session_falls_back_to_recall_metadata() {
+    let mut harness = TestHarness::new().await;
+    assert_eq!(harness.send_bot("user_a").await, StatusCode::OK);
+    harness.forget_sessions();
+
+    Mock::given(method("GET"))
+        .and(path(format!("/bot/{BOT_ID}")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": BOT_ID,
+            "status": { "code": "in_call_recording", "message": null },
+            "metadata": { "user_id": "user_a" },
+        })))
+        .mount(&harness.recall)
+        .await;
+
+    let remove = |user: &'static str| {
+        Request::delete(format!("/bot/{BOT_ID}"))
+            .header(common::harness::USER_HEADER, user)
+            .body(Body::empty())
+            .unwrap()
+    };
+    assert_eq!(
+        harness.send(remove("user_b")).await.status(),
+        StatusCode::NOT_FOUND
+    );
+    assert_eq!(
+        harness.send(remove("user_a")).await.status(),
+        StatusCode::OK
+    );
+
+    harness
+        .deliver("/webhook/transcript", &fixture("transcript_alice.json"))
+        .await;
+    harness
+        .deliver("/webhook", &fixture("status_call_ended.json"))
+        .await;
+    let response = harness
+        .get(&format!("/bot/{BOT_ID}/transcript"), "user_a")
+        .await;
+    assert_eq!(response.status(), StatusCode::OK);
+}
@@ -3,6 +3,7 @@ mod openapi;
 mod provider;
 mod providers;
 mod routes;
+mod sync;
 
 pub use openapi::openapi;
 pub use provider::CalendarConfig;
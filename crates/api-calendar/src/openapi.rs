@@ -7,17 +7,28 @@ use crate::routes::ListEventsResponse;
     paths(
         crate::routes::calendar::list_calendars,
         crate::routes::calendar::list_events,
+        crate::routes::calendar::sync_events,
         crate::routes::calendar::create_event,
+        crate::routes::calendar::update_event,
+        crate::routes::calendar::delete_event,
+        crate::routes::calendar::rsvp_event,
     ),
     components(
         schemas(
             crate::routes::calendar::ListCalendarsResponse,
             crate::routes::calendar::ListEventsRequest,
             ListEventsResponse,
+            crate::routes::calendar::SyncEventsRequest,
+            crate::routes::calendar::SyncEventsResponse,
             crate::routes::calendar::CreateEventRequest,
             crate::routes::calendar::CreateEventResponse,
             crate::routes::calendar::EventDateTime,
             crate::routes::calendar::EventAttendee,
+            crate::routes::calendar::UpdateEventRequest,
+            crate::routes::calendar::UpdateEventResponse,
+            crate::routes::calendar::DeleteEventRequest,
+            crate::routes::calendar::RsvpEventRequest,
+            crate::routes::calendar::RsvpResponse,
         )
     ),
     tags(
@@ -7,6 +7,7 @@ use hypr_api_nango::{GoogleCalendar, NangoConnection, NangoConnectionError, Outl
 use crate::error::CalendarError;
 use crate::providers::google::GoogleAdapter;
 use crate::providers::outlook::OutlookAdapter;
+use crate::sync::SyncEventsResult;
 
 pub struct ListCalendarsResult {
     pub calendars: Vec<serde_json::Value>,
@@ -21,6 +22,10 @@ pub struct CreateEventResult {
     pub event: serde_json::Value,
 }
 
+pub struct UpdateEventResult {
+    pub event: serde_json::Value,
+}
+
 #[derive(Clone, Default)]
 pub struct CalendarConfig {
     pub google: bool,
@@ -50,6 +55,16 @@ impl CalendarClient {
         }
     }
 
+    pub async fn sync_events(
+        &self,
+        req: crate::routes::calendar::SyncEventsRequest,
+    ) -> Result<SyncEventsResult, CalendarError> {
+        match self {
+            Self::Google(a) => a.sync_events(req).await,
+            Self::Outlook(a) => a.sync_events(req).await,
+        }
+    }
+
     pub async fn create_event(
         &self,
         req: crate::routes::calendar::CreateEventRequest,
@@ -59,6 +74,36 @@ impl CalendarClient {
             Self::Outlook(a) => a.create_event(req).await,
         }
     }
+
+    pub async fn update_event(
+        &self,
+        req: crate::routes::calendar::UpdateEventRequest,
+    ) -> Result<UpdateEventResult, CalendarError> {
+        match self {
+            Self::Google(a) => a.update_event(req).await,
+            Self::Outlook(a) => a.update_event(req).await,
+        }
+    }
+
+    pub async fn delete_event(
+        &self,
+        req: crate::routes::calendar::DeleteEventRequest,
+    ) -> Result<(), CalendarError> {
+        match self {
+            Self::Google(a) => a.delete_event(req).await,
+            Self::Outlook(a) => a.delete_event(req).await,
+        }
+    }
+
+    pub async fn rsvp_event(
+        &self,
+        req: crate::routes::calendar::RsvpEventRequest,
+    ) -> Result<(), CalendarError> {
+        match self {
+            Self::Google(a) => a.rsvp_event(req).await,
+            Self::Outlook(a) => a.rsvp_event(req).await,
+        }
+    }
 }
 
 impl<S: Send + Sync> FromRequestParts<S> for CalendarClient {
@@ -1,9 +1,19 @@
+use std::collections::HashSet;
+
 use hypr_google_calendar::GoogleCalendarClient;
 use hypr_nango::OwnedNangoHttpClient;
 
 use crate::error::CalendarError;
-use crate::provider::{CreateEventResult, ListCalendarsResult, ListEventsResult};
-use crate::routes::calendar::{CreateEventRequest, EventDateTime, ListEventsRequest};
+use crate::provider::{
+    CreateEventResult, ListCalendarsResult, ListEventsResult, UpdateEventResult,
+};
+use crate::routes::calendar::{
+    CreateEventRequest, DeleteEventRequest, EventAttendee, EventDateTime, ListEventsRequest,
+    RsvpEventRequest, RsvpResponse, SyncEventsRequest, UpdateEventRequest,
+};
+use crate::sync::{SyncEventsResult, SyncToken, apply_changes};
+
+const SYNC_PAGE_SIZE: u32 = 250;
 
 pub struct GoogleAdapter {
     client: GoogleCalendarClient<OwnedNangoHttpClient>,
@@ -97,6 +107,125 @@ impl GoogleAdapter {
         })
     }
 
+    /// Syncs with `singleEvents=false`, so series come back once and are
+    /// expanded here, together with the exceptions Google reports for them.
+    pub async fn sync_events(
+        &self,
+        req: SyncEventsRequest,
+    ) -> Result<SyncEventsResult, CalendarError> {
+        if let Some(cursor) =
+            SyncToken::cursor_for(req.sync_token.as_deref(), req.time_min, req.time_max)
+        {
+            match self.sync_changes(&req, Some(cursor)).await {
+                Err(hypr_google_calendar::Error::SyncTokenExpired) => {
+                    tracing::info!(calendar_id = %req.calendar_id, "google_sync_token_expired");
+                }
+                result => return result.map_err(|e| CalendarError::Internal(e.to_string())),
+            }
+        }
+
+        let mut result = self
+            .sync_changes(&req, None)
+            .await
+            .map_err(|e| CalendarError::Internal(e.to_string()))?;
+        result.full_sync = true;
+        result.replaced_series.clear();
+        Ok(result)
+    }
+
+    async fn sync_changes(
+        &self,
+        req: &SyncEventsRequest,
+        sync_token: Option<String>,
+    ) -> Result<SyncEventsResult, hypr_google_calendar::Error> {
+        let incremental = sync_token.is_some();
+        // Sync tokens can't be combined with a time range.
+        let (mut items, next_sync_token) = self
+            .list_all(hypr_google_calendar::ListEventsRequest {
+                calendar_id: req.calendar_id.clone(),
+                time_min: (!incremental).then_some(req.time_min),
+                time_max: (!incremental).then_some(req.time_max),
+                sync_token,
+                single_events: Some(false),
+                max_results: Some(SYNC_PAGE_SIZE),
+                ..Default::default()
+            })
+            .await?;
+
+        // A changed series is expanded again, which needs all of its
+        // exceptions, not just the ones that changed with it.
+        if incremental {
+            let uids: HashSet<String> = items
+                .iter()
+                .filter(|e| {
+                    e.recurrence.is_some()
+                        && !matches!(e.status, Some(hypr_google_calendar::EventStatus::Cancelled))
+                })
+                .filter_map(|e| e.ical_uid.clone())
+                .collect();
+            for uid in uids {
+                let (series, _) = self
+                    .list_all(hypr_google_calendar::ListEventsRequest {
+                        calendar_id: req.calendar_id.clone(),
+                        i_cal_uid: Some(uid),
+                        single_events: Some(false),
+                        max_results: Some(SYNC_PAGE_SIZE),
+                        ..Default::default()
+                    })
+                    .await?;
+                items.extend(series);
+            }
+        }
+
+        let mut seen = HashSet::new();
+        let mut changed = Vec::new();
+        let mut deleted = Vec::new();
+        for item in items {
+            if !seen.insert(item.id.clone()) {
+                continue;
+            }
+            // Deleted events and series; deleted occurrences still carry
+            // their series and original start.
+            if matches!(
+                item.status,
+                Some(hypr_google_calendar::EventStatus::Cancelled)
+            ) && item.recurring_event_id.is_none()
+            {
+                deleted.push(item.id);
+                continue;
+            }
+            changed.extend(item.to_calendar_event(&req.calendar_id));
+        }
+
+        let mut result = apply_changes(changed, deleted, req.time_min, req.time_max);
+        result.next_sync_token = next_sync_token.map(|cursor| {
+            SyncToken {
+                time_min: req.time_min,
+                time_max: req.time_max,
+                cursor,
+            }
+            .encode()
+        });
+        Ok(result)
+    }
+
+    /// Every page of `req`, and the sync token that comes with the last.
+    async fn list_all(
+        &self,
+        mut req: hypr_google_calendar::ListEventsRequest,
+    ) -> Result<(Vec<hypr_google_calendar::Event>, Option<String>), hypr_google_calendar::Error>
+    {
+        let mut items = Vec::new();
+        loop {
+            let response = self.client.list_events(req.clone()).await?;
+            items.extend(response.items);
+            match response.next_page_token {
+                Some(page_token) => req.page_token = Some(page_token),
+                None => return Ok((items, response.next_sync_token)),
+            }
+        }
+    }
+
     pub async fn create_event(
         &self,
         req: CreateEventRequest,
@@ -112,17 +241,7 @@ impl GoogleAdapter {
                 end,
                 description: req.description,
                 location: req.location,
-                attendees: req.attendees.map(|attendees| {
-                    attendees
-                        .into_iter()
-                        .map(|a| hypr_google_calendar::Attendee {
-                            email: Some(a.email),
-                            display_name: a.display_name,
-                            optional: a.optional,
-                            ..Default::default()
-                        })
-                        .collect()
-                }),
+                attendees: req.attendees.map(convert_attendees),
                 ..Default::default()
             },
         };
@@ -136,6 +255,79 @@ impl GoogleAdapter {
         let event = serde_json::to_value(event).unwrap_or_default();
         Ok(CreateEventResult { event })
     }
+
+    pub async fn update_event(
+        &self,
+        req: UpdateEventRequest,
+    ) -> Result<UpdateEventResult, CalendarError> {
+        let start = req
+            .start
+            .map(|dt| convert_event_datetime(dt, "start"))
+            .transpose()?;
+        let end = req
+            .end
+            .map(|dt| convert_event_datetime(dt, "end"))
+            .transpose()?;
+
+        let google_req = hypr_google_calendar::UpdateEventRequest {
+            calendar_id: req.calendar_id,
+            event_id: req.event_id,
+            event: hypr_google_calendar::UpdateEventBody {
+                summary: req.summary,
+                description: req.description,
+                location: req.location,
+                start,
+                end,
+                attendees: req.attendees.map(convert_attendees),
+            },
+            send_updates: Some(send_updates(req.notify_attendees)),
+        };
+
+        let event = self
+            .client
+            .update_event(google_req)
+            .await
+            .map_err(|e| CalendarError::Internal(e.to_string()))?;
+
+        let event = serde_json::to_value(event).unwrap_or_default();
+        Ok(UpdateEventResult { event })
+    }
+
+    pub async fn delete_event(&self, req: DeleteEventRequest) -> Result<(), CalendarError> {
+        self.client
+            .delete_event(hypr_google_calendar::DeleteEventRequest {
+                calendar_id: req.calendar_id,
+                event_id: req.event_id,
+                send_updates: Some(send_updates(req.notify_attendees)),
+            })
+            .await
+            .map_err(|e| CalendarError::Internal(e.to_string()))
+    }
+
+    pub async fn rsvp_event(&self, req: RsvpEventRequest) -> Result<(), CalendarError> {
+        let response = match req.response {
+            RsvpResponse::Accepted => hypr_google_calendar::AttendeeResponseStatus::Accepted,
+            RsvpResponse::Tentative => hypr_google_calendar::AttendeeResponseStatus::Tentative,
+            RsvpResponse::Declined => hypr_google_calendar::AttendeeResponseStatus::Declined,
+        };
+
+        self.client
+            .respond_to_event(hypr_google_calendar::RespondToEventRequest {
+                calendar_id: req.calendar_id,
+                event_id: req.event_id,
+                response,
+                comment: req.comment,
+                send_updates: Some(send_updates(req.notify_organizer)),
+            })
+            .await
+            .map_err(|e| match e {
+                hypr_google_calendar::Error::NotAnAttendee => {
+                    CalendarError::BadRequest(e.to_string())
+                }
+                e => CalendarError::Internal(e.to_string()),
+            })?;
+        Ok(())
+    }
 }
 
 fn parse_date(s: &str, field: &str) -> Result<chrono::NaiveDate, CalendarError> {
@@ -167,3 +359,23 @@ fn convert_event_datetime(
         time_zone: dt.time_zone,
     })
 }
+
+fn convert_attendees(attendees: Vec<EventAttendee>) -> Vec<hypr_google_calendar::Attendee> {
+    attendees
+        .into_iter()
+        .map(|a| hypr_google_calendar::Attendee {
+            email: Some(a.email),
+            display_name: a.display_name,
+            optional: a.optional,
+            ..Default::default()
+        })
+        .collect()
+}
+
+fn send_updates(notify: Option<bool>) -> hypr_google_calendar::SendUpdates {
+    if notify.unwrap_or(true) {
+        hypr_google_calendar::SendUpdates::All
+    } else {
+        hypr_google_calendar::SendUpdates::None
+    }
+}
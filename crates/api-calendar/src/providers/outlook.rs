@@ -2,8 +2,14 @@ use hypr_nango::OwnedNangoHttpClient;
 use hypr_outlook_calendar::OutlookCalendarClient;
 
 use crate::error::CalendarError;
-use crate::provider::{CreateEventResult, ListCalendarsResult, ListEventsResult};
-use crate::routes::calendar::{CreateEventRequest, ListEventsRequest};
+use crate::provider::{
+    CreateEventResult, ListCalendarsResult, ListEventsResult, UpdateEventResult,
+};
+use crate::routes::calendar::{
+    CreateEventRequest, DeleteEventRequest, EventAttendee, ListEventsRequest, RsvpEventRequest,
+    RsvpResponse, SyncEventsRequest, UpdateEventRequest,
+};
+use crate::sync::{SyncEventsResult, SyncToken, apply_changes};
 
 pub struct OutlookAdapter {
     client: OutlookCalendarClient<OwnedNangoHttpClient>,
@@ -89,6 +95,82 @@ impl OutlookAdapter {
         })
     }
 
+    /// Syncs with a delta query over the window; the delta link is the
+    /// cursor. Graph expands series itself, so occurrences come back as
+    /// events of their own.
+    pub async fn sync_events(
+        &self,
+        req: SyncEventsRequest,
+    ) -> Result<SyncEventsResult, CalendarError> {
+        if let Some(link) =
+            SyncToken::cursor_for(req.sync_token.as_deref(), req.time_min, req.time_max)
+        {
+            match self.sync_changes(&req, Some(link)).await {
+                Err(hypr_outlook_calendar::Error::SyncTokenExpired) => {
+                    tracing::info!(calendar_id = %req.calendar_id, "outlook_delta_link_expired");
+                }
+                result => return result.map_err(|e| CalendarError::Internal(e.to_string())),
+            }
+        }
+
+        let mut result = self
+            .sync_changes(&req, None)
+            .await
+            .map_err(|e| CalendarError::Internal(e.to_string()))?;
+        result.full_sync = true;
+        Ok(result)
+    }
+
+    async fn sync_changes(
+        &self,
+        req: &SyncEventsRequest,
+        mut link: Option<String>,
+    ) -> Result<SyncEventsResult, hypr_outlook_calendar::Error> {
+        let mut changed = Vec::new();
+        let mut deleted = Vec::new();
+
+        let delta_link = loop {
+            let response = self
+                .client
+                .list_events_delta(hypr_outlook_calendar::EventsDeltaRequest {
+                    calendar_id: req.calendar_id.clone(),
+                    start_date_time: Some(req.time_min),
+                    end_date_time: Some(req.time_max),
+                    link: link.take(),
+                })
+                .await?;
+
+            for item in response.value {
+                if item.removed.is_some() {
+                    deleted.push(item.id);
+                    continue;
+                }
+                // Masters would be expanded again next to their occurrences.
+                changed.extend(
+                    item.to_calendar_event(&req.calendar_id)
+                        .filter(|e| !e.is_series()),
+                );
+            }
+
+            match response.odata_next_link {
+                Some(next) => link = Some(next),
+                None => break response.odata_delta_link,
+            }
+        };
+
+        let mut result = apply_changes(changed, deleted, req.time_min, req.time_max);
+        result.replaced_series.clear();
+        result.next_sync_token = delta_link.map(|cursor| {
+            SyncToken {
+                time_min: req.time_min,
+                time_max: req.time_max,
+                cursor,
+            }
+            .encode()
+        });
+        Ok(result)
+    }
+
     pub async fn create_event(
         &self,
         req: CreateEventRequest,
@@ -102,26 +184,9 @@ impl OutlookAdapter {
                 subject: req.summary,
                 start,
                 end,
-                body: req.description.map(|d| hypr_outlook_calendar::ItemBody {
-                    content_type: Some(hypr_outlook_calendar::BodyType::Text),
-                    content: Some(d),
-                }),
-                location: req.location.map(|l| hypr_outlook_calendar::Location {
-                    display_name: Some(l),
-                    ..Default::default()
-                }),
-                attendees: req.attendees.map(|attendees| {
-                    attendees
-                        .into_iter()
-                        .map(|a| hypr_outlook_calendar::Attendee {
-                            email_address: Some(hypr_outlook_calendar::EmailAddress {
-                                name: a.display_name,
-                                address: Some(a.email),
-                            }),
-                            ..Default::default()
-                        })
-                        .collect()
-                }),
+                body: req.description.map(text_body),
+                location: req.location.map(location),
+                attendees: req.attendees.map(convert_attendees),
                 ..Default::default()
             },
         };
@@ -135,6 +200,73 @@ impl OutlookAdapter {
         let event = serde_json::to_value(event).unwrap_or_default();
         Ok(CreateEventResult { event })
     }
+
+    pub async fn update_event(
+        &self,
+        req: UpdateEventRequest,
+    ) -> Result<UpdateEventResult, CalendarError> {
+        let start = req
+            .start
+            .as_ref()
+            .map(convert_to_outlook_datetime)
+            .transpose()?;
+        let end = req
+            .end
+            .as_ref()
+            .map(convert_to_outlook_datetime)
+            .transpose()?;
+
+        let outlook_req = hypr_outlook_calendar::UpdateEventRequest {
+            calendar_id: req.calendar_id,
+            event_id: req.event_id,
+            event: hypr_outlook_calendar::UpdateEventBody {
+                subject: req.summary,
+                body: req.description.map(text_body),
+                location: req.location.map(location),
+                start,
+                end,
+                attendees: req.attendees.map(convert_attendees),
+            },
+        };
+
+        let event = self
+            .client
+            .update_event(outlook_req)
+            .await
+            .map_err(|e| CalendarError::Internal(e.to_string()))?;
+
+        let event = serde_json::to_value(event).unwrap_or_default();
+        Ok(UpdateEventResult { event })
+    }
+
+    pub async fn delete_event(&self, req: DeleteEventRequest) -> Result<(), CalendarError> {
+        self.client
+            .delete_event(hypr_outlook_calendar::DeleteEventRequest {
+                calendar_id: req.calendar_id,
+                event_id: req.event_id,
+            })
+            .await
+            .map_err(|e| CalendarError::Internal(e.to_string()))
+    }
+
+    pub async fn rsvp_event(&self, req: RsvpEventRequest) -> Result<(), CalendarError> {
+        let response = match req.response {
+            RsvpResponse::Accepted => hypr_outlook_calendar::EventResponse::Accept,
+            RsvpResponse::Tentative => hypr_outlook_calendar::EventResponse::TentativelyAccept,
+            RsvpResponse::Declined => hypr_outlook_calendar::EventResponse::Decline,
+        };
+
+        self.client
+            .respond_to_event(hypr_outlook_calendar::RespondToEventRequest {
+                calendar_id: req.calendar_id,
+                event_id: req.event_id,
+                response,
+                comment: req.comment,
+                send_response: req.notify_organizer.unwrap_or(true),
+            })
+            .await
+            .map_err(|e| CalendarError::Internal(e.to_string()))
+    }
 }
 
 fn convert_to_outlook_datetime(
@@ -166,3 +298,35 @@ fn convert_to_outlook_datetime(
         ))
     }
 }
+
+fn convert_attendees(attendees: Vec<EventAttendee>) -> Vec<hypr_outlook_calendar::Attendee> {
+    attendees
+        .into_iter()
+        .map(|a| hypr_outlook_calendar::Attendee {
+            type_: Some(if a.optional == Some(true) {
+                hypr_outlook_calendar::AttendeeType::Optional
+            } else {
+                hypr_outlook_calendar::AttendeeType::Required
+            }),
+            email_address: Some(hypr_outlook_calendar::EmailAddress {
+                name: a.display_name,
+                address: Some(a.email),
+            }),
+            ..Default::default()
+        })
+        .collect()
+}
+
+fn text_body(content: String) -> hypr_outlook_calendar::ItemBody {
+    hypr_outlook_calendar::ItemBody {
+        content_type: Some(hypr_outlook_calendar::BodyType::Text),
+        content: Some(content),
+    }
+}
+
+fn location(display_name: String) -> hypr_outlook_calendar::Location {
+    hypr_outlook_calendar::Location {
+        display_name: Some(display_name),
+        ..Default::default()
+    }
+}
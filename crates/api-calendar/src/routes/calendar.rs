@@ -1,8 +1,11 @@
 use axum::Json;
+use axum::http::StatusCode;
+use chrono::{DateTime, Utc};
+use hypr_calendar_interface::CalendarEvent;
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
-use crate::error::Result;
+use crate::error::{CalendarError, Result};
 use crate::provider::CalendarClient;
 
 #[derive(Debug, Serialize, ToSchema)]
@@ -34,6 +37,33 @@ pub struct ListEventsResponse {
     pub next_page_token: Option<String>,
 }
 
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct SyncEventsRequest {
+    pub calendar_id: String,
+    /// From the previous sync of this calendar and window; omit to start over.
+    #[serde(default)]
+    pub sync_token: Option<String>,
+    /// The window recurring events are expanded into.
+    pub time_min: DateTime<Utc>,
+    pub time_max: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SyncEventsResponse {
+    /// Occurrences to insert, or to replace by `id`.
+    pub events: Vec<CalendarEvent>,
+    /// IDs of events and occurrences to delete.
+    pub removed: Vec<String>,
+    /// Series whose stored occurrences should be deleted before applying
+    /// `events`, because they were expanded again.
+    pub replaced_series: Vec<String>,
+    /// Everything stored for the calendar should be deleted first, because
+    /// the sync token was missing, expired or for another window.
+    pub full_sync: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_sync_token: Option<String>,
+}
+
 #[derive(Debug, Deserialize, ToSchema)]
 pub struct CreateEventRequest {
     pub calendar_id: String,
@@ -72,6 +102,62 @@ pub struct CreateEventResponse {
     pub event: serde_json::Value,
 }
 
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateEventRequest {
+    pub calendar_id: String,
+    pub event_id: String,
+    #[serde(default)]
+    pub summary: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub location: Option<String>,
+    #[serde(default)]
+    pub start: Option<EventDateTime>,
+    #[serde(default)]
+    pub end: Option<EventDateTime>,
+    /// Replaces the attendee list.
+    #[serde(default)]
+    pub attendees: Option<Vec<EventAttendee>>,
+    /// Defaults to true. Outlook always notifies attendees.
+    #[serde(default)]
+    pub notify_attendees: Option<bool>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct UpdateEventResponse {
+    pub event: serde_json::Value,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct DeleteEventRequest {
+    pub calendar_id: String,
+    pub event_id: String,
+    /// Defaults to true. Outlook always notifies attendees.
+    #[serde(default)]
+    pub notify_attendees: Option<bool>,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum RsvpResponse {
+    Accepted,
+    Tentative,
+    Declined,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct RsvpEventRequest {
+    pub calendar_id: String,
+    pub event_id: String,
+    pub response: RsvpResponse,
+    #[serde(default)]
+    pub comment: Option<String>,
+    /// Defaults to true.
+    #[serde(default)]
+    pub notify_organizer: Option<bool>,
+}
+
 #[utoipa::path(
     post,
     path = "/calendars",
@@ -111,6 +197,38 @@ pub async fn list_events(
     }))
 }
 
+#[utoipa::path(
+    post,
+    path = "/events/sync",
+    request_body = SyncEventsRequest,
+    responses(
+        (status = 200, description = "Changes since the last sync", body = SyncEventsResponse),
+        (status = 400, description = "Invalid window"),
+        (status = 401, description = "Unauthorized"),
+        (status = 500, description = "Internal server error"),
+    ),
+    tag = "calendar",
+)]
+pub async fn sync_events(
+    client: CalendarClient,
+    Json(payload): Json<SyncEventsRequest>,
+) -> Result<Json<SyncEventsResponse>> {
+    if payload.time_max <= payload.time_min {
+        return Err(CalendarError::BadRequest(
+            "time_max must be after time_min".into(),
+        ));
+    }
+
+    let result = client.sync_events(payload).await?;
+    Ok(Json(SyncEventsResponse {
+        events: result.events,
+        removed: result.removed,
+        replaced_series: result.replaced_series,
+        full_sync: result.full_sync,
+        next_sync_token: result.next_sync_token,
+    }))
+}
+
 #[utoipa::path(
     post,
     path = "/events/create",
@@ -131,3 +249,64 @@ pub async fn create_event(
         event: result.event,
     }))
 }
+
+#[utoipa::path(
+    post,
+    path = "/events/update",
+    request_body = UpdateEventRequest,
+    responses(
+        (status = 200, description = "Event updated", body = UpdateEventResponse),
+        (status = 400, description = "Invalid request"),
+        (status = 401, description = "Unauthorized"),
+        (status = 500, description = "Internal server error"),
+    ),
+    tag = "calendar",
+)]
+pub async fn update_event(
+    client: CalendarClient,
+    Json(payload): Json<UpdateEventRequest>,
+) -> Result<Json<UpdateEventResponse>> {
+    let result = client.update_event(payload).await?;
+    Ok(Json(UpdateEventResponse {
+        event: result.event,
+    }))
+}
+
+#[utoipa::path(
+    post,
+    path = "/events/delete",
+    request_body = DeleteEventRequest,
+    responses(
+        (status = 204, description = "Event deleted"),
+        (status = 401, description = "Unauthorized"),
+        (status = 500, description = "Internal server error"),
+    ),
+    tag = "calendar",
+)]
+pub async fn delete_event(
+    client: CalendarClient,
+    Json(payload): Json<DeleteEventRequest>,
+) -> Result<StatusCode> {
+    client.delete_event(payload).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    post,
+    path = "/events/rsvp",
+    request_body = RsvpEventRequest,
+    responses(
+        (status = 204, description = "Response recorded"),
+        (status = 400, description = "Not an attendee of the event"),
+        (status = 401, description = "Unauthorized"),
+        (status = 500, description = "Internal server error"),
+    ),
+    tag = "calendar",
+)]
+pub async fn rsvp_event(
+    client: CalendarClient,
+    Json(payload): Json<RsvpEventRequest>,
+) -> Result<StatusCode> {
+    client.rsvp_event(payload).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -12,6 +12,10 @@ pub fn router(config: CalendarConfig) -> Router {
     Router::new()
         .route("/calendars", post(calendar::list_calendars))
         .route("/events", post(calendar::list_events))
+        .route("/events/sync", post(calendar::sync_events))
         .route("/events/create", post(calendar::create_event))
+        .route("/events/update", post(calendar::update_event))
+        .route("/events/delete", post(calendar::delete_event))
+        .route("/events/rsvp", post(calendar::rsvp_event))
         .layer(axum::Extension(Arc::new(config)))
 }
@@ -0,0 +1,182 @@
+use std::collections::HashSet;
+
+use chrono::{DateTime, Utc};
+use hypr_calendar_interface::{CalendarEvent, expand};
+
+/// What a sync changed, for the caller to apply to its stored events.
+#[derive(Debug, Default)]
+pub struct SyncEventsResult {
+    pub events: Vec<CalendarEvent>,
+    pub removed: Vec<String>,
+    pub replaced_series: Vec<String>,
+    pub full_sync: bool,
+    pub next_sync_token: Option<String>,
+}
+
+/// The token handed to clients: the provider's cursor, tied to the window
+/// occurrences were expanded into. A different window needs a full sync,
+/// since series that didn't change would otherwise never be expanded into
+/// the new part of it.
+pub struct SyncToken {
+    pub time_min: DateTime<Utc>,
+    pub time_max: DateTime<Utc>,
+    pub cursor: String,
+}
+
+impl SyncToken {
+    pub fn encode(&self) -> String {
+        format!(
+            "{}:{}:{}",
+            self.time_min.timestamp(),
+            self.time_max.timestamp(),
+            self.cursor
+        )
+    }
+
+    /// The provider cursor, if `token` was issued for this window.
+    pub fn cursor_for(
+        token: Option<&str>,
+        time_min: DateTime<Utc>,
+        time_max: DateTime<Utc>,
+    ) -> Option<String> {
+        let mut parts = token?.splitn(3, ':');
+        let min: i64 = parts.next()?.parse().ok()?;
+        let max: i64 = parts.next()?.parse().ok()?;
+        let cursor = parts.next()?;
+        (min == time_min.timestamp() && max == time_max.timestamp() && !cursor.is_empty())
+            .then(|| cursor.to_string())
+    }
+}
+
+/// Turns changed events into occurrences within `time_min..time_max`.
+///
+/// `deleted` are events the provider reported as gone. Changed series are
+/// re-expanded, so their stored occurrences are replaced; changed events
+/// that ended up outside the window are removed.
+pub fn apply_changes(
+    changed: Vec<CalendarEvent>,
+    deleted: Vec<String>,
+    time_min: DateTime<Utc>,
+    time_max: DateTime<Utc>,
+) -> SyncEventsResult {
+    let replaced_series: Vec<String> = changed
+        .iter()
+        .filter(|e| e.is_series())
+        .map(|e| e.id.clone())
+        .chain(deleted.iter().cloned())
+        .collect();
+    let changed_ids: Vec<String> = changed
+        .iter()
+        .filter(|e| !e.is_series())
+        .map(|e| e.id.clone())
+        .collect();
+
+    let (cancelled, events): (Vec<_>, Vec<_>) = expand(changed, time_min, time_max)
+        .into_iter()
+        .partition(CalendarEvent::is_cancelled);
+
+    let kept: HashSet<&str> = events.iter().map(|e| e.id.as_str()).collect();
+    let mut removed: Vec<String> = deleted
+        .into_iter()
+        .chain(cancelled.into_iter().map(|e| e.id))
+        .chain(
+            changed_ids
+                .into_iter()
+                .filter(|id| !kept.contains(id.as_str())),
+        )
+        .collect();
+    removed.sort_unstable();
+    removed.dedup();
+
+    SyncEventsResult {
+        events,
+        removed,
+        replaced_series,
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hypr_calendar_interface::{CalendarProvider, EventStatus};
+
+    use super::*;
+
+    fn utc(s: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc)
+    }
+
+    fn event(id: &str, start: &str) -> CalendarEvent {
+        CalendarEvent {
+            id: id.into(),
+            calendar_id: "primary".into(),
+            provider: CalendarProvider::Google,
+            ical_uid: None,
+            series_id: None,
+            original_start: None,
+            recurrence: vec![],
+            title: id.into(),
+            description: None,
+            location: None,
+            meeting_url: None,
+            start: utc(start),
+            end: utc(start) + chrono::Duration::minutes(30),
+            is_all_day: false,
+            time_zone: None,
+            status: EventStatus::Confirmed,
+            organizer: None,
+            attendees: vec![],
+            self_response: None,
+            updated_at: None,
+        }
+    }
+
+    #[test]
+    fn test_token_is_tied_to_window() {
+        let (min, max) = (utc("2026-03-01T00:00:00Z"), utc("2026-04-01T00:00:00Z"));
+        let token = SyncToken {
+            time_min: min,
+            time_max: max,
+            cursor: "CPDAlvWDx:abc".into(),
+        }
+        .encode();
+
+        assert_eq!(
+            SyncToken::cursor_for(Some(&token), min, max).as_deref(),
+            Some("CPDAlvWDx:abc")
+        );
+        assert_eq!(
+            SyncToken::cursor_for(Some(&token), min, utc("2026-05-01T00:00:00Z")),
+            None
+        );
+        assert_eq!(SyncToken::cursor_for(Some("garbage"), min, max), None);
+        assert_eq!(SyncToken::cursor_for(None, min, max), None);
+    }
+
+    #[test]
+    fn test_apply_changes() {
+        let series = CalendarEvent {
+            recurrence: vec!["RRULE:FREQ=WEEKLY;COUNT=3".into()],
+            ..event("weekly", "2026-03-02T09:00:00Z")
+        };
+        let cancelled = CalendarEvent {
+            status: EventStatus::Cancelled,
+            series_id: Some("weekly".into()),
+            original_start: Some(utc("2026-03-09T09:00:00Z")),
+            ..event("weekly_20260309T090000Z", "2026-03-09T09:00:00Z")
+        };
+        let moved_out = event("lunch", "2026-06-01T12:00:00Z");
+
+        let result = apply_changes(
+            vec![series, cancelled, moved_out],
+            vec!["gone".into()],
+            utc("2026-03-01T00:00:00Z"),
+            utc("2026-04-01T00:00:00Z"),
+        );
+
+        let ids: Vec<&str> = result.events.iter().map(|e| e.id.as_str()).collect();
+        assert_eq!(ids, ["weekly_20260302T090000Z", "weekly_20260316T090000Z"]);
+        assert_eq!(result.removed, ["gone", "lunch", "weekly_20260309T090000Z"]);
+        assert_eq!(result.replaced_series, ["weekly", "gone"]);
+    }
+}
@@ -16,6 +16,19 @@ where
     s.parse().map_err(serde::de::Error::custom)
 }
 
+/// Like [`string_to_u64`] for optional values of any parseable type; unset
+/// and empty variables are `None`.
+pub fn string_to_optional<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: std::str::FromStr,
+    T::Err: std::fmt::Display,
+{
+    filter_empty(deserializer)?
+        .map(|s| s.parse().map_err(serde::de::Error::custom))
+        .transpose()
+}
+
 #[derive(Clone, Deserialize)]
 pub struct SupabaseEnv {
     pub supabase_url: String,
@@ -1,5 +1,11 @@
 mod error;
+mod openapi;
+mod recap;
+mod render;
 mod routes;
 
 pub use error::{MessengerError, Result};
+pub use openapi::openapi;
+pub use recap::{ActionItem, NoteContent, SessionRecap};
+pub use render::{slack_messages, teams_cards};
 pub use routes::router;
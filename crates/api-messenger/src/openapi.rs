@@ -0,0 +1,40 @@
+use utoipa::OpenApi;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        crate::routes::channels::list_slack_channels,
+        crate::routes::channels::list_teams_channels,
+        crate::routes::messenger::send_slack_message,
+        crate::routes::messenger::send_teams_message,
+        crate::routes::share::share_to_slack,
+        crate::routes::share::share_to_teams,
+    ),
+    components(
+        schemas(
+            crate::routes::channels::ListSlackChannelsRequest,
+            crate::routes::channels::ListSlackChannelsResponse,
+            crate::routes::channels::SlackChannel,
+            crate::routes::channels::ListTeamsChannelsResponse,
+            crate::routes::channels::TeamsTeam,
+            crate::routes::channels::TeamsChannel,
+            crate::routes::messenger::SlackSendRequest,
+            crate::routes::messenger::TeamsSendRequest,
+            crate::routes::messenger::SendMessageResponse,
+            crate::routes::share::SlackShareRequest,
+            crate::routes::share::TeamsShareRequest,
+            crate::routes::share::ShareSessionResponse,
+            crate::recap::SessionRecap,
+            crate::recap::NoteContent,
+            crate::recap::ActionItem,
+        )
+    ),
+    tags(
+        (name = "messenger", description = "Slack and Teams messaging")
+    )
+)]
+struct ApiDoc;
+
+pub fn openapi() -> utoipa::openapi::OpenApi {
+    ApiDoc::openapi()
+}
@@ -0,0 +1,74 @@
+use markdown::mdast;
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+use crate::error::{MessengerError, Result};
+
+/// A session shared to a channel: its enhanced note and action items.
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct SessionRecap {
+    pub title: String,
+    pub note: NoteContent,
+    #[serde(default)]
+    pub action_items: Vec<ActionItem>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(untagged)]
+pub enum NoteContent {
+    Markdown(String),
+    /// A Tiptap document, as stored for enhanced notes.
+    Tiptap(serde_json::Value),
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct ActionItem {
+    pub text: String,
+    #[serde(default)]
+    pub assignee: Option<String>,
+    #[serde(default)]
+    pub due: Option<String>,
+    #[serde(default)]
+    pub done: bool,
+}
+
+impl SessionRecap {
+    /// The note's top-level blocks. Markdown goes through the editor's
+    /// schema first, so both forms render the same.
+    pub(crate) fn note_blocks(&self) -> Result<Vec<mdast::Node>> {
+        let doc = match &self.note {
+            NoteContent::Markdown(md) => {
+                hypr_tiptap::md_to_tiptap_json(md).map_err(MessengerError::BadRequest)?
+            }
+            NoteContent::Tiptap(doc) => doc.clone(),
+        };
+
+        match hypr_tiptap::tiptap_json_to_mdast(&doc) {
+            mdast::Node::Root(root) => Ok(root.children),
+            node => Ok(vec![node]),
+        }
+    }
+}
+
+impl ActionItem {
+    pub(crate) fn checkbox(&self) -> &'static str {
+        if self.done { "☑" } else { "☐" }
+    }
+
+    /// The assignee and due date, e.g. ` (Dana, due Mar 9)`.
+    pub(crate) fn details(&self) -> String {
+        let details: Vec<String> = [
+            self.assignee.clone(),
+            self.due.as_ref().map(|due| format!("due {due}")),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+
+        if details.is_empty() {
+            String::new()
+        } else {
+            format!(" ({})", details.join(", "))
+        }
+    }
+}
@@ -0,0 +1,51 @@
+mod slack;
+mod teams;
+
+pub use slack::slack_messages;
+pub use teams::teams_cards;
+
+/// Splits `text` into pieces of at most `max` chars, preferring line
+/// breaks, then spaces.
+pub(crate) fn split_text(text: &str, max: usize) -> Vec<String> {
+    let mut pieces = Vec::new();
+    let mut rest = text.trim();
+
+    while rest.chars().count() > max {
+        let limit = rest
+            .char_indices()
+            .nth(max)
+            .map(|(i, _)| i)
+            .unwrap_or(rest.len());
+        let head = &rest[..limit];
+        let at = head
+            .rfind('\n')
+            .or_else(|| head.rfind(' '))
+            .filter(|&i| i > 0)
+            .unwrap_or(limit);
+
+        pieces.push(rest[..at].trim_end().to_string());
+        rest = rest[at..].trim_start();
+    }
+
+    if !rest.is_empty() {
+        pieces.push(rest.to_string());
+    }
+    pieces
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_text() {
+        assert_eq!(split_text("short", 10), ["short"]);
+        assert_eq!(
+            split_text("one two\nthree four", 12),
+            ["one two", "three four"]
+        );
+        assert_eq!(split_text("one two three", 9), ["one two", "three"]);
+        assert_eq!(split_text("abcdefgh", 3), ["abc", "def", "gh"]);
+        assert_eq!(split_text("ééééé", 2), ["éé", "éé", "é"]);
+    }
+}
@@ -0,0 +1,250 @@
+use markdown::mdast;
+use serde_json::{Value, json};
+
+use super::split_text;
+use crate::recap::ActionItem;
+
+/// Slack rejects messages with more blocks than this.
+const MAX_BLOCKS: usize = 50;
+const MAX_SECTION_TEXT: usize = 3000;
+const MAX_HEADER_TEXT: usize = 150;
+
+/// Renders a recap as Block Kit, one block list per message.
+pub fn slack_messages(
+    title: &str,
+    note: &[mdast::Node],
+    action_items: &[ActionItem],
+) -> Vec<Vec<Value>> {
+    let mut blocks = vec![header(title)];
+
+    for node in note {
+        push_block(node, &mut blocks);
+    }
+
+    if !action_items.is_empty() {
+        blocks.push(json!({ "type": "divider" }));
+        push_sections("*Action items*", &mut blocks);
+
+        let lines: Vec<String> = action_items
+            .iter()
+            .map(|item| {
+                format!(
+                    "{} {}{}",
+                    item.checkbox(),
+                    escape(&item.text),
+                    escape(&item.details())
+                )
+            })
+            .collect();
+        push_sections(&lines.join("\n"), &mut blocks);
+    }
+
+    blocks.chunks(MAX_BLOCKS).map(<[Value]>::to_vec).collect()
+}
+
+fn header(title: &str) -> Value {
+    let mut text: String = title.chars().take(MAX_HEADER_TEXT).collect();
+    if text.trim().is_empty() {
+        text = "Untitled".to_string();
+    }
+
+    json!({
+        "type": "header",
+        "text": { "type": "plain_text", "text": text, "emoji": true },
+    })
+}
+
+fn push_sections(text: &str, blocks: &mut Vec<Value>) {
+    for piece in split_text(text, MAX_SECTION_TEXT) {
+        blocks.push(json!({
+            "type": "section",
+            "text": { "type": "mrkdwn", "text": piece },
+        }));
+    }
+}
+
+fn push_block(node: &mdast::Node, blocks: &mut Vec<Value>) {
+    match node {
+        mdast::Node::ThematicBreak(_) => blocks.push(json!({ "type": "divider" })),
+        mdast::Node::Code(code) => {
+            // Each piece keeps its own fence, so split ones still render.
+            for piece in split_text(&escape(&code.value), MAX_SECTION_TEXT - 8) {
+                push_sections(&format!("```\n{piece}\n```"), blocks);
+            }
+        }
+        node => push_sections(&block_text(node), blocks),
+    }
+}
+
+fn block_text(node: &mdast::Node) -> String {
+    match node {
+        mdast::Node::Heading(heading) => format!("*{}*", inline(&heading.children)),
+        mdast::Node::Paragraph(paragraph) => inline(&paragraph.children),
+        mdast::Node::List(list) => list_text(list, 0),
+        mdast::Node::Code(code) => format!("```\n{}\n```", escape(&code.value)),
+        mdast::Node::Blockquote(quote) => quote
+            .children
+            .iter()
+            .map(block_text)
+            .collect::<Vec<_>>()
+            .join("\n")
+            .lines()
+            .map(|line| format!("> {line}"))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        mdast::Node::Table(table) => table
+            .children
+            .iter()
+            .filter_map(|row| match row {
+                mdast::Node::TableRow(row) => Some(
+                    row.children
+                        .iter()
+                        .map(|cell| inline(cell.children().map(Vec::as_slice).unwrap_or(&[])))
+                        .collect::<Vec<_>>()
+                        .join(" | "),
+                ),
+                _ => None,
+            })
+            .collect::<Vec<_>>()
+            .join("\n"),
+        node => inline(std::slice::from_ref(node)),
+    }
+}
+
+fn list_text(list: &mdast::List, depth: usize) -> String {
+    let indent = "    ".repeat(depth);
+    let mut lines = Vec::new();
+
+    for (i, item) in list.children.iter().enumerate() {
+        let mdast::Node::ListItem(item) = item else {
+            continue;
+        };
+
+        let marker = match item.checked {
+            Some(true) => "☑".to_string(),
+            Some(false) => "☐".to_string(),
+            None if list.ordered => format!("{}.", list.start.unwrap_or(1) as usize + i),
+            None => "•".to_string(),
+        };
+
+        let mut first = true;
+        for child in &item.children {
+            match child {
+                mdast::Node::List(nested) => lines.push(list_text(nested, depth + 1)),
+                child => {
+                    let text = block_text(child);
+                    if first {
+                        lines.push(format!("{indent}{marker} {text}"));
+                        first = false;
+                    } else {
+                        lines.push(format!("{indent}    {text}"));
+                    }
+                }
+            }
+        }
+        if first {
+            lines.push(format!("{indent}{marker}"));
+        }
+    }
+
+    lines.join("\n")
+}
+
+fn inline(nodes: &[mdast::Node]) -> String {
+    nodes
+        .iter()
+        .map(|node| match node {
+            mdast::Node::Text(text) => escape(&text.value),
+            mdast::Node::Strong(strong) => format!("*{}*", inline(&strong.children)),
+            mdast::Node::Emphasis(emphasis) => format!("_{}_", inline(&emphasis.children)),
+            mdast::Node::Delete(delete) => format!("~{}~", inline(&delete.children)),
+            mdast::Node::InlineCode(code) => format!("`{}`", escape(&code.value)),
+            mdast::Node::Link(link) => format!("<{}|{}>", link.url, inline(&link.children)),
+            mdast::Node::Image(image) => format!("<{}|{}>", image.url, escape(&image.alt)),
+            mdast::Node::Break(_) => "\n".to_string(),
+            node => escape(&node.to_string()),
+        })
+        .collect()
+}
+
+/// Slack treats these as control characters in `mrkdwn`.
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn note(md: &str) -> Vec<mdast::Node> {
+        let doc = hypr_tiptap::md_to_tiptap_json(md).unwrap();
+        match hypr_tiptap::tiptap_json_to_mdast(&doc) {
+            mdast::Node::Root(root) => root.children,
+            _ => unreachable!(),
+        }
+    }
+
+    fn texts(blocks: &[Value]) -> Vec<&str> {
+        blocks
+            .iter()
+            .filter_map(|b| b["text"]["text"].as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_slack_messages() {
+        let note = note(
+            "## Summary\n\nShip **v2** by _Friday_ for R&D\n\n- One\n  - Nested\n- [Docs](https://example.com)\n\n---\n\n1. First\n2. Second",
+        );
+        let action_items = vec![ActionItem {
+            text: "Send the deck".to_string(),
+            assignee: Some("Dana".to_string()),
+            due: Some("Mar 9".to_string()),
+            done: false,
+        }];
+
+        let messages = slack_messages("Weekly sync", &note, &action_items);
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0][0]["type"], "header");
+        assert_eq!(
+            texts(&messages[0]),
+            [
+                "Weekly sync",
+                "*Summary*",
+                "Ship *v2* by _Friday_ for R&amp;D",
+                "• One\n    • Nested\n• <https://example.com|Docs>",
+                "1. First\n2. Second",
+                "*Action items*",
+                "☐ Send the deck (Dana, due Mar 9)",
+            ]
+        );
+        assert_eq!(messages[0][4]["type"], "divider");
+    }
+
+    #[test]
+    fn test_slack_messages_chunked() {
+        let md = (0..120)
+            .map(|i| format!("Paragraph {i}"))
+            .collect::<Vec<_>>()
+            .join("\n\n");
+        let messages = slack_messages("Long", &note(&md), &[]);
+
+        assert_eq!(
+            messages.iter().map(Vec::len).collect::<Vec<_>>(),
+            [50, 50, 21]
+        );
+        assert!(
+            messages
+                .iter()
+                .flatten()
+                .filter_map(|b| b["text"]["text"].as_str())
+                .all(|text| text.len() <= MAX_SECTION_TEXT)
+        );
+
+        let long = "word ".repeat(1000);
+        let messages = slack_messages("Long", &note(&long), &[]);
+        assert_eq!(messages[0].len(), 3);
+    }
+}
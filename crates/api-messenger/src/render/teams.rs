@@ -0,0 +1,266 @@
+use markdown::mdast;
+use serde_json::{Value, json};
+
+use super::split_text;
+use crate::recap::ActionItem;
+
+/// Graph rejects channel messages over roughly 28 KB, so cards are kept
+/// well under that.
+const MAX_CARD_BYTES: usize = 20_000;
+const MAX_TEXT_BLOCK: usize = 4000;
+
+/// Renders a recap as Adaptive Cards, one card per message.
+pub fn teams_cards(title: &str, note: &[mdast::Node], action_items: &[ActionItem]) -> Vec<Value> {
+    let title = if title.trim().is_empty() {
+        "Untitled"
+    } else {
+        title
+    };
+
+    let mut elements = vec![json!({
+        "type": "TextBlock",
+        "text": title,
+        "size": "Large",
+        "weight": "Bolder",
+        "wrap": true,
+    })];
+
+    let mut separator = false;
+    for node in note {
+        if matches!(node, mdast::Node::ThematicBreak(_)) {
+            separator = true;
+            continue;
+        }
+
+        for mut element in block_elements(node) {
+            if separator {
+                element["separator"] = json!(true);
+                separator = false;
+            }
+            elements.push(element);
+        }
+    }
+
+    if !action_items.is_empty() {
+        elements.push(json!({
+            "type": "TextBlock",
+            "text": "Action items",
+            "weight": "Bolder",
+            "wrap": true,
+            "separator": true,
+        }));
+
+        let lines: Vec<String> = action_items
+            .iter()
+            .map(|item| format!("- {} {}{}", item.checkbox(), item.text, item.details()))
+            .collect();
+        elements.extend(text_blocks(&lines.join("\n")));
+    }
+
+    let empty_size = card(vec![]).to_string().len();
+    let mut cards = Vec::new();
+    let mut body: Vec<Value> = Vec::new();
+    let mut size = empty_size;
+
+    for element in elements {
+        // Plus the separating comma.
+        let element_size = element.to_string().len() + 1;
+        if !body.is_empty() && size + element_size > MAX_CARD_BYTES {
+            cards.push(card(std::mem::take(&mut body)));
+            size = empty_size;
+        }
+        size += element_size;
+        body.push(element);
+    }
+    cards.push(card(body));
+
+    cards
+}
+
+fn card(body: Vec<Value>) -> Value {
+    json!({
+        "type": "AdaptiveCard",
+        "$schema": "http://adaptivecards.io/schemas/adaptive-card.json",
+        "version": "1.4",
+        "body": body,
+    })
+}
+
+fn text_blocks(text: &str) -> Vec<Value> {
+    split_text(text, MAX_TEXT_BLOCK)
+        .into_iter()
+        .map(|piece| json!({ "type": "TextBlock", "text": piece, "wrap": true }))
+        .collect()
+}
+
+fn block_elements(node: &mdast::Node) -> Vec<Value> {
+    match node {
+        mdast::Node::Heading(heading) => vec![json!({
+            "type": "TextBlock",
+            "text": inline(&heading.children),
+            "size": if heading.depth <= 2 { "Medium" } else { "Default" },
+            "weight": "Bolder",
+            "wrap": true,
+        })],
+        mdast::Node::Code(code) => split_text(&code.value, MAX_TEXT_BLOCK)
+            .into_iter()
+            .map(|piece| {
+                json!({
+                    "type": "TextBlock",
+                    "text": piece,
+                    "fontType": "Monospace",
+                    "wrap": true,
+                })
+            })
+            .collect(),
+        mdast::Node::Blockquote(quote) => vec![json!({
+            "type": "Container",
+            "style": "emphasis",
+            "items": quote.children.iter().flat_map(block_elements).collect::<Vec<_>>(),
+        })],
+        node => text_blocks(&block_text(node)),
+    }
+}
+
+fn block_text(node: &mdast::Node) -> String {
+    match node {
+        mdast::Node::Heading(heading) => format!("**{}**", inline(&heading.children)),
+        mdast::Node::Paragraph(paragraph) => inline(&paragraph.children),
+        mdast::Node::List(list) => list_text(list, 0),
+        mdast::Node::Table(table) => table
+            .children
+            .iter()
+            .filter_map(|row| match row {
+                mdast::Node::TableRow(row) => Some(
+                    row.children
+                        .iter()
+                        .map(|cell| inline(cell.children().map(Vec::as_slice).unwrap_or(&[])))
+                        .collect::<Vec<_>>()
+                        .join(" | "),
+                ),
+                _ => None,
+            })
+            .collect::<Vec<_>>()
+            .join("\n"),
+        node => node.to_string(),
+    }
+}
+
+/// Adaptive Cards only render flat lists, so nesting is shown by indent.
+fn list_text(list: &mdast::List, depth: usize) -> String {
+    let indent = "\u{2003}".repeat(depth);
+    let mut lines = Vec::new();
+
+    for (i, item) in list.children.iter().enumerate() {
+        let mdast::Node::ListItem(item) = item else {
+            continue;
+        };
+
+        let marker = match item.checked {
+            Some(true) => "- ☑".to_string(),
+            Some(false) => "- ☐".to_string(),
+            None if list.ordered => format!("{}.", list.start.unwrap_or(1) as usize + i),
+            None => "-".to_string(),
+        };
+
+        let mut first = true;
+        for child in &item.children {
+            match child {
+                mdast::Node::List(nested) => lines.push(list_text(nested, depth + 1)),
+                child => {
+                    let text = block_text(child);
+                    if first {
+                        lines.push(format!("{indent}{marker} {text}"));
+                        first = false;
+                    } else {
+                        lines.push(format!("{indent}\u{2003}{text}"));
+                    }
+                }
+            }
+        }
+    }
+
+    lines.join("\n")
+}
+
+fn inline(nodes: &[mdast::Node]) -> String {
+    nodes
+        .iter()
+        .map(|node| match node {
+            mdast::Node::Strong(strong) => format!("**{}**", inline(&strong.children)),
+            mdast::Node::Emphasis(emphasis) => format!("_{}_", inline(&emphasis.children)),
+            mdast::Node::Delete(delete) => inline(&delete.children),
+            mdast::Node::Link(link) => format!("[{}]({})", inline(&link.children), link.url),
+            mdast::Node::Break(_) => "\n".to_string(),
+            node => node.to_string(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn note(md: &str) -> Vec<mdast::Node> {
+        let doc = hypr_tiptap::md_to_tiptap_json(md).unwrap();
+        match hypr_tiptap::tiptap_json_to_mdast(&doc) {
+            mdast::Node::Root(root) => root.children,
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn test_teams_cards() {
+        let note = note(
+            "## Summary\n\nShip **v2** by [Friday](https://example.com)\n\n---\n\n- [ ] One\n- [x] Two",
+        );
+        let action_items = vec![ActionItem {
+            text: "Send the deck".to_string(),
+            assignee: None,
+            due: None,
+            done: true,
+        }];
+
+        let cards = teams_cards("Weekly sync", &note, &action_items);
+        assert_eq!(cards.len(), 1);
+        assert_eq!(cards[0]["type"], "AdaptiveCard");
+
+        let body = cards[0]["body"].as_array().unwrap();
+        let texts: Vec<&str> = body.iter().filter_map(|e| e["text"].as_str()).collect();
+        assert_eq!(
+            texts,
+            [
+                "Weekly sync",
+                "Summary",
+                "Ship **v2** by [Friday](https://example.com)",
+                "- ☐ One\n- ☑ Two",
+                "Action items",
+                "- ☑ Send the deck",
+            ]
+        );
+        assert_eq!(body[3]["separator"], true);
+    }
+
+    #[test]
+    fn test_teams_cards_chunked() {
+        let md = (0..400)
+            .map(|i| format!("Paragraph {i} {}", "text ".repeat(20)))
+            .collect::<Vec<_>>()
+            .join("\n\n");
+        let cards = teams_cards("Long", &note(&md), &[]);
+
+        assert!(cards.len() > 1);
+        assert!(
+            cards
+                .iter()
+                .all(|card| card.to_string().len() <= MAX_CARD_BYTES)
+        );
+        assert_eq!(
+            cards
+                .iter()
+                .map(|card| card["body"].as_array().unwrap().len())
+                .sum::<usize>(),
+            401
+        );
+    }
+}
@@ -0,0 +1,128 @@
+use axum::Json;
+use hypr_api_nango::{MicrosoftTeams, NangoConnection, Slack};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::error::Result;
+
+const SLACK_PAGE_SIZE: u32 = 200;
+
+#[derive(Debug, Default, Deserialize, ToSchema)]
+pub struct ListSlackChannelsRequest {
+    #[serde(default)]
+    pub cursor: Option<String>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SlackChannel {
+    pub id: String,
+    pub name: String,
+    pub is_private: bool,
+    /// The app can only post to channels it is a member of.
+    pub is_member: bool,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ListSlackChannelsResponse {
+    pub channels: Vec<SlackChannel>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TeamsTeam {
+    pub id: String,
+    pub name: String,
+    pub channels: Vec<TeamsChannel>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TeamsChannel {
+    pub id: String,
+    pub name: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ListTeamsChannelsResponse {
+    pub teams: Vec<TeamsTeam>,
+}
+
+#[utoipa::path(
+    post,
+    path = "/slack/channels",
+    request_body = ListSlackChannelsRequest,
+    responses(
+        (status = 200, description = "Channels of the connected workspace", body = ListSlackChannelsResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 500, description = "Slack error"),
+    ),
+    tag = "messenger",
+)]
+pub async fn list_slack_channels(
+    connection: NangoConnection<Slack>,
+    Json(req): Json<ListSlackChannelsRequest>,
+) -> Result<Json<ListSlackChannelsResponse>> {
+    let client = hypr_slack_web::SlackWebClient::new(connection.into_http());
+    let response = client
+        .list_conversations(hypr_slack_web::ListConversationsRequest {
+            types: vec!["public_channel".into(), "private_channel".into()],
+            limit: Some(SLACK_PAGE_SIZE),
+            cursor: req.cursor,
+            ..Default::default()
+        })
+        .await?;
+
+    let next_cursor = response.next_cursor().map(str::to_string);
+    let channels = response
+        .channels
+        .into_iter()
+        .map(|c| SlackChannel {
+            name: c.name.unwrap_or_else(|| c.id.clone()),
+            id: c.id,
+            is_private: c.is_private,
+            is_member: c.is_member,
+        })
+        .collect();
+
+    Ok(Json(ListSlackChannelsResponse {
+        channels,
+        next_cursor,
+    }))
+}
+
+#[utoipa::path(
+    post,
+    path = "/teams/channels",
+    responses(
+        (status = 200, description = "Joined teams and their channels", body = ListTeamsChannelsResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 500, description = "Teams error"),
+    ),
+    tag = "messenger",
+)]
+pub async fn list_teams_channels(
+    connection: NangoConnection<MicrosoftTeams>,
+) -> Result<Json<ListTeamsChannelsResponse>> {
+    let client = hypr_teems::TeamsClient::new(connection.into_http());
+
+    let mut teams = Vec::new();
+    for team in client.list_joined_teams().await? {
+        let channels = client
+            .list_channels(&team.id)
+            .await?
+            .into_iter()
+            .map(|c| TeamsChannel {
+                name: c.display_name.unwrap_or_else(|| c.id.clone()),
+                id: c.id,
+            })
+            .collect();
+
+        teams.push(TeamsTeam {
+            name: team.display_name.unwrap_or_else(|| team.id.clone()),
+            id: team.id,
+            channels,
+        });
+    }
+
+    Ok(Json(ListTeamsChannelsResponse { teams }))
+}
@@ -1,89 +1,131 @@
 use axum::Json;
+use hypr_api_nango::{MicrosoftTeams, NangoConnection, Slack};
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 use crate::error::{MessengerError, Result};
 
-#[derive(Debug, Deserialize)]
-#[serde(tag = "platform", rename_all = "lowercase")]
-pub enum SendMessageRequest {
-    Slack(SlackSendRequest),
-    Teams(TeamsSendRequest),
-}
-
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct SlackSendRequest {
     pub channel: String,
     #[serde(default)]
     pub text: Option<String>,
+    /// Block Kit blocks.
     #[serde(default)]
+    #[schema(value_type = Option<Object>)]
     pub blocks: Option<serde_json::Value>,
     #[serde(default)]
     pub thread_ts: Option<String>,
 }
 
-#[derive(Debug, Deserialize)]
-#[allow(dead_code)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct TeamsSendRequest {
     pub team_id: String,
     pub channel_id: String,
     pub content: String,
+    /// `text` or `html`; Teams defaults to `text`.
     #[serde(default)]
     pub content_type: Option<String>,
+    /// Posts as a reply to this message instead of a new one.
+    #[serde(default)]
+    pub reply_to_id: Option<String>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct SendMessageResponse {
     pub platform: String,
     pub message_id: String,
     pub channel: String,
 }
 
-pub async fn send_message(
-    Json(payload): Json<SendMessageRequest>,
+#[utoipa::path(
+    post,
+    path = "/slack/send",
+    request_body = SlackSendRequest,
+    responses(
+        (status = 200, description = "Message posted", body = SendMessageResponse),
+        (status = 400, description = "Neither text nor blocks given"),
+        (status = 401, description = "Unauthorized"),
+        (status = 500, description = "Slack error"),
+    ),
+    tag = "messenger",
+)]
+pub async fn send_slack_message(
+    connection: NangoConnection<Slack>,
+    Json(req): Json<SlackSendRequest>,
 ) -> Result<Json<SendMessageResponse>> {
-    match payload {
-        SendMessageRequest::Slack(req) => {
-            if req.text.is_none() && req.blocks.is_none() {
-                return Err(MessengerError::BadRequest(
-                    "either text or blocks must be provided".into(),
-                ));
-            }
-
-            let slack_req = hypr_slack_web::PostMessageRequest {
-                channel: req.channel.clone(),
-                text: req.text,
-                blocks: req.blocks,
-                attachments: None,
-                thread_ts: req.thread_ts,
-                reply_broadcast: None,
-                mrkdwn: None,
-                unfurl_links: None,
-                unfurl_media: None,
-                metadata: None,
-                username: None,
-                icon_url: None,
-                icon_emoji: None,
-            };
+    if req.text.is_none() && req.blocks.is_none() {
+        return Err(MessengerError::BadRequest(
+            "either text or blocks must be provided".into(),
+        ));
+    }
 
-            let _ = slack_req;
+    let client = hypr_slack_web::SlackWebClient::new(connection.into_http());
+    let response = client
+        .post_message(hypr_slack_web::PostMessageRequest {
+            channel: req.channel,
+            text: req.text,
+            blocks: req.blocks,
+            attachments: None,
+            thread_ts: req.thread_ts,
+            reply_broadcast: None,
+            mrkdwn: None,
+            unfurl_links: None,
+            unfurl_media: None,
+            metadata: None,
+            username: None,
+            icon_url: None,
+            icon_emoji: None,
+        })
+        .await?;
 
-            Err(MessengerError::Internal(
-                "slack client not configured in app state".into(),
-            ))
-        }
-        SendMessageRequest::Teams(req) => {
-            let teams_req = hypr_teems::SendMessageRequest {
-                body: hypr_teems::MessageBody {
-                    content: req.content,
-                    content_type: req.content_type,
-                },
-            };
+    Ok(Json(SendMessageResponse {
+        platform: "slack".to_string(),
+        message_id: response.ts,
+        channel: response.channel,
+    }))
+}
 
-            let _ = teams_req;
+#[utoipa::path(
+    post,
+    path = "/teams/send",
+    request_body = TeamsSendRequest,
+    responses(
+        (status = 200, description = "Message posted", body = SendMessageResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 500, description = "Teams error"),
+    ),
+    tag = "messenger",
+)]
+pub async fn send_teams_message(
+    connection: NangoConnection<MicrosoftTeams>,
+    Json(req): Json<TeamsSendRequest>,
+) -> Result<Json<SendMessageResponse>> {
+    let client = hypr_teems::TeamsClient::new(connection.into_http());
+    let message = hypr_teems::SendMessageRequest {
+        body: hypr_teems::MessageBody {
+            content: req.content,
+            content_type: req.content_type,
+        },
+        attachments: vec![],
+    };
 
-            Err(MessengerError::Internal(
-                "teams client not configured in app state".into(),
-            ))
+    let response = match &req.reply_to_id {
+        Some(parent) => {
+            client
+                .reply_to_message(&req.team_id, &req.channel_id, parent, message)
+                .await?
         }
-    }
+        None => {
+            client
+                .send_message(&req.team_id, &req.channel_id, message)
+                .await?
+        }
+    };
+
+    Ok(Json(SendMessageResponse {
+        platform: "teams".to_string(),
+        message_id: response.id,
+        channel: req.channel_id,
+    }))
 }
@@ -1,7 +1,15 @@
+pub(crate) mod channels;
 pub(crate) mod messenger;
+pub(crate) mod share;
 
 use axum::{Router, routing::post};
 
 pub fn router() -> Router {
-    Router::new().route("/send", post(messenger::send_message))
+    Router::new()
+        .route("/slack/channels", post(channels::list_slack_channels))
+        .route("/slack/send", post(messenger::send_slack_message))
+        .route("/slack/share", post(share::share_to_slack))
+        .route("/teams/channels", post(channels::list_teams_channels))
+        .route("/teams/send", post(messenger::send_teams_message))
+        .route("/teams/share", post(share::share_to_teams))
 }
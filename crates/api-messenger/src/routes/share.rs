@@ -0,0 +1,159 @@
+use axum::Json;
+use hypr_api_nango::{MicrosoftTeams, NangoConnection, Slack};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::error::Result;
+use crate::recap::SessionRecap;
+use crate::render::{slack_messages, teams_cards};
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct SlackShareRequest {
+    pub channel: String,
+    /// Posts the recap as replies to this message instead of a new one.
+    #[serde(default)]
+    pub thread_ts: Option<String>,
+    #[serde(flatten)]
+    pub recap: SessionRecap,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct TeamsShareRequest {
+    pub team_id: String,
+    pub channel_id: String,
+    /// Posts the recap as replies to this message instead of a new one.
+    #[serde(default)]
+    pub reply_to_id: Option<String>,
+    #[serde(flatten)]
+    pub recap: SessionRecap,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ShareSessionResponse {
+    pub platform: String,
+    pub channel: String,
+    /// The message the recap is threaded under.
+    pub thread_id: String,
+    /// One per posted chunk, in order.
+    pub message_ids: Vec<String>,
+}
+
+/// Long recaps are split over several messages; all but the first are
+/// replies to it, or all are replies to `thread_ts` when given.
+#[utoipa::path(
+    post,
+    path = "/slack/share",
+    request_body = SlackShareRequest,
+    responses(
+        (status = 200, description = "Recap posted", body = ShareSessionResponse),
+        (status = 400, description = "Invalid note"),
+        (status = 401, description = "Unauthorized"),
+        (status = 500, description = "Slack error"),
+    ),
+    tag = "messenger",
+)]
+pub async fn share_to_slack(
+    connection: NangoConnection<Slack>,
+    Json(req): Json<SlackShareRequest>,
+) -> Result<Json<ShareSessionResponse>> {
+    let client = hypr_slack_web::SlackWebClient::new(connection.into_http());
+    let note = req.recap.note_blocks()?;
+    let messages = slack_messages(&req.recap.title, &note, &req.recap.action_items);
+
+    let mut channel = req.channel;
+    let mut thread_ts = req.thread_ts;
+    let mut message_ids = Vec::new();
+
+    for blocks in messages {
+        let response = client
+            .post_message(hypr_slack_web::PostMessageRequest {
+                channel: channel.clone(),
+                text: Some(req.recap.title.clone()),
+                blocks: Some(serde_json::Value::Array(blocks)),
+                attachments: None,
+                thread_ts: thread_ts.clone(),
+                reply_broadcast: None,
+                mrkdwn: None,
+                unfurl_links: Some(false),
+                unfurl_media: Some(false),
+                metadata: None,
+                username: None,
+                icon_url: None,
+                icon_emoji: None,
+            })
+            .await?;
+
+        // Slack resolves channel names to IDs; replies need the ID.
+        channel = response.channel;
+        thread_ts.get_or_insert_with(|| response.ts.clone());
+        message_ids.push(response.ts);
+    }
+
+    Ok(Json(ShareSessionResponse {
+        platform: "slack".to_string(),
+        channel,
+        thread_id: thread_ts.unwrap_or_default(),
+        message_ids,
+    }))
+}
+
+#[utoipa::path(
+    post,
+    path = "/teams/share",
+    request_body = TeamsShareRequest,
+    responses(
+        (status = 200, description = "Recap posted", body = ShareSessionResponse),
+        (status = 400, description = "Invalid note"),
+        (status = 401, description = "Unauthorized"),
+        (status = 500, description = "Teams error"),
+    ),
+    tag = "messenger",
+)]
+pub async fn share_to_teams(
+    connection: NangoConnection<MicrosoftTeams>,
+    Json(req): Json<TeamsShareRequest>,
+) -> Result<Json<ShareSessionResponse>> {
+    let client = hypr_teems::TeamsClient::new(connection.into_http());
+    let note = req.recap.note_blocks()?;
+    let cards = teams_cards(&req.recap.title, &note, &req.recap.action_items);
+
+    let mut thread_id = req.reply_to_id;
+    let mut message_ids = Vec::new();
+
+    for (i, card) in cards.iter().enumerate() {
+        let attachment_id = format!("recap-{i}");
+        let message = hypr_teems::SendMessageRequest {
+            body: hypr_teems::MessageBody {
+                content: format!("<attachment id=\"{attachment_id}\"></attachment>"),
+                content_type: Some("html".to_string()),
+            },
+            attachments: vec![hypr_teems::ChatMessageAttachment::adaptive_card(
+                attachment_id,
+                card,
+            )],
+        };
+
+        let response = match &thread_id {
+            Some(parent) => {
+                client
+                    .reply_to_message(&req.team_id, &req.channel_id, parent, message)
+                    .await?
+            }
+            None => {
+                client
+                    .send_message(&req.team_id, &req.channel_id, message)
+                    .await?
+            }
+        };
+
+        thread_id.get_or_insert_with(|| response.id.clone());
+        message_ids.push(response.id);
+    }
+
+    Ok(Json(ShareSessionResponse {
+        platform: "teams".to_string(),
+        channel: req.channel_id,
+        thread_id: thread_id.unwrap_or_default(),
+        message_ids,
+    }))
+}
@@ -18,6 +18,7 @@ pub struct NangoConnectionState {
     http_client: reqwest::Client,
     supabase_url: String,
     supabase_anon_key: String,
+    supabase_service_role_key: Option<String>,
 }
 
 impl NangoConnectionState {
@@ -31,9 +32,16 @@ impl NangoConnectionState {
             http_client: reqwest::Client::new(),
             supabase_url: supabase_url.into().trim_end_matches('/').to_string(),
             supabase_anon_key: supabase_anon_key.into(),
+            supabase_service_role_key: None,
         }
     }
 
+    /// Lets [`Self::connect`] look up connections without a user token.
+    pub fn with_service_role_key(mut self, supabase_service_role_key: impl Into<String>) -> Self {
+        self.supabase_service_role_key = Some(supabase_service_role_key.into());
+        self
+    }
+
     pub fn from_config(config: &crate::config::NangoConfig) -> Self {
         let mut builder =
             hypr_nango::NangoClient::builder().api_key(&config.nango.nango_secret_key);
@@ -42,12 +50,42 @@ impl NangoConnectionState {
         }
         let nango = builder.build().expect("failed to build NangoClient");
 
-        Self::new(nango, &config.supabase_url, &config.supabase_anon_key)
+        let state = Self::new(nango, &config.supabase_url, &config.supabase_anon_key);
+        match &config.supabase_service_role_key {
+            Some(key) => state.with_service_role_key(key),
+            None => state,
+        }
+    }
+
+    /// The connection of `user_id` outside of a request, e.g. for background
+    /// jobs. Needs the service role key, since there is no user token.
+    pub async fn connect<I: NangoIntegrationId>(
+        &self,
+        user_id: &str,
+    ) -> Result<NangoConnection<I>, NangoConnectionError> {
+        let service_role_key = self.supabase_service_role_key.as_deref().ok_or_else(|| {
+            NangoConnectionError::Database("supabase_service_role_key not configured".to_string())
+        })?;
+
+        let connection_id = self
+            .get_connection_id(service_role_key, service_role_key, user_id, I::ID)
+            .await?;
+        Ok(self.connection(connection_id))
+    }
+
+    fn connection<I: NangoIntegrationId>(&self, connection_id: String) -> NangoConnection<I> {
+        let proxy = OwnedNangoProxy::new(&self.nango, I::ID.to_string(), connection_id);
+
+        NangoConnection {
+            http: OwnedNangoHttpClient::new(proxy),
+            _marker: PhantomData,
+        }
     }
 
     async fn get_connection_id(
         &self,
         auth_token: &str,
+        api_key: &str,
         user_id: &str,
         integration_id: &str,
     ) -> Result<String, NangoConnectionError> {
@@ -62,7 +100,7 @@ impl NangoConnectionState {
             .http_client
             .get(&url)
             .header("Authorization", format!("Bearer {}", auth_token))
-            .header("apikey", &self.supabase_anon_key)
+            .header("apikey", api_key)
             .send()
             .await
             .map_err(|e| NangoConnectionError::Database(e.to_string()))?;
@@ -189,15 +227,14 @@ impl<S: Send + Sync, I: NangoIntegrationId> FromRequestParts<S> for NangoConnect
             .ok_or(NangoConnectionError::MissingState)?;
 
         let connection_id = nango_state
-            .get_connection_id(&auth.token, &auth.claims.sub, I::ID)
+            .get_connection_id(
+                &auth.token,
+                &nango_state.supabase_anon_key,
+                &auth.claims.sub,
+                I::ID,
+            )
             .await?;
 
-        let proxy = OwnedNangoProxy::new(&nango_state.nango, I::ID.to_string(), connection_id);
-        let http = OwnedNangoHttpClient::new(proxy);
-
-        Ok(NangoConnection {
-            http,
-            _marker: PhantomData,
-        })
+        Ok(nango_state.connection(connection_id))
     }
 }
@@ -19,3 +19,15 @@ pub struct OutlookCalendar;
 impl NangoIntegrationId for OutlookCalendar {
     const ID: &'static str = "outlook-calendar";
 }
+
+pub struct Slack;
+
+impl NangoIntegrationId for Slack {
+    const ID: &'static str = "slack";
+}
+
+pub struct MicrosoftTeams;
+
+impl NangoIntegrationId for MicrosoftTeams {
+    const ID: &'static str = "microsoft-teams";
+}
@@ -9,6 +9,8 @@ mod supabase;
 
 pub use config::NangoConfig;
 pub use extractor::{NangoConnection, NangoConnectionError, NangoConnectionState};
-pub use integrations::{GoogleCalendar, GoogleDrive, NangoIntegrationId, OutlookCalendar};
+pub use integrations::{
+    GoogleCalendar, GoogleDrive, MicrosoftTeams, NangoIntegrationId, OutlookCalendar, Slack,
+};
 pub use openapi::openapi;
 pub use routes::{router, webhook_router};
@@ -5,6 +5,7 @@ use std::sync::Arc;
 use std::sync::atomic::{AtomicU32, Ordering};
 use std::task::{Context, Poll};
 
+/// Yields fixed chunks in order, then ends. Never pending.
 #[pin_project]
 pub struct MockInnerStream {
     chunks: Vec<Vec<f32>>,
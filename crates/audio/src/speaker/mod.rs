@@ -26,8 +26,10 @@ type PlatformSpeakerInput = linux::SpeakerInput;
 #[cfg(all(target_os = "linux", not(test)))]
 type PlatformSpeakerStream = linux::SpeakerStream;
 
-#[cfg(test)]
+#[cfg(any(test, feature = "mock"))]
 mod mock;
+#[cfg(feature = "mock")]
+pub use mock::MockInnerStream;
 
 #[cfg(all(
     any(target_os = "macos", target_os = "windows", target_os = "linux"),
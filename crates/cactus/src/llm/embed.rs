@@ -0,0 +1,36 @@
+use std::ffi::CString;
+
+use crate::error::{Error, Result};
+use crate::model::Model;
+
+/// Upper bound on the hidden size of supported embedding models.
+const MAX_EMBEDDING_DIM: usize = 8192;
+
+impl Model {
+    /// Embeds `text` into a single vector, L2-normalized when `normalize` is
+    /// set.
+    pub fn embed(&self, text: &str, normalize: bool) -> Result<Vec<f32>> {
+        let guard = self.lock_inference();
+        let text_c = CString::new(text)?;
+        let mut buf = vec![0f32; MAX_EMBEDDING_DIM];
+        let mut dim = 0usize;
+
+        let rc = unsafe {
+            cactus_sys::cactus_embed(
+                guard.raw_handle(),
+                text_c.as_ptr(),
+                buf.as_mut_ptr(),
+                buf.len() * std::mem::size_of::<f32>(),
+                &mut dim,
+                normalize,
+            )
+        };
+
+        if rc < 0 {
+            return Err(Error::Inference(format!("cactus_embed failed ({rc})")));
+        }
+
+        buf.truncate(dim.min(MAX_EMBEDDING_DIM));
+        Ok(buf)
+    }
+}
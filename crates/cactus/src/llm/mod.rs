@@ -1,4 +1,5 @@
 mod complete;
+mod embed;
 mod result;
 mod stream;
 
@@ -20,4 +21,7 @@ pub struct CompleteOptions {
     pub stop_sequences: Option<Vec<String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub confidence_threshold: Option<f32>,
+    /// GBNF grammar constraining sampling.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub grammar: Option<String>,
 }
@@ -128,3 +128,26 @@ fn test_complete_multi_turn() {
     println!("turn1: {:?}", r1.text);
     println!("turn2: {:?}", r2.text);
 }
+
+// cargo test -p cactus --test llm test_complete_with_grammar -- --ignored --nocapture
+#[ignore]
+#[test]
+fn test_complete_with_grammar() {
+    let model = llm_model();
+    let messages = vec![Message::user(
+        "Is the sky blue? Explain your reasoning in detail.",
+    )];
+    let options = CompleteOptions {
+        max_tokens: Some(40),
+        temperature: Some(0.0),
+        confidence_threshold: Some(0.0),
+        grammar: Some(r#"root ::= "{\"answer\": " ("true" | "false") "}""#.into()),
+        ..Default::default()
+    };
+
+    let r = model.complete(&messages, &options).unwrap();
+
+    let parsed: serde_json::Value = serde_json::from_str(r.text.trim())
+        .unwrap_or_else(|_| panic!("output ignored the grammar: {:?}", r.text));
+    assert!(parsed["answer"].is_boolean(), "unexpected: {:?}", r.text);
+}
@@ -0,0 +1,33 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("HTTP error: {0}")]
+    Http(#[from] reqwest::Error),
+
+    #[error("{method} {url} returned {status}")]
+    Status {
+        method: String,
+        url: String,
+        status: u16,
+    },
+
+    #[error("invalid URL: {0}")]
+    Url(#[from] url::ParseError),
+
+    #[error("invalid XML: {0}")]
+    Xml(String),
+
+    /// Neither the given URL nor `/.well-known/caldav` led to a principal.
+    #[error("no CalDAV service found at {0}")]
+    ServiceNotFound(String),
+
+    #[error("no calendar home set for {0}")]
+    CalendarHomeNotFound(String),
+}
+
+impl From<quick_xml::Error> for Error {
+    fn from(error: quick_xml::Error) -> Self {
+        Self::Xml(error.to_string())
+    }
+}
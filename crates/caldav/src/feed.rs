@@ -0,0 +1,124 @@
+use hypr_calendar_interface as interface;
+use reqwest::{StatusCode, header};
+
+use crate::error::Error;
+use crate::ics::parse_events;
+
+/// Cache validators from the last fetch of a feed, sent back so an
+/// unchanged feed costs a `304`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FeedValidators {
+    pub etag: Option<String>,
+    pub last_modified: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum FeedResponse {
+    NotModified,
+    Updated {
+        events: Vec<interface::CalendarEvent>,
+        validators: FeedValidators,
+    },
+}
+
+/// Fetches a read-only ICS subscription. `webcal://` URLs are fetched over
+/// HTTPS. Events use the feed URL as their calendar ID.
+pub async fn fetch_feed(
+    http: &reqwest::Client,
+    url: &str,
+    previous: &FeedValidators,
+) -> Result<FeedResponse, Error> {
+    let url = match url.strip_prefix("webcal://") {
+        Some(rest) => format!("https://{rest}"),
+        None => url.to_string(),
+    };
+
+    let mut request = http.get(&url);
+    if let Some(etag) = &previous.etag {
+        request = request.header(header::IF_NONE_MATCH, etag);
+    }
+    if let Some(last_modified) = &previous.last_modified {
+        request = request.header(header::IF_MODIFIED_SINCE, last_modified);
+    }
+
+    let response = request.send().await?;
+    let status = response.status();
+    if status == StatusCode::NOT_MODIFIED {
+        return Ok(FeedResponse::NotModified);
+    }
+    if !status.is_success() {
+        return Err(Error::Status {
+            method: "GET".into(),
+            url,
+            status: status.as_u16(),
+        });
+    }
+
+    let header = |name| {
+        response
+            .headers()
+            .get(name)
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string)
+    };
+    let validators = FeedValidators {
+        etag: header(header::ETAG),
+        last_modified: header(header::LAST_MODIFIED),
+    };
+
+    let body = response.text().await?;
+    let events = parse_events(&body, &url, interface::CalendarProvider::Ics, None);
+    Ok(FeedResponse::Updated { events, validators })
+}
+
+#[cfg(test)]
+mod tests {
+    use wiremock::matchers::{header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use super::*;
+
+    const FEED: &str = "BEGIN:VCALENDAR\r\nVERSION:2.0\r\nBEGIN:VEVENT\r\nUID:holiday-1\r\nDTSTART;VALUE=DATE:20261225\r\nSUMMARY:Christmas Day\r\nEND:VEVENT\r\nEND:VCALENDAR\r\n";
+
+    #[tokio::test]
+    async fn test_fetch_feed_with_validators() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/holidays.ics"))
+            .and(header("If-None-Match", "\"v1\""))
+            .respond_with(ResponseTemplate::new(304))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/holidays.ics"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("ETag", "\"v1\"")
+                    .insert_header("Content-Type", "text/calendar")
+                    .set_body_string(FEED),
+            )
+            .mount(&server)
+            .await;
+
+        let http = reqwest::Client::new();
+        let url = format!("{}/holidays.ics", server.uri());
+
+        let FeedResponse::Updated { events, validators } =
+            fetch_feed(&http, &url, &FeedValidators::default())
+                .await
+                .unwrap()
+        else {
+            panic!("expected an updated feed");
+        };
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].title, "Christmas Day");
+        assert_eq!(events[0].provider, interface::CalendarProvider::Ics);
+        assert_eq!(events[0].calendar_id, url);
+        assert_eq!(validators.etag.as_deref(), Some("\"v1\""));
+
+        assert_eq!(
+            fetch_feed(&http, &url, &validators).await.unwrap(),
+            FeedResponse::NotModified
+        );
+    }
+}
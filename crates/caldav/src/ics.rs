@@ -0,0 +1,556 @@
+use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use chrono_tz::Tz;
+use hypr_calendar_interface as interface;
+
+/// One content line, `NAME;PARAM=value:value`, unfolded.
+#[derive(Debug, Clone, PartialEq)]
+struct Property {
+    name: String,
+    params: Vec<(String, String)>,
+    value: String,
+}
+
+impl Property {
+    fn param(&self, name: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// A `DTSTART`-like value in UTC. All-day dates sit at midnight UTC, as
+/// in the other providers.
+#[derive(Debug, Clone, Copy)]
+struct Time {
+    at: DateTime<Utc>,
+    is_all_day: bool,
+    zone: Option<Tz>,
+}
+
+/// The `VEVENT`s of an iCalendar document: series, their exceptions and
+/// single events, ready for [`hypr_calendar_interface::expand`].
+///
+/// Exceptions (`RECURRENCE-ID`) get the occurrence ID of the instance they
+/// replace. `TZID`s are read as IANA or Windows names; `VTIMEZONE`
+/// definitions are not, so unknown zones and floating times fall back to
+/// UTC. Attendees whose address is `owner_email` are marked as the user.
+pub fn parse_events(
+    ics: &str,
+    calendar_id: &str,
+    provider: interface::CalendarProvider,
+    owner_email: Option<&str>,
+) -> Vec<interface::CalendarEvent> {
+    let mut events = Vec::new();
+    let mut stack: Vec<String> = Vec::new();
+    let mut current: Vec<Property> = Vec::new();
+
+    for property in unfold(ics).iter().filter_map(|line| parse_line(line)) {
+        match property.name.as_str() {
+            "BEGIN" => {
+                stack.push(property.value.to_ascii_uppercase());
+                if stack.last().is_some_and(|c| c == "VEVENT") {
+                    current.clear();
+                }
+            }
+            "END" => {
+                if stack.pop().is_some_and(|c| c == "VEVENT") {
+                    let props = std::mem::take(&mut current);
+                    events.extend(to_event(&props, calendar_id, provider.clone(), owner_email));
+                }
+            }
+            _ if stack.last().is_some_and(|c| c == "VEVENT") => current.push(property),
+            _ => {}
+        }
+    }
+
+    events
+}
+
+fn to_event(
+    props: &[Property],
+    calendar_id: &str,
+    provider: interface::CalendarProvider,
+    owner_email: Option<&str>,
+) -> Option<interface::CalendarEvent> {
+    let get = |name: &str| props.iter().find(|p| p.name == name);
+    let text = |name: &str| {
+        get(name)
+            .map(|p| unescape(&p.value))
+            .filter(|v| !v.is_empty())
+    };
+
+    let uid = get("UID")?.value.trim().to_string();
+    let start = parse_time(get("DTSTART")?, None)?;
+    let end = match (get("DTEND"), get("DURATION")) {
+        (Some(end), _) => parse_time(end, start.zone).map(|t| t.at),
+        (None, Some(duration)) => parse_duration(&duration.value).map(|d| start.at + d),
+        (None, None) if start.is_all_day => Some(start.at + Duration::days(1)),
+        (None, None) => Some(start.at),
+    }
+    .filter(|end| *end >= start.at)
+    .unwrap_or(start.at);
+
+    let original_start = get("RECURRENCE-ID")
+        .and_then(|p| parse_time(p, start.zone))
+        .map(|t| t.at);
+    let (id, series_id) = match original_start {
+        Some(original_start) => (
+            interface::instance_id(&uid, original_start, start.is_all_day),
+            Some(uid.clone()),
+        ),
+        None => (uid.clone(), None),
+    };
+
+    let recurrence = if original_start.is_some() {
+        vec![]
+    } else {
+        props
+            .iter()
+            .filter_map(|p| match p.name.as_str() {
+                "RRULE" => Some(format!("RRULE:{}", p.value)),
+                "EXDATE" | "RDATE" => recurrence_dates(p, start),
+                _ => None,
+            })
+            .collect()
+    };
+
+    let is_owner = |email: &Option<String>| {
+        owner_email.is_some_and(|owner| {
+            email
+                .as_deref()
+                .is_some_and(|e| e.eq_ignore_ascii_case(owner))
+        })
+    };
+    let organizer = get("ORGANIZER").map(|p| {
+        let email = mailto(&p.value);
+        interface::Attendee {
+            is_self: is_owner(&email),
+            email,
+            name: p.param("CN").map(str::to_string),
+            response: interface::ResponseStatus::Accepted,
+            is_organizer: true,
+            optional: false,
+        }
+    });
+    let attendees: Vec<interface::Attendee> = props
+        .iter()
+        .filter(|p| p.name == "ATTENDEE")
+        .map(|p| {
+            let email = mailto(&p.value);
+            interface::Attendee {
+                is_self: is_owner(&email),
+                is_organizer: organizer
+                    .as_ref()
+                    .is_some_and(|o| o.email.is_some() && o.email == email),
+                email,
+                name: p.param("CN").map(str::to_string),
+                response: match p.param("PARTSTAT").map(str::to_ascii_uppercase).as_deref() {
+                    Some("ACCEPTED") => interface::ResponseStatus::Accepted,
+                    Some("TENTATIVE") => interface::ResponseStatus::Tentative,
+                    Some("DECLINED") => interface::ResponseStatus::Declined,
+                    _ => interface::ResponseStatus::NeedsAction,
+                },
+                optional: p
+                    .param("ROLE")
+                    .is_some_and(|r| r.eq_ignore_ascii_case("OPT-PARTICIPANT")),
+            }
+        })
+        .collect();
+    let self_response = attendees
+        .iter()
+        .find(|a| a.is_self)
+        .map(|a| a.response.clone())
+        .or_else(|| {
+            (attendees.is_empty() || organizer.as_ref().is_some_and(|o| o.is_self))
+                .then_some(interface::ResponseStatus::Accepted)
+        });
+
+    let description = text("DESCRIPTION");
+    let location = text("LOCATION");
+    let url = text("URL");
+    let meeting_url = [
+        "X-GOOGLE-CONFERENCE",
+        "X-MICROSOFT-SKYPETEAMSMEETINGURL",
+        "X-MICROSOFT-ONLINEMEETINGCONFLINK",
+        "CONFERENCE",
+    ]
+    .into_iter()
+    .filter_map(text)
+    .chain(
+        [url, location.clone(), description.clone()]
+            .into_iter()
+            .flatten(),
+    )
+    .find_map(|v| hypr_meeting_link::parse_conference_link(&v));
+
+    Some(interface::CalendarEvent {
+        id,
+        calendar_id: calendar_id.to_string(),
+        provider,
+        ical_uid: Some(uid),
+        series_id,
+        original_start,
+        recurrence,
+        title: text("SUMMARY").unwrap_or_default(),
+        description,
+        location,
+        meeting_url,
+        start: start.at,
+        end,
+        is_all_day: start.is_all_day,
+        time_zone: start.zone.map(|zone| zone.name().to_string()),
+        status: match get("STATUS")
+            .map(|p| p.value.to_ascii_uppercase())
+            .as_deref()
+        {
+            Some("CANCELLED") => interface::EventStatus::Cancelled,
+            Some("TENTATIVE") => interface::EventStatus::Tentative,
+            _ => interface::EventStatus::Confirmed,
+        },
+        organizer,
+        attendees,
+        self_response,
+        updated_at: get("LAST-MODIFIED")
+            .or_else(|| get("DTSTAMP"))
+            .and_then(|p| parse_time(p, None))
+            .map(|t| t.at),
+    })
+}
+
+/// `EXDATE`/`RDATE` rewritten in UTC, or as dates for all-day series, so
+/// their zone no longer needs resolving.
+fn recurrence_dates(property: &Property, start: Time) -> Option<String> {
+    let values: Vec<String> = property
+        .value
+        .split(',')
+        .filter_map(|value| {
+            // Periods (`VALUE=PERIOD`) only need their start.
+            let value = value.split('/').next().unwrap_or_default();
+            let time = parse_time_value(value, property.param("TZID"), start.zone)?;
+            Some(if start.is_all_day {
+                time.at.format("%Y%m%d").to_string()
+            } else {
+                time.at.format("%Y%m%dT%H%M%SZ").to_string()
+            })
+        })
+        .collect();
+
+    if values.is_empty() {
+        return None;
+    }
+    let value_type = if start.is_all_day { ";VALUE=DATE" } else { "" };
+    Some(format!(
+        "{}{value_type}:{}",
+        property.name,
+        values.join(",")
+    ))
+}
+
+fn parse_time(property: &Property, default_zone: Option<Tz>) -> Option<Time> {
+    parse_time_value(&property.value, property.param("TZID"), default_zone)
+}
+
+fn parse_time_value(value: &str, tzid: Option<&str>, default_zone: Option<Tz>) -> Option<Time> {
+    let value = value.trim();
+    if value.len() == 8 {
+        let date = NaiveDate::parse_from_str(value, "%Y%m%d").ok()?;
+        return Some(Time {
+            at: date.and_hms_opt(0, 0, 0)?.and_utc(),
+            is_all_day: true,
+            zone: None,
+        });
+    }
+    if let Some(utc) = value.strip_suffix('Z') {
+        let at = NaiveDateTime::parse_from_str(utc, "%Y%m%dT%H%M%S").ok()?;
+        return Some(Time {
+            at: at.and_utc(),
+            is_all_day: false,
+            zone: default_zone,
+        });
+    }
+
+    let local = NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%S").ok()?;
+    let zone = tzid.and_then(resolve_zone).or(default_zone);
+    let at = match zone {
+        Some(zone) => zone
+            .from_local_datetime(&local)
+            .earliest()
+            // Times skipped by a DST change.
+            .or_else(|| {
+                zone.from_local_datetime(&(local + Duration::hours(1)))
+                    .earliest()
+            })?
+            .with_timezone(&Utc),
+        None => local.and_utc(),
+    };
+    Some(Time {
+        at,
+        is_all_day: false,
+        zone,
+    })
+}
+
+/// IANA zones, including ones with a vendor prefix such as
+/// `/mozilla.org/20050126_1/Europe/Berlin`, and the Windows names Exchange
+/// and Outlook write, such as `Pacific Standard Time`.
+fn resolve_zone(tzid: &str) -> Option<Tz> {
+    let tzid = tzid.trim_matches('"');
+    std::iter::once(tzid)
+        .chain(tzid.match_indices('/').map(|(i, _)| &tzid[i + 1..]))
+        .find_map(interface::parse_zone)
+}
+
+/// `P1W`, `PT1H30M`, `P1DT12H`; negative durations are ignored.
+fn parse_duration(value: &str) -> Option<Duration> {
+    let rest = value.trim().strip_prefix('+').unwrap_or(value.trim());
+    let rest = rest.strip_prefix('P')?;
+
+    let mut total = Duration::zero();
+    let mut number = String::new();
+    for c in rest.chars() {
+        match c {
+            '0'..='9' => number.push(c),
+            'T' => {}
+            'W' | 'D' | 'H' | 'M' | 'S' => {
+                let n: i64 = number.parse().ok()?;
+                number.clear();
+                total += match c {
+                    'W' => Duration::weeks(n),
+                    'D' => Duration::days(n),
+                    'H' => Duration::hours(n),
+                    'M' => Duration::minutes(n),
+                    _ => Duration::seconds(n),
+                };
+            }
+            _ => return None,
+        }
+    }
+    Some(total)
+}
+
+fn mailto(value: &str) -> Option<String> {
+    let value = value.trim();
+    let address = value
+        .get(..7)
+        .filter(|scheme| scheme.eq_ignore_ascii_case("mailto:"))
+        .map_or(value, |_| &value[7..]);
+    (!address.is_empty()).then(|| address.to_string())
+}
+
+/// Joins folded lines: a line starting with a space or tab continues the
+/// previous one.
+fn unfold(ics: &str) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    for line in ics.split('\n') {
+        let line = line.strip_suffix('\r').unwrap_or(line);
+        match (line.strip_prefix([' ', '\t']), lines.last_mut()) {
+            (Some(continuation), Some(last)) => last.push_str(continuation),
+            _ if line.is_empty() => {}
+            _ => lines.push(line.to_string()),
+        }
+    }
+    lines
+}
+
+/// Splits a content line. Parameter values may be quoted, and quoted
+/// values may contain `:` and `;`.
+fn parse_line(line: &str) -> Option<Property> {
+    let mut parts: Vec<String> = vec![String::new()];
+    let mut in_quotes = false;
+    let mut value_start = None;
+
+    for (i, c) in line.char_indices() {
+        match c {
+            '"' => {
+                in_quotes = !in_quotes;
+                parts.last_mut()?.push(c);
+            }
+            ';' if !in_quotes => parts.push(String::new()),
+            ':' if !in_quotes => {
+                value_start = Some(i + 1);
+                break;
+            }
+            _ => parts.last_mut()?.push(c),
+        }
+    }
+
+    let value = line[value_start?..].to_string();
+    let mut parts = parts.into_iter();
+    let name = parts.next()?.trim().to_ascii_uppercase();
+    let params = parts
+        .filter_map(|param| {
+            let (key, value) = param.split_once('=')?;
+            Some((
+                key.to_ascii_uppercase(),
+                value.trim_matches('"').to_string(),
+            ))
+        })
+        .collect();
+
+    Some(Property {
+        name,
+        params,
+        value,
+    })
+}
+
+fn unescape(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n' | 'N') => out.push('\n'),
+            Some(other) => out.push(other),
+            None => out.push('\\'),
+        }
+    }
+    out.trim().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utc(s: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc)
+    }
+
+    const SERIES: &str = "BEGIN:VCALENDAR\r
+VERSION:2.0\r
+PRODID:-//Nextcloud calendar v4//EN\r
+BEGIN:VTIMEZONE\r
+TZID:Europe/Berlin\r
+BEGIN:STANDARD\r
+DTSTART:19701025T030000\r
+TZOFFSETFROM:+0200\r
+TZOFFSETTO:+0100\r
+END:STANDARD\r
+END:VTIMEZONE\r
+BEGIN:VEVENT\r
+UID:weekly-1@example.com\r
+DTSTAMP:20260301T120000Z\r
+LAST-MODIFIED:20260302T080000Z\r
+DTSTART;TZID=Europe/Berlin:20260302T100000\r
+DTEND;TZID=Europe/Berlin:20260302T103000\r
+RRULE:FREQ=WEEKLY;COUNT=4\r
+EXDATE;TZID=/mozilla.org/20050126_1/Europe/Berlin:20260309T100000\r
+SUMMARY:Design review\\, weekly\r
+DESCRIPTION:Agenda:\\nhttps://meet.google.com/abc-defg-\r
+ hij\r
+ORGANIZER;CN=\"Lead; Design\":mailto:lead@example.com\r
+ATTENDEE;CN=Me;PARTSTAT=TENTATIVE;ROLE=OPT-PARTICIPANT:mailto:Me@Example.com\r
+ATTENDEE;PARTSTAT=ACCEPTED:mailto:lead@example.com\r
+BEGIN:VALARM\r
+ACTION:DISPLAY\r
+DESCRIPTION:Reminder\r
+TRIGGER:-PT10M\r
+END:VALARM\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+UID:weekly-1@example.com\r
+RECURRENCE-ID;TZID=Europe/Berlin:20260316T100000\r
+DTSTART;TZID=Europe/Berlin:20260316T140000\r
+DURATION:PT45M\r
+SUMMARY:Design review (moved)\r
+END:VEVENT\r
+END:VCALENDAR\r
+";
+
+    #[test]
+    fn test_parse_series_and_exception() {
+        let events = parse_events(
+            SERIES,
+            "personal",
+            interface::CalendarProvider::CalDav,
+            Some("me@example.com"),
+        );
+        assert_eq!(events.len(), 2);
+
+        let series = &events[0];
+        assert_eq!(series.id, "weekly-1@example.com");
+        assert_eq!(series.title, "Design review, weekly");
+        assert_eq!(series.start, utc("2026-03-02T09:00:00Z"));
+        assert_eq!(series.end, utc("2026-03-02T09:30:00Z"));
+        assert_eq!(series.time_zone.as_deref(), Some("Europe/Berlin"));
+        assert_eq!(
+            series.recurrence,
+            ["RRULE:FREQ=WEEKLY;COUNT=4", "EXDATE:20260309T090000Z"]
+        );
+        assert_eq!(
+            series.meeting_url.as_deref(),
+            Some("https://meet.google.com/abc-defg-hij")
+        );
+        assert_eq!(
+            series.organizer.as_ref().and_then(|o| o.name.as_deref()),
+            Some("Lead; Design")
+        );
+        assert!(series.attendees[0].is_self && series.attendees[0].optional);
+        assert!(series.attendees[1].is_organizer);
+        assert_eq!(
+            series.self_response,
+            Some(interface::ResponseStatus::Tentative)
+        );
+        assert_eq!(series.updated_at, Some(utc("2026-03-02T08:00:00Z")));
+
+        let moved = &events[1];
+        assert_eq!(moved.id, "weekly-1@example.com_20260316T090000Z");
+        assert_eq!(moved.series_id.as_deref(), Some("weekly-1@example.com"));
+        assert_eq!(moved.end - moved.start, Duration::minutes(45));
+
+        let expanded = interface::expand(
+            events,
+            utc("2026-03-01T00:00:00Z"),
+            utc("2026-04-01T00:00:00Z"),
+        );
+        let titles: Vec<(String, &str)> = expanded
+            .iter()
+            .map(|e| (e.start.to_rfc3339(), e.title.as_str()))
+            .collect();
+        assert_eq!(
+            titles,
+            [
+                ("2026-03-02T09:00:00+00:00".into(), "Design review, weekly"),
+                ("2026-03-16T13:00:00+00:00".into(), "Design review (moved)"),
+                ("2026-03-23T09:00:00+00:00".into(), "Design review, weekly"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_all_day_and_cancelled() {
+        let ics = "BEGIN:VCALENDAR\nBEGIN:VEVENT\nUID:offsite\nDTSTART;VALUE=DATE:20260304\nSTATUS:CANCELLED\nEND:VEVENT\nBEGIN:VEVENT\nSUMMARY:no uid\nDTSTART:20260304T100000Z\nEND:VEVENT\nEND:VCALENDAR\n";
+
+        let events = parse_events(ics, "feed", interface::CalendarProvider::Ics, None);
+        assert_eq!(events.len(), 1);
+        assert!(events[0].is_all_day && events[0].is_cancelled());
+        assert_eq!(events[0].start, utc("2026-03-04T00:00:00Z"));
+        assert_eq!(events[0].end, utc("2026-03-05T00:00:00Z"));
+        assert_eq!(
+            events[0].self_response,
+            Some(interface::ResponseStatus::Accepted)
+        );
+    }
+
+    #[test]
+    fn test_windows_tzid() {
+        let ics = "BEGIN:VCALENDAR\nBEGIN:VEVENT\nUID:standup\nDTSTART;TZID=Pacific Standard Time:20260304T090000\nDTEND;TZID=\"Pacific Standard Time\":20260304T093000\nEND:VEVENT\nEND:VCALENDAR\n";
+
+        let events = parse_events(ics, "work", interface::CalendarProvider::CalDav, None);
+        assert_eq!(events[0].start, utc("2026-03-04T17:00:00Z"));
+        assert_eq!(events[0].end, utc("2026-03-04T17:30:00Z"));
+        assert_eq!(events[0].time_zone.as_deref(), Some("America/Los_Angeles"));
+    }
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(parse_duration("PT1H30M"), Some(Duration::minutes(90)));
+        assert_eq!(parse_duration("P1DT2H"), Some(Duration::hours(26)));
+        assert_eq!(parse_duration("P2W"), Some(Duration::weeks(2)));
+        assert_eq!(parse_duration("-PT5M"), None);
+    }
+}
@@ -0,0 +1,10 @@
+mod client;
+mod error;
+mod feed;
+mod ics;
+mod xml;
+
+pub use client::{CalDavClient, CalendarObject, DavCalendar, EtagChanges, diff_etags};
+pub use error::Error;
+pub use feed::{FeedResponse, FeedValidators, fetch_feed};
+pub use ics::parse_events;
@@ -0,0 +1,232 @@
+use quick_xml::Reader;
+use quick_xml::events::{BytesStart, Event};
+
+use crate::error::Error;
+
+/// One `<response>` of a `207 Multi-Status`, with the properties the
+/// server found. Elements are matched by local name; the WebDAV, CalDAV
+/// and CalendarServer properties used here don't clash.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub(crate) struct DavResponse {
+    pub href: String,
+    /// Set when the whole response has a status, as for missing
+    /// resources in a multiget.
+    pub status: Option<u16>,
+    pub props: Props,
+}
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub(crate) struct Props {
+    pub display_name: Option<String>,
+    pub description: Option<String>,
+    pub color: Option<String>,
+    pub is_calendar: bool,
+    /// From `supported-calendar-component-set`, e.g. `VEVENT`.
+    pub components: Vec<String>,
+    pub ctag: Option<String>,
+    pub etag: Option<String>,
+    pub calendar_data: Option<String>,
+    pub current_user_principal: Option<String>,
+    pub calendar_home_set: Option<String>,
+}
+
+impl Props {
+    fn merge(&mut self, other: Props) {
+        self.display_name = other.display_name.or(self.display_name.take());
+        self.description = other.description.or(self.description.take());
+        self.color = other.color.or(self.color.take());
+        self.is_calendar |= other.is_calendar;
+        self.components.extend(other.components);
+        self.ctag = other.ctag.or(self.ctag.take());
+        self.etag = other.etag.or(self.etag.take());
+        self.calendar_data = other.calendar_data.or(self.calendar_data.take());
+        self.current_user_principal = other
+            .current_user_principal
+            .or(self.current_user_principal.take());
+        self.calendar_home_set = other.calendar_home_set.or(self.calendar_home_set.take());
+    }
+}
+
+/// Parses a multistatus body. Properties in a `propstat` with a non-2xx
+/// status (typically 404 for unsupported ones) are dropped.
+pub(crate) fn parse_multistatus(xml: &str) -> Result<Vec<DavResponse>, Error> {
+    let mut reader = Reader::from_str(xml);
+    reader.config_mut().trim_text(false);
+
+    let mut stack: Vec<String> = Vec::new();
+    let mut text = String::new();
+    let mut responses = Vec::new();
+    let mut response = DavResponse::default();
+    let mut propstat = Props::default();
+    let mut propstat_status: Option<u16> = None;
+
+    loop {
+        match reader.read_event()? {
+            Event::Start(start) => {
+                let name = local_name(&start);
+                on_element(&name, &stack, &start, &mut propstat)?;
+                stack.push(name);
+                text.clear();
+            }
+            Event::Empty(start) => {
+                on_element(&local_name(&start), &stack, &start, &mut propstat)?;
+            }
+            Event::Text(t) => text.push_str(&t.decode().map_err(quick_xml::Error::from)?),
+            Event::CData(t) => text.push_str(&t.decode().map_err(quick_xml::Error::from)?),
+            Event::GeneralRef(r) => match r.resolve_char_ref()? {
+                Some(c) => text.push(c),
+                None => {
+                    let name = r.decode().map_err(quick_xml::Error::from)?;
+                    let entity = format!("&{name};");
+                    let resolved = quick_xml::escape::unescape(&entity)
+                        .map_err(|e| Error::Xml(e.to_string()))?;
+                    text.push_str(&resolved);
+                }
+            },
+            Event::End(_) => {
+                let Some(name) = stack.pop() else {
+                    return Err(Error::Xml("unbalanced end tag".into()));
+                };
+                let parent = stack.last().map(String::as_str);
+                let value = std::mem::take(&mut text);
+                let trimmed = value.trim().to_string();
+
+                match (name.as_str(), parent) {
+                    ("href", Some("response")) => response.href = trimmed,
+                    ("href", Some("current-user-principal")) => {
+                        propstat.current_user_principal = Some(trimmed)
+                    }
+                    ("href", Some("calendar-home-set")) => {
+                        propstat.calendar_home_set = Some(trimmed)
+                    }
+                    ("status", Some("response")) => response.status = parse_status(&trimmed),
+                    ("status", Some("propstat")) => propstat_status = parse_status(&trimmed),
+                    ("displayname", _) => propstat.display_name = Some(trimmed),
+                    ("calendar-description", _) => propstat.description = Some(trimmed),
+                    ("calendar-color", _) => propstat.color = Some(trimmed),
+                    ("getctag", _) => propstat.ctag = Some(trimmed),
+                    ("getetag", _) => propstat.etag = Some(trimmed),
+                    // Kept as sent; line endings matter to the iCalendar parser.
+                    ("calendar-data", _) => propstat.calendar_data = Some(value),
+                    ("propstat", _) => {
+                        let props = std::mem::take(&mut propstat);
+                        if propstat_status.is_none_or(|s| (200..300).contains(&s)) {
+                            response.props.merge(props);
+                        }
+                        propstat_status = None;
+                    }
+                    ("response", _) => responses.push(std::mem::take(&mut response)),
+                    _ => {}
+                }
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+
+    Ok(responses)
+}
+
+fn on_element(
+    name: &str,
+    stack: &[String],
+    start: &BytesStart<'_>,
+    propstat: &mut Props,
+) -> Result<(), Error> {
+    match (name, stack.last().map(String::as_str)) {
+        ("calendar", Some("resourcetype")) => propstat.is_calendar = true,
+        ("comp", Some("supported-calendar-component-set")) => {
+            if let Some(attr) = start
+                .try_get_attribute("name")
+                .map_err(quick_xml::Error::from)?
+            {
+                let value = attr.unescape_value()?;
+                propstat.components.push(value.to_ascii_uppercase());
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+fn local_name(start: &BytesStart<'_>) -> String {
+    String::from_utf8_lossy(start.local_name().as_ref()).into_owned()
+}
+
+/// `HTTP/1.1 404 Not Found` to `404`.
+fn parse_status(line: &str) -> Option<u16> {
+    line.split_whitespace().nth(1)?.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_multistatus() {
+        let xml = r#"<?xml version="1.0" encoding="utf-8"?>
+<d:multistatus xmlns:d="DAV:" xmlns:cal="urn:ietf:params:xml:ns:caldav" xmlns:cs="http://calendarserver.org/ns/" xmlns:x1="http://apple.com/ns/ical/">
+  <d:response>
+    <d:href>/dav/calendars/user/me@example.com/</d:href>
+    <d:propstat>
+      <d:prop>
+        <d:resourcetype><d:collection/></d:resourcetype>
+      </d:prop>
+      <d:status>HTTP/1.1 200 OK</d:status>
+    </d:propstat>
+  </d:response>
+  <d:response>
+    <d:href>/dav/calendars/user/me@example.com/work/</d:href>
+    <d:propstat>
+      <d:prop>
+        <d:displayname>Work &amp; Projects</d:displayname>
+        <d:resourcetype><d:collection/><cal:calendar/></d:resourcetype>
+        <cal:supported-calendar-component-set>
+          <cal:comp name="VEVENT"/><cal:comp name="VTODO"/>
+        </cal:supported-calendar-component-set>
+        <cs:getctag>"ctag-7"</cs:getctag>
+        <x1:calendar-color>#3A87ADFF</x1:calendar-color>
+      </d:prop>
+      <d:status>HTTP/1.1 200 OK</d:status>
+    </d:propstat>
+    <d:propstat>
+      <d:prop><cal:calendar-description/></d:prop>
+      <d:status>HTTP/1.1 404 Not Found</d:status>
+    </d:propstat>
+  </d:response>
+  <d:response>
+    <d:href>/dav/calendars/user/me@example.com/work/gone.ics</d:href>
+    <d:status>HTTP/1.1 404 Not Found</d:status>
+  </d:response>
+</d:multistatus>"#;
+
+        let responses = parse_multistatus(xml).unwrap();
+        assert_eq!(responses.len(), 3);
+        assert!(!responses[0].props.is_calendar);
+
+        let work = &responses[1];
+        assert_eq!(work.href, "/dav/calendars/user/me@example.com/work/");
+        assert_eq!(work.props.display_name.as_deref(), Some("Work & Projects"));
+        assert!(work.props.is_calendar);
+        assert_eq!(work.props.components, ["VEVENT", "VTODO"]);
+        assert_eq!(work.props.ctag.as_deref(), Some("\"ctag-7\""));
+        assert_eq!(work.props.color.as_deref(), Some("#3A87ADFF"));
+        assert_eq!(work.props.description, None);
+
+        assert_eq!(responses[2].status, Some(404));
+    }
+
+    #[test]
+    fn test_calendar_data_keeps_line_endings() {
+        let xml = "<multistatus xmlns=\"DAV:\"><response><href>/a.ics</href><propstat><prop>\
+            <getetag>\"1\"</getetag>\
+            <calendar-data xmlns=\"urn:ietf:params:xml:ns:caldav\">BEGIN:VCALENDAR&#13;\nEND:VCALENDAR&#13;\n</calendar-data>\
+            </prop><status>HTTP/1.1 200 OK</status></propstat></response></multistatus>";
+
+        let responses = parse_multistatus(xml).unwrap();
+        assert_eq!(
+            responses[0].props.calendar_data.as_deref(),
+            Some("BEGIN:VCALENDAR\r\nEND:VCALENDAR\r\n")
+        );
+    }
+}
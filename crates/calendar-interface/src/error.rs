@@ -0,0 +1,10 @@
+use thiserror::Error;
+
+#[derive(Debug, Error, PartialEq)]
+pub enum Error {
+    #[error("invalid recurrence: {0}")]
+    InvalidRecurrence(String),
+
+    #[error("unsupported recurrence rule part: {0}")]
+    UnsupportedRecurrence(String),
+}
@@ -0,0 +1,108 @@
+use chrono::{DateTime, Utc};
+
+use crate::common_derives;
+
+common_derives! {
+    #[serde(rename_all = "lowercase")]
+    pub enum CalendarProvider {
+        Google,
+        Outlook,
+        Apple,
+        CalDav,
+        Ics,
+    }
+}
+
+common_derives! {
+    #[derive(Default)]
+    #[serde(rename_all = "lowercase")]
+    pub enum EventStatus {
+        #[default]
+        Confirmed,
+        Tentative,
+        Cancelled,
+    }
+}
+
+common_derives! {
+    #[derive(Default)]
+    #[serde(rename_all = "snake_case")]
+    pub enum ResponseStatus {
+        #[default]
+        NeedsAction,
+        Accepted,
+        Tentative,
+        Declined,
+    }
+}
+
+common_derives! {
+    #[derive(Default)]
+    pub struct Attendee {
+        pub email: Option<String>,
+        pub name: Option<String>,
+        pub response: ResponseStatus,
+        /// The account the calendar was synced from.
+        pub is_self: bool,
+        pub is_organizer: bool,
+        pub optional: bool,
+    }
+}
+
+common_derives! {
+    /// One event as every provider reports it.
+    ///
+    /// All-day events start and end at midnight UTC of their (floating)
+    /// dates, with an exclusive end. Occurrences of a series carry the
+    /// provider ID of the series in `series_id`; the series itself carries
+    /// its `recurrence` lines and is turned into occurrences by
+    /// [`crate::expand`].
+    pub struct CalendarEvent {
+        pub id: String,
+        pub calendar_id: String,
+        pub provider: CalendarProvider,
+        /// Stable across calendars and providers for the same invite.
+        pub ical_uid: Option<String>,
+        pub series_id: Option<String>,
+        /// Where this occurrence was scheduled before it was moved.
+        pub original_start: Option<DateTime<Utc>>,
+        /// RFC 5545 `RRULE`, `EXDATE` and `RDATE` lines.
+        pub recurrence: Vec<String>,
+        pub title: String,
+        pub description: Option<String>,
+        pub location: Option<String>,
+        pub meeting_url: Option<String>,
+        pub start: DateTime<Utc>,
+        pub end: DateTime<Utc>,
+        pub is_all_day: bool,
+        /// IANA zone the event was scheduled in, used to expand recurrences
+        /// across DST changes.
+        pub time_zone: Option<String>,
+        pub status: EventStatus,
+        pub organizer: Option<Attendee>,
+        pub attendees: Vec<Attendee>,
+        /// How the synced account answered the invite; `None` when it
+        /// wasn't invited, e.g. on a shared calendar.
+        pub self_response: Option<ResponseStatus>,
+        pub updated_at: Option<DateTime<Utc>>,
+    }
+}
+
+impl CalendarEvent {
+    pub fn is_series(&self) -> bool {
+        !self.recurrence.is_empty()
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.status == EventStatus::Cancelled
+    }
+
+    pub fn is_organized_by_self(&self) -> bool {
+        self.organizer.as_ref().is_some_and(|o| o.is_self)
+    }
+
+    /// Zero-length events count when they start inside `from..to`.
+    pub fn overlaps(&self, from: DateTime<Utc>, to: DateTime<Utc>) -> bool {
+        self.start < to && (self.end > from || self.start >= from)
+    }
+}
@@ -0,0 +1,230 @@
+use std::collections::HashSet;
+
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
+
+use crate::error::Error;
+use crate::event::{CalendarEvent, EventStatus};
+use crate::rrule::Recurrence;
+
+/// The ID of one occurrence of a series, in Google's format, so generated
+/// occurrences line up with the exceptions Google reports for them.
+pub fn instance_id(series_id: &str, start: DateTime<Utc>, is_all_day: bool) -> String {
+    if is_all_day {
+        format!("{series_id}_{}", start.format("%Y%m%d"))
+    } else {
+        format!("{series_id}_{}", start.format("%Y%m%dT%H%M%SZ"))
+    }
+}
+
+/// Every occurrence of `series` overlapping `from..to`, ignoring
+/// exceptions.
+pub fn expand_series(
+    series: &CalendarEvent,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+) -> Result<Vec<CalendarEvent>, Error> {
+    let tz = match series.time_zone.as_deref() {
+        Some(zone) if !series.is_all_day => zone.parse().unwrap_or(Tz::UTC),
+        _ => Tz::UTC,
+    };
+    let duration = series.end - series.start;
+
+    let starts = Recurrence::parse(&series.recurrence)?.starts_before(series.start, tz, to)?;
+
+    Ok(starts
+        .into_iter()
+        .filter(|start| *start + duration > from)
+        .map(|start| CalendarEvent {
+            id: instance_id(&series.id, start, series.is_all_day),
+            series_id: Some(series.id.clone()),
+            original_start: Some(start),
+            recurrence: vec![],
+            start,
+            end: start + duration,
+            ..series.clone()
+        })
+        .collect())
+}
+
+/// Turns series, their exceptions and single events into the concrete
+/// occurrences overlapping `from..to`, ordered by start.
+///
+/// Exceptions replace the occurrence they were moved or edited from.
+/// Cancelled occurrences are kept, marked as such, so callers can drop
+/// what they stored for them. A series whose rule can't be expanded is
+/// kept as its first occurrence.
+pub fn expand(
+    events: Vec<CalendarEvent>,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+) -> Vec<CalendarEvent> {
+    let (series, mut others): (Vec<_>, Vec<_>) =
+        events.into_iter().partition(CalendarEvent::is_series);
+
+    let ids: HashSet<String> = others.iter().map(|e| e.id.clone()).collect();
+    let exceptions: HashSet<(String, DateTime<Utc>)> = others
+        .iter()
+        .filter_map(|e| Some((e.series_id.clone()?, e.original_start?)))
+        .collect();
+
+    let mut expanded = Vec::new();
+    for series in series {
+        // A cancelled series has no occurrences left.
+        if series.status == EventStatus::Cancelled {
+            others.push(CalendarEvent {
+                recurrence: vec![],
+                ..series
+            });
+            continue;
+        }
+
+        match expand_series(&series, from, to) {
+            Ok(instances) => expanded.extend(instances.into_iter().filter(|instance| {
+                !ids.contains(&instance.id)
+                    && !exceptions.contains(&(series.id.clone(), instance.start))
+            })),
+            Err(_) => others.push(CalendarEvent {
+                recurrence: vec![],
+                ..series
+            }),
+        }
+    }
+
+    expanded.extend(others.into_iter().filter(|e| e.overlaps(from, to)));
+    expanded.sort_by(|a, b| a.start.cmp(&b.start).then_with(|| a.id.cmp(&b.id)));
+    expanded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::CalendarProvider;
+
+    fn utc(s: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc)
+    }
+
+    fn event(id: &str, start: &str, end: &str) -> CalendarEvent {
+        CalendarEvent {
+            id: id.into(),
+            calendar_id: "primary".into(),
+            provider: CalendarProvider::Google,
+            ical_uid: Some(format!("{id}@google.com")),
+            series_id: None,
+            original_start: None,
+            recurrence: vec![],
+            title: "Standup".into(),
+            description: None,
+            location: None,
+            meeting_url: None,
+            start: utc(start),
+            end: utc(end),
+            is_all_day: false,
+            time_zone: Some("Europe/Berlin".into()),
+            status: EventStatus::Confirmed,
+            organizer: None,
+            attendees: vec![],
+            self_response: None,
+            updated_at: None,
+        }
+    }
+
+    fn series() -> CalendarEvent {
+        CalendarEvent {
+            recurrence: vec![
+                "RRULE:FREQ=DAILY;COUNT=5".into(),
+                "EXDATE;TZID=Europe/Berlin:20260304T100000".into(),
+            ],
+            ..event("standup", "2026-03-02T09:00:00Z", "2026-03-02T09:15:00Z")
+        }
+    }
+
+    #[test]
+    fn test_expand_applies_exceptions() {
+        let moved = CalendarEvent {
+            title: "Standup (moved)".into(),
+            series_id: Some("standup".into()),
+            original_start: Some(utc("2026-03-05T09:00:00Z")),
+            ..event(
+                "standup_20260305T090000Z",
+                "2026-03-05T13:00:00Z",
+                "2026-03-05T13:15:00Z",
+            )
+        };
+        let cancelled = CalendarEvent {
+            status: EventStatus::Cancelled,
+            series_id: Some("standup".into()),
+            original_start: Some(utc("2026-03-06T09:00:00Z")),
+            ..event(
+                "standup_20260306T090000Z",
+                "2026-03-06T09:00:00Z",
+                "2026-03-06T09:00:00Z",
+            )
+        };
+        let single = CalendarEvent {
+            title: "Lunch".into(),
+            ..event("lunch", "2026-03-03T11:00:00Z", "2026-03-03T12:00:00Z")
+        };
+
+        let expanded = expand(
+            vec![series(), moved, cancelled, single],
+            utc("2026-03-03T00:00:00Z"),
+            utc("2026-04-01T00:00:00Z"),
+        );
+
+        let summary: Vec<(&str, &str, bool)> = expanded
+            .iter()
+            .map(|e| (e.id.as_str(), e.title.as_str(), e.is_cancelled()))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                ("standup_20260303T090000Z", "Standup", false),
+                ("lunch", "Lunch", false),
+                ("standup_20260305T090000Z", "Standup (moved)", false),
+                ("standup_20260306T090000Z", "Standup", true),
+            ]
+        );
+        assert_eq!(expanded[0].series_id.as_deref(), Some("standup"));
+        assert!(expanded[0].recurrence.is_empty());
+        assert_eq!(
+            expanded[0].end - expanded[0].start,
+            chrono::Duration::minutes(15)
+        );
+    }
+
+    #[test]
+    fn test_all_day_instance_ids() {
+        let birthday = CalendarEvent {
+            is_all_day: true,
+            recurrence: vec!["RRULE:FREQ=YEARLY".into()],
+            ..event("bday", "2026-03-04T00:00:00Z", "2026-03-05T00:00:00Z")
+        };
+
+        let expanded = expand(
+            vec![birthday],
+            utc("2027-01-01T00:00:00Z"),
+            utc("2028-01-01T00:00:00Z"),
+        );
+        assert_eq!(expanded.len(), 1);
+        assert_eq!(expanded[0].id, "bday_20270304");
+    }
+
+    #[test]
+    fn test_unsupported_rule_keeps_first_occurrence() {
+        let hourly = CalendarEvent {
+            recurrence: vec!["RRULE:FREQ=HOURLY".into()],
+            ..event("ping", "2026-03-02T09:00:00Z", "2026-03-02T09:05:00Z")
+        };
+
+        let expanded = expand(
+            vec![hourly],
+            utc("2026-03-01T00:00:00Z"),
+            utc("2026-03-03T00:00:00Z"),
+        );
+        assert_eq!(expanded.len(), 1);
+        assert_eq!(expanded[0].id, "ping");
+        assert!(!expanded[0].is_series());
+    }
+}
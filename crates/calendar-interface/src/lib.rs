@@ -0,0 +1,28 @@
+mod error;
+mod event;
+mod expand;
+mod rrule;
+mod zone;
+
+pub use error::Error;
+pub use event::*;
+pub use expand::{expand, expand_series, instance_id};
+pub use rrule::{ByDay, Frequency, RRule, Recurrence, Until};
+pub use zone::parse_zone;
+
+#[macro_export]
+macro_rules! common_derives {
+    ($item:item) => {
+        #[derive(
+            PartialEq,
+            Debug,
+            Clone,
+            serde::Serialize,
+            serde::Deserialize,
+            specta::Type,
+            schemars::JsonSchema,
+        )]
+        #[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+        $item
+    };
+}
@@ -0,0 +1,774 @@
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::{
+    DateTime, Datelike, Duration, LocalResult, Months, NaiveDate, NaiveDateTime, Offset, TimeZone,
+    Utc, Weekday,
+};
+use chrono_tz::Tz;
+
+use crate::error::Error;
+
+/// Periods to walk before giving up on a rule that never matches, e.g.
+/// `FREQ=YEARLY;BYMONTH=2;BYMONTHDAY=30`.
+const MAX_PERIODS: u32 = 100_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Frequency {
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+/// A `BYDAY` entry; `ordinal` picks e.g. the second (`2`) or last (`-1`)
+/// such weekday of the month, or of the year for yearly rules.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ByDay {
+    pub ordinal: Option<i8>,
+    pub weekday: Weekday,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Until {
+    Date(NaiveDate),
+    /// In the event's own time zone.
+    Local(NaiveDateTime),
+    Utc(DateTime<Utc>),
+}
+
+/// An RFC 5545 `RRULE`. Time-of-day parts (`BYHOUR` and finer) are not
+/// supported; every occurrence starts at the time of day of the event.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RRule {
+    pub frequency: Frequency,
+    pub interval: u32,
+    pub count: Option<u32>,
+    pub until: Option<Until>,
+    pub by_day: Vec<ByDay>,
+    pub by_month_day: Vec<i8>,
+    pub by_month: Vec<u8>,
+    pub by_year_day: Vec<i16>,
+    pub by_week_no: Vec<i8>,
+    pub by_set_pos: Vec<i16>,
+    pub week_start: Weekday,
+}
+
+impl RRule {
+    pub fn new(frequency: Frequency) -> Self {
+        Self {
+            frequency,
+            interval: 1,
+            count: None,
+            until: None,
+            by_day: vec![],
+            by_month_day: vec![],
+            by_month: vec![],
+            by_year_day: vec![],
+            by_week_no: vec![],
+            by_set_pos: vec![],
+            week_start: Weekday::Mon,
+        }
+    }
+
+    /// Occurrence starts from `dtstart` (local to `tz`) up to, but not
+    /// including, `to`.
+    pub(crate) fn occurrences(
+        &self,
+        dtstart: NaiveDateTime,
+        tz: Tz,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<DateTime<Utc>>, Error> {
+        if !self.by_year_day.is_empty() {
+            return Err(Error::UnsupportedRecurrence("BYYEARDAY".into()));
+        }
+        if !self.by_week_no.is_empty() {
+            return Err(Error::UnsupportedRecurrence("BYWEEKNO".into()));
+        }
+
+        let time = dtstart.time();
+        let mut occurrences = Vec::new();
+        let mut count = 0;
+
+        for period in 0..MAX_PERIODS {
+            let Some((period_start, dates)) = self.period(period, dtstart.date()) else {
+                break;
+            };
+            if resolve(tz, period_start.and_time(time)) >= to {
+                break;
+            }
+
+            for date in dates {
+                let local = date.and_time(time);
+                if local < dtstart {
+                    continue;
+                }
+                let at = resolve(tz, local);
+                let past_until = match self.until {
+                    Some(Until::Date(until)) => date > until,
+                    Some(Until::Local(until)) => local > until,
+                    Some(Until::Utc(until)) => at > until,
+                    None => false,
+                };
+                if past_until || self.count.is_some_and(|c| count >= c) || at >= to {
+                    return Ok(occurrences);
+                }
+                count += 1;
+                occurrences.push(at);
+            }
+        }
+
+        Ok(occurrences)
+    }
+
+    /// The first day of the `n`th period and the dates it produces, in
+    /// order.
+    fn period(&self, n: u32, start: NaiveDate) -> Option<(NaiveDate, Vec<NaiveDate>)> {
+        let step = n.checked_mul(self.interval)?;
+
+        let (period_start, mut dates) = match self.frequency {
+            Frequency::Daily => {
+                let day = start.checked_add_signed(Duration::days(step.into()))?;
+                let dates = if self.day_matches(day) {
+                    vec![day]
+                } else {
+                    vec![]
+                };
+                (day, dates)
+            }
+            Frequency::Weekly => {
+                let offset = (7 + start.weekday().num_days_from_monday()
+                    - self.week_start.num_days_from_monday())
+                    % 7;
+                let week = start
+                    .checked_sub_signed(Duration::days(offset.into()))?
+                    .checked_add_signed(Duration::weeks(step.into()))?;
+                let weekdays: Vec<Weekday> = if self.by_day.is_empty() {
+                    vec![start.weekday()]
+                } else {
+                    self.by_day.iter().map(|d| d.weekday).collect()
+                };
+                let dates = week
+                    .iter_days()
+                    .take(7)
+                    .filter(|d| weekdays.contains(&d.weekday()) && self.month_matches(*d))
+                    .collect();
+                (week, dates)
+            }
+            Frequency::Monthly => {
+                let month = start.with_day(1)?.checked_add_months(Months::new(step))?;
+                let dates = if self.month_matches(month) {
+                    self.days_in_month(month, start.day())
+                } else {
+                    vec![]
+                };
+                (month, dates)
+            }
+            Frequency::Yearly => {
+                let year = NaiveDate::from_ymd_opt(start.year().checked_add_unsigned(step)?, 1, 1)?;
+                (year, self.days_in_year(year, start))
+            }
+        };
+
+        dates.sort_unstable();
+        dates.dedup();
+        Some((period_start, self.apply_set_pos(dates)))
+    }
+
+    fn days_in_year(&self, year: NaiveDate, start: NaiveDate) -> Vec<NaiveDate> {
+        let months = (1..=12).filter_map(|m| year.with_month(m));
+
+        if !self.by_month.is_empty() || !self.by_month_day.is_empty() {
+            months
+                .filter(|m| self.by_month.is_empty() || self.month_matches(*m))
+                .flat_map(|m| self.days_in_month(m, start.day()))
+                .collect()
+        } else if !self.by_day.is_empty() {
+            let next = year.with_year(year.year() + 1).unwrap_or(NaiveDate::MAX);
+            self.by_day
+                .iter()
+                .flat_map(|by_day| weekdays_between(year, next, *by_day))
+                .collect()
+        } else {
+            year.with_month(start.month())
+                .and_then(|m| m.with_day(start.day()))
+                .into_iter()
+                .collect()
+        }
+    }
+
+    /// Dates in the month starting at `month`, falling back to the start's
+    /// day of month when no `BYMONTHDAY`/`BYDAY` is given.
+    fn days_in_month(&self, month: NaiveDate, start_day: u32) -> Vec<NaiveDate> {
+        let next = month
+            .checked_add_months(Months::new(1))
+            .unwrap_or(NaiveDate::MAX);
+        let length = (next - month).num_days() as u32;
+
+        let by_month_day: Vec<NaiveDate> = self
+            .by_month_day
+            .iter()
+            .filter_map(|&d| month.with_day(month_day(d, length)?))
+            .collect();
+        let by_day: Vec<NaiveDate> = self
+            .by_day
+            .iter()
+            .flat_map(|by_day| weekdays_between(month, next, *by_day))
+            .collect();
+
+        match (self.by_month_day.is_empty(), self.by_day.is_empty()) {
+            (false, false) => by_month_day
+                .into_iter()
+                .filter(|d| by_day.contains(d))
+                .collect(),
+            (false, true) => by_month_day,
+            (true, false) => by_day,
+            (true, true) => month.with_day(start_day).into_iter().collect(),
+        }
+    }
+
+    fn day_matches(&self, day: NaiveDate) -> bool {
+        let length = day
+            .with_day(1)
+            .zip(
+                day.with_day(1)
+                    .and_then(|m| m.checked_add_months(Months::new(1))),
+            )
+            .map_or(31, |(month, next)| (next - month).num_days() as u32);
+
+        self.month_matches(day)
+            && (self.by_month_day.is_empty()
+                || self
+                    .by_month_day
+                    .iter()
+                    .any(|&d| month_day(d, length) == Some(day.day())))
+            && (self.by_day.is_empty() || self.by_day.iter().any(|d| d.weekday == day.weekday()))
+    }
+
+    fn month_matches(&self, day: NaiveDate) -> bool {
+        self.by_month.is_empty() || self.by_month.contains(&(day.month() as u8))
+    }
+
+    fn apply_set_pos(&self, dates: Vec<NaiveDate>) -> Vec<NaiveDate> {
+        if self.by_set_pos.is_empty() {
+            return dates;
+        }
+        let mut picked: Vec<NaiveDate> = self
+            .by_set_pos
+            .iter()
+            .filter_map(|&pos| pick(&dates, pos.into()))
+            .collect();
+        picked.sort_unstable();
+        picked.dedup();
+        picked
+    }
+}
+
+impl FromStr for RRule {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value = s.strip_prefix("RRULE:").unwrap_or(s);
+        let invalid = |part: &str| Error::InvalidRecurrence(format!("{part} in {s}"));
+
+        let mut frequency = None;
+        let mut rule = RRule::new(Frequency::Daily);
+
+        for part in value.split(';').filter(|p| !p.is_empty()) {
+            let (key, value) = part.split_once('=').ok_or_else(|| invalid(part))?;
+            let list = || value.split(',');
+            match key.to_ascii_uppercase().as_str() {
+                "FREQ" => {
+                    frequency = Some(match value {
+                        "DAILY" => Frequency::Daily,
+                        "WEEKLY" => Frequency::Weekly,
+                        "MONTHLY" => Frequency::Monthly,
+                        "YEARLY" => Frequency::Yearly,
+                        _ => return Err(Error::UnsupportedRecurrence(part.into())),
+                    })
+                }
+                "INTERVAL" => {
+                    rule.interval = value.parse().map_err(|_| invalid(part))?;
+                    if rule.interval == 0 {
+                        return Err(invalid(part));
+                    }
+                }
+                "COUNT" => rule.count = Some(value.parse().map_err(|_| invalid(part))?),
+                "UNTIL" => rule.until = Some(parse_until(value).ok_or_else(|| invalid(part))?),
+                "BYDAY" => {
+                    rule.by_day = list()
+                        .map(parse_by_day)
+                        .collect::<Option<_>>()
+                        .ok_or_else(|| invalid(part))?
+                }
+                "BYMONTHDAY" => {
+                    rule.by_month_day = parse_list(value).ok_or_else(|| invalid(part))?
+                }
+                "BYMONTH" => rule.by_month = parse_list(value).ok_or_else(|| invalid(part))?,
+                "BYYEARDAY" => rule.by_year_day = parse_list(value).ok_or_else(|| invalid(part))?,
+                "BYWEEKNO" => rule.by_week_no = parse_list(value).ok_or_else(|| invalid(part))?,
+                "BYSETPOS" => rule.by_set_pos = parse_list(value).ok_or_else(|| invalid(part))?,
+                "WKST" => rule.week_start = parse_weekday(value).ok_or_else(|| invalid(part))?,
+                _ => return Err(Error::UnsupportedRecurrence(part.into())),
+            }
+        }
+
+        rule.frequency = frequency.ok_or_else(|| invalid("missing FREQ"))?;
+        if rule.count.is_some() && rule.until.is_some() {
+            return Err(invalid("COUNT with UNTIL"));
+        }
+        Ok(rule)
+    }
+}
+
+impl fmt::Display for RRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let frequency = match self.frequency {
+            Frequency::Daily => "DAILY",
+            Frequency::Weekly => "WEEKLY",
+            Frequency::Monthly => "MONTHLY",
+            Frequency::Yearly => "YEARLY",
+        };
+        write!(f, "RRULE:FREQ={frequency}")?;
+
+        if self.interval != 1 {
+            write!(f, ";INTERVAL={}", self.interval)?;
+        }
+        if let Some(count) = self.count {
+            write!(f, ";COUNT={count}")?;
+        }
+        match self.until {
+            Some(Until::Date(date)) => write!(f, ";UNTIL={}", date.format("%Y%m%d"))?,
+            Some(Until::Local(local)) => write!(f, ";UNTIL={}", local.format("%Y%m%dT%H%M%S"))?,
+            Some(Until::Utc(at)) => write!(f, ";UNTIL={}", at.format("%Y%m%dT%H%M%SZ"))?,
+            None => {}
+        }
+        if !self.by_day.is_empty() {
+            let days: Vec<String> = self
+                .by_day
+                .iter()
+                .map(|d| match d.ordinal {
+                    Some(n) => format!("{n}{}", weekday_code(d.weekday)),
+                    None => weekday_code(d.weekday).to_string(),
+                })
+                .collect();
+            write!(f, ";BYDAY={}", days.join(","))?;
+        }
+        write_list(f, "BYMONTHDAY", &self.by_month_day)?;
+        write_list(f, "BYMONTH", &self.by_month)?;
+        write_list(f, "BYYEARDAY", &self.by_year_day)?;
+        write_list(f, "BYWEEKNO", &self.by_week_no)?;
+        write_list(f, "BYSETPOS", &self.by_set_pos)?;
+        if self.week_start != Weekday::Mon {
+            write!(f, ";WKST={}", weekday_code(self.week_start))?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum RecurrenceDate {
+    Date(NaiveDate),
+    /// Floating times (no `TZID`) are in the event's own time zone.
+    Local(NaiveDateTime, Option<Tz>),
+    Utc(DateTime<Utc>),
+}
+
+impl RecurrenceDate {
+    fn resolve(&self, tz: Tz, time_of_day: NaiveDateTime) -> DateTime<Utc> {
+        match self {
+            Self::Date(date) => resolve(tz, date.and_time(time_of_day.time())),
+            Self::Local(local, zone) => resolve(zone.unwrap_or(tz), *local),
+            Self::Utc(at) => *at,
+        }
+    }
+}
+
+/// The recurrence lines of a series: `RRULE`s plus `EXDATE`/`RDATE` lists.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Recurrence {
+    rules: Vec<RRule>,
+    exdates: Vec<RecurrenceDate>,
+    rdates: Vec<RecurrenceDate>,
+}
+
+impl Recurrence {
+    pub fn parse<S: AsRef<str>>(lines: &[S]) -> Result<Self, Error> {
+        let mut recurrence = Self {
+            rules: vec![],
+            exdates: vec![],
+            rdates: vec![],
+        };
+
+        for line in lines {
+            let line = line.as_ref().trim();
+            let (head, value) = line
+                .split_once(':')
+                .ok_or_else(|| Error::InvalidRecurrence(line.into()))?;
+            let mut params = head.split(';');
+            let name = params.next().unwrap_or_default().to_ascii_uppercase();
+
+            match name.as_str() {
+                "RRULE" => recurrence.rules.push(value.parse()?),
+                "EXDATE" | "RDATE" => {
+                    let dates = parse_dates(params, value)
+                        .ok_or_else(|| Error::InvalidRecurrence(line.into()))?;
+                    if name == "EXDATE" {
+                        recurrence.exdates.extend(dates);
+                    } else {
+                        recurrence.rdates.extend(dates);
+                    }
+                }
+                _ => return Err(Error::UnsupportedRecurrence(line.into())),
+            }
+        }
+
+        Ok(recurrence)
+    }
+
+    pub fn rules(&self) -> &[RRule] {
+        &self.rules
+    }
+
+    /// Start times of every occurrence before `to`, in order, for a series
+    /// whose first occurrence starts at `dtstart` in `tz`.
+    pub fn starts_before(
+        &self,
+        dtstart: DateTime<Utc>,
+        tz: Tz,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<DateTime<Utc>>, Error> {
+        let local = dtstart.with_timezone(&tz).naive_local();
+
+        let mut starts = vec![dtstart];
+        for rule in &self.rules {
+            starts.extend(rule.occurrences(local, tz, to)?);
+        }
+        starts.extend(self.rdates.iter().map(|d| d.resolve(tz, local)));
+
+        starts.retain(|start| {
+            *start < to
+                && !self.exdates.iter().any(|exdate| match exdate {
+                    RecurrenceDate::Date(date) => start.with_timezone(&tz).date_naive() == *date,
+                    other => other.resolve(tz, local) == *start,
+                })
+        });
+        starts.sort_unstable();
+        starts.dedup();
+        Ok(starts)
+    }
+}
+
+/// Converts a local time to UTC. Ambiguous times take the earlier instant;
+/// times skipped by a DST change keep the offset from before the change,
+/// as RFC 5545 asks.
+fn resolve(tz: Tz, local: NaiveDateTime) -> DateTime<Utc> {
+    match tz.from_local_datetime(&local) {
+        LocalResult::Single(at) | LocalResult::Ambiguous(at, _) => at.with_timezone(&Utc),
+        LocalResult::None => {
+            let before = tz.offset_from_utc_datetime(&(local - Duration::days(1)));
+            (local - Duration::seconds(before.fix().local_minus_utc().into())).and_utc()
+        }
+    }
+}
+
+/// Resolves a `BYMONTHDAY` value, counting negative ones from the end of a
+/// month with `length` days.
+fn month_day(day: i8, length: u32) -> Option<u32> {
+    let day = if day < 0 {
+        length.checked_sub(u32::from(day.unsigned_abs()) - 1)?
+    } else {
+        day as u32
+    };
+    (1..=length).contains(&day).then_some(day)
+}
+
+fn pick<T: Copy>(items: &[T], position: i32) -> Option<T> {
+    let index = if position < 0 {
+        items.len().checked_sub(position.unsigned_abs() as usize)?
+    } else {
+        (position as usize).checked_sub(1)?
+    };
+    items.get(index).copied()
+}
+
+/// Days in `from..to` falling on `by_day.weekday`, narrowed to the
+/// `by_day.ordinal`th one when set.
+fn weekdays_between(from: NaiveDate, to: NaiveDate, by_day: ByDay) -> Vec<NaiveDate> {
+    let days: Vec<NaiveDate> = from
+        .iter_days()
+        .take_while(|d| *d < to)
+        .filter(|d| d.weekday() == by_day.weekday)
+        .collect();
+    match by_day.ordinal {
+        Some(n) => pick(&days, n.into()).into_iter().collect(),
+        None => days,
+    }
+}
+
+fn parse_list<T: FromStr>(value: &str) -> Option<Vec<T>> {
+    value.split(',').map(|v| v.trim().parse().ok()).collect()
+}
+
+fn parse_by_day(value: &str) -> Option<ByDay> {
+    let value = value.trim();
+    let split = value.len().checked_sub(2)?;
+    let (ordinal, weekday) = value.split_at(split);
+    Some(ByDay {
+        ordinal: match ordinal.trim_start_matches('+') {
+            "" => None,
+            n => Some(n.parse().ok().filter(|n: &i8| *n != 0)?),
+        },
+        weekday: parse_weekday(weekday)?,
+    })
+}
+
+fn parse_weekday(value: &str) -> Option<Weekday> {
+    Some(match value {
+        "MO" => Weekday::Mon,
+        "TU" => Weekday::Tue,
+        "WE" => Weekday::Wed,
+        "TH" => Weekday::Thu,
+        "FR" => Weekday::Fri,
+        "SA" => Weekday::Sat,
+        "SU" => Weekday::Sun,
+        _ => return None,
+    })
+}
+
+fn weekday_code(weekday: Weekday) -> &'static str {
+    match weekday {
+        Weekday::Mon => "MO",
+        Weekday::Tue => "TU",
+        Weekday::Wed => "WE",
+        Weekday::Thu => "TH",
+        Weekday::Fri => "FR",
+        Weekday::Sat => "SA",
+        Weekday::Sun => "SU",
+    }
+}
+
+fn write_list<T: fmt::Display>(
+    f: &mut fmt::Formatter<'_>,
+    name: &str,
+    values: &[T],
+) -> fmt::Result {
+    if values.is_empty() {
+        return Ok(());
+    }
+    let values: Vec<String> = values.iter().map(ToString::to_string).collect();
+    write!(f, ";{name}={}", values.join(","))
+}
+
+fn parse_until(value: &str) -> Option<Until> {
+    Some(match parse_date_value(value, None)? {
+        RecurrenceDate::Date(date) => Until::Date(date),
+        RecurrenceDate::Local(local, _) => Until::Local(local),
+        RecurrenceDate::Utc(at) => Until::Utc(at),
+    })
+}
+
+fn parse_dates<'a>(
+    params: impl Iterator<Item = &'a str>,
+    value: &str,
+) -> Option<Vec<RecurrenceDate>> {
+    let mut tz = None;
+    for param in params {
+        match param.split_once('=') {
+            Some(("TZID", id)) => tz = Some(id.trim_matches('"').parse::<Tz>().ok()?),
+            Some(("VALUE", "DATE" | "DATE-TIME")) => {}
+            _ => return None,
+        }
+    }
+    value.split(',').map(|v| parse_date_value(v, tz)).collect()
+}
+
+fn parse_date_value(value: &str, tz: Option<Tz>) -> Option<RecurrenceDate> {
+    let value = value.trim();
+    if let Some(utc) = value.strip_suffix('Z') {
+        let at = NaiveDateTime::parse_from_str(utc, "%Y%m%dT%H%M%S").ok()?;
+        return Some(RecurrenceDate::Utc(at.and_utc()));
+    }
+    if value.len() == 8 {
+        return NaiveDate::parse_from_str(value, "%Y%m%d")
+            .ok()
+            .map(RecurrenceDate::Date);
+    }
+    NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%S")
+        .ok()
+        .map(|local| RecurrenceDate::Local(local, tz))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utc(s: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc)
+    }
+
+    fn starts(lines: &[&str], dtstart: &str, tz: &str, to: &str) -> Vec<String> {
+        Recurrence::parse(lines)
+            .unwrap()
+            .starts_before(utc(dtstart), tz.parse().unwrap(), utc(to))
+            .unwrap()
+            .iter()
+            .map(|s| s.format("%Y-%m-%dT%H:%MZ").to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_and_display_round_trip() {
+        let line = "RRULE:FREQ=MONTHLY;INTERVAL=2;UNTIL=20261231T235959Z;BYDAY=-1FR,2MO;BYSETPOS=1;WKST=SU";
+        let rule: RRule = line.parse().unwrap();
+        assert_eq!(rule.frequency, Frequency::Monthly);
+        assert_eq!(
+            rule.by_day[0],
+            ByDay {
+                ordinal: Some(-1),
+                weekday: Weekday::Fri
+            }
+        );
+        assert_eq!(rule.to_string(), line);
+
+        assert!(matches!(
+            "FREQ=HOURLY".parse::<RRule>(),
+            Err(Error::UnsupportedRecurrence(_))
+        ));
+        assert!(matches!(
+            "FREQ=DAILY;BYHOUR=9".parse::<RRule>(),
+            Err(Error::UnsupportedRecurrence(_))
+        ));
+        assert!(matches!(
+            "FREQ=DAILY;COUNT=2;UNTIL=20260101".parse::<RRule>(),
+            Err(Error::InvalidRecurrence(_))
+        ));
+    }
+
+    #[test]
+    fn test_weekly_keeps_local_time_across_dst() {
+        // New York moves to EDT on 2026-03-08.
+        assert_eq!(
+            starts(
+                &["RRULE:FREQ=WEEKLY;BYDAY=MO,WE;COUNT=4"],
+                "2026-03-02T14:00:00Z",
+                "America/New_York",
+                "2026-04-01T00:00:00Z",
+            ),
+            [
+                "2026-03-02T14:00Z",
+                "2026-03-04T14:00Z",
+                "2026-03-09T13:00Z",
+                "2026-03-11T13:00Z",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_monthly_ordinals_and_negative_days() {
+        assert_eq!(
+            starts(
+                &["RRULE:FREQ=MONTHLY;BYDAY=-1FR"],
+                "2026-01-30T09:00:00Z",
+                "UTC",
+                "2026-04-01T00:00:00Z",
+            ),
+            [
+                "2026-01-30T09:00Z",
+                "2026-02-27T09:00Z",
+                "2026-03-27T09:00Z"
+            ]
+        );
+        assert_eq!(
+            starts(
+                &["RRULE:FREQ=MONTHLY;BYMONTHDAY=-1;COUNT=3"],
+                "2026-01-31T09:00:00Z",
+                "UTC",
+                "2027-01-01T00:00:00Z",
+            ),
+            [
+                "2026-01-31T09:00Z",
+                "2026-02-28T09:00Z",
+                "2026-03-31T09:00Z"
+            ]
+        );
+        // Months without a 31st are skipped, not clamped.
+        assert_eq!(
+            starts(
+                &["RRULE:FREQ=MONTHLY;UNTIL=20260601"],
+                "2026-01-31T09:00:00Z",
+                "UTC",
+                "2027-01-01T00:00:00Z",
+            ),
+            [
+                "2026-01-31T09:00Z",
+                "2026-03-31T09:00Z",
+                "2026-05-31T09:00Z"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_set_pos_and_yearly() {
+        // Last weekday of the month.
+        assert_eq!(
+            starts(
+                &["RRULE:FREQ=MONTHLY;BYDAY=MO,TU,WE,TH,FR;BYSETPOS=-1;COUNT=2"],
+                "2026-02-27T09:00:00Z",
+                "UTC",
+                "2027-01-01T00:00:00Z",
+            ),
+            ["2026-02-27T09:00Z", "2026-03-31T09:00Z"]
+        );
+        // US Thanksgiving.
+        assert_eq!(
+            starts(
+                &["RRULE:FREQ=YEARLY;BYMONTH=11;BYDAY=4TH"],
+                "2026-11-26T17:00:00Z",
+                "UTC",
+                "2028-01-01T00:00:00Z",
+            ),
+            ["2026-11-26T17:00Z", "2027-11-25T17:00Z"]
+        );
+    }
+
+    #[test]
+    fn test_exdates_and_rdates() {
+        assert_eq!(
+            starts(
+                &[
+                    "RRULE:FREQ=DAILY;COUNT=5",
+                    "EXDATE;TZID=Europe/Berlin:20260303T100000,20260304T100000",
+                    "RDATE:20260310T090000Z",
+                ],
+                "2026-03-02T09:00:00Z",
+                "Europe/Berlin",
+                "2026-04-01T00:00:00Z",
+            ),
+            [
+                "2026-03-02T09:00Z",
+                "2026-03-05T09:00Z",
+                "2026-03-06T09:00Z",
+                "2026-03-10T09:00Z",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_time_in_dst_gap_moves_forward() {
+        // 02:30 doesn't exist in New York on 2026-03-08.
+        assert_eq!(
+            starts(
+                &["RRULE:FREQ=DAILY;COUNT=3"],
+                "2026-03-07T07:30:00Z",
+                "America/New_York",
+                "2026-04-01T00:00:00Z",
+            ),
+            [
+                "2026-03-07T07:30Z",
+                "2026-03-08T07:30Z",
+                "2026-03-09T06:30Z"
+            ]
+        );
+    }
+}
@@ -1,8 +1,12 @@
+mod streams;
+
 use super::InstalledApp;
 use std::collections::HashMap;
 use std::fs;
 use std::path::PathBuf;
 
+use streams::{InputStream, ProcessLookup};
+
 pub fn list_installed_apps() -> Vec<InstalledApp> {
     let desktop_dirs = get_desktop_file_dirs();
     let mut apps = HashMap::new();
@@ -26,55 +30,103 @@ pub fn list_installed_apps() -> Vec<InstalledApp> {
 }
 
 pub fn list_mic_using_apps() -> Vec<InstalledApp> {
+    let lookup = LocalProcesses;
+
+    let mut apps: Vec<InstalledApp> = capturing_streams()
+        .iter()
+        .filter_map(|stream| {
+            let app = streams::identify(stream, &lookup);
+            tracing::debug!(
+                pid = ?stream.pid(),
+                props = ?stream.props,
+                app = ?app,
+                "mic_stream"
+            );
+            app
+        })
+        .collect();
+
+    apps.sort_by(|a, b| a.id.cmp(&b.id));
+    apps.dedup_by(|a, b| a.id == b.id);
+    apps.sort_by(|a, b| a.name.cmp(&b.name));
+    apps
+}
+
+pub(crate) fn is_mic_in_use() -> bool {
+    !capturing_streams().is_empty()
+}
+
+/// Streams capturing from a source right now, from PipeWire when it is
+/// running, or from PulseAudio otherwise.
+fn capturing_streams() -> Vec<InputStream> {
+    pipewire_streams()
+        .unwrap_or_else(pulse_streams)
+        .into_iter()
+        .filter(|stream| stream.active && !stream.is_monitor())
+        .collect()
+}
+
+fn pipewire_streams() -> Option<Vec<InputStream>> {
+    let output = std::process::Command::new("pw-dump")
+        .arg("--no-colors")
+        .output()
+        .ok()
+        .filter(|output| output.status.success())?;
+
+    let json = String::from_utf8_lossy(&output.stdout);
+    match streams::parse_pw_dump(&json) {
+        Ok(streams) => Some(streams),
+        Err(e) => {
+            tracing::warn!(error = %e, "failed_to_parse_pw_dump");
+            None
+        }
+    }
+}
+
+fn pulse_streams() -> Vec<InputStream> {
     use libpulse_binding::context::{Context, FlagSet as ContextFlagSet};
     use libpulse_binding::mainloop::standard::{IterateResult, Mainloop};
     use std::cell::RefCell;
     use std::rc::Rc;
 
-    let mut apps = Vec::new();
-
     let mut mainloop = match Mainloop::new() {
         Some(m) => m,
-        None => return apps,
+        None => return Vec::new(),
     };
 
     let mut context = match Context::new(&mainloop, "hyprnote-detect") {
         Some(c) => c,
-        None => return apps,
+        None => return Vec::new(),
     };
 
     if context
         .connect(None, ContextFlagSet::NOFLAGS, None)
         .is_err()
     {
-        return apps;
+        return Vec::new();
     }
 
-    let apps_rc: Rc<RefCell<Vec<InstalledApp>>> = Rc::new(RefCell::new(Vec::new()));
-    let apps_clone = apps_rc.clone();
+    let streams_rc: Rc<RefCell<Vec<InputStream>>> = Rc::new(RefCell::new(Vec::new()));
+    let streams_clone = streams_rc.clone();
 
     let introspect = context.introspect();
     introspect.get_source_output_info_list(move |result| {
         use libpulse_binding::callbacks::ListResult;
 
         if let ListResult::Item(info) = result {
-            let props = &info.proplist;
-            let app_name = props
-                .get_str("application.name")
-                .or_else(|| props.get_str("application.process.binary"))
-                .unwrap_or_default();
-
-            let app_id = props
-                .get_str("application.process.binary")
-                .or_else(|| props.get_str("application.name"))
-                .unwrap_or_default();
-
-            if !app_name.is_empty() && !app_id.is_empty() {
-                apps_clone.borrow_mut().push(InstalledApp {
-                    id: app_id,
-                    name: app_name,
-                });
-            }
+            let props = info
+                .proplist
+                .iter()
+                .filter_map(|key| {
+                    let value = info.proplist.get_str(&key)?;
+                    Some((key, value))
+                })
+                .collect();
+
+            streams_clone.borrow_mut().push(InputStream {
+                props,
+                active: !info.corked,
+            });
         }
     });
 
@@ -87,11 +139,51 @@ pub fn list_mic_using_apps() -> Vec<InstalledApp> {
 
     context.disconnect();
 
-    apps = apps_rc.borrow().clone();
-    apps.sort_by(|a, b| a.id.cmp(&b.id));
-    apps.dedup_by(|a, b| a.id == b.id);
-    apps.sort_by(|a, b| a.name.cmp(&b.name));
-    apps
+    streams_rc.take()
+}
+
+struct LocalProcesses;
+
+impl ProcessLookup for LocalProcesses {
+    fn cmdline(&self, pid: u32) -> Option<Vec<String>> {
+        let cmdline = fs::read_to_string(format!("/proc/{pid}/cmdline")).ok()?;
+        Some(
+            cmdline
+                .split('\0')
+                .filter(|arg| !arg.is_empty())
+                .map(str::to_string)
+                .collect(),
+        )
+    }
+
+    fn parent(&self, pid: u32) -> Option<u32> {
+        let status = fs::read_to_string(format!("/proc/{pid}/status")).ok()?;
+        status
+            .lines()
+            .find_map(|line| line.strip_prefix("PPid:"))
+            .and_then(|ppid| ppid.trim().parse().ok())
+    }
+
+    fn web_app(&self, app_id: &str) -> Option<InstalledApp> {
+        // Chromium-based browsers install these as `<browser>-<id>-<profile>.desktop`.
+        let needle = format!("-{app_id}-");
+
+        get_desktop_file_dirs().into_iter().find_map(|dir| {
+            fs::read_dir(dir).ok()?.flatten().find_map(|entry| {
+                let path = entry.path();
+                let stem = path.file_stem()?.to_str()?.to_string();
+                if !stem.contains(&needle) {
+                    return None;
+                }
+
+                let app = parse_desktop_file(&path)?;
+                Some(InstalledApp {
+                    id: stem,
+                    name: app.name,
+                })
+            })
+        })
+    }
 }
 
 fn get_desktop_file_dirs() -> Vec<PathBuf> {
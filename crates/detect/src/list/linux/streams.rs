@@ -0,0 +1,406 @@
+use std::collections::HashMap;
+
+use crate::InstalledApp;
+
+/// A capture stream: a PipeWire `Stream/Input/Audio` node, or a PulseAudio
+/// source output. Both carry the same `application.*` properties.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct InputStream {
+    pub props: HashMap<String, String>,
+    /// Capturing right now, rather than corked or paused.
+    pub active: bool,
+}
+
+struct KnownApp {
+    id: &'static str,
+    name: &'static str,
+    binaries: &'static [&'static str],
+    browser: bool,
+}
+
+/// Matched by Flatpak app ID or process binary. The binary comes first,
+/// since Electron apps report themselves as "Chromium".
+const KNOWN_APPS: &[KnownApp] = &[
+    KnownApp {
+        id: "us.zoom.Zoom",
+        name: "Zoom",
+        binaries: &["zoom", "zoom.real"],
+        browser: false,
+    },
+    KnownApp {
+        id: "com.slack.Slack",
+        name: "Slack",
+        binaries: &["slack"],
+        browser: false,
+    },
+    KnownApp {
+        id: "com.github.IsmaelMartinez.teams_for_linux",
+        name: "Teams for Linux",
+        binaries: &["teams-for-linux"],
+        browser: false,
+    },
+    KnownApp {
+        id: "com.microsoft.Teams",
+        name: "Microsoft Teams",
+        binaries: &["teams"],
+        browser: false,
+    },
+    KnownApp {
+        id: "com.discordapp.Discord",
+        name: "Discord",
+        binaries: &["discord"],
+        browser: false,
+    },
+    KnownApp {
+        id: "com.skype.Client",
+        name: "Skype",
+        binaries: &["skypeforlinux"],
+        browser: false,
+    },
+    KnownApp {
+        id: "com.google.Chrome",
+        name: "Google Chrome",
+        binaries: &["chrome", "google-chrome", "google-chrome-stable"],
+        browser: true,
+    },
+    KnownApp {
+        id: "org.chromium.Chromium",
+        name: "Chromium",
+        binaries: &["chromium", "chromium-browser"],
+        browser: true,
+    },
+    KnownApp {
+        id: "com.brave.Browser",
+        name: "Brave",
+        binaries: &["brave", "brave-browser"],
+        browser: true,
+    },
+    KnownApp {
+        id: "com.microsoft.Edge",
+        name: "Microsoft Edge",
+        binaries: &["msedge", "microsoft-edge"],
+        browser: true,
+    },
+    KnownApp {
+        id: "org.mozilla.firefox",
+        name: "Firefox",
+        binaries: &["firefox", "firefox-bin", "firefox-esr"],
+        browser: true,
+    },
+];
+
+/// Tab titles of web meetings, as Firefox reports them in `media.name`.
+const WEB_MEETINGS: &[(&str, &str)] = &[
+    ("Google Meet", "Google Meet"),
+    ("Meet – ", "Google Meet"),
+    ("Microsoft Teams", "Microsoft Teams"),
+    ("Zoom", "Zoom"),
+    ("Slack", "Slack"),
+    ("Jitsi Meet", "Jitsi Meet"),
+    ("Whereby", "Whereby"),
+];
+
+/// Level meters and effect chains that keep a capture stream open without
+/// anyone being in a call.
+const MONITOR_BINARIES: &[&str] = &["pavucontrol", "pwvucontrol", "easyeffects"];
+
+/// Names Electron and ALSA clients report instead of their own.
+const GENERIC_NAMES: &[&str] = &["Chromium", "Electron", "ALSA plug-in"];
+
+/// How many parent processes to search for a browser's `--app-id`.
+const MAX_ANCESTORS: usize = 4;
+
+pub(crate) trait ProcessLookup {
+    fn cmdline(&self, pid: u32) -> Option<Vec<String>>;
+    fn parent(&self, pid: u32) -> Option<u32>;
+    /// The installed web app a browser launches with `--app-id`.
+    fn web_app(&self, app_id: &str) -> Option<InstalledApp>;
+}
+
+impl InputStream {
+    fn prop(&self, key: &str) -> Option<&str> {
+        self.props
+            .get(key)
+            .map(|v| v.trim())
+            .filter(|v| !v.is_empty())
+    }
+
+    pub fn pid(&self) -> Option<u32> {
+        self.prop("application.process.id")?.parse().ok()
+    }
+
+    fn binary(&self) -> Option<&str> {
+        self.prop("application.process.binary")
+            .and_then(|b| b.rsplit('/').next())
+    }
+
+    fn flatpak_id(&self) -> Option<&str> {
+        self.prop("pipewire.access.portal.app_id")
+            .or_else(|| self.prop("application.id"))
+    }
+
+    pub fn is_monitor(&self) -> bool {
+        self.prop("stream.monitor") == Some("true")
+            || self
+                .binary()
+                .is_some_and(|b| MONITOR_BINARIES.contains(&b.to_lowercase().as_str()))
+    }
+}
+
+/// Parses `pw-dump` output into its capture streams.
+pub(crate) fn parse_pw_dump(json: &str) -> Result<Vec<InputStream>, serde_json::Error> {
+    let objects: Vec<serde_json::Value> = serde_json::from_str(json)?;
+
+    Ok(objects
+        .iter()
+        .filter(|o| o["type"] == "PipeWire:Interface:Node")
+        .filter(|o| o["info"]["props"]["media.class"] == "Stream/Input/Audio")
+        .map(|o| {
+            let props = o["info"]["props"]
+                .as_object()
+                .map(|props| {
+                    props
+                        .iter()
+                        .map(|(k, v)| {
+                            let value = match v {
+                                serde_json::Value::String(s) => s.clone(),
+                                other => other.to_string(),
+                            };
+                            (k.clone(), value)
+                        })
+                        .collect()
+                })
+                .unwrap_or_default();
+
+            InputStream {
+                props,
+                active: o["info"]["state"] == "running",
+            }
+        })
+        .collect())
+}
+
+/// Works out which app owns `stream`.
+pub(crate) fn identify(stream: &InputStream, lookup: &impl ProcessLookup) -> Option<InstalledApp> {
+    let binary = stream.binary();
+    let flatpak_id = stream.flatpak_id();
+
+    let known = KNOWN_APPS.iter().find(|app| {
+        binary.is_some_and(|b| app.binaries.contains(&b.to_lowercase().as_str()))
+            || flatpak_id.is_some_and(|id| id.eq_ignore_ascii_case(app.id))
+    });
+
+    if let Some(app) = known {
+        if !app.browser {
+            return Some(InstalledApp {
+                id: app.id.to_string(),
+                name: app.name.to_string(),
+            });
+        }
+
+        if let Some(web_app) = stream.pid().and_then(|pid| find_web_app(pid, lookup)) {
+            return Some(web_app);
+        }
+
+        let name = stream
+            .prop("media.name")
+            .and_then(|title| {
+                WEB_MEETINGS
+                    .iter()
+                    .find(|(pattern, _)| title.contains(pattern))
+            })
+            .map(|(_, meeting)| format!("{meeting} ({})", app.name))
+            .unwrap_or_else(|| app.name.to_string());
+
+        return Some(InstalledApp {
+            id: app.id.to_string(),
+            name,
+        });
+    }
+
+    let name = stream
+        .prop("application.name")
+        .filter(|name| !GENERIC_NAMES.iter().any(|g| name.starts_with(g)))
+        .or(binary)?;
+    let id = flatpak_id.or(binary).unwrap_or(name);
+
+    Some(InstalledApp {
+        id: id.to_string(),
+        name: name.to_string(),
+    })
+}
+
+/// Installed web apps (PWAs) run as the browser, launched with
+/// `--app-id=<id>`. That flag is on the process that started the window,
+/// which can be a parent of the one capturing audio.
+fn find_web_app(pid: u32, lookup: &impl ProcessLookup) -> Option<InstalledApp> {
+    let mut current = Some(pid);
+
+    for _ in 0..=MAX_ANCESTORS {
+        let pid = current?;
+        let app_id = lookup.cmdline(pid).and_then(|args| {
+            args.iter()
+                .find_map(|arg| arg.strip_prefix("--app-id=").map(str::to_string))
+        });
+
+        if let Some(app_id) = app_id {
+            return lookup.web_app(&app_id);
+        }
+        current = lookup.parent(pid).filter(|&parent| parent > 1);
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Default)]
+    struct FakeProcesses {
+        cmdlines: HashMap<u32, Vec<String>>,
+        parents: HashMap<u32, u32>,
+        web_apps: HashMap<String, InstalledApp>,
+    }
+
+    impl ProcessLookup for FakeProcesses {
+        fn cmdline(&self, pid: u32) -> Option<Vec<String>> {
+            self.cmdlines.get(&pid).cloned()
+        }
+
+        fn parent(&self, pid: u32) -> Option<u32> {
+            self.parents.get(&pid).copied()
+        }
+
+        fn web_app(&self, app_id: &str) -> Option<InstalledApp> {
+            self.web_apps.get(app_id).cloned()
+        }
+    }
+
+    fn identify_all(json: &str, lookup: &FakeProcesses) -> Vec<(String, String)> {
+        parse_pw_dump(json)
+            .unwrap()
+            .iter()
+            .filter(|s| s.active && !s.is_monitor())
+            .filter_map(|s| identify(s, lookup))
+            .map(|app| (app.id, app.name))
+            .collect()
+    }
+
+    fn pair(id: &str, name: &str) -> (String, String) {
+        (id.to_string(), name.to_string())
+    }
+
+    #[test]
+    fn test_parse_pw_dump() {
+        let streams = parse_pw_dump(include_str!("../../../data/pw-dump/zoom-slack.json")).unwrap();
+
+        assert_eq!(streams.len(), 3);
+        assert!(streams.iter().all(|s| s.active));
+        assert_eq!(streams[0].pid(), Some(48211));
+        assert!(!streams[0].is_monitor());
+        assert!(streams[2].is_monitor());
+    }
+
+    #[test]
+    fn test_identify_native_apps() {
+        let apps = identify_all(
+            include_str!("../../../data/pw-dump/zoom-slack.json"),
+            &FakeProcesses::default(),
+        );
+
+        assert_eq!(
+            apps,
+            [
+                pair("us.zoom.Zoom", "Zoom"),
+                pair("com.slack.Slack", "Slack"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_identify_browsers() {
+        let lookup = FakeProcesses {
+            cmdlines: HashMap::from([
+                (
+                    9120,
+                    vec![
+                        "/opt/microsoft/msedge/msedge".to_string(),
+                        "--type=utility".to_string(),
+                    ],
+                ),
+                (
+                    9100,
+                    vec![
+                        "/opt/microsoft/msedge/msedge".to_string(),
+                        "--profile-directory=Default".to_string(),
+                        "--app-id=cifhbcnohmdccbgoicgdjpfamggdegmo".to_string(),
+                    ],
+                ),
+            ]),
+            parents: HashMap::from([(9120, 9100), (9100, 1)]),
+            web_apps: HashMap::from([(
+                "cifhbcnohmdccbgoicgdjpfamggdegmo".to_string(),
+                InstalledApp {
+                    id: "msedge-cifhbcnohmdccbgoicgdjpfamggdegmo-Default".to_string(),
+                    name: "Microsoft Teams".to_string(),
+                },
+            )]),
+        };
+
+        let apps = identify_all(include_str!("../../../data/pw-dump/browsers.json"), &lookup);
+
+        assert_eq!(
+            apps,
+            [
+                pair("org.mozilla.firefox", "Google Meet (Firefox)"),
+                pair("com.google.Chrome", "Google Chrome"),
+                pair(
+                    "msedge-cifhbcnohmdccbgoicgdjpfamggdegmo-Default",
+                    "Microsoft Teams"
+                ),
+                pair(
+                    "com.github.IsmaelMartinez.teams_for_linux",
+                    "Teams for Linux"
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_identify_unknown_app() {
+        let stream = |props: &[(&str, &str)]| InputStream {
+            props: props
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+            active: true,
+        };
+        let lookup = FakeProcesses::default();
+
+        let app = identify(
+            &stream(&[
+                ("application.name", "Audacity"),
+                ("application.process.binary", "/usr/bin/audacity"),
+            ]),
+            &lookup,
+        )
+        .unwrap();
+        assert_eq!(
+            (app.id.as_str(), app.name.as_str()),
+            ("audacity", "Audacity")
+        );
+
+        let app = identify(
+            &stream(&[
+                ("application.name", "ALSA plug-in [mumble]"),
+                ("application.process.binary", "mumble"),
+            ]),
+            &lookup,
+        )
+        .unwrap();
+        assert_eq!((app.id.as_str(), app.name.as_str()), ("mumble", "mumble"));
+
+        assert!(identify(&stream(&[("media.name", "record")]), &lookup).is_none());
+    }
+}
@@ -1,10 +1,15 @@
 use libpulse_binding as pulse;
 use libpulse_binding::context::{Context, FlagSet as ContextFlagSet};
 use libpulse_binding::mainloop::threaded::Mainloop;
-use std::sync::{Arc, Mutex};
+use std::sync::mpsc;
 use std::time::{Duration, Instant};
 
-use crate::{BackgroundTask, DetectEvent};
+use super::diff_apps;
+use crate::{BackgroundTask, DetectEvent, InstalledApp};
+
+/// Streams come and go in bursts when a call starts; changes are checked
+/// once they settle.
+const SETTLE_DURATION: Duration = Duration::from_millis(200);
 
 #[derive(Default)]
 pub struct Detector {
@@ -15,6 +20,7 @@ struct DetectorState {
     last_state: bool,
     last_change: Instant,
     debounce_duration: Duration,
+    active_apps: Vec<InstalledApp>,
 }
 
 impl DetectorState {
@@ -23,6 +29,7 @@ impl DetectorState {
             last_state: false,
             last_change: Instant::now(),
             debounce_duration: Duration::from_millis(500),
+            active_apps: Vec::new(),
         }
     }
 
@@ -40,6 +47,47 @@ impl DetectorState {
         self.last_change = now;
         true
     }
+
+    /// Events for the mic going on or off, or, while it stays on, for apps
+    /// joining or leaving it.
+    fn update(&mut self, mic_in_use: bool, apps: Vec<InstalledApp>) -> Vec<DetectEvent> {
+        if self.should_trigger(mic_in_use) {
+            return if mic_in_use {
+                self.active_apps = apps.clone();
+                vec![DetectEvent::MicStarted(apps)]
+            } else {
+                vec![DetectEvent::MicStopped(std::mem::take(
+                    &mut self.active_apps,
+                ))]
+            };
+        }
+
+        if !(mic_in_use && self.last_state) {
+            return vec![];
+        }
+
+        let (started, stopped) = diff_apps(&self.active_apps, &apps);
+        self.active_apps = apps;
+
+        let mut events = Vec::new();
+        if !started.is_empty() {
+            events.push(DetectEvent::MicStarted(started));
+        }
+        if !stopped.is_empty() {
+            events.push(DetectEvent::MicStopped(stopped));
+        }
+        events
+    }
+}
+
+fn check_mic(state: &mut DetectorState) -> Vec<DetectEvent> {
+    let mic_in_use = crate::is_mic_in_use();
+    let apps = if mic_in_use {
+        crate::list_mic_using_apps()
+    } else {
+        vec![]
+    };
+    state.update(mic_in_use, apps)
 }
 
 impl crate::Observer for Detector {
@@ -47,10 +95,27 @@ impl crate::Observer for Detector {
         self.background.start(|running, mut rx| async move {
             let (tx, mut notify_rx) = tokio::sync::mpsc::channel(1);
 
+            let (changes_tx, changes_rx) = mpsc::channel::<()>();
+
             std::thread::spawn(move || {
-                let callback = Arc::new(Mutex::new(f));
-                let detector_state = Arc::new(Mutex::new(DetectorState::new()));
+                let mut state = DetectorState::new();
+                state.last_state = crate::is_mic_in_use();
+                if state.last_state {
+                    state.active_apps = crate::list_mic_using_apps();
+                }
+
+                while changes_rx.recv().is_ok() {
+                    std::thread::sleep(SETTLE_DURATION);
+                    while changes_rx.try_recv().is_ok() {}
+
+                    for event in check_mic(&mut state) {
+                        tracing::info!(event = ?event, "detected");
+                        f(event);
+                    }
+                }
+            });
 
+            std::thread::spawn(move || {
                 let mut mainloop = match Mainloop::new() {
                     Some(m) => m,
                     None => {
@@ -67,36 +132,16 @@ impl crate::Observer for Detector {
                     }
                 };
 
-                let callback_for_subscribe = callback.clone();
-                let detector_state_for_subscribe = detector_state.clone();
-
+                // Sources change state when the first stream opens or the
+                // last one closes; source outputs are the streams themselves.
                 context.set_subscribe_callback(Some(Box::new(
-                    move |facility, operation, _index| {
-                        if let Some(pulse::context::subscribe::Facility::Source) = facility
-                            && let Some(pulse::context::subscribe::Operation::Changed) = operation
-                            && let Ok(mut state) = detector_state_for_subscribe.lock()
-                        {
-                            let mic_in_use = check_mic_in_use();
-
-                            if state.should_trigger(mic_in_use) {
-                                if mic_in_use {
-                                    let cb = callback_for_subscribe.clone();
-                                    std::thread::spawn(move || {
-                                        let apps = crate::list_mic_using_apps();
-                                        tracing::info!("mic_started_detected: {:?}", apps);
-
-                                        if let Ok(guard) = cb.lock() {
-                                            let event = DetectEvent::MicStarted(apps);
-                                            tracing::info!(event = ?event, "detected");
-                                            (*guard)(event);
-                                        }
-                                    });
-                                } else if let Ok(guard) = callback_for_subscribe.lock() {
-                                    let event = DetectEvent::MicStopped(vec![]);
-                                    tracing::info!(event = ?event, "detected");
-                                    (*guard)(event);
-                                }
-                            }
+                    move |facility, _operation, _index| {
+                        if matches!(
+                            facility,
+                            Some(pulse::context::subscribe::Facility::Source)
+                                | Some(pulse::context::subscribe::Facility::SourceOutput)
+                        ) {
+                            let _ = changes_tx.send(());
                         }
                     },
                 )));
@@ -133,7 +178,8 @@ impl crate::Observer for Detector {
                 tracing::info!("pulseaudio_context_connected");
 
                 context.subscribe(
-                    pulse::context::subscribe::InterestMaskSet::SOURCE,
+                    pulse::context::subscribe::InterestMaskSet::SOURCE
+                        | pulse::context::subscribe::InterestMaskSet::SOURCE_OUTPUT,
                     |success| {
                         if success {
                             tracing::info!("subscribed_to_pulseaudio_source_events");
@@ -143,11 +189,6 @@ impl crate::Observer for Detector {
                     },
                 );
 
-                let initial_mic_state = check_mic_in_use();
-                if let Ok(mut state) = detector_state.lock() {
-                    state.last_state = initial_mic_state;
-                }
-
                 let _ = tx.blocking_send(());
 
                 loop {
@@ -177,89 +218,59 @@ impl crate::Observer for Detector {
     }
 }
 
-fn check_mic_in_use() -> bool {
-    let mut mainloop = match Mainloop::new() {
-        Some(m) => m,
-        None => return false,
-    };
-
-    let mut context = match Context::new(&mainloop, "hyprnote-mic-check") {
-        Some(c) => c,
-        None => return false,
-    };
-
-    let result = Arc::new(Mutex::new(false));
-    let result_clone = result.clone();
-
-    if context
-        .connect(None, ContextFlagSet::NOFLAGS, None)
-        .is_err()
-    {
-        return false;
-    }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Observer, new_callback};
 
-    if mainloop.start().is_err() {
-        return false;
+    fn app(id: &str) -> InstalledApp {
+        InstalledApp {
+            id: id.to_string(),
+            name: id.to_string(),
+        }
     }
 
-    mainloop.lock();
-    loop {
-        match context.get_state() {
-            pulse::context::State::Ready => break,
-            pulse::context::State::Failed | pulse::context::State::Terminated => {
-                mainloop.unlock();
-                return false;
+    fn ids(event: &DetectEvent) -> (&'static str, Vec<&str>) {
+        match event {
+            DetectEvent::MicStarted(apps) => {
+                ("started", apps.iter().map(|a| a.id.as_str()).collect())
             }
-            _ => {
-                mainloop.wait();
+            DetectEvent::MicStopped(apps) => {
+                ("stopped", apps.iter().map(|a| a.id.as_str()).collect())
             }
         }
     }
-    mainloop.unlock();
-
-    let introspector = context.introspect();
-    let done = Arc::new(Mutex::new(false));
-    let done_clone = done.clone();
-
-    introspector.get_source_output_info_list(move |list_result| match list_result {
-        pulse::callbacks::ListResult::Item(info) => {
-            if !info.corked
-                && let Ok(mut r) = result_clone.lock()
-            {
-                *r = true;
-            }
-        }
-        pulse::callbacks::ListResult::End => {
-            if let Ok(mut d) = done_clone.lock() {
-                *d = true;
-            }
-        }
-        pulse::callbacks::ListResult::Error => {
-            if let Ok(mut d) = done_clone.lock() {
-                *d = true;
-            }
-        }
-    });
 
-    for _ in 0..100 {
-        if let Ok(d) = done.lock()
-            && *d
-        {
-            break;
-        }
-        std::thread::sleep(Duration::from_millis(10));
+    #[test]
+    fn test_update_tracks_apps() {
+        let mut state = DetectorState::new();
+        state.last_change -= Duration::from_secs(1);
+
+        let events = state.update(true, vec![app("us.zoom.Zoom")]);
+        assert_eq!(
+            events.iter().map(ids).collect::<Vec<_>>(),
+            [("started", vec!["us.zoom.Zoom"])]
+        );
+
+        let events = state.update(true, vec![app("com.slack.Slack")]);
+        assert_eq!(
+            events.iter().map(ids).collect::<Vec<_>>(),
+            [
+                ("started", vec!["com.slack.Slack"]),
+                ("stopped", vec!["us.zoom.Zoom"])
+            ]
+        );
+
+        assert!(state.update(true, vec![app("com.slack.Slack")]).is_empty());
+
+        state.last_change -= Duration::from_secs(1);
+        let events = state.update(false, vec![]);
+        assert_eq!(
+            events.iter().map(ids).collect::<Vec<_>>(),
+            [("stopped", vec!["com.slack.Slack"])]
+        );
     }
 
-    mainloop.stop();
-
-    result.lock().map(|r| *r).unwrap_or(false)
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::{Observer, new_callback};
-
     #[tokio::test]
     async fn test_detector() {
         let mut detector = Detector::default();
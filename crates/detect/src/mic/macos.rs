@@ -1,9 +1,9 @@
 use cidre::{core_audio as ca, os};
-use std::collections::HashSet;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 
+use super::diff_apps;
 use crate::{BackgroundTask, DetectEvent, InstalledApp};
 
 const DEVICE_IS_RUNNING_SOMEWHERE: ca::PropAddr = ca::PropAddr {
@@ -121,28 +121,6 @@ fn is_mic_running(device: &ca::Device) -> bool {
         .unwrap_or(false)
 }
 
-fn diff_apps(
-    previous: &[InstalledApp],
-    current: &[InstalledApp],
-) -> (Vec<InstalledApp>, Vec<InstalledApp>) {
-    let previous_ids: HashSet<_> = previous.iter().map(|app| &app.id).collect();
-    let current_ids: HashSet<_> = current.iter().map(|app| &app.id).collect();
-
-    let started = current
-        .iter()
-        .filter(|app| !previous_ids.contains(&app.id))
-        .cloned()
-        .collect();
-
-    let stopped = previous
-        .iter()
-        .filter(|app| !current_ids.contains(&app.id))
-        .cloned()
-        .collect();
-
-    (started, stopped)
-}
-
 struct ListenerData {
     ctx: SharedContext,
     device_listener_ptr: *mut (),
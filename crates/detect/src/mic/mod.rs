@@ -27,6 +27,32 @@ impl crate::Observer for MicDetector {
     }
 }
 
+/// Apps in `current` but not `previous`, and the other way round.
+#[cfg(any(target_os = "macos", target_os = "linux"))]
+pub(crate) fn diff_apps(
+    previous: &[crate::InstalledApp],
+    current: &[crate::InstalledApp],
+) -> (Vec<crate::InstalledApp>, Vec<crate::InstalledApp>) {
+    use std::collections::HashSet;
+
+    let previous_ids: HashSet<_> = previous.iter().map(|app| &app.id).collect();
+    let current_ids: HashSet<_> = current.iter().map(|app| &app.id).collect();
+
+    let started = current
+        .iter()
+        .filter(|app| !previous_ids.contains(&app.id))
+        .cloned()
+        .collect();
+
+    let stopped = previous
+        .iter()
+        .filter(|app| !current_ids.contains(&app.id))
+        .cloned()
+        .collect();
+
+    (started, stopped)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,23 @@
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+
+    #[error(transparent)]
+    Smtp(#[from] lettre::transport::smtp::Error),
+
+    #[error(transparent)]
+    Message(#[from] lettre::error::Error),
+
+    #[error("invalid email address: {0}")]
+    InvalidAddress(String),
+
+    #[error("invalid content type: {0}")]
+    InvalidContentType(String),
+
+    #[error("message has no recipients")]
+    NoRecipients,
+
+    #[error(transparent)]
+    Template(#[from] hypr_template_app::Error),
+}
@@ -0,0 +1,155 @@
+use std::collections::HashSet;
+
+use hypr_template_app as template;
+
+use crate::{Attachment, Error, Mailbox, Message};
+
+/// A session participant, as stored in `humans`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FollowupParticipant {
+    pub name: String,
+    pub email: String,
+}
+
+/// Everything the recap is built from.
+#[derive(Clone)]
+pub struct Followup {
+    pub session: template::Session,
+    pub participants: Vec<FollowupParticipant>,
+    /// Markdown of the enhanced note.
+    pub enhanced_note: String,
+    pub action_items: Vec<template::ActionItem>,
+    /// Attached as `transcript.txt` when set.
+    pub transcript: Option<template::Transcript>,
+}
+
+/// Addresses the recap to every participant with a usable email, other
+/// than the sender. Participants without one are skipped rather than
+/// failing the whole recap.
+pub fn followup_recipients(from: &Mailbox, participants: &[FollowupParticipant]) -> Vec<Mailbox> {
+    let mut seen = HashSet::from([from.email.to_lowercase()]);
+
+    participants
+        .iter()
+        .filter_map(|p| {
+            let mailbox = Mailbox::new(&p.email).ok()?.with_name(&p.name);
+            seen.insert(mailbox.email.to_lowercase()).then_some(mailbox)
+        })
+        .collect()
+}
+
+/// Renders the recap of `followup` from `from` to its participants.
+pub fn followup_message(from: Mailbox, followup: Followup) -> Result<Message, Error> {
+    let to = followup_recipients(&from, &followup.participants);
+    let recipients: Vec<template::Participant> = to
+        .iter()
+        .map(|m| template::Participant {
+            name: m.name.clone().unwrap_or_default(),
+            job_title: None,
+        })
+        .collect();
+
+    let subject = template::followup_subject(&followup.session);
+    let html = template::render(template::Template::FollowupEmailHtml(
+        template::FollowupEmailHtml {
+            session: followup.session.clone(),
+            recipients: recipients.clone(),
+            sender_name: from.name.clone(),
+            enhanced_note: followup.enhanced_note.clone(),
+            action_items: followup.action_items.clone(),
+        },
+    ))?;
+    let text = template::render(template::Template::FollowupEmailText(
+        template::FollowupEmailText {
+            session: followup.session,
+            recipients,
+            sender_name: from.name.clone(),
+            enhanced_note: followup.enhanced_note,
+            action_items: followup.action_items,
+        },
+    ))?;
+
+    let mut message = Message::new(from, subject)
+        .with_to(to)
+        .with_text(text)
+        .with_html(html);
+
+    if let Some(transcript) = followup.transcript.filter(|t| !t.segments.is_empty()) {
+        message = message.with_attachment(Attachment {
+            filename: "transcript.txt".to_string(),
+            content_type: "text/plain; charset=utf-8".to_string(),
+            data: transcript_text(&transcript).into_bytes(),
+        });
+    }
+
+    Ok(message)
+}
+
+fn transcript_text(transcript: &template::Transcript) -> String {
+    transcript
+        .segments
+        .iter()
+        .map(|s| format!("{}: {}\n", s.speaker, s.text))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn participant(name: &str, email: &str) -> FollowupParticipant {
+        FollowupParticipant {
+            name: name.to_string(),
+            email: email.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_followup_message() {
+        let from = Mailbox::new("jordan@example.com")
+            .unwrap()
+            .with_name("Jordan");
+        let followup = Followup {
+            session: template::Session {
+                title: Some("Acme onboarding".to_string()),
+                started_at: None,
+                ended_at: None,
+                event: None,
+            },
+            participants: vec![
+                participant("Jordan", "Jordan@example.com"),
+                participant("Dana Lee", "dana@acme.com"),
+                participant("Dana Lee", "DANA@acme.com"),
+                participant("Phone dial-in", ""),
+            ],
+            enhanced_note: "## Summary\n\n- Kickoff on March 9".to_string(),
+            action_items: vec![],
+            transcript: Some(template::Transcript {
+                segments: vec![template::Segment {
+                    text: "Let's start.".to_string(),
+                    speaker: "Jordan".to_string(),
+                }],
+                started_at: None,
+                ended_at: None,
+            }),
+        };
+
+        let message = followup_message(from, followup).unwrap();
+        assert_eq!(message.subject, "Recap: Acme onboarding");
+        assert_eq!(
+            message.to,
+            [Mailbox::new("dana@acme.com").unwrap().with_name("Dana Lee")]
+        );
+        assert!(message.text.starts_with("Hi Dana,"));
+        assert!(message.text.ends_with("Best,\nJordan"));
+        assert!(
+            message
+                .html
+                .as_deref()
+                .unwrap()
+                .contains("<h2>Summary</h2>")
+        );
+        assert_eq!(message.attachments[0].filename, "transcript.txt");
+        assert_eq!(message.attachments[0].data, b"Jordan: Let's start.\n");
+    }
+}
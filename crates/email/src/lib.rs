@@ -1,3 +1,13 @@
+mod error;
+mod followup;
+mod message;
+mod smtp;
+
+pub use error::Error;
+pub use followup::{Followup, FollowupParticipant, followup_message, followup_recipients};
+pub use message::{Attachment, Mailbox, Message};
+pub use smtp::{SmtpClient, SmtpSecurity};
+
 #[cfg(target_os = "macos")]
 use objc2::{rc::Retained, runtime::NSObject};
 #[cfg(target_os = "macos")]
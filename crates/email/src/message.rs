@@ -0,0 +1,340 @@
+use std::path::Path;
+
+use chrono::{DateTime, Utc};
+use lettre::message::header::ContentType;
+use lettre::message::{MultiPart, SinglePart};
+
+use crate::Error;
+
+/// An address, with an optional display name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mailbox {
+    pub name: Option<String>,
+    pub email: String,
+}
+
+impl Mailbox {
+    pub fn new(email: &str) -> Result<Self, Error> {
+        let email = email.trim();
+        let valid = match email.split_once('@') {
+            Some((local, domain)) => {
+                !local.is_empty()
+                    && domain.contains('.')
+                    && !domain.starts_with('.')
+                    && !domain.ends_with('.')
+                    && email.parse::<lettre::Address>().is_ok()
+            }
+            None => false,
+        };
+
+        if !valid {
+            return Err(Error::InvalidAddress(email.to_string()));
+        }
+
+        Ok(Self {
+            name: None,
+            email: email.to_string(),
+        })
+    }
+
+    /// Names come from calendar invites and contacts, so line breaks and
+    /// other control characters are dropped rather than trusted.
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        let name = header_safe(&name.into());
+        self.name = (!name.is_empty()).then_some(name);
+        self
+    }
+
+    fn domain(&self) -> &str {
+        self.email.rsplit_once('@').map_or("localhost", |(_, d)| d)
+    }
+
+    fn to_lettre(&self) -> Result<lettre::message::Mailbox, Error> {
+        let address = self
+            .email
+            .parse()
+            .map_err(|_| Error::InvalidAddress(self.email.clone()))?;
+        Ok(lettre::message::Mailbox::new(self.name.clone(), address))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Attachment {
+    pub filename: String,
+    pub content_type: String,
+    pub data: Vec<u8>,
+}
+
+/// A MIME message with a plain-text body, an optional HTML alternative and
+/// attachments.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Message {
+    pub from: Mailbox,
+    pub to: Vec<Mailbox>,
+    pub cc: Vec<Mailbox>,
+    pub subject: String,
+    pub text: String,
+    pub html: Option<String>,
+    pub attachments: Vec<Attachment>,
+    pub date: DateTime<Utc>,
+    pub message_id: String,
+}
+
+impl Message {
+    pub fn new(from: Mailbox, subject: impl Into<String>) -> Self {
+        let message_id = format!("<{}@{}>", uuid::Uuid::new_v4(), from.domain());
+
+        Self {
+            from,
+            to: vec![],
+            cc: vec![],
+            subject: subject.into(),
+            text: String::new(),
+            html: None,
+            attachments: vec![],
+            date: Utc::now(),
+            message_id,
+        }
+    }
+
+    pub fn with_to(mut self, to: Vec<Mailbox>) -> Self {
+        self.to = to;
+        self
+    }
+
+    pub fn with_cc(mut self, cc: Vec<Mailbox>) -> Self {
+        self.cc = cc;
+        self
+    }
+
+    pub fn with_text(mut self, text: impl Into<String>) -> Self {
+        self.text = text.into();
+        self
+    }
+
+    pub fn with_html(mut self, html: impl Into<String>) -> Self {
+        self.html = Some(html.into());
+        self
+    }
+
+    pub fn with_attachment(mut self, attachment: Attachment) -> Self {
+        self.attachments.push(attachment);
+        self
+    }
+
+    pub fn with_date(mut self, date: DateTime<Utc>) -> Self {
+        self.date = date;
+        self
+    }
+
+    /// Everyone the message is delivered to.
+    pub fn recipients(&self) -> impl Iterator<Item = &Mailbox> {
+        self.to.iter().chain(&self.cc)
+    }
+
+    /// The message as sent over SMTP, with CRLF line endings.
+    pub fn to_mime(&self) -> Result<Vec<u8>, Error> {
+        Ok(self.to_lettre()?.formatted())
+    }
+
+    /// Writes the message as an `.eml` file that mail clients open as an
+    /// unsent draft.
+    pub fn write_draft(&self, path: &Path) -> Result<(), Error> {
+        let mut eml = b"X-Unsent: 1\r\n".to_vec();
+        eml.extend(self.to_mime()?);
+        std::fs::write(path, eml)?;
+        Ok(())
+    }
+
+    pub(crate) fn to_lettre(&self) -> Result<lettre::Message, Error> {
+        let from = self.from.to_lettre()?;
+        let mut builder = lettre::Message::builder()
+            .from(from.clone())
+            .subject(header_safe(&self.subject))
+            .date(self.date.into())
+            .message_id(Some(self.message_id.clone()));
+        for mailbox in &self.to {
+            builder = builder.to(mailbox.to_lettre()?);
+        }
+        for mailbox in &self.cc {
+            builder = builder.cc(mailbox.to_lettre()?);
+        }
+
+        // A draft may not be addressed yet; the envelope is only used for
+        // SMTP, which refuses messages without recipients anyway.
+        if self.recipients().next().is_none() {
+            let envelope =
+                lettre::address::Envelope::new(Some(from.email.clone()), vec![from.email.clone()])?;
+            builder = builder.envelope(envelope);
+        }
+
+        let text = SinglePart::plain(self.text.clone());
+        let alternative = self
+            .html
+            .as_ref()
+            .map(|html| MultiPart::alternative_plain_html(self.text.clone(), html.clone()));
+
+        if self.attachments.is_empty() {
+            return Ok(match alternative {
+                Some(alternative) => builder.multipart(alternative)?,
+                None => builder.singlepart(text)?,
+            });
+        }
+
+        let mut mixed = match alternative {
+            Some(alternative) => MultiPart::mixed().multipart(alternative),
+            None => MultiPart::mixed().singlepart(text),
+        };
+        for attachment in &self.attachments {
+            mixed = mixed.singlepart(attachment_part(attachment)?);
+        }
+        Ok(builder.multipart(mixed)?)
+    }
+}
+
+fn attachment_part(attachment: &Attachment) -> Result<SinglePart, Error> {
+    let content_type = ContentType::parse(&attachment.content_type)
+        .map_err(|_| Error::InvalidContentType(attachment.content_type.clone()))?;
+
+    Ok(
+        lettre::message::Attachment::new(header_safe(&attachment.filename))
+            .body(attachment.data.clone(), content_type),
+    )
+}
+
+/// Replaces control characters, so text that ends up in a header can't
+/// start a new one.
+fn header_safe(text: &str) -> String {
+    text.chars()
+        .map(|c| if c.is_control() { ' ' } else { c })
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mime(message: &Message) -> String {
+        String::from_utf8(message.to_mime().unwrap()).unwrap()
+    }
+
+    fn headers(mime: &str) -> Vec<&str> {
+        mime.split("\r\n")
+            .filter(|line| !line.starts_with([' ', '\t']))
+            .filter_map(|line| line.split_once(':').map(|(name, _)| name))
+            .collect()
+    }
+
+    #[test]
+    fn test_mailbox() {
+        let mailbox = Mailbox::new(" dana@acme.com ")
+            .unwrap()
+            .with_name("  Dana \"D\" Lee ");
+        assert_eq!(mailbox.email, "dana@acme.com");
+        assert_eq!(mailbox.name.as_deref(), Some("Dana \"D\" Lee"));
+        assert_eq!(
+            Mailbox::new("dana@acme.com")
+                .unwrap()
+                .with_name(" \r\n")
+                .name,
+            None
+        );
+
+        for invalid in [
+            "",
+            "dana",
+            "dana@",
+            "@acme.com",
+            "dana@acme",
+            "a b@acme.com",
+            "dana@acme.com\r\nBcc: eve@evil.com",
+        ] {
+            assert!(Mailbox::new(invalid).is_err(), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn test_to_mime() {
+        let message = Message::new(
+            Mailbox::new("jordan@example.com").unwrap(),
+            "Recap: Café sync",
+        )
+        .with_to(vec![
+            Mailbox::new("dana@acme.com").unwrap().with_name("Dana Lee"),
+            Mailbox::new("minji@acme.com").unwrap().with_name("김민지"),
+        ])
+        .with_text("Price = $10\nLine with trailing space \n")
+        .with_html("<p>Hi</p>")
+        .with_attachment(Attachment {
+            filename: "transcript.txt".to_string(),
+            content_type: "text/plain; charset=utf-8".to_string(),
+            data: b"Dana: hello".to_vec(),
+        });
+
+        let mime = mime(&message);
+        assert!(mime.contains("From: jordan@example.com\r\n"));
+        assert!(mime.contains("\"Dana Lee\" <dana@acme.com>"));
+        assert!(mime.contains("=?utf-8?b?6rmA66+87KeA?= <minji@acme.com>"));
+        assert!(mime.contains("Subject: Recap: =?utf-8?b?Q2Fmw6k=?= sync\r\n"));
+        assert!(mime.contains(&format!("Message-ID: {}\r\n", message.message_id)));
+        assert!(mime.contains("Content-Type: multipart/mixed;"));
+        assert!(mime.contains("Content-Type: multipart/alternative;"));
+        assert!(mime.contains("Content-Type: text/html; charset=utf-8"));
+        assert!(mime.contains("<p>Hi</p>"));
+        assert!(mime.contains("Content-Disposition: attachment; filename=\"transcript.txt\""));
+        assert!(!mime.replace("\r\n", "").contains('\n'));
+    }
+
+    #[test]
+    fn test_hostile_header_values() {
+        let message = Message::new(
+            Mailbox::new("jordan@example.com").unwrap(),
+            "Recap\r\nX-Injected: subject",
+        )
+        .with_to(vec![
+            Mailbox::new("dana@acme.com")
+                .unwrap()
+                .with_name("Dana\r\nBcc: eve@evil.com\r\n\r\nbody"),
+        ])
+        .with_text("Hi")
+        .with_attachment(Attachment {
+            filename: "notes\r\nX-Injected: filename.txt".to_string(),
+            content_type: "text/plain".to_string(),
+            data: b"hello".to_vec(),
+        });
+
+        let mime = mime(&message);
+        let headers = headers(&mime);
+        assert!(!headers.contains(&"Bcc"), "{mime}");
+        assert!(!headers.contains(&"X-Injected"), "{mime}");
+
+        let hostile_type = message.clone().with_attachment(Attachment {
+            filename: "a.txt".to_string(),
+            content_type: "text/plain\r\nX-Injected: type".to_string(),
+            data: vec![],
+        });
+        assert!(matches!(
+            hostile_type.to_mime(),
+            Err(Error::InvalidContentType(_))
+        ));
+    }
+
+    #[test]
+    fn test_write_draft() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("recap.eml");
+
+        Message::new(Mailbox::new("jordan@example.com").unwrap(), "Recap")
+            .with_text("Hi")
+            .write_draft(&path)
+            .unwrap();
+
+        let eml = std::fs::read_to_string(&path).unwrap();
+        assert!(eml.starts_with("X-Unsent: 1\r\n"));
+        assert!(eml.contains("Subject: Recap\r\n"));
+        assert!(eml.contains("Content-Type: text/plain; charset=utf-8"));
+    }
+}
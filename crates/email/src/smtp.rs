@@ -0,0 +1,224 @@
+use std::time::Duration;
+
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::transport::smtp::extension::ClientId;
+use lettre::{AsyncSmtpTransport, AsyncTransport, Tokio1Executor};
+
+use crate::{Error, Message};
+
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SmtpSecurity {
+    /// TLS from the first byte, usually on port 465.
+    Tls,
+    /// Plaintext, upgraded with `STARTTLS` before authenticating. Usually
+    /// on port 587.
+    StartTls,
+    /// No encryption. Only for local relays and tests.
+    None,
+}
+
+/// Sends messages through an SMTP submission server.
+#[derive(Debug, Clone)]
+pub struct SmtpClient {
+    host: String,
+    port: u16,
+    security: SmtpSecurity,
+    credentials: Option<(String, String)>,
+    hello_name: String,
+    timeout: Duration,
+}
+
+impl SmtpClient {
+    /// Uses implicit TLS on port 465 and `STARTTLS` everywhere else.
+    pub fn new(host: impl Into<String>, port: u16) -> Self {
+        Self {
+            host: host.into(),
+            port,
+            security: if port == 465 {
+                SmtpSecurity::Tls
+            } else {
+                SmtpSecurity::StartTls
+            },
+            credentials: None,
+            hello_name: "localhost".to_string(),
+            timeout: DEFAULT_TIMEOUT,
+        }
+    }
+
+    pub fn with_security(mut self, security: SmtpSecurity) -> Self {
+        self.security = security;
+        self
+    }
+
+    pub fn with_credentials(
+        mut self,
+        username: impl Into<String>,
+        password: impl Into<String>,
+    ) -> Self {
+        self.credentials = Some((username.into(), password.into()));
+        self
+    }
+
+    /// The name given in `EHLO`.
+    pub fn with_hello_name(mut self, name: impl Into<String>) -> Self {
+        self.hello_name = name.into();
+        self
+    }
+
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    pub async fn send(&self, message: &Message) -> Result<(), Error> {
+        if message.recipients().next().is_none() {
+            return Err(Error::NoRecipients);
+        }
+
+        self.transport()?.send(message.to_lettre()?).await?;
+        Ok(())
+    }
+
+    fn transport(&self) -> Result<AsyncSmtpTransport<Tokio1Executor>, Error> {
+        let builder = match self.security {
+            SmtpSecurity::Tls => AsyncSmtpTransport::<Tokio1Executor>::relay(&self.host)?,
+            SmtpSecurity::StartTls => {
+                AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&self.host)?
+            }
+            SmtpSecurity::None => {
+                AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(&self.host)
+            }
+        };
+
+        let mut builder = builder
+            .port(self.port)
+            .hello_name(ClientId::Domain(self.hello_name.clone()))
+            .timeout(Some(self.timeout));
+        if let Some((username, password)) = &self.credentials {
+            builder = builder.credentials(Credentials::new(username.clone(), password.clone()));
+        }
+
+        Ok(builder.build())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use base64::Engine;
+    use base64::engine::general_purpose::STANDARD;
+    use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+    use tokio::net::TcpListener;
+
+    use super::*;
+    use crate::Mailbox;
+
+    /// A local SMTP sink: accepts one session, answers every command with
+    /// the next scripted reply and records what the client sent.
+    async fn sink(rcpt_reply: &'static str) -> (u16, tokio::task::JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+
+        let handle = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut stream = BufReader::new(stream);
+            let mut received = vec![];
+            let mut in_data = false;
+            let mut data = String::new();
+
+            stream.write_all(b"220 sink ESMTP\r\n").await.unwrap();
+            loop {
+                let mut line = String::new();
+                if stream.read_line(&mut line).await.unwrap() == 0 {
+                    break;
+                }
+
+                if in_data {
+                    if line == ".\r\n" {
+                        in_data = false;
+                        received.push(std::mem::take(&mut data));
+                        stream.write_all(b"250 2.0.0 queued\r\n").await.unwrap();
+                    } else {
+                        data.push_str(&line);
+                    }
+                    continue;
+                }
+
+                let command = line.trim_end().to_string();
+                let reply: &[u8] = match command.split_whitespace().next().unwrap_or("") {
+                    "EHLO" => b"250-sink\r\n250-AUTH LOGIN PLAIN\r\n250 8BITMIME\r\n",
+                    "AUTH" => b"235 2.7.0 accepted\r\n",
+                    "MAIL" => b"250 2.1.0 ok\r\n",
+                    "RCPT" => rcpt_reply.as_bytes(),
+                    "DATA" => {
+                        in_data = true;
+                        b"354 go ahead\r\n"
+                    }
+                    "QUIT" => b"221 bye\r\n",
+                    _ => b"502 unknown\r\n",
+                };
+                received.push(command.clone());
+                stream.write_all(reply).await.unwrap();
+                if command == "QUIT" {
+                    break;
+                }
+            }
+            received
+        });
+
+        (port, handle)
+    }
+
+    fn message() -> Message {
+        Message::new(Mailbox::new("jordan@example.com").unwrap(), "Recap")
+            .with_to(vec![Mailbox::new("dana@acme.com").unwrap()])
+            .with_cc(vec![Mailbox::new("sam@acme.com").unwrap()])
+            .with_text("Hi\n.hidden line\n")
+    }
+
+    #[tokio::test]
+    async fn test_send_to_sink() {
+        let (port, sink) = sink("250 2.1.5 ok\r\n").await;
+
+        SmtpClient::new("127.0.0.1", port)
+            .with_security(SmtpSecurity::None)
+            .with_credentials("jordan", "app-password")
+            .send(&message())
+            .await
+            .unwrap();
+
+        let received = sink.await.unwrap();
+        assert_eq!(received[0], "EHLO localhost");
+        assert_eq!(
+            received[1],
+            format!("AUTH PLAIN {}", STANDARD.encode("\0jordan\0app-password"))
+        );
+        assert_eq!(
+            &received[2..6],
+            [
+                "MAIL FROM:<jordan@example.com>",
+                "RCPT TO:<dana@acme.com>",
+                "RCPT TO:<sam@acme.com>",
+                "DATA",
+            ]
+        );
+        assert!(received[6].contains("Subject: Recap\r\n"));
+        assert!(received[6].contains("\r\n..hidden line\r\n"));
+        assert_eq!(received[7], "QUIT");
+    }
+
+    #[tokio::test]
+    async fn test_rejected_recipient() {
+        let (port, _sink) = sink("550 5.1.1 no such user\r\n").await;
+
+        let result = SmtpClient::new("127.0.0.1", port)
+            .with_security(SmtpSecurity::None)
+            .send(&message())
+            .await;
+        assert!(matches!(
+            result,
+            Err(Error::Smtp(e)) if e.status().map(u16::from) == Some(550)
+        ));
+    }
+}
@@ -116,6 +116,70 @@ impl CachingClient {
     }
 }
 
+/// Disk cache for STT transcripts, so re-running a comparison only
+/// transcribes fixtures with new providers or settings.
+pub struct TranscriptCache {
+    disk_cache: DiskCacheState,
+}
+
+impl TranscriptCache {
+    pub fn new(cache_dir: Option<String>) -> Self {
+        let dir = cache_dir
+            .map(PathBuf::from)
+            .or_else(|| dirs::cache_dir().map(|p| p.join("hyprnote").join("eval.stt.cache")));
+
+        let disk_cache = match dir {
+            Some(dir) => match fs::create_dir_all(&dir) {
+                Ok(()) => DiskCacheState::Available(dir),
+                Err(_) => DiskCacheState::Disabled,
+            },
+            None => DiskCacheState::Disabled,
+        };
+
+        Self { disk_cache }
+    }
+
+    pub fn disabled() -> Self {
+        Self {
+            disk_cache: DiskCacheState::Disabled,
+        }
+    }
+
+    /// Key for transcribing `audio` with the given provider settings. Hashes
+    /// the audio itself so edited fixtures are re-transcribed.
+    pub fn key(&self, provider: &str, model: &str, language: &str, audio: &[u8]) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(CACHE_KEY_VERSION.to_le_bytes());
+        for part in [provider, model, language] {
+            hasher.update(part.as_bytes());
+            hasher.update([0]);
+        }
+        hasher.update(audio);
+        hex::encode(hasher.finalize())
+    }
+
+    pub fn get(&self, key: &str) -> Option<Vec<crate::SttWord>> {
+        let DiskCacheState::Available(dir) = &self.disk_cache else {
+            return None;
+        };
+        let content = fs::read_to_string(dir.join(format!("{}.json", key))).ok()?;
+        serde_json::from_str(&content).ok()
+    }
+
+    pub fn set(&self, key: &str, words: &[crate::SttWord]) {
+        let DiskCacheState::Available(dir) = &self.disk_cache else {
+            return;
+        };
+        let path = dir.join(format!("{}.json", key));
+        let written = serde_json::to_string(words)
+            .map_err(std::io::Error::other)
+            .and_then(|content| fs::write(&path, content));
+        if written.is_err() {
+            eprintln!("Warning: failed to write cache file: {}", path.display());
+        }
+    }
+}
+
 mod hex {
     pub fn encode(bytes: impl AsRef<[u8]>) -> String {
         bytes
@@ -0,0 +1,519 @@
+use std::collections::HashSet;
+use std::path::Path;
+use std::sync::Arc;
+
+use markdown::mdast::Node;
+use serde::Deserialize;
+
+use crate::{
+    CheckResult, EvalCase, GraderSpec, RubricSpec, Rule, extract_text, find_headings, grade,
+};
+
+/// Meta key holding the source transcript for [`DeclarativeGrader::KeywordRecall`].
+pub const TRANSCRIPT_META_KEY: &str = "transcript";
+
+#[derive(Debug, thiserror::Error)]
+pub enum GraderConfigError {
+    #[error("io error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("invalid grader config: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("rubric '{rubric}': invalid regex: {error}")]
+    Regex { rubric: String, error: regex::Error },
+    #[error("rubric '{rubric}': invalid JSON schema: {error}")]
+    Schema { rubric: String, error: String },
+}
+
+/// A grader defined in config rather than code. None of these call an LLM,
+/// so results are reproducible across runs.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum DeclarativeGrader {
+    /// Output must (or, with `must_match: false`, must not) match `pattern`.
+    Regex {
+        pattern: String,
+        #[serde(default = "default_true")]
+        must_match: bool,
+    },
+    /// Output must be JSON valid against `schema`. A surrounding code fence is
+    /// ignored.
+    JsonSchema { schema: serde_json::Value },
+    MarkdownHeadings {
+        #[serde(default)]
+        min_count: Option<usize>,
+        #[serde(default)]
+        max_count: Option<usize>,
+        /// Every heading must have this depth.
+        #[serde(default)]
+        depth: Option<u8>,
+        /// Headings that must be present, compared case-insensitively.
+        #[serde(default)]
+        required: Vec<String>,
+    },
+    MaxLength {
+        #[serde(default)]
+        max_chars: Option<usize>,
+        #[serde(default)]
+        max_words: Option<usize>,
+    },
+    /// Fraction of keywords that appear in the output. Without explicit
+    /// `keywords`, names and numbers are taken from the case's transcript
+    /// meta.
+    KeywordRecall {
+        #[serde(default)]
+        keywords: Vec<String>,
+        min_recall: f64,
+    },
+}
+
+fn default_true() -> bool {
+    true
+}
+
+impl DeclarativeGrader {
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::Regex { .. } => "regex",
+            Self::JsonSchema { .. } => "json_schema",
+            Self::MarkdownHeadings { .. } => "markdown_headings",
+            Self::MaxLength { .. } => "max_length",
+            Self::KeywordRecall { .. } => "keyword_recall",
+        }
+    }
+
+    /// Builds the regex or JSON schema validator once, so grading a rubric
+    /// across cases and samples doesn't recompile it.
+    pub fn compile(&self, rubric: &str) -> Result<CompiledGrader, GraderConfigError> {
+        let matcher = match self {
+            Self::Regex { pattern, .. } => {
+                Matcher::Regex(regex::Regex::new(pattern).map_err(|error| {
+                    GraderConfigError::Regex {
+                        rubric: rubric.to_string(),
+                        error,
+                    }
+                })?)
+            }
+            Self::JsonSchema { schema } => {
+                Matcher::Schema(jsonschema::Validator::new(schema).map_err(|e| {
+                    GraderConfigError::Schema {
+                        rubric: rubric.to_string(),
+                        error: e.to_string(),
+                    }
+                })?)
+            }
+            _ => Matcher::None,
+        };
+        Ok(CompiledGrader {
+            grader: self.clone(),
+            matcher,
+        })
+    }
+}
+
+enum Matcher {
+    None,
+    Regex(regex::Regex),
+    Schema(jsonschema::Validator),
+}
+
+/// A [`DeclarativeGrader`] with its regex or schema already compiled.
+pub struct CompiledGrader {
+    grader: DeclarativeGrader,
+    matcher: Matcher,
+}
+
+impl std::fmt::Debug for CompiledGrader {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.grader.fmt(f)
+    }
+}
+
+impl CompiledGrader {
+    pub fn grader(&self) -> &DeclarativeGrader {
+        &self.grader
+    }
+
+    pub fn grade(&self, output: &str, meta: Option<&serde_json::Value>) -> (bool, String) {
+        match (&self.grader, &self.matcher) {
+            (
+                DeclarativeGrader::Regex {
+                    pattern,
+                    must_match,
+                },
+                Matcher::Regex(re),
+            ) => match (re.is_match(output), must_match) {
+                (true, true) => (true, format!("matches /{}/", pattern)),
+                (false, true) => (false, format!("does not match /{}/", pattern)),
+                (true, false) => (false, format!("unexpectedly matches /{}/", pattern)),
+                (false, false) => (true, format!("does not match /{}/", pattern)),
+            },
+            (DeclarativeGrader::JsonSchema { .. }, Matcher::Schema(validator)) => {
+                grade_json_schema(validator, output)
+            }
+            (
+                DeclarativeGrader::MarkdownHeadings {
+                    min_count,
+                    max_count,
+                    depth,
+                    required,
+                },
+                _,
+            ) => {
+                let result = grade(
+                    output,
+                    vec![heading_rule(
+                        *min_count,
+                        *max_count,
+                        *depth,
+                        required.clone(),
+                    )],
+                );
+                (result.score >= 1.0, result.summary())
+            }
+            (
+                DeclarativeGrader::MaxLength {
+                    max_chars,
+                    max_words,
+                },
+                _,
+            ) => {
+                let chars = output.chars().count();
+                let words = output.split_whitespace().count();
+                if let Some(max) = max_chars.filter(|max| chars > *max) {
+                    return (false, format!("{} chars, expected at most {}", chars, max));
+                }
+                if let Some(max) = max_words.filter(|max| words > *max) {
+                    return (false, format!("{} words, expected at most {}", words, max));
+                }
+                (true, format!("{} chars, {} words", chars, words))
+            }
+            (
+                DeclarativeGrader::KeywordRecall {
+                    keywords,
+                    min_recall,
+                },
+                _,
+            ) => {
+                if !keywords.is_empty() {
+                    return grade_keyword_recall(keywords, *min_recall, output);
+                }
+                // A case without a transcript can't be graded for recall;
+                // passing it would hide a misconfigured rubric.
+                match meta
+                    .and_then(|m| m.get(TRANSCRIPT_META_KEY))
+                    .and_then(|t| t.as_str())
+                {
+                    Some(transcript) => {
+                        grade_keyword_recall(&transcript_keywords(transcript), *min_recall, output)
+                    }
+                    None => (
+                        false,
+                        format!("no keywords and no '{}' meta", TRANSCRIPT_META_KEY),
+                    ),
+                }
+            }
+            (grader, _) => unreachable!("{} grader compiled without its matcher", grader.kind()),
+        }
+    }
+}
+
+fn grade_json_schema(validator: &jsonschema::Validator, output: &str) -> (bool, String) {
+    let body = strip_code_fence(output);
+    let instance: serde_json::Value = match serde_json::from_str(body) {
+        Ok(value) => value,
+        Err(e) => return (false, format!("not valid JSON: {}", e)),
+    };
+    let errors: Vec<String> = validator
+        .iter_errors(&instance)
+        .map(|e| e.to_string())
+        .collect();
+    if errors.is_empty() {
+        (true, "matches schema".to_string())
+    } else {
+        (false, errors.join("; "))
+    }
+}
+
+fn strip_code_fence(output: &str) -> &str {
+    let trimmed = output.trim();
+    trimmed
+        .strip_prefix("```")
+        .and_then(|rest| rest.strip_suffix("```"))
+        .map(|inner| inner.split_once('\n').map_or(inner, |(_, body)| body))
+        .unwrap_or(trimmed)
+}
+
+fn heading_rule(
+    min_count: Option<usize>,
+    max_count: Option<usize>,
+    depth: Option<u8>,
+    required: Vec<String>,
+) -> Rule {
+    Box::new(move |node| {
+        let headings = find_headings(node);
+        let titles: Vec<String> = headings
+            .iter()
+            .map(|h| {
+                extract_text(&Node::Heading((*h).clone()))
+                    .trim()
+                    .to_lowercase()
+            })
+            .collect();
+        let mut checks = Vec::new();
+
+        if let Some(min) = min_count {
+            checks.push(if headings.len() >= min {
+                CheckResult::pass(1, format!("at least {} headings", min))
+            } else {
+                CheckResult::fail(
+                    1,
+                    format!("expected at least {} headings, got {}", min, headings.len()),
+                )
+            });
+        }
+        if let Some(max) = max_count {
+            checks.push(if headings.len() <= max {
+                CheckResult::pass(1, format!("at most {} headings", max))
+            } else {
+                CheckResult::fail(
+                    1,
+                    format!("expected at most {} headings, got {}", max, headings.len()),
+                )
+            });
+        }
+        if let Some(depth) = depth {
+            for (i, h) in headings.iter().enumerate() {
+                checks.push(if h.depth == depth {
+                    CheckResult::pass(1, format!("heading {} is h{}", i + 1, depth))
+                } else {
+                    CheckResult::fail(
+                        1,
+                        format!("heading {}: expected h{}, got h{}", i + 1, depth, h.depth),
+                    )
+                });
+            }
+        }
+        for title in &required {
+            checks.push(if titles.contains(&title.trim().to_lowercase()) {
+                CheckResult::pass(1, format!("has '{}' heading", title))
+            } else {
+                CheckResult::fail(1, format!("missing '{}' heading", title))
+            });
+        }
+
+        checks
+    })
+}
+
+fn grade_keyword_recall(keywords: &[String], min_recall: f64, output: &str) -> (bool, String) {
+    if keywords.is_empty() {
+        return (true, "no keywords to recall".to_string());
+    }
+
+    let output = output.to_lowercase();
+    let missing: Vec<&str> = keywords
+        .iter()
+        .filter(|k| !output.contains(&k.to_lowercase()))
+        .map(String::as_str)
+        .collect();
+    let recall = 1.0 - missing.len() as f64 / keywords.len() as f64;
+
+    let mut reasoning = format!(
+        "recall {:.0}% ({}/{})",
+        recall * 100.0,
+        keywords.len() - missing.len(),
+        keywords.len()
+    );
+    if !missing.is_empty() {
+        reasoning.push_str(&format!(", missing: {}", missing.join(", ")));
+    }
+    (recall >= min_recall, reasoning)
+}
+
+/// Names and numbers from `transcript`: capitalized words that don't start a
+/// sentence, and anything containing a digit.
+pub fn transcript_keywords(transcript: &str) -> Vec<String> {
+    let mut seen = HashSet::new();
+    let mut keywords = Vec::new();
+    let mut sentence_start = true;
+
+    for raw in transcript.split_whitespace() {
+        let word = raw.trim_matches(|c: char| !c.is_alphanumeric());
+        let is_name = !sentence_start && word.chars().next().is_some_and(char::is_uppercase);
+        let is_number = word.chars().any(|c| c.is_ascii_digit());
+
+        if (is_name || is_number) && seen.insert(word.to_lowercase()) {
+            keywords.push(word.to_string());
+        }
+        sentence_start = raw.ends_with(['.', '?', '!', ':']);
+    }
+
+    keywords
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct RubricConfig {
+    pub name: String,
+    pub description: String,
+    /// Case ids to attach the rubric to. Empty means every case.
+    #[serde(default)]
+    pub cases: Vec<String>,
+    pub grader: DeclarativeGrader,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct GraderConfig {
+    pub rubrics: Vec<RubricConfig>,
+    /// `rubrics[i].grader`, compiled by [`GraderConfig::from_json`].
+    #[serde(skip)]
+    compiled: Vec<Arc<CompiledGrader>>,
+}
+
+impl GraderConfig {
+    pub fn from_json(json: &str) -> Result<Self, GraderConfigError> {
+        let mut config: Self = serde_json::from_str(json)?;
+        config.compiled = config
+            .rubrics
+            .iter()
+            .map(|rubric| rubric.grader.compile(&rubric.name).map(Arc::new))
+            .collect::<Result<_, _>>()?;
+        Ok(config)
+    }
+
+    /// Appends each rubric to the cases it applies to.
+    pub fn apply(&self, cases: &mut [EvalCase]) {
+        for case in cases.iter_mut() {
+            for (rubric, grader) in self.rubrics.iter().zip(&self.compiled) {
+                if rubric.cases.is_empty()
+                    || rubric
+                        .cases
+                        .iter()
+                        .any(|c| c.eq_ignore_ascii_case(&case.case_id))
+                {
+                    case.rubrics.push(RubricSpec {
+                        name: rubric.name.clone(),
+                        description: rubric.description.clone(),
+                        grader: GraderSpec::Declarative(grader.clone()),
+                    });
+                }
+            }
+        }
+    }
+}
+
+pub fn load_grader_config(path: &Path) -> Result<GraderConfig, GraderConfigError> {
+    GraderConfig::from_json(&std::fs::read_to_string(path)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(json: serde_json::Value) -> CompiledGrader {
+        serde_json::from_value::<DeclarativeGrader>(json)
+            .unwrap()
+            .compile("test")
+            .unwrap()
+    }
+
+    #[test]
+    fn test_regex_grader() {
+        let grader = parse(serde_json::json!({"type": "regex", "pattern": "^# "}));
+        assert!(grader.grade("# Title", None).0);
+        assert!(!grader.grade("Title", None).0);
+
+        let grader = parse(serde_json::json!({
+            "type": "regex",
+            "pattern": "(?i)as an ai",
+            "must_match": false,
+        }));
+        assert!(grader.grade("Summary", None).0);
+        assert!(!grader.grade("As an AI, I cannot", None).0);
+    }
+
+    #[test]
+    fn test_json_schema_grader() {
+        let grader = parse(serde_json::json!({
+            "type": "json_schema",
+            "schema": {
+                "type": "object",
+                "required": ["title"],
+                "properties": {"title": {"type": "string"}},
+            },
+        }));
+        assert!(grader.grade("```json\n{\"title\": \"Sync\"}\n```", None).0);
+        assert!(!grader.grade("{\"title\": 1}", None).0);
+        assert!(!grader.grade("not json", None).0);
+    }
+
+    #[test]
+    fn test_markdown_headings_grader() {
+        let grader = parse(serde_json::json!({
+            "type": "markdown_headings",
+            "min_count": 2,
+            "depth": 1,
+            "required": ["Action Items"],
+        }));
+        let (passed, reasoning) = grader.grade("# Summary\n\n- a\n\n# Action items\n\n- b\n", None);
+        assert!(passed, "{}", reasoning);
+
+        let (passed, reasoning) = grader.grade("# Summary\n\n## Details\n", None);
+        assert!(!passed);
+        assert!(reasoning.contains("missing 'Action Items' heading"));
+    }
+
+    #[test]
+    fn test_max_length_grader() {
+        let grader = parse(serde_json::json!({"type": "max_length", "max_words": 3}));
+        assert!(grader.grade("one two three", None).0);
+        assert!(!grader.grade("one two three four", None).0);
+    }
+
+    #[test]
+    fn test_keyword_recall_from_transcript() {
+        let grader = parse(serde_json::json!({"type": "keyword_recall", "min_recall": 0.5}));
+        let meta = serde_json::json!({
+            "transcript": "We met with Acme yesterday. The budget is 40k and Priya owns it.",
+        });
+        assert_eq!(
+            transcript_keywords(meta["transcript"].as_str().unwrap()),
+            ["Acme", "40k", "Priya"]
+        );
+
+        let (passed, reasoning) = grader.grade("Priya owns the 40k budget.", Some(&meta));
+        assert!(passed);
+        assert!(reasoning.contains("missing: Acme"));
+        assert!(!grader.grade("Budget discussed.", Some(&meta)).0);
+
+        let (passed, reasoning) = grader.grade("Priya owns the 40k budget.", None);
+        assert!(!passed);
+        assert!(reasoning.contains("no keywords"));
+    }
+
+    #[test]
+    fn test_config_applies_to_matching_cases() {
+        let config = GraderConfig::from_json(
+            r#"{"rubrics": [
+                {"name": "short", "description": "Short", "grader": {"type": "max_length", "max_chars": 10}},
+                {"name": "json", "description": "JSON", "cases": ["other"], "grader": {"type": "json_schema", "schema": {}}}
+            ]}"#,
+        )
+        .unwrap();
+
+        let mut cases = vec![crate::EvalCase {
+            case_id: "mdbench".to_string(),
+            messages: vec![],
+            rubrics: vec![],
+            samples: 1,
+            meta: None,
+        }];
+        config.apply(&mut cases);
+        assert_eq!(cases[0].rubrics.len(), 1);
+        assert_eq!(cases[0].rubrics[0].name, "short");
+
+        let invalid = GraderConfig::from_json(
+            r#"{"rubrics": [{"name": "bad", "description": "Bad", "grader": {"type": "regex", "pattern": "("}}]}"#,
+        );
+        assert!(matches!(invalid, Err(GraderConfigError::Regex { .. })));
+    }
+}
@@ -5,7 +5,8 @@
 //! ## Features
 //!
 //! - Parallel execution of evaluation cases
-//! - Multiple grading strategies (function-based, LLM-based)
+//! - Multiple grading strategies (function-based, declarative, LLM-based)
+//! - STT scoring (WER, CER, speaker-attributed WER, timing drift)
 //! - Statistical analysis with confidence intervals
 //! - Response caching for reproducibility
 //! - Progress tracking
@@ -41,9 +42,11 @@ mod cache;
 mod client;
 mod config;
 mod format;
+mod grader;
 mod models;
 mod rubric;
 mod stats;
+mod stt;
 mod submission;
 mod testing;
 
@@ -53,6 +56,7 @@ pub mod constants;
 pub use testing::*;
 
 // Re-export core types at root for convenience
+pub use cache::TranscriptCache;
 pub use client::{
     ChatCompleter, ChatCompletionRequest, ChatCompletionResponse, ChatMessage, ClientError,
     GraderResponse, OpenRouterClient, Usage, UsageResolver, generate_chat_multi_with_generation_id,
@@ -66,11 +70,20 @@ pub use format::{
     CheckResult, GradeResult, Rule, count_list_items_in_section, extract_text, find_headings,
     find_list_items, find_lists, first_inline_child, grade, split_by_headings,
 };
+pub use grader::{
+    CompiledGrader, DeclarativeGrader, GraderConfig, GraderConfigError, RubricConfig,
+    TRANSCRIPT_META_KEY, load_grader_config, transcript_keywords,
+};
 pub use models::{fetch_openrouter_models, filter_models};
 pub use rubric::{Score, grade_with_func, grade_with_llm, is_non_empty};
 pub use stats::{
     AggregatedGraderResponse, ConfidenceInterval, PassStats, aggregate_grader_responses,
-    calc_pass_stats,
+    calc_pass_stats, is_significant_regression,
+};
+pub use stt::{
+    ErrorRate, SttError, SttFixture, SttResult, SttScore, SttWord, TimingDrift,
+    character_error_rate, discover_fixtures, normalize_text, score_transcript,
+    speaker_attributed_wer, timing_drift, word_error_rate,
 };
 pub use submission::{
     EvalCase, EvalResult, Executor, ExecutorProgress, ExecutorProgressCallback, GraderSpec,
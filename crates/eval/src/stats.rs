@@ -43,6 +43,25 @@ pub fn calc_pass_stats(pass_count: i32, total_count: i32) -> PassStats {
     }
 }
 
+/// True when `current` passes less often than `baseline` beyond sampling
+/// noise: the lower bound of Newcombe's hybrid score interval for the
+/// difference in pass rates, built from the two 95% Wilson intervals, is
+/// above zero. Unlike checking whether the intervals overlap, this detects
+/// a drop such as 3/3 to 0/3.
+pub fn is_significant_regression(baseline: &PassStats, current: &PassStats) -> bool {
+    if baseline.samples == 0 || current.samples == 0 {
+        return false;
+    }
+
+    let difference = baseline.pass_rate - current.pass_rate;
+    let lower = difference
+        - ((baseline.pass_rate - baseline.confidence_interval.lower).powi(2)
+            + (current.confidence_interval.upper - current.pass_rate).powi(2))
+        .sqrt();
+
+    lower > 0.0
+}
+
 #[derive(Debug, Clone)]
 pub struct AggregatedGraderResponse {
     pub pass_stats: PassStats,
@@ -158,4 +177,49 @@ mod tests {
         assert!(lower > 0.0 && lower < 0.5);
         assert!(upper > 0.5 && upper < 1.0);
     }
+
+    #[test]
+    fn test_is_significant_regression() {
+        let baseline = calc_pass_stats(18, 20);
+        assert!(is_significant_regression(
+            &baseline,
+            &calc_pass_stats(4, 20)
+        ));
+        assert!(!is_significant_regression(
+            &baseline,
+            &calc_pass_stats(15, 20)
+        ));
+        assert!(!is_significant_regression(
+            &baseline,
+            &calc_pass_stats(20, 20)
+        ));
+        assert!(!is_significant_regression(
+            &baseline,
+            &calc_pass_stats(0, 0)
+        ));
+    }
+
+    #[test]
+    fn test_regression_at_default_samples() {
+        // One case at the default three samples: a total collapse trips the
+        // gate, losing a single sample doesn't.
+        assert!(is_significant_regression(
+            &calc_pass_stats(3, 3),
+            &calc_pass_stats(0, 3)
+        ));
+        assert!(!is_significant_regression(
+            &calc_pass_stats(3, 3),
+            &calc_pass_stats(1, 3)
+        ));
+
+        // Ten cases pooled per model and rubric.
+        assert!(is_significant_regression(
+            &calc_pass_stats(27, 30),
+            &calc_pass_stats(18, 30)
+        ));
+        assert!(!is_significant_regression(
+            &calc_pass_stats(27, 30),
+            &calc_pass_stats(25, 30)
+        ));
+    }
 }
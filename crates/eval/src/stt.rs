@@ -0,0 +1,641 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+/// Audio files tried, in order, when loading a fixture directory.
+const AUDIO_FILES: &[&str] = &["audio.wav", "audio.flac", "audio.mp3", "audio.m4a"];
+
+/// Disfluencies that most providers drop and some keep. Removed from both
+/// sides so they don't count as errors.
+const FILLERS: &[&str] = &[
+    "um", "umm", "uh", "uhh", "hmm", "mm", "mhm", "ah", "er", "erm",
+];
+
+#[derive(Debug, thiserror::Error)]
+pub enum SttError {
+    #[error("io error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("json error: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("no audio file in {0}")]
+    MissingAudio(PathBuf),
+}
+
+/// A transcribed word with its timing in milliseconds.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SttWord {
+    pub text: String,
+    pub start_ms: u64,
+    pub end_ms: u64,
+    pub speaker: Option<String>,
+}
+
+/// An audio file with its reference transcript, as laid out in `crates/data`.
+#[derive(Debug, Clone)]
+pub struct SttFixture {
+    pub name: String,
+    pub language: String,
+    pub audio_path: PathBuf,
+    pub reference: Vec<SttWord>,
+}
+
+#[derive(Deserialize)]
+struct ReferenceToken {
+    start: u64,
+    end: u64,
+    text: String,
+}
+
+#[derive(Deserialize)]
+struct DiarizationSpan {
+    start: u64,
+    end: u64,
+    speaker: String,
+}
+
+impl SttFixture {
+    /// Loads `transcription.json`, `diarization.json` (optional) and the first
+    /// audio file found in `dir`. The language is taken from the directory
+    /// name (`english_1`, `korean_2`, ...).
+    pub fn load(dir: &Path) -> Result<Self, SttError> {
+        let name = dir
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_default();
+
+        let audio_path = AUDIO_FILES
+            .iter()
+            .map(|f| dir.join(f))
+            .find(|p| p.exists())
+            .ok_or_else(|| SttError::MissingAudio(dir.to_path_buf()))?;
+
+        let tokens: Vec<ReferenceToken> =
+            serde_json::from_str(&fs::read_to_string(dir.join("transcription.json"))?)?;
+        let diarization: Vec<DiarizationSpan> =
+            match fs::read_to_string(dir.join("diarization.json")) {
+                Ok(content) => serde_json::from_str(&content)?,
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => vec![],
+                Err(e) => return Err(e.into()),
+            };
+
+        let mut reference = join_tokens(&tokens);
+        for word in &mut reference {
+            word.speaker = speaker_at(&diarization, word.start_ms, word.end_ms);
+        }
+
+        Ok(Self {
+            language: language_from_name(&name).to_string(),
+            name,
+            audio_path,
+            reference,
+        })
+    }
+}
+
+/// Loads every fixture under `root` that has both a reference transcript and
+/// audio, sorted by name.
+pub fn discover_fixtures(root: &Path) -> Result<Vec<SttFixture>, SttError> {
+    let mut dirs: Vec<PathBuf> = fs::read_dir(root)?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|p| p.join("transcription.json").exists())
+        .collect();
+    dirs.sort();
+
+    let mut fixtures = Vec::new();
+    for dir in dirs {
+        match SttFixture::load(&dir) {
+            Ok(fixture) => fixtures.push(fixture),
+            Err(SttError::MissingAudio(_)) => continue,
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(fixtures)
+}
+
+fn language_from_name(name: &str) -> &'static str {
+    match name.split('_').next().unwrap_or_default() {
+        "korean" => "ko",
+        "japanese" => "ja",
+        "german" => "de",
+        "french" => "fr",
+        "spanish" => "es",
+        _ => "en",
+    }
+}
+
+/// Reference tokens are sub-word pieces that may also span several words. A
+/// piece continues the previous word unless whitespace separates them.
+fn join_tokens(tokens: &[ReferenceToken]) -> Vec<SttWord> {
+    let mut words: Vec<SttWord> = Vec::new();
+    let mut continues = false;
+
+    for token in tokens {
+        for (i, piece) in token.text.split_whitespace().enumerate() {
+            let joins = i == 0 && continues && !token.text.starts_with(char::is_whitespace);
+            match words.last_mut() {
+                Some(word) if joins => {
+                    word.text.push_str(piece);
+                    word.end_ms = token.end;
+                }
+                _ => words.push(SttWord {
+                    text: piece.to_string(),
+                    start_ms: token.start,
+                    end_ms: token.end,
+                    speaker: None,
+                }),
+            }
+        }
+        continues = !token.text.trim().is_empty() && !token.text.ends_with(char::is_whitespace);
+    }
+
+    words
+}
+
+fn speaker_at(spans: &[DiarizationSpan], start_ms: u64, end_ms: u64) -> Option<String> {
+    spans
+        .iter()
+        .map(|s| (s.end.min(end_ms).saturating_sub(s.start.max(start_ms)), s))
+        .filter(|(overlap, _)| *overlap > 0)
+        .max_by_key(|(overlap, _)| *overlap)
+        .or_else(|| {
+            let mid = (start_ms + end_ms) / 2;
+            spans
+                .iter()
+                .find(|s| s.start <= mid && mid <= s.end)
+                .map(|s| (0, s))
+        })
+        .map(|(_, s)| s.speaker.clone())
+}
+
+/// Lowercases, strips punctuation and fillers, and splits on whitespace.
+/// Apostrophes are dropped rather than split on, so "don't" and "dont"
+/// compare equal.
+pub fn normalize_text(text: &str) -> Vec<String> {
+    let cleaned: String = text
+        .to_lowercase()
+        .chars()
+        .filter(|c| *c != '\'' && *c != '’')
+        .map(|c| if c.is_alphanumeric() { c } else { ' ' })
+        .collect();
+
+    cleaned
+        .split_whitespace()
+        .filter(|w| !FILLERS.contains(w))
+        .map(str::to_string)
+        .collect()
+}
+
+/// Normalized tokens paired with the index of the word they came from.
+fn normalize_words(words: &[SttWord]) -> Vec<(String, usize)> {
+    words
+        .iter()
+        .enumerate()
+        .flat_map(|(i, w)| normalize_text(&w.text).into_iter().map(move |t| (t, i)))
+        .collect()
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ErrorRate {
+    pub substitutions: usize,
+    pub deletions: usize,
+    pub insertions: usize,
+    pub reference_len: usize,
+}
+
+impl ErrorRate {
+    pub fn errors(&self) -> usize {
+        self.substitutions + self.deletions + self.insertions
+    }
+
+    /// Errors per reference unit. Can exceed 1.0 when the hypothesis has many
+    /// insertions.
+    pub fn rate(&self) -> f64 {
+        if self.reference_len == 0 {
+            return if self.errors() == 0 { 0.0 } else { 1.0 };
+        }
+        self.errors() as f64 / self.reference_len as f64
+    }
+}
+
+/// Pools counts, so a sum over fixtures gives the corpus-level rate rather
+/// than a mean of per-fixture rates.
+impl std::ops::AddAssign for ErrorRate {
+    fn add_assign(&mut self, other: ErrorRate) {
+        self.substitutions += other.substitutions;
+        self.deletions += other.deletions;
+        self.insertions += other.insertions;
+        self.reference_len += other.reference_len;
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Edit {
+    Match(usize, usize),
+    Substitution,
+    Deletion,
+    Insertion,
+}
+
+/// Minimum edit alignment of `hypothesis` against `reference`.
+fn align<T: PartialEq>(reference: &[T], hypothesis: &[T]) -> Vec<Edit> {
+    let (n, m) = (reference.len(), hypothesis.len());
+    let mut cost = vec![vec![0u32; m + 1]; n + 1];
+    for (i, row) in cost.iter_mut().enumerate() {
+        row[0] = i as u32;
+    }
+    for (j, c) in cost[0].iter_mut().enumerate() {
+        *c = j as u32;
+    }
+    for i in 1..=n {
+        for j in 1..=m {
+            let diagonal = cost[i - 1][j - 1] + u32::from(reference[i - 1] != hypothesis[j - 1]);
+            cost[i][j] = diagonal.min(cost[i - 1][j] + 1).min(cost[i][j - 1] + 1);
+        }
+    }
+
+    let mut edits = Vec::with_capacity(n.max(m));
+    let (mut i, mut j) = (n, m);
+    while i > 0 || j > 0 {
+        if i > 0 && j > 0 {
+            let same = reference[i - 1] == hypothesis[j - 1];
+            if cost[i][j] == cost[i - 1][j - 1] + u32::from(!same) {
+                edits.push(if same {
+                    Edit::Match(i - 1, j - 1)
+                } else {
+                    Edit::Substitution
+                });
+                i -= 1;
+                j -= 1;
+                continue;
+            }
+        }
+        if i > 0 && cost[i][j] == cost[i - 1][j] + 1 {
+            edits.push(Edit::Deletion);
+            i -= 1;
+        } else {
+            edits.push(Edit::Insertion);
+            j -= 1;
+        }
+    }
+    edits.reverse();
+    edits
+}
+
+fn count_edits(edits: &[Edit], reference_len: usize) -> ErrorRate {
+    let mut rate = ErrorRate {
+        reference_len,
+        ..Default::default()
+    };
+    for edit in edits {
+        match edit {
+            Edit::Match(..) => {}
+            Edit::Substitution => rate.substitutions += 1,
+            Edit::Deletion => rate.deletions += 1,
+            Edit::Insertion => rate.insertions += 1,
+        }
+    }
+    rate
+}
+
+pub fn word_error_rate(reference: &str, hypothesis: &str) -> ErrorRate {
+    let reference = normalize_text(reference);
+    let hypothesis = normalize_text(hypothesis);
+    count_edits(&align(&reference, &hypothesis), reference.len())
+}
+
+/// Character error rate over normalized text, ignoring spaces. Preferred over
+/// WER for languages where word boundaries are loosely defined (Korean,
+/// Japanese).
+pub fn character_error_rate(reference: &str, hypothesis: &str) -> ErrorRate {
+    let chars = |text: &str| -> Vec<char> { normalize_text(text).concat().chars().collect() };
+    let reference = chars(reference);
+    let hypothesis = chars(hypothesis);
+    count_edits(&align(&reference, &hypothesis), reference.len())
+}
+
+/// Concatenated-minimum-permutation WER (cpWER). Words are grouped by speaker
+/// and hypothesis speakers are mapped to reference speakers so that the total
+/// error is minimal; unmapped speakers count as deletions or insertions.
+pub fn speaker_attributed_wer(reference: &[SttWord], hypothesis: &[SttWord]) -> ErrorRate {
+    let by_speaker = |words: &[SttWord]| -> Vec<Vec<String>> {
+        let mut groups: BTreeMap<&str, Vec<String>> = BTreeMap::new();
+        for word in words {
+            groups
+                .entry(word.speaker.as_deref().unwrap_or_default())
+                .or_default()
+                .extend(normalize_text(&word.text));
+        }
+        groups.into_values().collect()
+    };
+    let reference = by_speaker(reference);
+    let hypothesis = by_speaker(hypothesis);
+
+    let pair_cost: Vec<Vec<ErrorRate>> = reference
+        .iter()
+        .map(|r| {
+            hypothesis
+                .iter()
+                .map(|h| count_edits(&align(r, h), r.len()))
+                .collect()
+        })
+        .collect();
+
+    let reference_lens: Vec<usize> = reference.iter().map(Vec::len).collect();
+    let hypothesis_lens: Vec<usize> = hypothesis.iter().map(Vec::len).collect();
+    let mut search = AssignmentSearch {
+        pair_cost: &pair_cost,
+        reference_lens: &reference_lens,
+        hypothesis_lens: &hypothesis_lens,
+        best: None,
+    };
+    search.run(&mut Vec::new(), &mut BTreeSet::new());
+    let assignment = search.best.map(|(_, a)| a).unwrap_or_default();
+
+    let mut total = ErrorRate::default();
+    for (r, h) in assignment.iter().enumerate() {
+        match h {
+            Some(h) => total += pair_cost[r][*h],
+            None => {
+                total += ErrorRate {
+                    deletions: reference_lens[r],
+                    reference_len: reference_lens[r],
+                    ..Default::default()
+                }
+            }
+        }
+    }
+    let assigned: BTreeSet<usize> = assignment.iter().flatten().copied().collect();
+    for (h, len) in hypothesis_lens.iter().enumerate() {
+        if !assigned.contains(&h) {
+            total.insertions += len;
+        }
+    }
+    total
+}
+
+/// Exhaustive search over speaker mappings. Meetings rarely have more than a
+/// handful of speakers, so this stays small.
+struct AssignmentSearch<'a> {
+    pair_cost: &'a [Vec<ErrorRate>],
+    reference_lens: &'a [usize],
+    hypothesis_lens: &'a [usize],
+    best: Option<(usize, Vec<Option<usize>>)>,
+}
+
+impl AssignmentSearch<'_> {
+    fn run(&mut self, assignment: &mut Vec<Option<usize>>, used: &mut BTreeSet<usize>) {
+        let r = assignment.len();
+        if r == self.reference_lens.len() {
+            let errors = self.errors(assignment, used);
+            if self.best.as_ref().is_none_or(|(e, _)| errors < *e) {
+                self.best = Some((errors, assignment.clone()));
+            }
+            return;
+        }
+
+        for h in 0..self.hypothesis_lens.len() {
+            if used.insert(h) {
+                assignment.push(Some(h));
+                self.run(assignment, used);
+                assignment.pop();
+                used.remove(&h);
+            }
+        }
+        assignment.push(None);
+        self.run(assignment, used);
+        assignment.pop();
+    }
+
+    fn errors(&self, assignment: &[Option<usize>], used: &BTreeSet<usize>) -> usize {
+        let assigned: usize = assignment
+            .iter()
+            .enumerate()
+            .map(|(r, h)| match h {
+                Some(h) => self.pair_cost[r][*h].errors(),
+                None => self.reference_lens[r],
+            })
+            .sum();
+        let unassigned: usize = self
+            .hypothesis_lens
+            .iter()
+            .enumerate()
+            .filter(|(h, _)| !used.contains(h))
+            .map(|(_, len)| len)
+            .sum();
+        assigned + unassigned
+    }
+}
+
+/// Start-time offset of hypothesis words against the reference words they
+/// were aligned to.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct TimingDrift {
+    pub matched_words: usize,
+    /// Positive when the hypothesis is late.
+    pub mean_ms: f64,
+    pub mean_abs_ms: f64,
+    pub max_abs_ms: u64,
+}
+
+pub fn timing_drift(reference: &[SttWord], hypothesis: &[SttWord]) -> TimingDrift {
+    let reference_tokens = normalize_words(reference);
+    let hypothesis_tokens = normalize_words(hypothesis);
+    let edits = align(
+        &reference_tokens.iter().map(|(t, _)| t).collect::<Vec<_>>(),
+        &hypothesis_tokens.iter().map(|(t, _)| t).collect::<Vec<_>>(),
+    );
+
+    let offsets: Vec<i64> = edits
+        .iter()
+        .filter_map(|edit| match edit {
+            Edit::Match(r, h) => {
+                let r = &reference[reference_tokens[*r].1];
+                let h = &hypothesis[hypothesis_tokens[*h].1];
+                Some(h.start_ms as i64 - r.start_ms as i64)
+            }
+            _ => None,
+        })
+        .collect();
+
+    if offsets.is_empty() {
+        return TimingDrift::default();
+    }
+
+    let count = offsets.len() as f64;
+    TimingDrift {
+        matched_words: offsets.len(),
+        mean_ms: offsets.iter().sum::<i64>() as f64 / count,
+        mean_abs_ms: offsets.iter().map(|o| o.unsigned_abs()).sum::<u64>() as f64 / count,
+        max_abs_ms: offsets.iter().map(|o| o.unsigned_abs()).max().unwrap_or(0),
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct SttScore {
+    pub wer: ErrorRate,
+    pub cer: ErrorRate,
+    /// `None` when either side has no speaker labels.
+    pub speaker_wer: Option<ErrorRate>,
+    pub timing: TimingDrift,
+}
+
+pub fn score_transcript(reference: &[SttWord], hypothesis: &[SttWord]) -> SttScore {
+    let join = |words: &[SttWord]| -> String {
+        words
+            .iter()
+            .map(|w| w.text.as_str())
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+    let reference_text = join(reference);
+    let hypothesis_text = join(hypothesis);
+
+    let has_speakers = |words: &[SttWord]| words.iter().any(|w| w.speaker.is_some());
+
+    SttScore {
+        wer: word_error_rate(&reference_text, &hypothesis_text),
+        cer: character_error_rate(&reference_text, &hypothesis_text),
+        speaker_wer: (has_speakers(reference) && has_speakers(hypothesis))
+            .then(|| speaker_attributed_wer(reference, hypothesis)),
+        timing: timing_drift(reference, hypothesis),
+    }
+}
+
+/// Outcome of transcribing one fixture with one provider.
+#[derive(Debug, Clone, Default)]
+pub struct SttResult {
+    pub fixture: String,
+    pub language: String,
+    pub provider: String,
+    pub model: String,
+    pub score: Option<SttScore>,
+    pub error: Option<String>,
+    pub elapsed_ms: u64,
+    pub cached: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn word(text: &str, start_ms: u64, speaker: &str) -> SttWord {
+        SttWord {
+            text: text.to_string(),
+            start_ms,
+            end_ms: start_ms + 100,
+            speaker: Some(speaker.to_string()),
+        }
+    }
+
+    #[test]
+    fn test_normalize_text() {
+        assert_eq!(
+            normalize_text("Um, I DON'T know... well-known!"),
+            ["i", "dont", "know", "well", "known"]
+        );
+    }
+
+    #[test]
+    fn test_word_error_rate() {
+        let rate = word_error_rate("the cat sat on the mat", "the cat sit on mat today");
+        assert_eq!(rate.errors(), 3);
+        assert_eq!(rate.reference_len, 6);
+        assert!((rate.rate() - 0.5).abs() < f64::EPSILON);
+
+        assert_eq!(word_error_rate("Hello, world.", "hello world").errors(), 0);
+        assert_eq!(word_error_rate("a b c", "a c").deletions, 1);
+        assert_eq!(word_error_rate("a b", "a b c").insertions, 1);
+        assert_eq!(word_error_rate("a b", "a x").substitutions, 1);
+        assert_eq!(word_error_rate("", "").rate(), 0.0);
+    }
+
+    #[test]
+    fn test_character_error_rate() {
+        let rate = character_error_rate("개인적인 질문인데요", "개인 적인 질문인대요");
+        assert_eq!(rate.reference_len, 9);
+        assert_eq!(rate.errors(), 1);
+    }
+
+    #[test]
+    fn test_speaker_attributed_wer_ignores_labels() {
+        let reference = vec![
+            word("hello", 0, "speaker0"),
+            word("there", 100, "speaker0"),
+            word("hi", 200, "speaker1"),
+        ];
+        let swapped = vec![
+            word("hello", 0, "1"),
+            word("there", 100, "1"),
+            word("hi", 200, "0"),
+        ];
+        assert_eq!(speaker_attributed_wer(&reference, &swapped).errors(), 0);
+
+        let merged = vec![
+            word("hello", 0, "0"),
+            word("there", 100, "0"),
+            word("hi", 200, "0"),
+        ];
+        let rate = speaker_attributed_wer(&reference, &merged);
+        assert_eq!(rate.errors(), 2);
+        assert_eq!(rate.reference_len, 3);
+    }
+
+    #[test]
+    fn test_timing_drift() {
+        let reference = vec![
+            word("a", 0, "s"),
+            word("b", 1000, "s"),
+            word("c", 2000, "s"),
+        ];
+        let hypothesis = vec![
+            word("a", 100, "s"),
+            word("b", 900, "s"),
+            word("x", 2000, "s"),
+        ];
+        let drift = timing_drift(&reference, &hypothesis);
+        assert_eq!(drift.matched_words, 2);
+        assert_eq!(drift.mean_ms, 0.0);
+        assert_eq!(drift.mean_abs_ms, 100.0);
+        assert_eq!(drift.max_abs_ms, 100);
+    }
+
+    #[test]
+    fn test_discover_fixtures() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("../data/src");
+        let fixtures = discover_fixtures(&root).unwrap();
+
+        let korean = fixtures.iter().find(|f| f.name == "korean_2").unwrap();
+        assert_eq!(korean.language, "ko");
+        assert!(korean.reference.iter().all(|w| !w.text.contains(' ')));
+
+        let english = fixtures.iter().find(|f| f.name == "english_2").unwrap();
+        assert_eq!(english.language, "en");
+        assert!(english.reference.iter().any(|w| w.speaker.is_some()));
+
+        let score = score_transcript(&english.reference, &english.reference);
+        assert_eq!(score.wer.errors(), 0);
+        assert_eq!(score.speaker_wer.map(|r| r.errors()), Some(0));
+        assert_eq!(score.timing.max_abs_ms, 0);
+    }
+
+    #[test]
+    fn test_join_tokens() {
+        let tokens: Vec<ReferenceToken> = serde_json::from_str(
+            r#"[
+                {"start": 0, "end": 10, "text": "개"},
+                {"start": 10, "end": 20, "text": "인적인"},
+                {"start": 20, "end": 30, "text": " "},
+                {"start": 30, "end": 40, "text": "질문"},
+                {"start": 40, "end": 50, "text": " wor"},
+                {"start": 50, "end": 60, "text": "ld and more "}
+            ]"#,
+        )
+        .unwrap();
+        let words = join_tokens(&tokens);
+        let texts: Vec<_> = words.iter().map(|w| w.text.as_str()).collect();
+        assert_eq!(texts, ["개인적인", "질문", "world", "and", "more"]);
+        assert_eq!((words[0].start_ms, words[0].end_ms), (0, 20));
+    }
+}
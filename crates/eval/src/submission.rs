@@ -87,6 +87,7 @@ pub enum GraderSpec {
     Func(ValidatorFn),
     FuncWithMeta(ValidatorFnWithMeta),
     Llm { samples: i32 },
+    Declarative(std::sync::Arc<crate::CompiledGrader>),
 }
 
 impl std::fmt::Debug for GraderSpec {
@@ -95,6 +96,7 @@ impl std::fmt::Debug for GraderSpec {
             GraderSpec::Func(_) => write!(f, "Func(<fn>)"),
             GraderSpec::FuncWithMeta(_) => write!(f, "FuncWithMeta(<fn>)"),
             GraderSpec::Llm { samples } => f.debug_struct("Llm").field("samples", samples).finish(),
+            GraderSpec::Declarative(grader) => f.debug_tuple("Declarative").field(grader).finish(),
         }
     }
 }
@@ -483,6 +485,20 @@ impl Executor {
                     ..Default::default()
                 }
             }
+            GraderSpec::Declarative(grader) => {
+                let (passed, reasoning) = grader.grade(output, meta);
+                if let Some(cb) = on_evaluation {
+                    cb();
+                }
+                Score {
+                    rubric_name: rubric.name.clone(),
+                    passed,
+                    value: if passed { 1 } else { 0 },
+                    reasoning,
+                    grader_type: "declarative".to_string(),
+                    ..Default::default()
+                }
+            }
             GraderSpec::Llm { samples } => {
                 let meta_map: Option<HashMap<String, serde_json::Value>> = meta
                     .and_then(|v| v.as_object())
@@ -0,0 +1,237 @@
+//! Fireflies.ai JSON exports.
+//!
+//! Accepts the GraphQL `transcript` shape (`{ "data": { "transcript": ... } }`),
+//! a bare transcript object or a list of them, and the sentence-only array
+//! produced by the web app's download button.
+
+use serde::Deserialize;
+
+use crate::segment::{Meeting, Segment, merge};
+use crate::time::parse_clock;
+use crate::types::*;
+use crate::{Error, Result};
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum Export {
+    Wrapped { data: Wrapped },
+    Many(Vec<FirefliesTranscript>),
+    One(FirefliesTranscript),
+    Sentences(Vec<Sentence>),
+}
+
+#[derive(Debug, Deserialize)]
+struct Wrapped {
+    transcript: FirefliesTranscript,
+}
+
+#[derive(Debug, Deserialize)]
+struct FirefliesTranscript {
+    #[serde(default)]
+    id: Option<String>,
+    #[serde(default)]
+    title: Option<String>,
+    #[serde(default)]
+    date: Option<serde_json::Value>,
+    sentences: Vec<Sentence>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Sentence {
+    #[serde(default, alias = "speaker")]
+    speaker_name: Option<String>,
+    #[serde(alias = "sentence")]
+    text: String,
+    #[serde(default, alias = "startTime")]
+    start_time: Option<TimeValue>,
+    #[serde(default, alias = "endTime")]
+    end_time: Option<TimeValue>,
+}
+
+/// Seconds as a number, or a `mm:ss` style string.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum TimeValue {
+    Seconds(f64),
+    Clock(String),
+}
+
+impl TimeValue {
+    fn to_ms(&self) -> Option<f64> {
+        match self {
+            TimeValue::Seconds(s) => Some(s * 1000.0),
+            TimeValue::Clock(s) => {
+                parse_clock(s).or_else(|| s.parse::<f64>().ok().map(|s| s * 1000.0))
+            }
+        }
+    }
+}
+
+pub fn parse(content: &str, title: &str, created_at: &str) -> Result<Collection> {
+    let transcripts = match serde_json::from_str::<Export>(content)? {
+        Export::Wrapped { data } => vec![data.transcript],
+        Export::Many(list) => list,
+        Export::One(t) => vec![t],
+        Export::Sentences(sentences) => vec![FirefliesTranscript {
+            id: None,
+            title: None,
+            date: None,
+            sentences,
+        }],
+    };
+
+    if transcripts.iter().all(|t| t.sentences.is_empty()) {
+        return Err(Error::InvalidData(format!(
+            "no sentences found in Fireflies export: {}",
+            title
+        )));
+    }
+
+    Ok(merge(
+        transcripts
+            .into_iter()
+            .enumerate()
+            .filter(|(_, t)| !t.sentences.is_empty())
+            .map(|(index, t)| to_meeting(t, index, content, title, created_at).into_collection()),
+    ))
+}
+
+/// Transcripts without an ID are keyed by the export and their position in
+/// it; the first keeps the bare export so earlier imports still match.
+fn to_meeting(
+    transcript: FirefliesTranscript,
+    index: usize,
+    content: &str,
+    title: &str,
+    created_at: &str,
+) -> Meeting {
+    let key = match &transcript.id {
+        Some(id) => id.clone(),
+        None if index == 0 => content.to_string(),
+        None => format!("{content}#{index}"),
+    };
+    let created_at = transcript
+        .date
+        .as_ref()
+        .and_then(parse_date)
+        .unwrap_or_else(|| created_at.to_string());
+
+    let mut meeting = Meeting::new(
+        "fireflies",
+        &key,
+        transcript.title.unwrap_or_else(|| title.to_string()),
+        created_at,
+    );
+    meeting.segments = transcript
+        .sentences
+        .into_iter()
+        .map(|s| Segment {
+            speaker: s.speaker_name.filter(|n| !n.trim().is_empty()),
+            start_ms: s.start_time.as_ref().and_then(TimeValue::to_ms),
+            end_ms: s.end_time.as_ref().and_then(TimeValue::to_ms),
+            text: s.text,
+        })
+        .collect();
+
+    meeting
+}
+
+/// Fireflies reports `date` as epoch milliseconds, sometimes as a string.
+fn parse_date(value: &serde_json::Value) -> Option<String> {
+    let millis = match value {
+        serde_json::Value::Number(n) => n.as_i64(),
+        serde_json::Value::String(s) => {
+            if let Ok(dt) = chrono::DateTime::parse_from_rfc3339(s) {
+                return Some(dt.to_rfc3339());
+            }
+            s.parse::<i64>().ok()
+        }
+        _ => None,
+    }?;
+
+    chrono::DateTime::from_timestamp_millis(millis).map(|dt| dt.to_rfc3339())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_graphql_shape() {
+        let content = r#"{
+            "data": {
+                "transcript": {
+                    "id": "01HX",
+                    "title": "Pricing review",
+                    "date": 1714557600000,
+                    "sentences": [
+                        { "index": 0, "speaker_name": "Jane Doe", "text": "Let's review pricing.", "raw_text": "lets review pricing", "start_time": 0.5, "end_time": 2.1 },
+                        { "index": 1, "speaker_name": "John Smith", "text": "Agreed.", "start_time": 2.4, "end_time": 3.0 }
+                    ]
+                }
+            }
+        }"#;
+        let collection = parse(content, "file", "").unwrap();
+
+        let session = &collection.sessions[0];
+        assert_eq!(session.title, "Pricing review");
+        assert_eq!(session.created_at, "2024-05-01T10:00:00+00:00");
+        assert_eq!(collection.humans.len(), 2);
+
+        let words = &collection.transcripts[0].words;
+        assert_eq!(words.len(), 4);
+        assert_eq!(words[0].start_ms, Some(500.0));
+        assert_eq!(words[2].end_ms, Some(2100.0));
+        assert_eq!(words[3].speaker.as_deref(), Some("John Smith"));
+    }
+
+    #[test]
+    fn test_parse_sentence_array() {
+        let content = r#"[
+            { "sentence": "Hello there", "startTime": "00:01", "endTime": "00:02", "speaker_name": "Jane Doe" },
+            { "sentence": "Hi", "startTime": "00:03", "endTime": "00:04", "speaker_name": "" }
+        ]"#;
+        let collection = parse(content, "call", "2024-05-01T10:00:00Z").unwrap();
+
+        assert_eq!(collection.sessions[0].title, "call");
+        assert_eq!(collection.humans.len(), 1);
+        let words = &collection.transcripts[0].words;
+        assert_eq!(words[0].start_ms, Some(1000.0));
+        assert_eq!(words[2].speaker, None);
+    }
+
+    #[test]
+    fn test_parse_many_uses_stable_ids() {
+        let content = r#"[
+            { "id": "a", "title": "A", "sentences": [{ "speaker_name": "Jane", "text": "one" }] },
+            { "id": "b", "title": "B", "sentences": [{ "speaker_name": "Jane", "text": "two" }] }
+        ]"#;
+        let first = parse(content, "", "").unwrap();
+        let second = parse(content, "", "").unwrap();
+
+        assert_eq!(first.sessions.len(), 2);
+        assert_eq!(first.humans.len(), 1);
+        assert_eq!(first.sessions[0].id, second.sessions[0].id);
+    }
+
+    #[test]
+    fn test_parse_many_without_ids() {
+        let content = r#"[
+            { "title": "A", "sentences": [{ "speaker_name": "Jane", "text": "one" }] },
+            { "title": "B", "sentences": [{ "speaker_name": "Jane", "text": "two" }] }
+        ]"#;
+        let first = parse(content, "", "").unwrap();
+        let second = parse(content, "", "").unwrap();
+
+        assert_eq!(first.sessions.len(), 2);
+        assert_ne!(first.sessions[0].id, first.sessions[1].id);
+        assert_eq!(first.sessions[1].id, second.sessions[1].id);
+        assert_eq!(first.transcripts.len(), 2);
+    }
+
+    #[test]
+    fn test_parse_rejects_empty() {
+        assert!(parse(r#"{ "sentences": [] }"#, "", "").is_err());
+        assert!(parse("not json", "", "").is_err());
+    }
+}
@@ -0,0 +1,125 @@
+mod error;
+mod segment;
+mod time;
+mod types;
+
+pub mod fireflies;
+pub mod otter;
+pub mod vtt;
+
+pub use error::{Error, Result};
+pub use segment::{Meeting, Segment, merge};
+pub use types::*;
+
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Otter,
+    Fireflies,
+    /// Zoom cloud-recording and Teams transcripts.
+    Vtt,
+}
+
+impl ExportFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Otter => "txt",
+            ExportFormat::Fireflies => "json",
+            ExportFormat::Vtt => "vtt",
+        }
+    }
+
+    pub fn parse(&self, content: &str, title: &str, created_at: &str) -> Result<Collection> {
+        match self {
+            ExportFormat::Otter => otter::parse(content, title, created_at),
+            ExportFormat::Fireflies => fireflies::parse(content, title, created_at),
+            ExportFormat::Vtt => vtt::parse(content, title, created_at),
+        }
+    }
+}
+
+/// Parses a single export file, or every matching file under a directory.
+///
+/// The file stem becomes the session title and the modification time its
+/// creation date, unless the export itself says otherwise.
+pub fn parse_from_path(path: &Path, format: ExportFormat) -> Result<Collection> {
+    if path.is_file() {
+        return parse_file(path, format);
+    }
+
+    let mut files = Vec::new();
+    collect_files(path, format.extension(), &mut files)?;
+    files.sort();
+
+    let collections = files
+        .iter()
+        .map(|file| parse_file(file, format))
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(merge(collections))
+}
+
+fn parse_file(path: &Path, format: ExportFormat) -> Result<Collection> {
+    let content = std::fs::read_to_string(path)?;
+    let title = path
+        .file_stem()
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let created_at = std::fs::metadata(path)
+        .and_then(|m| m.modified())
+        .map(|t| chrono::DateTime::<chrono::Utc>::from(t).to_rfc3339())
+        .unwrap_or_default();
+
+    format.parse(&content, &title, &created_at)
+}
+
+fn collect_files(dir: &Path, extension: &str, out: &mut Vec<PathBuf>) -> Result<()> {
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_files(&path, extension, out)?;
+        } else if path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case(extension))
+        {
+            out.push(path);
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_from_directory() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir(dir.path().join("2024")).unwrap();
+        std::fs::write(
+            dir.path().join("standup.vtt"),
+            "WEBVTT\n\n00:00:01.000 --> 00:00:02.000\nJane Doe: Morning\n",
+        )
+        .unwrap();
+        std::fs::write(
+            dir.path().join("2024/retro.vtt"),
+            "WEBVTT\n\n00:00:01.000 --> 00:00:02.000\nJane Doe: Retro time\n",
+        )
+        .unwrap();
+        std::fs::write(dir.path().join("ignored.txt"), "Jane Doe  0:00\nhi").unwrap();
+
+        let collection = parse_from_path(dir.path(), ExportFormat::Vtt).unwrap();
+
+        let mut titles: Vec<_> = collection
+            .sessions
+            .iter()
+            .map(|s| s.title.as_str())
+            .collect();
+        titles.sort();
+        assert_eq!(titles, vec!["retro", "standup"]);
+        assert_eq!(collection.humans.len(), 1);
+        assert!(!collection.sessions[0].created_at.is_empty());
+    }
+}
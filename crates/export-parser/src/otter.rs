@@ -0,0 +1,158 @@
+//! Otter.ai plain-text exports (TXT, or the text of a DOCX export).
+//!
+//! ```text
+//! Jane Doe  0:00
+//! Thanks everyone for joining.
+//!
+//! John Smith  1:02:03
+//! Happy to be here.
+//! ```
+
+use crate::segment::{Meeting, Segment};
+use crate::time::parse_clock;
+use crate::types::*;
+use crate::{Error, Result};
+
+const FOOTER_PREFIX: &str = "Transcribed by";
+
+pub fn parse(content: &str, title: &str, created_at: &str) -> Result<Collection> {
+    let mut meeting = Meeting::new("otter", content, title.to_string(), created_at.to_string());
+    meeting.segments = parse_segments(content);
+
+    if meeting.segments.is_empty() {
+        return Err(Error::InvalidData(format!(
+            "no speaker sections found in Otter export: {}",
+            title
+        )));
+    }
+
+    Ok(meeting.into_collection())
+}
+
+fn parse_segments(content: &str) -> Vec<Segment> {
+    let lines: Vec<&str> = content
+        .lines()
+        .map(|line| line.trim_start_matches('\u{feff}').trim())
+        .filter(|line| !line.is_empty() && !line.starts_with(FOOTER_PREFIX))
+        .collect();
+
+    let mut segments: Vec<Segment> = Vec::new();
+    for (index, line) in lines.iter().enumerate() {
+        // A header always has the speaker's text on the lines after it.
+        let header = parse_header(line).filter(|_| {
+            lines
+                .get(index + 1)
+                .is_some_and(|next| parse_header(next).is_none())
+        });
+
+        if let Some((speaker, start_ms)) = header {
+            segments.push(Segment {
+                speaker: Some(speaker),
+                text: String::new(),
+                start_ms: Some(start_ms),
+                end_ms: None,
+            });
+            continue;
+        }
+
+        if let Some(current) = segments.last_mut() {
+            if !current.text.is_empty() {
+                current.text.push(' ');
+            }
+            current.text.push_str(line);
+        }
+    }
+
+    segments
+}
+
+/// `Speaker Name  0:00` → (`Speaker Name`, 0.0). Otter separates the name
+/// from the `H:MM` or `H:MM:SS` time with two or more spaces (a tab in some
+/// DOCX exports), unlike a spoken line that happens to end in a time.
+fn parse_header(line: &str) -> Option<(String, f64)> {
+    let (speaker, timestamp) = line.split_once("  ").or_else(|| line.split_once('\t'))?;
+
+    let timestamp = timestamp.trim();
+    if !timestamp.chars().all(|c| c.is_ascii_digit() || c == ':') {
+        return None;
+    }
+    let start_ms = parse_clock(timestamp)?;
+
+    let speaker = speaker.trim();
+    if speaker.is_empty() || speaker.split_whitespace().count() > 6 {
+        return None;
+    }
+
+    Some((speaker.to_string(), start_ms))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "\u{feff}Jane Doe  0:00
+Thanks everyone for joining.
+Let's get started.
+
+Speaker 2  0:07
+Sounds good.
+
+John Smith  1:02:03
+Happy to be here.
+
+Transcribed by https://otter.ai
+";
+
+    #[test]
+    fn test_parse_segments() {
+        let segments = parse_segments(SAMPLE);
+
+        assert_eq!(segments.len(), 3);
+        assert_eq!(segments[0].speaker.as_deref(), Some("Jane Doe"));
+        assert_eq!(
+            segments[0].text,
+            "Thanks everyone for joining. Let's get started."
+        );
+        assert_eq!(segments[1].start_ms, Some(7_000.0));
+        assert_eq!(segments[2].speaker.as_deref(), Some("John Smith"));
+        assert_eq!(segments[2].start_ms, Some(3_723_000.0));
+    }
+
+    #[test]
+    fn test_parse() {
+        let collection = parse(SAMPLE, "Weekly sync", "2024-05-01T10:00:00Z").unwrap();
+
+        assert_eq!(collection.sessions[0].title, "Weekly sync");
+        let names: Vec<_> = collection.humans.iter().map(|h| h.name.as_str()).collect();
+        assert_eq!(names, vec!["Jane Doe", "John Smith"]);
+
+        let words = &collection.transcripts[0].words;
+        assert_eq!(words.first().unwrap().start_ms, Some(0.0));
+        assert_eq!(words[7].start_ms, Some(7_000.0));
+    }
+
+    #[test]
+    fn test_body_ending_in_a_time() {
+        let segments = parse_segments(
+            "Jane Doe  0:00\nWhen works for you?\n\nJohn Smith  0:05\nLet's meet at 3:00\n\nJane Doe  0:09\nPerfect, see you at 3:00\n",
+        );
+
+        let texts: Vec<_> = segments
+            .iter()
+            .map(|s| (s.speaker.as_deref().unwrap(), s.text.as_str()))
+            .collect();
+        assert_eq!(
+            texts,
+            vec![
+                ("Jane Doe", "When works for you?"),
+                ("John Smith", "Let's meet at 3:00"),
+                ("Jane Doe", "Perfect, see you at 3:00"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_rejects_unrelated_text() {
+        assert!(parse("just some notes\nwithout speakers", "notes", "").is_err());
+    }
+}
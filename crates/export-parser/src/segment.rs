@@ -0,0 +1,324 @@
+use std::collections::HashSet;
+
+use crate::types::*;
+
+// Rough conversational pace, used when an export has no end times.
+const MS_PER_WORD: f64 = 400.0;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Segment {
+    pub speaker: Option<String>,
+    pub text: String,
+    pub start_ms: Option<f64>,
+    pub end_ms: Option<f64>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Meeting {
+    pub id: String,
+    pub title: String,
+    pub created_at: String,
+    pub segments: Vec<Segment>,
+}
+
+impl Meeting {
+    pub fn new(source: &str, content: &str, title: String, created_at: String) -> Self {
+        let id = uuid::Uuid::new_v5(
+            &uuid::Uuid::NAMESPACE_OID,
+            format!("{}:{}", source, content).as_bytes(),
+        )
+        .to_string();
+
+        Self {
+            id,
+            title,
+            created_at,
+            segments: vec![],
+        }
+    }
+
+    pub fn into_collection(mut self) -> Collection {
+        self.segments.retain(|s| !s.text.trim().is_empty());
+        fill_timestamps(&mut self.segments);
+
+        let mut humans = Vec::new();
+        let mut participants = Vec::new();
+        let mut seen = HashSet::new();
+
+        for name in self.segments.iter().filter_map(|s| s.speaker.as_deref()) {
+            if is_generic_speaker(name) || !seen.insert(name.to_lowercase()) {
+                continue;
+            }
+
+            let human_id = human_id_for(name);
+            participants.push(SessionParticipant {
+                id: format!("{}-{}", self.id, human_id),
+                user_id: String::new(),
+                session_id: self.id.clone(),
+                human_id: human_id.clone(),
+                source: "imported".to_string(),
+            });
+            humans.push(Human {
+                id: human_id,
+                user_id: String::new(),
+                created_at: self.created_at.clone(),
+                name: name.to_string(),
+                email: None,
+                org_id: None,
+                job_title: None,
+                linkedin_username: None,
+            });
+        }
+
+        let words = segments_to_words(&self.id, &self.segments);
+        let start_ms = words.first().and_then(|w| w.start_ms);
+        let end_ms = words.last().and_then(|w| w.end_ms);
+
+        let transcripts = if words.is_empty() {
+            vec![]
+        } else {
+            vec![Transcript {
+                id: self.id.clone(),
+                user_id: String::new(),
+                created_at: self.created_at.clone(),
+                session_id: self.id.clone(),
+                title: self.title.clone(),
+                started_at: start_ms.unwrap_or(0.0),
+                ended_at: end_ms,
+                start_ms,
+                end_ms,
+                words,
+                speaker_hints: vec![],
+            }]
+        };
+
+        let session = Session {
+            id: self.id,
+            user_id: String::new(),
+            created_at: self.created_at,
+            title: self.title,
+            raw_md: None,
+            enhanced_content: None,
+            folder_id: None,
+            event_id: None,
+        };
+
+        Collection {
+            sessions: vec![session],
+            transcripts,
+            humans,
+            participants,
+            ..Default::default()
+        }
+    }
+}
+
+/// Merges per-file collections, keeping one `Human` per speaker name.
+pub fn merge(collections: impl IntoIterator<Item = Collection>) -> Collection {
+    let mut merged = Collection::default();
+    let mut human_ids = HashSet::new();
+
+    for c in collections {
+        merged.sessions.extend(c.sessions);
+        merged.transcripts.extend(c.transcripts);
+        merged.participants.extend(c.participants);
+        merged.humans.extend(
+            c.humans
+                .into_iter()
+                .filter(|h| human_ids.insert(h.id.clone())),
+        );
+    }
+
+    merged
+}
+
+pub fn human_id_for(name: &str) -> String {
+    uuid::Uuid::new_v5(
+        &uuid::Uuid::NAMESPACE_OID,
+        format!("human:{}", name.trim().to_lowercase()).as_bytes(),
+    )
+    .to_string()
+}
+
+/// Labels like "Speaker 1" or "Unknown Speaker" that don't identify a person.
+pub fn is_generic_speaker(name: &str) -> bool {
+    let name = name.trim();
+    if name.is_empty() {
+        return true;
+    }
+
+    let lower = name.to_lowercase();
+    if matches!(lower.as_str(), "unknown" | "unknown speaker" | "speaker") {
+        return true;
+    }
+
+    lower
+        .strip_prefix("speaker")
+        .map(|rest| {
+            let rest = rest.trim();
+            !rest.is_empty() && rest.chars().all(|c| c.is_ascii_digit())
+        })
+        .unwrap_or(false)
+}
+
+/// Fills in missing start/end times so that every segment has a range.
+///
+/// A missing end is taken from the next segment's start, or estimated from
+/// the word count. A missing start continues from the previous segment.
+pub fn fill_timestamps(segments: &mut [Segment]) {
+    let mut cursor = 0.0;
+
+    for i in 0..segments.len() {
+        let start = segments[i].start_ms.unwrap_or(cursor);
+        let estimated = start + segments[i].text.split_whitespace().count() as f64 * MS_PER_WORD;
+
+        let end = segments[i]
+            .end_ms
+            .filter(|end| *end > start)
+            .or_else(|| {
+                segments[i + 1..]
+                    .iter()
+                    .find_map(|s| s.start_ms)
+                    .filter(|next| *next > start)
+                    .map(|next| next.min(estimated))
+            })
+            .unwrap_or(estimated);
+
+        segments[i].start_ms = Some(start);
+        segments[i].end_ms = Some(end);
+        cursor = end;
+    }
+}
+
+/// Splits each segment into words, spreading its time range across them in
+/// proportion to their length.
+fn segments_to_words(session_id: &str, segments: &[Segment]) -> Vec<Word> {
+    let mut words = Vec::new();
+
+    for segment in segments {
+        let tokens: Vec<&str> = segment.text.split_whitespace().collect();
+        let total_chars: usize = tokens.iter().map(|t| t.chars().count()).sum();
+        if total_chars == 0 {
+            continue;
+        }
+
+        let start = segment.start_ms.unwrap_or(0.0);
+        let end = segment.end_ms.unwrap_or(start);
+        let ms_per_char = (end - start).max(0.0) / total_chars as f64;
+
+        let mut offset = start;
+        for token in tokens {
+            let duration = token.chars().count() as f64 * ms_per_char;
+            words.push(Word {
+                id: format!("{}-{}", session_id, words.len()),
+                text: format!(" {}", token),
+                start_ms: Some(offset.round()),
+                end_ms: Some((offset + duration).round()),
+                channel: 0,
+                speaker: segment.speaker.clone(),
+            });
+            offset += duration;
+        }
+    }
+
+    words
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn segment(speaker: &str, text: &str, start_ms: Option<f64>, end_ms: Option<f64>) -> Segment {
+        Segment {
+            speaker: Some(speaker.to_string()),
+            text: text.to_string(),
+            start_ms,
+            end_ms,
+        }
+    }
+
+    #[test]
+    fn test_is_generic_speaker() {
+        assert!(is_generic_speaker("Speaker 1"));
+        assert!(is_generic_speaker("speaker12"));
+        assert!(is_generic_speaker("Unknown Speaker"));
+        assert!(is_generic_speaker("  "));
+        assert!(!is_generic_speaker("Jane Doe"));
+        assert!(!is_generic_speaker("Speakerman"));
+    }
+
+    #[test]
+    fn test_fill_timestamps_from_next_start() {
+        let mut segments = vec![
+            segment("A", "one two three", Some(0.0), None),
+            segment("B", "four", Some(1000.0), None),
+        ];
+        fill_timestamps(&mut segments);
+
+        assert_eq!(segments[0].end_ms, Some(1000.0));
+        assert_eq!(segments[1].start_ms, Some(1000.0));
+        assert_eq!(segments[1].end_ms, Some(1000.0 + MS_PER_WORD));
+    }
+
+    #[test]
+    fn test_fill_timestamps_caps_long_gaps() {
+        let mut segments = vec![
+            segment("A", "hi", Some(0.0), None),
+            segment("B", "hello", Some(60_000.0), None),
+        ];
+        fill_timestamps(&mut segments);
+
+        assert_eq!(segments[0].end_ms, Some(MS_PER_WORD));
+    }
+
+    #[test]
+    fn test_fill_timestamps_without_any_times() {
+        let mut segments = vec![
+            segment("A", "one two", None, None),
+            segment("B", "three", None, None),
+        ];
+        fill_timestamps(&mut segments);
+
+        assert_eq!(segments[0].start_ms, Some(0.0));
+        assert_eq!(segments[1].start_ms, Some(2.0 * MS_PER_WORD));
+        assert_eq!(segments[1].end_ms, Some(3.0 * MS_PER_WORD));
+    }
+
+    #[test]
+    fn test_into_collection() {
+        let mut meeting = Meeting::new("test", "content", "Standup".into(), String::new());
+        meeting.segments = vec![
+            segment("Jane Doe", "Good morning", Some(0.0), Some(1000.0)),
+            segment("Speaker 2", "Hi", Some(1000.0), Some(1500.0)),
+            segment("jane doe", "Let's start", Some(1500.0), Some(2500.0)),
+        ];
+        let collection = meeting.into_collection();
+
+        assert_eq!(collection.sessions.len(), 1);
+        assert_eq!(collection.humans.len(), 1);
+        assert_eq!(collection.humans[0].name, "Jane Doe");
+        assert_eq!(collection.participants.len(), 1);
+
+        let words = &collection.transcripts[0].words;
+        assert_eq!(words.len(), 5);
+        assert_eq!(words[0].text, " Good");
+        assert_eq!(words[0].start_ms, Some(0.0));
+        assert_eq!(words[1].end_ms, Some(1000.0));
+        assert_eq!(words[2].speaker.as_deref(), Some("Speaker 2"));
+        assert!(words.windows(2).all(|w| w[0].start_ms <= w[1].start_ms));
+    }
+
+    #[test]
+    fn test_merge_dedups_humans() {
+        let make = |content: &str| {
+            let mut meeting = Meeting::new("test", content, String::new(), String::new());
+            meeting.segments = vec![segment("Jane Doe", "hello", None, None)];
+            meeting.into_collection()
+        };
+        let merged = merge([make("a"), make("b")]);
+
+        assert_eq!(merged.sessions.len(), 2);
+        assert_eq!(merged.humans.len(), 1);
+        assert_eq!(merged.participants.len(), 2);
+    }
+}
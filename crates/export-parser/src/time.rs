@@ -0,0 +1,51 @@
+/// Parses clock-style offsets (`1:02`, `01:02:03`, `00:01:02.500`, `00:01:02,5`)
+/// into milliseconds.
+pub fn parse_clock(s: &str) -> Option<f64> {
+    let s = s.trim();
+    let (clock, fraction) = match s.split_once(['.', ',']) {
+        Some((clock, fraction)) => (clock, Some(fraction)),
+        None => (s, None),
+    };
+
+    let parts: Vec<&str> = clock.split(':').collect();
+    if parts.len() < 2 || parts.len() > 3 {
+        return None;
+    }
+
+    let mut seconds = 0.0;
+    for part in &parts {
+        if part.is_empty() || !part.chars().all(|c| c.is_ascii_digit()) {
+            return None;
+        }
+        seconds = seconds * 60.0 + part.parse::<f64>().ok()?;
+    }
+
+    let millis = match fraction {
+        Some(f) if !f.is_empty() && f.chars().all(|c| c.is_ascii_digit()) => {
+            let padded = format!("{:0<3}", f);
+            padded[..3].parse::<f64>().ok()?
+        }
+        Some(_) => return None,
+        None => 0.0,
+    };
+
+    Some(seconds * 1000.0 + millis)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_clock() {
+        assert_eq!(parse_clock("0:05"), Some(5_000.0));
+        assert_eq!(parse_clock("12:34"), Some(754_000.0));
+        assert_eq!(parse_clock("1:02:03"), Some(3_723_000.0));
+        assert_eq!(parse_clock("00:00:01.5"), Some(1_500.0));
+        assert_eq!(parse_clock("00:00:01,250"), Some(1_250.0));
+        assert_eq!(parse_clock("00:01.123456"), Some(1_123.0));
+        assert_eq!(parse_clock("hello"), None);
+        assert_eq!(parse_clock("5"), None);
+        assert_eq!(parse_clock("1:2:3:4"), None);
+    }
+}
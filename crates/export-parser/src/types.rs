@@ -0,0 +1 @@
+pub use hypr_importer_core::ir::*;
@@ -0,0 +1,207 @@
+//! WebVTT transcripts from Zoom cloud recordings and Microsoft Teams.
+//!
+//! Zoom puts the speaker in the cue text (`Jane Doe: Hello`), Teams uses voice
+//! spans (`<v Jane Doe>Hello</v>`). Cues without either keep no speaker.
+
+use crate::segment::{Meeting, Segment};
+use crate::time::parse_clock;
+use crate::types::*;
+use crate::{Error, Result};
+
+// Longer prefixes before ':' are more likely part of the sentence than a name.
+const MAX_SPEAKER_WORDS: usize = 5;
+
+pub fn parse(content: &str, title: &str, created_at: &str) -> Result<Collection> {
+    let content = content.trim_start_matches('\u{feff}');
+    if !content.trim_start().starts_with("WEBVTT") {
+        return Err(Error::InvalidData(format!(
+            "missing WEBVTT header: {}",
+            title
+        )));
+    }
+
+    let mut meeting = Meeting::new("vtt", content, title.to_string(), created_at.to_string());
+    meeting.segments = merge_consecutive(parse_cues(content));
+
+    Ok(meeting.into_collection())
+}
+
+fn parse_cues(content: &str) -> Vec<Segment> {
+    let normalized = content.replace("\r\n", "\n");
+
+    normalized
+        .split("\n\n")
+        .filter_map(|block| {
+            let mut lines = block.lines().map(str::trim).filter(|l| !l.is_empty());
+            let mut line = lines.next()?;
+
+            if !line.contains("-->") {
+                line = lines.next()?;
+            }
+            let (start, end) = parse_timing(line)?;
+
+            let text = lines.collect::<Vec<_>>().join(" ");
+            let (speaker, text) = split_speaker(&text);
+            let text = strip_tags(&text);
+            if text.trim().is_empty() {
+                return None;
+            }
+
+            Some(Segment {
+                speaker,
+                text: text.trim().to_string(),
+                start_ms: Some(start),
+                end_ms: Some(end),
+            })
+        })
+        .collect()
+}
+
+/// `00:00:01.000 --> 00:00:04.000 align:start` → (1000.0, 4000.0)
+fn parse_timing(line: &str) -> Option<(f64, f64)> {
+    let (start, rest) = line.split_once("-->")?;
+    let end = rest.split_whitespace().next()?;
+    Some((parse_clock(start)?, parse_clock(end)?))
+}
+
+fn split_speaker(text: &str) -> (Option<String>, String) {
+    if let Some(rest) = text.strip_prefix("<v")
+        && let Some((tag, body)) = rest.split_once('>')
+    {
+        // `<v.loud Jane Doe>` carries classes before the name.
+        let name = match tag.strip_prefix('.') {
+            Some(classed) => classed.split_once(' ').map(|(_, n)| n).unwrap_or(""),
+            None => tag,
+        };
+        let name = name.trim();
+        let speaker = (!name.is_empty()).then(|| name.to_string());
+        return (speaker, body.to_string());
+    }
+
+    if let Some((name, body)) = text.split_once(": ") {
+        let name = name.trim();
+        let word_count = name.split_whitespace().count();
+        if word_count > 0
+            && word_count <= MAX_SPEAKER_WORDS
+            && !name.contains(['<', '.', '?', '!', ','])
+        {
+            return (Some(name.to_string()), body.to_string());
+        }
+    }
+
+    (None, text.to_string())
+}
+
+fn strip_tags(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut in_tag = false;
+
+    for c in text.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => out.push(c),
+            _ => {}
+        }
+    }
+
+    out.replace("&amp;", "&")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&nbsp;", " ")
+}
+
+/// Teams splits utterances into many short cues; join adjacent ones from the
+/// same speaker so word timings stay monotonic within a turn.
+fn merge_consecutive(segments: Vec<Segment>) -> Vec<Segment> {
+    let mut merged: Vec<Segment> = Vec::with_capacity(segments.len());
+
+    for segment in segments {
+        match merged.last_mut() {
+            Some(last) if last.speaker.is_some() && last.speaker == segment.speaker => {
+                last.text.push(' ');
+                last.text.push_str(&segment.text);
+                last.end_ms = segment.end_ms;
+            }
+            _ => merged.push(segment),
+        }
+    }
+
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ZOOM: &str = "WEBVTT
+
+1
+00:00:01.000 --> 00:00:04.000
+Jane Doe: Hello everyone, thanks for joining.
+
+2
+00:00:04.500 --> 00:00:06.000
+John Smith: Happy to be here.
+
+3
+00:00:06.000 --> 00:00:08.000
+Note that this line has no speaker.
+";
+
+    const TEAMS: &str = "WEBVTT\r
+\r
+0e8a6b5c-1/12-0\r
+00:00:00.000 --> 00:00:02.500\r
+<v Jane Doe>Hello everyone,</v>\r
+\r
+0e8a6b5c-1/12-1\r
+00:00:02.500 --> 00:00:04.000\r
+<v Jane Doe>let's get going.</v>\r
+\r
+NOTE this block is ignored\r
+\r
+4f1d-2/20-0\r
+00:00:04.200 --> 00:00:05.000\r
+<v.loud John Smith>Sure &amp; thanks!</v>\r
+";
+
+    #[test]
+    fn test_parse_zoom_cues() {
+        let segments = parse_cues(ZOOM);
+
+        assert_eq!(segments.len(), 3);
+        assert_eq!(segments[0].speaker.as_deref(), Some("Jane Doe"));
+        assert_eq!(segments[0].text, "Hello everyone, thanks for joining.");
+        assert_eq!(segments[0].start_ms, Some(1000.0));
+        assert_eq!(segments[1].end_ms, Some(6000.0));
+        assert_eq!(segments[2].speaker, None);
+    }
+
+    #[test]
+    fn test_parse_teams_cues() {
+        let segments = merge_consecutive(parse_cues(TEAMS));
+
+        assert_eq!(segments.len(), 2);
+        assert_eq!(segments[0].speaker.as_deref(), Some("Jane Doe"));
+        assert_eq!(segments[0].text, "Hello everyone, let's get going.");
+        assert_eq!(segments[0].end_ms, Some(4000.0));
+        assert_eq!(segments[1].speaker.as_deref(), Some("John Smith"));
+        assert_eq!(segments[1].text, "Sure & thanks!");
+    }
+
+    #[test]
+    fn test_parse() {
+        let collection = parse(TEAMS, "Teams call", "").unwrap();
+
+        assert_eq!(collection.humans.len(), 2);
+        let transcript = &collection.transcripts[0];
+        assert_eq!(transcript.start_ms, Some(0.0));
+        assert_eq!(transcript.end_ms, Some(5000.0));
+    }
+
+    #[test]
+    fn test_parse_rejects_non_vtt() {
+        assert!(parse("1\n00:00:01,000 --> 00:00:02,000\nhi", "srt", "").is_err());
+    }
+}
@@ -0,0 +1,563 @@
+// Follows the approach of llama.cpp's `json_schema_to_grammar`, limited to the
+// subset of JSON Schema that structured-output prompts actually use:
+// `type`, `properties`/`required`, `items`/`minItems`/`maxItems`,
+// `minLength`/`maxLength`, `enum`, `const`, `anyOf`/`oneOf` and local `$ref`s.
+// Anything else falls back to an arbitrary JSON value.
+
+use std::collections::HashMap;
+
+use serde_json::Value;
+
+const SPACE_RULE: &str = r#"| " " | "\n" [ \t]{0,20}"#;
+
+const PRIMITIVE_RULES: &[(&str, &str, &[&str])] = &[
+    ("boolean", r#"("true" | "false") space"#, &[]),
+    ("null", r#""null" space"#, &[]),
+    ("integral-part", r#"[0] | [1-9] [0-9]{0,15}"#, &[]),
+    ("decimal-part", r#"[0-9]{1,16}"#, &[]),
+    (
+        "integer",
+        r#"("-"? integral-part) space"#,
+        &["integral-part"],
+    ),
+    (
+        "number",
+        r#"("-"? integral-part) ("." decimal-part)? ([eE] [-+]? integral-part)? space"#,
+        &["integral-part", "decimal-part"],
+    ),
+    (
+        "char",
+        r#"[^"\\\x7F\x00-\x1F] | [\\] (["\\bfnrt] | "u" [0-9a-fA-F]{4})"#,
+        &[],
+    ),
+    ("string", r#""\"" char* "\"" space"#, &["char"]),
+    (
+        "object",
+        r#""{" space ( string ":" space value ("," space string ":" space value)* )? "}" space"#,
+        &["string", "value"],
+    ),
+    (
+        "array",
+        r#""[" space ( value ("," space value)* )? "]" space"#,
+        &["value"],
+    ),
+    (
+        "value",
+        r#"object | array | string | number | boolean | null"#,
+        &["object", "array", "string", "number", "boolean", "null"],
+    ),
+];
+
+pub fn build_json_schema_grammar(schema: &Value) -> String {
+    let mut converter = Converter::new(schema);
+    let root = converter.visit(schema, "root");
+    if root != "root" {
+        converter.add_rule("root", &root);
+    }
+    converter.render()
+}
+
+struct Converter<'a> {
+    root_schema: &'a Value,
+    rules: Vec<(String, String)>,
+    refs: HashMap<String, String>,
+}
+
+impl<'a> Converter<'a> {
+    fn new(root_schema: &'a Value) -> Self {
+        Self {
+            root_schema,
+            rules: vec![("space".to_string(), SPACE_RULE.to_string())],
+            refs: HashMap::new(),
+        }
+    }
+
+    fn render(&self) -> String {
+        let mut rules: Vec<_> = self.rules.iter().collect();
+        rules.sort_by_key(|(name, _)| name != "root");
+        rules
+            .into_iter()
+            .map(|(name, body)| format!("{name} ::= {body}"))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn has_rule(&self, name: &str) -> bool {
+        self.rules.iter().any(|(n, _)| n == name)
+    }
+
+    /// Registers `body` under `name`, or under a numbered variant if `name`
+    /// is already taken by a different body. A name reserved by a `$ref`
+    /// takes the first body registered under it.
+    fn add_rule(&mut self, name: &str, body: &str) -> String {
+        let base = sanitize(name);
+        let mut candidate = base.clone();
+        let mut i = 0;
+
+        loop {
+            match self.rules.iter_mut().find(|(n, _)| *n == candidate) {
+                None => {
+                    self.rules.push((candidate.clone(), body.to_string()));
+                    return candidate;
+                }
+                Some((_, existing)) if existing.is_empty() || existing == body => {
+                    *existing = body.to_string();
+                    return candidate;
+                }
+                Some(_) => {
+                    i += 1;
+                    candidate = format!("{base}{i}");
+                }
+            }
+        }
+    }
+
+    fn reserve_rule(&mut self, name: &str) -> String {
+        let base = sanitize(name);
+        let mut candidate = base.clone();
+        let mut i = 0;
+        while self.has_rule(&candidate) {
+            i += 1;
+            candidate = format!("{base}{i}");
+        }
+        self.rules.push((candidate.clone(), String::new()));
+        candidate
+    }
+
+    fn set_rule(&mut self, name: &str, body: String) {
+        match self.rules.iter_mut().find(|(n, _)| n == name) {
+            Some((_, existing)) => *existing = body,
+            None => self.rules.push((name.to_string(), body)),
+        }
+    }
+
+    fn primitive(&mut self, name: &str) -> String {
+        if self.has_rule(name) {
+            return name.to_string();
+        }
+
+        let (_, body, deps) = PRIMITIVE_RULES
+            .iter()
+            .find(|(n, _, _)| *n == name)
+            .expect("known primitive");
+        self.rules.push((name.to_string(), body.to_string()));
+        for dep in *deps {
+            self.primitive(dep);
+        }
+        name.to_string()
+    }
+
+    fn visit(&mut self, schema: &Value, name: &str) -> String {
+        let Some(obj) = schema.as_object() else {
+            // `true` and `{}` accept anything.
+            return self.primitive("value");
+        };
+
+        if let Some(reference) = obj.get("$ref").and_then(Value::as_str) {
+            return self.visit_ref(reference);
+        }
+
+        if let Some(value) = obj.get("const") {
+            let body = format!("{} space", literal(value));
+            return self.add_rule(name, &body);
+        }
+
+        if let Some(values) = obj.get("enum").and_then(Value::as_array) {
+            let alts: Vec<_> = values.iter().map(literal).collect();
+            let body = format!("({}) space", alts.join(" | "));
+            return self.add_rule(name, &body);
+        }
+
+        if let Some(variants) = obj
+            .get("anyOf")
+            .or_else(|| obj.get("oneOf"))
+            .and_then(Value::as_array)
+        {
+            return self.visit_alternatives(variants, name);
+        }
+
+        match obj.get("type") {
+            Some(Value::Array(types)) => {
+                let variants: Vec<Value> = types
+                    .iter()
+                    .map(|t| {
+                        let mut variant = schema.clone();
+                        variant["type"] = t.clone();
+                        variant
+                    })
+                    .collect();
+                self.visit_alternatives(&variants, name)
+            }
+            Some(Value::String(t)) => match t.as_str() {
+                "object" => self.visit_object(obj, name),
+                "array" => self.visit_array(obj, name),
+                "string" => self.visit_string(obj, name),
+                "integer" | "number" | "boolean" | "null" => self.primitive(t),
+                _ => self.primitive("value"),
+            },
+            _ if obj.contains_key("properties") => self.visit_object(obj, name),
+            _ => self.primitive("value"),
+        }
+    }
+
+    fn visit_alternatives(&mut self, variants: &[Value], name: &str) -> String {
+        let alts: Vec<_> = variants
+            .iter()
+            .enumerate()
+            .map(|(i, v)| self.visit(v, &format!("{name}-{i}")))
+            .collect();
+        self.add_rule(name, &alts.join(" | "))
+    }
+
+    fn visit_ref(&mut self, reference: &str) -> String {
+        if let Some(rule) = self.refs.get(reference) {
+            return rule.clone();
+        }
+
+        let Some(target) = reference
+            .strip_prefix('#')
+            .and_then(|pointer| self.root_schema.pointer(pointer))
+        else {
+            return self.primitive("value");
+        };
+
+        let name = reference.rsplit('/').next().unwrap_or("ref");
+        // Reserve the name first so recursive references resolve to it.
+        let rule = self.reserve_rule(name);
+        self.refs.insert(reference.to_string(), rule.clone());
+
+        let body = self.visit(target, &rule);
+        if body != rule {
+            self.set_rule(&rule, body);
+        }
+        rule
+    }
+
+    fn visit_object(&mut self, obj: &serde_json::Map<String, Value>, name: &str) -> String {
+        let empty = serde_json::Map::new();
+        let properties = obj
+            .get("properties")
+            .and_then(Value::as_object)
+            .unwrap_or(&empty);
+        let required: Vec<&str> = obj
+            .get("required")
+            .and_then(Value::as_array)
+            .map(|r| r.iter().filter_map(Value::as_str).collect())
+            .unwrap_or_default();
+
+        if properties.is_empty() {
+            return self.primitive("object");
+        }
+
+        // Required properties keep the order of `required`.
+        let ordered = required
+            .iter()
+            .filter_map(|key| properties.get_key_value(*key))
+            .chain(
+                properties
+                    .iter()
+                    .filter(|(key, _)| !required.contains(&key.as_str())),
+            );
+
+        let mut required_kvs = vec![];
+        let mut optional_kvs = vec![];
+        for (key, prop_schema) in ordered {
+            let prop_name = format!("{name}-{key}");
+            let value_rule = self.visit(prop_schema, &prop_name);
+            let kv = self.add_rule(
+                &format!("{prop_name}-kv"),
+                &format!(
+                    "{} space \":\" space {value_rule}",
+                    literal(&Value::from(key.as_str()))
+                ),
+            );
+            if required.contains(&key.as_str()) {
+                required_kvs.push(kv);
+            } else {
+                optional_kvs.push(kv);
+            }
+        }
+
+        let mut body = String::from("\"{\" space ");
+        body.push_str(&required_kvs.join(" \",\" space "));
+
+        if required_kvs.is_empty() {
+            if !optional_kvs.is_empty() {
+                let alts: Vec<_> = (0..optional_kvs.len())
+                    .map(|i| {
+                        std::iter::once(optional_kvs[i].clone())
+                            .chain(
+                                optional_kvs[i + 1..]
+                                    .iter()
+                                    .map(|kv| format!("(\",\" space {kv})?")),
+                            )
+                            .collect::<Vec<_>>()
+                            .join(" ")
+                    })
+                    .collect();
+                body.push_str(&format!("({})?", alts.join(" | ")));
+            }
+        } else {
+            for kv in &optional_kvs {
+                body.push_str(&format!(" (\",\" space {kv})?"));
+            }
+        }
+
+        body.push_str(" \"}\" space");
+        self.add_rule(name, &body)
+    }
+
+    fn visit_array(&mut self, obj: &serde_json::Map<String, Value>, name: &str) -> String {
+        let item = match obj.get("items") {
+            Some(items) => self.visit(items, &format!("{name}-item")),
+            None => self.primitive("value"),
+        };
+        let min = obj.get("minItems").and_then(Value::as_u64).unwrap_or(0);
+        let max = obj.get("maxItems").and_then(Value::as_u64);
+
+        let items = match (min, max) {
+            (_, Some(0)) => String::new(),
+            (0, None) => format!("({item} (\",\" space {item})*)?"),
+            (0, Some(max)) => format!("({item} (\",\" space {item}){{0,{}}})?", max - 1),
+            (min, None) => format!("{item} (\",\" space {item}){{{},}}", min - 1),
+            (min, Some(max)) => {
+                format!("{item} (\",\" space {item}){{{},{}}}", min - 1, max - 1)
+            }
+        };
+
+        self.add_rule(name, &format!("\"[\" space {items} \"]\" space"))
+    }
+
+    fn visit_string(&mut self, obj: &serde_json::Map<String, Value>, name: &str) -> String {
+        let min = obj.get("minLength").and_then(Value::as_u64);
+        let max = obj.get("maxLength").and_then(Value::as_u64);
+        if min.is_none() && max.is_none() {
+            return self.primitive("string");
+        }
+
+        let char_rule = self.primitive("char");
+        let repeat = match (min.unwrap_or(0), max) {
+            (min, Some(max)) => format!("{{{min},{max}}}"),
+            (min, None) => format!("{{{min},}}"),
+        };
+        self.add_rule(
+            name,
+            &format!("\"\\\"\" {char_rule}{repeat} \"\\\"\" space"),
+        )
+    }
+}
+
+/// GBNF literal matching the JSON encoding of `value`.
+fn literal(value: &Value) -> String {
+    let json = value.to_string();
+    let mut out = String::with_capacity(json.len() + 2);
+    out.push('"');
+    for c in json.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+fn sanitize(name: &str) -> String {
+    let sanitized: String = name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+        .collect();
+    if sanitized.is_empty() {
+        "rule".to_string()
+    } else {
+        sanitized
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn rule<'g>(grammar: &'g str, name: &str) -> &'g str {
+        let prefix = format!("{name} ::= ");
+        grammar
+            .lines()
+            .find_map(|l| l.strip_prefix(&prefix))
+            .unwrap_or_else(|| panic!("missing rule {name} in:\n{grammar}"))
+    }
+
+    #[test]
+    fn object_with_required_and_optional_properties() {
+        let grammar = build_json_schema_grammar(&json!({
+            "type": "object",
+            "properties": {
+                "title": {"type": "string", "maxLength": 80},
+                "tags": {"type": "array", "items": {"type": "string"}, "maxItems": 4},
+                "score": {"type": "number"}
+            },
+            "required": ["title", "tags"]
+        }));
+
+        assert!(grammar.starts_with("root ::= "));
+        assert_eq!(
+            rule(&grammar, "root"),
+            r#""{" space root-title-kv "," space root-tags-kv ("," space root-score-kv)? "}" space"#
+        );
+        assert_eq!(
+            rule(&grammar, "root-title-kv"),
+            r#""\"title\"" space ":" space root-title"#
+        );
+        assert_eq!(
+            rule(&grammar, "root-title"),
+            r#""\"" char{0,80} "\"" space"#
+        );
+        assert_eq!(
+            rule(&grammar, "root-tags"),
+            r#""[" space (string ("," space string){0,3})? "]" space"#
+        );
+        assert_eq!(
+            rule(&grammar, "root-score-kv"),
+            r#""\"score\"" space ":" space number"#
+        );
+        rule(&grammar, "integral-part");
+    }
+
+    #[test]
+    fn only_optional_properties() {
+        let grammar = build_json_schema_grammar(&json!({
+            "properties": {"a": {"type": "boolean"}, "b": {"type": "null"}}
+        }));
+
+        assert_eq!(
+            rule(&grammar, "root"),
+            r#""{" space (root-a-kv ("," space root-b-kv)? | root-b-kv)? "}" space"#
+        );
+    }
+
+    #[test]
+    fn enums_unions_and_refs() {
+        let grammar = build_json_schema_grammar(&json!({
+            "type": "object",
+            "properties": {
+                "kind": {"enum": ["todo", "decision"]},
+                "owner": {"type": ["string", "null"]},
+                "children": {"type": "array", "items": {"$ref": "#/$defs/node"}}
+            },
+            "required": ["kind", "owner", "children"],
+            "$defs": {
+                "node": {
+                    "type": "object",
+                    "properties": {"children": {"type": "array", "items": {"$ref": "#/$defs/node"}}},
+                    "required": ["children"]
+                }
+            }
+        }));
+
+        assert_eq!(
+            rule(&grammar, "root-kind"),
+            r#"("\"todo\"" | "\"decision\"") space"#
+        );
+        assert_eq!(rule(&grammar, "root-owner"), "string | null");
+        assert_eq!(
+            rule(&grammar, "root-children"),
+            r#""[" space (node ("," space node)*)? "]" space"#
+        );
+        assert_eq!(
+            rule(&grammar, "node"),
+            r#""{" space node-children-kv "}" space"#
+        );
+    }
+
+    #[test]
+    #[ignore]
+    fn test_object_grammar_validates() {
+        let gbnf = gbnf_validator::Validator::new().unwrap();
+        let grammar = build_json_schema_grammar(&json!({
+            "type": "object",
+            "properties": {
+                "title": {"type": "string", "maxLength": 20},
+                "tags": {"type": "array", "items": {"type": "string"}, "maxItems": 2},
+                "score": {"type": "number"}
+            },
+            "required": ["title", "tags"]
+        }));
+
+        for (input, expected) in vec![
+            (r#"{"title": "Weekly sync", "tags": ["eng"]}"#, true),
+            (
+                r#"{"title": "Weekly sync", "tags": [], "score": -0.5}"#,
+                true,
+            ),
+            (r#"{"title": "Weekly \"sync\"", "tags": ["a", "b"]}"#, true),
+            (r#"{"tags": ["eng"]}"#, false),
+            (r#"{"title": 1, "tags": []}"#, false),
+            (
+                r#"{"title": "Weekly sync", "tags": ["a", "b", "c"]}"#,
+                false,
+            ),
+            (
+                r#"{"title": "A title well over twenty chars", "tags": []}"#,
+                false,
+            ),
+            (
+                r#"{"title": "Weekly sync", "tags": [], "extra": true}"#,
+                false,
+            ),
+        ] {
+            let result = gbnf.validate(&grammar, input).unwrap();
+            assert_eq!(result, expected, "failed: {}", input);
+        }
+    }
+
+    #[test]
+    #[ignore]
+    fn test_enums_unions_and_refs_grammar_validates() {
+        let gbnf = gbnf_validator::Validator::new().unwrap();
+        let grammar = build_json_schema_grammar(&json!({
+            "type": "object",
+            "properties": {
+                "kind": {"enum": ["todo", "decision"]},
+                "owner": {"type": ["string", "null"]},
+                "children": {"type": "array", "items": {"$ref": "#/$defs/node"}}
+            },
+            "required": ["kind", "owner", "children"],
+            "$defs": {
+                "node": {
+                    "type": "object",
+                    "properties": {"children": {"type": "array", "items": {"$ref": "#/$defs/node"}}},
+                    "required": ["children"]
+                }
+            }
+        }));
+
+        for (input, expected) in vec![
+            (r#"{"kind": "todo", "owner": "Ana", "children": []}"#, true),
+            (
+                r#"{"kind": "decision", "owner": null, "children": [{"children": [{"children": []}]}]}"#,
+                true,
+            ),
+            (r#"{"kind": "note", "owner": null, "children": []}"#, false),
+            (r#"{"kind": "todo", "owner": 3, "children": []}"#, false),
+            (
+                r#"{"kind": "todo", "owner": null, "children": [{}]}"#,
+                false,
+            ),
+        ] {
+            let result = gbnf.validate(&grammar, input).unwrap();
+            assert_eq!(result, expected, "failed: {}", input);
+        }
+    }
+
+    #[test]
+    fn unsupported_schema_falls_back_to_any_value() {
+        let grammar = build_json_schema_grammar(&json!({"allOf": [{"type": "string"}]}));
+        assert_eq!(rule(&grammar, "root"), "value");
+        rule(&grammar, "object");
+        rule(&grammar, "array");
+    }
+}
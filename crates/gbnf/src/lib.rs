@@ -1,5 +1,9 @@
 // https://github.com/ggml-org/llama.cpp/blob/master/grammars/README.md
 
+mod json_schema;
+
+pub use json_schema::build_json_schema_grammar;
+
 #[derive(specta::Type, serde::Serialize, serde::Deserialize)]
 #[serde(tag = "task")]
 pub enum Grammar {
@@ -11,6 +15,11 @@ pub enum Grammar {
     Tags,
     #[serde(rename = "email-to-name")]
     EmailToName,
+    #[serde(rename = "json-schema")]
+    JsonSchema { schema: serde_json::Value },
+    /// A GBNF grammar passed through as-is.
+    #[serde(rename = "raw")]
+    Raw { grammar: String },
 }
 
 impl Grammar {
@@ -20,6 +29,8 @@ impl Grammar {
             Grammar::Title => build_title_grammar(),
             Grammar::Tags => build_tags_grammar(),
             Grammar::EmailToName => build_email_to_name_grammar(),
+            Grammar::JsonSchema { schema } => build_json_schema_grammar(schema),
+            Grammar::Raw { grammar } => grammar.clone(),
         }
     }
 }
@@ -2,8 +2,9 @@ use hypr_http::HttpClient;
 
 use crate::error::Error;
 use crate::types::{
-    CreateEventRequest, Event, EventType, ListCalendarsResponse, ListEventsRequest,
-    ListEventsResponse,
+    Attendee, CreateEventRequest, DeleteEventRequest, Event, EventType, GetEventRequest,
+    ListCalendarsResponse, ListEventsRequest, ListEventsResponse, RespondToEventRequest,
+    SendUpdates, UpdateEventBody, UpdateEventRequest,
 };
 
 pub struct GoogleCalendarClient<C> {
@@ -104,7 +105,13 @@ impl<C: HttpClient> GoogleCalendarClient<C> {
             format!("{}?{}", path, query_parts.join("&"))
         };
 
-        let bytes = self.http.get(&full_path).await.map_err(Error::Http)?;
+        let bytes = self.http.get(&full_path).await.map_err(|e| {
+            if req.sync_token.is_some() && hypr_http::status_of(&e) == Some(410) {
+                Error::SyncTokenExpired
+            } else {
+                Error::Http(e)
+            }
+        })?;
         let response: ListEventsResponse = serde_json::from_slice(&bytes)?;
         Ok(response)
     }
@@ -122,4 +129,263 @@ impl<C: HttpClient> GoogleCalendarClient<C> {
         let event: Event = serde_json::from_slice(&bytes)?;
         Ok(event)
     }
+
+    pub async fn get_event(&self, req: GetEventRequest) -> Result<Event, Error> {
+        let calendar_id = &req.calendar_id;
+        let event_id = &req.event_id;
+        let path = format!("/calendar/v3/calendars/{calendar_id}/events/{event_id}");
+
+        let bytes = self.http.get(&path).await.map_err(Error::Http)?;
+        let event: Event = serde_json::from_slice(&bytes)?;
+        Ok(event)
+    }
+
+    pub async fn update_event(&self, req: UpdateEventRequest) -> Result<Event, Error> {
+        let calendar_id = &req.calendar_id;
+        let event_id = &req.event_id;
+        let path = with_send_updates(
+            format!("/calendar/v3/calendars/{calendar_id}/events/{event_id}"),
+            req.send_updates.as_ref(),
+        );
+
+        let body = serde_json::to_vec(&req.event)?;
+        let bytes = self.http.patch(&path, body).await.map_err(Error::Http)?;
+        let event: Event = serde_json::from_slice(&bytes)?;
+        Ok(event)
+    }
+
+    pub async fn delete_event(&self, req: DeleteEventRequest) -> Result<(), Error> {
+        let calendar_id = &req.calendar_id;
+        let event_id = &req.event_id;
+        let path = with_send_updates(
+            format!("/calendar/v3/calendars/{calendar_id}/events/{event_id}"),
+            req.send_updates.as_ref(),
+        );
+
+        self.http.delete(&path).await.map_err(Error::Http)?;
+        Ok(())
+    }
+
+    /// Sets the user's own response. Google has no dedicated endpoint, so
+    /// the event is fetched and patched with its attendee list, in which
+    /// only the user's entry changed. The patch is conditional on the
+    /// fetched ETag so concurrent attendee changes aren't overwritten; if
+    /// the event changed in between, it is fetched again.
+    pub async fn respond_to_event(&self, req: RespondToEventRequest) -> Result<Event, Error> {
+        let calendar_id = &req.calendar_id;
+        let event_id = &req.event_id;
+        let path = with_send_updates(
+            format!("/calendar/v3/calendars/{calendar_id}/events/{event_id}"),
+            req.send_updates.as_ref(),
+        );
+
+        let mut attempt = 1;
+        loop {
+            let event = self
+                .get_event(GetEventRequest {
+                    calendar_id: calendar_id.clone(),
+                    event_id: event_id.clone(),
+                })
+                .await?;
+
+            let attendees = with_self_response(event.attendees.unwrap_or_default(), &req)
+                .ok_or(Error::NotAnAttendee)?;
+            let body = serde_json::to_vec(&UpdateEventBody {
+                attendees: Some(attendees),
+                ..Default::default()
+            })?;
+
+            let result = match &event.etag {
+                Some(etag) => self.http.patch_if_match(&path, body, etag).await,
+                None => self.http.patch(&path, body).await,
+            };
+            match result {
+                Ok(bytes) => return Ok(serde_json::from_slice(&bytes)?),
+                Err(e) if hypr_http::status_of(&e) == Some(412) && attempt < RESPOND_ATTEMPTS => {
+                    attempt += 1;
+                }
+                Err(e) => return Err(Error::Http(e)),
+            }
+        }
+    }
+}
+
+/// How often an RSVP is retried when the event keeps changing under it.
+const RESPOND_ATTEMPTS: usize = 3;
+
+fn with_send_updates(path: String, send_updates: Option<&SendUpdates>) -> String {
+    let value = match send_updates {
+        Some(SendUpdates::All) => "all",
+        Some(SendUpdates::ExternalOnly) => "externalOnly",
+        Some(SendUpdates::None) => "none",
+        None => return path,
+    };
+    format!("{path}?sendUpdates={value}")
+}
+
+/// `attendees` with the user's response replaced, or `None` if the user
+/// isn't one of them.
+fn with_self_response(
+    mut attendees: Vec<Attendee>,
+    req: &RespondToEventRequest,
+) -> Option<Vec<Attendee>> {
+    let attendee = attendees.iter_mut().find(|a| a.is_self == Some(true))?;
+    attendee.response_status = Some(req.response.clone());
+    attendee.comment = req.comment.clone();
+    Some(attendees)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::AttendeeResponseStatus;
+
+    fn request(response: AttendeeResponseStatus) -> RespondToEventRequest {
+        RespondToEventRequest {
+            calendar_id: "primary".into(),
+            event_id: "4kq7c1".into(),
+            response,
+            comment: Some("Running late".into()),
+            send_updates: None,
+        }
+    }
+
+    #[test]
+    fn test_with_self_response() {
+        let attendees: Vec<Attendee> = serde_json::from_value(serde_json::json!([
+            { "email": "lead@acme.com", "organizer": true, "responseStatus": "accepted" },
+            { "email": "me@example.com", "self": true, "responseStatus": "needsAction" }
+        ]))
+        .unwrap();
+
+        let updated = with_self_response(
+            attendees.clone(),
+            &request(AttendeeResponseStatus::Tentative),
+        )
+        .unwrap();
+        assert!(matches!(
+            updated[0].response_status,
+            Some(AttendeeResponseStatus::Accepted)
+        ));
+        assert!(matches!(
+            updated[1].response_status,
+            Some(AttendeeResponseStatus::Tentative)
+        ));
+        assert_eq!(updated[1].comment.as_deref(), Some("Running late"));
+
+        assert!(
+            with_self_response(
+                attendees[..1].to_vec(),
+                &request(AttendeeResponseStatus::Accepted)
+            )
+            .is_none()
+        );
+    }
+
+    /// Serves one event and records the `If-Match` of each patch. The
+    /// first `stale` conditional patches fail as if the event had changed.
+    struct FakeHttp {
+        event: serde_json::Value,
+        stale: std::sync::Mutex<usize>,
+        patches: std::sync::Mutex<Vec<Option<String>>>,
+    }
+
+    impl FakeHttp {
+        fn new(stale: usize) -> Self {
+            Self {
+                event: serde_json::json!({
+                    "id": "4kq7c1",
+                    "etag": "\"3181159875584000\"",
+                    "attendees": [
+                        { "email": "lead@acme.com", "organizer": true, "responseStatus": "accepted" },
+                        { "email": "me@example.com", "self": true, "responseStatus": "needsAction" }
+                    ]
+                }),
+                stale: std::sync::Mutex::new(stale),
+                patches: std::sync::Mutex::default(),
+            }
+        }
+    }
+
+    impl HttpClient for FakeHttp {
+        async fn get(&self, _path: &str) -> Result<Vec<u8>, hypr_http::Error> {
+            Ok(serde_json::to_vec(&self.event)?)
+        }
+
+        async fn post(
+            &self,
+            _path: &str,
+            _body: Vec<u8>,
+            _content_type: &str,
+        ) -> Result<Vec<u8>, hypr_http::Error> {
+            unimplemented!()
+        }
+
+        async fn put(&self, _path: &str, _body: Vec<u8>) -> Result<Vec<u8>, hypr_http::Error> {
+            unimplemented!()
+        }
+
+        async fn patch(&self, _path: &str, _body: Vec<u8>) -> Result<Vec<u8>, hypr_http::Error> {
+            self.patches.lock().unwrap().push(None);
+            Ok(serde_json::to_vec(&self.event)?)
+        }
+
+        async fn patch_if_match(
+            &self,
+            _path: &str,
+            _body: Vec<u8>,
+            etag: &str,
+        ) -> Result<Vec<u8>, hypr_http::Error> {
+            self.patches.lock().unwrap().push(Some(etag.to_string()));
+            let mut stale = self.stale.lock().unwrap();
+            if *stale > 0 {
+                *stale -= 1;
+                return Err(Box::new(hypr_http::StatusError {
+                    status: 412,
+                    body: "conditionNotMet".into(),
+                }));
+            }
+            Ok(serde_json::to_vec(&self.event)?)
+        }
+
+        async fn delete(&self, _path: &str) -> Result<Vec<u8>, hypr_http::Error> {
+            unimplemented!()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_respond_sends_etag() {
+        let client = GoogleCalendarClient::new(FakeHttp::new(1));
+        client
+            .respond_to_event(request(AttendeeResponseStatus::Accepted))
+            .await
+            .unwrap();
+
+        let etag = Some("\"3181159875584000\"".to_string());
+        assert_eq!(
+            *client.http.patches.lock().unwrap(),
+            vec![etag.clone(), etag]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_respond_gives_up_on_changing_event() {
+        let client = GoogleCalendarClient::new(FakeHttp::new(RESPOND_ATTEMPTS));
+        let err = client
+            .respond_to_event(request(AttendeeResponseStatus::Accepted))
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, Error::Http(e) if hypr_http::status_of(&e) == Some(412)));
+        assert_eq!(client.http.patches.lock().unwrap().len(), RESPOND_ATTEMPTS);
+    }
+
+    #[test]
+    fn test_with_send_updates() {
+        assert_eq!(
+            with_send_updates("/events/a".into(), Some(&SendUpdates::ExternalOnly)),
+            "/events/a?sendUpdates=externalOnly"
+        );
+        assert_eq!(with_send_updates("/events/a".into(), None), "/events/a");
+    }
 }
@@ -0,0 +1,181 @@
+use chrono::{DateTime, Utc};
+use hypr_calendar_interface as interface;
+
+use crate::types::{
+    Attendee, AttendeeResponseStatus, EntryPointType, Event, EventDateTime, EventStatus,
+};
+
+impl Event {
+    /// Normalizes the event. Cancelled occurrences reported by incremental
+    /// sync only carry their original start, which is used for both ends.
+    pub fn to_calendar_event(&self, calendar_id: &str) -> Option<interface::CalendarEvent> {
+        let original_start = self.original_start_time.as_ref().and_then(to_utc);
+        let (start, is_all_day) = match self.start.as_ref().and_then(to_utc) {
+            Some(start) => start,
+            None => original_start?,
+        };
+        let end = self
+            .end
+            .as_ref()
+            .and_then(to_utc)
+            .map_or(start, |(end, _)| end);
+
+        let attendees: Vec<interface::Attendee> =
+            self.attendees.iter().flatten().map(to_attendee).collect();
+        let organizer = self.organizer.as_ref().map(|o| interface::Attendee {
+            email: o.email.clone(),
+            name: o.display_name.clone(),
+            response: interface::ResponseStatus::Accepted,
+            is_self: o.is_self.unwrap_or(false),
+            is_organizer: true,
+            optional: false,
+        });
+        let self_response = attendees
+            .iter()
+            .find(|a| a.is_self)
+            .map(|a| a.response.clone())
+            .or_else(|| {
+                // Events without attendees only live on the owner's calendar.
+                (attendees.is_empty() || organizer.as_ref().is_some_and(|o| o.is_self))
+                    .then_some(interface::ResponseStatus::Accepted)
+            });
+
+        let meeting_url = self
+            .hangout_link
+            .clone()
+            .or_else(|| {
+                self.conference_data
+                    .as_ref()?
+                    .entry_points
+                    .iter()
+                    .flatten()
+                    .find(|e| matches!(e.entry_point_type, EntryPointType::Video))
+                    .map(|e| e.uri.clone())
+            })
+            .or_else(|| {
+                [self.location.as_deref(), self.description.as_deref()]
+                    .into_iter()
+                    .flatten()
+                    .find_map(hypr_meeting_link::parse_conference_link)
+            });
+
+        Some(interface::CalendarEvent {
+            id: self.id.clone(),
+            calendar_id: calendar_id.to_string(),
+            provider: interface::CalendarProvider::Google,
+            ical_uid: self.ical_uid.clone(),
+            series_id: self.recurring_event_id.clone(),
+            original_start: original_start.map(|(start, _)| start),
+            recurrence: self.recurrence.clone().unwrap_or_default(),
+            title: self.summary.clone().unwrap_or_default(),
+            description: self.description.clone(),
+            location: self.location.clone(),
+            meeting_url,
+            start,
+            end,
+            is_all_day,
+            time_zone: self.start.as_ref().and_then(|s| s.time_zone.clone()),
+            status: match self.status {
+                Some(EventStatus::Cancelled) => interface::EventStatus::Cancelled,
+                Some(EventStatus::Tentative) => interface::EventStatus::Tentative,
+                _ => interface::EventStatus::Confirmed,
+            },
+            organizer,
+            attendees,
+            self_response,
+            updated_at: self.updated,
+        })
+    }
+}
+
+/// Start of the day in UTC for all-day (date-only) times.
+fn to_utc(time: &EventDateTime) -> Option<(DateTime<Utc>, bool)> {
+    match (time.date_time, time.date) {
+        (Some(date_time), _) => Some((date_time.with_timezone(&Utc), false)),
+        (None, Some(date)) => Some((date.and_hms_opt(0, 0, 0)?.and_utc(), true)),
+        (None, None) => None,
+    }
+}
+
+fn to_attendee(attendee: &Attendee) -> interface::Attendee {
+    interface::Attendee {
+        email: attendee.email.clone(),
+        name: attendee.display_name.clone(),
+        response: match attendee.response_status {
+            Some(AttendeeResponseStatus::Accepted) => interface::ResponseStatus::Accepted,
+            Some(AttendeeResponseStatus::Tentative) => interface::ResponseStatus::Tentative,
+            Some(AttendeeResponseStatus::Declined) => interface::ResponseStatus::Declined,
+            _ => interface::ResponseStatus::NeedsAction,
+        },
+        is_self: attendee.is_self.unwrap_or(false),
+        is_organizer: attendee.organizer.unwrap_or(false),
+        optional: attendee.optional.unwrap_or(false),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_series_and_cancelled_occurrence() {
+        let series: Event = serde_json::from_value(serde_json::json!({
+            "id": "4kq7c1",
+            "status": "confirmed",
+            "summary": "Weekly sync",
+            "iCalUID": "4kq7c1@google.com",
+            "recurrence": ["RRULE:FREQ=WEEKLY;BYDAY=WE"],
+            "start": { "dateTime": "2026-03-04T16:00:00+01:00", "timeZone": "Europe/Berlin" },
+            "end": { "dateTime": "2026-03-04T16:30:00+01:00", "timeZone": "Europe/Berlin" },
+            "organizer": { "email": "lead@acme.com" },
+            "attendees": [
+                { "email": "lead@acme.com", "organizer": true, "responseStatus": "accepted" },
+                { "email": "me@example.com", "self": true, "responseStatus": "tentative" }
+            ],
+            "location": "https://meet.google.com/abc-defg-hij"
+        }))
+        .unwrap();
+
+        let event = series.to_calendar_event("primary").unwrap();
+        assert!(event.is_series());
+        assert_eq!(event.start.to_rfc3339(), "2026-03-04T15:00:00+00:00");
+        assert_eq!(event.time_zone.as_deref(), Some("Europe/Berlin"));
+        assert_eq!(
+            event.self_response,
+            Some(interface::ResponseStatus::Tentative)
+        );
+        assert_eq!(
+            event.meeting_url.as_deref(),
+            Some("https://meet.google.com/abc-defg-hij")
+        );
+
+        let cancelled: Event = serde_json::from_value(serde_json::json!({
+            "id": "4kq7c1_20260311T150000Z",
+            "status": "cancelled",
+            "recurringEventId": "4kq7c1",
+            "originalStartTime": { "dateTime": "2026-03-11T16:00:00+01:00" }
+        }))
+        .unwrap();
+
+        let event = cancelled.to_calendar_event("primary").unwrap();
+        assert!(event.is_cancelled());
+        assert_eq!(event.series_id.as_deref(), Some("4kq7c1"));
+        assert_eq!(event.start, event.end);
+        assert_eq!(event.original_start, Some(event.start));
+    }
+
+    #[test]
+    fn test_all_day() {
+        let event: Event = serde_json::from_value(serde_json::json!({
+            "id": "offsite",
+            "start": { "date": "2026-03-04" },
+            "end": { "date": "2026-03-05" }
+        }))
+        .unwrap();
+
+        let event = event.to_calendar_event("primary").unwrap();
+        assert!(event.is_all_day);
+        assert_eq!(event.start.to_rfc3339(), "2026-03-04T00:00:00+00:00");
+        assert_eq!(event.end.to_rfc3339(), "2026-03-05T00:00:00+00:00");
+    }
+}
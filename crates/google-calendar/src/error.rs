@@ -5,6 +5,14 @@ pub enum Error {
     #[error("HTTP client error: {0}")]
     Http(Box<dyn std::error::Error + Send + Sync>),
 
+    /// The sync token was invalidated by the server; sync again from scratch.
+    #[error("sync token expired")]
+    SyncTokenExpired,
+
+    /// RSVPs can only be sent for events the user was invited to.
+    #[error("not an attendee of the event")]
+    NotAnAttendee,
+
     #[error("JSON error: {0}")]
     Json(#[from] serde_json::Error),
 }
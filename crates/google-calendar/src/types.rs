@@ -79,6 +79,14 @@ pub enum EventOrderBy {
     Updated,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SendUpdates {
+    All,
+    ExternalOnly,
+    None,
+}
+
 // === Typed enums replacing free-form strings ===
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -742,3 +750,56 @@ pub struct CreateEventBody {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub event_type: Option<EventType>,
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetEventRequest {
+    pub calendar_id: String,
+    pub event_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateEventRequest {
+    pub calendar_id: String,
+    pub event_id: String,
+    pub event: UpdateEventBody,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub send_updates: Option<SendUpdates>,
+}
+
+/// A patch: fields left as `None` keep their current value. `attendees`
+/// replaces the whole list.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateEventBody {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start: Option<EventDateTime>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end: Option<EventDateTime>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attendees: Option<Vec<Attendee>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeleteEventRequest {
+    pub calendar_id: String,
+    pub event_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub send_updates: Option<SendUpdates>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RespondToEventRequest {
+    pub calendar_id: String,
+    pub event_id: String,
+    pub response: AttendeeResponseStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub send_updates: Option<SendUpdates>,
+}
@@ -133,7 +133,7 @@ pub struct ProseMirrorNode {
     pub attrs: Option<serde_json::Map<String, Value>>,
 }
 
-fn parse_maybe_stringified_json(value: &Value) -> Option<ProseMirrorDoc> {
+pub(crate) fn parse_maybe_stringified_json(value: &Value) -> Option<ProseMirrorDoc> {
     match value {
         Value::Null => None,
         Value::Object(_) => serde_json::from_value(value.clone()).ok(),
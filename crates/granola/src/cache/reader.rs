@@ -1,3 +1,4 @@
+use crate::api::{ProseMirrorDoc, parse_maybe_stringified_json};
 use crate::error::{Error, Result};
 use serde::Deserialize;
 use serde_json::Value;
@@ -15,12 +16,17 @@ pub struct TranscriptSegment {
     pub is_final: bool,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct CacheDocument {
     pub id: String,
     pub title: String,
     pub created_at: String,
     pub updated_at: String,
+    pub deleted_at: Option<String>,
+    pub tags: Vec<String>,
+    pub notes: Option<ProseMirrorDoc>,
+    pub notes_markdown: Option<String>,
+    pub notes_plain: Option<String>,
 }
 
 #[derive(Debug)]
@@ -78,6 +84,12 @@ fn parse_cache_document(id: &str, value: &Value) -> Option<CacheDocument> {
         title: Option<String>,
         created_at: Option<String>,
         updated_at: Option<String>,
+        deleted_at: Option<String>,
+        #[serde(default)]
+        tags: Option<Vec<Value>>,
+        notes: Option<Value>,
+        notes_markdown: Option<String>,
+        notes_plain: Option<String>,
     }
 
     let raw: RawDoc = serde_json::from_value(value.clone()).ok()?;
@@ -87,6 +99,16 @@ fn parse_cache_document(id: &str, value: &Value) -> Option<CacheDocument> {
         title: raw.title.unwrap_or_default(),
         created_at: raw.created_at.unwrap_or_default(),
         updated_at: raw.updated_at.unwrap_or_default(),
+        deleted_at: raw.deleted_at,
+        tags: raw
+            .tags
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|tag| tag.as_str().map(|s| s.to_string()))
+            .collect(),
+        notes: raw.notes.as_ref().and_then(parse_maybe_stringified_json),
+        notes_markdown: raw.notes_markdown,
+        notes_plain: raw.notes_plain,
     })
 }
 
@@ -29,6 +29,12 @@ pub enum Error {
     #[error("failed to parse cache JSON: {0}")]
     CacheJsonParse(#[source] serde_json::Error),
 
+    #[error("failed to read import state file: {0}")]
+    StateFileRead(#[source] std::io::Error),
+
+    #[error("failed to parse import state JSON: {0}")]
+    StateJsonParse(#[source] serde_json::Error),
+
     #[error("failed to create output directory: {0}")]
     CreateDirectory(std::io::Error),
 
@@ -67,8 +67,7 @@ pub fn write_transcripts(
             .unwrap_or_else(|| CacheDocument {
                 id: doc_id.clone(),
                 title: doc_id.clone(),
-                created_at: String::new(),
-                updated_at: String::new(),
+                ..Default::default()
             });
 
         let filename =
@@ -1,27 +1,109 @@
+mod state;
+
+pub use state::ImportState;
+
 use crate::api::{Document, GranolaClient};
 use crate::cache::{CacheData, CacheDocument, TranscriptSegment, read_cache};
 use crate::error::Result;
-use crate::prosemirror::convert_to_plain_text;
+use crate::prosemirror::{convert_to_markdown, convert_to_plain_text};
 use hypr_importer_core::ir::{Collection, Session, Tag, TagMapping, Transcript, Word};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::path::Path;
 use std::time::Duration;
 
+// The fields `build_collection` needs, shared by API and cache documents.
+struct NoteDocument {
+    id: String,
+    title: String,
+    created_at: String,
+    tags: Vec<String>,
+    content: String,
+}
+
 pub async fn import_all_from_path(path: &Path) -> Result<Collection> {
     let supabase_content = std::fs::read(path)?;
 
     let client = GranolaClient::new(&supabase_content, Duration::from_secs(30))?;
     let documents = client.get_documents().await?;
 
+    let notes: Vec<NoteDocument> = documents
+        .iter()
+        .map(|doc| NoteDocument {
+            id: doc.id.clone(),
+            title: doc.title.clone(),
+            created_at: doc.created_at.clone(),
+            tags: doc.tags.clone(),
+            content: get_document_content(doc),
+        })
+        .collect();
+
+    let cache_path = path
+        .parent()
+        .map(|p| p.join("cache"))
+        .unwrap_or_else(crate::cache::default_cache_path);
+    let cache_data = if cache_path.exists() {
+        Some(read_cache(&cache_path)?)
+    } else {
+        None
+    };
+
+    Ok(build_collection(notes, cache_data.as_ref()))
+}
+
+/// Builds the collection from the local cache file alone, without calling the
+/// Granola API.
+pub fn import_all_from_cache(cache_path: &Path) -> Result<Collection> {
+    let (collection, _) = import_changed_from_cache(cache_path, &ImportState::default())?;
+    Ok(collection)
+}
+
+/// Like [`import_all_from_cache`], but only documents that are new or whose
+/// `updated_at` changed since `state`. Returns the state to persist once the
+/// import has been applied.
+pub fn import_changed_from_cache(
+    cache_path: &Path,
+    state: &ImportState,
+) -> Result<(Collection, ImportState)> {
+    let cache_data = read_cache(cache_path)?;
+
+    let mut documents: Vec<&CacheDocument> = cache_data
+        .documents
+        .values()
+        .filter(|doc| doc.deleted_at.is_none())
+        .collect();
+    documents.sort_by(|a, b| (&a.created_at, &a.id).cmp(&(&b.created_at, &b.id)));
+
+    let mut next_state = state.clone();
+    let notes: Vec<NoteDocument> = documents
+        .into_iter()
+        .filter(|doc| next_state.record(&doc.id, &doc.updated_at))
+        .map(|doc| NoteDocument {
+            id: doc.id.clone(),
+            title: doc.title.clone(),
+            created_at: doc.created_at.clone(),
+            tags: doc.tags.clone(),
+            content: get_cache_document_content(doc),
+        })
+        .collect();
+
+    let mut collection = build_collection(notes, None);
+    let session_ids: HashSet<&str> = collection.sessions.iter().map(|s| s.id.as_str()).collect();
+    collection.transcripts = cache_data_to_transcripts(&cache_data)
+        .into_iter()
+        .filter(|t| session_ids.contains(t.session_id.as_str()))
+        .collect();
+
+    Ok((collection, next_state))
+}
+
+fn build_collection(notes: Vec<NoteDocument>, cache_data: Option<&CacheData>) -> Collection {
     let mut sessions = Vec::new();
     let mut tags: Vec<Tag> = Vec::new();
     let mut tag_mappings: Vec<TagMapping> = Vec::new();
     let mut tag_name_to_id: HashMap<String, String> = HashMap::new();
 
-    for doc in documents {
-        let session = document_to_session(&doc);
-
-        for tag_name in &doc.tags {
+    for note in notes {
+        for tag_name in &note.tags {
             let tag_id = tag_name_to_id
                 .entry(tag_name.to_string())
                 .or_insert_with(|| {
@@ -37,28 +119,30 @@ pub async fn import_all_from_path(path: &Path) -> Result<Collection> {
                 .clone();
 
             tag_mappings.push(TagMapping {
-                id: format!("{}_{}", tag_id, session.id),
+                id: format!("{}_{}", tag_id, note.id),
                 user_id: String::new(),
                 tag_id,
-                session_id: session.id.clone(),
+                session_id: note.id.clone(),
             });
         }
 
-        sessions.push(session);
+        sessions.push(Session {
+            id: note.id,
+            user_id: String::new(),
+            created_at: note.created_at,
+            title: note.title,
+            raw_md: Some(note.content),
+            enhanced_content: None,
+            folder_id: None,
+            event_id: None,
+        });
     }
 
-    let cache_path = path
-        .parent()
-        .map(|p| p.join("cache"))
-        .unwrap_or_else(crate::cache::default_cache_path);
-    let transcripts = if cache_path.exists() {
-        let cache_data = read_cache(&cache_path)?;
-        cache_data_to_transcripts(&cache_data)
-    } else {
-        vec![]
-    };
+    let transcripts = cache_data
+        .map(cache_data_to_transcripts)
+        .unwrap_or_default();
 
-    Ok(Collection {
+    Collection {
         sessions,
         transcripts,
         humans: vec![],
@@ -68,21 +152,6 @@ pub async fn import_all_from_path(path: &Path) -> Result<Collection> {
         enhanced_notes: vec![],
         tags,
         tag_mappings,
-    })
-}
-
-fn document_to_session(doc: &Document) -> Session {
-    let content = get_document_content(doc);
-
-    Session {
-        id: doc.id.clone(),
-        user_id: String::new(),
-        created_at: doc.created_at.clone(),
-        title: doc.title.clone(),
-        raw_md: Some(content),
-        enhanced_content: None,
-        folder_id: None,
-        event_id: None,
     }
 }
 
@@ -110,6 +179,23 @@ fn get_document_content(doc: &Document) -> String {
     doc.content.clone()
 }
 
+fn get_cache_document_content(doc: &CacheDocument) -> String {
+    if let Some(ref notes) = doc.notes {
+        let content = convert_to_markdown(notes).trim().to_string();
+        if !content.is_empty() {
+            return content;
+        }
+    }
+
+    [&doc.notes_markdown, &doc.notes_plain]
+        .into_iter()
+        .flatten()
+        .map(|s| s.trim())
+        .find(|s| !s.is_empty())
+        .unwrap_or_default()
+        .to_string()
+}
+
 fn cache_data_to_transcripts(cache_data: &CacheData) -> Vec<Transcript> {
     cache_data
         .transcripts
@@ -126,8 +212,7 @@ fn cache_data_to_transcripts(cache_data: &CacheData) -> Vec<Transcript> {
                 .unwrap_or_else(|| CacheDocument {
                     id: doc_id.clone(),
                     title: doc_id.clone(),
-                    created_at: String::new(),
-                    updated_at: String::new(),
+                    ..Default::default()
                 });
 
             Some(cache_document_to_transcript(&doc, segments))
@@ -190,3 +275,112 @@ fn parse_timestamp_to_ms(timestamp: &str) -> Option<f64> {
 
     Some((hours * 3600.0 + minutes * 60.0 + seconds) * 1000.0 + millis)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_cache(dir: &Path, documents: serde_json::Value) -> std::path::PathBuf {
+        let inner = serde_json::json!({
+            "state": {
+                "documents": documents,
+                "transcripts": {
+                    "doc-1": [{
+                        "id": "seg-1",
+                        "document_id": "doc-1",
+                        "start_timestamp": "00:00:01.000",
+                        "end_timestamp": "00:00:02.500",
+                        "text": "Hello",
+                        "source": "microphone",
+                        "is_final": true
+                    }]
+                }
+            }
+        });
+        let outer = serde_json::json!({ "cache": inner.to_string() });
+
+        let path = dir.join("cache-v3.json");
+        std::fs::write(&path, outer.to_string()).unwrap();
+        path
+    }
+
+    fn document(updated_at: &str) -> serde_json::Value {
+        serde_json::json!({
+            "title": "Weekly Sync",
+            "created_at": "2024-01-01T14:00:00Z",
+            "updated_at": updated_at,
+            "tags": ["team"],
+            "notes": {
+                "type": "doc",
+                "content": [{
+                    "type": "heading",
+                    "attrs": { "level": 1 },
+                    "content": [{ "type": "text", "text": "Agenda" }]
+                }]
+            }
+        })
+    }
+
+    #[test]
+    fn test_import_all_from_cache() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = write_cache(
+            dir.path(),
+            serde_json::json!({
+                "doc-1": document("2024-01-01T15:00:00Z"),
+                "doc-2": {
+                    "title": "Deleted",
+                    "created_at": "2024-01-02T14:00:00Z",
+                    "updated_at": "2024-01-02T15:00:00Z",
+                    "deleted_at": "2024-01-03T00:00:00Z"
+                },
+                "doc-3": {
+                    "title": "Plain",
+                    "created_at": "2024-01-04T14:00:00Z",
+                    "updated_at": "2024-01-04T15:00:00Z",
+                    "notes_plain": "Just text"
+                }
+            }),
+        );
+
+        let collection = import_all_from_cache(&path).unwrap();
+
+        assert_eq!(collection.sessions.len(), 2);
+        assert_eq!(collection.sessions[0].id, "doc-1");
+        assert_eq!(collection.sessions[0].raw_md.as_deref(), Some("# Agenda"));
+        assert_eq!(collection.sessions[1].raw_md.as_deref(), Some("Just text"));
+        assert_eq!(collection.transcripts.len(), 1);
+        assert_eq!(collection.transcripts[0].session_id, "doc-1");
+        assert_eq!(collection.tags.len(), 1);
+        assert_eq!(collection.tag_mappings[0].session_id, "doc-1");
+    }
+
+    #[test]
+    fn test_import_changed_from_cache() {
+        let dir = tempfile::tempdir().unwrap();
+        let state_path = dir.path().join("state.json");
+
+        let path = write_cache(
+            dir.path(),
+            serde_json::json!({ "doc-1": document("2024-01-01T15:00:00Z") }),
+        );
+        let state = ImportState::load(&state_path).unwrap();
+        let (first, state) = import_changed_from_cache(&path, &state).unwrap();
+        state.save(&state_path).unwrap();
+        assert_eq!(first.sessions.len(), 1);
+
+        let state = ImportState::load(&state_path).unwrap();
+        let (unchanged, state) = import_changed_from_cache(&path, &state).unwrap();
+        assert!(unchanged.sessions.is_empty());
+        assert!(unchanged.transcripts.is_empty());
+
+        let path = write_cache(
+            dir.path(),
+            serde_json::json!({ "doc-1": document("2024-01-05T09:00:00Z") }),
+        );
+        let (updated, state) = import_changed_from_cache(&path, &state).unwrap();
+        assert_eq!(updated.sessions.len(), 1);
+        assert_eq!(updated.sessions[0].id, "doc-1");
+        assert_eq!(state.documents["doc-1"], "2024-01-05T09:00:00Z");
+    }
+}
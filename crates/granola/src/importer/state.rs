@@ -0,0 +1,48 @@
+use crate::error::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// What a previous import saw, so re-running it only picks up new and edited
+/// documents.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ImportState {
+    /// Document id -> `updated_at` of the version last imported.
+    #[serde(default)]
+    pub documents: BTreeMap<String, String>,
+}
+
+impl ImportState {
+    /// A missing file is an empty state, i.e. everything gets imported.
+    pub fn load(path: &Path) -> Result<Self> {
+        match std::fs::read_to_string(path) {
+            Ok(content) => serde_json::from_str(&content).map_err(Error::StateJsonParse),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(Error::StateFileRead(e)),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).map_err(Error::CreateDirectory)?;
+        }
+
+        let content = serde_json::to_string_pretty(self).map_err(Error::StateJsonParse)?;
+        std::fs::write(path, content).map_err(|source| Error::WriteFile {
+            path: path.display().to_string(),
+            source,
+        })
+    }
+
+    /// Records `updated_at` for the document and returns whether it differs
+    /// from what was imported before.
+    pub fn record(&mut self, id: &str, updated_at: &str) -> bool {
+        if self.documents.get(id).map(String::as_str) == Some(updated_at) {
+            return false;
+        }
+
+        self.documents
+            .insert(id.to_string(), updated_at.to_string());
+        true
+    }
+}
@@ -21,5 +21,35 @@ pub trait HttpClient: Send + Sync {
         body: Vec<u8>,
     ) -> impl Future<Output = Result<Vec<u8>, Error>> + Send;
 
+    /// `patch` that only applies while the resource still has `etag`, sent
+    /// as `If-Match`. A resource changed in between fails with status 412.
+    fn patch_if_match(
+        &self,
+        path: &str,
+        body: Vec<u8>,
+        etag: &str,
+    ) -> impl Future<Output = Result<Vec<u8>, Error>> + Send;
+
     fn delete(&self, path: &str) -> impl Future<Output = Result<Vec<u8>, Error>> + Send;
 }
+
+/// A non-success response. Clients return it so callers can branch on the
+/// status, e.g. an expired sync token, without knowing the transport.
+#[derive(Debug)]
+pub struct StatusError {
+    pub status: u16,
+    pub body: String,
+}
+
+impl std::fmt::Display for StatusError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "HTTP status {}: {}", self.status, self.body)
+    }
+}
+
+impl std::error::Error for StatusError {}
+
+/// The status of a failed request, if the client reported one.
+pub fn status_of(error: &Error) -> Option<u16> {
+    error.downcast_ref::<StatusError>().map(|e| e.status)
+}
@@ -1,13 +1,13 @@
 use crate::LagoClient;
 
 #[derive(Debug, serde::Serialize, serde::Deserialize, specta::Type)]
-#[specta(rename = "RegenerateCheckoutUrlRequest")]
+#[serde(rename = "RegenerateCheckoutUrlRequest")]
 pub struct Request {
     pub external_customer_id: String,
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize, specta::Type)]
-#[specta(rename = "RegenerateCheckoutUrlResponse")]
+#[serde(rename = "RegenerateCheckoutUrlResponse")]
 #[serde(untagged)]
 pub enum Response {
     Ok { customer: ResponseCustomer },
@@ -15,7 +15,7 @@ pub enum Response {
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize, specta::Type)]
-#[specta(rename = "RegenerateCheckoutUrlResponseCustomer")]
+#[serde(rename = "RegenerateCheckoutUrlResponseCustomer")]
 pub struct ResponseCustomer {
     pub lago_customer_id: String,
     pub external_customer_id: String,
@@ -54,6 +54,17 @@ impl ListenerRuntime for CliRuntime {
             SessionProgressEvent::Connected { adapter, .. } => {
                 eprintln!("[progress] connected via {adapter}");
             }
+            SessionProgressEvent::AutoStopPending {
+                reason, stop_at, ..
+            } => {
+                eprintln!("[progress] still recording, stopping at {stop_at} reason={reason:?}");
+            }
+            SessionProgressEvent::AutoStopCancelled { .. } => {
+                eprintln!("[progress] auto-stop cancelled");
+            }
+            SessionProgressEvent::AutoStopped { reason, .. } => {
+                eprintln!("[progress] auto-stopped reason={reason:?}");
+            }
         }
     }
 
@@ -143,6 +154,8 @@ async fn main() {
         base_url,
         api_key,
         keywords: vec![],
+        auto_stop: Default::default(),
+        event_ends_at: None,
     };
 
     let started = ractor::call!(root_ref, RootMsg::StartSession, params)
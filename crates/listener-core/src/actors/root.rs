@@ -10,12 +10,13 @@ use crate::actors::session::lifecycle::{
 use crate::actors::{
     SessionContext, SessionMsg, SessionParams, session_span, spawn_session_supervisor,
 };
-use crate::{ListenerRuntime, SessionLifecycleEvent, State};
+use crate::{AutoStopSignal, ListenerRuntime, SessionLifecycleEvent, State};
 
 pub enum RootMsg {
     StartSession(SessionParams, RpcReplyPort<bool>),
     StopSession(RpcReplyPort<()>),
     GetState(RpcReplyPort<State>),
+    AutoStop(AutoStopSignal),
 }
 
 pub struct RootArgs {
@@ -29,6 +30,23 @@ pub struct RootState {
     finalizing: bool,
 }
 
+#[cfg(test)]
+impl RootState {
+    /// Root as it is once `session` has started, without spawning one.
+    pub(crate) fn with_session(
+        runtime: Arc<dyn ListenerRuntime>,
+        session_id: String,
+        session: ActorCell,
+    ) -> Self {
+        Self {
+            runtime,
+            session_id: Some(session_id),
+            supervisor: Some(session),
+            finalizing: false,
+        }
+    }
+}
+
 pub struct RootActor;
 
 impl RootActor {
@@ -81,6 +99,14 @@ impl Actor for RootActor {
                 };
                 let _ = reply.send(fsm_state);
             }
+            RootMsg::AutoStop(signal) => {
+                if let Some(supervisor) = &state.supervisor {
+                    let session_ref: ActorRef<SessionMsg> = supervisor.clone().into();
+                    if let Err(error) = session_ref.cast(SessionMsg::AutoStop(signal)) {
+                        tracing::warn!(?error, "failed_to_cast_auto_stop_signal");
+                    }
+                }
+            }
         }
         Ok(())
     }
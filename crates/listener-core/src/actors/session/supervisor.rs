@@ -1,14 +1,18 @@
 use hypr_supervisor::{RestartBudget, RestartTracker, RetryStrategy, spawn_with_retry};
 use ractor::concurrency::Duration;
-use ractor::{Actor, ActorCell, ActorProcessingErr, ActorRef, SupervisionEvent};
+use ractor::{Actor, ActorCell, ActorProcessingErr, ActorRef, SupervisionEvent, registry};
 use tracing::Instrument;
 
 use crate::actors::session::lifecycle;
 use crate::actors::session::types::{SessionContext, session_span, session_supervisor_name};
 use crate::actors::{
-    ChannelMode, ListenerActor, ListenerArgs, RecArgs, RecorderActor, SourceActor, SourceArgs,
+    ChannelMode, ListenerActor, ListenerArgs, RecArgs, RecorderActor, RootActor, RootMsg,
+    SourceActor, SourceArgs,
+};
+use crate::{
+    AutoStopAction, AutoStopPolicy, AutoStopSignal, DegradedError, SessionLifecycleEvent,
+    SessionProgressEvent,
 };
-use crate::{DegradedError, SessionLifecycleEvent};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum ChildKind {
@@ -23,6 +27,8 @@ const RESTART_BUDGET: RestartBudget = RestartBudget {
     reset_after: Some(Duration::from_secs(30)),
 };
 
+const AUTO_STOP_TICK: Duration = Duration::from_secs(1);
+
 const RETRY_STRATEGY: RetryStrategy = RetryStrategy {
     max_attempts: 3,
     base_delay: Duration::from_millis(100),
@@ -35,6 +41,7 @@ pub struct SessionState {
     recorder_cell: Option<ActorCell>,
     source_restarts: RestartTracker,
     recorder_restarts: RestartTracker,
+    auto_stop: AutoStopPolicy,
     shutting_down: bool,
 }
 
@@ -43,6 +50,10 @@ pub struct SessionActor;
 #[derive(Debug)]
 pub enum SessionMsg {
     Shutdown,
+    /// Someone spoke, on either channel. Sent at most about once a second.
+    SpeechDetected,
+    AutoStop(AutoStopSignal),
+    AutoStopTick,
 }
 
 #[ractor::async_trait]
@@ -89,6 +100,9 @@ impl Actor for SessionActor {
                 None
             };
 
+            let auto_stop = AutoStopPolicy::new(ctx.params.auto_stop.clone())
+                .with_event_end(ctx.params.event_ends_at);
+
             Ok(SessionState {
                 ctx,
                 source_cell: Some(source_ref.get_cell()),
@@ -96,6 +110,7 @@ impl Actor for SessionActor {
                 recorder_cell,
                 source_restarts: RestartTracker::new(),
                 recorder_restarts: RestartTracker::new(),
+                auto_stop,
                 shutting_down: false,
             })
         }
@@ -112,6 +127,8 @@ impl Actor for SessionActor {
     ) -> Result<(), ActorProcessingErr> {
         let span = session_span(&state.ctx.params.session_id);
 
+        myself.send_interval(AUTO_STOP_TICK, || SessionMsg::AutoStopTick);
+
         async {
             let mode = ChannelMode::determine(state.ctx.params.onboarding);
             match Actor::spawn_linked(
@@ -182,6 +199,15 @@ impl Actor for SessionActor {
 
                 myself.stop(None);
             }
+            SessionMsg::SpeechDetected => state.auto_stop.observe_speech(),
+            SessionMsg::AutoStop(signal) => state.auto_stop.signal(signal),
+            SessionMsg::AutoStopTick => {
+                if !state.shutting_down
+                    && let Some(action) = state.auto_stop.poll()
+                {
+                    handle_auto_stop(&myself, state, action).await;
+                }
+            }
         }
         Ok(())
     }
@@ -283,6 +309,54 @@ impl Actor for SessionActor {
     }
 }
 
+async fn handle_auto_stop(
+    myself: &ActorRef<SessionMsg>,
+    state: &SessionState,
+    action: AutoStopAction,
+) {
+    let session_id = state.ctx.params.session_id.clone();
+    let span = session_span(&session_id);
+    let runtime = &state.ctx.runtime;
+
+    async {
+        match action {
+            AutoStopAction::Prompt { reason, stop_at } => {
+                tracing::info!(?reason, "auto_stop_pending");
+                runtime.emit_progress(SessionProgressEvent::AutoStopPending {
+                    session_id,
+                    reason,
+                    stop_at,
+                });
+            }
+            AutoStopAction::Cancel => {
+                tracing::info!("auto_stop_cancelled");
+                runtime.emit_progress(SessionProgressEvent::AutoStopCancelled { session_id });
+            }
+            AutoStopAction::Stop { reason } => {
+                tracing::info!(?reason, "auto_stopping_session");
+                runtime.emit_progress(SessionProgressEvent::AutoStopped { session_id, reason });
+                request_stop(myself).await;
+            }
+        }
+    }
+    .instrument(span)
+    .await
+}
+
+// Stops through the root actor, like a user-requested stop, so it reports
+// finalizing as usual. Root only casts back to us, so waiting is safe.
+async fn request_stop(myself: &ActorRef<SessionMsg>) {
+    if let Some(cell) = registry::where_is(RootActor::name()) {
+        let root: ActorRef<RootMsg> = cell.into();
+        if ractor::call!(root, RootMsg::StopSession).is_ok() {
+            return;
+        }
+    }
+
+    tracing::warn!("root_unavailable_shutting_down_session_directly");
+    let _ = myself.cast(SessionMsg::Shutdown);
+}
+
 fn identify_child(state: &SessionState, cell: &ActorCell) -> Option<ChildKind> {
     if state
         .source_cell
@@ -435,7 +509,14 @@ pub async fn spawn_session_supervisor(
 
 #[cfg(test)]
 mod tests {
+    use std::sync::{Arc, Mutex};
+    use std::time::{Instant, SystemTime};
+
     use super::*;
+    use crate::actors::{RootState, SessionParams};
+    use crate::{
+        AutoStopConfig, ListenerRuntime, SessionDataEvent, SessionErrorEvent, SessionLifecycleEvent,
+    };
 
     #[test]
     fn parse_degraded_reason_uses_json_payload() {
@@ -456,4 +537,196 @@ mod tests {
         let parsed = parse_degraded_reason(Some(&reason));
         assert!(matches!(parsed, DegradedError::StreamError { .. }));
     }
+
+    #[derive(Default)]
+    struct TestRuntime {
+        events: Mutex<Vec<String>>,
+    }
+
+    impl TestRuntime {
+        fn record(&self, event: impl serde::Serialize) {
+            let event = serde_json::to_value(event).unwrap();
+            let kind = event["type"].as_str().unwrap().to_string();
+            self.events.lock().unwrap().push(kind);
+        }
+
+        fn events(&self) -> Vec<String> {
+            self.events.lock().unwrap().clone()
+        }
+    }
+
+    impl hypr_storage::StorageRuntime for TestRuntime {
+        fn global_base(&self) -> Result<std::path::PathBuf, hypr_storage::Error> {
+            Ok(std::env::temp_dir())
+        }
+
+        fn vault_base(&self) -> Result<std::path::PathBuf, hypr_storage::Error> {
+            Ok(std::env::temp_dir())
+        }
+    }
+
+    impl ListenerRuntime for TestRuntime {
+        fn emit_lifecycle(&self, event: SessionLifecycleEvent) {
+            self.record(event);
+        }
+
+        fn emit_progress(&self, event: SessionProgressEvent) {
+            self.record(event);
+        }
+
+        fn emit_error(&self, event: SessionErrorEvent) {
+            self.record(event);
+        }
+
+        fn emit_data(&self, _event: SessionDataEvent) {}
+    }
+
+    /// The real session handlers over a session with no children, so no
+    /// audio devices or STT server are needed.
+    struct DetachedSession;
+
+    #[ractor::async_trait]
+    impl Actor for DetachedSession {
+        type Msg = SessionMsg;
+        type State = SessionState;
+        type Arguments = SessionState;
+
+        async fn pre_start(
+            &self,
+            _myself: ActorRef<Self::Msg>,
+            state: Self::Arguments,
+        ) -> Result<Self::State, ActorProcessingErr> {
+            Ok(state)
+        }
+
+        async fn handle(
+            &self,
+            myself: ActorRef<Self::Msg>,
+            message: Self::Msg,
+            state: &mut Self::State,
+        ) -> Result<(), ActorProcessingErr> {
+            SessionActor.handle(myself, message, state).await
+        }
+    }
+
+    /// The real root, already holding the session.
+    struct TestRoot;
+
+    #[ractor::async_trait]
+    impl Actor for TestRoot {
+        type Msg = RootMsg;
+        type State = RootState;
+        type Arguments = RootState;
+
+        async fn pre_start(
+            &self,
+            _myself: ActorRef<Self::Msg>,
+            state: Self::Arguments,
+        ) -> Result<Self::State, ActorProcessingErr> {
+            Ok(state)
+        }
+
+        async fn handle(
+            &self,
+            myself: ActorRef<Self::Msg>,
+            message: Self::Msg,
+            state: &mut Self::State,
+        ) -> Result<(), ActorProcessingErr> {
+            RootActor.handle(myself, message, state).await
+        }
+
+        async fn handle_supervisor_evt(
+            &self,
+            myself: ActorRef<Self::Msg>,
+            message: SupervisionEvent,
+            state: &mut Self::State,
+        ) -> Result<(), ActorProcessingErr> {
+            RootActor
+                .handle_supervisor_evt(myself, message, state)
+                .await
+        }
+    }
+
+    #[tokio::test]
+    async fn auto_stop_tick_stops_session_through_root() {
+        let runtime = Arc::new(TestRuntime::default());
+        let session_id = "auto-stop-session".to_string();
+
+        let auto_stop = AutoStopConfig {
+            silence_minutes: 0,
+            grace_period_secs: 0,
+            ..Default::default()
+        };
+        let ctx = SessionContext {
+            runtime: runtime.clone(),
+            params: SessionParams {
+                session_id: session_id.clone(),
+                languages: vec![],
+                onboarding: false,
+                record_enabled: false,
+                model: "test".to_string(),
+                base_url: "http://localhost".to_string(),
+                api_key: String::new(),
+                keywords: vec![],
+                auto_stop: auto_stop.clone(),
+                event_ends_at: None,
+            },
+            app_dir: std::env::temp_dir(),
+            started_at_instant: Instant::now(),
+            started_at_system: SystemTime::now(),
+        };
+        let state = SessionState {
+            ctx,
+            source_cell: None,
+            listener_cell: None,
+            recorder_cell: None,
+            source_restarts: RestartTracker::new(),
+            recorder_restarts: RestartTracker::new(),
+            auto_stop: AutoStopPolicy::new(auto_stop),
+            shutting_down: false,
+        };
+
+        let (session, session_handle) = Actor::spawn(None, DetachedSession, state).await.unwrap();
+        let (root, root_handle) = Actor::spawn(
+            Some(RootActor::name()),
+            TestRoot,
+            RootState::with_session(runtime.clone(), session_id, session.get_cell()),
+        )
+        .await
+        .unwrap();
+        session.get_cell().link(root.get_cell());
+
+        // The first tick prompts; the grace period is zero, so the next stops.
+        session.cast(SessionMsg::AutoStopTick).unwrap();
+        session.cast(SessionMsg::AutoStopTick).unwrap();
+
+        tokio::time::timeout(Duration::from_secs(5), session_handle)
+            .await
+            .expect("session should stop")
+            .unwrap();
+
+        tokio::time::timeout(Duration::from_secs(5), async {
+            while !matches!(
+                ractor::call!(root, RootMsg::GetState),
+                Ok(crate::State::Inactive)
+            ) {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .expect("root should see the session end");
+
+        assert_eq!(
+            runtime.events(),
+            [
+                "auto_stop_pending",
+                "auto_stopped",
+                "finalizing",
+                "inactive"
+            ]
+        );
+
+        root.stop(None);
+        root_handle.await.unwrap();
+    }
 }
@@ -2,7 +2,9 @@ use std::path::PathBuf;
 use std::sync::Arc;
 use std::time::{Instant, SystemTime};
 
-use crate::ListenerRuntime;
+use chrono::{DateTime, Utc};
+
+use crate::{AutoStopConfig, ListenerRuntime};
 
 pub const SESSION_SUPERVISOR_PREFIX: &str = "session_supervisor_";
 
@@ -21,6 +23,11 @@ pub struct SessionParams {
     pub base_url: String,
     pub api_key: String,
     pub keywords: Vec<String>,
+    #[serde(default)]
+    pub auto_stop: AutoStopConfig,
+    /// End of the calendar event being recorded, if any.
+    #[serde(default)]
+    pub event_ends_at: Option<DateTime<Utc>>,
 }
 
 #[derive(Clone)]
@@ -7,8 +7,11 @@ use std::{
 use ractor::{ActorRef, registry};
 
 use crate::{
-    ListenerRuntime, SessionDataEvent,
-    actors::{AudioChunk, ChannelMode, ListenerActor, ListenerMsg, RecMsg, RecorderActor},
+    ListenerRuntime, SessionDataEvent, SpeechActivity,
+    actors::{
+        AudioChunk, ChannelMode, ListenerActor, ListenerMsg, RecMsg, RecorderActor, SessionMsg,
+        session_supervisor_name,
+    },
 };
 use hypr_aec::AEC;
 use hypr_audio_utils::f32_to_i16_bytes;
@@ -16,6 +19,7 @@ use hypr_vad_ext::VadMask;
 
 const AUDIO_AMPLITUDE_THROTTLE: Duration = Duration::from_millis(100);
 const MAX_BUFFER_CHUNKS: usize = 150;
+const SPEECH_REPORT_THROTTLE: Duration = Duration::from_secs(1);
 
 type AudioPair = (Vec<f32>, Vec<f32>);
 type BufferedAudio = (Arc<[f32]>, Arc<[f32]>, ChannelMode);
@@ -27,6 +31,9 @@ pub(in crate::actors) struct Pipeline {
     amplitude: AmplitudeEmitter,
     audio_buffer: AudioBuffer,
     backlog_quota: f32,
+    speech: SpeechActivity,
+    last_speech_report: Option<Instant>,
+    session_id: String,
 }
 
 impl Pipeline {
@@ -39,10 +46,13 @@ impl Pipeline {
                 .map_err(|e| tracing::warn!(error = ?e, "aec_init_failed"))
                 .ok(),
             joiner: Joiner::new(),
-            amplitude: AmplitudeEmitter::new(runtime, session_id),
+            amplitude: AmplitudeEmitter::new(runtime, session_id.clone()),
             audio_buffer: AudioBuffer::new(MAX_BUFFER_CHUNKS),
             backlog_quota: 0.0,
             vad_mask: VadMask::default(),
+            speech: SpeechActivity::new(),
+            last_speech_report: None,
+            session_id,
         }
     }
 
@@ -55,6 +65,8 @@ impl Pipeline {
         self.audio_buffer.clear();
         self.backlog_quota = 0.0;
         self.vad_mask = VadMask::default();
+        self.speech = SpeechActivity::new();
+        self.last_speech_report = None;
     }
 
     pub(super) fn ingest_mic(&mut self, chunk: AudioChunk) {
@@ -91,6 +103,10 @@ impl Pipeline {
         self.amplitude.observe_mic(&processed_mic);
         self.amplitude.observe_spk(&processed_spk);
 
+        if self.speech.observe(&processed_mic, &processed_spk) {
+            self.report_speech();
+        }
+
         if let Some(cell) = registry::where_is(RecorderActor::name()) {
             let actor: ActorRef<RecMsg> = cell.into();
             let result = match mode {
@@ -125,6 +141,23 @@ impl Pipeline {
         self.send_to_listener(&actor, &processed_mic, &processed_spk, mode);
     }
 
+    fn report_speech(&mut self) {
+        if self
+            .last_speech_report
+            .is_some_and(|at| at.elapsed() < SPEECH_REPORT_THROTTLE)
+        {
+            return;
+        }
+        self.last_speech_report = Some(Instant::now());
+
+        if let Some(cell) = registry::where_is(session_supervisor_name(&self.session_id)) {
+            let actor: ActorRef<SessionMsg> = cell.into();
+            if actor.cast(SessionMsg::SpeechDetected).is_err() {
+                tracing::debug!("failed_to_report_speech");
+            }
+        }
+    }
+
     fn flush_buffer_to_listener(&mut self, actor: &ActorRef<ListenerMsg>, mode: ChannelMode) {
         if !self.audio_buffer.is_empty() {
             self.backlog_quota =
@@ -0,0 +1,578 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use hypr_vad_ext::{StreamingVad, VadConfig};
+
+pub trait Clock: Send + Sync {
+    fn now(&self) -> DateTime<Utc>;
+}
+
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+#[serde(default)]
+pub struct AutoStopConfig {
+    pub enabled: bool,
+    /// Minutes with no speech on either side before asking to stop.
+    pub silence_minutes: u32,
+    /// Seconds with no speech before asking, once the meeting app has
+    /// released the mic or the calendar event is over.
+    pub ended_silence_secs: u32,
+    /// Minutes past the calendar event's end before it counts as over.
+    pub event_overrun_minutes: u32,
+    /// Seconds the "Still recording — stop?" prompt waits for an answer.
+    pub grace_period_secs: u32,
+}
+
+impl Default for AutoStopConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            silence_minutes: 15,
+            ended_silence_secs: 60,
+            event_overrun_minutes: 5,
+            grace_period_secs: 60,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+#[serde(rename_all = "snake_case")]
+pub enum AutoStopReason {
+    Silence,
+    MeetingAppStopped,
+    EventEnded,
+}
+
+/// What the policy learns from outside the audio pipeline.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+#[serde(rename_all = "snake_case")]
+pub enum AutoStopSignal {
+    /// The meeting app being recorded released the mic.
+    MeetingAppStopped,
+    /// It took the mic again, e.g. after rejoining the call.
+    MeetingAppStarted,
+    /// The user answered the prompt with "keep recording".
+    KeepRecording,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum AutoStopAction {
+    /// Ask whether to stop. Stops at `stop_at` unless speech resumes or the
+    /// user keeps recording.
+    Prompt {
+        reason: AutoStopReason,
+        stop_at: DateTime<Utc>,
+    },
+    /// Speech resumed; withdraw the prompt.
+    Cancel,
+    Stop {
+        reason: AutoStopReason,
+    },
+}
+
+#[derive(Debug, Clone)]
+struct Prompt {
+    reason: AutoStopReason,
+    shown_at: DateTime<Utc>,
+    stop_at: DateTime<Utc>,
+}
+
+/// Decides when a session has outlived its meeting. Holds no timers:
+/// callers report speech and signals as they happen and act on what
+/// [`Self::poll`] returns, calling it about once a second.
+///
+/// A meeting app releasing the mic or the event running over only counts
+/// once nobody has spoken for a short while, so a call that moves to
+/// another app, or a meeting that runs late, keeps recording.
+pub struct AutoStopPolicy {
+    clock: Arc<dyn Clock>,
+    config: AutoStopConfig,
+    last_speech: DateTime<Utc>,
+    app_stopped: bool,
+    event_end: Option<DateTime<Utc>>,
+    prompt: Option<Prompt>,
+    stopped: bool,
+}
+
+impl Default for AutoStopPolicy {
+    fn default() -> Self {
+        Self::new(AutoStopConfig::default())
+    }
+}
+
+impl AutoStopPolicy {
+    pub fn new(config: AutoStopConfig) -> Self {
+        Self {
+            clock: Arc::new(SystemClock),
+            config,
+            last_speech: Utc::now(),
+            app_stopped: false,
+            event_end: None,
+            prompt: None,
+            stopped: false,
+        }
+    }
+
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.last_speech = clock.now();
+        self.clock = clock;
+        self
+    }
+
+    pub fn with_event_end(mut self, event_end: Option<DateTime<Utc>>) -> Self {
+        self.event_end = event_end;
+        self
+    }
+
+    pub fn observe_speech(&mut self) {
+        self.last_speech = self.clock.now();
+    }
+
+    pub fn signal(&mut self, signal: AutoStopSignal) {
+        match signal {
+            AutoStopSignal::MeetingAppStopped => self.app_stopped = true,
+            AutoStopSignal::MeetingAppStarted => self.app_stopped = false,
+            AutoStopSignal::KeepRecording => {
+                // Only silence can end the session from here on.
+                self.prompt = None;
+                self.app_stopped = false;
+                self.event_end = None;
+                self.last_speech = self.clock.now();
+            }
+        }
+    }
+
+    /// The next action to take, if any. Each is returned once.
+    pub fn poll(&mut self) -> Option<AutoStopAction> {
+        if !self.config.enabled || self.stopped {
+            return None;
+        }
+
+        let now = self.clock.now();
+
+        if let Some(prompt) = &self.prompt {
+            if self.last_speech > prompt.shown_at {
+                self.prompt = None;
+                return Some(AutoStopAction::Cancel);
+            }
+            if now >= prompt.stop_at {
+                self.stopped = true;
+                return Some(AutoStopAction::Stop {
+                    reason: prompt.reason,
+                });
+            }
+            return None;
+        }
+
+        let reason = self.reason(now)?;
+        let stop_at = now + seconds(self.config.grace_period_secs);
+        self.prompt = Some(Prompt {
+            reason,
+            shown_at: now,
+            stop_at,
+        });
+
+        Some(AutoStopAction::Prompt { reason, stop_at })
+    }
+
+    fn reason(&self, now: DateTime<Utc>) -> Option<AutoStopReason> {
+        let silence = now - self.last_speech;
+        let ended = silence >= seconds(self.config.ended_silence_secs);
+
+        if ended && self.app_stopped {
+            return Some(AutoStopReason::MeetingAppStopped);
+        }
+
+        let overrun = minutes(self.config.event_overrun_minutes);
+        if ended && self.event_end.is_some_and(|end| now >= end + overrun) {
+            return Some(AutoStopReason::EventEnded);
+        }
+
+        if silence >= minutes(self.config.silence_minutes) {
+            return Some(AutoStopReason::Silence);
+        }
+
+        None
+    }
+}
+
+fn seconds(s: u32) -> chrono::Duration {
+    chrono::Duration::seconds(s.into())
+}
+
+fn minutes(m: u32) -> chrono::Duration {
+    chrono::Duration::minutes(m.into())
+}
+
+/// Whether anyone is speaking, on either side of the call.
+pub(crate) struct SpeechActivity {
+    mic: Option<StreamingVad>,
+    spk: Option<StreamingVad>,
+    scratch: Vec<f32>,
+}
+
+impl SpeechActivity {
+    pub fn new() -> Self {
+        Self {
+            mic: None,
+            spk: None,
+            scratch: Vec::new(),
+        }
+    }
+
+    /// Whether either channel has speech in this chunk.
+    pub fn observe(&mut self, mic: &[f32], spk: &[f32]) -> bool {
+        let mic = Self::detect(&mut self.mic, &mut self.scratch, mic);
+        let spk = Self::detect(&mut self.spk, &mut self.scratch, spk);
+        mic || spk
+    }
+
+    fn detect(vad: &mut Option<StreamingVad>, scratch: &mut Vec<f32>, samples: &[f32]) -> bool {
+        if samples.is_empty() {
+            return false;
+        }
+
+        let vad = vad.get_or_insert_with(|| {
+            StreamingVad::with_config(
+                samples.len(),
+                VadConfig {
+                    start_in_speech: false,
+                    ..Default::default()
+                },
+            )
+        });
+
+        // The VAD works in place; the chunk itself goes on unchanged.
+        scratch.clear();
+        scratch.extend_from_slice(samples);
+
+        let mut speech = false;
+        vad.process_in_place(scratch, |_, is_speech| speech |= is_speech);
+        speech
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+    use std::time::Duration;
+
+    use futures_util::{FutureExt, StreamExt};
+    use hypr_audio::MockInnerStream;
+    use hypr_audio_utils::chunk_size_for_stt;
+
+    use super::*;
+    use crate::actors::SAMPLE_RATE;
+
+    struct FakeClock(Mutex<DateTime<Utc>>);
+
+    impl FakeClock {
+        fn at(time: &str) -> Arc<Self> {
+            Arc::new(Self(Mutex::new(time.parse().unwrap())))
+        }
+
+        fn advance(&self, by: Duration) {
+            *self.0.lock().unwrap() += chrono::Duration::from_std(by).unwrap();
+        }
+    }
+
+    impl Clock for FakeClock {
+        fn now(&self) -> DateTime<Utc> {
+            *self.0.lock().unwrap()
+        }
+    }
+
+    enum Segment {
+        MicSpeech,
+        SpeakerSpeech,
+        Silence(Duration),
+    }
+
+    /// Mic and speaker chunks as the source actor delivers them, from a
+    /// recorded speech fixture and digital silence.
+    fn mock_source(segments: &[Segment]) -> impl Iterator<Item = (Vec<f32>, Vec<f32>)> {
+        let speech = speech_fixture();
+        let (mut mic, mut spk) = (vec![], vec![]);
+
+        for segment in segments {
+            let len = match segment {
+                Segment::MicSpeech | Segment::SpeakerSpeech => speech.len(),
+                Segment::Silence(d) => (d.as_secs_f64() * SAMPLE_RATE as f64) as usize,
+            };
+            let silence = std::iter::repeat_n(0.0, len);
+
+            match segment {
+                Segment::MicSpeech => {
+                    mic.extend_from_slice(&speech);
+                    spk.extend(silence);
+                }
+                Segment::SpeakerSpeech => {
+                    mic.extend(silence);
+                    spk.extend_from_slice(&speech);
+                }
+                Segment::Silence(_) => {
+                    mic.extend(silence.clone());
+                    spk.extend(silence);
+                }
+            }
+        }
+
+        let stream = |samples: Vec<f32>| {
+            let chunks = samples
+                .chunks(chunk_size_for_stt(SAMPLE_RATE))
+                .map(<[f32]>::to_vec)
+                .collect();
+            MockInnerStream::new(chunks, SAMPLE_RATE)
+        };
+
+        // The mock streams are always ready, so they can be drained in step.
+        let mut chunks = stream(mic).zip(stream(spk));
+        std::iter::from_fn(move || chunks.next().now_or_never().flatten())
+    }
+
+    fn speech_fixture() -> Vec<f32> {
+        let reader =
+            hound::WavReader::open(hypr_data::english_1::AUDIO_PART2_16000HZ_PATH).unwrap();
+        assert_eq!(reader.spec().sample_rate, SAMPLE_RATE);
+
+        reader
+            .into_samples::<i16>()
+            .map(|s| s.unwrap() as f32 / i16::MAX as f32)
+            .collect()
+    }
+
+    struct Harness {
+        clock: Arc<FakeClock>,
+        started: DateTime<Utc>,
+        policy: AutoStopPolicy,
+        speech: SpeechActivity,
+    }
+
+    impl Harness {
+        fn new(config: AutoStopConfig) -> Self {
+            let clock = FakeClock::at("2026-03-02T10:00:00Z");
+            Self {
+                started: clock.now(),
+                policy: AutoStopPolicy::new(config).with_clock(clock.clone()),
+                speech: SpeechActivity::new(),
+                clock,
+            }
+        }
+
+        /// Plays `source` in real time, returning each action with the
+        /// seconds since the harness started.
+        fn play(
+            &mut self,
+            source: impl Iterator<Item = (Vec<f32>, Vec<f32>)>,
+        ) -> Vec<(i64, AutoStopAction)> {
+            let mut actions = vec![];
+
+            for (mic, spk) in source {
+                self.clock.advance(Duration::from_secs_f64(
+                    mic.len() as f64 / SAMPLE_RATE as f64,
+                ));
+
+                if self.speech.observe(&mic, &spk) {
+                    self.policy.observe_speech();
+                }
+                if let Some(action) = self.policy.poll() {
+                    actions.push((self.elapsed(), action));
+                }
+            }
+
+            actions
+        }
+
+        fn elapsed(&self) -> i64 {
+            (self.clock.now() - self.started).num_seconds()
+        }
+    }
+
+    fn speech_secs() -> i64 {
+        (speech_fixture().len() / SAMPLE_RATE as usize) as i64
+    }
+
+    fn reasons(actions: &[(i64, AutoStopAction)]) -> Vec<Option<AutoStopReason>> {
+        actions
+            .iter()
+            .map(|(_, action)| match action {
+                AutoStopAction::Prompt { reason, .. } | AutoStopAction::Stop { reason } => {
+                    Some(*reason)
+                }
+                AutoStopAction::Cancel => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_speech_activity() {
+        let mut speech = SpeechActivity::new();
+
+        let detected = mock_source(&[Segment::SpeakerSpeech])
+            .filter(|(mic, spk)| speech.observe(mic, spk))
+            .count();
+        assert!(detected > 0);
+
+        let detected = mock_source(&[Segment::Silence(Duration::from_secs(5))])
+            .filter(|(mic, spk)| speech.observe(mic, spk))
+            .count();
+        // Only the hangover after the speech above.
+        assert!(detected <= 1);
+    }
+
+    #[test]
+    fn test_prompts_then_stops_after_silence() {
+        let mut harness = Harness::new(AutoStopConfig::default());
+
+        let actions = harness.play(mock_source(&[
+            Segment::SpeakerSpeech,
+            Segment::Silence(Duration::from_secs(20 * 60)),
+        ]));
+
+        assert_eq!(
+            reasons(&actions),
+            [Some(AutoStopReason::Silence), Some(AutoStopReason::Silence)]
+        );
+
+        let (prompted, stop_at) = match &actions[0] {
+            (t, AutoStopAction::Prompt { stop_at, .. }) => (*t, *stop_at),
+            other => panic!("expected prompt, got {other:?}"),
+        };
+        assert!((15 * 60..=15 * 60 + speech_secs() + 1).contains(&prompted));
+        assert_eq!((stop_at - harness.started).num_seconds(), prompted + 60);
+        assert_eq!(actions[1].0, prompted + 60);
+
+        assert!(harness.policy.poll().is_none());
+    }
+
+    #[test]
+    fn test_speech_cancels_prompt() {
+        let mut harness = Harness::new(AutoStopConfig::default());
+
+        let actions = harness.play(mock_source(&[
+            Segment::Silence(Duration::from_secs(15 * 60 + 30)),
+            Segment::MicSpeech,
+        ]));
+
+        assert_eq!(reasons(&actions), [Some(AutoStopReason::Silence), None]);
+        assert!(matches!(actions[1].1, AutoStopAction::Cancel));
+    }
+
+    #[test]
+    fn test_meeting_app_stopped() {
+        let mut harness = Harness::new(AutoStopConfig::default());
+
+        harness.policy.signal(AutoStopSignal::MeetingAppStopped);
+        let actions = harness.play(mock_source(&[Segment::MicSpeech]));
+        assert!(actions.is_empty(), "people are still talking");
+
+        let actions = harness.play(mock_source(&[Segment::Silence(Duration::from_secs(
+            3 * 60,
+        ))]));
+        assert_eq!(
+            reasons(&actions),
+            [
+                Some(AutoStopReason::MeetingAppStopped),
+                Some(AutoStopReason::MeetingAppStopped)
+            ]
+        );
+        assert!(actions[0].0 <= speech_secs() + 60 + 1);
+    }
+
+    #[test]
+    fn test_meeting_app_restarted() {
+        let mut harness = Harness::new(AutoStopConfig::default());
+
+        harness.policy.signal(AutoStopSignal::MeetingAppStopped);
+        harness.policy.signal(AutoStopSignal::MeetingAppStarted);
+
+        let actions = harness.play(mock_source(&[Segment::Silence(Duration::from_secs(
+            10 * 60,
+        ))]));
+        assert!(actions.is_empty());
+    }
+
+    #[test]
+    fn test_event_ended() {
+        let config = AutoStopConfig {
+            grace_period_secs: 120,
+            ..Default::default()
+        };
+        let mut harness = Harness::new(config.clone());
+        harness.policy = AutoStopPolicy::new(config)
+            .with_clock(harness.clock.clone())
+            .with_event_end(Some("2026-03-02T10:01:00Z".parse().unwrap()));
+
+        let actions = harness.play(mock_source(&[
+            Segment::Silence(Duration::from_secs(6 * 60 + 30)),
+            Segment::MicSpeech,
+            Segment::Silence(Duration::from_secs(5 * 60)),
+        ]));
+
+        assert_eq!(
+            reasons(&actions),
+            [
+                Some(AutoStopReason::EventEnded),
+                None,
+                Some(AutoStopReason::EventEnded),
+                Some(AutoStopReason::EventEnded)
+            ]
+        );
+        // Not before the overrun, even though it was already quiet.
+        assert_eq!(actions[0].0, 6 * 60);
+    }
+
+    #[test]
+    fn test_keep_recording() {
+        let mut harness = Harness::new(AutoStopConfig::default());
+
+        harness.policy.signal(AutoStopSignal::MeetingAppStopped);
+        let actions = harness.play(mock_source(&[Segment::Silence(Duration::from_secs(90))]));
+        assert_eq!(reasons(&actions), [Some(AutoStopReason::MeetingAppStopped)]);
+
+        harness.policy.signal(AutoStopSignal::KeepRecording);
+        let kept_at = harness.elapsed();
+
+        let actions = harness.play(mock_source(&[Segment::Silence(Duration::from_secs(
+            15 * 60 + 30,
+        ))]));
+        assert_eq!(reasons(&actions), [Some(AutoStopReason::Silence)]);
+        assert_eq!(actions[0].0, kept_at + 15 * 60);
+    }
+
+    #[test]
+    fn test_thresholds_and_disabled() {
+        let mut harness = Harness::new(AutoStopConfig {
+            silence_minutes: 2,
+            grace_period_secs: 10,
+            ..Default::default()
+        });
+        let actions = harness.play(mock_source(&[Segment::Silence(Duration::from_secs(
+            3 * 60,
+        ))]));
+        assert_eq!(
+            actions.iter().map(|(t, _)| *t).collect::<Vec<_>>(),
+            [120, 130]
+        );
+
+        let mut harness = Harness::new(AutoStopConfig {
+            enabled: false,
+            ..Default::default()
+        });
+        harness.policy.signal(AutoStopSignal::MeetingAppStopped);
+        let actions = harness.play(mock_source(&[Segment::Silence(Duration::from_secs(
+            30 * 60,
+        ))]));
+        assert!(actions.is_empty());
+    }
+}
@@ -1,6 +1,8 @@
 use owhisper_interface::stream::StreamResponse;
 
-use crate::DegradedError;
+use chrono::{DateTime, Utc};
+
+use crate::{AutoStopReason, DegradedError};
 
 #[derive(serde::Serialize, serde::Deserialize, Clone)]
 #[serde(tag = "type")]
@@ -34,6 +36,19 @@ pub enum SessionProgressEvent {
     Connecting { session_id: String },
     #[serde(rename = "connected")]
     Connected { session_id: String, adapter: String },
+    #[serde(rename = "auto_stop_pending")]
+    AutoStopPending {
+        session_id: String,
+        reason: AutoStopReason,
+        stop_at: DateTime<Utc>,
+    },
+    #[serde(rename = "auto_stop_cancelled")]
+    AutoStopCancelled { session_id: String },
+    #[serde(rename = "auto_stopped")]
+    AutoStopped {
+        session_id: String,
+        reason: AutoStopReason,
+    },
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone)]
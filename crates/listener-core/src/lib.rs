@@ -1,7 +1,9 @@
 pub mod actors;
+mod auto_stop;
 mod events;
 mod runtime;
 
+pub use auto_stop::*;
 pub use events::*;
 pub use runtime::*;
 
@@ -0,0 +1,206 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+};
+
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+    response::{IntoResponse, Response},
+};
+use tower::Service;
+
+use crate::ModelManager;
+
+/// Serves OpenAI-compatible `/embeddings` requests from the registered
+/// embedding models. Each input is embedded separately and normalized.
+#[derive(Clone)]
+pub struct EmbedService {
+    manager: ModelManager,
+}
+
+impl EmbedService {
+    pub fn new(manager: ModelManager) -> Self {
+        Self { manager }
+    }
+}
+
+impl Service<Request<Body>> for EmbedService {
+    type Response = Response;
+    type Error = crate::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, req: Request<Body>) -> Self::Future {
+        let manager = self.manager.clone();
+
+        Box::pin(async move {
+            let body_bytes = match axum::body::to_bytes(req.into_body(), 10 * 1024 * 1024).await {
+                Ok(b) => b,
+                Err(e) => {
+                    return Ok((StatusCode::BAD_REQUEST, e.to_string()).into_response());
+                }
+            };
+
+            let request: EmbeddingRequest = match serde_json::from_slice(&body_bytes) {
+                Ok(r) => r,
+                Err(e) => {
+                    return Ok((StatusCode::BAD_REQUEST, e.to_string()).into_response());
+                }
+            };
+
+            if request
+                .encoding_format
+                .as_deref()
+                .is_some_and(|f| f != "float")
+            {
+                return Ok((
+                    StatusCode::BAD_REQUEST,
+                    "only float encoding_format is supported".to_string(),
+                )
+                    .into_response());
+            }
+
+            let model = match manager.get(request.model.as_deref()).await {
+                Ok(m) => m,
+                Err(e) => {
+                    return Ok((StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response());
+                }
+            };
+
+            let inputs = request.input.into_texts();
+            let dimensions = request.dimensions;
+            let result = tokio::task::spawn_blocking(move || embed_all(&model, &inputs)).await;
+
+            let embeddings = match result {
+                Ok(Ok(e)) => e,
+                Ok(Err(e)) => {
+                    return Ok((StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response());
+                }
+                Err(_) => {
+                    return Ok((
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                        "worker task panicked".to_string(),
+                    )
+                        .into_response());
+                }
+            };
+
+            Ok(build_response(embeddings, dimensions, &request.model))
+        })
+    }
+}
+
+#[derive(serde::Deserialize)]
+struct EmbeddingRequest {
+    #[serde(default)]
+    model: Option<String>,
+    input: EmbeddingInput,
+    #[serde(default)]
+    encoding_format: Option<String>,
+    #[serde(default)]
+    dimensions: Option<usize>,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(untagged)]
+enum EmbeddingInput {
+    Single(String),
+    Batch(Vec<String>),
+}
+
+impl EmbeddingInput {
+    fn into_texts(self) -> Vec<String> {
+        match self {
+            Self::Single(text) => vec![text],
+            Self::Batch(texts) => texts,
+        }
+    }
+}
+
+fn embed_all(
+    model: &Arc<hypr_cactus::Model>,
+    inputs: &[String],
+) -> Result<Vec<Vec<f32>>, hypr_cactus::Error> {
+    inputs.iter().map(|text| model.embed(text, true)).collect()
+}
+
+/// Truncates to `dimensions` and re-normalizes, which is how Matryoshka
+/// embedding models are meant to be shortened.
+fn shorten(mut embedding: Vec<f32>, dimensions: Option<usize>) -> Vec<f32> {
+    let Some(dimensions) = dimensions.filter(|d| *d < embedding.len()) else {
+        return embedding;
+    };
+
+    embedding.truncate(dimensions);
+    let norm = embedding.iter().map(|v| v * v).sum::<f32>().sqrt();
+    if norm > 0.0 {
+        embedding.iter_mut().for_each(|v| *v /= norm);
+    }
+    embedding
+}
+
+fn build_response(
+    embeddings: Vec<Vec<f32>>,
+    dimensions: Option<usize>,
+    model: &Option<String>,
+) -> Response {
+    let data: Vec<serde_json::Value> = embeddings
+        .into_iter()
+        .enumerate()
+        .map(|(index, embedding)| {
+            serde_json::json!({
+                "object": "embedding",
+                "index": index,
+                "embedding": shorten(embedding, dimensions),
+            })
+        })
+        .collect();
+
+    let response = serde_json::json!({
+        "object": "list",
+        "data": data,
+        "model": model.as_deref().unwrap_or("cactus"),
+        "usage": {
+            "prompt_tokens": 0,
+            "total_tokens": 0
+        }
+    });
+
+    axum::Json(response).into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn input_accepts_string_or_batch() {
+        let request: EmbeddingRequest =
+            serde_json::from_value(serde_json::json!({"input": "hello"})).unwrap();
+        assert_eq!(request.input.into_texts(), vec!["hello"]);
+
+        let request: EmbeddingRequest =
+            serde_json::from_value(serde_json::json!({"input": ["a", "b"], "model": "m"})).unwrap();
+        assert_eq!(request.model.as_deref(), Some("m"));
+        assert_eq!(request.input.into_texts(), vec!["a", "b"]);
+
+        assert!(
+            serde_json::from_value::<EmbeddingRequest>(serde_json::json!({"input": [[1, 2]]}))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn shorten_renormalizes() {
+        let embedding = vec![0.6, 0.0, 0.8];
+        assert_eq!(shorten(embedding.clone(), None), embedding);
+        assert_eq!(shorten(embedding.clone(), Some(8)), embedding);
+        assert_eq!(shorten(vec![3.0, 4.0, 1.0], Some(2)), vec![0.6, 0.8]);
+    }
+}
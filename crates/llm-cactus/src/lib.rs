@@ -1,7 +1,9 @@
+mod embed;
 mod error;
 mod manager;
 mod service;
 
+pub use embed::*;
 pub use error::*;
 pub use manager::{ModelLoader, ModelManager, ModelManagerBuilder};
 pub use service::*;
@@ -97,6 +97,25 @@ struct ChatCompletionRequest {
     max_tokens: Option<u32>,
     #[serde(default)]
     max_completion_tokens: Option<u32>,
+    #[serde(default)]
+    response_format: Option<ResponseFormat>,
+    /// Non-standard extension; takes precedence over `response_format`.
+    #[serde(default)]
+    grammar: Option<hypr_gbnf::Grammar>,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum ResponseFormat {
+    Text,
+    JsonObject,
+    JsonSchema { json_schema: JsonSchemaFormat },
+}
+
+#[derive(serde::Deserialize)]
+struct JsonSchemaFormat {
+    #[serde(default)]
+    schema: Option<serde_json::Value>,
 }
 
 #[derive(serde::Deserialize)]
@@ -121,10 +140,27 @@ fn build_options(request: &ChatCompletionRequest) -> hypr_cactus::CompleteOption
         temperature: request.temperature,
         top_p: request.top_p,
         max_tokens: request.max_completion_tokens.or(request.max_tokens),
+        grammar: build_grammar(request),
         ..Default::default()
     }
 }
 
+fn build_grammar(request: &ChatCompletionRequest) -> Option<String> {
+    if let Some(grammar) = &request.grammar {
+        return Some(grammar.build());
+    }
+
+    let schema = match request.response_format.as_ref()? {
+        ResponseFormat::Text => return None,
+        ResponseFormat::JsonObject => serde_json::json!({ "type": "object" }),
+        ResponseFormat::JsonSchema { json_schema } => json_schema
+            .schema
+            .clone()
+            .unwrap_or_else(|| serde_json::json!({ "type": "object" })),
+    };
+    Some(hypr_gbnf::build_json_schema_grammar(&schema))
+}
+
 fn model_name(model: &Option<String>) -> &str {
     model.as_deref().unwrap_or("cactus")
 }
@@ -294,3 +330,54 @@ async fn build_non_streaming_response(
 
     axum::Json(response).into_response()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(body: serde_json::Value) -> ChatCompletionRequest {
+        serde_json::from_value(body).unwrap()
+    }
+
+    #[test]
+    fn grammar_from_response_format() {
+        let request = parse(serde_json::json!({
+            "messages": [],
+            "response_format": {
+                "type": "json_schema",
+                "json_schema": {
+                    "name": "title",
+                    "strict": true,
+                    "schema": {
+                        "type": "object",
+                        "properties": {"title": {"type": "string"}},
+                        "required": ["title"]
+                    }
+                }
+            }
+        }));
+
+        let grammar = build_grammar(&request).unwrap();
+        assert!(grammar.starts_with(r#"root ::= "{" space root-title-kv "}" space"#));
+
+        let request = parse(serde_json::json!({
+            "messages": [],
+            "response_format": {"type": "text"}
+        }));
+        assert!(build_grammar(&request).is_none());
+    }
+
+    #[test]
+    fn explicit_grammar_wins() {
+        let request = parse(serde_json::json!({
+            "messages": [],
+            "response_format": {"type": "json_object"},
+            "grammar": {"task": "tags"}
+        }));
+
+        assert_eq!(
+            build_grammar(&request),
+            Some(hypr_gbnf::Grammar::Tags.build())
+        );
+    }
+}
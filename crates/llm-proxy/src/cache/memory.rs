@@ -0,0 +1,76 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use super::{CacheError, CacheStore, CachedResponse};
+use crate::usage::BoxFuture;
+
+const DEFAULT_MAX_ENTRIES: usize = 1_000;
+
+/// Process-local store with lazy expiry. When full, expired entries are
+/// dropped first, then the one closest to expiring.
+pub struct InMemoryCacheStore {
+    entries: Mutex<HashMap<String, (Instant, CachedResponse)>>,
+    max_entries: usize,
+}
+
+impl Default for InMemoryCacheStore {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_ENTRIES)
+    }
+}
+
+impl InMemoryCacheStore {
+    pub fn new(max_entries: usize) -> Self {
+        Self {
+            entries: Mutex::new(HashMap::new()),
+            max_entries: max_entries.max(1),
+        }
+    }
+}
+
+impl CacheStore for InMemoryCacheStore {
+    fn get<'a>(
+        &'a self,
+        key: &'a str,
+    ) -> BoxFuture<'a, Result<Option<CachedResponse>, CacheError>> {
+        Box::pin(async move {
+            let mut entries = self.entries.lock().unwrap();
+            match entries.get(key) {
+                Some((expires_at, _)) if *expires_at <= Instant::now() => {
+                    entries.remove(key);
+                    Ok(None)
+                }
+                Some((_, response)) => Ok(Some(response.clone())),
+                None => Ok(None),
+            }
+        })
+    }
+
+    fn put<'a>(
+        &'a self,
+        key: &'a str,
+        response: CachedResponse,
+        ttl: Duration,
+    ) -> BoxFuture<'a, Result<(), CacheError>> {
+        Box::pin(async move {
+            let now = Instant::now();
+            let mut entries = self.entries.lock().unwrap();
+
+            if entries.len() >= self.max_entries && !entries.contains_key(key) {
+                entries.retain(|_, (expires_at, _)| *expires_at > now);
+                if entries.len() >= self.max_entries
+                    && let Some(oldest) = entries
+                        .iter()
+                        .min_by_key(|(_, (expires_at, _))| *expires_at)
+                        .map(|(k, _)| k.clone())
+                {
+                    entries.remove(&oldest);
+                }
+            }
+
+            entries.insert(key.to_string(), (now + ttl, response));
+            Ok(())
+        })
+    }
+}
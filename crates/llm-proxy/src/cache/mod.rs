@@ -0,0 +1,365 @@
+mod memory;
+
+pub use memory::InMemoryCacheStore;
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use axum::{body::Body, http::StatusCode, response::Response};
+use bytes::Bytes;
+use sha2::{Digest, Sha256};
+use tokio::sync::watch;
+
+use crate::types::ChatCompletionRequest;
+use crate::usage::BoxFuture;
+
+pub const CACHE_STATUS_HEADER: &str = "x-cache";
+
+const DEFAULT_TTL: Duration = Duration::from_secs(10 * 60);
+
+/// Request fields that do not change what the model generates.
+const IGNORED_FIELDS: &[&str] = &["user", "metadata"];
+
+#[derive(Debug, thiserror::Error)]
+pub enum CacheError {
+    #[error("cache store error: {0}")]
+    Store(String),
+}
+
+/// A successful upstream response, as it was sent to the client. Streams keep
+/// their original chunking so replays are indistinguishable from the live
+/// response.
+#[derive(Debug, Clone)]
+pub struct CachedResponse {
+    pub stream: bool,
+    pub chunks: Vec<Bytes>,
+}
+
+impl CachedResponse {
+    pub(crate) fn into_response(self, cache_status: &'static str) -> Response {
+        let builder = Response::builder()
+            .status(StatusCode::OK)
+            .header(CACHE_STATUS_HEADER, cache_status);
+
+        if self.stream {
+            let chunks = self.chunks.into_iter().map(Ok::<_, std::io::Error>);
+            builder
+                .header("Content-Type", "text/event-stream")
+                .header("Cache-Control", "no-cache")
+                .body(Body::from_stream(futures_util::stream::iter(chunks)))
+                .unwrap()
+        } else {
+            builder
+                .header("Content-Type", "application/json")
+                .body(Body::from(self.chunks.concat()))
+                .unwrap()
+        }
+    }
+}
+
+pub trait CacheStore: Send + Sync {
+    fn get<'a>(&'a self, key: &'a str)
+    -> BoxFuture<'a, Result<Option<CachedResponse>, CacheError>>;
+
+    fn put<'a>(
+        &'a self,
+        key: &'a str,
+        response: CachedResponse,
+        ttl: Duration,
+    ) -> BoxFuture<'a, Result<(), CacheError>>;
+}
+
+/// `None` while the leading request is running; `Some(None)` if it finished
+/// without a cacheable response.
+type Flight = Option<Option<CachedResponse>>;
+
+/// Opt-in cache for completions with deterministic sampling. Identical
+/// requests made while one is already in flight wait for it instead of going
+/// upstream.
+#[derive(Clone)]
+pub struct ResponseCache {
+    store: Arc<dyn CacheStore>,
+    ttl: Duration,
+    inflight: Arc<Mutex<HashMap<String, watch::Receiver<Flight>>>>,
+}
+
+pub(crate) enum CacheLookup {
+    Hit(CachedResponse),
+    Miss(CacheRecorder),
+}
+
+impl ResponseCache {
+    pub fn new(store: Arc<dyn CacheStore>) -> Self {
+        Self {
+            store,
+            ttl: DEFAULT_TTL,
+            inflight: Arc::default(),
+        }
+    }
+
+    pub fn in_memory() -> Self {
+        Self::new(Arc::new(InMemoryCacheStore::default()))
+    }
+
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// Cache key for `request`, or `None` when its output is not
+    /// reproducible. Keys are scoped to the caller.
+    pub(crate) fn key(
+        &self,
+        request: &ChatCompletionRequest,
+        models: &[String],
+        user_id: Option<&str>,
+    ) -> Option<String> {
+        if !is_deterministic(request) {
+            return None;
+        }
+
+        let mut value = serde_json::to_value(request).ok()?;
+        let fields = value.as_object_mut()?;
+        for field in IGNORED_FIELDS {
+            fields.remove(*field);
+        }
+        fields.insert("models".into(), models.into());
+        fields.insert("stream".into(), request.stream.unwrap_or(false).into());
+        fields.insert("user_id".into(), user_id.into());
+
+        let mut canonical = String::new();
+        write_canonical(&value, &mut canonical);
+        Some(format!("{:x}", Sha256::digest(canonical.as_bytes())))
+    }
+
+    /// Returns the cached response for `key`, waiting for an identical
+    /// in-flight request if there is one. On a miss the caller becomes the
+    /// leader for `key` and must record its response.
+    pub(crate) async fn lookup(&self, key: String) -> CacheLookup {
+        loop {
+            match self.store.get(&key).await {
+                Ok(Some(response)) => return CacheLookup::Hit(response),
+                Ok(None) => {}
+                Err(e) => tracing::warn!(error = %e, "llm_cache_get_failed"),
+            }
+
+            let waiting = {
+                let mut inflight = self.inflight.lock().unwrap();
+                match inflight.get(&key) {
+                    Some(rx) => rx.clone(),
+                    None => {
+                        let (tx, rx) = watch::channel(None);
+                        inflight.insert(key.clone(), rx);
+                        return CacheLookup::Miss(CacheRecorder {
+                            cache: self.clone(),
+                            key,
+                            tx,
+                            chunks: Vec::new(),
+                        });
+                    }
+                }
+            };
+
+            if let Some(response) = wait_for_flight(waiting).await {
+                return CacheLookup::Hit(response);
+            }
+            // The leader failed or was cancelled; try to lead instead.
+        }
+    }
+}
+
+async fn wait_for_flight(mut rx: watch::Receiver<Flight>) -> Option<CachedResponse> {
+    rx.wait_for(Option::is_some)
+        .await
+        .ok()
+        .and_then(|flight| flight.clone().flatten())
+}
+
+/// Collects the response of the leading request for a key. Dropping it
+/// without calling [`CacheRecorder::finish`] releases waiting duplicates so
+/// they go upstream themselves.
+pub(crate) struct CacheRecorder {
+    cache: ResponseCache,
+    key: String,
+    tx: watch::Sender<Flight>,
+    chunks: Vec<Bytes>,
+}
+
+impl CacheRecorder {
+    pub(crate) fn push(&mut self, chunk: Bytes) {
+        self.chunks.push(chunk);
+    }
+
+    pub(crate) async fn finish(mut self, stream: bool) {
+        let response = CachedResponse {
+            stream,
+            chunks: std::mem::take(&mut self.chunks),
+        };
+
+        if let Err(e) = self
+            .cache
+            .store
+            .put(&self.key, response.clone(), self.cache.ttl)
+            .await
+        {
+            tracing::warn!(error = %e, "llm_cache_put_failed");
+        }
+        self.tx.send_replace(Some(Some(response)));
+    }
+}
+
+impl Drop for CacheRecorder {
+    fn drop(&mut self) {
+        self.cache.inflight.lock().unwrap().remove(&self.key);
+        self.tx.send_if_modified(|flight| {
+            if flight.is_some() {
+                return false;
+            }
+            *flight = Some(None);
+            true
+        });
+    }
+}
+
+/// Greedy decoding, or a fixed seed, gives reproducible output. Anything else
+/// is sampled and should not be replayed.
+fn is_deterministic(request: &ChatCompletionRequest) -> bool {
+    let seeded = request.extra.get("seed").is_some_and(|s| !s.is_null());
+    let multiple_choices = request
+        .extra
+        .get("n")
+        .and_then(|n| n.as_u64())
+        .is_some_and(|n| n > 1);
+
+    !multiple_choices && (seeded || request.temperature == Some(0.0))
+}
+
+/// Serializes `value` with object keys sorted, so equal requests hash equally
+/// regardless of field order.
+fn write_canonical(value: &serde_json::Value, out: &mut String) {
+    match value {
+        serde_json::Value::Object(map) => {
+            let mut entries: Vec<_> = map.iter().collect();
+            entries.sort_by(|a, b| a.0.cmp(b.0));
+            out.push('{');
+            for (i, (key, value)) in entries.into_iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                out.push_str(&serde_json::Value::String(key.clone()).to_string());
+                out.push(':');
+                write_canonical(value, out);
+            }
+            out.push('}');
+        }
+        serde_json::Value::Array(items) => {
+            out.push('[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_canonical(item, out);
+            }
+            out.push(']');
+        }
+        other => out.push_str(&other.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(body: serde_json::Value) -> ChatCompletionRequest {
+        serde_json::from_value(body).unwrap()
+    }
+
+    fn models() -> Vec<String> {
+        vec!["openai/gpt-4.1-nano".to_string()]
+    }
+
+    #[test]
+    fn key_normalizes_and_bypasses_sampling() {
+        let cache = ResponseCache::in_memory();
+        let a = request(serde_json::json!({
+            "messages": [{"role": "user", "content": "hi"}],
+            "temperature": 0.0,
+            "user": "a",
+        }));
+        let b = request(serde_json::json!({
+            "user": "b",
+            "temperature": 0,
+            "messages": [{"content": "hi", "role": "user"}],
+        }));
+        let key = cache.key(&a, &models(), Some("u1"));
+        assert!(key.is_some());
+        assert_eq!(key, cache.key(&b, &models(), Some("u1")));
+        assert_ne!(key, cache.key(&a, &models(), Some("u2")));
+        assert_ne!(key, cache.key(&a, &["other/model".to_string()], Some("u1")));
+
+        let sampled = request(serde_json::json!({
+            "messages": [{"role": "user", "content": "hi"}],
+            "temperature": 0.7,
+        }));
+        assert!(cache.key(&sampled, &models(), None).is_none());
+
+        let seeded = request(serde_json::json!({
+            "messages": [{"role": "user", "content": "hi"}],
+            "temperature": 0.7,
+            "seed": 42,
+        }));
+        assert!(cache.key(&seeded, &models(), None).is_some());
+
+        let default_sampling = request(serde_json::json!({
+            "messages": [{"role": "user", "content": "hi"}],
+        }));
+        assert!(cache.key(&default_sampling, &models(), None).is_none());
+    }
+
+    #[tokio::test]
+    async fn duplicates_wait_for_the_leader() {
+        let cache = ResponseCache::in_memory();
+
+        let CacheLookup::Miss(mut recorder) = cache.lookup("k".into()).await else {
+            panic!("expected miss");
+        };
+
+        let follower = tokio::spawn({
+            let cache = cache.clone();
+            async move { cache.lookup("k".into()).await }
+        });
+        tokio::task::yield_now().await;
+
+        recorder.push(Bytes::from_static(b"data: 1\n\n"));
+        recorder.push(Bytes::from_static(b"data: [DONE]\n\n"));
+        recorder.finish(true).await;
+
+        let CacheLookup::Hit(response) = follower.await.unwrap() else {
+            panic!("expected hit");
+        };
+        assert!(response.stream);
+        assert_eq!(response.chunks.len(), 2);
+        assert!(matches!(
+            cache.lookup("k".into()).await,
+            CacheLookup::Hit(_)
+        ));
+    }
+
+    #[tokio::test]
+    async fn failed_leader_hands_over() {
+        let cache = ResponseCache::in_memory();
+
+        let CacheLookup::Miss(recorder) = cache.lookup("k".into()).await else {
+            panic!("expected miss");
+        };
+        let follower = tokio::spawn({
+            let cache = cache.clone();
+            async move { cache.lookup("k".into()).await }
+        });
+        tokio::task::yield_now().await;
+        drop(recorder);
+
+        assert!(matches!(follower.await.unwrap(), CacheLookup::Miss(_)));
+    }
+}
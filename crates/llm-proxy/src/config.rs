@@ -2,9 +2,11 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use crate::analytics::AnalyticsReporter;
-use crate::env::ApiKey;
+use crate::cache::ResponseCache;
+use crate::env::{ApiKey, Env};
 use crate::model::{ModelContext, ModelResolver, StaticModelResolver};
-use crate::provider::{OpenRouterProvider, Provider};
+use crate::provider::{AnthropicProvider, OpenAIProvider, OpenRouterProvider, Provider};
+use crate::usage::UsageLedger;
 
 const DEFAULT_TIMEOUT_MS: u64 = 120_000;
 const DEFAULT_NUM_RETRIES: usize = 1;
@@ -25,6 +27,12 @@ impl Default for RetryConfig {
     }
 }
 
+#[derive(Clone)]
+pub struct ProviderRoute {
+    pub provider: Arc<dyn Provider>,
+    pub api_key: String,
+}
+
 #[derive(Clone)]
 pub struct LlmProxyConfig {
     pub api_key: String,
@@ -32,7 +40,12 @@ pub struct LlmProxyConfig {
     resolver: Arc<dyn ModelResolver>,
     pub analytics: Option<Arc<dyn AnalyticsReporter>>,
     pub provider: Arc<dyn Provider>,
+    /// Tried before `provider` for the models they serve. A route that fails
+    /// with a 5xx or times out falls through to the next one.
+    pub routes: Vec<ProviderRoute>,
     pub retry_config: RetryConfig,
+    pub usage: Option<UsageLedger>,
+    pub cache: Option<ResponseCache>,
 }
 
 impl LlmProxyConfig {
@@ -43,10 +56,25 @@ impl LlmProxyConfig {
             resolver: Arc::new(StaticModelResolver::default()),
             analytics: None,
             provider: Arc::new(OpenRouterProvider::default()),
+            routes: vec![],
             retry_config: RetryConfig::default(),
+            usage: None,
+            cache: None,
         }
     }
 
+    /// OpenRouter, preceded by the native providers that have a key set.
+    pub fn from_env(env: &Env) -> Self {
+        let mut config = Self::new(env);
+        if let Some(key) = &env.anthropic_api_key {
+            config = config.with_route(Arc::new(AnthropicProvider::default()), key.clone());
+        }
+        if let Some(key) = &env.openai_api_key {
+            config = config.with_route(Arc::new(OpenAIProvider::default()), key.clone());
+        }
+        config
+    }
+
     pub fn resolve(&self, ctx: &ModelContext) -> Vec<String> {
         self.resolver.resolve(ctx)
     }
@@ -66,11 +94,42 @@ impl LlmProxyConfig {
         self
     }
 
+    pub fn with_usage_ledger(mut self, ledger: UsageLedger) -> Self {
+        self.usage = Some(ledger);
+        self
+    }
+
+    pub fn with_response_cache(mut self, cache: ResponseCache) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
     pub fn with_provider(mut self, provider: Arc<dyn Provider>) -> Self {
         self.provider = provider;
         self
     }
 
+    pub fn with_route(mut self, provider: Arc<dyn Provider>, api_key: impl Into<String>) -> Self {
+        self.routes.push(ProviderRoute {
+            provider,
+            api_key: api_key.into(),
+        });
+        self
+    }
+
+    pub(crate) fn tracks_generations(&self) -> bool {
+        self.analytics.is_some() || self.usage.is_some()
+    }
+
+    pub(crate) fn all_routes(&self) -> Vec<ProviderRoute> {
+        let mut routes = self.routes.clone();
+        routes.push(ProviderRoute {
+            provider: self.provider.clone(),
+            api_key: self.api_key.clone(),
+        });
+        routes
+    }
+
     pub fn with_retry_config(mut self, retry_config: RetryConfig) -> Self {
         self.retry_config = retry_config;
         self
@@ -1,9 +1,83 @@
-pub use hypr_api_env::OpenRouterEnv as Env;
+use hypr_api_env::OpenRouterEnv;
+use serde::Deserialize;
+
+use crate::usage::{Budget, PlanBudget, UsagePlan};
+
+#[derive(Clone, Deserialize)]
+pub struct Env {
+    #[serde(flatten)]
+    pub openrouter: OpenRouterEnv,
+    #[serde(default, deserialize_with = "hypr_api_env::filter_empty")]
+    pub openai_api_key: Option<String>,
+    #[serde(default, deserialize_with = "hypr_api_env::filter_empty")]
+    pub anthropic_api_key: Option<String>,
+    #[serde(default, deserialize_with = "hypr_api_env::filter_empty")]
+    pub lago_api_key: Option<String>,
+    #[serde(flatten)]
+    pub budgets: BudgetEnv,
+}
+
+/// Per-plan token and cost budgets, e.g. `LLM_BUDGET_FREE_DAILY_TOKENS`.
+/// Unset limits are unlimited; a plan with none set is not budgeted.
+#[derive(Clone, Default, Deserialize)]
+pub struct BudgetEnv {
+    #[serde(default, deserialize_with = "hypr_api_env::string_to_optional")]
+    pub llm_budget_free_daily_tokens: Option<u64>,
+    #[serde(default, deserialize_with = "hypr_api_env::string_to_optional")]
+    pub llm_budget_free_daily_cost_usd: Option<f64>,
+    #[serde(default, deserialize_with = "hypr_api_env::string_to_optional")]
+    pub llm_budget_free_monthly_tokens: Option<u64>,
+    #[serde(default, deserialize_with = "hypr_api_env::string_to_optional")]
+    pub llm_budget_free_monthly_cost_usd: Option<f64>,
+    #[serde(default, deserialize_with = "hypr_api_env::string_to_optional")]
+    pub llm_budget_pro_daily_tokens: Option<u64>,
+    #[serde(default, deserialize_with = "hypr_api_env::string_to_optional")]
+    pub llm_budget_pro_daily_cost_usd: Option<f64>,
+    #[serde(default, deserialize_with = "hypr_api_env::string_to_optional")]
+    pub llm_budget_pro_monthly_tokens: Option<u64>,
+    #[serde(default, deserialize_with = "hypr_api_env::string_to_optional")]
+    pub llm_budget_pro_monthly_cost_usd: Option<f64>,
+}
+
+impl BudgetEnv {
+    pub fn plan_budget(&self, plan: UsagePlan) -> Option<PlanBudget> {
+        let budget = match plan {
+            UsagePlan::Free => PlanBudget {
+                daily: Budget {
+                    tokens: self.llm_budget_free_daily_tokens,
+                    cost_usd: self.llm_budget_free_daily_cost_usd,
+                },
+                monthly: Budget {
+                    tokens: self.llm_budget_free_monthly_tokens,
+                    cost_usd: self.llm_budget_free_monthly_cost_usd,
+                },
+            },
+            UsagePlan::Pro => PlanBudget {
+                daily: Budget {
+                    tokens: self.llm_budget_pro_daily_tokens,
+                    cost_usd: self.llm_budget_pro_daily_cost_usd,
+                },
+                monthly: Budget {
+                    tokens: self.llm_budget_pro_monthly_tokens,
+                    cost_usd: self.llm_budget_pro_monthly_cost_usd,
+                },
+            },
+        };
+
+        (budget != PlanBudget::default()).then_some(budget)
+    }
+}
 
 pub struct ApiKey(pub String);
 
 impl From<&Env> for ApiKey {
     fn from(env: &Env) -> Self {
+        Self::from(&env.openrouter)
+    }
+}
+
+impl From<&OpenRouterEnv> for ApiKey {
+    fn from(env: &OpenRouterEnv) -> Self {
         Self(env.openrouter_api_key.clone())
     }
 }
@@ -0,0 +1,155 @@
+use std::time::Instant;
+
+use axum::{
+    Extension, Json,
+    body::Body,
+    extract::State,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use serde::Deserialize;
+
+use crate::analytics::GenerationEvent;
+use crate::model::ModelContext;
+use crate::types::{EmbeddingRequest, UsageInfo};
+use crate::usage::UsagePlan;
+
+use super::{
+    AnalyticsContext, AppState, ProxyError, check_budget, send_request, spawn_generation_report,
+};
+
+#[derive(Debug, Deserialize)]
+struct EmbeddingResponse {
+    id: Option<String>,
+    model: Option<String>,
+    usage: Option<UsageInfo>,
+}
+
+/// Vectors from different models live in different spaces, so unlike chat
+/// completions this only falls back between routes serving the same model.
+/// A requested `model` is honored when it is one of the resolved ones.
+pub(super) async fn embeddings_handler(
+    State(state): State<AppState>,
+    analytics_ctx: AnalyticsContext,
+    plan: Option<Extension<UsagePlan>>,
+    Json(request): Json<EmbeddingRequest>,
+) -> Response {
+    let start_time = Instant::now();
+
+    let models = state.config.resolve(&ModelContext {
+        task: None,
+        needs_tool_calling: false,
+        has_audio: false,
+        needs_embedding: true,
+    });
+    let model = match &request.model {
+        Some(requested) => models.iter().find(|m| *m == requested),
+        None => models.first(),
+    };
+    let Some(model) = model.cloned() else {
+        return (StatusCode::BAD_REQUEST, "Embedding model not available").into_response();
+    };
+
+    let routes: Vec<_> = state
+        .config
+        .all_routes()
+        .into_iter()
+        .filter_map(|route| {
+            let url = route.provider.embeddings_url()?;
+            let name = route.provider.model_name(&model)?;
+            Some((route, url, name))
+        })
+        .collect();
+    if routes.is_empty() {
+        return (StatusCode::BAD_REQUEST, "Embedding model not available").into_response();
+    }
+
+    if let Some(exceeded) = check_budget(&state, &analytics_ctx, plan).await {
+        return exceeded;
+    }
+
+    tracing::info!(
+        model = %model,
+        route_count = %routes.len(),
+        provider = %routes[0].0.provider.name(),
+        "llm_embedding_request_received"
+    );
+
+    let route_count = routes.len();
+    for (i, (route, url, name)) in routes.into_iter().enumerate() {
+        let is_last = i + 1 == route_count;
+        let provider = route.provider.clone();
+
+        let mut body = match serde_json::to_value(&request) {
+            Ok(body) => body,
+            Err(e) => {
+                tracing::error!(error = %e, "failed_to_build_provider_request");
+                return (StatusCode::INTERNAL_SERVER_ERROR, "Invalid request").into_response();
+            }
+        };
+        body["model"] = serde_json::Value::String(name);
+
+        let response = match send_request(&state, &route, &url, &body).await {
+            Ok(resp) if resp.status().is_server_error() && !is_last => {
+                tracing::warn!(
+                    upstream_status = %resp.status().as_u16(),
+                    provider = %provider.name(),
+                    "llm_provider_fallback"
+                );
+                continue;
+            }
+            Ok(resp) => resp,
+            Err(_) if !is_last => {
+                tracing::warn!(provider = %provider.name(), "llm_provider_fallback");
+                continue;
+            }
+            Err(e) => return e.into_response(),
+        };
+
+        let status = response.status();
+        let http_status = status.as_u16();
+        let body_bytes = match response.bytes().await {
+            Ok(b) => b,
+            Err(e) => return ProxyError::BodyRead(e).into_response(),
+        };
+
+        tracing::info!(
+            http_status = %http_status,
+            latency_ms = %start_time.elapsed().as_millis(),
+            "llm_embedding_response_received"
+        );
+
+        if status.is_success()
+            && let Ok(parsed) = serde_json::from_slice::<EmbeddingResponse>(&body_bytes)
+        {
+            let event = GenerationEvent {
+                fingerprint: analytics_ctx.fingerprint,
+                user_id: analytics_ctx.user_id,
+                generation_id: parsed.id.unwrap_or_default(),
+                model: parsed.model.unwrap_or_else(|| model.clone()),
+                input_tokens: parsed.usage.as_ref().map(|u| u.input_tokens()).unwrap_or(0),
+                output_tokens: 0,
+                latency: start_time.elapsed().as_secs_f64(),
+                http_status,
+                total_cost: None,
+                provider_name: provider.name().to_string(),
+                base_url: url,
+            };
+            spawn_generation_report(
+                state.config.clone(),
+                provider,
+                state.client.clone(),
+                route.api_key,
+                event,
+            );
+        }
+
+        return Response::builder()
+            .status(status)
+            .header("Content-Type", "application/json")
+            .body(Body::from(body_bytes))
+            .unwrap();
+    }
+
+    unreachable!("routes is not empty")
+}
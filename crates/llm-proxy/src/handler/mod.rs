@@ -1,6 +1,8 @@
+mod embeddings;
 mod non_streaming;
 mod streaming;
 
+use embeddings::*;
 use non_streaming::*;
 use streaming::*;
 
@@ -9,43 +11,59 @@ use std::sync::Arc;
 use std::time::{Duration, Instant};
 
 use axum::{
-    Json, Router,
+    Extension, Json, Router,
     extract::{FromRequestParts, State},
     http::{StatusCode, request::Parts},
     response::{IntoResponse, Response},
-    routing::post,
+    routing::{get, post},
 };
 use backon::{ExponentialBuilder, Retryable};
+use chrono::Utc;
 use reqwest::Client;
 
-use crate::analytics::{AnalyticsReporter, GenerationEvent};
-use crate::config::LlmProxyConfig;
+use crate::analytics::GenerationEvent;
+use crate::cache::CacheLookup;
+use crate::config::{LlmProxyConfig, ProviderRoute};
 use crate::model::{CharTask, ModelContext};
 use crate::types::{ChatCompletionRequest, ToolChoice, has_audio_content};
+use crate::usage::UsagePlan;
 
-async fn report_with_cost(
-    analytics: &dyn AnalyticsReporter,
+/// Fetches the generation cost, then records it in the usage ledger and
+/// reports it to analytics.
+async fn report_generation(
+    config: &LlmProxyConfig,
     provider: &dyn crate::provider::Provider,
     client: &Client,
     api_key: &str,
     mut event: GenerationEvent,
 ) {
-    event.total_cost = provider
-        .fetch_cost(client, api_key, &event.generation_id)
-        .await;
-    analytics.report_generation(event).await;
+    if !config.tracks_generations() {
+        return;
+    }
+
+    if !event.generation_id.is_empty() {
+        event.total_cost = provider
+            .fetch_cost(client, api_key, &event.generation_id)
+            .await;
+    }
+    if let Some(usage) = &config.usage {
+        usage.record(&event, Utc::now()).await;
+    }
+    if let Some(analytics) = &config.analytics {
+        analytics.report_generation(event).await;
+    }
 }
 
-pub(super) fn spawn_analytics_report(
-    analytics: Option<Arc<dyn AnalyticsReporter>>,
+pub(super) fn spawn_generation_report(
+    config: LlmProxyConfig,
     provider: Arc<dyn crate::provider::Provider>,
     client: Client,
     api_key: String,
     event: GenerationEvent,
 ) {
-    if let Some(analytics) = analytics {
+    if config.tracks_generations() {
         tokio::spawn(async move {
-            report_with_cost(&*analytics, &*provider, &client, &api_key, event).await;
+            report_generation(&config, &*provider, &client, &api_key, event).await;
         });
     }
 }
@@ -125,6 +143,8 @@ pub fn router(config: LlmProxyConfig) -> Router {
     Router::new()
         .route("/", post(completions_handler))
         .route("/chat/completions", post(completions_handler))
+        .route("/embeddings", post(embeddings_handler))
+        .route("/usage", get(usage_handler))
         .with_state(state)
 }
 
@@ -168,9 +188,32 @@ where
     }
 }
 
+async fn usage_handler(
+    State(state): State<AppState>,
+    analytics_ctx: AnalyticsContext,
+    plan: Option<Extension<UsagePlan>>,
+) -> Response {
+    let Some(usage) = &state.config.usage else {
+        return StatusCode::NOT_FOUND.into_response();
+    };
+    let Some(user_id) = analytics_ctx.user_id else {
+        return StatusCode::UNAUTHORIZED.into_response();
+    };
+    let plan = plan.map(|Extension(plan)| plan).unwrap_or_default();
+
+    match usage.summary(&user_id, plan, Utc::now()).await {
+        Ok(summary) => Json(summary).into_response(),
+        Err(e) => {
+            tracing::error!(error = %e, "usage_summary_failed");
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
 async fn completions_handler(
     State(state): State<AppState>,
     analytics_ctx: AnalyticsContext,
+    plan: Option<Extension<UsagePlan>>,
     headers: axum::http::HeaderMap,
     Json(request): Json<ChatCompletionRequest>,
 ) -> Response {
@@ -189,25 +232,57 @@ async fn completions_handler(
         task,
         needs_tool_calling,
         has_audio,
+        needs_embedding: false,
     };
     let models = state.config.resolve(&ctx);
 
     let stream = request.stream.unwrap_or(false);
 
+    let cache_key = state
+        .config
+        .cache
+        .as_ref()
+        .filter(|_| !bypasses_cache(&headers))
+        .and_then(|cache| {
+            cache
+                .key(&request, &models, analytics_ctx.user_id.as_deref())
+                .map(|key| (cache, key))
+        });
+    let recorder = match cache_key {
+        Some((cache, key)) => match cache.lookup(key).await {
+            CacheLookup::Hit(cached) => {
+                tracing::info!(
+                    stream = %stream,
+                    task = ?task,
+                    latency_ms = %start_time.elapsed().as_millis(),
+                    "llm_cache_hit"
+                );
+                return cached.into_response("HIT");
+            }
+            CacheLookup::Miss(recorder) => Some(recorder),
+        },
+        None => None,
+    };
+
+    if let Some(exceeded) = check_budget(&state, &analytics_ctx, plan).await {
+        return exceeded;
+    }
+
+    let attempts = plan_attempts(&state.config.all_routes(), &models);
+
     tracing::info!(
         stream = %stream,
         has_tools = %needs_tool_calling,
         task = ?task,
         message_count = %request.messages.len(),
         model_count = %models.len(),
-        provider = %state.config.provider.name(),
+        attempt_count = %attempts.len(),
+        provider = %attempts[0].route.provider.name(),
         "llm_completion_request_received"
     );
 
-    let provider = &state.config.provider;
-
     sentry::configure_scope(|scope| {
-        scope.set_tag("llm.provider", provider.name());
+        scope.set_tag("llm.provider", attempts[0].route.provider.name());
         if let Some(model) = models.first() {
             scope.set_tag("llm.model", model);
         }
@@ -227,14 +302,150 @@ async fn completions_handler(
         scope.set_context("llm_request", sentry::protocol::Context::Other(ctx));
     });
 
-    let provider_request = match provider.build_request(&request, models, stream) {
-        Ok(req) => req,
-        Err(e) => {
-            tracing::error!(error = %e, "failed_to_build_provider_request");
-            return (StatusCode::INTERNAL_SERVER_ERROR, "Invalid request").into_response();
-        }
+    let attempt_count = attempts.len();
+    for (i, attempt) in attempts.into_iter().enumerate() {
+        let is_last = i + 1 == attempt_count;
+        let provider = attempt.route.provider.clone();
+
+        let provider_request = match provider.build_request(&request, attempt.models, stream) {
+            Ok(req) => req,
+            Err(e) if !is_last => {
+                tracing::warn!(error = %e, provider = %provider.name(), "llm_provider_skipped");
+                continue;
+            }
+            Err(e) => {
+                tracing::error!(error = %e, "failed_to_build_provider_request");
+                return (StatusCode::INTERNAL_SERVER_ERROR, "Invalid request").into_response();
+            }
+        };
+
+        let url = provider.base_url().to_string();
+        let response = match send_request(&state, &attempt.route, &url, &provider_request).await {
+            Ok(resp) if resp.status().is_server_error() && !is_last => {
+                tracing::warn!(
+                    upstream_status = %resp.status().as_u16(),
+                    provider = %provider.name(),
+                    "llm_provider_fallback"
+                );
+                continue;
+            }
+            Ok(resp) => resp,
+            Err(_) if !is_last => {
+                tracing::warn!(provider = %provider.name(), "llm_provider_fallback");
+                continue;
+            }
+            Err(e) => return e.into_response(),
+        };
+
+        // Error bodies are plain JSON even when a stream was requested.
+        return if stream && response.status().is_success() {
+            handle_stream_response(
+                state,
+                attempt.route,
+                response,
+                start_time,
+                analytics_ctx,
+                recorder,
+            )
+            .await
+        } else {
+            handle_non_stream_response(
+                state,
+                attempt.route,
+                response,
+                start_time,
+                analytics_ctx,
+                recorder,
+            )
+            .await
+        };
+    }
+
+    unreachable!("plan_attempts returns at least one attempt")
+}
+
+/// Rejects the request when the caller has used up their plan's budget.
+async fn check_budget(
+    state: &AppState,
+    analytics_ctx: &AnalyticsContext,
+    plan: Option<Extension<UsagePlan>>,
+) -> Option<Response> {
+    let (Some(usage), Some(user_id)) = (&state.config.usage, &analytics_ctx.user_id) else {
+        return None;
     };
+    let plan = plan.map(|Extension(plan)| plan).unwrap_or_default();
+    let exceeded = usage.check(user_id, plan, Utc::now()).await.err()?;
+    tracing::info!(
+        window = %exceeded.window,
+        limit = %exceeded.limit,
+        "llm_usage_budget_exceeded"
+    );
+    Some(exceeded.into_response())
+}
+
+/// Lets clients force a fresh generation with `Cache-Control: no-cache`.
+fn bypasses_cache(headers: &axum::http::HeaderMap) -> bool {
+    headers
+        .get(axum::http::header::CACHE_CONTROL)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|v| v.contains("no-cache") || v.contains("no-store"))
+}
 
+struct Attempt {
+    route: ProviderRoute,
+    models: Vec<String>,
+}
+
+/// Orders attempts by model priority, then by route. A route that can fall
+/// back between models itself gets a single attempt carrying all of its
+/// models.
+fn plan_attempts(routes: &[ProviderRoute], models: &[String]) -> Vec<Attempt> {
+    let mut attempts = Vec::new();
+    let mut grouped = vec![false; routes.len()];
+
+    for model in models {
+        for (i, route) in routes.iter().enumerate() {
+            let Some(name) = route.provider.model_name(model) else {
+                continue;
+            };
+
+            if !route.provider.supports_model_fallback() {
+                attempts.push(Attempt {
+                    route: route.clone(),
+                    models: vec![name],
+                });
+            } else if !grouped[i] {
+                grouped[i] = true;
+                attempts.push(Attempt {
+                    route: route.clone(),
+                    models: models
+                        .iter()
+                        .filter_map(|m| route.provider.model_name(m))
+                        .collect(),
+                });
+            }
+        }
+    }
+
+    if attempts.is_empty() {
+        // Nothing resolved; let the default route decide.
+        let route = routes.last().expect("default route").clone();
+        attempts.push(Attempt {
+            route,
+            models: models.to_vec(),
+        });
+    }
+
+    attempts
+}
+
+async fn send_request(
+    state: &AppState,
+    route: &ProviderRoute,
+    url: &str,
+    provider_request: &serde_json::Value,
+) -> Result<reqwest::Response, ProxyError> {
+    let provider = &route.provider;
     let retry_config = &state.config.retry_config;
     let backoff = ExponentialBuilder::default()
         .with_jitter()
@@ -245,18 +456,18 @@ async fn completions_handler(
         (|| async {
             let mut req_builder = state
                 .client
-                .post(provider.base_url())
+                .post(url)
                 .header("Content-Type", "application/json")
                 .header(
-                    "Authorization",
-                    provider.build_auth_header(&state.config.api_key),
+                    provider.auth_header_name(),
+                    provider.build_auth_header(&route.api_key),
                 );
 
             for (key, value) in provider.additional_headers() {
                 req_builder = req_builder.header(key, value);
             }
 
-            req_builder.json(&provider_request).send().await
+            req_builder.json(provider_request).send().await
         })
         .retry(backoff)
         .notify(|err, dur: Duration| {
@@ -272,15 +483,9 @@ async fn completions_handler(
     })
     .await;
 
-    let response = match result {
-        Ok(Ok(resp)) => resp,
-        Ok(Err(e)) => return ProxyError::UpstreamRequest(e).into_response(),
-        Err(_) => return ProxyError::Timeout.into_response(),
-    };
-
-    if stream {
-        handle_stream_response(state, response, start_time, analytics_ctx).await
-    } else {
-        handle_non_stream_response(state, response, start_time, analytics_ctx).await
+    match result {
+        Ok(Ok(resp)) => Ok(resp),
+        Ok(Err(e)) => Err(ProxyError::UpstreamRequest(e)),
+        Err(_) => Err(ProxyError::Timeout),
     }
 }
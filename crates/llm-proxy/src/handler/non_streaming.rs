@@ -7,14 +7,18 @@ use axum::{
 };
 
 use crate::analytics::GenerationEvent;
+use crate::cache::{CACHE_STATUS_HEADER, CacheRecorder};
+use crate::config::ProviderRoute;
 
-use super::{AnalyticsContext, AppState, ProxyError, spawn_analytics_report};
+use super::{AnalyticsContext, AppState, ProxyError, spawn_generation_report};
 
 pub(super) async fn handle_non_stream_response(
     state: AppState,
+    route: ProviderRoute,
     response: reqwest::Response,
     start_time: Instant,
     analytics_ctx: AnalyticsContext,
+    recorder: Option<CacheRecorder>,
 ) -> Response {
     let status = response.status();
     let http_status = status.as_u16();
@@ -36,7 +40,9 @@ pub(super) async fn handle_non_stream_response(
         Err(e) => return ProxyError::BodyRead(e).into_response(),
     };
 
-    if let Ok(metadata) = state.config.provider.parse_response(&body_bytes) {
+    let provider = route.provider;
+
+    if let Ok(metadata) = provider.parse_response(&body_bytes) {
         sentry::configure_scope(|scope| {
             let mut ctx = BTreeMap::new();
             ctx.insert(
@@ -63,22 +69,36 @@ pub(super) async fn handle_non_stream_response(
             latency: start_time.elapsed().as_secs_f64(),
             http_status,
             total_cost: None,
-            provider_name: state.config.provider.name().to_string(),
-            base_url: state.config.provider.base_url().to_string(),
+            provider_name: provider.name().to_string(),
+            base_url: provider.base_url().to_string(),
         };
 
-        spawn_analytics_report(
-            state.config.analytics.clone(),
-            state.config.provider.clone(),
+        spawn_generation_report(
+            state.config.clone(),
+            provider.clone(),
             state.client.clone(),
-            state.config.api_key.clone(),
+            route.api_key,
             event,
         );
     }
 
-    Response::builder()
+    let body_bytes = if status.is_success() {
+        provider.transform_response(body_bytes)
+    } else {
+        body_bytes
+    };
+
+    let mut builder = Response::builder()
         .status(status)
-        .header("Content-Type", "application/json")
-        .body(Body::from(body_bytes))
-        .unwrap()
+        .header("Content-Type", "application/json");
+    if let Some(mut recorder) = recorder {
+        builder = builder.header(CACHE_STATUS_HEADER, "MISS");
+        // Errors are not cached; dropping the recorder releases duplicates.
+        if status.is_success() {
+            recorder.push(body_bytes.clone());
+            recorder.finish(false).await;
+        }
+    }
+
+    builder.body(Body::from(body_bytes)).unwrap()
 }
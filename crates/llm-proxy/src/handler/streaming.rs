@@ -6,22 +6,28 @@ use axum::{body::Body, response::Response};
 use futures_util::StreamExt;
 
 use crate::analytics::GenerationEvent;
+use crate::cache::{CACHE_STATUS_HEADER, CacheRecorder};
+use crate::config::ProviderRoute;
 
-use super::{AnalyticsContext, AppState, report_with_cost};
+use super::{AnalyticsContext, AppState, report_generation};
 
 pub(super) async fn handle_stream_response(
     state: AppState,
+    route: ProviderRoute,
     response: reqwest::Response,
     start_time: Instant,
     analytics_ctx: AnalyticsContext,
+    mut recorder: Option<CacheRecorder>,
 ) -> Response {
     let status = response.status();
     let http_status = status.as_u16();
     let latency_ms = start_time.elapsed().as_millis();
-    let analytics = state.config.analytics.clone();
-    let api_key = state.config.api_key.clone();
+    let config = state.config.clone();
+    let tracks_generations = config.tracks_generations();
+    let api_key = route.api_key;
     let client = state.client.clone();
-    let provider = state.config.provider.clone();
+    let provider = route.provider;
+    let mut translator = provider.stream_translator();
 
     tracing::info!(
         http_status = %http_status,
@@ -40,6 +46,7 @@ pub(super) async fn handle_stream_response(
     });
 
     let upstream = response.bytes_stream();
+    let cache_status = recorder.as_ref().map(|_| "MISS");
 
     let output_stream = stream! {
         let mut accumulator = crate::provider::StreamAccumulator::new();
@@ -49,19 +56,34 @@ pub(super) async fn handle_stream_response(
         while let Some(chunk_result) = upstream.next().await {
             match chunk_result {
                 Ok(chunk) => {
-                    if analytics.is_some() {
+                    if tracks_generations {
                         provider.parse_stream_chunk(&chunk, &mut accumulator);
                     }
-                    yield Ok::<_, std::io::Error>(chunk);
+                    let chunk = match translator.as_mut() {
+                        Some(translator) => translator.translate(&chunk),
+                        None => chunk,
+                    };
+                    if !chunk.is_empty() {
+                        if let Some(recorder) = recorder.as_mut() {
+                            recorder.push(chunk.clone());
+                        }
+                        yield Ok::<_, std::io::Error>(chunk);
+                    }
                 }
                 Err(e) => {
+                    // Drop the partial recording so waiting duplicates retry.
+                    recorder = None;
                     yield Err(std::io::Error::other(e));
                     break;
                 }
             }
         }
 
-        if let Some(analytics) = analytics
+        if let Some(recorder) = recorder {
+            recorder.finish(true).await;
+        }
+
+        if tracks_generations
             && let Some(generation_id) = accumulator.generation_id {
                 let event = GenerationEvent {
                     fingerprint: analytics_ctx.fingerprint,
@@ -76,15 +98,17 @@ pub(super) async fn handle_stream_response(
                     provider_name: provider.name().to_string(),
                     base_url: provider.base_url().to_string(),
                 };
-                report_with_cost(&*analytics, &*provider, &client, &api_key, event).await;
+                report_generation(&config, &*provider, &client, &api_key, event).await;
             }
     };
 
     let body = Body::from_stream(output_stream);
-    Response::builder()
+    let mut builder = Response::builder()
         .status(status)
         .header("Content-Type", "text/event-stream")
-        .header("Cache-Control", "no-cache")
-        .body(body)
-        .unwrap()
+        .header("Cache-Control", "no-cache");
+    if let Some(cache_status) = cache_status {
+        builder = builder.header(CACHE_STATUS_HEADER, cache_status);
+    }
+    builder.body(body).unwrap()
 }
@@ -1,4 +1,5 @@
 mod analytics;
+pub mod cache;
 mod config;
 mod env;
 mod handler;
@@ -6,16 +7,22 @@ pub mod model;
 mod openapi;
 pub mod provider;
 mod types;
+pub mod usage;
 
 pub const CHAR_TASK_HEADER: &str = "x-char-task";
 
 pub use analytics::{AnalyticsReporter, GenerationEvent};
+pub use cache::{CacheStore, CachedResponse, InMemoryCacheStore, ResponseCache};
 pub use config::*;
-pub use env::{ApiKey, Env};
+pub use env::{ApiKey, BudgetEnv, Env};
 pub use handler::{chat_completions_router, router};
 pub use hypr_analytics::{AuthenticatedUserId, DeviceFingerprint};
 pub use model::{
-    CharTask, MODEL_KEY_AUDIO, MODEL_KEY_DEFAULT, MODEL_KEY_TOOL_CALLING, ModelContext,
-    ModelResolver, StaticModelResolver,
+    CharTask, MODEL_KEY_AUDIO, MODEL_KEY_DEFAULT, MODEL_KEY_EMBEDDING, MODEL_KEY_TOOL_CALLING,
+    ModelContext, ModelResolver, StaticModelResolver,
 };
 pub use openapi::openapi;
+pub use usage::{
+    Budget, InMemoryUsageStore, LagoMeter, PlanBudget, SupabaseUsageStore, UsageLedger, UsageMeter,
+    UsagePlan, UsageStore,
+};
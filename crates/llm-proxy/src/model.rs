@@ -6,6 +6,7 @@ use utoipa::ToSchema;
 pub const MODEL_KEY_DEFAULT: &str = "default";
 pub const MODEL_KEY_TOOL_CALLING: &str = "tool_calling";
 pub const MODEL_KEY_AUDIO: &str = "audio";
+pub const MODEL_KEY_EMBEDDING: &str = "embedding";
 
 #[derive(
     Debug,
@@ -25,6 +26,7 @@ pub const MODEL_KEY_AUDIO: &str = "audio";
 pub enum CharTask {
     Chat,
     Enhance,
+    Extract,
     Title,
 }
 
@@ -32,6 +34,7 @@ pub struct ModelContext {
     pub task: Option<CharTask>,
     pub needs_tool_calling: bool,
     pub has_audio: bool,
+    pub needs_embedding: bool,
 }
 
 pub trait ModelResolver: Send + Sync {
@@ -63,6 +66,14 @@ impl Default for StaticModelResolver {
                 "z-ai/glm-4.7-flash".into(),
             ],
         );
+        models.insert(
+            CharTask::Extract.to_string(),
+            vec![
+                "openai/gpt-5.2-chat".into(),
+                "anthropic/claude-haiku-4.5".into(),
+                "google/gemini-2.5-flash".into(),
+            ],
+        );
         models.insert(
             MODEL_KEY_TOOL_CALLING.to_owned(),
             vec![
@@ -86,6 +97,10 @@ impl Default for StaticModelResolver {
                 "mistralai/voxtral-small-24b-2507".into(),
             ],
         );
+        models.insert(
+            MODEL_KEY_EMBEDDING.to_owned(),
+            vec!["openai/text-embedding-3-small".into()],
+        );
 
         Self { models }
     }
@@ -100,10 +115,19 @@ impl StaticModelResolver {
 
 impl ModelResolver for StaticModelResolver {
     fn resolve(&self, ctx: &ModelContext) -> Vec<String> {
-        if ctx.has_audio {
-            if let Some(models) = self.models.get(MODEL_KEY_AUDIO) {
-                return models.clone();
-            }
+        // Embedding models are never interchangeable with chat models.
+        if ctx.needs_embedding {
+            return self
+                .models
+                .get(MODEL_KEY_EMBEDDING)
+                .cloned()
+                .unwrap_or_default();
+        }
+
+        if ctx.has_audio
+            && let Some(models) = self.models.get(MODEL_KEY_AUDIO)
+        {
+            return models.clone();
         }
 
         if let Some(models) = ctx.task.and_then(|t| self.models.get(&t.to_string())) {
@@ -158,6 +182,18 @@ mod tests {
                     "z-ai/glm-5",
                 ],
             ),
+            (
+                "extract_task",
+                Some(CharTask::Extract),
+                false,
+                false,
+                None,
+                &[
+                    "openai/gpt-5.2-chat",
+                    "anthropic/claude-haiku-4.5",
+                    "google/gemini-2.5-flash",
+                ],
+            ),
             (
                 "by_tool_calling",
                 None,
@@ -251,9 +287,28 @@ mod tests {
                     task: *task,
                     needs_tool_calling: *needs_tool_calling,
                     has_audio: *has_audio,
+                    needs_embedding: false,
                 },
                 expected,
             );
         }
     }
+
+    #[test]
+    fn resolve_embedding() {
+        let ctx = ModelContext {
+            task: Some(CharTask::Chat),
+            needs_tool_calling: false,
+            has_audio: false,
+            needs_embedding: true,
+        };
+        assert_eq!(
+            StaticModelResolver::default().resolve(&ctx),
+            vec!["openai/text-embedding-3-small".to_string()]
+        );
+
+        let mut resolver = StaticModelResolver::default();
+        resolver.models.remove(MODEL_KEY_EMBEDDING);
+        assert!(resolver.resolve(&ctx).is_empty());
+    }
 }
@@ -1,6 +1,7 @@
 use utoipa::OpenApi;
 
 use crate::model::CharTask;
+use crate::usage::{Budget, Usage, UsagePlan, UsageSummary, UsageWindow, WindowUsage};
 
 #[utoipa::path(
     post,
@@ -12,7 +13,7 @@ use crate::model::CharTask;
     responses(
         (status = 200, description = "Chat completion response (streaming or non-streaming)"),
         (status = 401, description = "Unauthorized"),
-        (status = 429, description = "Rate limit exceeded"),
+        (status = 429, description = "Rate limit or usage budget exceeded"),
         (status = 502, description = "Upstream provider failed"),
         (status = 504, description = "Request timeout"),
     ),
@@ -20,11 +21,48 @@ use crate::model::CharTask;
 )]
 async fn _chat_completions_spec() {}
 
+#[utoipa::path(
+    post,
+    path = "/llm/embeddings",
+    operation_id = "llm_embeddings",
+    responses(
+        (status = 200, description = "OpenAI-compatible embeddings response"),
+        (status = 400, description = "Embedding model not available"),
+        (status = 401, description = "Unauthorized"),
+        (status = 429, description = "Rate limit or usage budget exceeded"),
+        (status = 502, description = "Upstream provider failed"),
+        (status = 504, description = "Request timeout"),
+    ),
+    tag = "llm",
+)]
+async fn _embeddings_spec() {}
+
+#[utoipa::path(
+    get,
+    path = "/llm/usage",
+    operation_id = "llm_usage",
+    responses(
+        (status = 200, description = "Token and cost usage of the caller", body = UsageSummary),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Usage ledger not configured"),
+    ),
+    tag = "llm",
+)]
+async fn _usage_spec() {}
+
 #[derive(OpenApi)]
 #[openapi(
-    paths(_chat_completions_spec),
-    components(schemas(CharTask)),
-    tags((name = "llm", description = "LLM chat completions proxy"))
+    paths(_chat_completions_spec, _embeddings_spec, _usage_spec),
+    components(schemas(
+        CharTask,
+        UsageSummary,
+        WindowUsage,
+        Usage,
+        Budget,
+        UsagePlan,
+        UsageWindow
+    )),
+    tags((name = "llm", description = "LLM chat completions and embeddings proxy"))
 )]
 pub struct ApiDoc;
 
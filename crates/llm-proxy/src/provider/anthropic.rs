@@ -0,0 +1,716 @@
+use std::collections::HashMap;
+
+use bytes::Bytes;
+use serde::Deserialize;
+use serde_json::{Value, json};
+
+use crate::types::{ChatCompletionRequest, ChatMessage, Role, ToolChoice};
+
+use super::{GenerationMetadata, Provider, ProviderError, StreamAccumulator, StreamTranslator};
+
+pub const ANTHROPIC_URL: &str = "https://api.anthropic.com/v1/messages";
+pub const ANTHROPIC_VERSION: &str = "2023-06-01";
+const ANTHROPIC_MODEL_PREFIX: &str = "anthropic/";
+// The Messages API requires `max_tokens`; Chat Completions doesn't.
+const DEFAULT_MAX_TOKENS: u32 = 4096;
+
+/// The Anthropic Messages API, translated to and from Chat Completions.
+/// Serves `anthropic/*` models, e.g. `anthropic/claude-haiku-4.5` is sent as
+/// `claude-haiku-4-5`.
+pub struct AnthropicProvider {
+    pub base_url: String,
+}
+
+impl Default for AnthropicProvider {
+    fn default() -> Self {
+        Self {
+            base_url: ANTHROPIC_URL.to_string(),
+        }
+    }
+}
+
+impl AnthropicProvider {
+    pub fn new(base_url: impl Into<String>) -> Self {
+        Self {
+            base_url: base_url.into(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct MessagesResponse {
+    id: String,
+    model: Option<String>,
+    #[serde(default)]
+    content: Vec<ContentBlock>,
+    stop_reason: Option<String>,
+    #[serde(default)]
+    usage: MessagesUsage,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum ContentBlock {
+    Text {
+        text: String,
+    },
+    ToolUse {
+        id: String,
+        name: String,
+        input: Value,
+    },
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct MessagesUsage {
+    #[serde(default)]
+    input_tokens: u32,
+    #[serde(default)]
+    output_tokens: u32,
+    #[serde(default)]
+    cache_creation_input_tokens: u32,
+    #[serde(default)]
+    cache_read_input_tokens: u32,
+}
+
+impl MessagesUsage {
+    fn prompt_tokens(&self) -> u32 {
+        self.input_tokens + self.cache_creation_input_tokens + self.cache_read_input_tokens
+    }
+}
+
+impl Provider for AnthropicProvider {
+    fn name(&self) -> &str {
+        "anthropic"
+    }
+
+    fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    fn model_name(&self, model: &str) -> Option<String> {
+        model
+            .strip_prefix(ANTHROPIC_MODEL_PREFIX)
+            .map(|m| m.replace('.', "-"))
+    }
+
+    fn build_request(
+        &self,
+        request: &ChatCompletionRequest,
+        models: Vec<String>,
+        stream: bool,
+    ) -> Result<Value, ProviderError> {
+        let model = models
+            .into_iter()
+            .next()
+            .ok_or_else(|| ProviderError::InvalidRequest("no model".to_string()))?;
+
+        let mut system = Vec::new();
+        let mut messages: Vec<Value> = Vec::new();
+
+        for message in &request.messages {
+            let (role, blocks) = match message.role {
+                Role::System => {
+                    system.push(content_text(message.content.as_ref()));
+                    continue;
+                }
+                Role::User => ("user", content_blocks(message.content.as_ref())?),
+                Role::Assistant => {
+                    let mut blocks = content_blocks(message.content.as_ref())?;
+                    blocks.extend(tool_use_blocks(message)?);
+                    ("assistant", blocks)
+                }
+                Role::Tool => ("user", vec![tool_result_block(message)?]),
+            };
+
+            if blocks.is_empty() {
+                continue;
+            }
+
+            // Roles have to alternate, so consecutive turns (e.g. several tool
+            // results) are merged.
+            match messages.last_mut() {
+                Some(last) if last["role"] == role => {
+                    last["content"].as_array_mut().unwrap().extend(blocks);
+                }
+                _ => messages.push(json!({ "role": role, "content": blocks })),
+            }
+        }
+
+        let max_tokens = request
+            .max_tokens
+            .or_else(|| {
+                request
+                    .extra
+                    .get("max_completion_tokens")
+                    .and_then(|v| v.as_u64())
+                    .map(|v| v as u32)
+            })
+            .unwrap_or(DEFAULT_MAX_TOKENS);
+
+        let mut body = json!({
+            "model": model,
+            "messages": messages,
+            "max_tokens": max_tokens,
+            "stream": stream,
+        });
+        let obj = body.as_object_mut().unwrap();
+
+        if !system.is_empty() {
+            obj.insert("system".to_string(), Value::String(system.join("\n\n")));
+        }
+        if let Some(temperature) = request.temperature {
+            obj.insert("temperature".to_string(), json!(temperature.min(1.0)));
+        }
+        if let Some(top_p) = request.extra.get("top_p") {
+            obj.insert("top_p".to_string(), top_p.clone());
+        }
+        match request.extra.get("stop") {
+            Some(Value::String(stop)) => {
+                obj.insert("stop_sequences".to_string(), json!([stop]));
+            }
+            Some(stop @ Value::Array(_)) => {
+                obj.insert("stop_sequences".to_string(), stop.clone());
+            }
+            _ => {}
+        }
+
+        if let Some(tools) = request.tools.as_ref().filter(|t| !t.is_empty()) {
+            let tools: Vec<Value> = tools
+                .iter()
+                .filter_map(|tool| {
+                    let function = tool.get("function")?;
+                    let mut out = json!({
+                        "name": function.get("name")?,
+                        "input_schema": function
+                            .get("parameters")
+                            .cloned()
+                            .unwrap_or_else(|| json!({ "type": "object", "properties": {} })),
+                    });
+                    if let Some(description) = function.get("description") {
+                        out["description"] = description.clone();
+                    }
+                    Some(out)
+                })
+                .collect();
+            obj.insert("tools".to_string(), Value::Array(tools));
+        }
+
+        if let Some(tool_choice) = &request.tool_choice {
+            let tool_choice = match tool_choice {
+                ToolChoice::String(s) if s == "required" => json!({ "type": "any" }),
+                ToolChoice::String(s) if s == "none" => json!({ "type": "none" }),
+                ToolChoice::String(_) => json!({ "type": "auto" }),
+                ToolChoice::Object { function, .. } => {
+                    json!({ "type": "tool", "name": function.get("name") })
+                }
+            };
+            obj.insert("tool_choice".to_string(), tool_choice);
+        }
+
+        Ok(body)
+    }
+
+    fn parse_response(&self, body: &[u8]) -> Result<GenerationMetadata, ProviderError> {
+        let parsed: MessagesResponse =
+            serde_json::from_slice(body).map_err(|e| ProviderError::ParseError(e.to_string()))?;
+
+        Ok(GenerationMetadata {
+            generation_id: parsed.id,
+            model: parsed.model,
+            input_tokens: parsed.usage.prompt_tokens(),
+            output_tokens: parsed.usage.output_tokens,
+        })
+    }
+
+    fn parse_stream_chunk(&self, chunk: &[u8], accumulator: &mut StreamAccumulator) {
+        let Ok(text) = std::str::from_utf8(chunk) else {
+            return;
+        };
+
+        for line in text.lines() {
+            let Some(data) = line.strip_prefix("data: ") else {
+                continue;
+            };
+            let Ok(event) = serde_json::from_str::<Value>(data) else {
+                continue;
+            };
+
+            match event.get("type").and_then(|v| v.as_str()) {
+                Some("message_start") => {
+                    let message = &event["message"];
+                    accumulator.generation_id = message["id"].as_str().map(String::from);
+                    accumulator.model = message["model"].as_str().map(String::from);
+                    if let Ok(usage) = MessagesUsage::deserialize(&message["usage"]) {
+                        accumulator.input_tokens = usage.prompt_tokens();
+                    }
+                }
+                Some("message_delta") => {
+                    if let Some(output_tokens) = event["usage"]["output_tokens"].as_u64() {
+                        accumulator.output_tokens = output_tokens as u32;
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn transform_response(&self, body: Bytes) -> Bytes {
+        let Ok(parsed) = serde_json::from_slice::<MessagesResponse>(&body) else {
+            return body;
+        };
+
+        let mut text = String::new();
+        let mut tool_calls = Vec::new();
+        for block in parsed.content {
+            match block {
+                ContentBlock::Text { text: t } => text.push_str(&t),
+                ContentBlock::ToolUse { id, name, input } => tool_calls.push(json!({
+                    "id": id,
+                    "type": "function",
+                    "function": { "name": name, "arguments": input.to_string() },
+                })),
+                ContentBlock::Other => {}
+            }
+        }
+
+        let mut message = json!({
+            "role": "assistant",
+            "content": if text.is_empty() && !tool_calls.is_empty() { Value::Null } else { Value::String(text) },
+        });
+        if !tool_calls.is_empty() {
+            message["tool_calls"] = Value::Array(tool_calls);
+        }
+
+        let response = json!({
+            "id": parsed.id,
+            "object": "chat.completion",
+            "created": unix_now(),
+            "model": parsed.model,
+            "choices": [{
+                "index": 0,
+                "message": message,
+                "finish_reason": parsed.stop_reason.as_deref().map(finish_reason),
+            }],
+            "usage": usage_json(parsed.usage.prompt_tokens(), parsed.usage.output_tokens),
+        });
+
+        Bytes::from(response.to_string())
+    }
+
+    fn stream_translator(&self) -> Option<Box<dyn StreamTranslator>> {
+        Some(Box::new(AnthropicStreamTranslator::default()))
+    }
+
+    fn auth_header_name(&self) -> &str {
+        "x-api-key"
+    }
+
+    fn build_auth_header(&self, api_key: &str) -> String {
+        api_key.to_string()
+    }
+
+    fn additional_headers(&self) -> Vec<(String, String)> {
+        vec![(
+            "anthropic-version".to_string(),
+            ANTHROPIC_VERSION.to_string(),
+        )]
+    }
+}
+
+#[derive(Default)]
+struct AnthropicStreamTranslator {
+    buffer: Vec<u8>,
+    id: String,
+    model: String,
+    created: u64,
+    input_tokens: u32,
+    // Content block index -> position in the `tool_calls` delta array.
+    tool_calls: HashMap<u64, usize>,
+}
+
+impl StreamTranslator for AnthropicStreamTranslator {
+    fn translate(&mut self, chunk: &[u8]) -> Bytes {
+        self.buffer.extend_from_slice(chunk);
+
+        let mut out = String::new();
+        while let Some(pos) = self.buffer.iter().position(|b| *b == b'\n') {
+            let line: Vec<u8> = self.buffer.drain(..=pos).collect();
+            let line = String::from_utf8_lossy(&line);
+            let Some(data) = line.trim_end().strip_prefix("data:") else {
+                continue;
+            };
+            if let Ok(event) = serde_json::from_str::<Value>(data.trim_start()) {
+                self.translate_event(&event, &mut out);
+            }
+        }
+
+        Bytes::from(out)
+    }
+}
+
+impl AnthropicStreamTranslator {
+    fn translate_event(&mut self, event: &Value, out: &mut String) {
+        match event["type"].as_str() {
+            Some("message_start") => {
+                let message = &event["message"];
+                self.id = message["id"].as_str().unwrap_or_default().to_string();
+                self.model = message["model"].as_str().unwrap_or_default().to_string();
+                self.created = unix_now();
+                if let Ok(usage) = MessagesUsage::deserialize(&message["usage"]) {
+                    self.input_tokens = usage.prompt_tokens();
+                }
+                self.emit(
+                    out,
+                    json!({ "role": "assistant", "content": "" }),
+                    None,
+                    None,
+                );
+            }
+            Some("content_block_start") => {
+                let block = &event["content_block"];
+                match block["type"].as_str() {
+                    Some("tool_use") => {
+                        let index = self.tool_calls.len();
+                        self.tool_calls
+                            .insert(event["index"].as_u64().unwrap_or_default(), index);
+                        let delta = json!({ "tool_calls": [{
+                            "index": index,
+                            "id": block["id"],
+                            "type": "function",
+                            "function": { "name": block["name"], "arguments": "" },
+                        }] });
+                        self.emit(out, delta, None, None);
+                    }
+                    Some("text") => {
+                        if let Some(text) = block["text"].as_str().filter(|t| !t.is_empty()) {
+                            self.emit(out, json!({ "content": text }), None, None);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            Some("content_block_delta") => {
+                let delta = &event["delta"];
+                match delta["type"].as_str() {
+                    Some("text_delta") => {
+                        self.emit(out, json!({ "content": delta["text"] }), None, None);
+                    }
+                    Some("input_json_delta") => {
+                        let block_index = event["index"].as_u64().unwrap_or_default();
+                        if let Some(index) = self.tool_calls.get(&block_index).copied() {
+                            let delta = json!({ "tool_calls": [{
+                                "index": index,
+                                "function": { "arguments": delta["partial_json"] },
+                            }] });
+                            self.emit(out, delta, None, None);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            Some("message_delta") => {
+                let reason = event["delta"]["stop_reason"].as_str().map(finish_reason);
+                let output_tokens = event["usage"]["output_tokens"].as_u64().unwrap_or(0) as u32;
+                let usage = usage_json(self.input_tokens, output_tokens);
+                self.emit(out, json!({}), reason, Some(usage));
+            }
+            Some("message_stop") => out.push_str("data: [DONE]\n\n"),
+            Some("error") => {
+                out.push_str(&format!("data: {}\n\n", json!({ "error": event["error"] })));
+            }
+            _ => {}
+        }
+    }
+
+    fn emit(
+        &self,
+        out: &mut String,
+        delta: Value,
+        finish_reason: Option<&str>,
+        usage: Option<Value>,
+    ) {
+        let mut chunk = json!({
+            "id": self.id,
+            "object": "chat.completion.chunk",
+            "created": self.created,
+            "model": self.model,
+            "choices": [{ "index": 0, "delta": delta, "finish_reason": finish_reason }],
+        });
+        if let Some(usage) = usage {
+            chunk["usage"] = usage;
+        }
+        out.push_str(&format!("data: {}\n\n", chunk));
+    }
+}
+
+fn content_text(content: Option<&Value>) -> String {
+    match content {
+        Some(Value::String(s)) => s.clone(),
+        Some(Value::Array(parts)) => parts
+            .iter()
+            .filter_map(|p| p.get("text").and_then(|t| t.as_str()))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        _ => String::new(),
+    }
+}
+
+fn content_blocks(content: Option<&Value>) -> Result<Vec<Value>, ProviderError> {
+    match content {
+        Some(Value::String(s)) if !s.is_empty() => Ok(vec![json!({ "type": "text", "text": s })]),
+        Some(Value::Array(parts)) => parts.iter().map(content_part).collect(),
+        _ => Ok(vec![]),
+    }
+}
+
+fn content_part(part: &Value) -> Result<Value, ProviderError> {
+    match part.get("type").and_then(|t| t.as_str()) {
+        Some("text") => Ok(json!({ "type": "text", "text": part["text"] })),
+        Some("image_url") => {
+            let url = part["image_url"]
+                .get("url")
+                .unwrap_or(&part["image_url"])
+                .as_str()
+                .unwrap_or_default();
+
+            let source = match url
+                .strip_prefix("data:")
+                .and_then(|rest| rest.split_once(";base64,"))
+            {
+                Some((media_type, data)) => {
+                    json!({ "type": "base64", "media_type": media_type, "data": data })
+                }
+                None => json!({ "type": "url", "url": url }),
+            };
+            Ok(json!({ "type": "image", "source": source }))
+        }
+        other => Err(ProviderError::InvalidRequest(format!(
+            "unsupported content part: {}",
+            other.unwrap_or("unknown")
+        ))),
+    }
+}
+
+fn tool_use_blocks(message: &ChatMessage) -> Result<Vec<Value>, ProviderError> {
+    let Some(tool_calls) = message.extra.get("tool_calls").and_then(|v| v.as_array()) else {
+        return Ok(vec![]);
+    };
+
+    tool_calls
+        .iter()
+        .map(|call| {
+            let function = &call["function"];
+            let input = match function["arguments"].as_str() {
+                Some(args) if !args.trim().is_empty() => serde_json::from_str(args)
+                    .map_err(|e| ProviderError::InvalidRequest(e.to_string()))?,
+                _ => json!({}),
+            };
+            Ok(json!({
+                "type": "tool_use",
+                "id": call["id"],
+                "name": function["name"],
+                "input": input,
+            }))
+        })
+        .collect()
+}
+
+fn tool_result_block(message: &ChatMessage) -> Result<Value, ProviderError> {
+    let tool_use_id = message
+        .extra
+        .get("tool_call_id")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| ProviderError::InvalidRequest("tool message without tool_call_id".into()))?;
+
+    Ok(json!({
+        "type": "tool_result",
+        "tool_use_id": tool_use_id,
+        "content": content_text(message.content.as_ref()),
+    }))
+}
+
+fn finish_reason(stop_reason: &str) -> &'static str {
+    match stop_reason {
+        "max_tokens" => "length",
+        "tool_use" => "tool_calls",
+        "refusal" => "content_filter",
+        _ => "stop",
+    }
+}
+
+fn usage_json(prompt_tokens: u32, completion_tokens: u32) -> Value {
+    json!({
+        "prompt_tokens": prompt_tokens,
+        "completion_tokens": completion_tokens,
+        "total_tokens": prompt_tokens + completion_tokens,
+    })
+}
+
+fn unix_now() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(body: Value) -> ChatCompletionRequest {
+        serde_json::from_value(body).unwrap()
+    }
+
+    #[test]
+    fn model_name() {
+        let provider = AnthropicProvider::default();
+
+        assert_eq!(
+            provider.model_name("anthropic/claude-haiku-4.5").as_deref(),
+            Some("claude-haiku-4-5")
+        );
+        assert_eq!(provider.model_name("openai/gpt-5.2-chat"), None);
+    }
+
+    #[test]
+    fn build_request_translates_messages_and_tools() {
+        let request = request(json!({
+            "messages": [
+                { "role": "system", "content": "Be brief." },
+                { "role": "user", "content": "Weather in Paris?" },
+                {
+                    "role": "assistant",
+                    "content": null,
+                    "tool_calls": [{
+                        "id": "call_1",
+                        "type": "function",
+                        "function": { "name": "get_weather", "arguments": "{\"city\":\"Paris\"}" }
+                    }]
+                },
+                { "role": "tool", "tool_call_id": "call_1", "content": "Sunny" }
+            ],
+            "tools": [{
+                "type": "function",
+                "function": {
+                    "name": "get_weather",
+                    "description": "Current weather",
+                    "parameters": { "type": "object", "properties": { "city": { "type": "string" } } }
+                }
+            }],
+            "tool_choice": "required",
+            "stop": "END"
+        }));
+
+        let body = AnthropicProvider::default()
+            .build_request(&request, vec!["claude-haiku-4-5".into()], false)
+            .unwrap();
+
+        assert_eq!(
+            body,
+            json!({
+                "model": "claude-haiku-4-5",
+                "max_tokens": 4096,
+                "stream": false,
+                "system": "Be brief.",
+                "stop_sequences": ["END"],
+                "messages": [
+                    { "role": "user", "content": [{ "type": "text", "text": "Weather in Paris?" }] },
+                    { "role": "assistant", "content": [{
+                        "type": "tool_use",
+                        "id": "call_1",
+                        "name": "get_weather",
+                        "input": { "city": "Paris" }
+                    }] },
+                    { "role": "user", "content": [{
+                        "type": "tool_result",
+                        "tool_use_id": "call_1",
+                        "content": "Sunny"
+                    }] }
+                ],
+                "tools": [{
+                    "name": "get_weather",
+                    "description": "Current weather",
+                    "input_schema": { "type": "object", "properties": { "city": { "type": "string" } } }
+                }],
+                "tool_choice": { "type": "any" }
+            })
+        );
+    }
+
+    #[test]
+    fn transform_response_with_tool_call() {
+        let body = json!({
+            "id": "msg_1",
+            "type": "message",
+            "role": "assistant",
+            "model": "claude-haiku-4-5",
+            "content": [
+                { "type": "text", "text": "Checking." },
+                { "type": "tool_use", "id": "toolu_1", "name": "get_weather", "input": { "city": "Paris" } }
+            ],
+            "stop_reason": "tool_use",
+            "usage": { "input_tokens": 12, "output_tokens": 7 }
+        });
+
+        let out = AnthropicProvider::default().transform_response(Bytes::from(body.to_string()));
+        let out: Value = serde_json::from_slice(&out).unwrap();
+
+        assert_eq!(out["id"], "msg_1");
+        assert_eq!(out["choices"][0]["message"]["content"], "Checking.");
+        assert_eq!(
+            out["choices"][0]["message"]["tool_calls"][0]["function"],
+            json!({ "name": "get_weather", "arguments": "{\"city\":\"Paris\"}" })
+        );
+        assert_eq!(out["choices"][0]["finish_reason"], "tool_calls");
+        assert_eq!(out["usage"]["total_tokens"], 19);
+    }
+
+    #[test]
+    fn stream_translator_handles_split_events() {
+        let events = [
+            r#"{"type":"message_start","message":{"id":"msg_1","model":"claude-haiku-4-5","usage":{"input_tokens":9,"output_tokens":1}}}"#,
+            r#"{"type":"content_block_start","index":0,"content_block":{"type":"text","text":""}}"#,
+            r#"{"type":"content_block_delta","index":0,"delta":{"type":"text_delta","text":"Hi"}}"#,
+            r#"{"type":"content_block_start","index":1,"content_block":{"type":"tool_use","id":"toolu_1","name":"lookup","input":{}}}"#,
+            r#"{"type":"content_block_delta","index":1,"delta":{"type":"input_json_delta","partial_json":"{\"q\":1}"}}"#,
+            r#"{"type":"message_delta","delta":{"stop_reason":"tool_use"},"usage":{"output_tokens":5}}"#,
+            r#"{"type":"message_stop"}"#,
+        ];
+        let stream: String = events
+            .iter()
+            .map(|e| format!("event: x\ndata: {}\n\n", e))
+            .collect();
+
+        let mut translator = AnthropicProvider::default().stream_translator().unwrap();
+        let mut out = Vec::new();
+        for chunk in stream.as_bytes().chunks(17) {
+            out.extend_from_slice(&translator.translate(chunk));
+        }
+        let out = String::from_utf8(out).unwrap();
+
+        let chunks: Vec<Value> = out
+            .lines()
+            .filter_map(|l| l.strip_prefix("data: "))
+            .filter(|d| *d != "[DONE]")
+            .map(|d| serde_json::from_str(d).unwrap())
+            .collect();
+
+        assert_eq!(chunks.len(), 5);
+        assert_eq!(chunks[1]["choices"][0]["delta"]["content"], "Hi");
+        assert_eq!(
+            chunks[2]["choices"][0]["delta"]["tool_calls"][0]["function"]["name"],
+            "lookup"
+        );
+        assert_eq!(
+            chunks[3]["choices"][0]["delta"]["tool_calls"][0]["function"]["arguments"],
+            "{\"q\":1}"
+        );
+        assert_eq!(chunks[4]["choices"][0]["finish_reason"], "tool_calls");
+        assert_eq!(chunks[4]["usage"]["prompt_tokens"], 9);
+        assert_eq!(chunks[4]["usage"]["completion_tokens"], 5);
+        assert!(out.ends_with("data: [DONE]\n\n"));
+    }
+}
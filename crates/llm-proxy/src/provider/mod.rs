@@ -1,7 +1,12 @@
+mod anthropic;
+mod openai;
 mod openrouter;
 
+pub use anthropic::AnthropicProvider;
+pub use openai::OpenAIProvider;
 pub use openrouter::OpenRouterProvider;
 
+use bytes::Bytes;
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
 
@@ -51,11 +56,44 @@ pub enum ProviderError {
     InvalidRequest(String),
 }
 
+/// Replaces the `/chat/completions` suffix of an OpenAI-compatible endpoint
+/// with `endpoint`.
+pub(crate) fn sibling_url(chat_url: &str, endpoint: &str) -> String {
+    let base = chat_url
+        .trim_end_matches('/')
+        .trim_end_matches("/chat/completions");
+    format!("{}/{}", base, endpoint)
+}
+
+/// Rewrites a provider's event stream into Chat Completions chunks. Chunks
+/// may split events anywhere, so implementations buffer partial lines.
+pub trait StreamTranslator: Send {
+    fn translate(&mut self, chunk: &[u8]) -> Bytes;
+}
+
 pub trait Provider: Send + Sync {
     fn name(&self) -> &str;
 
     fn base_url(&self) -> &str;
 
+    /// Maps a resolved model id to this provider's own id, or `None` when the
+    /// provider doesn't serve the model.
+    fn model_name(&self, model: &str) -> Option<String> {
+        Some(model.to_string())
+    }
+
+    /// Whether one request can carry several models and let the provider
+    /// fall back between them.
+    fn supports_model_fallback(&self) -> bool {
+        false
+    }
+
+    /// OpenAI-compatible `/embeddings` endpoint, or `None` when the provider
+    /// can't embed.
+    fn embeddings_url(&self) -> Option<String> {
+        None
+    }
+
     fn build_request(
         &self,
         request: &ChatCompletionRequest,
@@ -67,6 +105,15 @@ pub trait Provider: Send + Sync {
 
     fn parse_stream_chunk(&self, chunk: &[u8], accumulator: &mut StreamAccumulator);
 
+    /// Converts a successful response body into the Chat Completions format.
+    fn transform_response(&self, body: Bytes) -> Bytes {
+        body
+    }
+
+    fn stream_translator(&self) -> Option<Box<dyn StreamTranslator>> {
+        None
+    }
+
     fn fetch_cost(
         &self,
         client: &Client,
@@ -77,6 +124,10 @@ pub trait Provider: Send + Sync {
         Box::pin(async { None })
     }
 
+    fn auth_header_name(&self) -> &str {
+        "Authorization"
+    }
+
     fn build_auth_header(&self, api_key: &str) -> String {
         format!("Bearer {}", api_key)
     }
@@ -0,0 +1,153 @@
+use serde::Deserialize;
+
+use crate::types::{ChatCompletionRequest, UsageInfo};
+
+use super::{GenerationMetadata, Provider, ProviderError, StreamAccumulator, sibling_url};
+
+pub const OPENAI_URL: &str = "https://api.openai.com/v1/chat/completions";
+pub const OPENAI_MODEL_PREFIX: &str = "openai/";
+
+/// Any endpoint speaking the OpenAI Chat Completions API. Serves the resolved
+/// models starting with `model_prefix`, with the prefix stripped.
+pub struct OpenAIProvider {
+    pub base_url: String,
+    pub model_prefix: String,
+}
+
+impl Default for OpenAIProvider {
+    fn default() -> Self {
+        Self {
+            base_url: OPENAI_URL.to_string(),
+            model_prefix: OPENAI_MODEL_PREFIX.to_string(),
+        }
+    }
+}
+
+impl OpenAIProvider {
+    pub fn new(base_url: impl Into<String>) -> Self {
+        Self {
+            base_url: base_url.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_model_prefix(mut self, model_prefix: impl Into<String>) -> Self {
+        self.model_prefix = model_prefix.into();
+        self
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatCompletionResponse {
+    pub id: String,
+    pub model: Option<String>,
+    pub usage: Option<UsageInfo>,
+}
+
+pub(crate) fn parse_chat_completion(body: &[u8]) -> Result<GenerationMetadata, ProviderError> {
+    let parsed: ChatCompletionResponse =
+        serde_json::from_slice(body).map_err(|e| ProviderError::ParseError(e.to_string()))?;
+
+    Ok(GenerationMetadata {
+        generation_id: parsed.id,
+        model: parsed.model,
+        input_tokens: parsed.usage.as_ref().map(|u| u.input_tokens()).unwrap_or(0),
+        output_tokens: parsed
+            .usage
+            .as_ref()
+            .map(|u| u.output_tokens())
+            .unwrap_or(0),
+    })
+}
+
+pub(crate) fn parse_chat_completion_chunk(chunk: &[u8], accumulator: &mut StreamAccumulator) {
+    let Ok(text) = std::str::from_utf8(chunk) else {
+        return;
+    };
+
+    for line in text.lines() {
+        let Some(data) = line.strip_prefix("data: ") else {
+            continue;
+        };
+
+        if data.trim() == "[DONE]" {
+            continue;
+        }
+
+        let Ok(parsed) = serde_json::from_str::<serde_json::Value>(data) else {
+            continue;
+        };
+
+        if accumulator.generation_id.is_none() {
+            accumulator.generation_id = parsed.get("id").and_then(|v| v.as_str()).map(String::from);
+        }
+
+        if accumulator.model.is_none() {
+            accumulator.model = parsed
+                .get("model")
+                .and_then(|v| v.as_str())
+                .map(String::from);
+        }
+
+        if let Some(usage) = parsed
+            .get("usage")
+            .and_then(|u| serde_json::from_value::<UsageInfo>(u.clone()).ok())
+        {
+            accumulator.input_tokens = usage.input_tokens();
+            accumulator.output_tokens = usage.output_tokens();
+        }
+    }
+}
+
+impl Provider for OpenAIProvider {
+    fn name(&self) -> &str {
+        "openai"
+    }
+
+    fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    fn model_name(&self, model: &str) -> Option<String> {
+        model.strip_prefix(&self.model_prefix).map(String::from)
+    }
+
+    fn embeddings_url(&self) -> Option<String> {
+        Some(sibling_url(&self.base_url, "embeddings"))
+    }
+
+    fn build_request(
+        &self,
+        request: &ChatCompletionRequest,
+        models: Vec<String>,
+        stream: bool,
+    ) -> Result<serde_json::Value, ProviderError> {
+        let model = models
+            .into_iter()
+            .next()
+            .ok_or_else(|| ProviderError::InvalidRequest("no model".to_string()))?;
+
+        let mut body = serde_json::to_value(request)?;
+        let obj = body.as_object_mut().unwrap();
+
+        obj.insert("model".to_string(), serde_json::Value::String(model));
+        obj.insert("stream".to_string(), serde_json::Value::Bool(stream));
+        if stream {
+            // Usage is only sent on streams that ask for it.
+            obj.insert(
+                "stream_options".to_string(),
+                serde_json::json!({ "include_usage": true }),
+            );
+        }
+
+        Ok(body)
+    }
+
+    fn parse_response(&self, body: &[u8]) -> Result<GenerationMetadata, ProviderError> {
+        parse_chat_completion(body)
+    }
+
+    fn parse_stream_chunk(&self, chunk: &[u8], accumulator: &mut StreamAccumulator) {
+        parse_chat_completion_chunk(chunk, accumulator);
+    }
+}
@@ -48,6 +48,15 @@ pub struct ChatCompletionRequest {
     pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+pub struct EmbeddingRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    pub input: serde_json::Value,
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
 pub fn has_audio_content(messages: &[ChatMessage]) -> bool {
     messages.iter().any(|msg| {
         msg.content
@@ -0,0 +1,76 @@
+use std::collections::HashMap;
+
+use hypr_lago::LagoClient;
+use hypr_lago::event::send_usage::{Request, Response};
+
+use super::{BoxFuture, UsageMeter};
+use crate::analytics::GenerationEvent;
+
+pub const LAGO_API_BASE: &str = "https://api.getlago.com";
+pub const LAGO_METRIC_CODE: &str = "llm_tokens";
+
+/// Sends one Lago usage event per generation. Subscriptions are expected to
+/// use the user id as their external id; the generation id makes retries
+/// idempotent.
+pub struct LagoMeter {
+    client: LagoClient,
+    metric_code: String,
+}
+
+impl LagoMeter {
+    pub fn new(client: LagoClient, metric_code: impl Into<String>) -> Self {
+        Self {
+            client,
+            metric_code: metric_code.into(),
+        }
+    }
+
+    pub fn from_api_key(api_key: impl Into<String>) -> Self {
+        let client = LagoClient::builder()
+            .api_base(LAGO_API_BASE)
+            .api_key(api_key)
+            .build();
+        Self::new(client, LAGO_METRIC_CODE)
+    }
+}
+
+impl UsageMeter for LagoMeter {
+    fn meter(&self, user_id: String, event: GenerationEvent) -> BoxFuture<'_, ()> {
+        Box::pin(async move {
+            let properties = HashMap::from([
+                ("model".to_string(), event.model.into()),
+                ("provider".to_string(), event.provider_name.into()),
+                ("input_tokens".to_string(), event.input_tokens.into()),
+                ("output_tokens".to_string(), event.output_tokens.into()),
+            ]);
+
+            let req = Request {
+                code: self.metric_code.clone(),
+                external_subscription_id: user_id,
+                transaction_id: event.generation_id.clone(),
+                precise_total_amount_cents: event.total_cost.map(|c| (c * 100.0).to_string()),
+                properties,
+                timestamp: None,
+            };
+
+            match self.client.send_usage_event(req).await {
+                Ok(Response::Ok { .. }) => {}
+                Ok(Response::Error { status, message }) => {
+                    tracing::warn!(
+                        http_status = %status,
+                        error = %message,
+                        generation_id = %event.generation_id,
+                        "lago_usage_event_rejected"
+                    );
+                }
+                Err(e) => {
+                    tracing::warn!(
+                        error = %e,
+                        generation_id = %event.generation_id,
+                        "lago_usage_event_failed"
+                    );
+                }
+            }
+        })
+    }
+}
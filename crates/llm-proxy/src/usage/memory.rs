@@ -0,0 +1,45 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use super::{BoxFuture, Usage, UsageError, UsageStore};
+
+/// Process-local store. Totals are lost on restart and not shared between
+/// instances.
+#[derive(Default)]
+pub struct InMemoryUsageStore {
+    totals: Mutex<HashMap<(String, String), Usage>>,
+}
+
+impl UsageStore for InMemoryUsageStore {
+    fn get<'a>(
+        &'a self,
+        user_id: &'a str,
+        period: &'a str,
+    ) -> BoxFuture<'a, Result<Usage, UsageError>> {
+        Box::pin(async move {
+            let totals = self.totals.lock().unwrap();
+            Ok(totals
+                .get(&(user_id.to_string(), period.to_string()))
+                .copied()
+                .unwrap_or_default())
+        })
+    }
+
+    fn add<'a>(
+        &'a self,
+        user_id: &'a str,
+        periods: &'a [String],
+        usage: Usage,
+    ) -> BoxFuture<'a, Result<(), UsageError>> {
+        Box::pin(async move {
+            let mut totals = self.totals.lock().unwrap();
+            for period in periods {
+                totals
+                    .entry((user_id.to_string(), period.clone()))
+                    .or_default()
+                    .add(usage);
+            }
+            Ok(())
+        })
+    }
+}
@@ -0,0 +1,451 @@
+mod lago;
+mod memory;
+mod supabase;
+
+pub use lago::LagoMeter;
+pub use memory::InMemoryUsageStore;
+pub use supabase::SupabaseUsageStore;
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use chrono::{DateTime, Datelike, Duration, NaiveDate, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::analytics::GenerationEvent;
+
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+#[derive(Debug, thiserror::Error)]
+pub enum UsageError {
+    #[error("usage store error: {0}")]
+    Store(String),
+}
+
+/// Billing plan of the caller. Inserted into request extensions by the auth
+/// layer; requests without one are treated as `Free`.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    Hash,
+    Serialize,
+    Deserialize,
+    ToSchema,
+    strum::Display,
+)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum UsagePlan {
+    #[default]
+    Free,
+    Pro,
+}
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema, strum::Display,
+)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum UsageWindow {
+    Daily,
+    Monthly,
+}
+
+impl UsageWindow {
+    /// Store key of the window containing `now`.
+    pub fn period(&self, now: DateTime<Utc>) -> String {
+        match self {
+            Self::Daily => now.format("%Y-%m-%d").to_string(),
+            Self::Monthly => now.format("%Y-%m").to_string(),
+        }
+    }
+
+    pub fn resets_at(&self, now: DateTime<Utc>) -> DateTime<Utc> {
+        let next = match self {
+            Self::Daily => now.date_naive() + Duration::days(1),
+            Self::Monthly if now.month() == 12 => {
+                NaiveDate::from_ymd_opt(now.year() + 1, 1, 1).unwrap()
+            }
+            Self::Monthly => NaiveDate::from_ymd_opt(now.year(), now.month() + 1, 1).unwrap(),
+        };
+        Utc.from_utc_datetime(&next.and_hms_opt(0, 0, 0).unwrap())
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct Usage {
+    pub tokens: u64,
+    pub cost_usd: f64,
+}
+
+impl Usage {
+    fn add(&mut self, other: Usage) {
+        self.tokens += other.tokens;
+        self.cost_usd += other.cost_usd;
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum BudgetLimit {
+    Tokens,
+    Cost,
+}
+
+/// Limits for one window. `None` means unlimited.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct Budget {
+    pub tokens: Option<u64>,
+    pub cost_usd: Option<f64>,
+}
+
+impl Budget {
+    pub fn tokens(tokens: u64) -> Self {
+        Self {
+            tokens: Some(tokens),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_cost_usd(mut self, cost_usd: f64) -> Self {
+        self.cost_usd = Some(cost_usd);
+        self
+    }
+
+    fn exhausted_by(&self, usage: &Usage) -> Option<BudgetLimit> {
+        if self.tokens.is_some_and(|limit| usage.tokens >= limit) {
+            return Some(BudgetLimit::Tokens);
+        }
+        if self.cost_usd.is_some_and(|limit| usage.cost_usd >= limit) {
+            return Some(BudgetLimit::Cost);
+        }
+        None
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct PlanBudget {
+    pub daily: Budget,
+    pub monthly: Budget,
+}
+
+impl PlanBudget {
+    fn get(&self, window: UsageWindow) -> Budget {
+        match window {
+            UsageWindow::Daily => self.daily,
+            UsageWindow::Monthly => self.monthly,
+        }
+    }
+}
+
+/// Persists per-user totals for each period key produced by
+/// [`UsageWindow::period`].
+pub trait UsageStore: Send + Sync {
+    fn get<'a>(
+        &'a self,
+        user_id: &'a str,
+        period: &'a str,
+    ) -> BoxFuture<'a, Result<Usage, UsageError>>;
+
+    fn add<'a>(
+        &'a self,
+        user_id: &'a str,
+        periods: &'a [String],
+        usage: Usage,
+    ) -> BoxFuture<'a, Result<(), UsageError>>;
+}
+
+/// Forwards recorded generations to an external billing system.
+pub trait UsageMeter: Send + Sync {
+    fn meter(&self, user_id: String, event: GenerationEvent) -> BoxFuture<'_, ()>;
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct WindowUsage {
+    pub window: UsageWindow,
+    pub used: Usage,
+    pub limit: Budget,
+    #[schema(value_type = String)]
+    pub resets_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct UsageSummary {
+    pub plan: UsagePlan,
+    pub daily: WindowUsage,
+    pub monthly: WindowUsage,
+}
+
+#[derive(Debug, Clone)]
+pub struct BudgetExceeded {
+    pub window: UsageWindow,
+    pub limit: BudgetLimit,
+    pub resets_at: DateTime<Utc>,
+}
+
+impl IntoResponse for BudgetExceeded {
+    fn into_response(self) -> Response {
+        let body = serde_json::json!({
+            "error": {
+                "type": "budget_exceeded",
+                "message": format!("{} {} budget exhausted", self.window, self.limit),
+                "window": self.window,
+                "limit": self.limit,
+                "resets_at": self.resets_at,
+            }
+        });
+        (StatusCode::TOO_MANY_REQUESTS, Json(body)).into_response()
+    }
+}
+
+/// Per-user token and cost accounting. Plans without a configured budget are
+/// unlimited but still recorded.
+#[derive(Clone)]
+pub struct UsageLedger {
+    store: Arc<dyn UsageStore>,
+    budgets: HashMap<UsagePlan, PlanBudget>,
+    meter: Option<Arc<dyn UsageMeter>>,
+}
+
+impl UsageLedger {
+    pub fn new(store: Arc<dyn UsageStore>) -> Self {
+        Self {
+            store,
+            budgets: HashMap::new(),
+            meter: None,
+        }
+    }
+
+    pub fn with_budget(mut self, plan: UsagePlan, budget: PlanBudget) -> Self {
+        self.budgets.insert(plan, budget);
+        self
+    }
+
+    /// Applies the budgets configured in `env`, leaving plans without any
+    /// as they are.
+    pub fn with_env_budgets(mut self, env: &crate::env::BudgetEnv) -> Self {
+        for plan in [UsagePlan::Free, UsagePlan::Pro] {
+            if let Some(budget) = env.plan_budget(plan) {
+                self.budgets.insert(plan, budget);
+            }
+        }
+        self
+    }
+
+    pub fn with_meter(mut self, meter: Arc<dyn UsageMeter>) -> Self {
+        self.meter = Some(meter);
+        self
+    }
+
+    pub async fn summary(
+        &self,
+        user_id: &str,
+        plan: UsagePlan,
+        now: DateTime<Utc>,
+    ) -> Result<UsageSummary, UsageError> {
+        Ok(UsageSummary {
+            plan,
+            daily: self
+                .window_usage(user_id, plan, UsageWindow::Daily, now)
+                .await?,
+            monthly: self
+                .window_usage(user_id, plan, UsageWindow::Monthly, now)
+                .await?,
+        })
+    }
+
+    async fn window_usage(
+        &self,
+        user_id: &str,
+        plan: UsagePlan,
+        window: UsageWindow,
+        now: DateTime<Utc>,
+    ) -> Result<WindowUsage, UsageError> {
+        let used = self.store.get(user_id, &window.period(now)).await?;
+        Ok(WindowUsage {
+            window,
+            used,
+            limit: self
+                .budgets
+                .get(&plan)
+                .map(|b| b.get(window))
+                .unwrap_or_default(),
+            resets_at: window.resets_at(now),
+        })
+    }
+
+    /// Fails open when the store is unavailable.
+    pub async fn check(
+        &self,
+        user_id: &str,
+        plan: UsagePlan,
+        now: DateTime<Utc>,
+    ) -> Result<(), BudgetExceeded> {
+        if !self.budgets.contains_key(&plan) {
+            return Ok(());
+        }
+
+        let summary = match self.summary(user_id, plan, now).await {
+            Ok(summary) => summary,
+            Err(e) => {
+                tracing::warn!(error = %e, "usage_budget_check_failed");
+                return Ok(());
+            }
+        };
+
+        for usage in [summary.daily, summary.monthly] {
+            if let Some(limit) = usage.limit.exhausted_by(&usage.used) {
+                return Err(BudgetExceeded {
+                    window: usage.window,
+                    limit,
+                    resets_at: usage.resets_at,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    pub async fn record(&self, event: &GenerationEvent, now: DateTime<Utc>) {
+        let Some(user_id) = &event.user_id else {
+            return;
+        };
+
+        let usage = Usage {
+            tokens: u64::from(event.input_tokens) + u64::from(event.output_tokens),
+            cost_usd: event.total_cost.unwrap_or(0.0),
+        };
+        let periods = [
+            UsageWindow::Daily.period(now),
+            UsageWindow::Monthly.period(now),
+        ];
+
+        if let Err(e) = self.store.add(user_id, &periods, usage).await {
+            tracing::error!(error = %e, generation_id = %event.generation_id, "usage_record_failed");
+        }
+
+        if let Some(meter) = &self.meter {
+            meter.meter(user_id.clone(), event.clone()).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(s: &str) -> DateTime<Utc> {
+        s.parse().unwrap()
+    }
+
+    fn event(user_id: &str, tokens: u32, cost: f64) -> GenerationEvent {
+        GenerationEvent {
+            fingerprint: None,
+            user_id: Some(user_id.to_string()),
+            generation_id: "gen-1".to_string(),
+            model: "openai/gpt-4.1-nano".to_string(),
+            input_tokens: tokens,
+            output_tokens: 0,
+            latency: 0.0,
+            http_status: 200,
+            total_cost: Some(cost),
+            provider_name: "openrouter".to_string(),
+            base_url: "http://localhost".to_string(),
+        }
+    }
+
+    #[test]
+    fn window_periods_and_resets() {
+        let now = at("2025-12-31T18:30:00Z");
+
+        assert_eq!(UsageWindow::Daily.period(now), "2025-12-31");
+        assert_eq!(UsageWindow::Monthly.period(now), "2025-12");
+        assert_eq!(
+            UsageWindow::Daily.resets_at(now),
+            at("2026-01-01T00:00:00Z")
+        );
+        assert_eq!(
+            UsageWindow::Monthly.resets_at(now),
+            at("2026-01-01T00:00:00Z")
+        );
+        assert_eq!(
+            UsageWindow::Monthly.resets_at(at("2026-02-10T00:00:00Z")),
+            at("2026-03-01T00:00:00Z")
+        );
+    }
+
+    #[test]
+    fn env_budgets() {
+        let env = crate::env::BudgetEnv {
+            llm_budget_free_daily_tokens: Some(100),
+            llm_budget_pro_monthly_cost_usd: Some(30.0),
+            ..Default::default()
+        };
+        let ledger =
+            UsageLedger::new(Arc::new(InMemoryUsageStore::default())).with_env_budgets(&env);
+
+        assert_eq!(
+            ledger.budgets[&UsagePlan::Free],
+            PlanBudget {
+                daily: Budget::tokens(100),
+                monthly: Budget::default(),
+            }
+        );
+        assert_eq!(
+            ledger.budgets[&UsagePlan::Pro].monthly,
+            Budget::default().with_cost_usd(30.0)
+        );
+        assert!(
+            UsageLedger::new(Arc::new(InMemoryUsageStore::default()))
+                .with_env_budgets(&crate::env::BudgetEnv::default())
+                .budgets
+                .is_empty()
+        );
+    }
+
+    #[tokio::test]
+    async fn budgets_apply_per_window() {
+        let ledger = UsageLedger::new(Arc::new(InMemoryUsageStore::default())).with_budget(
+            UsagePlan::Free,
+            PlanBudget {
+                daily: Budget::tokens(100),
+                monthly: Budget::tokens(1_000).with_cost_usd(0.5),
+            },
+        );
+        let day1 = at("2026-03-01T10:00:00Z");
+        let day2 = at("2026-03-02T10:00:00Z");
+
+        ledger.record(&event("u1", 100, 0.1), day1).await;
+
+        let exceeded = ledger.check("u1", UsagePlan::Free, day1).await.unwrap_err();
+        assert_eq!(exceeded.window, UsageWindow::Daily);
+        assert_eq!(exceeded.limit, BudgetLimit::Tokens);
+        assert_eq!(exceeded.resets_at, at("2026-03-02T00:00:00Z"));
+
+        assert!(ledger.check("u1", UsagePlan::Free, day2).await.is_ok());
+        assert!(ledger.check("u2", UsagePlan::Free, day1).await.is_ok());
+        assert!(ledger.check("u1", UsagePlan::Pro, day1).await.is_ok());
+
+        ledger.record(&event("u1", 10, 0.4), day2).await;
+        let exceeded = ledger.check("u1", UsagePlan::Free, day2).await.unwrap_err();
+        assert_eq!(exceeded.window, UsageWindow::Monthly);
+        assert_eq!(exceeded.limit, BudgetLimit::Cost);
+
+        let summary = ledger.summary("u1", UsagePlan::Free, day2).await.unwrap();
+        assert_eq!(summary.daily.used.tokens, 10);
+        assert_eq!(summary.monthly.used.tokens, 110);
+    }
+}
@@ -0,0 +1,175 @@
+use serde::Deserialize;
+
+use super::{BoxFuture, Usage, UsageError, UsageStore};
+
+#[derive(Deserialize)]
+struct UsageRow {
+    tokens: u64,
+    cost_usd: f64,
+}
+
+/// Keeps totals in the `llm_usage` table, so budgets hold across restarts
+/// and every instance. Increments go through the `add_llm_usage` function,
+/// which adds atomically.
+pub struct SupabaseUsageStore {
+    client: reqwest::Client,
+    base_url: String,
+    service_role_key: String,
+}
+
+impl SupabaseUsageStore {
+    pub fn new(supabase_url: &str, service_role_key: &str) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            base_url: supabase_url.trim_end_matches('/').to_string(),
+            service_role_key: service_role_key.to_string(),
+        }
+    }
+
+    async fn send(
+        &self,
+        builder: reqwest::RequestBuilder,
+    ) -> Result<reqwest::Response, UsageError> {
+        let response = builder
+            .header("Authorization", format!("Bearer {}", self.service_role_key))
+            .header("apikey", &self.service_role_key)
+            .send()
+            .await
+            .map_err(|e| UsageError::Store(e.to_string()))?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            return Err(UsageError::Store(format!("{status} {body}")));
+        }
+
+        Ok(response)
+    }
+}
+
+impl UsageStore for SupabaseUsageStore {
+    fn get<'a>(
+        &'a self,
+        user_id: &'a str,
+        period: &'a str,
+    ) -> BoxFuture<'a, Result<Usage, UsageError>> {
+        Box::pin(async move {
+            let url = format!(
+                "{}/rest/v1/llm_usage?user_id=eq.{}&period=eq.{}&select=tokens,cost_usd",
+                self.base_url,
+                urlencoding::encode(user_id),
+                urlencoding::encode(period),
+            );
+            let rows: Vec<UsageRow> = self
+                .send(self.client.get(&url))
+                .await?
+                .json()
+                .await
+                .map_err(|e| UsageError::Store(e.to_string()))?;
+
+            Ok(rows
+                .into_iter()
+                .next()
+                .map(|row| Usage {
+                    tokens: row.tokens,
+                    cost_usd: row.cost_usd,
+                })
+                .unwrap_or_default())
+        })
+    }
+
+    fn add<'a>(
+        &'a self,
+        user_id: &'a str,
+        periods: &'a [String],
+        usage: Usage,
+    ) -> BoxFuture<'a, Result<(), UsageError>> {
+        Box::pin(async move {
+            let url = format!("{}/rest/v1/rpc/add_llm_usage", self.base_url);
+            self.send(self.client.post(&url).json(&serde_json::json!({
+                "p_user_id": user_id,
+                "p_periods": periods,
+                "p_tokens": usage.tokens,
+                "p_cost_usd": usage.cost_usd,
+            })))
+            .await?;
+            Ok(())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wiremock::matchers::{body_json, header, method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_add_and_get() {
+        let server = MockServer::start().await;
+        let store = SupabaseUsageStore::new(&format!("{}/", server.uri()), "service-key");
+
+        Mock::given(method("POST"))
+            .and(path("/rest/v1/rpc/add_llm_usage"))
+            .and(header("apikey", "service-key"))
+            .and(body_json(serde_json::json!({
+                "p_user_id": "u1",
+                "p_periods": ["2026-03-01", "2026-03"],
+                "p_tokens": 120,
+                "p_cost_usd": 0.25,
+            })))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/rest/v1/llm_usage"))
+            .and(query_param("user_id", "eq.u1"))
+            .and(query_param("period", "eq.2026-03"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!([{ "tokens": 120, "cost_usd": 0.25 }])),
+            )
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/rest/v1/llm_usage"))
+            .and(query_param("period", "eq.2026-04"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([])))
+            .mount(&server)
+            .await;
+
+        let usage = Usage {
+            tokens: 120,
+            cost_usd: 0.25,
+        };
+        store
+            .add(
+                "u1",
+                &["2026-03-01".to_string(), "2026-03".to_string()],
+                usage,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(store.get("u1", "2026-03").await.unwrap(), usage);
+        assert_eq!(store.get("u1", "2026-04").await.unwrap(), Usage::default());
+    }
+
+    #[tokio::test]
+    async fn test_error_status() {
+        let server = MockServer::start().await;
+        let store = SupabaseUsageStore::new(&server.uri(), "service-key");
+
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(500))
+            .mount(&server)
+            .await;
+
+        assert!(matches!(
+            store.get("u1", "2026-03").await,
+            Err(UsageError::Store(_))
+        ));
+    }
+}
@@ -5,7 +5,10 @@ use std::sync::Arc;
 use axum::body::Body;
 use axum::http::Request;
 use llm_proxy::provider::OpenRouterProvider;
-use llm_proxy::{GenerationEvent, LlmProxyConfig, MODEL_KEY_DEFAULT, StaticModelResolver};
+use llm_proxy::{
+    AuthenticatedUserId, GenerationEvent, LlmProxyConfig, MODEL_KEY_DEFAULT, StaticModelResolver,
+    UsagePlan,
+};
 use wiremock::matchers::{header, method, path};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
@@ -63,6 +66,30 @@ impl TestHarness {
             .with_model_resolver(Arc::new(resolver))
     }
 
+    pub fn config_with_models(&self, models: Vec<&str>) -> LlmProxyConfig {
+        let resolver = StaticModelResolver::default().with_models(
+            MODEL_KEY_DEFAULT,
+            models.into_iter().map(String::from).collect(),
+        );
+        LlmProxyConfig::new("test-api-key")
+            .with_provider(Arc::new(OpenRouterProvider::new(self.mock_server.uri())))
+            .with_model_resolver(Arc::new(resolver))
+            .with_analytics(Arc::new(self.analytics.clone()))
+    }
+
+    pub fn route_url(&self, route: &str) -> String {
+        format!("{}/{}", self.mock_server.uri(), route)
+    }
+
+    pub async fn mount_route(&self, route: &str, response: ResponseTemplate, expect: u64) {
+        Mock::given(method("POST"))
+            .and(path(format!("/{route}")))
+            .respond_with(response)
+            .expect(expect)
+            .mount(&self.mock_server)
+            .await;
+    }
+
     pub async fn mount_json_response(&self, response: serde_json::Value) {
         Mock::given(method("POST"))
             .and(path("/"))
@@ -121,6 +148,23 @@ pub fn build_request(body: serde_json::Value) -> Request<Body> {
         .unwrap()
 }
 
+pub fn with_user(mut request: Request<Body>, user_id: &str, plan: UsagePlan) -> Request<Body> {
+    request
+        .extensions_mut()
+        .insert(AuthenticatedUserId(user_id.to_string()));
+    request.extensions_mut().insert(plan);
+    request
+}
+
+pub fn usage_request(user_id: &str, plan: UsagePlan) -> Request<Body> {
+    let request = Request::builder()
+        .method("GET")
+        .uri("/usage")
+        .body(Body::empty())
+        .unwrap();
+    with_user(request, user_id, plan)
+}
+
 pub fn stream_chunks(id: &str) -> [String; 4] {
     [
         format!(
@@ -136,6 +180,40 @@ pub fn stream_chunks(id: &str) -> [String; 4] {
     ]
 }
 
+pub fn anthropic_response(id: &str, model: &str, content: &str) -> serde_json::Value {
+    serde_json::json!({
+        "id": id,
+        "type": "message",
+        "role": "assistant",
+        "model": model,
+        "content": [{"type": "text", "text": content}],
+        "stop_reason": "end_turn",
+        "usage": {"input_tokens": 12, "output_tokens": 2}
+    })
+}
+
+pub fn anthropic_stream_events(id: &str, model: &str) -> String {
+    [
+        format!(
+            r#"{{"type":"message_start","message":{{"id":"{id}","model":"{model}","usage":{{"input_tokens":9,"output_tokens":1}}}}}}"#
+        ),
+        r#"{"type":"content_block_start","index":0,"content_block":{"type":"text","text":""}}"#
+            .to_string(),
+        r#"{"type":"content_block_delta","index":0,"delta":{"type":"text_delta","text":"hello"}}"#
+            .to_string(),
+        r#"{"type":"content_block_stop","index":0}"#.to_string(),
+        r#"{"type":"message_delta","delta":{"stop_reason":"end_turn"},"usage":{"output_tokens":3}}"#
+            .to_string(),
+        r#"{"type":"message_stop"}"#.to_string(),
+    ]
+    .iter()
+    .map(|data| {
+        let event = data.split('"').nth(3).unwrap();
+        format!("event: {event}\ndata: {data}\n\n")
+    })
+    .collect()
+}
+
 pub fn completion_response(id: &str, model: &str, content: &str) -> serde_json::Value {
     serde_json::json!({
         "id": id,
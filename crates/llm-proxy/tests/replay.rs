@@ -6,8 +6,11 @@ use common::harness::*;
 use std::sync::Arc;
 
 use axum::http::StatusCode;
+use llm_proxy::provider::{AnthropicProvider, OpenAIProvider};
 use llm_proxy::{LlmProxyConfig, MODEL_KEY_DEFAULT, StaticModelResolver, router};
 use tower::ServiceExt;
+use wiremock::matchers::{body_partial_json, header, method, path};
+use wiremock::{Mock, ResponseTemplate};
 
 mod basic {
     use super::*;
@@ -149,6 +152,548 @@ mod error_handling {
     }
 }
 
+mod providers {
+    use super::*;
+
+    #[tokio::test]
+    async fn openai_non_streaming() {
+        let harness = TestHarness::new().await;
+        Mock::given(method("POST"))
+            .and(path("/openai"))
+            .and(header("Authorization", "Bearer openai-key"))
+            .and(body_partial_json(
+                serde_json::json!({"model": "gpt-4.1-nano", "stream": false}),
+            ))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(completion_response(
+                    "chatcmpl-1",
+                    "gpt-4.1-nano",
+                    "hello",
+                )),
+            )
+            .expect(1)
+            .mount(&harness.mock_server)
+            .await;
+
+        let config = harness
+            .config_with_models(vec!["openai/gpt-4.1-nano"])
+            .with_route(
+                Arc::new(OpenAIProvider::new(harness.route_url("openai"))),
+                "openai-key",
+            );
+        let response = router(config)
+            .oneshot(build_request(simple_message("Say hello")))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = response_to_json(response).await;
+        assert_eq!(body["choices"][0]["message"]["content"], "hello");
+
+        let event = harness.analytics.get_single_event().await;
+        assert_eq!(event.provider_name, "openai");
+        assert_eq!(event.input_tokens, 10);
+    }
+
+    #[tokio::test]
+    async fn anthropic_non_streaming() {
+        let harness = TestHarness::new().await;
+        Mock::given(method("POST"))
+            .and(path("/anthropic"))
+            .and(header("x-api-key", "anthropic-key"))
+            .and(header("anthropic-version", "2023-06-01"))
+            .and(body_partial_json(serde_json::json!({
+                "model": "claude-haiku-4-5",
+                "messages": [{"role": "user", "content": [{"type": "text", "text": "Say hello"}]}]
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(anthropic_response(
+                "msg_1",
+                "claude-haiku-4-5",
+                "hello",
+            )))
+            .expect(1)
+            .mount(&harness.mock_server)
+            .await;
+
+        let config = harness
+            .config_with_models(vec!["anthropic/claude-haiku-4.5"])
+            .with_route(
+                Arc::new(AnthropicProvider::new(harness.route_url("anthropic"))),
+                "anthropic-key",
+            );
+        let response = router(config)
+            .oneshot(build_request(simple_message("Say hello")))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = response_to_json(response).await;
+        assert_eq!(body["id"], "msg_1");
+        assert_eq!(body["object"], "chat.completion");
+        assert_eq!(body["choices"][0]["message"]["content"], "hello");
+        assert_eq!(body["choices"][0]["finish_reason"], "stop");
+        assert_eq!(body["usage"]["prompt_tokens"], 12);
+
+        let event = harness.analytics.get_single_event().await;
+        assert_eq!(event.generation_id, "msg_1");
+        assert_eq!(event.provider_name, "anthropic");
+        assert_eq!(event.output_tokens, 2);
+    }
+
+    #[tokio::test]
+    async fn anthropic_streaming() {
+        let harness = TestHarness::new().await;
+        harness
+            .mount_route(
+                "anthropic",
+                ResponseTemplate::new(200)
+                    .set_body_string(anthropic_stream_events("msg_2", "claude-haiku-4-5"))
+                    .insert_header("Content-Type", "text/event-stream"),
+                1,
+            )
+            .await;
+
+        let config = harness
+            .config_with_models(vec!["anthropic/claude-haiku-4.5"])
+            .with_route(
+                Arc::new(AnthropicProvider::new(harness.route_url("anthropic"))),
+                "anthropic-key",
+            );
+        let response = router(config)
+            .oneshot(build_request(stream_request("Say hello")))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body_str = response_to_string(response).await;
+        assert!(body_str.contains(r#""object":"chat.completion.chunk""#));
+        assert!(body_str.contains(r#""content":"hello""#));
+        assert!(body_str.ends_with("data: [DONE]\n\n"));
+
+        let event = harness.analytics.get_single_event().await;
+        assert_eq!(event.generation_id, "msg_2");
+        assert_eq!(event.input_tokens, 9);
+        assert_eq!(event.output_tokens, 3);
+    }
+}
+
+mod fallback {
+    use super::*;
+
+    fn anthropic_first(harness: &TestHarness) -> LlmProxyConfig {
+        harness
+            .config_with_models(vec!["anthropic/claude-haiku-4.5"])
+            .with_route(
+                Arc::new(AnthropicProvider::new(harness.route_url("anthropic"))),
+                "anthropic-key",
+            )
+    }
+
+    #[tokio::test]
+    async fn server_error_falls_back_to_next_provider() {
+        let harness = TestHarness::new().await;
+        harness
+            .mount_route("anthropic", ResponseTemplate::new(529), 1)
+            .await;
+        harness
+            .mount_json_response(completion_response(
+                "gen-fallback",
+                "anthropic/claude-haiku-4.5",
+                "hello",
+            ))
+            .await;
+
+        let response = router(anthropic_first(&harness))
+            .oneshot(build_request(simple_message("Say hello")))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = response_to_json(response).await;
+        assert_eq!(body["id"], "gen-fallback");
+
+        let event = harness.analytics.get_single_event().await;
+        assert_eq!(event.provider_name, "openrouter");
+    }
+
+    #[tokio::test]
+    async fn timeout_falls_back_to_next_provider() {
+        let harness = TestHarness::new().await;
+        harness
+            .mount_route(
+                "anthropic",
+                ResponseTemplate::new(200)
+                    .set_body_json(anthropic_response("msg_late", "claude-haiku-4-5", "late"))
+                    .set_delay(std::time::Duration::from_secs(2)),
+                1,
+            )
+            .await;
+        harness
+            .mount_json_response(completion_response(
+                "gen-fallback",
+                "anthropic/claude-haiku-4.5",
+                "hello",
+            ))
+            .await;
+
+        let config = anthropic_first(&harness).with_timeout(std::time::Duration::from_millis(300));
+        let response = router(config)
+            .oneshot(build_request(simple_message("Say hello")))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = response_to_json(response).await;
+        assert_eq!(body["id"], "gen-fallback");
+    }
+
+    #[tokio::test]
+    async fn client_error_does_not_fall_back() {
+        let harness = TestHarness::new().await;
+        harness
+            .mount_route(
+                "anthropic",
+                ResponseTemplate::new(400).set_body_json(serde_json::json!({
+                    "type": "error",
+                    "error": {"type": "invalid_request_error", "message": "bad request"}
+                })),
+                1,
+            )
+            .await;
+        harness.mount_route("", ResponseTemplate::new(200), 0).await;
+
+        let response = router(anthropic_first(&harness))
+            .oneshot(build_request(simple_message("Say hello")))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        let body = response_to_json(response).await;
+        assert_eq!(body["error"]["message"], "bad request");
+    }
+}
+
+mod usage {
+    use super::*;
+    use llm_proxy::{Budget, InMemoryUsageStore, PlanBudget, UsageLedger, UsagePlan};
+
+    fn metered(harness: &TestHarness) -> LlmProxyConfig {
+        let ledger = UsageLedger::new(Arc::new(InMemoryUsageStore::default())).with_budget(
+            UsagePlan::Free,
+            PlanBudget {
+                daily: Budget::tokens(10),
+                monthly: Budget::default(),
+            },
+        );
+        harness
+            .config_with_models(vec!["openai/gpt-4.1-nano"])
+            .with_route(
+                Arc::new(OpenAIProvider::new(harness.route_url("openai"))),
+                "openai-key",
+            )
+            .with_usage_ledger(ledger)
+    }
+
+    fn completion() -> ResponseTemplate {
+        ResponseTemplate::new(200).set_body_json(completion_response(
+            "chatcmpl-1",
+            "gpt-4.1-nano",
+            "hello",
+        ))
+    }
+
+    #[tokio::test]
+    async fn exhausted_budget_is_rejected() {
+        let harness = TestHarness::new().await;
+        harness.mount_route("openai", completion(), 1).await;
+        let app = router(metered(&harness));
+
+        let response = app
+            .clone()
+            .oneshot(with_user(
+                build_request(simple_message("Say hello")),
+                "user-1",
+                UsagePlan::Free,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        harness.analytics.get_single_event().await;
+
+        let response = app
+            .clone()
+            .oneshot(usage_request("user-1", UsagePlan::Free))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = response_to_json(response).await;
+        assert_eq!(body["plan"], "free");
+        assert_eq!(body["daily"]["used"]["tokens"], 11);
+        assert_eq!(body["daily"]["limit"]["tokens"], 10);
+        assert_eq!(body["monthly"]["used"]["tokens"], 11);
+
+        let response = app
+            .oneshot(with_user(
+                build_request(simple_message("Say hello")),
+                "user-1",
+                UsagePlan::Free,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        let body = response_to_json(response).await;
+        assert_eq!(body["error"]["type"], "budget_exceeded");
+        assert_eq!(body["error"]["window"], "daily");
+        assert_eq!(body["error"]["limit"], "tokens");
+    }
+
+    #[tokio::test]
+    async fn budgets_are_per_plan() {
+        let harness = TestHarness::new().await;
+        harness.mount_route("openai", completion(), 2).await;
+        let app = router(metered(&harness));
+
+        for _ in 0..2 {
+            let response = app
+                .clone()
+                .oneshot(with_user(
+                    build_request(simple_message("Say hello")),
+                    "user-1",
+                    UsagePlan::Pro,
+                ))
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+        }
+    }
+}
+
+mod cache {
+    use super::*;
+    use llm_proxy::ResponseCache;
+    use llm_proxy::cache::CACHE_STATUS_HEADER;
+
+    fn greedy(mut body: serde_json::Value) -> serde_json::Value {
+        body["temperature"] = 0.into();
+        body
+    }
+
+    fn completion() -> ResponseTemplate {
+        ResponseTemplate::new(200).set_body_json(completion_response(
+            "gen-cached",
+            "openai/gpt-4.1-nano",
+            "hello",
+        ))
+    }
+
+    #[tokio::test]
+    async fn non_streaming_hit() {
+        let harness = TestHarness::new().await;
+        harness.mount_route("", completion(), 1).await;
+        let app = router(
+            harness
+                .config()
+                .with_response_cache(ResponseCache::in_memory()),
+        );
+
+        let first = app
+            .clone()
+            .oneshot(build_request(greedy(simple_message("Say hello"))))
+            .await
+            .unwrap();
+        assert_eq!(first.headers()[CACHE_STATUS_HEADER], "MISS");
+        let first = response_to_json(first).await;
+
+        let second = app
+            .oneshot(build_request(greedy(simple_message("Say hello"))))
+            .await
+            .unwrap();
+        assert_eq!(second.status(), StatusCode::OK);
+        assert_eq!(second.headers()[CACHE_STATUS_HEADER], "HIT");
+        assert_eq!(response_to_json(second).await, first);
+
+        harness.analytics.get_single_event().await;
+    }
+
+    #[tokio::test]
+    async fn streaming_replay_is_identical() {
+        let harness = TestHarness::new().await;
+        harness
+            .mount_stream_response(&stream_chunks("gen-stream-cached"))
+            .await;
+        let app = router(
+            harness
+                .config()
+                .with_response_cache(ResponseCache::in_memory()),
+        );
+
+        let first = app
+            .clone()
+            .oneshot(build_request(greedy(stream_request("Say hello"))))
+            .await
+            .unwrap();
+        let first = response_to_string(first).await;
+
+        let second = app
+            .oneshot(build_request(greedy(stream_request("Say hello"))))
+            .await
+            .unwrap();
+        assert_eq!(second.headers()[CACHE_STATUS_HEADER], "HIT");
+        assert_eq!(second.headers()["Content-Type"], "text/event-stream");
+        assert_eq!(response_to_string(second).await, first);
+    }
+
+    #[tokio::test]
+    async fn sampled_and_no_cache_requests_bypass() {
+        let harness = TestHarness::new().await;
+        harness.mount_route("", completion(), 3).await;
+        let app = router(
+            harness
+                .config_no_analytics()
+                .with_response_cache(ResponseCache::in_memory()),
+        );
+
+        for _ in 0..2 {
+            let response = app
+                .clone()
+                .oneshot(build_request(simple_message("Say hello")))
+                .await
+                .unwrap();
+            assert!(response.headers().get(CACHE_STATUS_HEADER).is_none());
+        }
+
+        let mut request = build_request(greedy(simple_message("Say hello")));
+        request
+            .headers_mut()
+            .insert("Cache-Control", "no-cache".parse().unwrap());
+        let response = app.oneshot(request).await.unwrap();
+        assert!(response.headers().get(CACHE_STATUS_HEADER).is_none());
+    }
+
+    #[tokio::test]
+    async fn concurrent_duplicates_are_coalesced() {
+        let harness = TestHarness::new().await;
+        harness
+            .mount_route(
+                "",
+                completion().set_delay(std::time::Duration::from_millis(300)),
+                1,
+            )
+            .await;
+        let app = router(
+            harness
+                .config_no_analytics()
+                .with_response_cache(ResponseCache::in_memory()),
+        );
+
+        let requests = (0..3).map(|_| {
+            app.clone()
+                .oneshot(build_request(greedy(simple_message("Say hello"))))
+        });
+        let responses = futures_util::future::join_all(requests).await;
+
+        let mut statuses = vec![];
+        for response in responses {
+            let response = response.unwrap();
+            statuses.push(response.headers()[CACHE_STATUS_HEADER].clone());
+            assert_eq!(response_to_json(response).await["id"], "gen-cached");
+        }
+        assert_eq!(statuses.iter().filter(|s| *s == "MISS").count(), 1);
+    }
+}
+
+mod embeddings {
+    use super::*;
+    use axum::body::Body;
+    use axum::http::Request;
+
+    fn embedding_request(body: serde_json::Value) -> Request<Body> {
+        Request::builder()
+            .method("POST")
+            .uri("/embeddings")
+            .header("Content-Type", "application/json")
+            .body(Body::from(serde_json::to_string(&body).unwrap()))
+            .unwrap()
+    }
+
+    fn embedding() -> ResponseTemplate {
+        ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "object": "list",
+            "data": [{"object": "embedding", "index": 0, "embedding": [0.1, -0.2, 0.3]}],
+            "model": "openai/text-embedding-3-small",
+            "usage": {"prompt_tokens": 5, "total_tokens": 5}
+        }))
+    }
+
+    #[tokio::test]
+    async fn resolves_embedding_model() {
+        let harness = TestHarness::new().await;
+        Mock::given(method("POST"))
+            .and(path("/embeddings"))
+            .and(header("Authorization", "Bearer test-api-key"))
+            .and(body_partial_json(serde_json::json!({
+                "model": "openai/text-embedding-3-small",
+                "input": "hello",
+            })))
+            .respond_with(embedding())
+            .expect(1)
+            .mount(&harness.mock_server)
+            .await;
+
+        let response = router(harness.config())
+            .oneshot(embedding_request(serde_json::json!({"input": "hello"})))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = response_to_json(response).await;
+        assert_eq!(body["data"][0]["embedding"][1], -0.2);
+
+        let event = harness.analytics.get_single_event().await;
+        assert_eq!(event.model, "openai/text-embedding-3-small");
+        assert_eq!(event.input_tokens, 5);
+        assert_eq!(event.output_tokens, 0);
+    }
+
+    #[tokio::test]
+    async fn server_error_falls_back_to_same_model() {
+        let harness = TestHarness::new().await;
+        harness
+            .mount_route("openai/embeddings", ResponseTemplate::new(503), 1)
+            .await;
+        harness.mount_route("embeddings", embedding(), 1).await;
+
+        let config = harness.config().with_route(
+            Arc::new(OpenAIProvider::new(harness.route_url("openai"))),
+            "openai-key",
+        );
+        let response = router(config)
+            .oneshot(embedding_request(serde_json::json!({"input": ["a", "b"]})))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let event = harness.analytics.get_single_event().await;
+        assert_eq!(event.provider_name, "openrouter");
+    }
+
+    #[tokio::test]
+    async fn unresolved_model_is_rejected() {
+        let harness = TestHarness::new().await;
+        harness.mount_route("embeddings", embedding(), 0).await;
+
+        let response = router(harness.config())
+            .oneshot(embedding_request(serde_json::json!({
+                "model": "openai/text-embedding-3-large",
+                "input": "hello",
+            })))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+}
+
 mod e2e {
     use super::*;
 
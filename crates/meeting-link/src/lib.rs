@@ -0,0 +1,48 @@
+lazy_static::lazy_static! {
+    pub static ref MEETING_REGEXES: Vec<regex::Regex> = vec![
+        regex::Regex::new(r"https://meet\.google\.com/[a-z0-9]{3,4}-[a-z0-9]{3,4}-[a-z0-9]{3,4}").unwrap(),
+        regex::Regex::new(r"https://[a-z0-9.-]+\.zoom\.us/j/\d+(\?pwd=[a-zA-Z0-9.]+)?").unwrap(),
+        regex::Regex::new(r"https://app\.cal\.com/video/[a-zA-Z0-9]+").unwrap(),
+        regex::Regex::new(r"https://teams\.microsoft\.com/l/meetup-join/[^\s<>]+").unwrap(),
+        regex::Regex::new(r"https://teams\.live\.com/meet/\d+").unwrap(),
+    ];
+    static ref URL_REGEX: regex::Regex = regex::Regex::new(r"https?://[^\s]+").unwrap();
+}
+
+/// Finds a join URL for a known conferencing provider.
+pub fn parse_conference_link(text: &str) -> Option<String> {
+    MEETING_REGEXES
+        .iter()
+        .find_map(|regex| regex.find(text))
+        .map(|capture| capture.as_str().to_string())
+}
+
+/// Like [`parse_conference_link`], but falls back to the first URL in
+/// `text` when no known provider matches.
+pub fn parse_meeting_link(text: &str) -> Option<String> {
+    parse_conference_link(text).or_else(|| URL_REGEX.find(text).map(|c| c.as_str().to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_conference_link() {
+        assert_eq!(
+            parse_conference_link(
+                "Join: https://teams.microsoft.com/l/meetup-join/19%3ameeting_abc%40thread.v2/0 now"
+            )
+            .as_deref(),
+            Some("https://teams.microsoft.com/l/meetup-join/19%3ameeting_abc%40thread.v2/0")
+        );
+        assert_eq!(
+            parse_conference_link("Agenda: https://docs.example.com/agenda"),
+            None
+        );
+        assert_eq!(
+            parse_meeting_link("Agenda: https://docs.example.com/agenda").as_deref(),
+            Some("https://docs.example.com/agenda")
+        );
+    }
+}
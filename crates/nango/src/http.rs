@@ -1,5 +1,8 @@
 use crate::proxy::{NangoProxy, OwnedNangoProxy};
 
+// Nango forwards headers with this prefix to the provider, prefix stripped.
+const IF_MATCH: &str = "Nango-Proxy-If-Match";
+
 pub struct NangoHttpClient<'a> {
     proxy: NangoProxy<'a>,
 }
@@ -13,8 +16,7 @@ impl<'a> NangoHttpClient<'a> {
 impl<'a> hypr_http::HttpClient for NangoHttpClient<'a> {
     async fn get(&self, path: &str) -> Result<Vec<u8>, Box<dyn std::error::Error + Send + Sync>> {
         let response = self.proxy.get(path)?.send().await?;
-        let bytes = response.error_for_status()?.bytes().await?;
-        Ok(bytes.to_vec())
+        read_body(response).await
     }
 
     async fn post(
@@ -24,8 +26,7 @@ impl<'a> hypr_http::HttpClient for NangoHttpClient<'a> {
         content_type: &str,
     ) -> Result<Vec<u8>, Box<dyn std::error::Error + Send + Sync>> {
         let response = self.proxy.post(path, body, content_type)?.send().await?;
-        let bytes = response.error_for_status()?.bytes().await?;
-        Ok(bytes.to_vec())
+        read_body(response).await
     }
 
     async fn put(
@@ -35,8 +36,7 @@ impl<'a> hypr_http::HttpClient for NangoHttpClient<'a> {
     ) -> Result<Vec<u8>, Box<dyn std::error::Error + Send + Sync>> {
         let json_value: serde_json::Value = serde_json::from_slice(&body)?;
         let response = self.proxy.put(path, &json_value)?.send().await?;
-        let bytes = response.error_for_status()?.bytes().await?;
-        Ok(bytes.to_vec())
+        read_body(response).await
     }
 
     async fn patch(
@@ -46,8 +46,23 @@ impl<'a> hypr_http::HttpClient for NangoHttpClient<'a> {
     ) -> Result<Vec<u8>, Box<dyn std::error::Error + Send + Sync>> {
         let json_value: serde_json::Value = serde_json::from_slice(&body)?;
         let response = self.proxy.patch(path, &json_value)?.send().await?;
-        let bytes = response.error_for_status()?.bytes().await?;
-        Ok(bytes.to_vec())
+        read_body(response).await
+    }
+
+    async fn patch_if_match(
+        &self,
+        path: &str,
+        body: Vec<u8>,
+        etag: &str,
+    ) -> Result<Vec<u8>, Box<dyn std::error::Error + Send + Sync>> {
+        let json_value: serde_json::Value = serde_json::from_slice(&body)?;
+        let response = self
+            .proxy
+            .patch(path, &json_value)?
+            .header(IF_MATCH, etag)
+            .send()
+            .await?;
+        read_body(response).await
     }
 
     async fn delete(
@@ -55,8 +70,7 @@ impl<'a> hypr_http::HttpClient for NangoHttpClient<'a> {
         path: &str,
     ) -> Result<Vec<u8>, Box<dyn std::error::Error + Send + Sync>> {
         let response = self.proxy.delete(path)?.send().await?;
-        let bytes = response.error_for_status()?.bytes().await?;
-        Ok(bytes.to_vec())
+        read_body(response).await
     }
 }
 
@@ -74,8 +88,7 @@ impl OwnedNangoHttpClient {
 impl hypr_http::HttpClient for OwnedNangoHttpClient {
     async fn get(&self, path: &str) -> Result<Vec<u8>, Box<dyn std::error::Error + Send + Sync>> {
         let response = self.proxy.get(path)?.send().await?;
-        let bytes = response.error_for_status()?.bytes().await?;
-        Ok(bytes.to_vec())
+        read_body(response).await
     }
 
     async fn post(
@@ -85,8 +98,7 @@ impl hypr_http::HttpClient for OwnedNangoHttpClient {
         content_type: &str,
     ) -> Result<Vec<u8>, Box<dyn std::error::Error + Send + Sync>> {
         let response = self.proxy.post(path, body, content_type)?.send().await?;
-        let bytes = response.error_for_status()?.bytes().await?;
-        Ok(bytes.to_vec())
+        read_body(response).await
     }
 
     async fn put(
@@ -96,8 +108,7 @@ impl hypr_http::HttpClient for OwnedNangoHttpClient {
     ) -> Result<Vec<u8>, Box<dyn std::error::Error + Send + Sync>> {
         let json_value: serde_json::Value = serde_json::from_slice(&body)?;
         let response = self.proxy.put(path, &json_value)?.send().await?;
-        let bytes = response.error_for_status()?.bytes().await?;
-        Ok(bytes.to_vec())
+        read_body(response).await
     }
 
     async fn patch(
@@ -107,8 +118,23 @@ impl hypr_http::HttpClient for OwnedNangoHttpClient {
     ) -> Result<Vec<u8>, Box<dyn std::error::Error + Send + Sync>> {
         let json_value: serde_json::Value = serde_json::from_slice(&body)?;
         let response = self.proxy.patch(path, &json_value)?.send().await?;
-        let bytes = response.error_for_status()?.bytes().await?;
-        Ok(bytes.to_vec())
+        read_body(response).await
+    }
+
+    async fn patch_if_match(
+        &self,
+        path: &str,
+        body: Vec<u8>,
+        etag: &str,
+    ) -> Result<Vec<u8>, Box<dyn std::error::Error + Send + Sync>> {
+        let json_value: serde_json::Value = serde_json::from_slice(&body)?;
+        let response = self
+            .proxy
+            .patch(path, &json_value)?
+            .header(IF_MATCH, etag)
+            .send()
+            .await?;
+        read_body(response).await
     }
 
     async fn delete(
@@ -116,7 +142,20 @@ impl hypr_http::HttpClient for OwnedNangoHttpClient {
         path: &str,
     ) -> Result<Vec<u8>, Box<dyn std::error::Error + Send + Sync>> {
         let response = self.proxy.delete(path)?.send().await?;
-        let bytes = response.error_for_status()?.bytes().await?;
-        Ok(bytes.to_vec())
+        read_body(response).await
+    }
+}
+
+async fn read_body(
+    response: reqwest::Response,
+) -> Result<Vec<u8>, Box<dyn std::error::Error + Send + Sync>> {
+    let status = response.status();
+    let bytes = response.bytes().await?;
+    if !status.is_success() {
+        return Err(Box::new(hypr_http::StatusError {
+            status: status.as_u16(),
+            body: String::from_utf8_lossy(&bytes).into_owned(),
+        }));
     }
+    Ok(bytes.to_vec())
 }
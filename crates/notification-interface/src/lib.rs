@@ -96,6 +96,8 @@ pub enum NotificationSource {
     CalendarEvent { event_id: String },
     #[serde(rename = "mic_detected")]
     MicDetected { app_names: Vec<String> },
+    #[serde(rename = "auto_stop")]
+    AutoStop { session_id: String },
 }
 
 #[derive(Debug, Clone)]
@@ -115,6 +117,8 @@ pub struct Notification {
     pub participants: Option<Vec<Participant>>,
     pub event_details: Option<EventDetails>,
     pub action_label: Option<String>,
+    /// Offers to remind again later. Reported as a snooze, not a dismissal.
+    pub snooze_label: Option<String>,
 }
 
 impl Notification {
@@ -138,6 +142,7 @@ pub struct NotificationBuilder {
     participants: Option<Vec<Participant>>,
     event_details: Option<EventDetails>,
     action_label: Option<String>,
+    snooze_label: Option<String>,
 }
 
 impl NotificationBuilder {
@@ -186,6 +191,11 @@ impl NotificationBuilder {
         self
     }
 
+    pub fn snooze_label(mut self, snooze_label: impl Into<String>) -> Self {
+        self.snooze_label = Some(snooze_label.into());
+        self
+    }
+
     pub fn build(self) -> Notification {
         Notification {
             key: self.key,
@@ -197,6 +207,7 @@ impl NotificationBuilder {
             participants: self.participants,
             event_details: self.event_details,
             action_label: self.action_label,
+            snooze_label: self.snooze_label,
         }
     }
 }
@@ -17,6 +17,7 @@ thread_local! {
 
 static CONFIRM_CB: NotificationCallback = Mutex::new(None);
 static ACCEPT_CB: NotificationCallback = Mutex::new(None);
+static SNOOZE_CB: NotificationCallback = Mutex::new(None);
 static DISMISS_CB: NotificationCallback = Mutex::new(None);
 static TIMEOUT_CB: NotificationCallback = Mutex::new(None);
 
@@ -41,6 +42,13 @@ where
     *ACCEPT_CB.lock().unwrap() = Some(Box::new(f));
 }
 
+pub fn setup_notification_snooze_handler<F>(f: F)
+where
+    F: Fn(String) + Send + Sync + 'static,
+{
+    *SNOOZE_CB.lock().unwrap() = Some(Box::new(f));
+}
+
 pub fn setup_notification_timeout_handler<F>(f: F)
 where
     F: Fn(String) + Send + Sync + 'static,
@@ -62,6 +70,12 @@ fn call_accept_handler(key: String) {
     }
 }
 
+fn call_snooze_handler(key: String) {
+    if let Some(cb) = SNOOZE_CB.lock().unwrap().as_ref() {
+        cb(key);
+    }
+}
+
 fn call_dismiss_handler(key: String) {
     if let Some(cb) = DISMISS_CB.lock().unwrap().as_ref() {
         cb(key);
@@ -147,7 +161,14 @@ impl NotificationManager {
         }
     }
 
-    fn show(&mut self, key: String, title: String, message: String, timeout_seconds: f64) {
+    fn show(
+        &mut self,
+        key: String,
+        title: String,
+        message: String,
+        snooze_label: Option<String>,
+        timeout_seconds: f64,
+    ) {
         if !self.ensure_gtk() {
             return;
         }
@@ -170,7 +191,7 @@ impl NotificationManager {
         window.set_keep_above(true);
 
         self.setup_window_style(&window);
-        self.create_notification_content(&window, &title, &message, &key);
+        self.create_notification_content(&window, &title, &message, snooze_label.as_deref(), &key);
         self.position_window(&window);
 
         window.show_all();
@@ -241,7 +262,14 @@ impl NotificationManager {
         }
     }
 
-    fn create_notification_content(&self, window: &Window, title: &str, message: &str, key: &str) {
+    fn create_notification_content(
+        &self,
+        window: &Window,
+        title: &str,
+        message: &str,
+        snooze_label: Option<&str>,
+        key: &str,
+    ) {
         let main_box = GtkBox::new(Orientation::Horizontal, 8);
         main_box.set_margin_start(12);
         main_box.set_margin_end(12);
@@ -271,6 +299,21 @@ impl NotificationManager {
 
         main_box.pack_start(&text_box, true, true, 0);
 
+        if let Some(snooze_label) = snooze_label {
+            let snooze_button = Button::with_label(snooze_label);
+            snooze_button.style_context().add_class("action-button");
+            snooze_button.set_valign(Align::Center);
+
+            let key_clone = key.to_string();
+            let window_clone = window.clone();
+            snooze_button.connect_clicked(move |_| {
+                call_snooze_handler(key_clone.clone());
+                NotificationInstance::dismiss_window(&window_clone, &key_clone, false);
+            });
+
+            main_box.pack_start(&snooze_button, false, false, 0);
+        }
+
         let close_button = Button::new();
         close_button.set_label("×");
         close_button.style_context().add_class("close-button");
@@ -320,6 +363,14 @@ impl NotificationManager {
         });
     }
 
+    fn dismiss(&mut self, key: &str) {
+        if let Some(notif) = self.active_notifications.get(key) {
+            let window = notif.window.clone();
+            NotificationInstance::dismiss_window_inner(&window, key, false);
+            self.remove_notification_locked(key);
+        }
+    }
+
     fn dismiss_all(&mut self) {
         let keys: Vec<String> = self.active_notifications.keys().cloned().collect();
         for key in keys {
@@ -339,13 +390,23 @@ pub fn show(notification: &hypr_notification_interface::Notification) {
         .unwrap_or_else(|| notification.title.clone());
     let title = notification.title.clone();
     let message = notification.message.clone();
+    let snooze_label = notification.snooze_label.clone();
     let timeout_seconds = notification.timeout.map(|d| d.as_secs_f64()).unwrap_or(0.0);
 
     glib::MainContext::default().invoke(move || {
         NOTIFICATION_MANAGER.with(|manager| {
             manager
                 .borrow_mut()
-                .show(key, title, message, timeout_seconds);
+                .show(key, title, message, snooze_label, timeout_seconds);
+        });
+    });
+}
+
+pub fn dismiss(key: &str) {
+    let key = key.to_string();
+    glib::MainContext::default().invoke(move || {
+        NOTIFICATION_MANAGER.with(|manager| {
+            manager.borrow_mut().dismiss(&key);
         });
     });
 }
@@ -16,6 +16,9 @@ fn main() {
         setup_collapsed_confirm_handler(|id| {
             println!("collapsed_confirm: {}", id);
         });
+        setup_snooze_handler(|id| {
+            println!("snooze: {}", id);
+        });
         setup_dismiss_handler(|id| {
             println!("dismiss: {}", id);
         });
@@ -66,6 +69,7 @@ fn main() {
             .participants(participants)
             .event_details(event_details)
             .action_label("Join Zoom & Start listening")
+            .snooze_label("Snooze")
             .start_time(start_time)
             .build();
 
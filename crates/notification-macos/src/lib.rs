@@ -8,6 +8,8 @@ pub use hypr_notification_interface::*;
 
 swift!(fn _show_notification(json_payload: &SRString) -> Bool);
 
+swift!(fn _dismiss_notification(key: &SRString) -> Bool);
+
 swift!(fn _dismiss_all_notifications() -> Bool);
 
 macro_rules! define_notification_callback {
@@ -46,6 +48,7 @@ define_notification_callback!(
     setup_expanded_accept_handler,
     rust_on_expanded_accept
 );
+define_notification_callback!(SNOOZE_CB, setup_snooze_handler, rust_on_snooze);
 define_notification_callback!(DISMISS_CB, setup_dismiss_handler, rust_on_dismiss);
 define_notification_callback!(
     COLLAPSED_TIMEOUT_CB,
@@ -69,6 +72,7 @@ struct NotificationPayload<'a> {
     participants: Option<&'a [Participant]>,
     event_details: Option<&'a EventDetails>,
     action_label: Option<&'a str>,
+    snooze_label: Option<&'a str>,
 }
 
 pub fn show(notification: &hypr_notification_interface::Notification) {
@@ -87,6 +91,7 @@ pub fn show(notification: &hypr_notification_interface::Notification) {
         participants: notification.participants.as_deref(),
         event_details: notification.event_details.as_ref(),
         action_label: notification.action_label.as_deref(),
+        snooze_label: notification.snooze_label.as_deref(),
     };
 
     let json = serde_json::to_string(&payload).unwrap();
@@ -97,6 +102,14 @@ pub fn show(notification: &hypr_notification_interface::Notification) {
     }
 }
 
+pub fn dismiss(key: &str) {
+    let key = SRString::from(key);
+
+    unsafe {
+        _dismiss_notification(&key);
+    }
+}
+
 pub fn dismiss_all() {
     unsafe {
         _dismiss_all_notifications();
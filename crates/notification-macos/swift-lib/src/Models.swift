@@ -21,6 +21,7 @@ struct NotificationPayload: Codable {
   let participants: [Participant]?
   let eventDetails: EventDetails?
   let actionLabel: String?
+  let snoozeLabel: String?
 
   var isPersistent: Bool {
     return timeoutSeconds <= 0
@@ -162,6 +162,14 @@ class ActionButton: NotificationButton {
   }
 }
 
+class SnoozeButton: NotificationButton {
+  override func performAction() {
+    guard let notification = notification else { return }
+    RustBridge.onSnooze(key: notification.key)
+    notification.dismiss()
+  }
+}
+
 class DetailsButton: NotificationButton {
   override func performAction() {
     notification?.toggleExpansion()
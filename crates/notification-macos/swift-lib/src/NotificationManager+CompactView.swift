@@ -70,14 +70,26 @@ extension NotificationManager {
       detailsButton.notification = notification
       detailsButton.setContentHuggingPriority(.required, for: .horizontal)
       container.addArrangedSubview(detailsButton)
+      addSnoozeButton(to: container, notification: notification)
     } else {
       let actionButton = ActionButton()
       actionButton.title = notification.payload.actionLabel ?? "Take Notes"
       actionButton.notification = notification
       actionButton.setContentHuggingPriority(.required, for: .horizontal)
       container.addArrangedSubview(actionButton)
+      addSnoozeButton(to: container, notification: notification)
     }
 
     return container
   }
+
+  private func addSnoozeButton(to container: NSStackView, notification: NotificationInstance) {
+    guard let snoozeLabel = notification.payload.snoozeLabel else { return }
+
+    let snoozeButton = SnoozeButton()
+    snoozeButton.title = snoozeLabel
+    snoozeButton.notification = notification
+    snoozeButton.setContentHuggingPriority(.required, for: .horizontal)
+    container.addArrangedSubview(snoozeButton)
+  }
 }
@@ -178,6 +178,14 @@ extension NotificationManager {
     timerLabel.alignment = .center
 
     stack.addArrangedSubview(actionButton)
+
+    if let snoozeLabel = notification.payload.snoozeLabel {
+      let snoozeButton = SnoozeButton()
+      snoozeButton.title = snoozeLabel
+      snoozeButton.notification = notification
+      stack.addArrangedSubview(snoozeButton)
+    }
+
     stack.addArrangedSubview(timerLabel)
 
     actionButton.widthAnchor.constraint(equalTo: stack.widthAnchor, constant: -24).isActive = true
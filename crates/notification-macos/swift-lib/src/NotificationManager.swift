@@ -34,6 +34,12 @@ class NotificationManager {
     }
   }
 
+  func dismiss(key: String) {
+    DispatchQueue.main.async { [weak self] in
+      self?.activeNotifications[key]?.dismiss()
+    }
+  }
+
   func dismissAll() {
     activeNotifications.values.forEach { $0.dismiss() }
   }
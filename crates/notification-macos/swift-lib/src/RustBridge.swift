@@ -6,6 +6,9 @@ private func rustOnCollapsedConfirm(_ keyPtr: UnsafePointer<CChar>)
 @_silgen_name("rust_on_expanded_accept")
 private func rustOnExpandedAccept(_ keyPtr: UnsafePointer<CChar>)
 
+@_silgen_name("rust_on_snooze")
+private func rustOnSnooze(_ keyPtr: UnsafePointer<CChar>)
+
 @_silgen_name("rust_on_dismiss")
 private func rustOnDismiss(_ keyPtr: UnsafePointer<CChar>)
 
@@ -28,6 +31,12 @@ enum RustBridge {
     }
   }
 
+  static func onSnooze(key: String) {
+    key.withCString { keyPtr in
+      rustOnSnooze(keyPtr)
+    }
+  }
+
   static func onDismiss(key: String) {
     key.withCString { keyPtr in
       rustOnDismiss(keyPtr)
@@ -17,6 +17,12 @@ public func _showNotification(jsonPayload: SRString) -> Bool {
   return true
 }
 
+@_cdecl("_dismiss_notification")
+public func _dismissNotification(key: SRString) -> Bool {
+  NotificationManager.shared.dismiss(key: key.toString())
+  return true
+}
+
 @_cdecl("_dismiss_all_notifications")
 public func _dismissAllNotifications() -> Bool {
   NotificationManager.shared.dismissAll()
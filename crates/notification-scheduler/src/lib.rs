@@ -0,0 +1,51 @@
+mod scheduler;
+
+pub use scheduler::ReminderScheduler;
+
+use chrono::{DateTime, Utc};
+use hypr_notification_interface::Participant;
+
+pub trait Clock: Send + Sync {
+    fn now(&self) -> DateTime<Utc>;
+}
+
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// One occurrence of a calendar event, from any calendar source.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct UpcomingEvent {
+    /// Unique per occurrence. Reported back as the notification's
+    /// `calendar_event` source when the user acts on it.
+    pub id: String,
+    pub title: String,
+    pub start: DateTime<Utc>,
+    pub timezone: Option<String>,
+    pub location: Option<String>,
+    pub meeting_url: Option<String>,
+    pub participants: Vec<Participant>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, specta::Type)]
+pub struct ReminderSettings {
+    pub enabled: bool,
+    /// Minutes before the start to remind at. Each fires once per
+    /// occurrence.
+    pub lead_minutes: Vec<u32>,
+    pub snooze_minutes: u32,
+}
+
+impl Default for ReminderSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            lead_minutes: vec![5],
+            snooze_minutes: 2,
+        }
+    }
+}
@@ -0,0 +1,437 @@
+use std::collections::{BTreeSet, HashMap};
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use hypr_notification_interface::{
+    EventDetails, Notification, NotificationEvent, NotificationKey, NotificationSource,
+};
+
+use crate::{Clock, ReminderSettings, SystemClock, UpcomingEvent};
+
+const NOTIFICATION_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// How long after the start a snoozed reminder may still fire, as
+/// "starting now".
+const STARTED_GRACE: chrono::Duration = chrono::Duration::minutes(1);
+
+#[derive(Debug, Clone)]
+struct Occurrence {
+    start: DateTime<Utc>,
+    fired: BTreeSet<u32>,
+    snoozed_until: Option<DateTime<Utc>>,
+    /// Dismissed, or acted on; no more reminders.
+    done: bool,
+}
+
+impl Occurrence {
+    fn new(start: DateTime<Utc>) -> Self {
+        Self {
+            start,
+            fired: BTreeSet::new(),
+            snoozed_until: None,
+            done: false,
+        }
+    }
+}
+
+/// Decides when to remind about upcoming events. Holds no timers: callers
+/// show what [`Self::due`] returns and call it again at
+/// [`Self::next_wakeup`] or whenever the events change.
+///
+/// Reminders are keyed by [`NotificationKey::to_dedup_key`] of the
+/// occurrence, so a moved event is reminded about again at its new time.
+pub struct ReminderScheduler {
+    clock: Arc<dyn Clock>,
+    settings: ReminderSettings,
+    events: Vec<UpcomingEvent>,
+    occurrences: HashMap<String, Occurrence>,
+}
+
+impl Default for ReminderScheduler {
+    fn default() -> Self {
+        Self::new(ReminderSettings::default())
+    }
+}
+
+impl ReminderScheduler {
+    pub fn new(settings: ReminderSettings) -> Self {
+        Self {
+            clock: Arc::new(SystemClock),
+            settings,
+            events: vec![],
+            occurrences: HashMap::new(),
+        }
+    }
+
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    pub fn settings(&self) -> &ReminderSettings {
+        &self.settings
+    }
+
+    pub fn set_settings(&mut self, settings: ReminderSettings) {
+        self.settings = settings;
+    }
+
+    /// Replaces the watched events. State for occurrences that are gone is
+    /// dropped; dismissals and snoozes of the rest are kept.
+    pub fn set_events(&mut self, events: Vec<UpcomingEvent>) {
+        let keys: BTreeSet<String> = events.iter().map(dedup_key).collect();
+        self.occurrences.retain(|key, _| keys.contains(key));
+        self.events = events;
+    }
+
+    /// Reminders to show now. Each is returned once; lead times that passed
+    /// together (say, after waking from sleep) produce a single reminder.
+    pub fn due(&mut self) -> Vec<Notification> {
+        if !self.settings.enabled {
+            return vec![];
+        }
+
+        let now = self.clock.now();
+        let leads = self.lead_minutes();
+        let mut due = vec![];
+
+        for event in &self.events {
+            let occurrence = self
+                .occurrences
+                .entry(dedup_key(event))
+                .or_insert_with(|| Occurrence::new(event.start));
+            if occurrence.start != event.start {
+                *occurrence = Occurrence::new(event.start);
+            }
+            if occurrence.done {
+                continue;
+            }
+
+            let passed: Vec<u32> = leads
+                .iter()
+                .copied()
+                .filter(|lead| event.start - minutes(*lead) <= now)
+                .filter(|lead| !occurrence.fired.contains(lead))
+                .collect();
+
+            match occurrence.snoozed_until {
+                Some(until) if now < until => continue,
+                Some(_) => {
+                    if now >= event.start + STARTED_GRACE {
+                        continue;
+                    }
+                    occurrence.snoozed_until = None;
+                }
+                None => {
+                    if passed.is_empty() || now >= event.start {
+                        continue;
+                    }
+                }
+            }
+
+            occurrence.fired.extend(passed);
+            due.push(reminder(event, now));
+        }
+
+        due
+    }
+
+    /// When [`Self::due`] next has something to return, if ever.
+    pub fn next_wakeup(&self) -> Option<DateTime<Utc>> {
+        if !self.settings.enabled {
+            return None;
+        }
+
+        let now = self.clock.now();
+        let leads = self.lead_minutes();
+
+        self.events
+            .iter()
+            .filter_map(|event| {
+                let occurrence = self.occurrences.get(&dedup_key(event));
+                if occurrence.is_some_and(|o| o.done) {
+                    return None;
+                }
+                if let Some(until) = occurrence.and_then(|o| o.snoozed_until) {
+                    return Some(until);
+                }
+
+                leads
+                    .iter()
+                    .filter(|lead| !occurrence.is_some_and(|o| o.fired.contains(lead)))
+                    .map(|lead| event.start - minutes(*lead))
+                    .filter(|at| *at > now)
+                    .min()
+            })
+            .min()
+    }
+
+    /// Records what the user did with a reminder. Dismissing or starting
+    /// to listen ends reminders for the occurrence; a timeout leaves later
+    /// lead times in place.
+    pub fn handle(&mut self, key: &str, event: NotificationEvent) {
+        let Some(occurrence) = self.occurrences.get_mut(key) else {
+            return;
+        };
+
+        match event {
+            NotificationEvent::Dismiss | NotificationEvent::Accept | NotificationEvent::Confirm => {
+                occurrence.done = true;
+            }
+            NotificationEvent::Timeout => {}
+        }
+    }
+
+    /// Reminds again after `minutes`, or the configured snooze. Lead times
+    /// passing meanwhile are skipped. A snooze past the start reminds at
+    /// the start.
+    pub fn snooze(&mut self, key: &str, minutes: Option<u32>) {
+        let now = self.clock.now();
+        let snooze = self::minutes(minutes.unwrap_or(self.settings.snooze_minutes).max(1));
+
+        if let Some(occurrence) = self.occurrences.get_mut(key) {
+            occurrence.snoozed_until = Some((now + snooze).min(occurrence.start).max(now));
+        }
+    }
+
+    fn lead_minutes(&self) -> Vec<u32> {
+        let leads: BTreeSet<u32> = self
+            .settings
+            .lead_minutes
+            .iter()
+            .copied()
+            .filter(|m| *m > 0)
+            .collect();
+        leads.into_iter().collect()
+    }
+}
+
+fn dedup_key(event: &UpcomingEvent) -> String {
+    NotificationKey::calendar_event(&event.id).to_dedup_key()
+}
+
+fn minutes(m: u32) -> chrono::Duration {
+    chrono::Duration::minutes(m.into())
+}
+
+fn reminder(event: &UpcomingEvent, now: DateTime<Utc>) -> Notification {
+    let seconds = (event.start - now).num_seconds();
+    let message = match (seconds + 59) / 60 {
+        ..=0 => "Starting now".to_string(),
+        1 => "Starting in 1 minute".to_string(),
+        n => format!("Starting in {n} minutes"),
+    };
+
+    let mut builder = Notification::builder()
+        .key(dedup_key(event))
+        .title(&event.title)
+        .message(message)
+        .timeout(NOTIFICATION_TIMEOUT)
+        .source(NotificationSource::CalendarEvent {
+            event_id: event.id.clone(),
+        })
+        .start_time(event.start.timestamp())
+        .event_details(EventDetails {
+            what: event.title.clone(),
+            timezone: event.timezone.clone(),
+            location: event.meeting_url.clone().or(event.location.clone()),
+        })
+        .action_label("Start listening")
+        .snooze_label("Snooze");
+
+    if !event.participants.is_empty() {
+        builder = builder.participants(event.participants.clone());
+    }
+
+    builder.build()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use super::*;
+
+    struct FakeClock(Mutex<DateTime<Utc>>);
+
+    impl FakeClock {
+        fn at(time: &str) -> Arc<Self> {
+            Arc::new(Self(Mutex::new(time.parse().unwrap())))
+        }
+
+        fn set(&self, time: &str) {
+            *self.0.lock().unwrap() = time.parse().unwrap();
+        }
+    }
+
+    impl Clock for FakeClock {
+        fn now(&self) -> DateTime<Utc> {
+            *self.0.lock().unwrap()
+        }
+    }
+
+    fn event(id: &str, start: &str) -> UpcomingEvent {
+        UpcomingEvent {
+            id: id.to_string(),
+            title: format!("Meeting {id}"),
+            start: start.parse().unwrap(),
+            timezone: None,
+            location: Some("Room 4".to_string()),
+            meeting_url: None,
+            participants: vec![],
+        }
+    }
+
+    fn scheduler(clock: &Arc<FakeClock>, lead_minutes: Vec<u32>) -> ReminderScheduler {
+        ReminderScheduler::new(ReminderSettings {
+            enabled: true,
+            lead_minutes,
+            snooze_minutes: 2,
+        })
+        .with_clock(clock.clone())
+    }
+
+    fn messages(notifications: &[Notification]) -> Vec<(String, String)> {
+        notifications
+            .iter()
+            .map(|n| (n.key.clone().unwrap(), n.message.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn test_fires_once_per_lead_time() {
+        let clock = FakeClock::at("2026-03-02T09:40:00Z");
+        let mut scheduler = scheduler(&clock, vec![10, 1]);
+        scheduler.set_events(vec![event("a", "2026-03-02T10:00:00Z")]);
+
+        assert!(scheduler.due().is_empty());
+        assert_eq!(
+            scheduler.next_wakeup(),
+            Some("2026-03-02T09:50:00Z".parse().unwrap())
+        );
+
+        clock.set("2026-03-02T09:50:00Z");
+        let due = scheduler.due();
+        assert_eq!(
+            messages(&due),
+            [("event:a".into(), "Starting in 10 minutes".into())]
+        );
+        assert_eq!(due[0].action_label.as_deref(), Some("Start listening"));
+        assert_eq!(due[0].snooze_label.as_deref(), Some("Snooze"));
+        assert!(matches!(
+            &due[0].source,
+            Some(NotificationSource::CalendarEvent { event_id }) if event_id == "a"
+        ));
+        assert!(scheduler.due().is_empty());
+
+        clock.set("2026-03-02T09:59:00Z");
+        assert_eq!(
+            messages(&scheduler.due()),
+            [("event:a".into(), "Starting in 1 minute".into())]
+        );
+        assert_eq!(scheduler.next_wakeup(), None);
+
+        clock.set("2026-03-02T10:00:30Z");
+        assert!(scheduler.due().is_empty());
+    }
+
+    #[test]
+    fn test_missed_lead_times_collapse() {
+        let clock = FakeClock::at("2026-03-02T09:58:30Z");
+        let mut scheduler = scheduler(&clock, vec![15, 5, 1]);
+        scheduler.set_events(vec![
+            event("a", "2026-03-02T10:00:00Z"),
+            event("past", "2026-03-02T09:30:00Z"),
+        ]);
+
+        assert_eq!(
+            messages(&scheduler.due()),
+            [("event:a".into(), "Starting in 2 minutes".into())]
+        );
+        clock.set("2026-03-02T09:58:45Z");
+        assert!(scheduler.due().is_empty());
+        clock.set("2026-03-02T09:59:00Z");
+        assert_eq!(scheduler.due().len(), 1);
+    }
+
+    #[test]
+    fn test_dismissal_and_rescheduling() {
+        let clock = FakeClock::at("2026-03-02T09:55:00Z");
+        let mut scheduler = scheduler(&clock, vec![5, 1]);
+        scheduler.set_events(vec![event("a", "2026-03-02T10:00:00Z")]);
+
+        assert_eq!(scheduler.due().len(), 1);
+        scheduler.handle("event:a", NotificationEvent::Dismiss);
+        assert_eq!(scheduler.next_wakeup(), None);
+
+        clock.set("2026-03-02T09:59:00Z");
+        assert!(scheduler.due().is_empty());
+
+        // Moved to later: a new occurrence as far as reminders go.
+        scheduler.set_events(vec![event("a", "2026-03-02T11:00:00Z")]);
+        clock.set("2026-03-02T10:55:00Z");
+        assert_eq!(scheduler.due().len(), 1);
+    }
+
+    #[test]
+    fn test_timeout_keeps_later_reminders() {
+        let clock = FakeClock::at("2026-03-02T09:55:00Z");
+        let mut scheduler = scheduler(&clock, vec![5, 1]);
+        scheduler.set_events(vec![event("a", "2026-03-02T10:00:00Z")]);
+
+        assert_eq!(scheduler.due().len(), 1);
+        scheduler.handle("event:a", NotificationEvent::Timeout);
+
+        clock.set("2026-03-02T09:59:00Z");
+        assert_eq!(scheduler.due().len(), 1);
+    }
+
+    #[test]
+    fn test_snooze() {
+        let clock = FakeClock::at("2026-03-02T09:50:00Z");
+        let mut scheduler = scheduler(&clock, vec![10, 9]);
+        scheduler.set_events(vec![event("a", "2026-03-02T10:00:00Z")]);
+
+        assert_eq!(scheduler.due().len(), 1);
+        scheduler.snooze("event:a", None);
+        assert_eq!(
+            scheduler.next_wakeup(),
+            Some("2026-03-02T09:52:00Z".parse().unwrap())
+        );
+
+        // The 9-minute reminder falls inside the snooze.
+        clock.set("2026-03-02T09:51:00Z");
+        assert!(scheduler.due().is_empty());
+
+        clock.set("2026-03-02T09:52:00Z");
+        assert_eq!(
+            messages(&scheduler.due()),
+            [("event:a".into(), "Starting in 8 minutes".into())]
+        );
+        assert!(scheduler.due().is_empty());
+
+        // Snoozing past the start reminds at the start.
+        clock.set("2026-03-02T09:59:00Z");
+        scheduler.snooze("event:a", Some(5));
+        clock.set("2026-03-02T10:00:00Z");
+        assert_eq!(
+            messages(&scheduler.due()),
+            [("event:a".into(), "Starting now".into())]
+        );
+    }
+
+    #[test]
+    fn test_disabled() {
+        let clock = FakeClock::at("2026-03-02T09:55:00Z");
+        let mut scheduler = scheduler(&clock, vec![5]);
+        scheduler.set_events(vec![event("a", "2026-03-02T10:00:00Z")]);
+        scheduler.set_settings(ReminderSettings {
+            enabled: false,
+            ..ReminderSettings::default()
+        });
+
+        assert!(scheduler.due().is_empty());
+        assert_eq!(scheduler.next_wakeup(), None);
+    }
+}
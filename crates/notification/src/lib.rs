@@ -79,6 +79,18 @@ pub fn show(notification: &hypr_notification_interface::Notification) {
     show_inner(notification);
 }
 
+/// Closes the notification shown under `key`, without reporting it as
+/// dismissed by the user.
+pub fn dismiss(key: &str) {
+    #[cfg(all(feature = "legacy", target_os = "macos"))]
+    hypr_notification_macos::dismiss(key);
+
+    #[cfg(all(feature = "legacy", target_os = "linux"))]
+    hypr_notification_linux::dismiss(key);
+
+    let _ = key;
+}
+
 pub fn clear() {
     #[cfg(all(feature = "legacy", target_os = "macos"))]
     hypr_notification_macos::dismiss_all();
@@ -162,6 +174,31 @@ where
     let _ = f;
 }
 
+pub fn setup_snooze_handler<F>(f: F)
+where
+    F: Fn(NotificationContext) + Send + Sync + 'static,
+{
+    let f = std::sync::Arc::new(f);
+
+    #[cfg(all(feature = "legacy", target_os = "macos"))]
+    {
+        let f = f.clone();
+        hypr_notification_macos::setup_snooze_handler(move |key| {
+            f(get_context(&key));
+        });
+    }
+
+    #[cfg(all(feature = "legacy", target_os = "linux"))]
+    {
+        let f = f.clone();
+        hypr_notification_linux::setup_notification_snooze_handler(move |key| {
+            f(get_context(&key));
+        });
+    }
+
+    let _ = f;
+}
+
 pub fn setup_collapsed_timeout_handler<F>(f: F)
 where
     F: Fn(NotificationContext) + Send + Sync + 'static,
@@ -0,0 +1,18 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum Error {
+    #[error("io error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("frontmatter error: {0}")]
+    Frontmatter(#[from] hypr_frontmatter::Error),
+
+    #[error("failed to convert markdown: {0}")]
+    Markdown(String),
+
+    #[error("not a directory: {0}")]
+    NotADirectory(String),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
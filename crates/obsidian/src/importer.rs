@@ -0,0 +1,359 @@
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use hypr_frontmatter::Document;
+use hypr_importer_core::ir::{Collection, Human, Session, SessionParticipant, Tag, TagMapping};
+use serde_yaml::Value;
+
+use crate::error::{Error, Result};
+use crate::link::{normalize_target, rewrite};
+
+const TITLE_KEYS: &[&str] = &["title"];
+const DATE_KEYS: &[&str] = &["created", "created_at", "date"];
+const PEOPLE_KEYS: &[&str] = &["attendees", "participants", "people"];
+const SESSION_ID_KEYS: &[&str] = &["session_id", "id"];
+
+struct NoteFile {
+    relative_path: PathBuf,
+    title: String,
+    frontmatter: HashMap<String, Value>,
+    body: String,
+    modified_at: Option<String>,
+}
+
+/// Imports every markdown note under `dir` (an Obsidian vault or any folder
+/// of `.md` files) as a session.
+pub fn import_from_path(dir: &Path) -> Result<Collection> {
+    if !dir.is_dir() {
+        return Err(Error::NotADirectory(dir.display().to_string()));
+    }
+
+    let mut paths = Vec::new();
+    collect_markdown_files(dir, &mut paths)?;
+    paths.sort();
+
+    let notes = paths
+        .iter()
+        .map(|path| read_note(dir, path))
+        .collect::<Result<Vec<_>>>()?;
+
+    let ids: Vec<String> = notes.iter().map(session_id_for).collect();
+
+    let mut targets = HashMap::new();
+    for (note, id) in notes.iter().zip(&ids) {
+        let stem = note
+            .relative_path
+            .file_stem()
+            .map(|s| s.to_string_lossy().to_string())
+            .unwrap_or_default();
+        targets.insert(normalize_target(&stem), id.clone());
+        for alias in string_list(note.frontmatter.get("aliases")) {
+            targets
+                .entry(normalize_target(&alias))
+                .or_insert_with(|| id.clone());
+        }
+    }
+
+    let mut collection = Collection::default();
+    let mut tag_ids: HashMap<String, String> = HashMap::new();
+    let mut human_ids: HashSet<String> = HashSet::new();
+
+    for (note, id) in notes.into_iter().zip(ids) {
+        let mut doc = hypr_tiptap::md_to_tiptap_json(&note.body).map_err(Error::Markdown)?;
+        let inline_tags = rewrite(&mut doc, &targets);
+
+        let created_at = first_value(&note.frontmatter, DATE_KEYS)
+            .and_then(value_to_datetime)
+            .or(note.modified_at)
+            .unwrap_or_default();
+
+        let mut tags: BTreeSet<String> = string_list(note.frontmatter.get("tags"))
+            .into_iter()
+            .flat_map(|t| {
+                t.split([',', ' '])
+                    .map(|s| s.trim().trim_start_matches('#').to_string())
+                    .collect::<Vec<_>>()
+            })
+            .filter(|t| !t.is_empty())
+            .collect();
+        tags.extend(inline_tags);
+
+        for name in tags {
+            let tag_id = tag_ids
+                .entry(name.clone())
+                .or_insert_with(|| {
+                    let tag_id =
+                        uuid::Uuid::new_v5(&uuid::Uuid::NAMESPACE_OID, name.as_bytes()).to_string();
+                    collection.tags.push(Tag {
+                        id: tag_id.clone(),
+                        user_id: String::new(),
+                        name: name.clone(),
+                    });
+                    tag_id
+                })
+                .clone();
+
+            collection.tag_mappings.push(TagMapping {
+                id: format!("{}_{}", tag_id, id),
+                user_id: String::new(),
+                tag_id,
+                session_id: id.clone(),
+            });
+        }
+
+        for key in PEOPLE_KEYS {
+            for name in string_list(note.frontmatter.get(*key)) {
+                let name = strip_wikilink(&name);
+                if name.is_empty() {
+                    continue;
+                }
+
+                let human_id = human_id_for(&name);
+                if human_ids.insert(human_id.clone()) {
+                    collection.humans.push(Human {
+                        id: human_id.clone(),
+                        user_id: String::new(),
+                        created_at: created_at.clone(),
+                        name: name.clone(),
+                        email: None,
+                        org_id: None,
+                        job_title: None,
+                        linkedin_username: None,
+                    });
+                }
+
+                collection.participants.push(SessionParticipant {
+                    id: format!("{}-{}", id, human_id),
+                    user_id: String::new(),
+                    session_id: id.clone(),
+                    human_id,
+                    source: "imported".to_string(),
+                });
+            }
+        }
+
+        collection.sessions.push(Session {
+            id,
+            user_id: String::new(),
+            created_at,
+            title: first_value(&note.frontmatter, TITLE_KEYS)
+                .and_then(|v| v.as_str())
+                .map(|s| s.to_string())
+                .unwrap_or(note.title),
+            raw_md: Some(doc.to_string()),
+            enhanced_content: None,
+            folder_id: None,
+            event_id: None,
+        });
+    }
+
+    Ok(collection)
+}
+
+fn collect_markdown_files(dir: &Path, out: &mut Vec<PathBuf>) -> Result<()> {
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        let hidden = path
+            .file_name()
+            .is_some_and(|n| n.to_string_lossy().starts_with('.'));
+        if hidden {
+            continue;
+        }
+
+        if path.is_dir() {
+            collect_markdown_files(&path, out)?;
+        } else if path.extension().is_some_and(|ext| ext == "md") {
+            out.push(path);
+        }
+    }
+
+    Ok(())
+}
+
+fn read_note(root: &Path, path: &Path) -> Result<NoteFile> {
+    let content = std::fs::read_to_string(path)?;
+    let (frontmatter, body) = match Document::<HashMap<String, Value>>::from_str(&content) {
+        Ok(doc) => (doc.frontmatter, doc.content),
+        // Notes without (or with broken) frontmatter are imported as plain text.
+        Err(_) => (HashMap::new(), content),
+    };
+
+    let modified_at = std::fs::metadata(path)
+        .and_then(|m| m.modified())
+        .ok()
+        .map(|t| chrono::DateTime::<chrono::Utc>::from(t).to_rfc3339());
+
+    Ok(NoteFile {
+        relative_path: path.strip_prefix(root).unwrap_or(path).to_path_buf(),
+        title: path
+            .file_stem()
+            .map(|s| s.to_string_lossy().to_string())
+            .unwrap_or_default(),
+        frontmatter,
+        body,
+        modified_at,
+    })
+}
+
+/// Notes written by the mirror carry their original `session_id`, so a
+/// round trip updates the same session instead of creating a copy.
+fn session_id_for(note: &NoteFile) -> String {
+    first_value(&note.frontmatter, SESSION_ID_KEYS)
+        .and_then(|v| v.as_str())
+        .and_then(|s| uuid::Uuid::parse_str(s).ok())
+        .map(|id| id.to_string())
+        .unwrap_or_else(|| {
+            let key = format!("obsidian:{}", note.relative_path.to_string_lossy());
+            uuid::Uuid::new_v5(&uuid::Uuid::NAMESPACE_OID, key.as_bytes()).to_string()
+        })
+}
+
+fn human_id_for(name: &str) -> String {
+    let key = format!("human:{}", name.trim().to_lowercase());
+    uuid::Uuid::new_v5(&uuid::Uuid::NAMESPACE_OID, key.as_bytes()).to_string()
+}
+
+fn first_value<'a>(frontmatter: &'a HashMap<String, Value>, keys: &[&str]) -> Option<&'a Value> {
+    keys.iter().find_map(|k| frontmatter.get(*k))
+}
+
+fn string_list(value: Option<&Value>) -> Vec<String> {
+    match value {
+        Some(Value::String(s)) => vec![s.clone()],
+        Some(Value::Sequence(items)) => items
+            .iter()
+            .filter_map(|v| v.as_str().map(|s| s.to_string()))
+            .collect(),
+        _ => vec![],
+    }
+}
+
+fn strip_wikilink(s: &str) -> String {
+    let s = s.trim();
+    let inner = s
+        .strip_prefix("[[")
+        .and_then(|s| s.strip_suffix("]]"))
+        .unwrap_or(s);
+    inner
+        .split('|')
+        .next_back()
+        .unwrap_or(inner)
+        .trim()
+        .to_string()
+}
+
+fn value_to_datetime(value: &Value) -> Option<String> {
+    let s = value.as_str()?.trim();
+
+    if let Ok(dt) = chrono::DateTime::parse_from_rfc3339(s) {
+        return Some(dt.to_rfc3339());
+    }
+    for format in ["%Y-%m-%dT%H:%M:%S", "%Y-%m-%d %H:%M:%S", "%Y-%m-%d %H:%M"] {
+        if let Ok(dt) = chrono::NaiveDateTime::parse_from_str(s, format) {
+            return Some(dt.and_utc().to_rfc3339());
+        }
+    }
+    chrono::NaiveDate::parse_from_str(s, "%Y-%m-%d")
+        .ok()
+        .and_then(|d| d.and_hms_opt(0, 0, 0))
+        .map(|dt| dt.and_utc().to_rfc3339())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write(root: &Path, path: &str, content: &str) {
+        let path = root.join(path);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, content).unwrap();
+    }
+
+    #[test]
+    fn test_import_from_path() {
+        let dir = tempfile::tempdir().unwrap();
+        write(
+            dir.path(),
+            "Meetings/Weekly Sync.md",
+            "---\ntitle: Weekly Sync (Jan)\ndate: 2024-01-15\ntags: [meeting, team]\nattendees:\n  - \"[[Jane Doe]]\"\n  - John Smith\n---\n# Agenda\n\nFollow up on [[Roadmap|the roadmap]] #followup\n",
+        );
+        write(
+            dir.path(),
+            "Roadmap.md",
+            "Plain note without frontmatter.\n",
+        );
+        write(dir.path(), ".obsidian/workspace.md", "ignored");
+
+        let collection = import_from_path(dir.path()).unwrap();
+
+        assert_eq!(collection.sessions.len(), 2);
+        let roadmap = collection
+            .sessions
+            .iter()
+            .find(|s| s.title == "Roadmap")
+            .unwrap();
+        let sync = collection
+            .sessions
+            .iter()
+            .find(|s| s.title == "Weekly Sync (Jan)")
+            .unwrap();
+        assert_eq!(sync.created_at, "2024-01-15T00:00:00+00:00");
+
+        let doc: serde_json::Value = serde_json::from_str(sync.raw_md.as_ref().unwrap()).unwrap();
+        assert_eq!(doc["content"][0]["type"], "heading");
+        let mention = &doc["content"][1]["content"][1];
+        assert_eq!(mention["type"], "mention-@");
+        assert_eq!(mention["attrs"]["id"], roadmap.id.as_str());
+        assert_eq!(mention["attrs"]["label"], "the roadmap");
+
+        let mut tags: Vec<_> = collection.tags.iter().map(|t| t.name.as_str()).collect();
+        tags.sort();
+        assert_eq!(tags, vec!["followup", "meeting", "team"]);
+        assert_eq!(collection.tag_mappings.len(), 3);
+
+        let names: Vec<_> = collection.humans.iter().map(|h| h.name.as_str()).collect();
+        assert_eq!(names, vec!["Jane Doe", "John Smith"]);
+        assert_eq!(collection.participants.len(), 2);
+    }
+
+    #[test]
+    fn test_import_keeps_mirrored_session_id() {
+        let dir = tempfile::tempdir().unwrap();
+        let id = "550e8400-e29b-41d4-a716-446655440000";
+        write(
+            dir.path(),
+            "2024-01-15 Standup.md",
+            &format!("---\nsession_id: {id}\n---\nNotes\n"),
+        );
+
+        let first = import_from_path(dir.path()).unwrap();
+        let second = import_from_path(dir.path()).unwrap();
+
+        assert_eq!(first.sessions[0].id, id);
+        assert_eq!(second.sessions[0].id, id);
+    }
+
+    #[test]
+    fn test_value_to_datetime() {
+        let s = |v: &str| value_to_datetime(&Value::String(v.to_string()));
+
+        assert_eq!(s("2024-01-15"), Some("2024-01-15T00:00:00+00:00".into()));
+        assert_eq!(
+            s("2024-01-15T10:30:00"),
+            Some("2024-01-15T10:30:00+00:00".into())
+        );
+        assert_eq!(
+            s("2024-01-15T10:30:00+02:00"),
+            Some("2024-01-15T10:30:00+02:00".into())
+        );
+        assert_eq!(s("yesterday"), None);
+    }
+
+    #[test]
+    fn test_strip_wikilink() {
+        assert_eq!(strip_wikilink("[[Jane Doe]]"), "Jane Doe");
+        assert_eq!(strip_wikilink("[[people/jane|Jane]]"), "Jane");
+        assert_eq!(strip_wikilink(" John "), "John");
+    }
+}
@@ -0,0 +1,7 @@
+pub mod error;
+pub mod importer;
+pub mod writer;
+
+mod link;
+
+pub use error::{Error, Result};
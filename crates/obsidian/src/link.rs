@@ -0,0 +1,215 @@
+use std::collections::{BTreeSet, HashMap};
+
+use serde_json::{Value, json};
+
+/// Rewrites `[[Target|Label]]` wikilinks in a tiptap document into session
+/// mentions, and collects inline `#tags` along the way.
+///
+/// `targets` maps lowercased note names (and aliases) to session ids. Links
+/// that don't resolve are kept as their label text.
+pub(crate) fn rewrite(doc: &mut Value, targets: &HashMap<String, String>) -> BTreeSet<String> {
+    let mut tags = BTreeSet::new();
+    walk(doc, targets, &mut tags);
+    tags
+}
+
+fn walk(node: &mut Value, targets: &HashMap<String, String>, tags: &mut BTreeSet<String>) {
+    if node["type"] == "codeBlock" {
+        return;
+    }
+
+    let Some(content) = node.get_mut("content").and_then(Value::as_array_mut) else {
+        return;
+    };
+
+    let mut rewritten = Vec::with_capacity(content.len());
+    for mut child in content.drain(..) {
+        if child["type"] == "text" && !has_mark(&child, "code") {
+            let text = child["text"].as_str().unwrap_or_default().to_string();
+            collect_tags(&text, tags);
+            rewritten.extend(split_wikilinks(&text, child.get("marks"), targets));
+        } else {
+            walk(&mut child, targets, tags);
+            rewritten.push(child);
+        }
+    }
+    *content = rewritten;
+}
+
+fn has_mark(node: &Value, mark: &str) -> bool {
+    node["marks"]
+        .as_array()
+        .is_some_and(|marks| marks.iter().any(|m| m["type"] == mark))
+}
+
+fn text_node(text: &str, marks: Option<&Value>) -> Value {
+    let mut node = json!({ "type": "text", "text": text });
+    if let Some(marks) = marks {
+        node["marks"] = marks.clone();
+    }
+    node
+}
+
+fn split_wikilinks(
+    text: &str,
+    marks: Option<&Value>,
+    targets: &HashMap<String, String>,
+) -> Vec<Value> {
+    let mut nodes = Vec::new();
+    let mut plain = String::new();
+    let mut rest = text;
+
+    while let Some(open) = rest.find("[[") {
+        let Some(close) = rest[open + 2..].find("]]") else {
+            break;
+        };
+
+        // `![[...]]` embeds are treated like plain links.
+        let before = rest[..open].strip_suffix('!').unwrap_or(&rest[..open]);
+        plain.push_str(before);
+
+        let inner = &rest[open + 2..open + 2 + close];
+        let (target, label) = parse_wikilink(inner);
+
+        match targets.get(&normalize_target(target)) {
+            Some(id) => {
+                if !plain.is_empty() {
+                    nodes.push(text_node(&plain, marks));
+                    plain.clear();
+                }
+                nodes.push(json!({
+                    "type": "mention-@",
+                    "attrs": { "id": id, "type": "session", "label": label }
+                }));
+            }
+            None => plain.push_str(label),
+        }
+
+        rest = &rest[open + 2 + close + 2..];
+    }
+
+    plain.push_str(rest);
+    if !plain.is_empty() {
+        nodes.push(text_node(&plain, marks));
+    }
+
+    nodes
+}
+
+/// `Note#Heading|Label` → (`Note#Heading`, `Label`); `Note` → (`Note`, `Note`)
+fn parse_wikilink(inner: &str) -> (&str, &str) {
+    match inner.split_once('|') {
+        Some((target, label)) => (target.trim(), label.trim()),
+        None => {
+            let target = inner.trim();
+            let label = target.split(['#', '^']).next().unwrap_or(target);
+            (target, label.trim())
+        }
+    }
+}
+
+/// Drops heading/block references and the `.md` extension, keeping only the
+/// file name so `[[folder/Note#Heading]]` resolves to `note`.
+pub(crate) fn normalize_target(target: &str) -> String {
+    let target = target.split(['#', '^']).next().unwrap_or(target).trim();
+    let target = target.rsplit('/').next().unwrap_or(target);
+    let target = target.strip_suffix(".md").unwrap_or(target);
+    target.to_lowercase()
+}
+
+/// Obsidian tags start with `#` after whitespace, may nest with `/`, and
+/// must contain at least one non-numeric character.
+fn collect_tags(text: &str, tags: &mut BTreeSet<String>) {
+    let chars: Vec<char> = text.chars().collect();
+
+    for (i, c) in chars.iter().enumerate() {
+        if *c != '#' || (i > 0 && !chars[i - 1].is_whitespace()) {
+            continue;
+        }
+
+        let tag: String = chars[i + 1..]
+            .iter()
+            .take_while(|c| c.is_alphanumeric() || matches!(c, '_' | '-' | '/'))
+            .collect();
+        let tag = tag.trim_end_matches('/');
+
+        if !tag.is_empty() && !tag.chars().all(|c| c.is_ascii_digit()) {
+            tags.insert(tag.to_string());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn targets() -> HashMap<String, String> {
+        HashMap::from([
+            ("weekly sync".to_string(), "id-1".to_string()),
+            ("q1 planning".to_string(), "id-2".to_string()),
+        ])
+    }
+
+    fn paragraph(content: Value) -> Value {
+        json!({ "type": "doc", "content": [{ "type": "paragraph", "content": content }] })
+    }
+
+    #[test]
+    fn test_rewrite_resolves_wikilinks() {
+        let mut doc = paragraph(json!([
+            { "type": "text", "text": "See [[Weekly Sync]] and [[Q1 Planning#Goals|the plan]], not [[Missing]]." }
+        ]));
+        rewrite(&mut doc, &targets());
+
+        assert_eq!(
+            doc["content"][0]["content"],
+            json!([
+                { "type": "text", "text": "See " },
+                { "type": "mention-@", "attrs": { "id": "id-1", "type": "session", "label": "Weekly Sync" } },
+                { "type": "text", "text": " and " },
+                { "type": "mention-@", "attrs": { "id": "id-2", "type": "session", "label": "the plan" } },
+                { "type": "text", "text": ", not Missing." }
+            ])
+        );
+    }
+
+    #[test]
+    fn test_rewrite_keeps_marks_and_skips_code() {
+        let mut doc = paragraph(json!([
+            { "type": "text", "text": "[[folder/weekly sync.md]] #bold", "marks": [{ "type": "bold" }] },
+            { "type": "text", "text": "[[Weekly Sync]] #code", "marks": [{ "type": "code" }] }
+        ]));
+        let tags = rewrite(&mut doc, &targets());
+
+        let content = &doc["content"][0]["content"];
+        assert_eq!(content[0]["type"], "mention-@");
+        assert_eq!(
+            content[1],
+            json!({ "type": "text", "text": " #bold", "marks": [{ "type": "bold" }] })
+        );
+        assert_eq!(content[2]["text"], "[[Weekly Sync]] #code");
+        assert_eq!(tags, BTreeSet::from(["bold".to_string()]));
+    }
+
+    #[test]
+    fn test_collect_tags() {
+        let mut tags = BTreeSet::new();
+        collect_tags(
+            "#meeting notes for #project/alpha, issue#12 and #2024 #q3-review",
+            &mut tags,
+        );
+
+        assert_eq!(
+            tags.into_iter().collect::<Vec<_>>(),
+            vec!["meeting", "project/alpha", "q3-review"]
+        );
+    }
+
+    #[test]
+    fn test_normalize_target() {
+        assert_eq!(normalize_target("Weekly Sync"), "weekly sync");
+        assert_eq!(normalize_target("folder/Weekly Sync.md"), "weekly sync");
+        assert_eq!(normalize_target("Weekly Sync#Agenda"), "weekly sync");
+        assert_eq!(normalize_target("Weekly Sync^abc123"), "weekly sync");
+    }
+}
@@ -0,0 +1,206 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use hypr_frontmatter::Document;
+use serde::Serialize;
+
+use crate::error::Result;
+
+// Characters Obsidian refuses in file names, plus the ones that break links.
+const INVALID_FILENAME_CHARS: &[char] = &[
+    '*', '"', '\\', '/', '<', '>', ':', '|', '?', '#', '^', '[', ']',
+];
+
+#[derive(Debug, Clone, Default)]
+pub struct MirrorNote {
+    pub session_id: String,
+    pub title: String,
+    pub created_at: Option<String>,
+    pub tags: Vec<String>,
+    pub attendees: Vec<String>,
+    pub content: String,
+}
+
+#[derive(Debug, Serialize)]
+struct MirrorFrontmatter {
+    session_id: String,
+    title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    created: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    tags: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    attendees: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    aliases: Vec<String>,
+}
+
+impl MirrorNote {
+    /// Renders the note with frontmatter Obsidian understands: `tags` without
+    /// spaces, `attendees` as wikilinks, and a `date` usable by Dataview.
+    pub fn render(&self) -> Result<String> {
+        let date = self
+            .created_at
+            .as_deref()
+            .and_then(|s| chrono::DateTime::parse_from_rfc3339(s).ok())
+            .map(|dt| dt.format("%Y-%m-%d").to_string());
+
+        let frontmatter = MirrorFrontmatter {
+            session_id: self.session_id.clone(),
+            title: self.title.clone(),
+            date,
+            created: self.created_at.clone(),
+            tags: self.tags.iter().map(|t| obsidian_tag(t)).collect(),
+            attendees: self
+                .attendees
+                .iter()
+                .map(|name| format!("[[{}]]", name.trim()))
+                .collect(),
+            aliases: if self.title.is_empty() {
+                vec![]
+            } else {
+                vec![self.title.clone()]
+            },
+        };
+
+        let mut content = self.content.trim_end().to_string();
+        content.push('\n');
+
+        Ok(Document::new(frontmatter, content).render()?)
+    }
+
+    /// `2024-01-15 Weekly Sync.md`, falling back to the session id when the
+    /// title has nothing usable.
+    pub fn file_name(&self) -> String {
+        let title: String = self
+            .title
+            .chars()
+            .map(|c| {
+                if INVALID_FILENAME_CHARS.contains(&c) {
+                    ' '
+                } else {
+                    c
+                }
+            })
+            .collect();
+        let title = title.split_whitespace().collect::<Vec<_>>().join(" ");
+        let title = if title.is_empty() {
+            self.session_id.clone()
+        } else {
+            title
+        };
+
+        let date = self
+            .created_at
+            .as_deref()
+            .and_then(|s| chrono::DateTime::parse_from_rfc3339(s).ok())
+            .map(|dt| dt.format("%Y-%m-%d ").to_string())
+            .unwrap_or_default();
+
+        format!("{}{}.md", date, title)
+    }
+}
+
+/// Writes the note into `dir`. A file that already carries the same
+/// `session_id` is overwritten in place, so notes renamed in Obsidian keep
+/// their name.
+pub fn write_note(dir: &Path, note: &MirrorNote) -> Result<PathBuf> {
+    std::fs::create_dir_all(dir)?;
+
+    let path =
+        find_mirrored_note(dir, &note.session_id)?.unwrap_or_else(|| dir.join(note.file_name()));
+    std::fs::write(&path, note.render()?)?;
+
+    Ok(path)
+}
+
+fn find_mirrored_note(dir: &Path, session_id: &str) -> Result<Option<PathBuf>> {
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.extension().is_none_or(|ext| ext != "md") {
+            continue;
+        }
+
+        let Ok(content) = std::fs::read_to_string(&path) else {
+            continue;
+        };
+        let Ok(doc) = Document::<BTreeMap<String, serde_yaml::Value>>::from_str(&content) else {
+            continue;
+        };
+
+        if doc.frontmatter.get("session_id").and_then(|v| v.as_str()) == Some(session_id) {
+            return Ok(Some(path));
+        }
+    }
+
+    Ok(None)
+}
+
+fn obsidian_tag(tag: &str) -> String {
+    tag.trim()
+        .trim_start_matches('#')
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn note() -> MirrorNote {
+        MirrorNote {
+            session_id: "550e8400-e29b-41d4-a716-446655440000".to_string(),
+            title: "Weekly Sync: Q1/Q2".to_string(),
+            created_at: Some("2024-01-15T10:30:00+00:00".to_string()),
+            tags: vec!["#meeting".to_string(), "project x".to_string()],
+            attendees: vec!["Jane Doe".to_string()],
+            content: "## Summary\n\n- Shipped\n\n".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_render() {
+        let rendered = note().render().unwrap();
+
+        assert_eq!(
+            rendered,
+            "---\naliases:\n- 'Weekly Sync: Q1/Q2'\nattendees:\n- '[[Jane Doe]]'\ncreated: 2024-01-15T10:30:00+00:00\ndate: 2024-01-15\nsession_id: 550e8400-e29b-41d4-a716-446655440000\ntags:\n- meeting\n- project-x\ntitle: 'Weekly Sync: Q1/Q2'\n---\n\n## Summary\n\n- Shipped\n"
+        );
+    }
+
+    #[test]
+    fn test_file_name() {
+        assert_eq!(note().file_name(), "2024-01-15 Weekly Sync Q1 Q2.md");
+
+        let untitled = MirrorNote {
+            session_id: "abc".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(untitled.file_name(), "abc.md");
+    }
+
+    #[test]
+    fn test_write_note_updates_existing_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut note = note();
+
+        let first = write_note(dir.path(), &note).unwrap();
+        let renamed = dir.path().join("Renamed in Obsidian.md");
+        std::fs::rename(&first, &renamed).unwrap();
+
+        note.content = "Updated".to_string();
+        let second = write_note(dir.path(), &note).unwrap();
+
+        assert_eq!(second, renamed);
+        assert!(
+            std::fs::read_to_string(&renamed)
+                .unwrap()
+                .ends_with("Updated\n")
+        );
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 1);
+    }
+}
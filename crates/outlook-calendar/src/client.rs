@@ -2,7 +2,9 @@ use hypr_http::HttpClient;
 
 use crate::error::Error;
 use crate::types::{
-    CreateEventRequest, Event, ListCalendarsResponse, ListEventsRequest, ListEventsResponse,
+    CreateEventRequest, DeleteEventRequest, Event, EventResponse, EventsDeltaRequest,
+    EventsDeltaResponse, ListCalendarsResponse, ListEventsRequest, ListEventsResponse,
+    RespondToEventRequest, UpdateEventRequest,
 };
 
 pub struct OutlookCalendarClient<C> {
@@ -72,6 +74,48 @@ impl<C: HttpClient> OutlookCalendarClient<C> {
         Ok(response)
     }
 
+    /// One page of a delta query over a calendar view. Occurrences of
+    /// series come back expanded. Follow `odata_next_link` until an
+    /// `odata_delta_link` is returned, then keep that for the next sync.
+    pub async fn list_events_delta(
+        &self,
+        req: EventsDeltaRequest,
+    ) -> Result<EventsDeltaResponse, Error> {
+        let path = match req.link {
+            Some(ref link) => relative_link(link).to_string(),
+            None => {
+                let calendar_id = &req.calendar_id;
+                let mut query_parts: Vec<String> = Vec::new();
+                if let Some(ref start) = req.start_date_time {
+                    query_parts.push(format!(
+                        "startDateTime={}",
+                        urlencoding::encode(&start.to_rfc3339())
+                    ));
+                }
+                if let Some(ref end) = req.end_date_time {
+                    query_parts.push(format!(
+                        "endDateTime={}",
+                        urlencoding::encode(&end.to_rfc3339())
+                    ));
+                }
+                format!(
+                    "/me/calendars/{calendar_id}/calendarView/delta?{}",
+                    query_parts.join("&")
+                )
+            }
+        };
+
+        let bytes = self.http.get(&path).await.map_err(|e| {
+            if req.link.is_some() && hypr_http::status_of(&e) == Some(410) {
+                Error::SyncTokenExpired
+            } else {
+                Error::Http(e)
+            }
+        })?;
+        let response: EventsDeltaResponse = serde_json::from_slice(&bytes)?;
+        Ok(response)
+    }
+
     pub async fn create_event(&self, req: CreateEventRequest) -> Result<Event, Error> {
         let calendar_id = &req.calendar_id;
         let path = format!("/me/calendars/{calendar_id}/events");
@@ -85,4 +129,73 @@ impl<C: HttpClient> OutlookCalendarClient<C> {
         let event: Event = serde_json::from_slice(&bytes)?;
         Ok(event)
     }
+
+    pub async fn update_event(&self, req: UpdateEventRequest) -> Result<Event, Error> {
+        let calendar_id = &req.calendar_id;
+        let event_id = &req.event_id;
+        let path = format!("/me/calendars/{calendar_id}/events/{event_id}");
+
+        let body = serde_json::to_vec(&req.event)?;
+        let bytes = self.http.patch(&path, body).await.map_err(Error::Http)?;
+        let event: Event = serde_json::from_slice(&bytes)?;
+        Ok(event)
+    }
+
+    /// Deleting a meeting the user organizes also sends a cancellation to
+    /// its attendees.
+    pub async fn delete_event(&self, req: DeleteEventRequest) -> Result<(), Error> {
+        let calendar_id = &req.calendar_id;
+        let event_id = &req.event_id;
+        let path = format!("/me/calendars/{calendar_id}/events/{event_id}");
+
+        self.http.delete(&path).await.map_err(Error::Http)?;
+        Ok(())
+    }
+
+    pub async fn respond_to_event(&self, req: RespondToEventRequest) -> Result<(), Error> {
+        let calendar_id = &req.calendar_id;
+        let event_id = &req.event_id;
+        let action = match req.response {
+            EventResponse::Accept => "accept",
+            EventResponse::TentativelyAccept => "tentativelyAccept",
+            EventResponse::Decline => "decline",
+        };
+        let path = format!("/me/calendars/{calendar_id}/events/{event_id}/{action}");
+
+        let body = serde_json::to_vec(&serde_json::json!({
+            "comment": req.comment.unwrap_or_default(),
+            "sendResponse": req.send_response,
+        }))?;
+        self.http
+            .post(&path, body, "application/json")
+            .await
+            .map_err(Error::Http)?;
+        Ok(())
+    }
+}
+
+/// Graph links are absolute; the HTTP client takes paths below the
+/// versioned API root.
+fn relative_link(link: &str) -> &str {
+    let path = match link.split_once("://") {
+        Some((_, rest)) => rest.find('/').map_or("", |i| &rest[i..]),
+        None => link,
+    };
+    path.strip_prefix("/v1.0").unwrap_or(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_relative_link() {
+        assert_eq!(
+            relative_link(
+                "https://graph.microsoft.com/v1.0/me/calendars/AAM/calendarView/delta?$deltatoken=abc"
+            ),
+            "/me/calendars/AAM/calendarView/delta?$deltatoken=abc"
+        );
+        assert_eq!(relative_link("/me/events"), "/me/events");
+    }
 }
@@ -0,0 +1,339 @@
+use chrono::{DateTime, NaiveDate, NaiveDateTime, TimeZone, Utc, Weekday};
+use hypr_calendar_interface as interface;
+
+use crate::types::{
+    Attendee, AttendeeType, DateTimeTimeZone, DayOfWeek, Event, PatternedRecurrence,
+    RecurrencePatternType, RecurrenceRangeType, ResponseType, WeekIndex,
+};
+
+impl Event {
+    /// Normalizes the event. Times are expected in UTC, which Graph returns
+    /// unless asked otherwise, or in an IANA or Windows zone. Zones are
+    /// reported by their IANA name. Deleted events from delta queries have
+    /// no times and give `None`.
+    pub fn to_calendar_event(&self, calendar_id: &str) -> Option<interface::CalendarEvent> {
+        let is_all_day = self.is_all_day == Some(true);
+        let start = parse_time(self.start.as_ref()?, is_all_day)?;
+        let end = parse_time(self.end.as_ref()?, is_all_day)?;
+
+        let organizer_is_self = self.is_organizer == Some(true);
+        let organizer = self.organizer.as_ref().map(|o| interface::Attendee {
+            email: o.email_address.as_ref().and_then(|e| e.address.clone()),
+            name: o.email_address.as_ref().and_then(|e| e.name.clone()),
+            response: interface::ResponseStatus::Accepted,
+            is_self: organizer_is_self,
+            is_organizer: true,
+            optional: false,
+        });
+
+        let self_response = match self
+            .response_status
+            .as_ref()
+            .and_then(|r| r.response.as_ref())
+        {
+            _ if organizer_is_self => Some(interface::ResponseStatus::Accepted),
+            Some(ResponseType::Organizer | ResponseType::Accepted) => {
+                Some(interface::ResponseStatus::Accepted)
+            }
+            Some(ResponseType::TentativelyAccepted) => Some(interface::ResponseStatus::Tentative),
+            Some(ResponseType::Declined) => Some(interface::ResponseStatus::Declined),
+            Some(ResponseType::NotResponded) => Some(interface::ResponseStatus::NeedsAction),
+            Some(ResponseType::None | ResponseType::Unknown) | None => None,
+        };
+
+        let location = self
+            .location
+            .as_ref()
+            .and_then(|l| l.display_name.clone())
+            .filter(|l| !l.is_empty());
+        let description = self.body.as_ref().and_then(|b| b.content.clone());
+        let meeting_url = self
+            .online_meeting
+            .as_ref()
+            .and_then(|m| m.join_url.clone())
+            .or_else(|| self.online_meeting_url.clone())
+            .or_else(|| {
+                [location.as_deref(), description.as_deref()]
+                    .into_iter()
+                    .flatten()
+                    .find_map(hypr_meeting_link::parse_conference_link)
+            });
+
+        Some(interface::CalendarEvent {
+            id: self.id.clone(),
+            calendar_id: calendar_id.to_string(),
+            provider: interface::CalendarProvider::Outlook,
+            ical_uid: self.ical_uid.clone(),
+            series_id: self.series_master_id.clone(),
+            original_start: self.original_start,
+            recurrence: self
+                .recurrence
+                .as_ref()
+                .and_then(to_rrule)
+                .map(|rule| vec![rule.to_string()])
+                .unwrap_or_default(),
+            title: self.subject.clone().unwrap_or_default(),
+            description,
+            location,
+            meeting_url,
+            start,
+            end,
+            is_all_day,
+            time_zone: self
+                .original_start_time_zone
+                .as_deref()
+                .and_then(interface::parse_zone)
+                .map(|zone| zone.name().to_string()),
+            status: if self.is_cancelled == Some(true) {
+                interface::EventStatus::Cancelled
+            } else {
+                interface::EventStatus::Confirmed
+            },
+            organizer,
+            attendees: self.attendees.iter().flatten().map(to_attendee).collect(),
+            self_response,
+            updated_at: self.last_modified_date_time,
+        })
+    }
+}
+
+/// All-day events keep their floating date at midnight UTC.
+fn parse_time(time: &DateTimeTimeZone, is_all_day: bool) -> Option<DateTime<Utc>> {
+    if let Ok(parsed) = DateTime::parse_from_rfc3339(&time.date_time) {
+        return Some(parsed.with_timezone(&Utc));
+    }
+    let naive = NaiveDateTime::parse_from_str(&time.date_time, "%Y-%m-%dT%H:%M:%S%.f").ok()?;
+    if is_all_day {
+        return Some(naive.and_utc());
+    }
+    match time.time_zone.as_deref() {
+        None | Some("UTC") => Some(naive.and_utc()),
+        Some(zone) => interface::parse_zone(zone)?
+            .from_local_datetime(&naive)
+            .earliest()
+            .map(|t| t.with_timezone(&Utc)),
+    }
+}
+
+fn to_attendee(attendee: &Attendee) -> interface::Attendee {
+    interface::Attendee {
+        email: attendee
+            .email_address
+            .as_ref()
+            .and_then(|e| e.address.clone()),
+        name: attendee.email_address.as_ref().and_then(|e| e.name.clone()),
+        response: match attendee.status.as_ref().and_then(|s| s.response.as_ref()) {
+            Some(ResponseType::Accepted | ResponseType::Organizer) => {
+                interface::ResponseStatus::Accepted
+            }
+            Some(ResponseType::TentativelyAccepted) => interface::ResponseStatus::Tentative,
+            Some(ResponseType::Declined) => interface::ResponseStatus::Declined,
+            _ => interface::ResponseStatus::NeedsAction,
+        },
+        is_self: false,
+        is_organizer: matches!(
+            attendee.status.as_ref().and_then(|s| s.response.as_ref()),
+            Some(ResponseType::Organizer)
+        ),
+        optional: matches!(attendee.type_, Some(AttendeeType::Optional)),
+    }
+}
+
+/// Graph's patterned recurrence as an `RRULE`, for series masters.
+fn to_rrule(recurrence: &PatternedRecurrence) -> Option<interface::RRule> {
+    let pattern = recurrence.pattern.as_ref()?;
+    let days = || -> Vec<interface::ByDay> {
+        pattern
+            .days_of_week
+            .iter()
+            .flatten()
+            .filter_map(to_weekday)
+            .map(|weekday| interface::ByDay {
+                ordinal: None,
+                weekday,
+            })
+            .collect()
+    };
+    let position = || -> Vec<i16> {
+        match pattern.index {
+            Some(WeekIndex::Second) => vec![2],
+            Some(WeekIndex::Third) => vec![3],
+            Some(WeekIndex::Fourth) => vec![4],
+            Some(WeekIndex::Last) => vec![-1],
+            _ => vec![1],
+        }
+    };
+    let month = || {
+        pattern
+            .month
+            .and_then(|m| u8::try_from(m).ok())
+            .into_iter()
+            .collect()
+    };
+    let day_of_month = || {
+        pattern
+            .day_of_month
+            .and_then(|d| i8::try_from(d).ok())
+            .into_iter()
+            .collect()
+    };
+
+    let mut rule = match pattern.type_.as_ref()? {
+        RecurrencePatternType::Daily => interface::RRule::new(interface::Frequency::Daily),
+        RecurrencePatternType::Weekly => interface::RRule {
+            by_day: days(),
+            ..interface::RRule::new(interface::Frequency::Weekly)
+        },
+        RecurrencePatternType::AbsoluteMonthly => interface::RRule {
+            by_month_day: day_of_month(),
+            ..interface::RRule::new(interface::Frequency::Monthly)
+        },
+        RecurrencePatternType::RelativeMonthly => interface::RRule {
+            by_day: days(),
+            by_set_pos: position(),
+            ..interface::RRule::new(interface::Frequency::Monthly)
+        },
+        RecurrencePatternType::AbsoluteYearly => interface::RRule {
+            by_month: month(),
+            by_month_day: day_of_month(),
+            ..interface::RRule::new(interface::Frequency::Yearly)
+        },
+        RecurrencePatternType::RelativeYearly => interface::RRule {
+            by_month: month(),
+            by_day: days(),
+            by_set_pos: position(),
+            ..interface::RRule::new(interface::Frequency::Yearly)
+        },
+        RecurrencePatternType::Unknown => return None,
+    };
+    rule.interval = pattern
+        .interval
+        .and_then(|i| u32::try_from(i).ok())
+        .unwrap_or(1)
+        .max(1);
+    if let Some(weekday) = pattern.first_day_of_week.as_ref().and_then(to_weekday) {
+        rule.week_start = weekday;
+    }
+
+    if let Some(range) = recurrence.range.as_ref() {
+        match range.type_ {
+            Some(RecurrenceRangeType::EndDate) => {
+                rule.until = range
+                    .end_date
+                    .as_deref()
+                    .and_then(|d| NaiveDate::parse_from_str(d, "%Y-%m-%d").ok())
+                    .map(interface::Until::Date);
+            }
+            Some(RecurrenceRangeType::Numbered) => {
+                rule.count = range
+                    .number_of_occurrences
+                    .and_then(|n| u32::try_from(n).ok());
+            }
+            _ => {}
+        }
+    }
+
+    Some(rule)
+}
+
+fn to_weekday(day: &DayOfWeek) -> Option<Weekday> {
+    Some(match day {
+        DayOfWeek::Monday => Weekday::Mon,
+        DayOfWeek::Tuesday => Weekday::Tue,
+        DayOfWeek::Wednesday => Weekday::Wed,
+        DayOfWeek::Thursday => Weekday::Thu,
+        DayOfWeek::Friday => Weekday::Fri,
+        DayOfWeek::Saturday => Weekday::Sat,
+        DayOfWeek::Sunday => Weekday::Sun,
+        DayOfWeek::Unknown => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_occurrence_with_link_in_body() {
+        let event: Event = serde_json::from_value(serde_json::json!({
+            "id": "AAMkAGI2_occ",
+            "iCalUId": "040000008200E00074C5B7101A82E008",
+            "seriesMasterId": "AAMkAGI2",
+            "type": "occurrence",
+            "originalStart": "2026-03-04T15:00:00Z",
+            "subject": "Vendor call",
+            "start": { "dateTime": "2026-03-04T15:00:00.0000000", "timeZone": "UTC" },
+            "end": { "dateTime": "2026-03-04T15:30:00.0000000", "timeZone": "UTC" },
+            "responseStatus": { "response": "tentativelyAccepted" },
+            "body": {
+                "contentType": "text",
+                "content": "Join: https://acme.zoom.us/j/87636383039 or dial in"
+            }
+        }))
+        .unwrap();
+
+        let event = event.to_calendar_event("work").unwrap();
+        assert_eq!(event.start.to_rfc3339(), "2026-03-04T15:00:00+00:00");
+        assert_eq!(event.series_id.as_deref(), Some("AAMkAGI2"));
+        assert_eq!(event.original_start, Some(event.start));
+        assert_eq!(
+            event.self_response,
+            Some(interface::ResponseStatus::Tentative)
+        );
+        assert_eq!(
+            event.meeting_url.as_deref(),
+            Some("https://acme.zoom.us/j/87636383039")
+        );
+    }
+
+    #[test]
+    fn test_windows_time_zone() {
+        let event: Event = serde_json::from_value(serde_json::json!({
+            "id": "AAMkAGI4",
+            "subject": "Standup",
+            "originalStartTimeZone": "Pacific Standard Time",
+            "start": { "dateTime": "2026-03-04T09:00:00.0000000", "timeZone": "Pacific Standard Time" },
+            "end": { "dateTime": "2026-03-04T09:15:00.0000000", "timeZone": "Pacific Standard Time" }
+        }))
+        .unwrap();
+
+        let event = event.to_calendar_event("work").unwrap();
+        assert_eq!(event.start.to_rfc3339(), "2026-03-04T17:00:00+00:00");
+        assert_eq!(event.time_zone.as_deref(), Some("America/Los_Angeles"));
+    }
+
+    #[test]
+    fn test_series_master_recurrence() {
+        let event: Event = serde_json::from_value(serde_json::json!({
+            "id": "AAMkAGI2",
+            "type": "seriesMaster",
+            "subject": "Board review",
+            "start": { "dateTime": "2026-01-30T09:00:00.0000000", "timeZone": "UTC" },
+            "end": { "dateTime": "2026-01-30T10:00:00.0000000", "timeZone": "UTC" },
+            "recurrence": {
+                "pattern": {
+                    "type": "relativeMonthly",
+                    "interval": 1,
+                    "daysOfWeek": ["friday"],
+                    "index": "last"
+                },
+                "range": { "type": "numbered", "numberOfOccurrences": 3 }
+            }
+        }))
+        .unwrap();
+
+        let event = event.to_calendar_event("work").unwrap();
+        assert_eq!(
+            event.recurrence,
+            ["RRULE:FREQ=MONTHLY;COUNT=3;BYDAY=FR;BYSETPOS=-1"]
+        );
+
+        let removed: Event = serde_json::from_value(serde_json::json!({
+            "id": "AAMkAGI3",
+            "@removed": { "reason": "deleted" }
+        }))
+        .unwrap();
+        assert!(removed.removed.is_some());
+        assert!(removed.to_calendar_event("work").is_none());
+    }
+}
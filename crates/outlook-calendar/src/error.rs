@@ -5,6 +5,10 @@ pub enum Error {
     #[error("HTTP client error: {0}")]
     Http(Box<dyn std::error::Error + Send + Sync>),
 
+    /// The delta link was invalidated by the server; sync again from scratch.
+    #[error("sync token expired")]
+    SyncTokenExpired,
+
     #[error("JSON error: {0}")]
     Json(#[from] serde_json::Error),
 }
@@ -433,11 +433,24 @@ pub struct Event {
     #[serde(default)]
     pub last_modified_date_time: Option<DateTime<Utc>>,
     #[serde(default)]
+    pub original_start: Option<DateTime<Utc>>,
+    #[serde(default)]
     pub original_start_time_zone: Option<String>,
     #[serde(default)]
     pub original_end_time_zone: Option<String>,
     #[serde(default)]
     pub transaction_id: Option<String>,
+    /// Set on events a delta query reports as deleted; nothing else but the
+    /// ID is filled in then.
+    #[serde(default, rename = "@removed", skip_serializing_if = "Option::is_none")]
+    pub removed: Option<Removed>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Removed {
+    #[serde(default)]
+    pub reason: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -481,6 +494,26 @@ pub struct ListEventsRequest {
     pub order_by: Option<String>,
 }
 
+#[derive(Default)]
+pub struct EventsDeltaRequest {
+    pub calendar_id: String,
+    pub start_date_time: Option<DateTime<Utc>>,
+    pub end_date_time: Option<DateTime<Utc>>,
+    /// An `@odata.nextLink` or `@odata.deltaLink` from an earlier response.
+    /// It carries the window, so the other fields are ignored when set.
+    pub link: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventsDeltaResponse {
+    #[serde(default, rename = "@odata.nextLink")]
+    pub odata_next_link: Option<String>,
+    #[serde(default, rename = "@odata.deltaLink")]
+    pub odata_delta_link: Option<String>,
+    #[serde(default)]
+    pub value: Vec<Event>,
+}
+
 pub struct CreateEventRequest {
     pub calendar_id: String,
     pub event: CreateEventBody,
@@ -528,3 +561,51 @@ pub struct CreateEventBody {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub hide_attendees: Option<bool>,
 }
+
+pub struct UpdateEventRequest {
+    pub calendar_id: String,
+    pub event_id: String,
+    pub event: UpdateEventBody,
+}
+
+/// A patch: fields left as `None` keep their current value. `attendees`
+/// replaces the whole list.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateEventBody {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subject: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<ItemBody>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<Location>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start: Option<DateTimeTimeZone>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end: Option<DateTimeTimeZone>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attendees: Option<Vec<Attendee>>,
+}
+
+pub struct DeleteEventRequest {
+    pub calendar_id: String,
+    pub event_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum EventResponse {
+    Accept,
+    TentativelyAccept,
+    Decline,
+}
+
+pub struct RespondToEventRequest {
+    pub calendar_id: String,
+    pub event_id: String,
+    pub response: EventResponse,
+    pub comment: Option<String>,
+    /// Whether the organizer gets a reply; the response is recorded either
+    /// way.
+    pub send_response: bool,
+}
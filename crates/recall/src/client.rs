@@ -3,11 +3,12 @@ use reqwest::header::{AUTHORIZATION, HeaderMap, HeaderValue};
 use crate::error::Error;
 use crate::types::{Bot, CreateBotRequest, OutputMedia};
 
-const BASE_URL: &str = "https://us-east-1.recall.ai/api/v1";
+const DEFAULT_BASE_URL: &str = "https://us-east-1.recall.ai/api/v1";
 
 #[derive(Clone)]
 pub struct RecallClient {
     http: reqwest::Client,
+    base_url: String,
 }
 
 impl RecallClient {
@@ -24,13 +25,22 @@ impl RecallClient {
             .default_headers(headers)
             .build()?;
 
-        Ok(Self { http })
+        Ok(Self {
+            http,
+            base_url: DEFAULT_BASE_URL.to_string(),
+        })
+    }
+
+    /// Points the client at another Recall region, or a mock server in tests.
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
     }
 
     pub async fn create_bot(&self, req: CreateBotRequest) -> Result<Bot, Error> {
         let resp = self
             .http
-            .post(format!("{BASE_URL}/bot"))
+            .post(format!("{}/bot", self.base_url))
             .json(&req)
             .send()
             .await?;
@@ -47,7 +57,7 @@ impl RecallClient {
     pub async fn get_bot(&self, bot_id: &str) -> Result<Bot, Error> {
         let resp = self
             .http
-            .get(format!("{BASE_URL}/bot/{bot_id}"))
+            .get(format!("{}/bot/{bot_id}", self.base_url))
             .send()
             .await?;
 
@@ -63,7 +73,7 @@ impl RecallClient {
     pub async fn output_media(&self, bot_id: &str, req: OutputMedia) -> Result<(), Error> {
         let resp = self
             .http
-            .post(format!("{BASE_URL}/bot/{bot_id}/output_media"))
+            .post(format!("{}/bot/{bot_id}/output_media", self.base_url))
             .json(&req)
             .send()
             .await?;
@@ -80,7 +90,7 @@ impl RecallClient {
     pub async fn remove_bot(&self, bot_id: &str) -> Result<(), Error> {
         let resp = self
             .http
-            .post(format!("{BASE_URL}/bot/{bot_id}/leave_call"))
+            .post(format!("{}/bot/{bot_id}/leave_call", self.base_url))
             .send()
             .await?;
 
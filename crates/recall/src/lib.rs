@@ -1,6 +1,7 @@
 mod client;
 mod error;
 mod types;
+mod webhook;
 
 pub use client::RecallClient;
 pub use error::Error;
@@ -11,3 +12,7 @@ pub use types::{
     SilenceDetectionConfig, StartRecordingOn, TranscriptSegment, TranscriptWebhook, TranscriptWord,
     TranscriptionOptions, TranscriptionProvider, VariantKind,
 };
+pub use webhook::{
+    WEBHOOK_ID_HEADER, WEBHOOK_SIGNATURE_HEADER, WEBHOOK_TIMESTAMP_HEADER, sign_webhook,
+    verify_webhook_signature,
+};
@@ -186,7 +186,7 @@ pub struct TranscriptWebhook {
     pub transcript: TranscriptSegment,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TranscriptSegment {
     pub speaker: String,
     pub words: Vec<TranscriptWord>,
@@ -194,7 +194,7 @@ pub struct TranscriptSegment {
     pub original_transcript_id: Option<i64>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TranscriptWord {
     pub text: String,
     pub start_time: f64,
@@ -0,0 +1,103 @@
+use base64::{Engine, engine::general_purpose::STANDARD};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+type HmacSha256 = Hmac<Sha256>;
+
+pub const WEBHOOK_ID_HEADER: &str = "webhook-id";
+pub const WEBHOOK_TIMESTAMP_HEADER: &str = "webhook-timestamp";
+pub const WEBHOOK_SIGNATURE_HEADER: &str = "webhook-signature";
+
+/// Verifies a Recall webhook, which is signed Svix-style: an HMAC-SHA256 of
+/// `{id}.{timestamp}.{body}` keyed with the base64 part of the `whsec_`
+/// secret. `signature` is the raw header, a space-separated list of
+/// `v1,<base64>` entries; any one matching is enough.
+///
+/// This doesn't check `timestamp` against the clock; callers should reject
+/// stale deliveries themselves.
+pub fn verify_webhook_signature(
+    secret: &str,
+    id: &str,
+    timestamp: &str,
+    body: &[u8],
+    signature: &str,
+) -> bool {
+    let Ok(key) = STANDARD.decode(secret.strip_prefix("whsec_").unwrap_or(secret)) else {
+        return false;
+    };
+    let Ok(mut mac) = HmacSha256::new_from_slice(&key) else {
+        return false;
+    };
+    mac.update(id.as_bytes());
+    mac.update(b".");
+    mac.update(timestamp.as_bytes());
+    mac.update(b".");
+    mac.update(body);
+
+    signature
+        .split_whitespace()
+        .filter_map(|entry| entry.strip_prefix("v1,"))
+        .filter_map(|encoded| STANDARD.decode(encoded).ok())
+        .any(|candidate| mac.clone().verify_slice(&candidate).is_ok())
+}
+
+/// Signs a payload the way Recall does. Used to build test deliveries.
+pub fn sign_webhook(secret: &str, id: &str, timestamp: &str, body: &[u8]) -> String {
+    let key = STANDARD
+        .decode(secret.strip_prefix("whsec_").unwrap_or(secret))
+        .unwrap_or_default();
+    let mut mac = HmacSha256::new_from_slice(&key).expect("HMAC accepts any key length");
+    mac.update(format!("{id}.{timestamp}.").as_bytes());
+    mac.update(body);
+    format!("v1,{}", STANDARD.encode(mac.finalize().into_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECRET: &str = "whsec_MfKQ9r8GKYqrTwjUPD8ILPZIo2LaLaSw";
+
+    #[test]
+    fn test_verify_webhook_signature() {
+        let body = br#"{"event":"bot.status_change"}"#;
+        let signature = sign_webhook(SECRET, "msg_1", "1700000000", body);
+
+        assert!(verify_webhook_signature(
+            SECRET,
+            "msg_1",
+            "1700000000",
+            body,
+            &signature
+        ));
+        assert!(verify_webhook_signature(
+            SECRET,
+            "msg_1",
+            "1700000000",
+            body,
+            &format!("v1,bm90LWEtc2lnbmF0dXJl {signature}")
+        ));
+
+        assert!(!verify_webhook_signature(
+            SECRET,
+            "msg_2",
+            "1700000000",
+            body,
+            &signature
+        ));
+        assert!(!verify_webhook_signature(
+            SECRET,
+            "msg_1",
+            "1700000000",
+            b"{}",
+            &signature
+        ));
+        assert!(!verify_webhook_signature(
+            SECRET,
+            "msg_1",
+            "1700000000",
+            body,
+            "v1,not base64"
+        ));
+    }
+}
@@ -1,7 +1,10 @@
 use hypr_http::HttpClient;
 
 use crate::error::Error;
-use crate::types::{PostMessageRequest, PostMessageResponse, SlackResponse};
+use crate::types::{
+    ListConversationsRequest, ListConversationsResponse, PostMessageRequest, PostMessageResponse,
+    SlackResponse,
+};
 
 pub struct SlackWebClient<C> {
     http: C,
@@ -25,4 +28,25 @@ impl<C: HttpClient> SlackWebClient<C> {
         let response: SlackResponse<PostMessageResponse> = serde_json::from_slice(&bytes)?;
         response.into_result()
     }
+
+    pub async fn list_conversations(
+        &self,
+        req: ListConversationsRequest,
+    ) -> Result<ListConversationsResponse, Error> {
+        let mut query_parts = vec![
+            format!("types={}", urlencoding::encode(&req.types.join(","))),
+            format!("exclude_archived={}", req.exclude_archived),
+        ];
+        if let Some(limit) = req.limit {
+            query_parts.push(format!("limit={limit}"));
+        }
+        if let Some(cursor) = &req.cursor {
+            query_parts.push(format!("cursor={}", urlencoding::encode(cursor)));
+        }
+
+        let path = format!("/api/conversations.list?{}", query_parts.join("&"));
+        let bytes = self.http.get(&path).await.map_err(Error::Http)?;
+        let response: SlackResponse<ListConversationsResponse> = serde_json::from_slice(&bytes)?;
+        response.into_result()
+    }
 }
@@ -76,3 +76,59 @@ pub struct Message {
     #[serde(default)]
     pub ts: Option<String>,
 }
+
+#[derive(Debug, Clone)]
+pub struct ListConversationsRequest {
+    /// Any of `public_channel`, `private_channel`, `mpim` and `im`.
+    pub types: Vec<String>,
+    pub exclude_archived: bool,
+    pub limit: Option<u32>,
+    pub cursor: Option<String>,
+}
+
+impl Default for ListConversationsRequest {
+    fn default() -> Self {
+        Self {
+            types: vec!["public_channel".into()],
+            exclude_archived: true,
+            limit: None,
+            cursor: None,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ListConversationsResponse {
+    pub channels: Vec<Conversation>,
+    #[serde(default)]
+    pub response_metadata: Option<ResponseMetadata>,
+}
+
+impl ListConversationsResponse {
+    /// Slack returns an empty cursor on the last page.
+    pub fn next_cursor(&self) -> Option<&str> {
+        self.response_metadata
+            .as_ref()
+            .map(|m| m.next_cursor.as_str())
+            .filter(|c| !c.is_empty())
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Conversation {
+    pub id: String,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub is_private: bool,
+    #[serde(default)]
+    pub is_member: bool,
+    #[serde(default)]
+    pub is_archived: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ResponseMetadata {
+    #[serde(default)]
+    pub next_cursor: String,
+}
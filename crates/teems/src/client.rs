@@ -1,7 +1,9 @@
 use hypr_http::HttpClient;
 
 use crate::error::Error;
-use crate::types::{SendMessageRequest, SendMessageResponse, parse_response};
+use crate::types::{
+    Channel, ListResponse, SendMessageRequest, SendMessageResponse, Team, parse_response,
+};
 
 pub struct TeamsClient<C> {
     http: C,
@@ -27,4 +29,42 @@ impl<C: HttpClient> TeamsClient<C> {
             .map_err(Error::Http)?;
         parse_response(&bytes)
     }
+
+    /// Posts `req` as a reply in the thread of `message_id`.
+    pub async fn reply_to_message(
+        &self,
+        team_id: &str,
+        channel_id: &str,
+        message_id: &str,
+        req: SendMessageRequest,
+    ) -> Result<SendMessageResponse, Error> {
+        let path = format!(
+            "/v1.0/teams/{}/channels/{}/messages/{}/replies",
+            team_id, channel_id, message_id
+        );
+        let body = serde_json::to_vec(&req)?;
+        let bytes = self
+            .http
+            .post(&path, body, "application/json")
+            .await
+            .map_err(Error::Http)?;
+        parse_response(&bytes)
+    }
+
+    pub async fn list_joined_teams(&self) -> Result<Vec<Team>, Error> {
+        let bytes = self
+            .http
+            .get("/v1.0/me/joinedTeams")
+            .await
+            .map_err(Error::Http)?;
+        let response: ListResponse<Team> = parse_response(&bytes)?;
+        Ok(response.value)
+    }
+
+    pub async fn list_channels(&self, team_id: &str) -> Result<Vec<Channel>, Error> {
+        let path = format!("/v1.0/teams/{}/channels", team_id);
+        let bytes = self.http.get(&path).await.map_err(Error::Http)?;
+        let response: ListResponse<Channel> = parse_response(&bytes)?;
+        Ok(response.value)
+    }
 }
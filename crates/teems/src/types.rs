@@ -1,4 +1,4 @@
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
 
 use crate::error::Error;
 
@@ -16,6 +16,30 @@ pub(crate) struct GraphError {
 #[derive(Debug, Serialize)]
 pub struct SendMessageRequest {
     pub body: MessageBody,
+    /// Referenced from the body as `<attachment id="..."></attachment>`.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub attachments: Vec<ChatMessageAttachment>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ChatMessageAttachment {
+    pub id: String,
+    #[serde(rename = "contentType")]
+    pub content_type: String,
+    /// The card JSON, serialized to a string.
+    pub content: String,
+}
+
+impl ChatMessageAttachment {
+    pub const ADAPTIVE_CARD: &str = "application/vnd.microsoft.card.adaptive";
+
+    pub fn adaptive_card(id: impl Into<String>, card: &serde_json::Value) -> Self {
+        Self {
+            id: id.into(),
+            content_type: Self::ADAPTIVE_CARD.to_string(),
+            content: card.to_string(),
+        }
+    }
 }
 
 #[derive(Debug, Serialize)]
@@ -65,8 +89,36 @@ pub struct MessageBodyResponse {
     pub content_type: Option<String>,
 }
 
-pub(crate) fn parse_response(bytes: &[u8]) -> Result<SendMessageResponse, Error> {
-    match serde_json::from_slice::<SendMessageResponse>(bytes) {
+#[derive(Debug, Deserialize)]
+pub struct ListResponse<T> {
+    pub value: Vec<T>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Team {
+    pub id: String,
+    #[serde(rename = "displayName")]
+    #[serde(default)]
+    pub display_name: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Channel {
+    pub id: String,
+    #[serde(rename = "displayName")]
+    #[serde(default)]
+    pub display_name: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(rename = "membershipType")]
+    #[serde(default)]
+    pub membership_type: Option<String>,
+}
+
+pub(crate) fn parse_response<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, Error> {
+    match serde_json::from_slice::<T>(bytes) {
         Ok(response) => Ok(response),
         Err(_) => {
             let error_resp: GraphErrorResponse = serde_json::from_slice(bytes)?;
@@ -0,0 +1,319 @@
+use crate::{Segment, Transcript, common_derives};
+
+/// Rough average for English text with BPE tokenizers. Good enough to size
+/// chunks; actual limits should leave some headroom.
+const CHARS_PER_TOKEN: usize = 4;
+
+common_derives! {
+    pub struct ChunkOptions {
+        pub max_tokens: u32,
+        pub overlap_tokens: u32,
+    }
+}
+
+pub fn estimate_tokens(text: &str) -> usize {
+    text.chars().count().div_ceil(CHARS_PER_TOKEN)
+}
+
+fn segment_tokens(segment: &Segment) -> usize {
+    estimate_tokens(&segment.speaker) + estimate_tokens(&segment.text) + 1
+}
+
+pub fn transcript_tokens(transcripts: &[Transcript]) -> usize {
+    transcripts
+        .iter()
+        .flat_map(|t| &t.segments)
+        .map(segment_tokens)
+        .sum()
+}
+
+/// Splits the segments of `transcripts` into chunks of at most
+/// `max_tokens`. Each chunk starts with the last `overlap_tokens` worth of
+/// segments from the previous one, so statements near a boundary keep their
+/// context. Segments longer than a chunk are split on whitespace.
+pub fn chunk_transcripts(transcripts: &[Transcript], options: &ChunkOptions) -> Vec<Transcript> {
+    let max_tokens = (options.max_tokens as usize).max(1);
+    let overlap_tokens = (options.overlap_tokens as usize).min(max_tokens / 2);
+
+    let mut chunks = vec![];
+    let mut current = Transcript {
+        segments: vec![],
+        started_at: None,
+        ended_at: None,
+    };
+    let mut current_tokens = 0;
+    let mut carried = 0;
+
+    for transcript in transcripts {
+        for segment in &transcript.segments {
+            for piece in split_segment(segment, max_tokens - overlap_tokens) {
+                let tokens = segment_tokens(&piece);
+
+                if current_tokens + tokens > max_tokens && current.segments.len() > carried {
+                    let overlap = overlap_tail(&current.segments, overlap_tokens);
+                    carried = overlap.len();
+                    current_tokens = overlap.iter().map(segment_tokens).sum();
+                    chunks.push(std::mem::replace(
+                        &mut current,
+                        Transcript {
+                            segments: overlap,
+                            started_at: None,
+                            ended_at: None,
+                        },
+                    ));
+                }
+
+                if current.segments.len() == carried {
+                    current.started_at = transcript.started_at;
+                }
+                current.ended_at = transcript.ended_at;
+                current.segments.push(piece);
+                current_tokens += tokens;
+            }
+        }
+    }
+
+    if current.segments.len() > carried {
+        chunks.push(current);
+    }
+
+    chunks
+}
+
+fn overlap_tail(segments: &[Segment], overlap_tokens: usize) -> Vec<Segment> {
+    let mut tokens = 0;
+    let start = segments
+        .iter()
+        .rposition(|s| {
+            tokens += segment_tokens(s);
+            tokens > overlap_tokens
+        })
+        .map_or(0, |i| i + 1);
+    segments[start..].to_vec()
+}
+
+fn split_segment(segment: &Segment, max_tokens: usize) -> Vec<Segment> {
+    if segment_tokens(segment) <= max_tokens {
+        return vec![segment.clone()];
+    }
+
+    let budget = max_tokens
+        .saturating_sub(estimate_tokens(&segment.speaker) + 1)
+        .max(1);
+    let mut pieces = vec![];
+    let mut text = String::new();
+
+    for word in segment.text.split_whitespace() {
+        if !text.is_empty() && estimate_tokens(&text) + estimate_tokens(word) + 1 > budget {
+            pieces.push(std::mem::take(&mut text));
+        }
+        if !text.is_empty() {
+            text.push(' ');
+        }
+        text.push_str(word);
+    }
+    if !text.is_empty() {
+        pieces.push(text);
+    }
+
+    pieces
+        .into_iter()
+        .map(|text| Segment {
+            text,
+            speaker: segment.speaker.clone(),
+        })
+        .collect()
+}
+
+/// Collects the bullets listed under action item headings across partial
+/// notes, dropping repeats that differ only in case, punctuation or spacing.
+pub fn collect_action_items(notes: &[String]) -> Vec<String> {
+    let mut seen = std::collections::HashSet::new();
+    let mut items = vec![];
+
+    for note in notes {
+        let mut in_section = false;
+
+        for line in note.lines() {
+            let line = line.trim();
+
+            if let Some(heading) = line.strip_prefix('#') {
+                let heading = heading.trim_start_matches('#').trim().to_lowercase();
+                in_section = ["action item", "next step", "todo", "to-do", "follow-up"]
+                    .iter()
+                    .any(|k| heading.contains(k));
+                continue;
+            }
+
+            if !in_section {
+                continue;
+            }
+
+            let Some(item) = strip_bullet(line) else {
+                continue;
+            };
+
+            let key = normalize(item);
+            if !key.is_empty() && seen.insert(key) {
+                items.push(item.to_string());
+            }
+        }
+    }
+
+    items
+}
+
+fn strip_bullet(line: &str) -> Option<&str> {
+    let rest = ["- ", "* ", "+ "]
+        .iter()
+        .find_map(|b| line.strip_prefix(b))
+        .or_else(|| {
+            let (number, rest) = line.split_once(". ")?;
+            number.chars().all(|c| c.is_ascii_digit()).then_some(rest)
+        })?;
+
+    let rest = ["[ ] ", "[x] ", "[X] "]
+        .iter()
+        .find_map(|c| rest.strip_prefix(c))
+        .unwrap_or(rest);
+
+    Some(rest.trim())
+}
+
+fn normalize(item: &str) -> String {
+    item.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn segment(speaker: &str, text: &str) -> Segment {
+        Segment {
+            speaker: speaker.to_string(),
+            text: text.to_string(),
+        }
+    }
+
+    fn texts(chunk: &Transcript) -> Vec<&str> {
+        chunk.segments.iter().map(|s| s.text.as_str()).collect()
+    }
+
+    #[test]
+    fn chunks_respect_budget_and_overlap() {
+        // Each segment is 1 + 1 + 3 = 5 tokens.
+        let transcripts = vec![
+            Transcript {
+                segments: (0..4)
+                    .map(|i| segment("A", &format!("segment {i:04}")))
+                    .collect(),
+                started_at: Some(100),
+                ended_at: Some(200),
+            },
+            Transcript {
+                segments: (4..6)
+                    .map(|i| segment("B", &format!("segment {i:04}")))
+                    .collect(),
+                started_at: Some(300),
+                ended_at: Some(400),
+            },
+        ];
+        let options = ChunkOptions {
+            max_tokens: 15,
+            overlap_tokens: 5,
+        };
+
+        let chunks = chunk_transcripts(&transcripts, &options);
+
+        assert_eq!(chunks.len(), 3);
+        assert_eq!(
+            texts(&chunks[0]),
+            ["segment 0000", "segment 0001", "segment 0002"]
+        );
+        assert_eq!(
+            texts(&chunks[1]),
+            ["segment 0002", "segment 0003", "segment 0004"]
+        );
+        assert_eq!(texts(&chunks[2]), ["segment 0004", "segment 0005"]);
+        assert_eq!(
+            (chunks[0].started_at, chunks[0].ended_at),
+            (Some(100), Some(200))
+        );
+        assert_eq!(
+            (chunks[1].started_at, chunks[1].ended_at),
+            (Some(100), Some(400))
+        );
+        assert!(
+            chunks
+                .iter()
+                .all(|c| transcript_tokens(std::slice::from_ref(c)) <= 15)
+        );
+    }
+
+    #[test]
+    fn short_transcript_is_a_single_chunk() {
+        let transcripts = vec![Transcript {
+            segments: vec![segment("A", "hello")],
+            started_at: None,
+            ended_at: None,
+        }];
+        let options = ChunkOptions {
+            max_tokens: 1000,
+            overlap_tokens: 100,
+        };
+
+        let chunks = chunk_transcripts(&transcripts, &options);
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(texts(&chunks[0]), ["hello"]);
+        assert!(chunk_transcripts(&[], &options).is_empty());
+    }
+
+    #[test]
+    fn long_segment_is_split() {
+        let text = "word ".repeat(100);
+        let transcripts = vec![Transcript {
+            segments: vec![segment("A", &text)],
+            started_at: None,
+            ended_at: None,
+        }];
+        let options = ChunkOptions {
+            max_tokens: 40,
+            overlap_tokens: 0,
+        };
+
+        let chunks = chunk_transcripts(&transcripts, &options);
+        assert!(chunks.len() > 1);
+        assert!(
+            chunks
+                .iter()
+                .all(|c| c.segments.iter().all(|s| s.speaker == "A"))
+        );
+        let words: usize = chunks
+            .iter()
+            .flat_map(|c| &c.segments)
+            .map(|s| s.text.split_whitespace().count())
+            .sum();
+        assert_eq!(words, 100);
+    }
+
+    #[test]
+    fn action_items_are_deduplicated() {
+        let notes = vec![
+            "# Budget\n\n- Not an action.\n\n# Action Items\n\n- John: send the Q3 report.\n- [ ] Book the venue\n".to_string(),
+            "# Action Items\n\n1. john: Send the Q3 report\n2. Review hiring plan\n\n# Other\n\n- Book the venue\n".to_string(),
+        ];
+
+        assert_eq!(
+            collect_action_items(&notes),
+            [
+                "John: send the Q3 report.",
+                "Book the venue",
+                "Review hiring plan"
+            ]
+        );
+    }
+}
@@ -20,6 +20,31 @@ common_derives! {
     }
 }
 
+common_derives! {
+    #[derive(askama::Template)]
+    #[template(path = "enhance.chunk.user.md.jinja")]
+    pub struct EnhanceChunkUser {
+        pub session: Session,
+        pub participants: Vec<Participant>,
+        pub template: Option<EnhanceTemplate>,
+        pub transcript: Transcript,
+        pub chunk_index: u32,
+        pub chunk_count: u32,
+    }
+}
+
+common_derives! {
+    #[derive(askama::Template)]
+    #[template(path = "enhance.reduce.user.md.jinja")]
+    pub struct EnhanceReduceUser {
+        pub session: Session,
+        pub participants: Vec<Participant>,
+        pub template: Option<EnhanceTemplate>,
+        pub partial_notes: Vec<String>,
+        pub action_items: Vec<String>,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -143,4 +168,107 @@ mod tests {
     1. Section 1 - Section 1 description
     2. Section 2 - Section 2 description
     ");
+
+    fn session() -> Session {
+        Session {
+            title: Some("All Hands".to_string()),
+            started_at: None,
+            ended_at: None,
+            event: None,
+        }
+    }
+
+    tpl_snapshot!(
+        test_enhance_chunk_user_formatting,
+        EnhanceChunkUser {
+            session: session(),
+            participants: vec![],
+            template: None,
+            transcript: Transcript {
+                segments: vec![Segment {
+                    text: "Ship on Friday".to_string(),
+                    speaker: "Alice".to_string(),
+                }],
+                started_at: None,
+                ended_at: None,
+            },
+            chunk_index: 2,
+            chunk_count: 3,
+        }, @r##"
+    # Context
+
+
+    Session: All Hands
+
+    # Transcript (Part 2 of 3)
+
+
+    Alice: Ship on Friday
+
+    # Partial Summary Instructions
+
+    - This transcript is one part of a longer meeting. The other parts are summarized separately and merged afterwards.
+    - Only summarize what is discussed in this part. Skip sections this part does not cover.
+    - The beginning of this part may repeat the end of the previous part.
+    - End with a "# Action Items" section listing every action item from this part, one bullet each, with the owner when mentioned.
+
+    # Output Template
+
+    # Instructions
+
+    1. Analyze the content and decide the sections to use.
+    2. Generate a well-formatted markdown summary.
+    "##);
+
+    tpl_snapshot!(
+        test_enhance_reduce_user_formatting,
+        EnhanceReduceUser {
+            session: session(),
+            participants: vec![],
+            template: None,
+            partial_notes: vec![
+                "# Launch\n\n- Shipping on Friday.".to_string(),
+                "# Hiring\n\n- Two open roles.".to_string(),
+            ],
+            action_items: vec!["Alice: send the launch email".to_string()],
+        }, @r#"
+    # Context
+
+
+    Session: All Hands
+
+    # Partial Summaries
+
+    The meeting was summarized in 2 consecutive parts.
+
+    <part index="1">
+    # Launch
+
+    - Shipping on Friday.
+    </part>
+
+    <part index="2">
+    # Hiring
+
+    - Two open roles.
+    </part>
+
+    # Action Items
+
+    - Alice: send the launch email
+
+    # Merge Instructions
+
+    - Merge the partial summaries into a single summary of the whole meeting.
+    - Combine points about the same topic from different parts into one section. Do not repeat points.
+    - Keep the order in which topics were discussed.
+    - Include each action item exactly once.
+
+    # Output Template
+
+    # Instructions
+
+    1. Analyze the content and decide the sections to use.
+    2. Generate a well-formatted markdown summary.
+    "#);
 }
@@ -0,0 +1,504 @@
+use chrono::{Datelike, Days, Months, NaiveDate, Weekday};
+
+use crate::{Error, Participant, Session, common_derives};
+use hypr_askama_utils::filters;
+
+common_derives! {
+    #[derive(askama::Template)]
+    #[template(path = "extract.system.md.jinja")]
+    pub struct ExtractSystem {
+        pub language: Option<String>,
+    }
+}
+
+common_derives! {
+    #[derive(askama::Template)]
+    #[template(path = "extract.user.md.jinja")]
+    pub struct ExtractUser {
+        pub session: Session,
+        pub participants: Vec<Participant>,
+        pub segments: Vec<ExtractSegment>,
+        /// Date the meeting took place on, as `YYYY-MM-DD`. Relative deadlines
+        /// are resolved against it.
+        pub event_date: Option<String>,
+    }
+}
+
+common_derives! {
+    pub struct ExtractSegment {
+        pub speaker: String,
+        pub text: String,
+        pub start_ms: Option<u64>,
+    }
+}
+
+common_derives! {
+    pub struct Assignee {
+        pub name: String,
+        /// Whether `name` matched one of the session participants.
+        pub participant: bool,
+    }
+}
+
+common_derives! {
+    pub struct ActionItem {
+        pub text: String,
+        pub assignee: Option<Assignee>,
+        /// Resolved deadline as `YYYY-MM-DD`.
+        pub due_date: Option<String>,
+        /// Deadline as it was said in the meeting.
+        pub due_text: Option<String>,
+        pub source_ms: Option<u64>,
+    }
+}
+
+common_derives! {
+    pub struct ExtractedItem {
+        pub text: String,
+        pub source_ms: Option<u64>,
+    }
+}
+
+common_derives! {
+    pub struct Extraction {
+        pub action_items: Vec<ActionItem>,
+        pub decisions: Vec<ExtractedItem>,
+        pub open_questions: Vec<ExtractedItem>,
+    }
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct RawExtraction {
+    action_items: Vec<RawActionItem>,
+    decisions: Vec<RawItem>,
+    open_questions: Vec<RawItem>,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawActionItem {
+    text: String,
+    #[serde(default)]
+    assignee: Option<String>,
+    #[serde(default)]
+    due: Option<String>,
+    #[serde(default)]
+    segment: Option<usize>,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawItem {
+    text: String,
+    #[serde(default)]
+    segment: Option<usize>,
+}
+
+/// JSON Schema of the model output requested by [`ExtractSystem`]. Providers
+/// that support structured output should be constrained with it.
+pub fn extraction_output_schema() -> serde_json::Value {
+    let segment = serde_json::json!({ "type": ["integer", "null"], "minimum": 0 });
+    let nullable_string = serde_json::json!({ "type": ["string", "null"] });
+    let item = serde_json::json!({
+        "type": "object",
+        "properties": {
+            "text": { "type": "string" },
+            "segment": segment,
+        },
+        "required": ["text", "segment"],
+        "additionalProperties": false,
+    });
+
+    serde_json::json!({
+        "type": "object",
+        "properties": {
+            "actionItems": {
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "properties": {
+                        "text": { "type": "string" },
+                        "assignee": nullable_string,
+                        "due": nullable_string,
+                        "segment": segment,
+                    },
+                    "required": ["text", "assignee", "due", "segment"],
+                    "additionalProperties": false,
+                },
+            },
+            "decisions": { "type": "array", "items": item },
+            "openQuestions": { "type": "array", "items": item },
+        },
+        "required": ["actionItems", "decisions", "openQuestions"],
+        "additionalProperties": false,
+    })
+}
+
+/// Parses the model output for `ctx` and resolves it into an [`Extraction`]:
+/// assignees are matched against the participants, deadlines are resolved
+/// against the event date and segment references become timestamps.
+pub fn resolve_extraction(output: &str, ctx: &ExtractUser) -> Result<Extraction, Error> {
+    let json = match (output.find('{'), output.rfind('}')) {
+        (Some(start), Some(end)) if start < end => &output[start..=end],
+        _ => return Err(Error::ParseError("no JSON object in output".to_string())),
+    };
+    let raw: RawExtraction =
+        serde_json::from_str(json).map_err(|e| Error::ParseError(e.to_string()))?;
+
+    let reference = ctx.event_date.as_deref().and_then(parse_iso_date);
+    let source_ms = |segment: Option<usize>| {
+        segment
+            .and_then(|i| ctx.segments.get(i))
+            .and_then(|s| s.start_ms)
+    };
+    let items = |raw: Vec<RawItem>| {
+        raw.into_iter()
+            .filter_map(|item| {
+                let text = non_empty(item.text)?;
+                Some(ExtractedItem {
+                    text,
+                    source_ms: source_ms(item.segment),
+                })
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let action_items = raw
+        .action_items
+        .into_iter()
+        .filter_map(|item| {
+            let text = non_empty(item.text)?;
+            let due_text = item.due.and_then(non_empty);
+            let due_date = due_text
+                .as_deref()
+                .zip(reference)
+                .and_then(|(due, reference)| resolve_due_date(due, reference))
+                .map(|d| d.format("%Y-%m-%d").to_string());
+
+            Some(ActionItem {
+                text,
+                assignee: item
+                    .assignee
+                    .and_then(|name| resolve_assignee(&name, &ctx.participants)),
+                due_date,
+                due_text,
+                source_ms: source_ms(item.segment),
+            })
+        })
+        .collect();
+
+    Ok(Extraction {
+        action_items,
+        decisions: items(raw.decisions),
+        open_questions: items(raw.open_questions),
+    })
+}
+
+fn non_empty(text: String) -> Option<String> {
+    let trimmed = text.trim();
+    (!trimmed.is_empty()).then(|| trimmed.to_string())
+}
+
+fn resolve_assignee(name: &str, participants: &[Participant]) -> Option<Assignee> {
+    let name = name.trim().trim_start_matches('@').trim();
+    let key = name.to_lowercase();
+    if key.is_empty() || ["none", "unassigned", "unknown", "n/a"].contains(&key.as_str()) {
+        return None;
+    }
+
+    let exact = participants.iter().find(|p| p.name.to_lowercase() == key);
+
+    // Fall back to a unique first-name match, e.g. "John" for "John Smith".
+    let partial = || {
+        let first = key.split_whitespace().next()?;
+        let mut matches = participants.iter().filter(|p| {
+            p.name
+                .split_whitespace()
+                .next()
+                .is_some_and(|n| n.to_lowercase() == first)
+        });
+        let found = matches.next()?;
+        matches.next().is_none().then_some(found)
+    };
+
+    Some(match exact.or_else(partial) {
+        Some(p) => Assignee {
+            name: p.name.clone(),
+            participant: true,
+        },
+        None => Assignee {
+            name: name.to_string(),
+            participant: false,
+        },
+    })
+}
+
+fn parse_iso_date(text: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(text.get(..10)?, "%Y-%m-%d").ok()
+}
+
+/// Resolves a deadline phrase such as "next Friday", "in two weeks" or
+/// "March 3rd" relative to `reference`. Returns `None` for phrases it does
+/// not understand rather than guessing.
+pub fn resolve_due_date(text: &str, reference: NaiveDate) -> Option<NaiveDate> {
+    if let Some(date) = parse_iso_date(text.trim()) {
+        return Some(date);
+    }
+
+    let text = text
+        .to_lowercase()
+        .replace(|c: char| !c.is_alphanumeric() && c != ' ', " ");
+    let mut words: Vec<&str> = text.split_whitespace().collect();
+    while let Some(first) = words.first()
+        && ["by", "on", "due", "before", "until", "the"].contains(first)
+    {
+        words.remove(0);
+    }
+
+    match words.as_slice() {
+        ["today" | "tonight" | "eod"] | ["end", "of", "day"] => Some(reference),
+        ["tomorrow"] => reference.checked_add_days(Days::new(1)),
+        ["day", "after", "tomorrow"] => reference.checked_add_days(Days::new(2)),
+        ["eow"] | ["end", "of", "week"] | ["end", "of", "the", "week"] => {
+            Some(weekday_on_or_after(reference, Weekday::Fri))
+        }
+        ["next", "week"] => Some(week_start(reference) + Days::new(7)),
+        ["eom"] | ["end", "of", "month"] | ["end", "of", "the", "month"] => {
+            last_day_of_month(reference)
+        }
+        ["next", "month"] => reference.with_day(1)?.checked_add_months(Months::new(1)),
+        ["in", amount, unit] => {
+            let amount = parse_amount(amount)?;
+            match unit.trim_end_matches('s') {
+                "day" => reference.checked_add_days(Days::new(amount)),
+                "week" => reference.checked_add_days(Days::new(amount * 7)),
+                "month" => reference.checked_add_months(Months::new(amount as u32)),
+                _ => None,
+            }
+        }
+        ["this", day] => {
+            let weekday = parse_weekday(day)?;
+            Some(weekday_on_or_after(reference, weekday))
+        }
+        ["next", day] => {
+            let weekday = parse_weekday(day)?;
+            let next_week = week_start(reference) + Days::new(7);
+            Some(weekday_on_or_after(next_week, weekday))
+        }
+        [day] if parse_weekday(day).is_some() => {
+            let weekday = parse_weekday(day)?;
+            Some(weekday_on_or_after(reference + Days::new(1), weekday))
+        }
+        [a, b] => {
+            let (month, day) = parse_month(a)
+                .zip(parse_day(b))
+                .or_else(|| parse_month(b).zip(parse_day(a)))?;
+            let date = NaiveDate::from_ymd_opt(reference.year(), month, day)?;
+            if date < reference {
+                NaiveDate::from_ymd_opt(reference.year() + 1, month, day)
+            } else {
+                Some(date)
+            }
+        }
+        _ => None,
+    }
+}
+
+fn week_start(date: NaiveDate) -> NaiveDate {
+    date - Days::new(date.weekday().num_days_from_monday() as u64)
+}
+
+fn weekday_on_or_after(date: NaiveDate, weekday: Weekday) -> NaiveDate {
+    let offset = (7 + weekday.num_days_from_monday() - date.weekday().num_days_from_monday()) % 7;
+    date + Days::new(offset as u64)
+}
+
+fn last_day_of_month(date: NaiveDate) -> Option<NaiveDate> {
+    date.with_day(1)?
+        .checked_add_months(Months::new(1))?
+        .pred_opt()
+}
+
+fn parse_amount(word: &str) -> Option<u64> {
+    const WORDS: [&str; 10] = [
+        "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "ten",
+    ];
+
+    match word {
+        "a" | "an" => Some(1),
+        _ => word
+            .parse()
+            .ok()
+            .or_else(|| WORDS.iter().position(|w| *w == word).map(|i| i as u64 + 1)),
+    }
+}
+
+fn parse_weekday(word: &str) -> Option<Weekday> {
+    match word {
+        "mon" | "monday" => Some(Weekday::Mon),
+        "tue" | "tues" | "tuesday" => Some(Weekday::Tue),
+        "wed" | "wednesday" => Some(Weekday::Wed),
+        "thu" | "thur" | "thurs" | "thursday" => Some(Weekday::Thu),
+        "fri" | "friday" => Some(Weekday::Fri),
+        "sat" | "saturday" => Some(Weekday::Sat),
+        "sun" | "sunday" => Some(Weekday::Sun),
+        _ => None,
+    }
+}
+
+fn parse_month(word: &str) -> Option<u32> {
+    const MONTHS: [&str; 12] = [
+        "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+    ];
+
+    if word.len() < 3 {
+        return None;
+    }
+    MONTHS
+        .iter()
+        .position(|m| word.starts_with(m))
+        .map(|i| i as u32 + 1)
+}
+
+fn parse_day(word: &str) -> Option<u32> {
+    let digits = word.trim_end_matches(|c: char| c.is_ascii_alphabetic());
+    digits.parse().ok().filter(|d| (1..=31).contains(d))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hypr_askama_utils::tpl_snapshot;
+
+    fn ctx() -> ExtractUser {
+        ExtractUser {
+            session: Session {
+                title: Some("Launch Sync".to_string()),
+                started_at: None,
+                ended_at: None,
+                event: None,
+            },
+            participants: vec![
+                Participant {
+                    name: "John Smith".to_string(),
+                    job_title: None,
+                },
+                Participant {
+                    name: "Jane Doe".to_string(),
+                    job_title: Some("PM".to_string()),
+                },
+            ],
+            segments: vec![
+                ExtractSegment {
+                    speaker: "Jane Doe".to_string(),
+                    text: "Can you send the report by Friday?".to_string(),
+                    start_ms: Some(1_000),
+                },
+                ExtractSegment {
+                    speaker: "John Smith".to_string(),
+                    text: "Sure. We ship on the 3rd of March.".to_string(),
+                    start_ms: Some(5_000),
+                },
+            ],
+            event_date: Some("2025-01-08".to_string()),
+        }
+    }
+
+    tpl_snapshot!(
+        test_extract_user,
+        ctx(),
+        @"
+    # Context
+
+    Session: Launch Sync
+    Date: 2025-01-08
+    Participants:
+    - John Smith
+    - Jane Doe (PM)
+
+    # Transcript
+
+    [0] Jane Doe: Can you send the report by Friday?
+    [1] John Smith: Sure. We ship on the 3rd of March.
+
+    Now, extract the action items, decisions and open questions from the transcript above.
+    "
+    );
+
+    #[test]
+    fn test_resolve_extraction() {
+        let output = r#"```json
+        {
+            "actionItems": [
+                { "text": "Send the report", "assignee": "john", "due": "by Friday", "segment": 0 },
+                { "text": "Book the venue", "assignee": "Alex", "due": null, "segment": 9 },
+                { "text": "  ", "assignee": null, "due": null, "segment": null }
+            ],
+            "decisions": [{ "text": "Ship on March 3rd", "segment": 1 }],
+            "openQuestions": []
+        }
+        ```"#;
+
+        let extraction = resolve_extraction(output, &ctx()).unwrap();
+
+        assert_eq!(extraction.action_items.len(), 2);
+        let report = &extraction.action_items[0];
+        assert_eq!(report.assignee.as_ref().unwrap().name, "John Smith");
+        assert!(report.assignee.as_ref().unwrap().participant);
+        assert_eq!(report.due_date.as_deref(), Some("2025-01-10"));
+        assert_eq!(report.due_text.as_deref(), Some("by Friday"));
+        assert_eq!(report.source_ms, Some(1_000));
+
+        let venue = &extraction.action_items[1];
+        assert_eq!(venue.assignee.as_ref().unwrap().name, "Alex");
+        assert!(!venue.assignee.as_ref().unwrap().participant);
+        assert_eq!(venue.source_ms, None);
+
+        assert_eq!(extraction.decisions[0].source_ms, Some(5_000));
+        assert!(extraction.open_questions.is_empty());
+    }
+
+    #[test]
+    fn test_resolve_extraction_rejects_invalid_output() {
+        assert!(resolve_extraction("Sorry, I can't help.", &ctx()).is_err());
+        assert!(resolve_extraction(r#"{ "actionItems": [] }"#, &ctx()).is_err());
+        assert!(
+            resolve_extraction(
+                r#"{ "actionItems": [{ "task": "x" }], "decisions": [], "openQuestions": [] }"#,
+                &ctx()
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_resolve_due_date() {
+        // Wednesday
+        let reference = NaiveDate::from_ymd_opt(2025, 1, 8).unwrap();
+
+        let cases = [
+            ("2025-02-01", Some("2025-02-01")),
+            ("today", Some("2025-01-08")),
+            ("tomorrow", Some("2025-01-09")),
+            ("by Friday", Some("2025-01-10")),
+            ("Wednesday", Some("2025-01-15")),
+            ("this Wednesday", Some("2025-01-08")),
+            ("next Tuesday", Some("2025-01-14")),
+            ("next week", Some("2025-01-13")),
+            ("end of the week", Some("2025-01-10")),
+            ("end of month", Some("2025-01-31")),
+            ("next month", Some("2025-02-01")),
+            ("in two weeks", Some("2025-01-22")),
+            ("in 3 days", Some("2025-01-11")),
+            ("March 3rd", Some("2025-03-03")),
+            ("3 Jan", Some("2026-01-03")),
+            ("sometime soon", None),
+        ];
+
+        for (text, expected) in cases {
+            let actual = resolve_due_date(text, reference).map(|d| d.to_string());
+            assert_eq!(actual.as_deref(), expected, "{text}");
+        }
+    }
+}
@@ -0,0 +1,212 @@
+use crate::{ActionItem, Participant, Session, common_derives};
+
+common_derives! {
+    #[derive(askama::Template)]
+    #[template(path = "followup.email.html.jinja")]
+    pub struct FollowupEmailHtml {
+        pub session: Session,
+        /// Who the email is addressed to, for the greeting.
+        pub recipients: Vec<Participant>,
+        pub sender_name: Option<String>,
+        /// Markdown, as produced by enhancing.
+        pub enhanced_note: String,
+        pub action_items: Vec<ActionItem>,
+    }
+}
+
+common_derives! {
+    #[derive(askama::Template)]
+    #[template(path = "followup.email.txt.jinja")]
+    pub struct FollowupEmailText {
+        pub session: Session,
+        pub recipients: Vec<Participant>,
+        pub sender_name: Option<String>,
+        pub enhanced_note: String,
+        pub action_items: Vec<ActionItem>,
+    }
+}
+
+impl FollowupEmailHtml {
+    fn greeting(&self) -> String {
+        greeting(&self.recipients)
+    }
+
+    fn details(&self, item: &ActionItem) -> String {
+        details(item)
+    }
+}
+
+impl FollowupEmailText {
+    fn greeting(&self) -> String {
+        greeting(&self.recipients)
+    }
+
+    fn details(&self, item: &ActionItem) -> String {
+        details(item)
+    }
+}
+
+/// Subject line for the recap of `session`.
+pub fn followup_subject(session: &Session) -> String {
+    let title = session
+        .title
+        .as_deref()
+        .map(str::trim)
+        .filter(|t| !t.is_empty())
+        .or(session.event.as_ref().map(|e| e.name.trim()));
+
+    match title {
+        Some(title) => format!("Recap: {title}"),
+        None => "Meeting recap".to_string(),
+    }
+}
+
+fn greeting(recipients: &[Participant]) -> String {
+    let names: Vec<&str> = recipients
+        .iter()
+        .filter_map(|p| p.name.split_whitespace().next())
+        .collect();
+
+    match names.as_slice() {
+        [] => "Hi,".to_string(),
+        [name] => format!("Hi {name},"),
+        [a, b] => format!("Hi {a} and {b},"),
+        _ => "Hi all,".to_string(),
+    }
+}
+
+/// ` (Alice, due 2026-03-04)`, or empty when the item has neither.
+fn details(item: &ActionItem) -> String {
+    let due = item
+        .due_date
+        .as_deref()
+        .or(item.due_text.as_deref())
+        .map(|d| format!("due {d}"));
+    let parts: Vec<String> = item
+        .assignee
+        .as_ref()
+        .map(|a| a.name.clone())
+        .into_iter()
+        .chain(due)
+        .collect();
+
+    if parts.is_empty() {
+        String::new()
+    } else {
+        format!(" ({})", parts.join(", "))
+    }
+}
+
+mod filters {
+    /// Renders GitHub-flavored markdown. Raw HTML in the note is escaped.
+    #[askama::filter_fn]
+    pub fn markdown(value: &str, _env: &dyn askama::Values) -> askama::Result<String> {
+        ::markdown::to_html_with_options(value, &::markdown::Options::gfm())
+            .map_err(|e| askama::Error::custom(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Assignee;
+    use hypr_askama_utils::{tpl_assert, tpl_snapshot};
+
+    fn session() -> Session {
+        Session {
+            title: Some("Acme onboarding".to_string()),
+            started_at: None,
+            ended_at: None,
+            event: None,
+        }
+    }
+
+    fn participant(name: &str) -> Participant {
+        Participant {
+            name: name.to_string(),
+            job_title: None,
+        }
+    }
+
+    fn action_items() -> Vec<ActionItem> {
+        vec![
+            ActionItem {
+                text: "Send the SSO metadata".to_string(),
+                assignee: Some(Assignee {
+                    name: "Dana Lee".to_string(),
+                    participant: true,
+                }),
+                due_date: Some("2026-03-06".to_string()),
+                due_text: Some("Friday".to_string()),
+                source_ms: None,
+            },
+            ActionItem {
+                text: "Share pricing tiers".to_string(),
+                assignee: None,
+                due_date: None,
+                due_text: None,
+                source_ms: None,
+            },
+        ]
+    }
+
+    tpl_snapshot!(
+        test_followup_email_text,
+        FollowupEmailText {
+            session: session(),
+            recipients: vec![participant("Dana Lee"), participant("Sam Ortiz")],
+            sender_name: Some("Jordan".to_string()),
+            enhanced_note: "## Summary\n\n- Kickoff on March 9\n".to_string(),
+            action_items: action_items(),
+        },
+        @"
+    Hi Dana and Sam,
+
+    Thanks for your time at Acme onboarding. Here's a recap of what we covered.
+
+    Action items
+    - Send the SSO metadata (Dana Lee, due 2026-03-06)
+    - Share pricing tiers
+
+    Notes
+
+    ## Summary
+
+    - Kickoff on March 9
+
+    Best,
+    Jordan
+    "
+    );
+
+    tpl_assert!(
+        test_followup_email_html_escapes,
+        FollowupEmailHtml {
+            session: Session {
+                title: Some("Q&A <prep>".to_string()),
+                ..session()
+            },
+            recipients: vec![],
+            sender_name: None,
+            enhanced_note: "**Agreed** on <script>alert(1)</script>".to_string(),
+            action_items: vec![],
+        },
+        |v| v.contains("<p>Hi,</p>")
+            && v.contains("<strong>Q&#38;A &#60;prep&#62;</strong>")
+            && v.contains("<strong>Agreed</strong>")
+            && !v.contains("<script>")
+            && !v.contains("Action items")
+    );
+
+    #[test]
+    fn test_followup_subject() {
+        assert_eq!(followup_subject(&session()), "Recap: Acme onboarding");
+        assert_eq!(
+            followup_subject(&Session {
+                title: Some(" ".to_string()),
+                ..session()
+            }),
+            "Meeting recap"
+        );
+    }
+}
@@ -1,12 +1,20 @@
 mod chat;
+mod chunk;
 mod enhance;
+mod extract;
+mod followup;
+mod retrieval;
 mod title;
 mod tool;
 mod types;
 mod validate;
 
 pub use chat::*;
+pub use chunk::*;
 pub use enhance::*;
+pub use extract::*;
+pub use followup::*;
+pub use retrieval::*;
 pub use title::*;
 pub use tool::*;
 pub use types::*;
@@ -25,10 +33,17 @@ common_derives! {
     pub enum Template {
         EnhanceSystem(EnhanceSystem),
         EnhanceUser(EnhanceUser),
+        EnhanceChunkUser(EnhanceChunkUser),
+        EnhanceReduceUser(EnhanceReduceUser),
+        ExtractSystem(ExtractSystem),
+        ExtractUser(ExtractUser),
+        FollowupEmailHtml(FollowupEmailHtml),
+        FollowupEmailText(FollowupEmailText),
         TitleSystem(TitleSystem),
         TitleUser(TitleUser),
         ChatSystem(ChatSystem),
         ToolSearchSessions(ToolSearchSessions),
+        ToolSearchVault(ToolSearchVault),
     }
 }
 
@@ -46,10 +61,17 @@ pub fn render(t: Template) -> Result<String, Error> {
     let value = match t {
         Template::EnhanceSystem(t) => askama::Template::render(&t),
         Template::EnhanceUser(t) => askama::Template::render(&t),
+        Template::EnhanceChunkUser(t) => askama::Template::render(&t),
+        Template::EnhanceReduceUser(t) => askama::Template::render(&t),
+        Template::ExtractSystem(t) => askama::Template::render(&t),
+        Template::ExtractUser(t) => askama::Template::render(&t),
+        Template::FollowupEmailHtml(t) => askama::Template::render(&t),
+        Template::FollowupEmailText(t) => askama::Template::render(&t),
         Template::TitleSystem(t) => askama::Template::render(&t),
         Template::TitleUser(t) => askama::Template::render(&t),
         Template::ChatSystem(t) => askama::Template::render(&t),
         Template::ToolSearchSessions(t) => askama::Template::render(&t),
+        Template::ToolSearchVault(t) => askama::Template::render(&t),
     }?;
 
     Ok(value)
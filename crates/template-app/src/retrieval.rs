@@ -0,0 +1,219 @@
+#[allow(unused_imports)]
+use hypr_askama_utils::filters;
+
+use crate::common_derives;
+
+common_derives! {
+    #[derive(Copy, Debug, PartialEq, Eq)]
+    pub enum CitationSource {
+        Note,
+        Transcript,
+    }
+}
+
+impl CitationSource {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Note => "note",
+            Self::Transcript => "transcript",
+        }
+    }
+}
+
+common_derives! {
+    pub struct RetrievalChunk {
+        pub session_id: String,
+        pub session_title: Option<String>,
+        pub source: CitationSource,
+        pub text: String,
+        /// Session creation time, in milliseconds since the epoch.
+        pub created_at: Option<i64>,
+        /// Offsets into the recording. Only set for transcript chunks.
+        pub start_ms: Option<i64>,
+        pub end_ms: Option<i64>,
+    }
+}
+
+impl RetrievalChunk {
+    pub fn date(&self) -> Option<String> {
+        let created_at = chrono::DateTime::from_timestamp_millis(self.created_at?)?;
+        Some(created_at.format("%Y-%m-%d").to_string())
+    }
+
+    pub fn time_range(&self) -> Option<String> {
+        let start = format_offset(self.start_ms?);
+        match self.end_ms {
+            Some(end) => Some(format!("{}-{}", start, format_offset(end))),
+            None => Some(start),
+        }
+    }
+}
+
+fn format_offset(ms: i64) -> String {
+    let secs = ms.max(0) / 1000;
+    let (hours, minutes, seconds) = (secs / 3600, (secs / 60) % 60, secs % 60);
+    if hours > 0 {
+        format!("{hours}:{minutes:02}:{seconds:02}")
+    } else {
+        format!("{minutes:02}:{seconds:02}")
+    }
+}
+
+common_derives! {
+    #[derive(askama::Template)]
+    #[template(path = "tool.search-vault.md.jinja")]
+    pub struct ToolSearchVault {
+        pub query: String,
+        /// Already ranked and packed to fit the prompt budget. Citation
+        /// markers refer to positions in this list, starting at 1.
+        pub chunks: Vec<RetrievalChunk>,
+    }
+}
+
+common_derives! {
+    #[derive(Debug, PartialEq)]
+    pub struct Citation {
+        /// The `[n]` marker used in the answer.
+        pub marker: u32,
+        pub session_id: String,
+        pub session_title: Option<String>,
+        pub source: CitationSource,
+        pub start_ms: Option<i64>,
+        pub end_ms: Option<i64>,
+    }
+}
+
+/// Resolves the `[n]` markers in an answer grounded on
+/// [`ToolSearchVault`] back to the chunks they point
+/// at, in order of first use. Markers that don't match a chunk are ignored; `[1, 2]` and `[1][2]`
+/// are both accepted.
+pub fn parse_citations(answer: &str, chunks: &[RetrievalChunk]) -> Vec<Citation> {
+    let mut markers: Vec<u32> = vec![];
+
+    let mut rest = answer;
+    while let Some(open) = rest.find('[') {
+        rest = &rest[open + 1..];
+        let Some(close) = rest.find(']') else {
+            break;
+        };
+
+        let inner = &rest[..close];
+        let parsed: Option<Vec<u32>> = inner
+            .split(',')
+            .map(|part| part.trim().parse::<u32>().ok())
+            .collect();
+        if let Some(parsed) = parsed {
+            rest = &rest[close + 1..];
+            for marker in parsed {
+                if !markers.contains(&marker) {
+                    markers.push(marker);
+                }
+            }
+        }
+    }
+
+    markers
+        .into_iter()
+        .filter_map(|marker| {
+            let chunk = chunks.get((marker as usize).checked_sub(1)?)?;
+            Some(Citation {
+                marker,
+                session_id: chunk.session_id.clone(),
+                session_title: chunk.session_title.clone(),
+                source: chunk.source,
+                start_ms: chunk.start_ms,
+                end_ms: chunk.end_ms,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hypr_askama_utils::tpl_snapshot;
+
+    fn chunks() -> Vec<RetrievalChunk> {
+        vec![
+            RetrievalChunk {
+                session_id: "s1".to_string(),
+                session_title: Some("Pricing review".to_string()),
+                source: CitationSource::Transcript,
+                text: "Alice: Let's move the Pro plan to $12 starting in July.".to_string(),
+                created_at: Some(1_718_000_000_000),
+                start_ms: Some(754_000),
+                end_ms: Some(3_812_500),
+            },
+            RetrievalChunk {
+                session_id: "s2".to_string(),
+                session_title: None,
+                source: CitationSource::Note,
+                text: "- Decided: annual discount stays at 20%".to_string(),
+                created_at: None,
+                start_ms: None,
+                end_ms: None,
+            },
+        ]
+    }
+
+    tpl_snapshot!(
+        test_tool_search_vault,
+        ToolSearchVault {
+            query: "pricing decision".to_string(),
+            chunks: chunks(),
+        },
+        @r#"
+    Excerpts from notes and transcripts for "pricing decision":
+
+    [1] Pricing review (2024-06-10), transcript 12:34-1:03:32
+    Alice: Let's move the Pro plan to $12 starting in July.
+
+    [2] Untitled session, note
+    - Decided: annual discount stays at 20%
+
+    Answer only from these excerpts, and say so if they don't contain the answer. Cite every statement that relies on an excerpt with its number in square brackets, like [1] or [2][3].
+    "#);
+
+    tpl_snapshot!(
+        test_tool_search_vault_empty,
+        ToolSearchVault {
+            query: "pricing decision".to_string(),
+            chunks: vec![],
+        },
+        @r#"
+    Excerpts from notes and transcripts for "pricing decision":
+
+    - No matching notes or transcripts found.
+    "#);
+
+    #[test]
+    fn test_parse_citations() {
+        let chunks = chunks();
+        let citations = parse_citations(
+            "The Pro plan goes to $12 [1]. The discount stays [2][1]. See [3], [a] and [1, 2].",
+            &chunks,
+        );
+
+        assert_eq!(
+            citations
+                .iter()
+                .map(|c| (c.marker, c.session_id.as_str(), c.source))
+                .collect::<Vec<_>>(),
+            vec![
+                (1, "s1", CitationSource::Transcript),
+                (2, "s2", CitationSource::Note),
+            ]
+        );
+        assert_eq!(citations[0].start_ms, Some(754_000));
+
+        assert!(parse_citations("No sources [0] here [", &chunks).is_empty());
+    }
+
+    #[test]
+    fn test_time_range() {
+        let chunks = chunks();
+        assert_eq!(chunks[0].time_range().as_deref(), Some("12:34-1:03:32"));
+        assert_eq!(chunks[0].date().as_deref(), Some("2024-06-10"));
+        assert_eq!(chunks[1].time_range(), None);
+    }
+}
@@ -3,17 +3,139 @@ use markdown::mdast;
 pub fn mdast_to_markdown(node: &mdast::Node) -> Result<String, String> {
     let task_items = collect_task_items(node);
 
-    let md = mdast_util_to_markdown::to_markdown_with_options(
+    let mut node = node.clone();
+    lower_gfm(&mut node)?;
+    let md = to_markdown(&node)?;
+
+    let md = inject_task_checkboxes(&md, &task_items);
+    Ok(unescape_markdown(&md))
+}
+
+fn to_markdown(node: &mdast::Node) -> Result<String, String> {
+    mdast_util_to_markdown::to_markdown_with_options(
         node,
         &mdast_util_to_markdown::Options {
             bullet: '-',
             ..Default::default()
         },
     )
-    .map_err(|e| e.to_string())?;
+    .map_err(|e| e.to_string())
+}
 
-    let md = inject_task_checkboxes(&md, &task_items);
-    Ok(unescape_markdown(&md))
+/// `mdast_util_to_markdown` has no handlers for the GFM extensions, so
+/// strikethrough and tables are serialized up front as raw markdown.
+fn lower_gfm(node: &mut mdast::Node) -> Result<(), String> {
+    let phrasing = !matches!(
+        node,
+        mdast::Node::Root(_)
+            | mdast::Node::Blockquote(_)
+            | mdast::Node::List(_)
+            | mdast::Node::ListItem(_)
+    );
+    let Some(children) = node.children_mut() else {
+        return Ok(());
+    };
+
+    let mut lowered = Vec::with_capacity(children.len());
+    for mut child in children.drain(..) {
+        lower_gfm(&mut child)?;
+
+        match child {
+            mdast::Node::Delete(delete) => {
+                lowered.push(raw("~~"));
+                lowered.extend(delete.children);
+                lowered.push(raw("~~"));
+            }
+            mdast::Node::Table(table) => {
+                lowered.push(mdast::Node::Html(mdast::Html {
+                    value: render_table(&table)?,
+                    position: None,
+                }));
+            }
+            // Inline html after the first child gets a space prepended by
+            // `mdast_util_to_markdown`. As text it is escaped instead, which
+            // `unescape_markdown` undoes.
+            mdast::Node::Html(h) if phrasing => lowered.push(raw(&h.value)),
+            child => lowered.push(child),
+        }
+    }
+    *children = lowered;
+
+    Ok(())
+}
+
+fn raw(value: &str) -> mdast::Node {
+    mdast::Node::Text(mdast::Text {
+        value: value.to_string(),
+        position: None,
+    })
+}
+
+fn render_table(table: &mdast::Table) -> Result<String, String> {
+    let mut rows = Vec::new();
+    for row in &table.children {
+        let mdast::Node::TableRow(row) = row else {
+            continue;
+        };
+
+        let mut cells = Vec::new();
+        for cell in &row.children {
+            let mdast::Node::TableCell(cell) = cell else {
+                continue;
+            };
+            cells.push(render_table_cell(cell)?);
+        }
+        rows.push(cells);
+    }
+
+    let columns = rows
+        .iter()
+        .map(Vec::len)
+        .max()
+        .unwrap_or(0)
+        .max(table.align.len());
+    if columns == 0 {
+        return Ok(String::new());
+    }
+
+    let delimiter = (0..columns)
+        .map(|i| match table.align.get(i) {
+            Some(mdast::AlignKind::Left) => ":---".to_string(),
+            Some(mdast::AlignKind::Right) => "---:".to_string(),
+            Some(mdast::AlignKind::Center) => ":---:".to_string(),
+            _ => "---".to_string(),
+        })
+        .collect::<Vec<_>>();
+
+    let mut lines = Vec::with_capacity(rows.len() + 1);
+    let mut rows = rows.into_iter();
+    lines.push(render_table_row(rows.next().unwrap_or_default(), columns));
+    lines.push(render_table_row(delimiter, columns));
+    lines.extend(rows.map(|row| render_table_row(row, columns)));
+
+    Ok(lines.join("\n"))
+}
+
+fn render_table_row(mut cells: Vec<String>, columns: usize) -> String {
+    cells.resize(columns, String::new());
+    format!("| {} |", cells.join(" | "))
+}
+
+// Cells are a single line, so breaks become `<br>` and pipes are encoded.
+fn render_table_cell(cell: &mdast::TableCell) -> Result<String, String> {
+    let mut lines = Vec::new();
+    for line in cell
+        .children
+        .split(|child| matches!(child, mdast::Node::Break(_)))
+    {
+        let paragraph = mdast::Node::Paragraph(mdast::Paragraph {
+            children: line.to_vec(),
+            position: None,
+        });
+        lines.push(to_markdown(&paragraph)?.trim().replace('|', "&#124;"));
+    }
+
+    Ok(lines.join("<br>"))
 }
 
 fn unescape_markdown(md: &str) -> String {
@@ -55,6 +177,7 @@ fn is_markdown_escapable(c: char) -> bool {
             | '|'
             | '<'
             | '>'
+            | '~'
     )
 }
 
@@ -1,6 +1,8 @@
 use markdown::mdast;
 use serde_json::{Value, json};
 
+use crate::marks::sort_marks;
+
 pub fn md_to_tiptap_json(md: &str) -> Result<Value, String> {
     let mdast = markdown::to_mdast(md, &markdown::ParseOptions::gfm())
         .map_err(|e| format!("Failed to parse markdown: {}", e))?;
@@ -36,6 +38,7 @@ fn convert_block_node(node: &mdast::Node) -> Option<Value> {
         mdast::Node::Blockquote(b) => Some(convert_blockquote(b)),
         mdast::Node::ThematicBreak(_) => Some(json!({ "type": "horizontalRule" })),
         mdast::Node::Image(img) => Some(convert_image(img)),
+        mdast::Node::Table(t) => Some(convert_table(t)),
         _ => None,
     }
 }
@@ -185,90 +188,182 @@ fn convert_image(img: &mdast::Image) -> Value {
     })
 }
 
-fn convert_inline_nodes(nodes: &[mdast::Node]) -> Vec<Value> {
-    nodes.iter().filter_map(convert_inline_node).collect()
-}
+fn convert_table(t: &mdast::Table) -> Value {
+    let rows: Vec<Value> = t
+        .children
+        .iter()
+        .enumerate()
+        .filter_map(|(index, row)| {
+            let mdast::Node::TableRow(row) = row else {
+                return None;
+            };
+            let cell_type = if index == 0 {
+                "tableHeader"
+            } else {
+                "tableCell"
+            };
 
-fn convert_inline_node(node: &mdast::Node) -> Option<Value> {
-    match node {
-        mdast::Node::Text(t) => Some(json!({ "type": "text", "text": t.value })),
-        mdast::Node::Strong(s) => Some(convert_marked_text(&s.children, "bold")),
-        mdast::Node::Emphasis(e) => Some(convert_marked_text(&e.children, "italic")),
-        mdast::Node::InlineCode(c) => Some(json!({
-            "type": "text",
-            "text": c.value,
-            "marks": [{ "type": "code" }]
-        })),
-        mdast::Node::Link(l) => Some(convert_link(l)),
-        mdast::Node::Delete(d) => Some(convert_marked_text(&d.children, "strike")),
-        mdast::Node::Break(_) => Some(json!({ "type": "hardBreak" })),
-        mdast::Node::Image(img) => Some(convert_image(img)),
-        _ => None,
-    }
-}
+            let cells: Vec<Value> = row
+                .children
+                .iter()
+                .filter_map(|cell| {
+                    let mdast::Node::TableCell(cell) = cell else {
+                        return None;
+                    };
+                    let paragraph = convert_paragraph(&mdast::Paragraph {
+                        children: cell.children.clone(),
+                        position: None,
+                    });
+                    Some(json!({ "type": cell_type, "content": [paragraph] }))
+                })
+                .collect();
 
-fn convert_marked_text(children: &[mdast::Node], mark_type: &str) -> Value {
-    let text = extract_text(children);
-    let mut existing_marks = extract_marks(children);
-    existing_marks.push(json!({ "type": mark_type }));
+            Some(json!({ "type": "tableRow", "content": cells }))
+        })
+        .collect();
 
     json!({
-        "type": "text",
-        "text": text,
-        "marks": existing_marks
+        "type": "table",
+        "content": rows
     })
 }
 
-fn convert_link(l: &mdast::Link) -> Value {
-    let text = extract_text(&l.children);
-    let mut existing_marks = extract_marks(&l.children);
-
-    let mut link_attrs = json!({ "href": l.url });
-    if let Some(title) = &l.title {
-        link_attrs["title"] = json!(title);
-    }
-    existing_marks.push(json!({ "type": "link", "attrs": link_attrs }));
-
-    json!({
-        "type": "text",
-        "text": text,
-        "marks": existing_marks
-    })
+fn convert_inline_nodes(nodes: &[mdast::Node]) -> Vec<Value> {
+    let mut result = Vec::new();
+    collect_inline_nodes(nodes, &mut Vec::new(), &mut result);
+    merge_adjacent_text(result)
 }
 
-fn extract_text(nodes: &[mdast::Node]) -> String {
-    nodes
-        .iter()
-        .map(|n| match n {
-            mdast::Node::Text(t) => t.value.clone(),
-            mdast::Node::Strong(s) => extract_text(&s.children),
-            mdast::Node::Emphasis(e) => extract_text(&e.children),
-            mdast::Node::InlineCode(c) => c.value.clone(),
-            mdast::Node::Link(l) => extract_text(&l.children),
-            mdast::Node::Delete(d) => extract_text(&d.children),
-            _ => String::new(),
-        })
-        .collect()
-}
+fn collect_inline_nodes(nodes: &[mdast::Node], marks: &mut Vec<Value>, out: &mut Vec<Value>) {
+    let mut index = 0;
+    while index < nodes.len() {
+        let node = &nodes[index];
+        index += 1;
 
-fn extract_marks(nodes: &[mdast::Node]) -> Vec<Value> {
-    let mut marks = Vec::new();
-    for node in nodes {
         match node {
-            mdast::Node::Strong(s) => {
-                marks.push(json!({ "type": "bold" }));
-                marks.extend(extract_marks(&s.children));
+            mdast::Node::Text(t) => {
+                // `@[label](type:id)` parses as an `@` followed by a link.
+                if let Some(prefix) = t.value.strip_suffix('@')
+                    && let Some(mention) = nodes.get(index).and_then(convert_mention)
+                {
+                    if !prefix.is_empty() {
+                        out.push(text_node(prefix, marks));
+                    }
+                    out.push(mention);
+                    index += 1;
+                    continue;
+                }
+                out.push(text_node(&t.value, marks));
             }
+            mdast::Node::InlineCode(c) => {
+                marks.push(json!({ "type": "code" }));
+                out.push(text_node(&c.value, marks));
+                marks.pop();
+            }
+            mdast::Node::Strong(s) => with_mark(&s.children, json!({ "type": "bold" }), marks, out),
             mdast::Node::Emphasis(e) => {
-                marks.push(json!({ "type": "italic" }));
-                marks.extend(extract_marks(&e.children));
+                with_mark(&e.children, json!({ "type": "italic" }), marks, out)
             }
             mdast::Node::Delete(d) => {
-                marks.push(json!({ "type": "strike" }));
-                marks.extend(extract_marks(&d.children));
+                with_mark(&d.children, json!({ "type": "strike" }), marks, out)
+            }
+            mdast::Node::Link(l) => {
+                let mut link_attrs = json!({ "href": l.url });
+                if let Some(title) = &l.title {
+                    link_attrs["title"] = json!(title);
+                }
+                with_mark(
+                    &l.children,
+                    json!({ "type": "link", "attrs": link_attrs }),
+                    marks,
+                    out,
+                )
             }
+            mdast::Node::Html(h) => match h.value.trim().to_lowercase().as_str() {
+                "<u>" => marks.push(json!({ "type": "underline" })),
+                "<mark>" => marks.push(json!({ "type": "highlight" })),
+                "</u>" => close_mark(marks, "underline"),
+                "</mark>" => close_mark(marks, "highlight"),
+                "<br>" | "<br/>" | "<br />" => out.push(json!({ "type": "hardBreak" })),
+                _ => {}
+            },
+            mdast::Node::Break(_) => out.push(json!({ "type": "hardBreak" })),
+            mdast::Node::Image(img) => out.push(convert_image(img)),
             _ => {}
         }
     }
-    marks
+}
+
+fn with_mark(children: &[mdast::Node], mark: Value, marks: &mut Vec<Value>, out: &mut Vec<Value>) {
+    marks.push(mark);
+    collect_inline_nodes(children, marks, out);
+    marks.pop();
+}
+
+fn close_mark(marks: &mut Vec<Value>, mark_type: &str) {
+    if let Some(index) = marks.iter().rposition(|m| m["type"] == mark_type) {
+        marks.remove(index);
+    }
+}
+
+fn text_node(text: &str, marks: &[Value]) -> Value {
+    let mut node = json!({ "type": "text", "text": text });
+    if !marks.is_empty() {
+        let mut marks = marks.to_vec();
+        sort_marks(&mut marks);
+        node["marks"] = json!(marks);
+    }
+    node
+}
+
+fn convert_mention(node: &mdast::Node) -> Option<Value> {
+    let mdast::Node::Link(link) = node else {
+        return None;
+    };
+    let (kind, id) = link.url.split_once(':')?;
+    if kind.is_empty()
+        || id.is_empty()
+        || id.starts_with("//")
+        || !kind
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c == '_' || c == '-')
+    {
+        return None;
+    }
+    let [mdast::Node::Text(label)] = link.children.as_slice() else {
+        return None;
+    };
+
+    Some(json!({
+        "type": "mention-@",
+        "attrs": { "id": id, "type": kind, "label": label.value }
+    }))
+}
+
+fn merge_adjacent_text(nodes: Vec<Value>) -> Vec<Value> {
+    let mut result: Vec<Value> = Vec::with_capacity(nodes.len());
+
+    for node in nodes {
+        if node["type"] == "text" && node["text"].as_str().is_some_and(str::is_empty) {
+            continue;
+        }
+
+        if let Some(last) = result.last_mut()
+            && last["type"] == "text"
+            && node["type"] == "text"
+            && last.get("marks") == node.get("marks")
+        {
+            let text = format!(
+                "{}{}",
+                last["text"].as_str().unwrap_or_default(),
+                node["text"].as_str().unwrap_or_default()
+            );
+            last["text"] = json!(text);
+            continue;
+        }
+
+        result.push(node);
+    }
+
+    result
 }
@@ -2,6 +2,7 @@
 
 mod from_ast;
 mod from_md;
+mod marks;
 mod to_ast;
 
 pub use from_ast::mdast_to_markdown;
@@ -16,6 +17,7 @@ pub fn tiptap_json_to_md(json: &serde_json::Value) -> Result<String, String> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use quickcheck::Arbitrary;
 
     fn to_md(json: serde_json::Value) -> String {
         let mdast = tiptap_json_to_mdast(&json);
@@ -152,7 +154,7 @@ mod tests {
 
         ***
 
-        [A ](https://example.com)[link](https://example.com)
+        [A link](https://example.com)
         ");
     }
 
@@ -467,7 +469,7 @@ mod tests {
         insta::assert_snapshot!(to_md(json), @r"
         Text with ***bold and italic*** combined.
 
-        **Bold with *****nested italic***** inside** normal text.
+        **Bold with *nested italic* inside** normal text.
 
         **[Bold link](https://example.com)** and *`italic code`*
         ");
@@ -847,4 +849,259 @@ mod tests {
         let result = tiptap_json_to_md(&json);
         assert!(result.is_ok(), "should not panic on multi-byte chars");
     }
+
+    #[test]
+    fn test_table_to_markdown() {
+        let cell = |kind: &str, text: &str| {
+            serde_json::json!({
+                "type": kind,
+                "content": [{ "type": "paragraph", "content": [{ "type": "text", "text": text }] }]
+            })
+        };
+        let json = serde_json::json!({
+            "type": "doc",
+            "content": [{
+                "type": "table",
+                "content": [
+                    { "type": "tableRow", "content": [cell("tableHeader", "Owner"), cell("tableHeader", "Task")] },
+                    { "type": "tableRow", "content": [
+                        cell("tableCell", "Jane"),
+                        {
+                            "type": "tableCell",
+                            "content": [
+                                { "type": "paragraph", "content": [{ "type": "text", "text": "Ship", "marks": [{ "type": "bold" }] }] },
+                                { "type": "paragraph", "content": [{ "type": "text", "text": "a|b" }] }
+                            ]
+                        }
+                    ] },
+                    { "type": "tableRow", "content": [cell("tableCell", "John")] }
+                ]
+            }]
+        });
+
+        insta::assert_snapshot!(to_md(json), @r"
+        | Owner | Task |
+        | --- | --- |
+        | Jane | **Ship**<br>a&#124;b |
+        | John |  |
+        ");
+    }
+
+    #[test]
+    fn test_md_to_tiptap_table() {
+        let md = "| Owner | Task |\n| :--- | ---: |\n| Jane | **Ship**<br>it |";
+        let json = md_to_tiptap_json(md).unwrap();
+
+        let table = &json["content"][0];
+        assert_eq!(table["type"], "table");
+        assert_eq!(table["content"][0]["content"][0]["type"], "tableHeader");
+        assert_eq!(table["content"][1]["content"][0]["type"], "tableCell");
+        assert_eq!(
+            table["content"][1]["content"][1]["content"][0]["content"],
+            serde_json::json!([
+                { "type": "text", "text": "Ship", "marks": [{ "type": "bold" }] },
+                { "type": "hardBreak" },
+                { "type": "text", "text": "it" }
+            ])
+        );
+    }
+
+    #[test]
+    fn test_marks_and_mentions_to_markdown() {
+        let json = serde_json::json!({
+            "type": "doc",
+            "content": [{
+                "type": "paragraph",
+                "content": [
+                    { "type": "text", "text": "gone", "marks": [{ "type": "strike" }] },
+                    { "type": "text", "text": " " },
+                    { "type": "text", "text": "under ", "marks": [{ "type": "underline" }] },
+                    { "type": "text", "text": "lit", "marks": [{ "type": "highlight" }, { "type": "bold" }] },
+                    { "type": "text", "text": " cc " },
+                    {
+                        "type": "mention-@",
+                        "attrs": { "id": "5c4f", "type": "human", "label": "Jane Doe" }
+                    }
+                ]
+            }]
+        });
+
+        insta::assert_snapshot!(to_md(json), @"~~gone~~ <u>under</u> **<mark>lit</mark>** cc @[Jane Doe](human:5c4f)");
+    }
+
+    #[test]
+    fn test_md_to_tiptap_mention() {
+        let json =
+            md_to_tiptap_json("Ask @[Jane Doe](human:5c4f) about [docs](https://example.com)")
+                .unwrap();
+
+        assert_eq!(
+            json["content"][0]["content"],
+            serde_json::json!([
+                { "type": "text", "text": "Ask " },
+                { "type": "mention-@", "attrs": { "id": "5c4f", "type": "human", "label": "Jane Doe" } },
+                { "type": "text", "text": " about " },
+                {
+                    "type": "text",
+                    "text": "docs",
+                    "marks": [{ "type": "link", "attrs": { "href": "https://example.com" } }]
+                }
+            ])
+        );
+    }
+
+    const WORDS: &[&str] = &["alpha", "beta", "gamma", "Jane", "q3", "42"];
+    const MARKS: &[&str] = &[
+        "bold",
+        "italic",
+        "strike",
+        "underline",
+        "highlight",
+        "link",
+        "code",
+    ];
+
+    #[derive(Debug, Clone)]
+    struct Doc(serde_json::Value);
+
+    fn arbitrary_words(g: &mut quickcheck::Gen) -> String {
+        let count = 1 + usize::arbitrary(g) % 2;
+        (0..count)
+            .map(|_| *g.choose(WORDS).unwrap())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    fn arbitrary_inline(g: &mut quickcheck::Gen) -> Vec<serde_json::Value> {
+        let count = usize::arbitrary(g) % 4;
+        let mut nodes = Vec::new();
+
+        for i in 0..count {
+            if i > 0 {
+                nodes.push(serde_json::json!({ "type": "text", "text": " " }));
+            }
+
+            if u8::arbitrary(g) % 6 == 0 {
+                nodes.push(serde_json::json!({
+                    "type": "mention-@",
+                    "attrs": {
+                        "id": format!("id-{}", u8::arbitrary(g)),
+                        "type": *g.choose(&["session", "human", "organization"]).unwrap(),
+                        "label": arbitrary_words(g),
+                    }
+                }));
+                continue;
+            }
+
+            let text = arbitrary_words(g);
+            let mut marks = Vec::new();
+            for mark in MARKS {
+                if u8::arbitrary(g) % 3 != 0 {
+                    continue;
+                }
+                marks.push(match *mark {
+                    "link" => serde_json::json!({
+                        "type": "link",
+                        "attrs": { "href": format!("https://example.com/{}", u8::arbitrary(g)) }
+                    }),
+                    mark => serde_json::json!({ "type": mark }),
+                });
+            }
+
+            let mut node = serde_json::json!({ "type": "text", "text": text });
+            if !marks.is_empty() {
+                node["marks"] = serde_json::json!(marks);
+            }
+            nodes.push(node);
+        }
+
+        normalize_inline(nodes)
+    }
+
+    fn arbitrary_paragraph(g: &mut quickcheck::Gen) -> serde_json::Value {
+        let content = arbitrary_inline(g);
+        if content.is_empty() {
+            serde_json::json!({ "type": "paragraph" })
+        } else {
+            serde_json::json!({ "type": "paragraph", "content": content })
+        }
+    }
+
+    fn arbitrary_table(g: &mut quickcheck::Gen) -> serde_json::Value {
+        let rows = 1 + usize::arbitrary(g) % 3;
+        let columns = 1 + usize::arbitrary(g) % 3;
+
+        let rows: Vec<_> = (0..rows)
+            .map(|row| {
+                let kind = if row == 0 { "tableHeader" } else { "tableCell" };
+                let cells: Vec<_> = (0..columns)
+                    .map(|_| serde_json::json!({ "type": kind, "content": [arbitrary_paragraph(g)] }))
+                    .collect();
+                serde_json::json!({ "type": "tableRow", "content": cells })
+            })
+            .collect();
+
+        serde_json::json!({ "type": "table", "content": rows })
+    }
+
+    // Adjacent text with the same marks is a single node once parsed back.
+    fn normalize_inline(nodes: Vec<serde_json::Value>) -> Vec<serde_json::Value> {
+        let mut result: Vec<serde_json::Value> = Vec::new();
+        for node in nodes {
+            if let Some(last) = result.last_mut()
+                && last["type"] == "text"
+                && node["type"] == "text"
+                && last.get("marks") == node.get("marks")
+            {
+                let text = format!(
+                    "{}{}",
+                    last["text"].as_str().unwrap(),
+                    node["text"].as_str().unwrap()
+                );
+                last["text"] = serde_json::json!(text);
+                continue;
+            }
+            result.push(node);
+        }
+        result
+    }
+
+    impl quickcheck::Arbitrary for Doc {
+        fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+            let count = 1 + usize::arbitrary(g) % 4;
+            let content: Vec<_> = (0..count)
+                .map(|_| {
+                    if bool::arbitrary(g) {
+                        arbitrary_table(g)
+                    } else {
+                        let mut paragraph = arbitrary_paragraph(g);
+                        if paragraph.get("content").is_none() {
+                            paragraph["content"] =
+                                serde_json::json!([{ "type": "text", "text": "alpha" }]);
+                        }
+                        paragraph
+                    }
+                })
+                .collect();
+
+            Doc(serde_json::json!({ "type": "doc", "content": content }))
+        }
+    }
+
+    #[quickcheck_macros::quickcheck]
+    fn prop_tiptap_markdown_roundtrip(doc: Doc) -> bool {
+        let md = tiptap_json_to_md(&doc.0).unwrap();
+        let parsed = md_to_tiptap_json(&md).unwrap();
+        if parsed != doc.0 {
+            eprintln!("markdown:\n{}\nexpected: {}\nactual: {}", md, doc.0, parsed);
+        }
+        parsed == doc.0
+    }
+
+    #[quickcheck_macros::quickcheck]
+    fn prop_markdown_is_stable(doc: Doc) -> bool {
+        let md = tiptap_json_to_md(&doc.0).unwrap();
+        let again = tiptap_json_to_md(&md_to_tiptap_json(&md).unwrap()).unwrap();
+        md == again
+    }
 }
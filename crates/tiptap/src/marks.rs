@@ -0,0 +1,36 @@
+use serde_json::Value;
+
+// Outermost first. `code` has to stay innermost since inline code can't
+// contain other markup.
+const MARK_ORDER: &[&str] = &[
+    "bold",
+    "italic",
+    "strike",
+    "underline",
+    "highlight",
+    "link",
+    "code",
+];
+
+pub(crate) fn mark_name(mark: &Value) -> Option<&'static str> {
+    match mark.get("type")?.as_str()? {
+        "bold" | "strong" => Some("bold"),
+        "italic" | "em" => Some("italic"),
+        "strike" => Some("strike"),
+        "underline" => Some("underline"),
+        "highlight" => Some("highlight"),
+        "link" => Some("link"),
+        "code" => Some("code"),
+        _ => None,
+    }
+}
+
+fn mark_rank(mark: &Value) -> usize {
+    mark_name(mark)
+        .and_then(|name| MARK_ORDER.iter().position(|m| *m == name))
+        .unwrap_or(MARK_ORDER.len())
+}
+
+pub(crate) fn sort_marks(marks: &mut [Value]) {
+    marks.sort_by_key(mark_rank);
+}
@@ -1,5 +1,7 @@
 use markdown::mdast;
 
+use crate::marks::{mark_name, sort_marks};
+
 pub fn tiptap_json_to_mdast(json: &serde_json::Value) -> mdast::Node {
     let children = convert_content(json);
     mdast::Node::Root(mdast::Root {
@@ -32,6 +34,7 @@ fn convert_node(node: &serde_json::Value) -> Option<mdast::Node> {
         "horizontalRule" => Some(convert_horizontal_rule()),
         "hardBreak" => Some(convert_hard_break()),
         "image" => Some(convert_image(node)),
+        "table" => Some(convert_table(node)),
         "text" => convert_text(node),
         _ => None,
     }
@@ -228,88 +231,273 @@ fn convert_text(node: &serde_json::Value) -> Option<mdast::Node> {
     }))
 }
 
-fn convert_inline_content(node: &serde_json::Value) -> Vec<mdast::Node> {
-    let Some(content) = node.get("content").and_then(|c| c.as_array()) else {
-        return vec![];
-    };
+fn convert_table(node: &serde_json::Value) -> mdast::Node {
+    let rows: Vec<mdast::Node> = node
+        .get("content")
+        .and_then(|c| c.as_array())
+        .map(|rows| {
+            rows.iter()
+                .filter(|row| row.get("type").and_then(|t| t.as_str()) == Some("tableRow"))
+                .map(convert_table_row)
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let columns = rows
+        .iter()
+        .map(|row| match row {
+            mdast::Node::TableRow(r) => r.children.len(),
+            _ => 0,
+        })
+        .max()
+        .unwrap_or(0);
 
-    content.iter().filter_map(convert_inline_node).collect()
+    mdast::Node::Table(mdast::Table {
+        children: rows,
+        align: vec![mdast::AlignKind::None; columns],
+        position: None,
+    })
 }
 
-fn convert_inline_node(node: &serde_json::Value) -> Option<mdast::Node> {
-    let node_type = node.get("type")?.as_str()?;
+fn convert_table_row(node: &serde_json::Value) -> mdast::Node {
+    let cells = node
+        .get("content")
+        .and_then(|c| c.as_array())
+        .map(|cells| cells.iter().map(convert_table_cell).collect())
+        .unwrap_or_default();
 
-    match node_type {
-        "text" => convert_text_with_marks(node),
-        "hardBreak" => Some(convert_hard_break()),
-        "image" => Some(convert_image(node)),
-        _ => None,
+    mdast::Node::TableRow(mdast::TableRow {
+        children: cells,
+        position: None,
+    })
+}
+
+// GFM cells hold a single line of inline content, so paragraphs inside a
+// cell are joined with breaks.
+fn convert_table_cell(node: &serde_json::Value) -> mdast::Node {
+    let mut children = Vec::new();
+
+    if let Some(blocks) = node.get("content").and_then(|c| c.as_array()) {
+        for block in blocks {
+            let inline = convert_inline_content(block);
+            if inline.is_empty() {
+                continue;
+            }
+            if !children.is_empty() {
+                children.push(convert_hard_break());
+            }
+            children.extend(inline);
+        }
     }
+
+    mdast::Node::TableCell(mdast::TableCell {
+        children,
+        position: None,
+    })
 }
 
-fn convert_text_with_marks(node: &serde_json::Value) -> Option<mdast::Node> {
-    let text = node.get("text")?.as_str()?;
-    let marks = node.get("marks").and_then(|m| m.as_array());
+/// `@[label](type:id)`, the same syntax `hypr_buffer` renders mentions from.
+fn convert_mention(node: &serde_json::Value) -> mdast::Node {
+    let attr = |key: &str| {
+        node.get("attrs")
+            .and_then(|a| a.get(key))
+            .and_then(|v| v.as_str())
+            .unwrap_or("")
+    };
 
-    let text_node = mdast::Node::Text(mdast::Text {
-        value: text.to_string(),
+    let (id, kind, label) = (attr("id"), attr("type"), attr("label"));
+    if id.is_empty() || kind.is_empty() {
+        return mdast::Node::Text(mdast::Text {
+            value: format!("@{}", label),
+            position: None,
+        });
+    }
+
+    mdast::Node::Html(mdast::Html {
+        value: format!("@[{}]({}:{})", label, kind, id),
         position: None,
-    });
+    })
+}
 
-    let Some(marks) = marks else {
-        return Some(text_node);
+struct InlineItem {
+    marks: Vec<serde_json::Value>,
+    node: mdast::Node,
+}
+
+fn convert_inline_content(node: &serde_json::Value) -> Vec<mdast::Node> {
+    let Some(content) = node.get("content").and_then(|c| c.as_array()) else {
+        return vec![];
     };
 
-    let mut result = text_node;
+    wrap_marks(content.iter().filter_map(convert_inline_node).collect())
+}
+
+fn convert_inline_node(node: &serde_json::Value) -> Option<InlineItem> {
+    let node_type = node.get("type")?.as_str()?;
 
-    for mark in marks.iter().rev() {
-        let mark_type = mark.get("type").and_then(|t| t.as_str());
-        result = match mark_type {
-            Some("bold") | Some("strong") => mdast::Node::Strong(mdast::Strong {
-                children: vec![result],
-                position: None,
-            }),
-            Some("italic") | Some("em") => mdast::Node::Emphasis(mdast::Emphasis {
-                children: vec![result],
-                position: None,
-            }),
-            Some("code") => {
-                if let mdast::Node::Text(t) = result {
+    let mut marks: Vec<serde_json::Value> = node
+        .get("marks")
+        .and_then(|m| m.as_array())
+        .map(|marks| {
+            marks
+                .iter()
+                .filter(|m| mark_name(m).is_some())
+                .cloned()
+                .collect()
+        })
+        .unwrap_or_default();
+    sort_marks(&mut marks);
+
+    let node = match node_type {
+        "text" => {
+            let text = node.get("text")?.as_str()?.to_string();
+            match marks.iter().position(|m| mark_name(m) == Some("code")) {
+                Some(index) => {
+                    marks.remove(index);
                     mdast::Node::InlineCode(mdast::InlineCode {
-                        value: t.value,
+                        value: text,
                         position: None,
                     })
-                } else {
-                    result
                 }
+                None => mdast::Node::Text(mdast::Text {
+                    value: text,
+                    position: None,
+                }),
             }
+        }
+        "hardBreak" => convert_hard_break(),
+        "image" => convert_image(node),
+        "mention-@" => convert_mention(node),
+        _ => return None,
+    };
+
+    Some(InlineItem { marks, node })
+}
+
+/// Nests runs of adjacent nodes that share a mark under a single mark node,
+/// so `**a *b* c**` doesn't come out as `**a *****b***** c**`.
+fn wrap_marks(items: Vec<InlineItem>) -> Vec<mdast::Node> {
+    let mut result = Vec::new();
+    let mut items = items.into_iter().peekable();
+
+    while let Some(item) = items.next() {
+        let Some(mark) = item.marks.first().cloned() else {
+            result.push(item.node);
+            continue;
+        };
+
+        let mut run = vec![item];
+        while let Some(next) = items.next_if(|next| next.marks.contains(&mark)) {
+            run.push(next);
+        }
+        for item in &mut run {
+            item.marks.retain(|m| m != &mark);
+        }
+
+        result.extend(wrap_mark(&mark, wrap_marks(run)));
+    }
+
+    result
+}
+
+fn wrap_mark(mark: &serde_json::Value, mut children: Vec<mdast::Node>) -> Vec<mdast::Node> {
+    // Delimiters next to whitespace don't open or close emphasis, so keep the
+    // whitespace outside of the mark.
+    let leading = take_whitespace(&mut children, true);
+    let trailing = take_whitespace(&mut children, false);
+
+    let mut result: Vec<mdast::Node> = leading.into_iter().collect();
+
+    if !children.is_empty() {
+        match mark_name(mark) {
+            Some("bold") => result.push(mdast::Node::Strong(mdast::Strong {
+                children,
+                position: None,
+            })),
+            Some("italic") => result.push(mdast::Node::Emphasis(mdast::Emphasis {
+                children,
+                position: None,
+            })),
+            Some("strike") => result.push(mdast::Node::Delete(mdast::Delete {
+                children,
+                position: None,
+            })),
             Some("link") => {
-                let url = mark
-                    .get("attrs")
+                let attrs = mark.get("attrs");
+                let url = attrs
                     .and_then(|a| a.get("href"))
                     .and_then(|h| h.as_str())
                     .unwrap_or("")
                     .to_string();
-                let title = mark
-                    .get("attrs")
+                let title = attrs
                     .and_then(|a| a.get("title"))
                     .and_then(|t| t.as_str())
                     .map(|s| s.to_string());
 
-                mdast::Node::Link(mdast::Link {
+                result.push(mdast::Node::Link(mdast::Link {
                     url,
                     title,
-                    children: vec![result],
+                    children,
                     position: None,
-                })
+                }));
             }
-            Some("strike") => mdast::Node::Delete(mdast::Delete {
-                children: vec![result],
-                position: None,
-            }),
-            _ => result,
-        };
+            // No markdown syntax for these; inline HTML survives the round trip.
+            Some("underline") => result.extend(wrap_html("<u>", "</u>", children)),
+            Some("highlight") => result.extend(wrap_html("<mark>", "</mark>", children)),
+            _ => result.extend(children),
+        }
     }
 
-    Some(result)
+    result.extend(trailing);
+    result
+}
+
+fn wrap_html(open: &str, close: &str, children: Vec<mdast::Node>) -> Vec<mdast::Node> {
+    let html = |value: &str| {
+        mdast::Node::Html(mdast::Html {
+            value: value.to_string(),
+            position: None,
+        })
+    };
+
+    let mut result = vec![html(open)];
+    result.extend(children);
+    result.push(html(close));
+    result
+}
+
+fn take_whitespace(children: &mut Vec<mdast::Node>, leading: bool) -> Option<mdast::Node> {
+    let index = if leading {
+        0
+    } else {
+        children.len().checked_sub(1)?
+    };
+    let mdast::Node::Text(text) = children.get_mut(index)? else {
+        return None;
+    };
+
+    let trimmed = if leading {
+        text.value.trim_start()
+    } else {
+        text.value.trim_end()
+    };
+    if trimmed.len() == text.value.len() {
+        return None;
+    }
+
+    let whitespace = if leading {
+        text.value[..text.value.len() - trimmed.len()].to_string()
+    } else {
+        text.value[trimmed.len()..].to_string()
+    };
+    text.value = trimmed.to_string();
+
+    if text.value.is_empty() {
+        children.remove(index);
+    }
+
+    Some(mdast::Node::Text(mdast::Text {
+        value: whitespace,
+        position: None,
+    }))
 }
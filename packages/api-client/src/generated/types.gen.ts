@@ -41,7 +41,7 @@ export type CanStartTrialResponse = {
     reason?: null | CanStartTrialReason;
 };
 
-export type CharTask = 'chat' | 'enhance' | 'title';
+export type CharTask = 'chat' | 'enhance' | 'extract' | 'title';
 
 export type ConnectSessionResponse = {
     expires_at: string;
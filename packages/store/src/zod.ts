@@ -259,6 +259,9 @@ export const generalSchema = z.object({
   telemetry_consent: z.boolean().default(true),
   save_recordings: z.boolean().default(true),
   notification_event: z.boolean().default(true),
+  notification_event_lead_minutes: jsonObject(
+    z.array(z.number()).default([5]),
+  ),
   notification_detect: z.boolean().default(true),
   respect_dnd: z.boolean().default(false),
   quit_intercept: z.boolean().default(false),
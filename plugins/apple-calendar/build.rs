@@ -3,6 +3,11 @@ const COMMANDS: &[&str] = &[
     "list_calendars",
     "list_events",
     "create_event",
+    "update_event",
+    "delete_event",
+    "rsvp_event",
+    "caldav_config",
+    "configure_caldav",
 ];
 
 fn main() {
@@ -37,6 +37,46 @@ async createEvent(input: CreateEventInput) : Promise<Result<string, string>> {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
+},
+async updateEvent(input: UpdateEventInput) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:apple-calendar|update_event", { input }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async deleteEvent(input: DeleteEventInput) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:apple-calendar|delete_event", { input }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async rsvpEvent(input: RsvpEventInput) : Promise<Result<RsvpOutcome, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:apple-calendar|rsvp_event", { input }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async caldavConfig() : Promise<Result<CalDavConfig | null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:apple-calendar|caldav_config") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async configureCaldav(config: CalDavConfig, password: string | null) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:apple-calendar|configure_caldav", { config, password }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
 }
 }
 
@@ -60,6 +100,19 @@ export type AlarmProximity = "None" | "Enter" | "Leave"
 export type AlarmType = "Display" | "Audio" | "Procedure" | "Email"
 export type AppleCalendar = { id: string; title: string; calendar_type: CalendarType; color: CalendarColor | null; allows_content_modifications: boolean; is_immutable: boolean; is_subscribed: boolean; supported_event_availabilities: EventAvailability[]; allowed_entity_types: CalendarEntityType[]; source: CalendarSource }
 export type AppleEvent = { event_identifier: string; calendar_item_identifier: string; external_identifier: string; calendar: CalendarRef; title: string; location: string | null; url: string | null; notes: string | null; creation_date: string | null; last_modified_date: string | null; time_zone: string | null; start_date: string; end_date: string; is_all_day: boolean; availability: EventAvailability; status: EventStatus; has_alarms: boolean; has_attendees: boolean; has_notes: boolean; has_recurrence_rules: boolean; organizer: Participant | null; attendees: Participant[]; structured_location: StructuredLocation | null; recurrence: RecurrenceInfo | null; occurrence_date: string | null; is_detached: boolean; alarms: Alarm[]; birthday_contact_identifier: string | null; is_birthday: boolean }
+/**
+ * A CalDAV account, authenticated with an app password. The password is
+ * kept in the OS keyring and never leaves the backend.
+ */
+export type CalDavAccount = { server_url: string; username: string }
+/**
+ * Calendar sources used on Linux, where EventKit isn't available.
+ */
+export type CalDavConfig = { account: CalDavAccount | null; 
+/**
+ * Read-only ICS subscriptions, `https://` or `webcal://`.
+ */
+feeds: string[] }
 export type CalendarChangedEvent = null
 export type CalendarColor = { red: number; green: number; blue: number; alpha: number }
 export type CalendarEntityType = "Event" | "Reminder"
@@ -68,8 +121,13 @@ export type CalendarSource = { identifier: string; title: string; source_type: C
 export type CalendarSourceType = "Local" | "Exchange" | "CalDav" | "MobileMe" | "Subscribed" | "Birthdays"
 export type CalendarType = "Local" | "CalDav" | "Exchange" | "Subscription" | "Birthday"
 export type CreateEventInput = { title: string; start_date: string; end_date: string; calendar_id: string; is_all_day: boolean | null; location: string | null; notes: string | null; url: string | null }
+export type DeleteEventInput = { event_id: string; occurrence_date: string | null; span: EventSpan | null }
 export type EventAvailability = "NotSupported" | "Busy" | "Free" | "Tentative" | "Unavailable"
 export type EventFilter = { from: string; to: string; calendar_tracking_id: string }
+/**
+ * Which occurrences of a recurring event a change applies to.
+ */
+export type EventSpan = "ThisEvent" | "FutureEvents"
 export type EventStatus = "None" | "Confirmed" | "Tentative" | "Canceled"
 export type GeoLocation = { latitude: number; longitude: number }
 export type Participant = { name: string | null; email: string | null; is_current_user: boolean; role: ParticipantRole; status: ParticipantStatus; participant_type: ParticipantType; schedule_status: ParticipantScheduleStatus | null; url: string | null; contact: ParticipantContact | null }
@@ -84,7 +142,21 @@ export type RecurrenceFrequency = "Daily" | "Weekly" | "Monthly" | "Yearly"
 export type RecurrenceInfo = { series_identifier: string; has_recurrence_rules: boolean; occurrence: RecurrenceOccurrence | null; rules: RecurrenceRule[] }
 export type RecurrenceOccurrence = { original_start: string; is_detached: boolean }
 export type RecurrenceRule = { frequency: RecurrenceFrequency; interval: number; days_of_week: RecurrenceDayOfWeek[]; days_of_month: number[]; months_of_year: number[]; weeks_of_year: number[]; days_of_year: number[]; set_positions: number[]; first_day_of_week: Weekday | null; end: RecurrenceEnd | null }
+export type RsvpEventInput = { event_id: string; occurrence_date: string | null; response: ParticipantStatus }
+/**
+ * What became of an RSVP. EventKit can't answer invitations, so on
+ * `Unsupported` the UI should send the user to Calendar instead.
+ */
+export type RsvpOutcome = "Sent" | "Unsupported"
 export type StructuredLocation = { title: string; geo: GeoLocation | null; radius: number | null }
+/**
+ * Fields left as `None` keep their current value.
+ */
+export type UpdateEventInput = { event_id: string; 
+/**
+ * Picks an occurrence of a recurring event; without it, the first one.
+ */
+occurrence_date: string | null; span: EventSpan | null; title: string | null; start_date: string | null; end_date: string | null; is_all_day: boolean | null; location: string | null; notes: string | null }
 export type Weekday = "Sunday" | "Monday" | "Tuesday" | "Wednesday" | "Thursday" | "Friday" | "Saturday"
 
 /** tauri-specta globals **/
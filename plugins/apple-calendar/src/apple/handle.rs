@@ -12,8 +12,9 @@ use objc2_foundation::{NSArray, NSDate};
 
 use crate::error::Error;
 use crate::types::{AppleCalendar, AppleEvent};
-use crate::types::{CreateEventInput, EventFilter};
+use crate::types::{CreateEventInput, DeleteEventInput, EventFilter, EventSpan, UpdateEventInput};
 
+use super::recurrence::offset_date_time_from;
 use super::transforms::{transform_calendar, transform_event};
 
 fn retry_backoff() -> ConstantBuilder {
@@ -81,6 +82,57 @@ impl Handle {
         result.map_err(|_| Error::XpcConnectionFailed)
     }
 
+    /// The event with `event_id`, or its occurrence at `occurrence_date`.
+    /// Occurrences of a recurring event share its identifier, so they are
+    /// looked up among the events around that date.
+    fn find_event(
+        event_store: &EKEventStore,
+        event_id: &str,
+        occurrence_date: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> Result<Retained<EKEvent>, Error> {
+        let event_store = AssertUnwindSafe(event_store);
+
+        let Some(occurrence_date) = occurrence_date else {
+            return objc2::exception::catch(|| unsafe {
+                event_store.eventWithIdentifier(&NSString::from_str(event_id))
+            })
+            .map_err(|_| Error::XpcConnectionFailed)?
+            .ok_or(Error::EventNotFound);
+        };
+
+        let (start_date, end_date) = [
+            occurrence_date - chrono::Duration::days(1),
+            occurrence_date + chrono::Duration::days(1),
+        ]
+        .map(|v| {
+            AssertUnwindSafe(NSDate::initWithTimeIntervalSince1970(
+                NSDate::alloc(),
+                v.timestamp() as f64,
+            ))
+        })
+        .into();
+
+        let events = objc2::exception::catch(|| unsafe {
+            let predicate = event_store.predicateForEventsWithStartDate_endDate_calendars(
+                &start_date,
+                &end_date,
+                None,
+            );
+            event_store.eventsMatchingPredicate(&predicate)
+        })
+        .map_err(|_| Error::XpcConnectionFailed)?;
+
+        events
+            .into_iter()
+            .find(|event| {
+                let id = unsafe { event.eventIdentifier() }.map(|s| s.to_string());
+                let date = unsafe { event.occurrenceDate() }.map(offset_date_time_from);
+                id.as_deref() == Some(event_id)
+                    && date.is_some_and(|d| d.timestamp() == occurrence_date.timestamp())
+            })
+            .ok_or(Error::EventNotFound)
+    }
+
     fn get_calendars_with_exception_handling(
         event_store: &EKEventStore,
     ) -> Result<Retained<NSArray<EKCalendar>>, Error> {
@@ -220,4 +272,111 @@ impl Handle {
             .when(|e| matches!(e, Error::XpcConnectionFailed))
             .call()
     }
+
+    pub fn update_event(&self, input: UpdateEventInput) -> Result<(), Error> {
+        if !Self::has_calendar_access() {
+            return Err(Error::CalendarAccessDenied);
+        }
+
+        let update = || {
+            let event_store = Self::create_event_store();
+            let event = Self::find_event(&event_store, &input.event_id, input.occurrence_date)?;
+
+            unsafe {
+                if let Some(ref title) = input.title {
+                    event.setTitle(Some(&NSString::from_str(title)));
+                }
+
+                if let Some(start_date) = input.start_date {
+                    let start_date = NSDate::initWithTimeIntervalSince1970(
+                        NSDate::alloc(),
+                        start_date.timestamp() as f64,
+                    );
+                    event.setStartDate(Some(&start_date));
+                }
+
+                if let Some(end_date) = input.end_date {
+                    let end_date = NSDate::initWithTimeIntervalSince1970(
+                        NSDate::alloc(),
+                        end_date.timestamp() as f64,
+                    );
+                    event.setEndDate(Some(&end_date));
+                }
+
+                if let Some(is_all_day) = input.is_all_day {
+                    event.setAllDay(is_all_day);
+                }
+
+                if let Some(ref location) = input.location {
+                    event.setLocation(Some(&NSString::from_str(location)));
+                }
+
+                if let Some(ref notes) = input.notes {
+                    event.setNotes(Some(&NSString::from_str(notes)));
+                }
+            }
+
+            let span = to_ek_span(input.span.as_ref());
+            let event_store = AssertUnwindSafe(&event_store);
+            let event = AssertUnwindSafe(&event);
+
+            let result = objc2::exception::catch(|| unsafe {
+                event_store.saveEvent_span_commit_error(&event, span, true)
+            });
+
+            match result {
+                Ok(Ok(())) => Ok(()),
+                Ok(Err(ns_error)) => {
+                    let error_msg = ns_error.localizedDescription().to_string();
+                    Err(Error::ObjectiveCException(error_msg))
+                }
+                Err(_) => Err(Error::XpcConnectionFailed),
+            }
+        };
+
+        update
+            .retry(retry_backoff())
+            .when(|e| matches!(e, Error::XpcConnectionFailed))
+            .call()
+    }
+
+    pub fn delete_event(&self, input: DeleteEventInput) -> Result<(), Error> {
+        if !Self::has_calendar_access() {
+            return Err(Error::CalendarAccessDenied);
+        }
+
+        let delete = || {
+            let event_store = Self::create_event_store();
+            let event = Self::find_event(&event_store, &input.event_id, input.occurrence_date)?;
+
+            let span = to_ek_span(input.span.as_ref());
+            let event_store = AssertUnwindSafe(&event_store);
+            let event = AssertUnwindSafe(&event);
+
+            let result = objc2::exception::catch(|| unsafe {
+                event_store.removeEvent_span_commit_error(&event, span, true)
+            });
+
+            match result {
+                Ok(Ok(())) => Ok(()),
+                Ok(Err(ns_error)) => {
+                    let error_msg = ns_error.localizedDescription().to_string();
+                    Err(Error::ObjectiveCException(error_msg))
+                }
+                Err(_) => Err(Error::XpcConnectionFailed),
+            }
+        };
+
+        delete
+            .retry(retry_backoff())
+            .when(|e| matches!(e, Error::XpcConnectionFailed))
+            .call()
+    }
+}
+
+fn to_ek_span(span: Option<&EventSpan>) -> EKSpan {
+    match span {
+        Some(EventSpan::FutureEvents) => EKSpan::FutureEvents,
+        Some(EventSpan::ThisEvent) | None => EKSpan::ThisEvent,
+    }
 }
@@ -0,0 +1,439 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use hypr_caldav::{CalDavClient, DavCalendar, FeedResponse, FeedValidators};
+use hypr_calendar_interface as interface;
+
+use crate::types::{
+    AppleCalendar, AppleEvent, CalDavAccount, CalDavConfig, CalendarColor, CalendarEntityType,
+    CalendarRef, CalendarSource, CalendarSourceType, CalendarType, EventAvailability, EventFilter,
+    EventStatus, Participant, ParticipantRole, ParticipantStatus, ParticipantType, RecurrenceInfo,
+    RecurrenceOccurrence,
+};
+
+/// Where EventKit isn't available, calendars come from a CalDAV account
+/// and ICS feeds instead. The config is kept in the app data dir and the
+/// account's app password in the OS keyring.
+pub struct State {
+    path: PathBuf,
+    /// Keyring service the password is stored under, the app identifier.
+    keyring_service: String,
+    config: Mutex<CalDavConfig>,
+    /// Calendar names from the last listing, by URL.
+    names: Mutex<HashMap<String, String>>,
+    /// The last fetch of each feed, by URL.
+    feeds: Mutex<HashMap<String, CachedFeed>>,
+}
+
+#[derive(Default)]
+struct CachedFeed {
+    validators: FeedValidators,
+    events: Vec<interface::CalendarEvent>,
+}
+
+impl State {
+    pub fn load(path: PathBuf, keyring_service: impl Into<String>) -> Self {
+        let config = std::fs::read_to_string(&path)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default();
+
+        Self {
+            path,
+            keyring_service: keyring_service.into(),
+            config: Mutex::new(config),
+            names: Mutex::default(),
+            feeds: Mutex::default(),
+        }
+    }
+
+    pub fn config(&self) -> CalDavConfig {
+        self.config.lock().unwrap().clone()
+    }
+
+    /// Checks the account can be discovered before saving it. Without a new
+    /// `password`, the one already stored for the account is used.
+    pub fn configure(&self, config: CalDavConfig, password: Option<String>) -> Result<(), String> {
+        let previous = self.config().account;
+
+        if let Some(account) = &config.account {
+            let password = match password {
+                Some(password) => password,
+                None => self.password(account)?,
+            };
+            let client = CalDavClient::new(&account.server_url, &account.username, &password)
+                .map_err(|e| e.to_string())?;
+            block_on(client.discover())?.map_err(|e| e.to_string())?;
+
+            self.keyring_entry(account)?
+                .set_password(&password)
+                .map_err(|e| e.to_string())?;
+        }
+
+        if let Some(previous) = previous.filter(|p| config.account.as_ref() != Some(p)) {
+            match self.keyring_entry(&previous)?.delete_credential() {
+                Ok(()) | Err(keyring::Error::NoEntry) => {}
+                Err(e) => return Err(e.to_string()),
+            }
+        }
+
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+        }
+        let content = serde_json::to_string_pretty(&config).map_err(|e| e.to_string())?;
+        std::fs::write(&self.path, content).map_err(|e| e.to_string())?;
+
+        *self.config.lock().unwrap() = config;
+        Ok(())
+    }
+
+    pub fn list_calendars(&self) -> Result<Vec<AppleCalendar>, String> {
+        let config = self.config();
+
+        let mut calendars = Vec::new();
+        if let Some(account) = &config.account {
+            let client = self.client(account)?;
+            let dav = block_on(client.list_calendars())?.map_err(|e| e.to_string())?;
+            calendars.extend(
+                dav.into_iter()
+                    .map(|calendar| from_dav(calendar, &account.server_url, &account.username)),
+            );
+        }
+        calendars.extend(config.feeds.iter().map(|url| from_feed(url)));
+
+        *self.names.lock().unwrap() = calendars
+            .iter()
+            .map(|c| (c.id.clone(), c.title.clone()))
+            .collect();
+        Ok(calendars)
+    }
+
+    pub fn list_events(&self, filter: EventFilter) -> Result<Vec<AppleEvent>, String> {
+        let config = self.config();
+        let calendar_id = filter.calendar_tracking_id.as_str();
+
+        let events = if config.feeds.iter().any(|url| url == calendar_id) {
+            block_on(self.feed_events(calendar_id))??
+        } else {
+            let account = config
+                .account
+                .as_ref()
+                .ok_or_else(|| crate::Error::CalendarNotFound.to_string())?;
+            let client = self.client(account)?;
+            let objects = block_on(client.list_objects(calendar_id, filter.from, filter.to))?
+                .map_err(|e| e.to_string())?;
+            let owner_email = Some(account.username.as_str()).filter(|u| u.contains('@'));
+            objects
+                .iter()
+                .flat_map(|object| object.events(calendar_id, owner_email))
+                .collect()
+        };
+
+        let title = self
+            .names
+            .lock()
+            .unwrap()
+            .get(calendar_id)
+            .cloned()
+            .unwrap_or_else(|| calendar_id.to_string());
+        Ok(interface::expand(events, filter.from, filter.to)
+            .into_iter()
+            .map(|event| to_apple_event(event, &title))
+            .collect())
+    }
+
+    /// Events of the feed at `url`. The validators of the last fetch are
+    /// sent along, and an unchanged feed keeps the events it had then.
+    async fn feed_events(&self, url: &str) -> Result<Vec<interface::CalendarEvent>, String> {
+        let validators = self
+            .feeds
+            .lock()
+            .unwrap()
+            .get(url)
+            .map(|feed| feed.validators.clone())
+            .unwrap_or_default();
+
+        // Each call runs on a runtime of its own (see `block_on`), so a pooled
+        // client would hold connections to runtimes that are gone.
+        let http = reqwest::Client::new();
+        let response = hypr_caldav::fetch_feed(&http, url, &validators)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let mut feeds = self.feeds.lock().unwrap();
+        match response {
+            FeedResponse::Updated { events, validators } => {
+                feeds.insert(
+                    url.to_string(),
+                    CachedFeed {
+                        validators,
+                        events: events.clone(),
+                    },
+                );
+                Ok(events)
+            }
+            FeedResponse::NotModified => Ok(feeds
+                .get(url)
+                .map(|feed| feed.events.clone())
+                .unwrap_or_default()),
+        }
+    }
+
+    fn client(&self, account: &CalDavAccount) -> Result<CalDavClient, String> {
+        let password = self.password(account)?;
+        CalDavClient::new(&account.server_url, &account.username, &password)
+            .map_err(|e| e.to_string())
+    }
+
+    fn password(&self, account: &CalDavAccount) -> Result<String, String> {
+        match self.keyring_entry(account)?.get_password() {
+            Ok(password) => Ok(password),
+            Err(keyring::Error::NoEntry) => {
+                Err(format!("no app password stored for {}", account.username))
+            }
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    fn keyring_entry(&self, account: &CalDavAccount) -> Result<keyring::Entry, String> {
+        let user = format!("{} {}", account.username, account.server_url);
+        keyring::Entry::new(&self.keyring_service, &user).map_err(|e| e.to_string())
+    }
+}
+
+/// Runs `future` on a runtime of its own thread. Commands are sync and may
+/// be called from the app's runtime, where blocking on it would panic.
+fn block_on<F>(future: F) -> Result<F::Output, String>
+where
+    F: Future + Send,
+    F::Output: Send,
+{
+    std::thread::scope(|scope| {
+        scope
+            .spawn(|| {
+                tokio::runtime::Builder::new_current_thread()
+                    .enable_all()
+                    .build()
+                    .map(|runtime| runtime.block_on(future))
+                    .map_err(|e| e.to_string())
+            })
+            .join()
+            .map_err(|_| "caldav request panicked".to_string())?
+    })
+}
+
+fn from_dav(calendar: DavCalendar, server_url: &str, username: &str) -> AppleCalendar {
+    AppleCalendar {
+        id: calendar.url,
+        title: calendar.name,
+        calendar_type: CalendarType::CalDav,
+        color: calendar.color.as_deref().and_then(parse_color),
+        allows_content_modifications: false,
+        is_immutable: false,
+        is_subscribed: false,
+        supported_event_availabilities: vec![],
+        allowed_entity_types: vec![CalendarEntityType::Event],
+        source: CalendarSource {
+            identifier: server_url.to_string(),
+            title: username.to_string(),
+            source_type: CalendarSourceType::CalDav,
+        },
+    }
+}
+
+fn from_feed(url: &str) -> AppleCalendar {
+    let title = url::Url::parse(url)
+        .ok()
+        .and_then(|u| u.host_str().map(str::to_string))
+        .unwrap_or_else(|| url.to_string());
+
+    AppleCalendar {
+        id: url.to_string(),
+        title: title.clone(),
+        calendar_type: CalendarType::Subscription,
+        color: None,
+        allows_content_modifications: false,
+        is_immutable: true,
+        is_subscribed: true,
+        supported_event_availabilities: vec![],
+        allowed_entity_types: vec![CalendarEntityType::Event],
+        source: CalendarSource {
+            identifier: url.to_string(),
+            title,
+            source_type: CalendarSourceType::Subscribed,
+        },
+    }
+}
+
+/// `#RRGGBB` or `#RRGGBBAA`, as Apple's `calendar-color` property.
+fn parse_color(value: &str) -> Option<CalendarColor> {
+    let hex = value.trim().strip_prefix('#')?;
+    let channel = |i: usize| -> Option<f32> {
+        let byte = u8::from_str_radix(hex.get(i..i + 2)?, 16).ok()?;
+        Some(byte as f32 / 255.0)
+    };
+    Some(CalendarColor {
+        red: channel(0)?,
+        green: channel(2)?,
+        blue: channel(4)?,
+        alpha: if hex.len() == 8 { channel(6)? } else { 1.0 },
+    })
+}
+
+/// The reverse of `From<AppleEvent>`: occurrences keep their series ID as
+/// `event_identifier` and carry their original start.
+fn to_apple_event(event: interface::CalendarEvent, calendar_title: &str) -> AppleEvent {
+    let recurrence = event.series_id.as_ref().map(|series_id| RecurrenceInfo {
+        series_identifier: series_id.clone(),
+        has_recurrence_rules: true,
+        occurrence: event
+            .original_start
+            .map(|original_start| RecurrenceOccurrence {
+                original_start,
+                is_detached: false,
+            }),
+        rules: vec![],
+    });
+    let identifier = event.series_id.clone().unwrap_or(event.id);
+
+    AppleEvent {
+        event_identifier: identifier.clone(),
+        calendar_item_identifier: identifier,
+        external_identifier: event.ical_uid.unwrap_or_default(),
+        calendar: CalendarRef {
+            id: event.calendar_id,
+            title: calendar_title.to_string(),
+        },
+        title: event.title,
+        location: event.location,
+        url: event.meeting_url,
+        has_notes: event.description.is_some(),
+        notes: event.description,
+        creation_date: None,
+        last_modified_date: event.updated_at,
+        time_zone: event.time_zone,
+        start_date: event.start,
+        end_date: event.end,
+        is_all_day: event.is_all_day,
+        availability: EventAvailability::NotSupported,
+        status: match event.status {
+            interface::EventStatus::Confirmed => EventStatus::Confirmed,
+            interface::EventStatus::Tentative => EventStatus::Tentative,
+            interface::EventStatus::Cancelled => EventStatus::Canceled,
+        },
+        has_alarms: false,
+        has_attendees: !event.attendees.is_empty(),
+        has_recurrence_rules: recurrence.is_some(),
+        organizer: event.organizer.as_ref().map(to_participant),
+        attendees: event.attendees.iter().map(to_participant).collect(),
+        structured_location: None,
+        occurrence_date: event.original_start,
+        recurrence,
+        is_detached: false,
+        alarms: vec![],
+        birthday_contact_identifier: None,
+        is_birthday: false,
+    }
+}
+
+fn to_participant(attendee: &interface::Attendee) -> Participant {
+    Participant {
+        name: attendee.name.clone(),
+        email: attendee.email.clone(),
+        is_current_user: attendee.is_self,
+        role: if attendee.is_organizer {
+            ParticipantRole::Chair
+        } else if attendee.optional {
+            ParticipantRole::Optional
+        } else {
+            ParticipantRole::Required
+        },
+        status: match attendee.response {
+            interface::ResponseStatus::NeedsAction => ParticipantStatus::Pending,
+            interface::ResponseStatus::Accepted => ParticipantStatus::Accepted,
+            interface::ResponseStatus::Tentative => ParticipantStatus::Tentative,
+            interface::ResponseStatus::Declined => ParticipantStatus::Declined,
+        },
+        participant_type: ParticipantType::Person,
+        schedule_status: None,
+        url: attendee
+            .email
+            .as_ref()
+            .map(|email| format!("mailto:{email}")),
+        contact: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{DateTime, Utc};
+    use wiremock::matchers::{header, method};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn utc(s: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc)
+    }
+
+    #[test]
+    fn test_parse_color() {
+        let color = parse_color("#FF000080").unwrap();
+        assert_eq!((color.red, color.green, color.blue), (1.0, 0.0, 0.0));
+        assert!((color.alpha - 128.0 / 255.0).abs() < f32::EPSILON);
+        assert_eq!(parse_color("#00ff00").map(|c| c.alpha), Some(1.0));
+        assert_eq!(parse_color("blue"), None);
+    }
+
+    #[test]
+    fn test_occurrence_round_trips() {
+        let ics = "BEGIN:VCALENDAR\nBEGIN:VEVENT\nUID:standup\nDTSTART:20260302T090000Z\nDTEND:20260302T091500Z\nRRULE:FREQ=DAILY;COUNT=3\nSUMMARY:Standup\nEND:VEVENT\nEND:VCALENDAR\n";
+        let object = hypr_caldav::CalendarObject {
+            href: "/standup.ics".into(),
+            etag: None,
+            data: ics.into(),
+        };
+        let from = utc("2026-03-03T00:00:00Z");
+        let to = utc("2026-03-04T00:00:00Z");
+
+        let expanded = interface::expand(object.events("work", None), from, to);
+        let apple = to_apple_event(expanded[0].clone(), "Work");
+        assert_eq!(apple.event_identifier, "standup");
+        assert_eq!(apple.calendar.title, "Work");
+
+        let back: interface::CalendarEvent = apple.into();
+        assert_eq!(back.id, expanded[0].id);
+        assert_eq!(back.start, utc("2026-03-03T09:00:00Z"));
+    }
+
+    #[tokio::test]
+    async fn test_unchanged_feed_keeps_cached_events() {
+        let feed = "BEGIN:VCALENDAR\nBEGIN:VEVENT\nUID:holiday-1\nDTSTART;VALUE=DATE:20261225\nSUMMARY:Christmas Day\nEND:VEVENT\nEND:VCALENDAR\n";
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(header("If-None-Match", "\"v1\""))
+            .respond_with(ResponseTemplate::new(304))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("ETag", "\"v1\"")
+                    .set_body_string(feed),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let state = State::load(std::env::temp_dir().join("caldav-missing.json"), "test");
+        let url = format!("{}/holidays.ics", server.uri());
+
+        let first = state.feed_events(&url).await.unwrap();
+        assert_eq!(first.len(), 1);
+        assert_eq!(first[0].title, "Christmas Day");
+
+        let second = state.feed_events(&url).await.unwrap();
+        assert_eq!(second, first);
+    }
+}
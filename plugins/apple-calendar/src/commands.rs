@@ -1,5 +1,8 @@
 use crate::AppleCalendarPluginExt;
-use crate::types::{AppleCalendar, AppleEvent, CreateEventInput, EventFilter};
+use crate::types::{
+    AppleCalendar, AppleEvent, CalDavConfig, CreateEventInput, DeleteEventInput, EventFilter,
+    RsvpEventInput, RsvpOutcome, UpdateEventInput,
+};
 
 #[tauri::command]
 #[specta::specta]
@@ -33,6 +36,51 @@ pub fn create_event<R: tauri::Runtime>(
     app.apple_calendar().create_event(input)
 }
 
+#[tauri::command]
+#[specta::specta]
+pub fn update_event<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    input: UpdateEventInput,
+) -> Result<(), String> {
+    app.apple_calendar().update_event(input)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn delete_event<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    input: DeleteEventInput,
+) -> Result<(), String> {
+    app.apple_calendar().delete_event(input)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn rsvp_event<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    input: RsvpEventInput,
+) -> Result<RsvpOutcome, String> {
+    app.apple_calendar().rsvp_event(input)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn caldav_config<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+) -> Result<Option<CalDavConfig>, String> {
+    Ok(app.apple_calendar().caldav_config())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn configure_caldav<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    config: CalDavConfig,
+    password: Option<String>,
+) -> Result<(), String> {
+    app.apple_calendar().configure_caldav(config, password)
+}
+
 #[cfg(feature = "fixture")]
 #[derive(serde::Serialize, specta::Type)]
 pub struct FixtureInfo {
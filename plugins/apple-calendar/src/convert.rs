@@ -0,0 +1,169 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use chrono_tz::Tz;
+use hypr_calendar_interface as interface;
+
+use crate::types::{AppleEvent, EventStatus, Participant, ParticipantRole, ParticipantStatus};
+
+/// EventKit hands out occurrences, never series, so converted events carry
+/// no recurrence lines. Occurrences share their `event_identifier`, so
+/// their IDs are derived from it and the occurrence date.
+impl From<AppleEvent> for interface::CalendarEvent {
+    fn from(event: AppleEvent) -> Self {
+        let (start, end) = if event.is_all_day {
+            let zone = event
+                .time_zone
+                .as_deref()
+                .and_then(|z| z.parse::<Tz>().ok());
+            (
+                floating_midnight(event.start_date, zone),
+                floating_midnight(event.end_date, zone),
+            )
+        } else {
+            (event.start_date, event.end_date)
+        };
+
+        let series_id = event
+            .recurrence
+            .as_ref()
+            .filter(|r| r.has_recurrence_rules)
+            .map(|r| r.series_identifier.clone());
+        let original_start = event
+            .recurrence
+            .as_ref()
+            .and_then(|r| r.occurrence.as_ref())
+            .map(|o| o.original_start)
+            .or(event.occurrence_date);
+        let id = match (&series_id, original_start) {
+            (Some(_), Some(original_start)) => {
+                interface::instance_id(&event.event_identifier, original_start, event.is_all_day)
+            }
+            _ => event.event_identifier.clone(),
+        };
+
+        let organizer = event.organizer.as_ref().map(|o| interface::Attendee {
+            is_organizer: true,
+            ..to_attendee(o)
+        });
+        let attendees: Vec<interface::Attendee> = event.attendees.iter().map(to_attendee).collect();
+        let self_response = attendees
+            .iter()
+            .chain(organizer.as_ref())
+            .find(|a| a.is_self)
+            .map(|a| a.response.clone())
+            .or_else(|| (!event.has_attendees).then_some(interface::ResponseStatus::Accepted));
+
+        let meeting_url = [
+            event.url.as_deref(),
+            event.location.as_deref(),
+            event.notes.as_deref(),
+        ]
+        .into_iter()
+        .flatten()
+        .find_map(hypr_meeting_link::parse_conference_link);
+
+        interface::CalendarEvent {
+            id,
+            calendar_id: event.calendar.id,
+            provider: interface::CalendarProvider::Apple,
+            ical_uid: Some(event.external_identifier).filter(|uid| !uid.is_empty()),
+            series_id,
+            original_start,
+            recurrence: vec![],
+            title: event.title,
+            description: event.notes,
+            location: event.location,
+            meeting_url,
+            start,
+            end,
+            is_all_day: event.is_all_day,
+            time_zone: event.time_zone,
+            status: match event.status {
+                EventStatus::Canceled => interface::EventStatus::Cancelled,
+                EventStatus::Tentative => interface::EventStatus::Tentative,
+                EventStatus::None | EventStatus::Confirmed => interface::EventStatus::Confirmed,
+            },
+            organizer,
+            attendees,
+            self_response,
+            updated_at: event.last_modified_date,
+        }
+    }
+}
+
+/// All-day dates start at local midnight in EventKit; floating ones are in
+/// the device's zone.
+fn floating_midnight(at: DateTime<Utc>, zone: Option<Tz>) -> DateTime<Utc> {
+    let date: NaiveDate = match zone {
+        Some(zone) => at.with_timezone(&zone).date_naive(),
+        None => at.with_timezone(&chrono::Local).date_naive(),
+    };
+    date.and_hms_opt(0, 0, 0).unwrap_or_default().and_utc()
+}
+
+fn to_attendee(participant: &Participant) -> interface::Attendee {
+    interface::Attendee {
+        email: participant.email.clone(),
+        name: participant.name.clone(),
+        response: match participant.status {
+            ParticipantStatus::Accepted => interface::ResponseStatus::Accepted,
+            ParticipantStatus::Tentative => interface::ResponseStatus::Tentative,
+            ParticipantStatus::Declined => interface::ResponseStatus::Declined,
+            _ => interface::ResponseStatus::NeedsAction,
+        },
+        is_self: participant.is_current_user,
+        is_organizer: matches!(participant.role, ParticipantRole::Chair),
+        optional: matches!(participant.role, ParticipantRole::Optional),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{RecurrenceInfo, RecurrenceOccurrence};
+
+    fn fixture_event() -> AppleEvent {
+        let events: Vec<AppleEvent> =
+            serde_json::from_str(include_str!("fixture/data/default/base/events.json")).unwrap();
+        events.into_iter().next().unwrap()
+    }
+
+    #[test]
+    fn test_single_event() {
+        let event: interface::CalendarEvent = fixture_event().into();
+        assert_eq!(event.id, "fixture-event-1");
+        assert_eq!(event.calendar_id, "fixture-calendar-1");
+        assert_eq!(event.ical_uid.as_deref(), Some("ext-fixture-1"));
+        assert_eq!(
+            event.self_response,
+            Some(interface::ResponseStatus::Accepted)
+        );
+        assert!(
+            event
+                .organizer
+                .is_some_and(|o| o.is_organizer && !o.is_self)
+        );
+    }
+
+    #[test]
+    fn test_occurrences_get_distinct_ids() {
+        let original_start: DateTime<Utc> = "2025-01-09T09:00:00Z".parse().unwrap();
+        let occurrence = AppleEvent {
+            recurrence: Some(RecurrenceInfo {
+                series_identifier: "series-1".into(),
+                has_recurrence_rules: true,
+                occurrence: Some(RecurrenceOccurrence {
+                    original_start,
+                    is_detached: false,
+                }),
+                rules: vec![],
+            }),
+            ..fixture_event()
+        };
+
+        let event: interface::CalendarEvent = occurrence.into();
+        assert_eq!(event.id, "fixture-event-1_20250109T090000Z");
+        assert_eq!(event.series_id.as_deref(), Some("series-1"));
+        assert_eq!(event.original_start, Some(original_start));
+        assert!(!event.is_series());
+    }
+}
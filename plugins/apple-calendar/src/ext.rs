@@ -1,4 +1,7 @@
-use crate::types::{AppleCalendar, AppleEvent, CreateEventInput, EventFilter};
+use crate::types::{
+    AppleCalendar, AppleEvent, CalDavConfig, CreateEventInput, DeleteEventInput, EventFilter,
+    RsvpEventInput, RsvpOutcome, UpdateEventInput,
+};
 
 pub struct AppleCalendarExt<'a, R: tauri::Runtime, M: tauri::Manager<R>> {
     #[allow(dead_code)]
@@ -27,6 +30,34 @@ impl<'a, R: tauri::Runtime, M: tauri::Manager<R>> AppleCalendarExt<'a, R, M> {
     pub fn create_event(&self, _input: CreateEventInput) -> Result<String, String> {
         Ok("fixture-event-created".to_string())
     }
+
+    #[tracing::instrument(skip_all)]
+    pub fn update_event(&self, _input: UpdateEventInput) -> Result<(), String> {
+        Ok(())
+    }
+
+    #[tracing::instrument(skip_all)]
+    pub fn delete_event(&self, _input: DeleteEventInput) -> Result<(), String> {
+        Ok(())
+    }
+
+    #[tracing::instrument(skip_all)]
+    pub fn rsvp_event(&self, _input: RsvpEventInput) -> Result<RsvpOutcome, String> {
+        Ok(RsvpOutcome::Unsupported)
+    }
+
+    pub fn caldav_config(&self) -> Option<CalDavConfig> {
+        None
+    }
+
+    #[tracing::instrument(skip_all)]
+    pub fn configure_caldav(
+        &self,
+        _config: CalDavConfig,
+        _password: Option<String>,
+    ) -> Result<(), String> {
+        Ok(())
+    }
 }
 
 #[cfg(all(target_os = "macos", not(feature = "fixture")))]
@@ -71,9 +102,95 @@ impl<'a, R: tauri::Runtime, M: tauri::Manager<R>> AppleCalendarExt<'a, R, M> {
         let handle = crate::apple::Handle;
         handle.create_event(input).map_err(|e| e.to_string())
     }
+
+    #[tracing::instrument(skip_all)]
+    pub fn update_event(&self, input: UpdateEventInput) -> Result<(), String> {
+        let handle = crate::apple::Handle;
+        handle.update_event(input).map_err(|e| e.to_string())
+    }
+
+    #[tracing::instrument(skip_all)]
+    pub fn delete_event(&self, input: DeleteEventInput) -> Result<(), String> {
+        let handle = crate::apple::Handle;
+        handle.delete_event(input).map_err(|e| e.to_string())
+    }
+
+    /// EventKit exposes participants read-only, so invitations can only be
+    /// answered in Calendar itself.
+    #[tracing::instrument(skip_all)]
+    pub fn rsvp_event(&self, _input: RsvpEventInput) -> Result<RsvpOutcome, String> {
+        Ok(RsvpOutcome::Unsupported)
+    }
+
+    pub fn caldav_config(&self) -> Option<CalDavConfig> {
+        None
+    }
+
+    #[tracing::instrument(skip_all)]
+    pub fn configure_caldav(
+        &self,
+        _config: CalDavConfig,
+        _password: Option<String>,
+    ) -> Result<(), String> {
+        Err("CalDAV is only used where EventKit isn't available".to_string())
+    }
 }
 
-#[cfg(all(not(target_os = "macos"), not(feature = "fixture")))]
+#[cfg(all(target_os = "linux", not(feature = "fixture")))]
+impl<'a, R: tauri::Runtime, M: tauri::Manager<R>> AppleCalendarExt<'a, R, M> {
+    fn caldav(&self) -> tauri::State<'_, crate::caldav::State> {
+        self.manager.state::<crate::caldav::State>()
+    }
+
+    pub fn open_calendar(&self) -> Result<(), String> {
+        Err("not supported on this platform".to_string())
+    }
+
+    #[tracing::instrument(skip_all)]
+    pub fn list_calendars(&self) -> Result<Vec<AppleCalendar>, String> {
+        self.caldav().list_calendars()
+    }
+
+    #[tracing::instrument(skip_all)]
+    pub fn list_events(&self, filter: EventFilter) -> Result<Vec<AppleEvent>, String> {
+        self.caldav().list_events(filter)
+    }
+
+    pub fn create_event(&self, _input: CreateEventInput) -> Result<String, String> {
+        Err("not supported on this platform".to_string())
+    }
+
+    pub fn update_event(&self, _input: UpdateEventInput) -> Result<(), String> {
+        Err("not supported on this platform".to_string())
+    }
+
+    pub fn delete_event(&self, _input: DeleteEventInput) -> Result<(), String> {
+        Err("not supported on this platform".to_string())
+    }
+
+    pub fn rsvp_event(&self, _input: RsvpEventInput) -> Result<RsvpOutcome, String> {
+        Ok(RsvpOutcome::Unsupported)
+    }
+
+    pub fn caldav_config(&self) -> Option<CalDavConfig> {
+        Some(self.caldav().config())
+    }
+
+    #[tracing::instrument(skip_all)]
+    pub fn configure_caldav(
+        &self,
+        config: CalDavConfig,
+        password: Option<String>,
+    ) -> Result<(), String> {
+        self.caldav().configure(config, password)
+    }
+}
+
+#[cfg(all(
+    not(target_os = "macos"),
+    not(target_os = "linux"),
+    not(feature = "fixture")
+))]
 impl<'a, R: tauri::Runtime, M: tauri::Manager<R>> AppleCalendarExt<'a, R, M> {
     pub fn open_calendar(&self) -> Result<(), String> {
         Err("not supported on this platform".to_string())
@@ -90,6 +207,30 @@ impl<'a, R: tauri::Runtime, M: tauri::Manager<R>> AppleCalendarExt<'a, R, M> {
     pub fn create_event(&self, _input: CreateEventInput) -> Result<String, String> {
         Err("not supported on this platform".to_string())
     }
+
+    pub fn update_event(&self, _input: UpdateEventInput) -> Result<(), String> {
+        Err("not supported on this platform".to_string())
+    }
+
+    pub fn delete_event(&self, _input: DeleteEventInput) -> Result<(), String> {
+        Err("not supported on this platform".to_string())
+    }
+
+    pub fn rsvp_event(&self, _input: RsvpEventInput) -> Result<RsvpOutcome, String> {
+        Ok(RsvpOutcome::Unsupported)
+    }
+
+    pub fn caldav_config(&self) -> Option<CalDavConfig> {
+        None
+    }
+
+    pub fn configure_caldav(
+        &self,
+        _config: CalDavConfig,
+        _password: Option<String>,
+    ) -> Result<(), String> {
+        Err("not supported on this platform".to_string())
+    }
 }
 
 pub trait AppleCalendarPluginExt<R: tauri::Runtime> {
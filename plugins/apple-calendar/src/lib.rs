@@ -1,10 +1,13 @@
 #[cfg(target_os = "macos")]
 mod apple;
+#[cfg(all(target_os = "linux", not(feature = "fixture")))]
+mod caldav;
 
 #[cfg(feature = "fixture")]
 pub mod fixture;
 
 mod commands;
+mod convert;
 mod error;
 mod events;
 mod ext;
@@ -26,6 +29,11 @@ fn make_specta_builder<R: tauri::Runtime>() -> tauri_specta::Builder<R> {
             commands::list_calendars::<tauri::Wry>,
             commands::list_events::<tauri::Wry>,
             commands::create_event::<tauri::Wry>,
+            commands::update_event::<tauri::Wry>,
+            commands::delete_event::<tauri::Wry>,
+            commands::rsvp_event::<tauri::Wry>,
+            commands::caldav_config::<tauri::Wry>,
+            commands::configure_caldav::<tauri::Wry>,
         ])
         .events(tauri_specta::collect_events![CalendarChangedEvent])
         .error_handling(tauri_specta::ErrorHandlingMode::Result)
@@ -40,6 +48,11 @@ fn make_specta_builder<R: tauri::Runtime>() -> tauri_specta::Builder<R> {
             commands::list_calendars::<tauri::Wry>,
             commands::list_events::<tauri::Wry>,
             commands::create_event::<tauri::Wry>,
+            commands::update_event::<tauri::Wry>,
+            commands::delete_event::<tauri::Wry>,
+            commands::rsvp_event::<tauri::Wry>,
+            commands::caldav_config::<tauri::Wry>,
+            commands::configure_caldav::<tauri::Wry>,
             commands::advance_fixture,
             commands::reset_fixture,
             commands::get_fixture_info,
@@ -56,6 +69,14 @@ pub fn init<R: tauri::Runtime>() -> tauri::plugin::TauriPlugin<R> {
         .setup(move |app, _api| {
             specta_builder.mount_events(app);
 
+            #[cfg(all(target_os = "linux", not(feature = "fixture")))]
+            {
+                use tauri::Manager;
+                let path = app.path().app_data_dir()?.join("caldav.json");
+                let keyring_service = app.config().identifier.clone();
+                app.manage(caldav::State::load(path, keyring_service));
+            }
+
             #[cfg(all(target_os = "macos", not(feature = "fixture")))]
             {
                 use tauri::Manager;
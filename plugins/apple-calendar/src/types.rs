@@ -21,6 +21,66 @@ pub struct CreateEventInput {
     pub url: Option<String>,
 }
 
+/// Which occurrences of a recurring event a change applies to.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Type)]
+pub enum EventSpan {
+    ThisEvent,
+    FutureEvents,
+}
+
+/// Fields left as `None` keep their current value.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Type)]
+pub struct UpdateEventInput {
+    pub event_id: String,
+    /// Picks an occurrence of a recurring event; without it, the first one.
+    pub occurrence_date: Option<DateTime<Utc>>,
+    pub span: Option<EventSpan>,
+    pub title: Option<String>,
+    pub start_date: Option<DateTime<Utc>>,
+    pub end_date: Option<DateTime<Utc>>,
+    pub is_all_day: Option<bool>,
+    pub location: Option<String>,
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Type)]
+pub struct DeleteEventInput {
+    pub event_id: String,
+    pub occurrence_date: Option<DateTime<Utc>>,
+    pub span: Option<EventSpan>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Type)]
+pub struct RsvpEventInput {
+    pub event_id: String,
+    pub occurrence_date: Option<DateTime<Utc>>,
+    pub response: ParticipantStatus,
+}
+
+/// What became of an RSVP. EventKit can't answer invitations, so on
+/// `Unsupported` the UI should send the user to Calendar instead.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Type)]
+pub enum RsvpOutcome {
+    Sent,
+    Unsupported,
+}
+
+/// A CalDAV account, authenticated with an app password. The password is
+/// kept in the OS keyring and never leaves the backend.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Type)]
+pub struct CalDavAccount {
+    pub server_url: String,
+    pub username: String,
+}
+
+/// Calendar sources used on Linux, where EventKit isn't available.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, Type)]
+pub struct CalDavConfig {
+    pub account: Option<CalDavAccount>,
+    /// Read-only ICS subscriptions, `https://` or `webcal://`.
+    pub feeds: Vec<String>,
+}
+
 macro_rules! common_derives {
     ($item:item) => {
         #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Type, schemars::JsonSchema)]
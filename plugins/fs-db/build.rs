@@ -5,6 +5,8 @@ const COMMANDS: &[&str] = &[
     "save_session_content",
     "save_session_transcript",
     "save_session_enhanced_note",
+    "fsck",
+    "draft_followup",
 ];
 
 fn main() {
@@ -53,6 +53,22 @@ async saveSessionEnhancedNote(sessionId: string, note: EnhancedNoteData, filenam
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
+},
+async fsck(apply: boolean) : Promise<Result<FsckReport, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:fs-db|fsck", { apply }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async draftFollowup(sessionId: string) : Promise<Result<string, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:fs-db|draft_followup", { sessionId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
 }
 }
 
@@ -67,6 +83,21 @@ async saveSessionEnhancedNote(sessionId: string, note: EnhancedNoteData, filenam
 /** user-defined types **/
 
 export type EnhancedNoteData = { id: string; sessionId: string; templateId?: string | null; position: number; title?: string | null; content: string }
+export type FsckIssue = { kind: FsckIssueKind; sessionId: string; 
+/**
+ * Relative to the vault base, with `/` separators.
+ */
+path: string; message: string; 
+/**
+ * What repairing would change. `None` when the issue needs a human.
+ */
+fix: string | null; 
+/**
+ * Unified diff of the file repairing would rewrite.
+ */
+diff: string | null; fixed: boolean }
+export type FsckIssueKind = "invalid_meta" | "invalid_transcript" | "transcript_word_order" | "session_id_mismatch" | "orphan_attachments" | "duplicate_session"
+export type FsckReport = { sessionsScanned: number; issues: FsckIssue[] }
 export type JsonValue = null | boolean | number | string | JsonValue[] | Partial<{ [key in string]: JsonValue }>
 export type SessionContent = { rawMd: string | null }
 export type SessionEnhancedNotes = { notes: EnhancedNoteData[] }
@@ -1,4 +1,5 @@
 use crate::FsDbPluginExt;
+use crate::fsck::FsckReport;
 use crate::types::{
     EnhancedNoteData, SessionContent, SessionEnhancedNotes, SessionTranscript, TranscriptData,
 };
@@ -78,3 +79,25 @@ pub(crate) async fn save_session_enhanced_note<R: tauri::Runtime>(
         .await
         .map_err(|e| e.to_string())
 }
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn fsck<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    apply: bool,
+) -> Result<FsckReport, String> {
+    app.fs_db().fsck(apply).await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn draft_followup<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    session_id: String,
+) -> Result<String, String> {
+    app.fs_db()
+        .draft_followup(&session_id)
+        .await
+        .map(|path| path.to_string_lossy().to_string())
+        .map_err(|e| e.to_string())
+}
@@ -14,6 +14,12 @@ pub enum Error {
     Json(#[from] serde_json::Error),
     #[error(transparent)]
     Settings(#[from] tauri_plugin_settings::Error),
+    #[error(transparent)]
+    Join(#[from] tokio::task::JoinError),
+    #[error(transparent)]
+    Email(#[from] hypr_email::Error),
+    #[error("add an email address to your profile to draft follow-ups")]
+    MissingSenderEmail,
     #[error("tiptap: {0}")]
     Tiptap(String),
 }
@@ -199,6 +199,24 @@ impl<'a, R: tauri::Runtime, M: tauri::Manager<R>> FsDb<'a, R, M> {
 
         Ok(())
     }
+
+    /// Checks every session in the vault. Nothing is written unless `apply`
+    /// is set.
+    pub async fn fsck(&self, apply: bool) -> crate::Result<crate::fsck::FsckReport> {
+        let base = self.manager.app_handle().settings().cached_vault_base()?;
+        tokio::task::spawn_blocking(move || crate::fsck::run(base.as_std_path(), apply)).await?
+    }
+
+    /// Writes a follow-up recap of the session as an `.eml` draft and
+    /// returns its path.
+    pub async fn draft_followup(&self, session_id: &str) -> crate::Result<PathBuf> {
+        let base = self.manager.app_handle().settings().cached_vault_base()?;
+        let session_dir = self.resolve_session_dir(session_id)?;
+        tokio::task::spawn_blocking(move || {
+            crate::followup::write_draft(base.as_std_path(), &session_dir)
+        })
+        .await?
+    }
 }
 
 pub trait FsDbPluginExt<R: tauri::Runtime> {
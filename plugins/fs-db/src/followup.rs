@@ -0,0 +1,598 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use hypr_email::{Followup, FollowupParticipant, Mailbox};
+use hypr_frontmatter::Document;
+use hypr_template_app as template;
+use serde::Deserialize;
+use serde_json::{Map, Value};
+
+use crate::types::files;
+use crate::{Error, Result};
+
+const DRAFT_FILE: &str = "followup.eml";
+
+/// Transcript channels: the user's own mic, and everyone else.
+const DIRECT_MIC: i64 = 0;
+const REMOTE_PARTY: i64 = 1;
+
+#[derive(Debug, Deserialize)]
+struct SessionMeta {
+    #[serde(default)]
+    user_id: String,
+    #[serde(default)]
+    title: String,
+    #[serde(default)]
+    created_at: String,
+    #[serde(default)]
+    event: Option<SessionEvent>,
+    #[serde(default)]
+    participants: Vec<SessionParticipant>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SessionEvent {
+    #[serde(default)]
+    title: String,
+    started_at: Option<String>,
+    ended_at: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SessionParticipant {
+    human_id: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct TranscriptFile {
+    #[serde(default)]
+    transcripts: Vec<TranscriptEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TranscriptEntry {
+    started_at: Option<f64>,
+    ended_at: Option<f64>,
+    #[serde(default)]
+    words: Vec<TranscriptWord>,
+    #[serde(default)]
+    speaker_hints: Vec<SpeakerHint>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TranscriptWord {
+    #[serde(default)]
+    id: Option<String>,
+    text: String,
+    #[serde(default)]
+    start_ms: f64,
+    #[serde(default)]
+    channel: i64,
+}
+
+#[derive(Debug, Deserialize)]
+struct SpeakerHint {
+    word_id: String,
+    #[serde(rename = "type")]
+    hint_type: String,
+    /// An object, or the same object as a JSON string in older files.
+    #[serde(default)]
+    value: Value,
+}
+
+/// Who said a word, as far as the speaker hints tell.
+#[derive(Debug, Default)]
+struct WordSpeaker {
+    speaker_index: Option<i64>,
+    human_id: Option<String>,
+}
+
+/// Writes the recap of the session in `session_dir` as an `.eml` draft
+/// inside it. Recipients are the session's participants, looked up in
+/// `humans`; the sender is the user's own human.
+pub fn write_draft(base_dir: &Path, session_dir: &Path) -> Result<PathBuf> {
+    let (from, followup) = load(base_dir, session_dir)?;
+    let message = hypr_email::followup_message(from, followup)?;
+
+    let path = session_dir.join(DRAFT_FILE);
+    message.write_draft(&path)?;
+    Ok(path)
+}
+
+fn load(base_dir: &Path, session_dir: &Path) -> Result<(Mailbox, Followup)> {
+    let meta: SessionMeta =
+        serde_json::from_str(&std::fs::read_to_string(session_dir.join(files::META))?)?;
+
+    let from = load_human(base_dir, &meta.user_id)?
+        .and_then(|human| Some(Mailbox::new(&human.email).ok()?.with_name(human.name)))
+        .ok_or(Error::MissingSenderEmail)?;
+
+    let mut participants = Vec::new();
+    for participant in &meta.participants {
+        if let Some(human) = load_human(base_dir, &participant.human_id)? {
+            participants.push(human);
+        }
+    }
+
+    let title = Some(meta.title.trim().to_string()).filter(|t| !t.is_empty());
+    let session = match meta.event {
+        Some(event) => template::Session {
+            title,
+            started_at: event.started_at.or(Some(meta.created_at)),
+            ended_at: event.ended_at,
+            event: Some(template::Event { name: event.title }),
+        },
+        None => template::Session {
+            title,
+            started_at: Some(meta.created_at),
+            ended_at: None,
+            event: None,
+        },
+    };
+
+    let followup = Followup {
+        session,
+        participants,
+        enhanced_note: load_enhanced_note(session_dir)?,
+        action_items: load_action_items(session_dir)?,
+        transcript: load_transcript(base_dir, session_dir, &meta.user_id)?,
+    };
+
+    Ok((from, followup))
+}
+
+/// Contents of `session_dir/name`, or `None` when the file doesn't exist.
+fn read_optional(session_dir: &Path, name: &str) -> Result<Option<String>> {
+    match std::fs::read_to_string(session_dir.join(name)) {
+        Ok(content) => Ok(Some(content)),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Action items from the session's extraction, if one was run.
+fn load_action_items(session_dir: &Path) -> Result<Vec<template::ActionItem>> {
+    let Some(content) = read_optional(session_dir, files::EXTRACTION)? else {
+        return Ok(vec![]);
+    };
+    let extraction: template::Extraction = serde_json::from_str(&content)?;
+    Ok(extraction.action_items)
+}
+
+/// Every transcript of the session merged in time order, with consecutive
+/// words from the same speaker joined into one segment. Speakers are named
+/// like the transcript view does: after the human their hints assign them
+/// to, the user for unhinted mic words, then by speaker index or channel.
+fn load_transcript(
+    base_dir: &Path,
+    session_dir: &Path,
+    user_id: &str,
+) -> Result<Option<template::Transcript>> {
+    let Some(content) = read_optional(session_dir, files::TRANSCRIPT)? else {
+        return Ok(None);
+    };
+    let file: TranscriptFile = serde_json::from_str(&content)?;
+
+    let started_at = file
+        .transcripts
+        .iter()
+        .filter_map(|t| t.started_at)
+        .reduce(f64::min);
+    let ended_at = file
+        .transcripts
+        .iter()
+        .filter_map(|t| t.ended_at)
+        .reduce(f64::max);
+
+    let hints = word_speakers(file.transcripts.iter().flat_map(|t| &t.speaker_hints));
+    // A speaker index assigned to a human once is that human everywhere.
+    let human_by_index: HashMap<i64, String> = hints
+        .values()
+        .filter_map(|s| Some((s.speaker_index?, s.human_id.clone()?)))
+        .collect();
+
+    let mut words: Vec<TranscriptWord> =
+        file.transcripts.into_iter().flat_map(|t| t.words).collect();
+    words.sort_by(|a, b| a.start_ms.total_cmp(&b.start_ms));
+
+    let mut names: HashMap<String, Option<String>> = HashMap::new();
+    let mut segments: Vec<template::Segment> = Vec::new();
+    for word in words {
+        let text = word.text.trim();
+        if text.is_empty() {
+            continue;
+        }
+
+        let hint = word.id.as_ref().and_then(|id| hints.get(id));
+        let human_id = match hint {
+            Some(h) => h
+                .human_id
+                .clone()
+                .or_else(|| human_by_index.get(&h.speaker_index?).cloned()),
+            None if word.channel == DIRECT_MIC => Some(user_id.to_string()),
+            None => None,
+        };
+        let name = match human_id {
+            Some(human_id) => match names.get(&human_id) {
+                Some(name) => name.clone(),
+                None => {
+                    let name = load_human(base_dir, &human_id)?
+                        .map(|human| human.name)
+                        .filter(|name| !name.trim().is_empty());
+                    names.insert(human_id, name.clone());
+                    name
+                }
+            },
+            None => None,
+        };
+        let speaker = name
+            .or_else(|| {
+                hint.and_then(|h| h.speaker_index)
+                    .map(|index| format!("Speaker {}", index + 1))
+            })
+            .unwrap_or_else(|| match word.channel {
+                DIRECT_MIC => "Speaker A".to_string(),
+                REMOTE_PARTY => "Speaker B".to_string(),
+                _ => "Speaker C".to_string(),
+            });
+
+        match segments.last_mut() {
+            Some(segment) if segment.speaker == speaker => {
+                segment.text.push(' ');
+                segment.text.push_str(text);
+            }
+            _ => segments.push(template::Segment {
+                text: text.to_string(),
+                speaker,
+            }),
+        }
+    }
+
+    Ok(Some(template::Transcript {
+        segments,
+        started_at: started_at.map(|v| v as u64),
+        ended_at: ended_at.map(|v| v as u64),
+    }))
+}
+
+/// Speaker index and assigned human of each hinted word, by word id.
+fn word_speakers<'a>(hints: impl Iterator<Item = &'a SpeakerHint>) -> HashMap<String, WordSpeaker> {
+    let mut speakers: HashMap<String, WordSpeaker> = HashMap::new();
+    for hint in hints {
+        let value = match &hint.value {
+            Value::String(raw) => serde_json::from_str(raw).unwrap_or(Value::Null),
+            value => value.clone(),
+        };
+        let speaker = speakers.entry(hint.word_id.clone()).or_default();
+        match hint.hint_type.as_str() {
+            "provider_speaker_index" => {
+                speaker.speaker_index = value.get("speaker_index").and_then(Value::as_i64);
+            }
+            "user_speaker_assignment" => {
+                speaker.human_id = value
+                    .get("human_id")
+                    .and_then(Value::as_str)
+                    .map(str::to_string);
+            }
+            _ => {}
+        }
+    }
+    speakers
+}
+
+/// Name and first email of `humans/<id>.md`. A missing file is `None`, since
+/// participants can outlive the human they point to.
+fn load_human(base_dir: &Path, human_id: &str) -> Result<Option<FollowupParticipant>> {
+    if human_id.is_empty() {
+        return Ok(None);
+    }
+
+    let path = base_dir.join("humans").join(format!("{human_id}.md"));
+    let content = match std::fs::read_to_string(&path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(e.into()),
+    };
+    let doc = Document::<Map<String, Value>>::from_str(&content)?;
+
+    // Older files have a comma-separated `email` instead of `emails`.
+    let email = match doc.frontmatter.get("emails") {
+        Some(Value::Array(emails)) => emails.iter().filter_map(Value::as_str).next(),
+        _ => doc
+            .frontmatter
+            .get("email")
+            .and_then(Value::as_str)
+            .and_then(|e| e.split(',').next()),
+    };
+
+    Ok(Some(FollowupParticipant {
+        name: doc
+            .frontmatter
+            .get("name")
+            .and_then(Value::as_str)
+            .unwrap_or_default()
+            .to_string(),
+        email: email.unwrap_or_default().trim().to_string(),
+    }))
+}
+
+/// The first enhanced note by position, or the memo when there is none.
+fn load_enhanced_note(session_dir: &Path) -> Result<String> {
+    let mut notes = Vec::new();
+    for entry in std::fs::read_dir(session_dir)? {
+        let path = entry?.path();
+        let is_note = path.extension().is_some_and(|ext| ext == "md")
+            && path.file_name().is_some_and(|name| name != files::MEMO);
+        if !is_note {
+            continue;
+        }
+
+        let Ok(doc) = Document::<Map<String, Value>>::from_str(&std::fs::read_to_string(&path)?)
+        else {
+            continue;
+        };
+        let position = doc
+            .frontmatter
+            .get("position")
+            .and_then(Value::as_i64)
+            .unwrap_or(i64::MAX);
+        notes.push((position, doc.content));
+    }
+
+    if let Some((_, content)) = notes.into_iter().min_by_key(|(position, _)| *position) {
+        return Ok(content);
+    }
+
+    match std::fs::read_to_string(session_dir.join(files::MEMO)) {
+        Ok(content) => Ok(Document::<Map<String, Value>>::from_str(&content)
+            .map(|doc| doc.content)
+            .unwrap_or(content)),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(String::new()),
+        Err(e) => Err(e.into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    const SESSION_ID: &str = "550e8400-e29b-41d4-a716-446655440000";
+
+    fn write(root: &Path, path: &str, content: &str) {
+        let path = root.join(path);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, content).unwrap();
+    }
+
+    fn write_session(base: &Path) -> PathBuf {
+        let meta = serde_json::json!({
+            "id": SESSION_ID,
+            "user_id": "user",
+            "created_at": "2026-03-02T10:00:00Z",
+            "title": "Acme onboarding",
+            "participants": [
+                { "id": "p1", "human_id": "user", "session_id": SESSION_ID },
+                { "id": "p2", "human_id": "dana", "session_id": SESSION_ID },
+                { "id": "p3", "human_id": "sam", "session_id": SESSION_ID },
+                { "id": "p4", "human_id": "deleted", "session_id": SESSION_ID },
+            ],
+        });
+        write(
+            base,
+            &format!("sessions/{SESSION_ID}/_meta.json"),
+            &meta.to_string(),
+        );
+        write(
+            base,
+            &format!("sessions/{SESSION_ID}/Summary.md"),
+            &format!(
+                "---\nid: n1\nsession_id: {SESSION_ID}\nposition: 1\n---\n\n## Summary\n\n- Kickoff"
+            ),
+        );
+        write(
+            base,
+            "humans/dana.md",
+            "---\nname: Dana Lee\nemails:\n  - dana@acme.com\n  - dana@home.com\n---\n",
+        );
+        write(base, "humans/sam.md", "---\nname: Sam\nemails: []\n---\n");
+
+        base.join(format!("sessions/{SESSION_ID}"))
+    }
+
+    #[test]
+    fn test_write_draft() {
+        let temp = tempdir().unwrap();
+        let base = temp.path();
+        let session_dir = write_session(base);
+        write(
+            base,
+            "humans/user.md",
+            "---\nname: Jordan\nemails:\n  - jordan@example.com\n---\n",
+        );
+
+        let path = write_draft(base, &session_dir).unwrap();
+        assert_eq!(path, session_dir.join(DRAFT_FILE));
+
+        let eml = std::fs::read_to_string(path).unwrap();
+        assert!(eml.starts_with("X-Unsent: 1\r\n"));
+        assert!(eml.contains("From: Jordan <jordan@example.com>\r\n"));
+        assert!(eml.contains("To: \"Dana Lee\" <dana@acme.com>\r\n"));
+        assert!(eml.contains("Subject: Recap: Acme onboarding\r\n"));
+        assert!(eml.contains("- Kickoff"));
+    }
+
+    #[test]
+    fn test_loads_action_items_and_transcript() {
+        let temp = tempdir().unwrap();
+        let base = temp.path();
+        let session_dir = write_session(base);
+        write(
+            base,
+            "humans/user.md",
+            "---\nname: Jordan\nemails:\n  - jordan@example.com\n---\n",
+        );
+        write(
+            base,
+            &format!("sessions/{SESSION_ID}/extraction.json"),
+            &serde_json::json!({
+                "actionItems": [{
+                    "text": "Send the contract",
+                    "assignee": { "name": "Dana Lee", "participant": true },
+                    "dueDate": "2026-03-06",
+                    "dueText": "by Friday",
+                    "sourceMs": 1200,
+                }],
+                "decisions": [],
+                "openQuestions": [],
+            })
+            .to_string(),
+        );
+        let word = |id: &str, text: &str, start_ms: i64, channel: i64| {
+            serde_json::json!({
+                "id": id,
+                "text": text,
+                "start_ms": start_ms,
+                "end_ms": start_ms + 100,
+                "channel": channel,
+            })
+        };
+        write(
+            base,
+            &format!("sessions/{SESSION_ID}/transcript.json"),
+            &serde_json::json!({
+                "transcripts": [
+                    {
+                        "id": "t2",
+                        "session_id": SESSION_ID,
+                        "started_at": 5000,
+                        "ended_at": 6000,
+                        "words": [word("w4", "Sounds", 5000, 0), word("w5", "good", 5100, 0)],
+                        "speaker_hints": [],
+                    },
+                    {
+                        "id": "t1",
+                        "session_id": SESSION_ID,
+                        "started_at": 1000,
+                        "ended_at": 2000,
+                        "words": [
+                            word("w1", "Hello", 1000, 0),
+                            word("w2", "Hi", 1500, 1),
+                            word("w3", "Dana", 1600, 1),
+                        ],
+                        "speaker_hints": [],
+                    },
+                ],
+            })
+            .to_string(),
+        );
+
+        let (_, followup) = load(base, &session_dir).unwrap();
+
+        assert_eq!(followup.action_items.len(), 1);
+        assert_eq!(followup.action_items[0].text, "Send the contract");
+        assert_eq!(
+            followup.action_items[0].due_date.as_deref(),
+            Some("2026-03-06")
+        );
+
+        let transcript = followup.transcript.unwrap();
+        let segments: Vec<(&str, &str)> = transcript
+            .segments
+            .iter()
+            .map(|s| (s.speaker.as_str(), s.text.as_str()))
+            .collect();
+        assert_eq!(
+            segments,
+            [
+                ("Jordan", "Hello"),
+                ("Speaker B", "Hi Dana"),
+                ("Jordan", "Sounds good"),
+            ]
+        );
+        assert_eq!(transcript.started_at, Some(1000));
+        assert_eq!(transcript.ended_at, Some(6000));
+    }
+
+    #[test]
+    fn test_names_speakers_from_hints() {
+        let temp = tempdir().unwrap();
+        let base = temp.path();
+        let session_dir = write_session(base);
+        let word = |id: &str, text: &str, start_ms: i64| {
+            serde_json::json!({
+                "id": id,
+                "text": text,
+                "start_ms": start_ms,
+                "end_ms": start_ms + 100,
+                "channel": 0,
+            })
+        };
+        let hint = |word_id: &str, hint_type: &str, value: serde_json::Value| {
+            serde_json::json!({
+                "id": format!("h-{word_id}-{hint_type}"),
+                "word_id": word_id,
+                "type": hint_type,
+                "value": value,
+            })
+        };
+        write(
+            base,
+            &format!("sessions/{SESSION_ID}/transcript.json"),
+            &serde_json::json!({
+                "transcripts": [{
+                    "id": "t1",
+                    "session_id": SESSION_ID,
+                    "started_at": 0,
+                    "words": [
+                        word("w1", "Welcome", 0),
+                        word("w2", "Thanks", 500),
+                        word("w3", "Next", 1000),
+                        word("w4", "Sure", 1500),
+                        word("w5", "Bye", 2000),
+                    ],
+                    "speaker_hints": [
+                        hint("w2", "provider_speaker_index", serde_json::json!({ "speaker_index": 1 })),
+                        hint("w2", "user_speaker_assignment", serde_json::json!({ "human_id": "dana" })),
+                        hint("w3", "provider_speaker_index", serde_json::json!({ "speaker_index": 0 })),
+                        hint("w4", "provider_speaker_index", serde_json::json!("{\"speaker_index\":1}")),
+                    ],
+                }],
+            })
+            .to_string(),
+        );
+
+        let transcript = load_transcript(base, &session_dir, "user")
+            .unwrap()
+            .unwrap();
+        let segments: Vec<(&str, &str)> = transcript
+            .segments
+            .iter()
+            .map(|s| (s.speaker.as_str(), s.text.as_str()))
+            .collect();
+        assert_eq!(
+            segments,
+            [
+                ("Speaker A", "Welcome"),
+                ("Dana Lee", "Thanks"),
+                ("Speaker 1", "Next"),
+                ("Dana Lee", "Sure"),
+                ("Speaker A", "Bye"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_missing_sender_email() {
+        let temp = tempdir().unwrap();
+        let base = temp.path();
+        let session_dir = write_session(base);
+        write(base, "humans/user.md", "---\nname: Jordan\n---\n");
+
+        assert!(matches!(
+            write_draft(base, &session_dir),
+            Err(Error::MissingSenderEmail)
+        ));
+    }
+}
@@ -0,0 +1,653 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use hypr_frontmatter::Document;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use specta::Type;
+
+use crate::Result;
+
+mod files {
+    pub const META: &str = "_meta.json";
+    pub const MEMO: &str = "_memo.md";
+    pub const TRANSCRIPT: &str = "transcript.json";
+    pub const ATTACHMENTS: &str = "attachments";
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct FsckReport {
+    pub sessions_scanned: u32,
+    pub issues: Vec<FsckIssue>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct FsckIssue {
+    pub kind: FsckIssueKind,
+    pub session_id: String,
+    /// Relative to the vault base, with `/` separators.
+    pub path: String,
+    pub message: String,
+    /// What repairing would change. `None` when the issue needs a human.
+    pub fix: Option<String>,
+    /// Unified diff of the file repairing would rewrite.
+    pub diff: Option<String>,
+    pub fixed: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum FsckIssueKind {
+    InvalidMeta,
+    InvalidTranscript,
+    TranscriptWordOrder,
+    SessionIdMismatch,
+    OrphanAttachments,
+    DuplicateSession,
+}
+
+enum FsckOp {
+    Write {
+        path: PathBuf,
+        content: String,
+    },
+    /// Removes the session's `attachments` dir, then the session dir itself
+    /// only if nothing else (e.g. audio) is left in it.
+    RemoveAttachments {
+        session_dir: PathBuf,
+    },
+}
+
+/// Scans every session directory under `base_dir/sessions`. With `apply`,
+/// fixable issues are repaired in place and marked `fixed`; otherwise the
+/// report only describes what would change.
+pub fn run(base_dir: &Path, apply: bool) -> Result<FsckReport> {
+    let mut session_dirs = Vec::new();
+    collect_session_dirs(&base_dir.join("sessions"), &mut session_dirs)?;
+    session_dirs.sort();
+
+    let mut found: Vec<(FsckIssue, Option<FsckOp>)> = Vec::new();
+    let mut seen: BTreeMap<String, PathBuf> = BTreeMap::new();
+
+    for dir in &session_dirs {
+        let session_id = dir_name(dir);
+
+        if let Some(first) = seen.get(&session_id) {
+            found.push((
+                issue(
+                    FsckIssueKind::DuplicateSession,
+                    &session_id,
+                    base_dir,
+                    dir,
+                    format!("session also exists at {}", relative_path(base_dir, first)),
+                    None,
+                ),
+                None,
+            ));
+            continue;
+        }
+        seen.insert(session_id.clone(), dir.clone());
+
+        check_session(base_dir, dir, &session_id, &mut found)?;
+    }
+
+    let mut issues = Vec::with_capacity(found.len());
+    for (mut issue, op) in found {
+        if apply && let Some(op) = op {
+            apply_op(op)?;
+            issue.fixed = true;
+        }
+        issues.push(issue);
+    }
+
+    Ok(FsckReport {
+        sessions_scanned: seen.len() as u32,
+        issues,
+    })
+}
+
+// Session directories are UUID-named; anything else under `sessions` is a
+// user folder that may contain more sessions.
+fn collect_session_dirs(dir: &Path, out: &mut Vec<PathBuf>) -> Result<()> {
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+        Err(e) => return Err(e.into()),
+    };
+
+    for entry in entries {
+        let path = entry?.path();
+        if !path.is_dir() {
+            continue;
+        }
+
+        let name = dir_name(&path);
+        if name.starts_with('.') {
+            continue;
+        }
+
+        if uuid::Uuid::try_parse(&name).is_ok() {
+            out.push(path);
+        } else {
+            collect_session_dirs(&path, out)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn check_session(
+    base_dir: &Path,
+    dir: &Path,
+    session_id: &str,
+    found: &mut Vec<(FsckIssue, Option<FsckOp>)>,
+) -> Result<()> {
+    let meta_path = dir.join(files::META);
+    let transcript_path = dir.join(files::TRANSCRIPT);
+
+    if meta_path.exists() {
+        let content = std::fs::read_to_string(&meta_path)?;
+        match serde_json::from_str::<Value>(&content) {
+            Ok(Value::Object(_)) => {}
+            Ok(_) => found.push((
+                issue(
+                    FsckIssueKind::InvalidMeta,
+                    session_id,
+                    base_dir,
+                    &meta_path,
+                    "expected a JSON object".to_string(),
+                    None,
+                ),
+                None,
+            )),
+            Err(e) => found.push((
+                issue(
+                    FsckIssueKind::InvalidMeta,
+                    session_id,
+                    base_dir,
+                    &meta_path,
+                    e.to_string(),
+                    None,
+                ),
+                None,
+            )),
+        }
+    }
+
+    if transcript_path.exists() {
+        check_transcript(base_dir, &transcript_path, session_id, found)?;
+    }
+
+    let mut notes = Vec::new();
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_file() && path.extension().is_some_and(|ext| ext == "md") {
+            notes.push(path);
+        }
+    }
+    notes.sort();
+
+    for path in &notes {
+        check_note(base_dir, path, session_id, found)?;
+    }
+
+    let attachments_dir = dir.join(files::ATTACHMENTS);
+    let has_attachments = std::fs::read_dir(&attachments_dir)
+        .map(|mut entries| entries.next().is_some())
+        .unwrap_or(false);
+    if has_attachments && !meta_path.exists() && !transcript_path.exists() && notes.is_empty() {
+        found.push((
+            issue(
+                FsckIssueKind::OrphanAttachments,
+                session_id,
+                base_dir,
+                &attachments_dir,
+                "attachments left behind by a deleted session".to_string(),
+                Some(format!(
+                    "remove {}",
+                    relative_path(base_dir, &attachments_dir)
+                )),
+            ),
+            Some(FsckOp::RemoveAttachments {
+                session_dir: dir.to_path_buf(),
+            }),
+        ));
+    }
+
+    Ok(())
+}
+
+fn check_transcript(
+    base_dir: &Path,
+    path: &Path,
+    session_id: &str,
+    found: &mut Vec<(FsckIssue, Option<FsckOp>)>,
+) -> Result<()> {
+    let content = std::fs::read_to_string(path)?;
+    let mut json: Value = match serde_json::from_str(&content) {
+        Ok(json) => json,
+        Err(e) => {
+            found.push((
+                issue(
+                    FsckIssueKind::InvalidTranscript,
+                    session_id,
+                    base_dir,
+                    path,
+                    e.to_string(),
+                    None,
+                ),
+                None,
+            ));
+            return Ok(());
+        }
+    };
+
+    let Some(transcripts) = json.get_mut("transcripts").and_then(|v| v.as_array_mut()) else {
+        found.push((
+            issue(
+                FsckIssueKind::InvalidTranscript,
+                session_id,
+                base_dir,
+                path,
+                "missing `transcripts` array".to_string(),
+                None,
+            ),
+            None,
+        ));
+        return Ok(());
+    };
+
+    let mut broken = Vec::new();
+    for transcript in transcripts.iter_mut() {
+        let Some(words) = transcript.get_mut("words").and_then(|v| v.as_array_mut()) else {
+            continue;
+        };
+        let bad = count_misordered_words(words);
+        if bad > 0 {
+            repair_word_order(words);
+            let id = transcript
+                .get("id")
+                .and_then(|v| v.as_str())
+                .unwrap_or_default()
+                .to_string();
+            broken.push(format!("{} ({} words)", id, bad));
+        }
+    }
+
+    if !broken.is_empty() {
+        let repaired = serde_json::to_string_pretty(&json)?;
+        found.push((
+            FsckIssue {
+                diff: Some(unified_diff(base_dir, path, &content, &repaired)),
+                ..issue(
+                    FsckIssueKind::TranscriptWordOrder,
+                    session_id,
+                    base_dir,
+                    path,
+                    format!("overlapping or out-of-order words in {}", broken.join(", ")),
+                    Some("sort words by start time and clamp overlapping end times".to_string()),
+                )
+            },
+            Some(FsckOp::Write {
+                path: path.to_path_buf(),
+                content: repaired,
+            }),
+        ));
+    }
+
+    Ok(())
+}
+
+fn word_times(word: &Value) -> (i64, i64) {
+    let start = word.get("start_ms").and_then(|v| v.as_i64()).unwrap_or(0);
+    let end = word.get("end_ms").and_then(|v| v.as_i64()).unwrap_or(start);
+    (start, end)
+}
+
+fn word_channel(word: &Value) -> i64 {
+    word.get("channel").and_then(|v| v.as_i64()).unwrap_or(0)
+}
+
+// Words on different channels may legitimately overlap (two people talking
+// at once), so ordering is only checked within a channel.
+fn count_misordered_words(words: &[Value]) -> usize {
+    let mut last_end: BTreeMap<i64, i64> = BTreeMap::new();
+    let mut bad = 0;
+
+    for word in words {
+        let (start, end) = word_times(word);
+        let channel = word_channel(word);
+
+        let overlaps = last_end.get(&channel).is_some_and(|prev| start < *prev);
+        if overlaps || end < start {
+            bad += 1;
+        }
+        last_end.insert(channel, end.max(start));
+    }
+
+    bad
+}
+
+fn repair_word_order(words: &mut [Value]) {
+    words.sort_by_key(|w| word_times(w).0);
+
+    let mut last: BTreeMap<i64, usize> = BTreeMap::new();
+    for i in 0..words.len() {
+        let (start, end) = word_times(&words[i]);
+        if end < start {
+            words[i]["end_ms"] = Value::from(start);
+        }
+
+        let channel = word_channel(&words[i]);
+        if let Some(prev) = last.insert(channel, i) {
+            let (_, prev_end) = word_times(&words[prev]);
+            if prev_end > start {
+                words[prev]["end_ms"] = Value::from(start);
+            }
+        }
+    }
+}
+
+fn check_note(
+    base_dir: &Path,
+    path: &Path,
+    session_id: &str,
+    found: &mut Vec<(FsckIssue, Option<FsckOp>)>,
+) -> Result<()> {
+    let content = std::fs::read_to_string(path)?;
+    let Ok(mut doc) = Document::<Map<String, Value>>::from_str(&content) else {
+        return Ok(());
+    };
+
+    let current = doc
+        .frontmatter
+        .get("session_id")
+        .and_then(|v| v.as_str())
+        .map(|s| s.to_string());
+    if current.as_deref().is_none_or(|id| id == session_id) {
+        return Ok(());
+    }
+
+    doc.frontmatter.insert(
+        "session_id".to_string(),
+        Value::String(session_id.to_string()),
+    );
+    // The memo's `id` is the session id too.
+    if dir_name(path) == files::MEMO {
+        doc.frontmatter
+            .insert("id".to_string(), Value::String(session_id.to_string()));
+    }
+
+    let repaired = doc.render()?;
+    found.push((
+        FsckIssue {
+            diff: Some(unified_diff(base_dir, path, &content, &repaired)),
+            ..issue(
+                FsckIssueKind::SessionIdMismatch,
+                session_id,
+                base_dir,
+                path,
+                format!("frontmatter session_id is {}", current.unwrap_or_default()),
+                Some(format!("set session_id to {}", session_id)),
+            )
+        },
+        Some(FsckOp::Write {
+            path: path.to_path_buf(),
+            content: repaired,
+        }),
+    ));
+
+    Ok(())
+}
+
+fn apply_op(op: FsckOp) -> Result<()> {
+    match op {
+        FsckOp::Write { path, content } => std::fs::write(path, content)?,
+        FsckOp::RemoveAttachments { session_dir } => {
+            std::fs::remove_dir_all(session_dir.join(files::ATTACHMENTS))?;
+            if std::fs::read_dir(&session_dir)?.next().is_none() {
+                std::fs::remove_dir(&session_dir)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn issue(
+    kind: FsckIssueKind,
+    session_id: &str,
+    base_dir: &Path,
+    path: &Path,
+    message: String,
+    fix: Option<String>,
+) -> FsckIssue {
+    FsckIssue {
+        kind,
+        session_id: session_id.to_string(),
+        path: relative_path(base_dir, path),
+        message,
+        fix,
+        diff: None,
+        fixed: false,
+    }
+}
+
+fn unified_diff(base_dir: &Path, path: &Path, before: &str, after: &str) -> String {
+    let path = relative_path(base_dir, path);
+    similar::TextDiff::from_lines(before, after)
+        .unified_diff()
+        .header(&format!("a/{path}"), &format!("b/{path}"))
+        .to_string()
+}
+
+fn dir_name(path: &Path) -> String {
+    path.file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default()
+}
+
+fn relative_path(base_dir: &Path, path: &Path) -> String {
+    path.strip_prefix(base_dir)
+        .unwrap_or(path)
+        .to_string_lossy()
+        .replace(std::path::MAIN_SEPARATOR, "/")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    const SESSION_1: &str = "550e8400-e29b-41d4-a716-446655440000";
+    const SESSION_2: &str = "6ba7b810-9dad-11d1-80b4-00c04fd430c8";
+
+    fn write(root: &Path, path: &str, content: &str) {
+        let path = root.join(path);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, content).unwrap();
+    }
+
+    fn word(id: &str, start: i64, end: i64) -> Value {
+        serde_json::json!({ "id": id, "text": id, "start_ms": start, "end_ms": end, "channel": 0 })
+    }
+
+    fn kinds(report: &FsckReport) -> Vec<FsckIssueKind> {
+        report.issues.iter().map(|i| i.kind).collect()
+    }
+
+    #[test]
+    fn test_clean_vault() {
+        let temp = tempdir().unwrap();
+        write(
+            temp.path(),
+            &format!("sessions/{SESSION_1}/_meta.json"),
+            r#"{"id":"x"}"#,
+        );
+        write(
+            temp.path(),
+            &format!("sessions/{SESSION_1}/_memo.md"),
+            &format!("---\nid: {SESSION_1}\nsession_id: {SESSION_1}\n---\n\nHello"),
+        );
+
+        let report = run(temp.path(), false).unwrap();
+
+        assert_eq!(report.sessions_scanned, 1);
+        assert!(report.issues.is_empty());
+    }
+
+    #[test]
+    fn test_reports_without_applying() {
+        let temp = tempdir().unwrap();
+        let base = temp.path();
+        write(
+            base,
+            &format!("sessions/{SESSION_1}/_meta.json"),
+            "{ broken",
+        );
+        write(
+            base,
+            &format!("sessions/Work/{SESSION_1}/_meta.json"),
+            r#"{"id":"x"}"#,
+        );
+        write(
+            base,
+            &format!("sessions/{SESSION_2}/attachments/image.png"),
+            "png",
+        );
+
+        let report = run(base, false).unwrap();
+
+        assert_eq!(report.sessions_scanned, 2);
+        assert_eq!(
+            kinds(&report),
+            vec![
+                FsckIssueKind::InvalidMeta,
+                FsckIssueKind::OrphanAttachments,
+                FsckIssueKind::DuplicateSession,
+            ]
+        );
+        assert_eq!(report.issues[2].path, format!("sessions/Work/{SESSION_1}"));
+        assert!(report.issues.iter().all(|i| !i.fixed));
+        assert!(base.join(format!("sessions/{SESSION_2}")).exists());
+    }
+
+    #[test]
+    fn test_repairs_word_order() {
+        let temp = tempdir().unwrap();
+        let base = temp.path();
+        let transcript = serde_json::json!({
+            "transcripts": [{
+                "id": "t1",
+                "words": [
+                    word("a", 0, 500),
+                    word("c", 900, 1200),
+                    word("b", 400, 800),
+                    { "id": "d", "text": "d", "start_ms": 300, "end_ms": 600, "channel": 1 },
+                ],
+            }]
+        });
+        write(
+            base,
+            &format!("sessions/{SESSION_1}/transcript.json"),
+            &transcript.to_string(),
+        );
+
+        let report = run(base, true).unwrap();
+        assert_eq!(kinds(&report), vec![FsckIssueKind::TranscriptWordOrder]);
+        assert!(report.issues[0].fixed);
+
+        let content =
+            std::fs::read_to_string(base.join(format!("sessions/{SESSION_1}/transcript.json")))
+                .unwrap();
+        let json: Value = serde_json::from_str(&content).unwrap();
+        let words: Vec<(&str, i64, i64)> = json["transcripts"][0]["words"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|w| {
+                (
+                    w["id"].as_str().unwrap(),
+                    w["start_ms"].as_i64().unwrap(),
+                    w["end_ms"].as_i64().unwrap(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            words,
+            vec![
+                ("a", 0, 400),
+                ("d", 300, 600),
+                ("b", 400, 800),
+                ("c", 900, 1200),
+            ]
+        );
+
+        assert!(run(base, false).unwrap().issues.is_empty());
+    }
+
+    #[test]
+    fn test_repairs_session_id_mismatch() {
+        let temp = tempdir().unwrap();
+        let base = temp.path();
+        write(
+            base,
+            &format!("sessions/{SESSION_1}/note.md"),
+            &format!("---\nid: n1\nsession_id: {SESSION_2}\nposition: 1\n---\n\nSummary"),
+        );
+
+        let dry_run = run(base, false).unwrap();
+        let diff = dry_run.issues[0].diff.as_deref().unwrap();
+        assert!(diff.starts_with(&format!("--- a/sessions/{SESSION_1}/note.md\n")));
+        assert!(diff.contains(&format!("\n-session_id: {SESSION_2}\n")));
+        assert!(diff.contains(&format!("\n+session_id: {SESSION_1}\n")));
+
+        let report = run(base, true).unwrap();
+        assert_eq!(kinds(&report), vec![FsckIssueKind::SessionIdMismatch]);
+        assert!(report.issues[0].fixed);
+
+        let content =
+            std::fs::read_to_string(base.join(format!("sessions/{SESSION_1}/note.md"))).unwrap();
+        let doc = Document::<Map<String, Value>>::from_str(&content).unwrap();
+        assert_eq!(doc.frontmatter["session_id"], SESSION_1);
+        assert_eq!(doc.frontmatter["id"], "n1");
+        assert_eq!(doc.content, "Summary");
+    }
+
+    #[test]
+    fn test_orphan_attachments_keeps_other_files() {
+        let temp = tempdir().unwrap();
+        let base = temp.path();
+        write(
+            base,
+            &format!("sessions/{SESSION_1}/attachments/image.png"),
+            "png",
+        );
+        write(base, &format!("sessions/{SESSION_1}/audio.ogg"), "ogg");
+        write(
+            base,
+            &format!("sessions/{SESSION_2}/attachments/image.png"),
+            "png",
+        );
+
+        let report = run(base, true).unwrap();
+        assert_eq!(
+            kinds(&report),
+            vec![
+                FsckIssueKind::OrphanAttachments,
+                FsckIssueKind::OrphanAttachments,
+            ]
+        );
+        assert!(report.issues.iter().all(|i| i.fixed));
+
+        let session_1 = base.join(format!("sessions/{SESSION_1}"));
+        assert!(!session_1.join("attachments").exists());
+        assert_eq!(
+            std::fs::read_to_string(session_1.join("audio.ogg")).unwrap(),
+            "ogg"
+        );
+        assert!(!base.join(format!("sessions/{SESSION_2}")).exists());
+    }
+}
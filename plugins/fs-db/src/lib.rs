@@ -1,12 +1,15 @@
 mod commands;
 mod error;
 mod ext;
+mod followup;
+pub mod fsck;
 pub mod migrations;
 pub mod types;
 pub mod version;
 
 pub use error::{Error, Result};
 pub use ext::*;
+pub use fsck::{FsckIssue, FsckIssueKind, FsckReport};
 pub use types::{
     EnhancedNoteData, SessionContent, SessionEnhancedNotes, SessionTranscript, SpeakerHint,
     TranscriptData, Word,
@@ -25,6 +28,8 @@ fn make_specta_builder<R: tauri::Runtime>() -> tauri_specta::Builder<R> {
             commands::save_session_content::<tauri::Wry>,
             commands::save_session_transcript::<tauri::Wry>,
             commands::save_session_enhanced_note::<tauri::Wry>,
+            commands::fsck::<tauri::Wry>,
+            commands::draft_followup::<tauri::Wry>,
         ])
         .error_handling(tauri_specta::ErrorHandlingMode::Result)
 }
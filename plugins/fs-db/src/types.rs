@@ -73,8 +73,10 @@ pub struct SessionEnhancedNotes {
 }
 
 pub(crate) mod files {
+    pub const META: &str = "_meta.json";
     pub const MEMO: &str = "_memo.md";
     pub const TRANSCRIPT: &str = "transcript.json";
+    pub const EXTRACTION: &str = "extraction.json";
 }
 
 #[derive(Debug, Deserialize)]
@@ -22,6 +22,7 @@ const COMMANDS: &[&str] = &[
     "attachment_save",
     "attachment_list",
     "attachment_remove",
+    "obsidian_mirror_session",
 ];
 
 fn main() {
@@ -189,6 +189,14 @@ async attachmentRemove(sessionId: string, attachmentId: string) : Promise<Result
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
+},
+async obsidianMirrorSession(sessionId: string, targetDir: string) : Promise<Result<string, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:fs-sync|obsidian_mirror_session", { sessionId, targetDir }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
 }
 }
 
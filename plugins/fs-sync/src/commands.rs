@@ -363,3 +363,27 @@ pub(crate) async fn attachment_remove<R: tauri::Runtime>(
             .map_err(|e| e.to_string())
     })
 }
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn obsidian_mirror_session<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    session_id: String,
+    target_dir: String,
+) -> Result<String, String> {
+    let base = app
+        .settings()
+        .cached_vault_base()
+        .map_err(|e| e.to_string())?;
+    let session_dir = resolve_session_dir(&app, &session_id)?;
+    spawn_blocking!({
+        crate::mirror::mirror_session(
+            base.as_std_path(),
+            &session_id,
+            &session_dir,
+            &PathBuf::from(&target_dir),
+        )
+        .map(|p| p.to_string_lossy().to_string())
+        .map_err(|e| e.to_string())
+    })
+}
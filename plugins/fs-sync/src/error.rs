@@ -14,6 +14,8 @@ pub enum Error {
     Frontmatter(#[from] hypr_frontmatter::Error),
     #[error("Markdown error: {0}")]
     Markdown(String),
+    #[error(transparent)]
+    Obsidian(#[from] hypr_obsidian::Error),
 }
 
 impl Serialize for Error {
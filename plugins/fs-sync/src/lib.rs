@@ -9,6 +9,7 @@ mod ext;
 mod folder;
 mod frontmatter;
 mod json;
+mod mirror;
 mod path;
 mod scan;
 mod session;
@@ -51,6 +52,7 @@ fn make_specta_builder<R: tauri::Runtime>() -> tauri_specta::Builder<R> {
             commands::attachment_save::<tauri::Wry>,
             commands::attachment_list::<tauri::Wry>,
             commands::attachment_remove::<tauri::Wry>,
+            commands::obsidian_mirror_session::<tauri::Wry>,
         ])
         .error_handling(tauri_specta::ErrorHandlingMode::Result)
 }
@@ -0,0 +1,80 @@
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use hypr_obsidian::writer::{MirrorNote, write_note};
+
+use crate::frontmatter::ParsedDocument;
+use crate::session_content::load_session_content;
+use crate::types::SessionContentData;
+
+/// Writes the session's notes as a single Obsidian note into `target_dir`.
+///
+/// Re-running it updates the same file (matched by `session_id` in the
+/// frontmatter), and importing the target folder maps back to this session.
+pub fn mirror_session(
+    vault_base: &Path,
+    session_id: &str,
+    session_dir: &Path,
+    target_dir: &Path,
+) -> crate::Result<PathBuf> {
+    let content = load_session_content(session_id, session_dir);
+    let note = to_mirror_note(vault_base, &content)?;
+
+    Ok(write_note(target_dir, &note)?)
+}
+
+fn to_mirror_note(vault_base: &Path, content: &SessionContentData) -> crate::Result<MirrorNote> {
+    let meta = content.meta.as_ref();
+
+    let mut notes: Vec<_> = content.notes.iter().collect();
+    notes.sort_by_key(|note| note.position.unwrap_or(i64::MAX));
+
+    let mut sections = Vec::with_capacity(notes.len() + 1);
+    for note in &notes {
+        let md =
+            hypr_tiptap::tiptap_json_to_md(&note.tiptap_json).map_err(crate::Error::Markdown)?;
+        match note.title.as_deref().filter(|_| notes.len() > 1) {
+            Some(title) => sections.push(format!("## {}\n\n{}", title, md.trim())),
+            None => sections.push(md.trim().to_string()),
+        }
+    }
+    if let Some(memo) = &content.raw_memo_tiptap_json {
+        let md = hypr_tiptap::tiptap_json_to_md(memo).map_err(crate::Error::Markdown)?;
+        if !md.trim().is_empty() {
+            sections.push(format!("## Memo\n\n{}", md.trim()));
+        }
+    }
+
+    let attendees = meta
+        .map(|m| {
+            m.participants
+                .iter()
+                .filter_map(|p| human_name(vault_base, &p.human_id))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Ok(MirrorNote {
+        session_id: content.session_id.clone(),
+        title: meta
+            .and_then(|m| m.title.clone())
+            .unwrap_or_else(|| "Untitled".to_string()),
+        created_at: meta.and_then(|m| m.created_at.clone()),
+        tags: meta.map(|m| m.tags.clone()).unwrap_or_default(),
+        attendees,
+        content: sections.join("\n\n"),
+    })
+}
+
+fn human_name(vault_base: &Path, human_id: &str) -> Option<String> {
+    let path = vault_base.join("humans").join(format!("{}.md", human_id));
+    let content = std::fs::read_to_string(path).ok()?;
+    let parsed = ParsedDocument::from_str(&content).ok()?;
+
+    parsed
+        .frontmatter
+        .get("name")
+        .and_then(|v| v.as_str())
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+}
@@ -1,4 +1,11 @@
-const COMMANDS: &[&str] = &["list_available_sources", "run_import", "run_import_dry"];
+const COMMANDS: &[&str] = &[
+    "list_available_sources",
+    "run_import",
+    "run_import_dry",
+    "run_import_from_path",
+    "run_import_dry_from_path",
+    "commit_import",
+];
 
 fn main() {
     tauri_plugin::Builder::new(COMMANDS).build();
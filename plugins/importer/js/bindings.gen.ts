@@ -29,6 +29,30 @@ async runImportDry(source: ImportSourceKind) : Promise<Result<ImportStats, strin
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
+},
+async runImportFromPath(path: string, transform: TransformKind, userId: string) : Promise<Result<ImportDataResult, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:importer|run_import_from_path", { path, transform, userId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async runImportDryFromPath(path: string, transform: TransformKind) : Promise<Result<ImportStats, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:importer|run_import_dry_from_path", { path, transform }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async commitImport(source: ImportSourceKind) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:importer|commit_import", { source }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
 }
 }
 
@@ -44,10 +68,10 @@ async runImportDry(source: ImportSourceKind) : Promise<Result<ImportStats, strin
 
 export type ImportDataResult = { stats: ImportStats; data: JsonValue }
 export type ImportSourceInfo = { kind: ImportSourceKind | null; transform: TransformKind; name: string; path: string; revealPath: string }
-export type ImportSourceKind = "granola" | "hyprnote_v0_stable" | "hyprnote_v0_nightly" | "as_is"
+export type ImportSourceKind = "granola" | "granola_cache" | "hyprnote_v0_stable" | "hyprnote_v0_nightly" | "as_is"
 export type ImportStats = { sessionsCount: number; transcriptsCount: number; humansCount: number; organizationsCount: number; participantsCount: number; templatesCount: number; enhancedNotesCount: number }
 export type JsonValue = null | boolean | number | string | JsonValue[] | Partial<{ [key in string]: JsonValue }>
-export type TransformKind = "hyprnote_v0" | "granola" | "as_is"
+export type TransformKind = "hyprnote_v0" | "granola" | "granola_cache" | "as_is" | "otter" | "fireflies" | "vtt" | "obsidian"
 
 /** tauri-specta globals **/
 
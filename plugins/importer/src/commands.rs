@@ -1,5 +1,7 @@
 use crate::ext::ImporterPluginExt;
-use crate::types::{ImportDataResult, ImportSourceInfo, ImportSourceKind, ImportStats};
+use crate::types::{
+    ImportDataResult, ImportSource, ImportSourceInfo, ImportSourceKind, ImportStats, TransformKind,
+};
 
 #[tauri::command]
 #[specta::specta]
@@ -34,3 +36,43 @@ pub async fn run_import_dry<R: tauri::Runtime>(
         .await
         .map_err(|e| e.to_string())
 }
+
+#[tauri::command]
+#[specta::specta]
+pub async fn run_import_from_path<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    path: String,
+    transform: TransformKind,
+    user_id: String,
+) -> Result<ImportDataResult, String> {
+    let source = ImportSource::from_path(path.into(), transform);
+    app.importer()
+        .run_import_from_source(&source, user_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn run_import_dry_from_path<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    path: String,
+    transform: TransformKind,
+) -> Result<ImportStats, String> {
+    let source = ImportSource::from_path(path.into(), transform);
+    app.importer()
+        .run_import_dry_from_source(&source)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn commit_import<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    source: ImportSourceKind,
+) -> Result<(), String> {
+    app.importer()
+        .commit_import(source)
+        .map_err(|e| e.to_string())
+}
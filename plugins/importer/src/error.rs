@@ -15,6 +15,12 @@ pub enum Error {
     #[error("db parser error: {0}")]
     DbParser(#[from] hypr_db_parser::Error),
 
+    #[error("export parser error: {0}")]
+    ExportParser(#[from] hypr_export_parser::Error),
+
+    #[error("obsidian error: {0}")]
+    Obsidian(#[from] hypr_obsidian::Error),
+
     #[error("import source not found: {0:?}")]
     SourceNotFound(ImportSourceKind),
 
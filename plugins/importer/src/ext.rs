@@ -2,12 +2,19 @@ use crate::types::{
     ImportDataResult, ImportSource, ImportSourceInfo, ImportSourceKind, ImportStats,
 };
 use hypr_importer_core::output::to_tinybase_json;
+use std::path::PathBuf;
+use tauri::Manager;
 
-pub struct Importer<R: tauri::Runtime> {
+pub struct Importer<'a, R: tauri::Runtime, M: tauri::Manager<R>> {
+    manager: &'a M,
     _runtime: std::marker::PhantomData<fn() -> R>,
 }
 
-impl<R: tauri::Runtime> Importer<R> {
+impl<'a, R: tauri::Runtime, M: tauri::Manager<R>> Importer<'a, R, M> {
+    fn state_dir(&self) -> Result<PathBuf, crate::Error> {
+        Ok(self.manager.path().app_data_dir()?.join("importer"))
+    }
+
     pub fn list_available_sources(&self) -> Vec<ImportSourceInfo> {
         crate::sources::list_available_sources()
     }
@@ -30,7 +37,7 @@ impl<R: tauri::Runtime> Importer<R> {
             return Err(crate::Error::SourceNotAvailable(source.name.clone()));
         }
 
-        let data = crate::sources::import_all(source).await?;
+        let data = crate::sources::import_all(source, &self.state_dir()?, false).await?;
         let stats = ImportStats::from_data(&data);
         let tinybase_json = to_tinybase_json(&data, &user_id);
 
@@ -56,18 +63,29 @@ impl<R: tauri::Runtime> Importer<R> {
             return Err(crate::Error::SourceNotAvailable(source.name.clone()));
         }
 
-        let data = crate::sources::import_all(source).await?;
+        let data = crate::sources::import_all(source, &self.state_dir()?, true).await?;
         Ok(ImportStats::from_data(&data))
     }
+
+    pub fn commit_import(&self, source_kind: ImportSourceKind) -> Result<(), crate::Error> {
+        let source = ImportSource::from(source_kind);
+        crate::sources::commit_import(&source, &self.state_dir()?)
+    }
 }
 
 pub trait ImporterPluginExt<R: tauri::Runtime> {
-    fn importer(&self) -> Importer<R>;
+    fn importer(&self) -> Importer<'_, R, Self>
+    where
+        Self: tauri::Manager<R> + Sized;
 }
 
 impl<R: tauri::Runtime, T: tauri::Manager<R>> ImporterPluginExt<R> for T {
-    fn importer(&self) -> Importer<R> {
+    fn importer(&self) -> Importer<'_, R, Self>
+    where
+        Self: Sized,
+    {
         Importer {
+            manager: self,
             _runtime: std::marker::PhantomData,
         }
     }
@@ -8,7 +8,7 @@ mod types;
 
 pub use error::*;
 pub use ext::*;
-pub use sources::{AsIsData, all_sources, import_all, list_available_sources};
+pub use sources::{AsIsData, all_sources, commit_import, import_all, list_available_sources};
 pub use types::*;
 
 const PLUGIN_NAME: &str = "importer";
@@ -20,6 +20,9 @@ fn make_specta_builder<R: tauri::Runtime>() -> tauri_specta::Builder<R> {
             commands::list_available_sources::<Wry>,
             commands::run_import::<Wry>,
             commands::run_import_dry::<Wry>,
+            commands::run_import_from_path::<Wry>,
+            commands::run_import_dry_from_path::<Wry>,
+            commands::commit_import::<Wry>,
         ])
         .error_handling(tauri_specta::ErrorHandlingMode::Result)
 }
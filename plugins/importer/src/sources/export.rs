@@ -0,0 +1,8 @@
+use crate::types::Collection;
+use hypr_export_parser::ExportFormat;
+use std::path::Path;
+
+pub fn import_all_from_path(path: &Path, format: ExportFormat) -> Result<Collection, crate::Error> {
+    let data = hypr_export_parser::parse_from_path(path, format)?;
+    Ok(data)
+}
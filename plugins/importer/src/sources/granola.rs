@@ -1,7 +1,36 @@
 use crate::types::Collection;
+use hypr_granola::importer::ImportState;
 use std::path::Path;
 
+const STATE_FILE: &str = "granola-cache.json";
+const PENDING_STATE_FILE: &str = "granola-cache.pending.json";
+
 pub async fn import_all_from_path(path: &Path) -> Result<Collection, crate::Error> {
     let data = hypr_granola::importer::import_all_from_path(path).await?;
     Ok(data)
 }
+
+/// The next state is only staged here; [`commit`] makes it current once the
+/// caller has applied the returned collection.
+pub fn import_changed_from_cache(
+    path: &Path,
+    state_dir: &Path,
+    dry_run: bool,
+) -> Result<Collection, crate::Error> {
+    let state = ImportState::load(&state_dir.join(STATE_FILE))?;
+
+    let (data, next_state) = hypr_granola::importer::import_changed_from_cache(path, &state)?;
+    if !dry_run {
+        next_state.save(&state_dir.join(PENDING_STATE_FILE))?;
+    }
+
+    Ok(data)
+}
+
+pub fn commit(state_dir: &Path) -> Result<(), crate::Error> {
+    let pending = state_dir.join(PENDING_STATE_FILE);
+    if pending.exists() {
+        std::fs::rename(pending, state_dir.join(STATE_FILE))?;
+    }
+    Ok(())
+}
@@ -1,16 +1,45 @@
 mod as_is;
+mod export;
 mod granola;
 mod hyprnote;
+mod obsidian;
 
 pub use as_is::AsIsData;
 
 use crate::types::{Collection, ImportSource, ImportSourceInfo, TransformKind};
+use hypr_export_parser::ExportFormat;
+use std::path::Path;
 
-pub async fn import_all(source: &ImportSource) -> Result<Collection, crate::Error> {
+/// `state_dir` holds what incremental sources have already imported. Unless
+/// `dry_run`, they stage their next state there, which only takes effect
+/// after [`commit_import`].
+pub async fn import_all(
+    source: &ImportSource,
+    state_dir: &Path,
+    dry_run: bool,
+) -> Result<Collection, crate::Error> {
     match source.transform {
         TransformKind::HyprnoteV0 => hyprnote::v0::import_all_from_path(&source.path).await,
         TransformKind::Granola => granola::import_all_from_path(&source.path).await,
+        TransformKind::GranolaCache => {
+            granola::import_changed_from_cache(&source.path, state_dir, dry_run)
+        }
         TransformKind::AsIs => as_is::load_data(&source.path),
+        TransformKind::Otter => export::import_all_from_path(&source.path, ExportFormat::Otter),
+        TransformKind::Fireflies => {
+            export::import_all_from_path(&source.path, ExportFormat::Fireflies)
+        }
+        TransformKind::Vtt => export::import_all_from_path(&source.path, ExportFormat::Vtt),
+        TransformKind::Obsidian => obsidian::import_all_from_path(&source.path),
+    }
+}
+
+/// Marks what the last non-dry [`import_all`] of `source` returned as
+/// imported. Call it once that data has been applied.
+pub fn commit_import(source: &ImportSource, state_dir: &Path) -> Result<(), crate::Error> {
+    match source.transform {
+        TransformKind::GranolaCache => granola::commit(state_dir),
+        _ => Ok(()),
     }
 }
 
@@ -18,6 +47,7 @@ pub fn all_sources() -> Vec<ImportSource> {
     [
         ImportSource::hyprnote_stable(),
         ImportSource::hyprnote_nightly(),
+        ImportSource::granola_cache(),
     ]
     .into_iter()
     .flatten()
@@ -0,0 +1,7 @@
+use crate::types::Collection;
+use std::path::Path;
+
+pub fn import_all_from_path(path: &Path) -> Result<Collection, crate::Error> {
+    let data = hypr_obsidian::importer::import_from_path(path)?;
+    Ok(data)
+}
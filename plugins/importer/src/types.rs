@@ -11,13 +11,19 @@ pub use hypr_importer_core::ir::{
 pub enum TransformKind {
     HyprnoteV0,
     Granola,
+    GranolaCache,
     AsIs,
+    Otter,
+    Fireflies,
+    Vtt,
+    Obsidian,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, specta::Type, PartialEq, Eq, Hash)]
 #[serde(rename_all = "snake_case")]
 pub enum ImportSourceKind {
     Granola,
+    GranolaCache,
     HyprnoteV0Stable,
     HyprnoteV0Nightly,
     AsIs,
@@ -75,6 +81,16 @@ impl ImportSource {
         })
     }
 
+    pub fn granola_cache() -> Option<Self> {
+        let path = hypr_granola::cache::default_cache_path();
+        Some(Self {
+            kind: Some(ImportSourceKind::GranolaCache),
+            transform: TransformKind::GranolaCache,
+            path,
+            name: "Granola (offline cache)".to_string(),
+        })
+    }
+
     pub fn is_available(&self) -> bool {
         self.path.exists()
     }
@@ -113,6 +129,7 @@ impl From<ImportSourceKind> for ImportSource {
             ImportSourceKind::HyprnoteV0Stable => Self::hyprnote_stable().unwrap(),
             ImportSourceKind::HyprnoteV0Nightly => Self::hyprnote_nightly().unwrap(),
             ImportSourceKind::Granola => Self::granola().unwrap(),
+            ImportSourceKind::GranolaCache => Self::granola_cache().unwrap(),
             ImportSourceKind::AsIs => Self {
                 kind: Some(ImportSourceKind::AsIs),
                 transform: TransformKind::AsIs,
@@ -6,6 +6,7 @@ const COMMANDS: &[&str] = &[
     "set_mic_muted",
     "start_session",
     "stop_session",
+    "auto_stop_signal",
     "get_state",
     "run_batch",
     "is_supported_languages_live",
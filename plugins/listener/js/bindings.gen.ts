@@ -54,6 +54,14 @@ async stopSession() : Promise<Result<null, string>> {
     else return { status: "error", error: e  as any };
 }
 },
+async autoStopSignal(signal: AutoStopSignal) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:listener|auto_stop_signal", { signal }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 async getState() : Promise<Result<State, string>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("plugin:listener|get_state") };
@@ -109,12 +117,51 @@ sessionProgressEvent: "plugin:listener:session-progress-event"
 
 /** user-defined types **/
 
+export type AutoStopConfig = { enabled: boolean; 
+/**
+ * Minutes with no speech on either side before asking to stop.
+ */
+silence_minutes: number; 
+/**
+ * Seconds with no speech before asking, once the meeting app has
+ * released the mic or the calendar event is over.
+ */
+ended_silence_secs: number; 
+/**
+ * Minutes past the calendar event's end before it counts as over.
+ */
+event_overrun_minutes: number; 
+/**
+ * Seconds the "Still recording — stop?" prompt waits for an answer.
+ */
+grace_period_secs: number }
+export type AutoStopReason = "silence" | "meeting_app_stopped" | "event_ended"
+/**
+ * What the policy learns from outside the audio pipeline.
+ */
+export type AutoStopSignal = 
+/**
+ * The meeting app being recorded released the mic.
+ */
+"meeting_app_stopped" | 
+/**
+ * It took the mic again, e.g. after rejoining the call.
+ */
+"meeting_app_started" | 
+/**
+ * The user answered the prompt with "keep recording".
+ */
+"keep_recording"
 export type DegradedError = { type: "authentication_failed"; provider: string } | { type: "upstream_unavailable"; message: string } | { type: "connection_timeout" } | { type: "stream_error"; message: string }
 export type SessionDataEvent = { type: "audio_amplitude"; session_id: string; mic: number; speaker: number } | { type: "mic_muted"; session_id: string; value: boolean } | { type: "stream_response"; session_id: string; response: StreamResponse }
 export type SessionErrorEvent = { type: "audio_error"; session_id: string; error: string; device: string | null; is_fatal: boolean } | { type: "connection_error"; session_id: string; error: string }
 export type SessionLifecycleEvent = { type: "inactive"; session_id: string; error: string | null } | { type: "active"; session_id: string; error?: DegradedError | null } | { type: "finalizing"; session_id: string }
-export type SessionParams = { session_id: string; languages: string[]; onboarding: boolean; record_enabled: boolean; model: string; base_url: string; api_key: string; keywords: string[] }
-export type SessionProgressEvent = { type: "audio_initializing"; session_id: string } | { type: "audio_ready"; session_id: string; device: string | null } | { type: "connecting"; session_id: string } | { type: "connected"; session_id: string; adapter: string }
+export type SessionParams = { session_id: string; languages: string[]; onboarding: boolean; record_enabled: boolean; model: string; base_url: string; api_key: string; keywords: string[]; auto_stop: AutoStopConfig; 
+/**
+ * End of the calendar event being recorded, if any.
+ */
+event_ends_at: string | null }
+export type SessionProgressEvent = { type: "audio_initializing"; session_id: string } | { type: "audio_ready"; session_id: string; device: string | null } | { type: "connecting"; session_id: string } | { type: "connected"; session_id: string; adapter: string } | { type: "auto_stop_pending"; session_id: string; reason: AutoStopReason; stop_at: string } | { type: "auto_stop_cancelled"; session_id: string } | { type: "auto_stopped"; session_id: string; reason: AutoStopReason }
 export type State = "active" | "inactive" | "finalizing"
 export type StreamAlternatives = { transcript: string; words: StreamWord[]; confidence: number; languages?: string[] }
 export type StreamChannel = { alternatives: StreamAlternatives[] }
@@ -2,6 +2,7 @@ use owhisper_client::AdapterKind;
 use std::str::FromStr;
 
 use crate::ListenerPluginExt;
+use hypr_listener_core::AutoStopSignal;
 use hypr_listener_core::actors::SessionParams;
 
 #[tauri::command]
@@ -59,6 +60,16 @@ pub async fn stop_session<R: tauri::Runtime>(app: tauri::AppHandle<R>) -> Result
     Ok(())
 }
 
+#[tauri::command]
+#[specta::specta]
+pub async fn auto_stop_signal<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    signal: AutoStopSignal,
+) -> Result<(), String> {
+    app.listener().auto_stop_signal(signal).await;
+    Ok(())
+}
+
 #[tauri::command]
 #[specta::specta]
 pub async fn get_state<R: tauri::Runtime>(
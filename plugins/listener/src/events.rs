@@ -1,5 +1,6 @@
 use owhisper_interface::stream::StreamResponse;
 
+use chrono::{DateTime, Utc};
 use hypr_listener_core as core;
 
 #[macro_export]
@@ -48,6 +49,19 @@ common_event_derives! {
             session_id: String,
             adapter: String,
         },
+        #[serde(rename = "auto_stop_pending")]
+        AutoStopPending {
+            session_id: String,
+            reason: core::AutoStopReason,
+            stop_at: DateTime<Utc>,
+        },
+        #[serde(rename = "auto_stop_cancelled")]
+        AutoStopCancelled { session_id: String },
+        #[serde(rename = "auto_stopped")]
+        AutoStopped {
+            session_id: String,
+            reason: core::AutoStopReason,
+        },
     }
 }
 
@@ -123,6 +137,21 @@ impl From<core::SessionProgressEvent> for SessionProgressEvent {
                 session_id,
                 adapter,
             },
+            core::SessionProgressEvent::AutoStopPending {
+                session_id,
+                reason,
+                stop_at,
+            } => SessionProgressEvent::AutoStopPending {
+                session_id,
+                reason,
+                stop_at,
+            },
+            core::SessionProgressEvent::AutoStopCancelled { session_id } => {
+                SessionProgressEvent::AutoStopCancelled { session_id }
+            }
+            core::SessionProgressEvent::AutoStopped { session_id, reason } => {
+                SessionProgressEvent::AutoStopped { session_id, reason }
+            }
         }
     }
 }
@@ -1,5 +1,6 @@
 use ractor::{ActorRef, call_t, registry};
 
+use hypr_listener_core::AutoStopSignal;
 use hypr_listener_core::actors::{RootActor, RootMsg, SessionParams, SourceActor, SourceMsg};
 
 pub struct Listener<'a, R: tauri::Runtime, M: tauri::Manager<R>> {
@@ -73,6 +74,14 @@ impl<'a, R: tauri::Runtime, M: tauri::Manager<R>> Listener<'a, R, M> {
             let _ = ractor::call!(actor, RootMsg::StopSession);
         }
     }
+
+    #[tracing::instrument(skip_all)]
+    pub async fn auto_stop_signal(&self, signal: AutoStopSignal) {
+        if let Some(cell) = registry::where_is(RootActor::name()) {
+            let actor: ActorRef<RootMsg> = cell.into();
+            let _ = actor.cast(RootMsg::AutoStop(signal));
+        }
+    }
 }
 
 pub trait ListenerPluginExt<R: tauri::Runtime> {
@@ -29,6 +29,7 @@ fn make_specta_builder<R: tauri::Runtime>() -> tauri_specta::Builder<R> {
             commands::set_mic_muted::<tauri::Wry>,
             commands::start_session::<tauri::Wry>,
             commands::stop_session::<tauri::Wry>,
+            commands::auto_stop_signal::<tauri::Wry>,
             commands::get_state::<tauri::Wry>,
             commands::is_supported_languages_live::<tauri::Wry>,
             commands::suggest_providers_for_languages_live::<tauri::Wry>,
@@ -1,4 +1,11 @@
-const COMMANDS: &[&str] = &["show_notification", "clear_notifications"];
+const COMMANDS: &[&str] = &[
+    "show_notification",
+    "dismiss_notification",
+    "clear_notifications",
+    "set_upcoming_events",
+    "set_reminder_settings",
+    "snooze_reminder",
+];
 
 fn main() {
     tauri_plugin::Builder::new(COMMANDS).build();
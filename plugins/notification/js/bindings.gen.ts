@@ -14,6 +14,14 @@ async showNotification(v: Notification) : Promise<Result<null, string>> {
     else return { status: "error", error: e  as any };
 }
 },
+async dismissNotification(key: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:notification|dismiss_notification", { key }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 async clearNotifications() : Promise<Result<null, string>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("plugin:notification|clear_notifications") };
@@ -21,6 +29,30 @@ async clearNotifications() : Promise<Result<null, string>> {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
+},
+async setUpcomingEvents(events: UpcomingEvent[]) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:notification|set_upcoming_events", { events }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async setReminderSettings(settings: ReminderSettings) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:notification|set_reminder_settings", { settings }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async snoozeReminder(key: string, minutes: number | null) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:notification|snooze_reminder", { key, minutes }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
 }
 }
 
@@ -41,11 +73,30 @@ notificationEvent: "plugin:notification:notification-event"
 
 export type Duration = { secs: number; nanos: number }
 export type EventDetails = { what: string; timezone: string | null; location: string | null }
-export type Notification = { key: string | null; title: string; message: string; timeout: Duration | null; source: NotificationSource | null; start_time: number | null; participants: Participant[] | null; event_details: EventDetails | null; action_label: string | null }
-export type NotificationEvent = { type: "notification_confirm"; key: string; source: NotificationSource | null } | { type: "notification_accept"; key: string; source: NotificationSource | null } | { type: "notification_dismiss"; key: string; source: NotificationSource | null } | { type: "notification_timeout"; key: string; source: NotificationSource | null }
-export type NotificationSource = { type: "calendar_event"; event_id: string } | { type: "mic_detected"; app_names: string[] }
+export type Notification = { key: string | null; title: string; message: string; timeout: Duration | null; source: NotificationSource | null; start_time: number | null; participants: Participant[] | null; event_details: EventDetails | null; action_label: string | null; 
+/**
+ * Offers to remind again later. Reported as a snooze, not a dismissal.
+ */
+snooze_label: string | null }
+export type NotificationEvent = { type: "notification_confirm"; key: string; source: NotificationSource | null } | { type: "notification_accept"; key: string; source: NotificationSource | null } | { type: "notification_snooze"; key: string; source: NotificationSource | null } | { type: "notification_dismiss"; key: string; source: NotificationSource | null } | { type: "notification_timeout"; key: string; source: NotificationSource | null }
+export type NotificationSource = { type: "calendar_event"; event_id: string } | { type: "mic_detected"; app_names: string[] } | { type: "auto_stop"; session_id: string }
 export type Participant = { name: string | null; email: string; status: ParticipantStatus }
 export type ParticipantStatus = "Accepted" | "Maybe" | "Declined"
+export type ReminderSettings = { enabled: boolean; 
+/**
+ * Minutes before the start to remind at. Each fires once per
+ * occurrence.
+ */
+lead_minutes: number[]; snooze_minutes: number }
+/**
+ * One occurrence of a calendar event, from any calendar source.
+ */
+export type UpcomingEvent = { 
+/**
+ * Unique per occurrence. Reported back as the notification's
+ * `calendar_event` source when the user acts on it.
+ */
+id: string; title: string; start: string; timezone: string | null; location: string | null; meeting_url: string | null; participants: Participant[] }
 
 /** tauri-specta globals **/
 
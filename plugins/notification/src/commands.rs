@@ -9,6 +9,15 @@ pub(crate) async fn show_notification<R: tauri::Runtime>(
     app.notification().show(v).map_err(|e| e.to_string())
 }
 
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn dismiss_notification<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    key: String,
+) -> Result<(), String> {
+    app.notification().dismiss(&key).map_err(|e| e.to_string())
+}
+
 #[tauri::command]
 #[specta::specta]
 pub(crate) async fn clear_notifications<R: tauri::Runtime>(
@@ -16,3 +25,37 @@ pub(crate) async fn clear_notifications<R: tauri::Runtime>(
 ) -> Result<(), String> {
     app.notification().clear().map_err(|e| e.to_string())
 }
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn set_upcoming_events<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    events: Vec<hypr_notification_scheduler::UpcomingEvent>,
+) -> Result<(), String> {
+    app.notification()
+        .set_upcoming_events(events)
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn set_reminder_settings<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    settings: hypr_notification_scheduler::ReminderSettings,
+) -> Result<(), String> {
+    app.notification()
+        .set_reminder_settings(settings)
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn snooze_reminder<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    key: String,
+    minutes: Option<u32>,
+) -> Result<(), String> {
+    app.notification()
+        .snooze_reminder(&key, minutes)
+        .map_err(|e| e.to_string())
+}
@@ -13,6 +13,8 @@ common_event_derives! {
         Confirm { key: String, source: Option<hypr_notification::NotificationSource> },
         #[serde(rename = "notification_accept")]
         Accept { key: String, source: Option<hypr_notification::NotificationSource> },
+        #[serde(rename = "notification_snooze")]
+        Snooze { key: String, source: Option<hypr_notification::NotificationSource> },
         #[serde(rename = "notification_dismiss")]
         Dismiss { key: String, source: Option<hypr_notification::NotificationSource> },
         #[serde(rename = "notification_timeout")]
@@ -1,4 +1,7 @@
+use tauri::Manager;
+
 use crate::error::Error;
+use crate::reminder::Reminders;
 
 pub struct Notification<'a, R: tauri::Runtime, M: tauri::Manager<R>> {
     manager: &'a M,
@@ -13,12 +16,44 @@ impl<'a, R: tauri::Runtime, M: tauri::Manager<R>> Notification<'a, R, M> {
         Ok(())
     }
 
+    #[tracing::instrument(skip(self))]
+    pub fn dismiss(&self, key: &str) -> Result<(), Error> {
+        let _ = self.manager;
+        hypr_notification::dismiss(key);
+        Ok(())
+    }
+
     #[tracing::instrument(skip(self))]
     pub fn clear(&self) -> Result<(), Error> {
         let _ = self.manager;
         hypr_notification::clear();
         Ok(())
     }
+
+    /// Replaces the events reminders are scheduled for.
+    #[tracing::instrument(skip_all)]
+    pub fn set_upcoming_events(
+        &self,
+        events: Vec<hypr_notification_scheduler::UpcomingEvent>,
+    ) -> Result<(), Error> {
+        self.manager.state::<Reminders>().set_events(events);
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub fn set_reminder_settings(
+        &self,
+        settings: hypr_notification_scheduler::ReminderSettings,
+    ) -> Result<(), Error> {
+        self.manager.state::<Reminders>().set_settings(settings);
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub fn snooze_reminder(&self, key: &str, minutes: Option<u32>) -> Result<(), Error> {
+        self.manager.state::<Reminders>().snooze(key, minutes);
+        Ok(())
+    }
 }
 
 pub trait NotificationPluginExt<R: tauri::Runtime> {
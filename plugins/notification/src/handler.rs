@@ -1,13 +1,33 @@
+use tauri::Manager;
 use tauri_plugin_analytics::{AnalyticsPayload, AnalyticsPluginExt};
 use tauri_plugin_windows::WindowsPluginExt;
 use tauri_specta::Event;
 
 use crate::events::NotificationEvent;
+use crate::reminder::Reminders;
+
+/// Lets the reminder scheduler know what happened to one of its
+/// notifications; other keys are ignored.
+fn record(
+    app: &tauri::AppHandle<tauri::Wry>,
+    key: &str,
+    event: hypr_notification::NotificationEvent,
+) {
+    if let Some(reminders) = app.try_state::<Reminders>() {
+        reminders.handle(key, event);
+    }
+}
 
 pub fn init(app: tauri::AppHandle<tauri::Wry>) {
     {
         let app = app.clone();
         hypr_notification::setup_collapsed_confirm_handler(move |ctx| {
+            record(
+                &app,
+                &ctx.key,
+                hypr_notification::NotificationEvent::Confirm,
+            );
+
             if let Err(_e) = app.windows().show(tauri_plugin_windows::AppWindow::Main) {}
 
             let _ = NotificationEvent::Confirm {
@@ -24,6 +44,8 @@ pub fn init(app: tauri::AppHandle<tauri::Wry>) {
     {
         let app = app.clone();
         hypr_notification::setup_expanded_accept_handler(move |ctx| {
+            record(&app, &ctx.key, hypr_notification::NotificationEvent::Accept);
+
             if let Err(_e) = app.windows().show(tauri_plugin_windows::AppWindow::Main) {}
 
             let _ = NotificationEvent::Accept {
@@ -40,6 +62,12 @@ pub fn init(app: tauri::AppHandle<tauri::Wry>) {
     {
         let app = app.clone();
         hypr_notification::setup_dismiss_handler(move |ctx| {
+            record(
+                &app,
+                &ctx.key,
+                hypr_notification::NotificationEvent::Dismiss,
+            );
+
             let _ = NotificationEvent::Dismiss {
                 key: ctx.key,
                 source: ctx.source,
@@ -51,9 +79,29 @@ pub fn init(app: tauri::AppHandle<tauri::Wry>) {
         });
     }
 
+    {
+        let app = app.clone();
+        hypr_notification::setup_snooze_handler(move |ctx| {
+            let _ = NotificationEvent::Snooze {
+                key: ctx.key,
+                source: ctx.source,
+            }
+            .emit(&app);
+
+            app.analytics()
+                .event_fire_and_forget(AnalyticsPayload::builder("snooze").build());
+        });
+    }
+
     {
         let app = app.clone();
         hypr_notification::setup_collapsed_timeout_handler(move |ctx| {
+            record(
+                &app,
+                &ctx.key,
+                hypr_notification::NotificationEvent::Timeout,
+            );
+
             let _ = NotificationEvent::Timeout {
                 key: ctx.key,
                 source: ctx.source,
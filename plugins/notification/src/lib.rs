@@ -1,10 +1,13 @@
 use std::str::FromStr;
 
+use tauri::Manager;
+
 mod commands;
 mod error;
 mod events;
 mod ext;
 mod handler;
+mod reminder;
 
 pub use error::*;
 pub use events::*;
@@ -17,7 +20,11 @@ fn make_specta_builder<R: tauri::Runtime>() -> tauri_specta::Builder<R> {
         .plugin_name(PLUGIN_NAME)
         .commands(tauri_specta::collect_commands![
             commands::show_notification::<tauri::Wry>,
+            commands::dismiss_notification::<tauri::Wry>,
             commands::clear_notifications::<tauri::Wry>,
+            commands::set_upcoming_events::<tauri::Wry>,
+            commands::set_reminder_settings::<tauri::Wry>,
+            commands::snooze_reminder::<tauri::Wry>,
         ])
         .events(tauri_specta::collect_events![NotificationEvent])
         .error_handling(tauri_specta::ErrorHandlingMode::Result)
@@ -30,7 +37,13 @@ pub fn init() -> tauri::plugin::TauriPlugin<tauri::Wry> {
         .invoke_handler(specta_builder.invoke_handler())
         .setup(move |app, _api| {
             specta_builder.mount_events(app);
+            app.manage(reminder::Reminders::default());
             handler::init(app.clone());
+
+            let handle = app.clone();
+            tauri::async_runtime::spawn(async move {
+                handle.state::<reminder::Reminders>().run().await;
+            });
             Ok(())
         })
         .on_event(|app, event| match event {
@@ -0,0 +1,60 @@
+use std::sync::Mutex;
+use std::time::Duration;
+
+use hypr_notification_scheduler::{ReminderScheduler, ReminderSettings, UpcomingEvent};
+use tokio::sync::Notify;
+
+/// Upper bound on a single sleep, so reminders stay on time after the
+/// machine wakes up.
+const MAX_SLEEP: Duration = Duration::from_secs(30);
+
+#[derive(Default)]
+pub struct Reminders {
+    scheduler: Mutex<ReminderScheduler>,
+    wake: Notify,
+}
+
+impl Reminders {
+    pub fn set_events(&self, events: Vec<UpcomingEvent>) {
+        self.scheduler.lock().unwrap().set_events(events);
+        self.wake.notify_one();
+    }
+
+    pub fn set_settings(&self, settings: ReminderSettings) {
+        self.scheduler.lock().unwrap().set_settings(settings);
+        self.wake.notify_one();
+    }
+
+    pub fn snooze(&self, key: &str, minutes: Option<u32>) {
+        self.scheduler.lock().unwrap().snooze(key, minutes);
+        self.wake.notify_one();
+    }
+
+    pub fn handle(&self, key: &str, event: hypr_notification::NotificationEvent) {
+        self.scheduler.lock().unwrap().handle(key, event);
+        self.wake.notify_one();
+    }
+
+    /// Shows reminders as they come due, until the app exits.
+    pub async fn run(&self) {
+        loop {
+            let (due, next) = {
+                let mut scheduler = self.scheduler.lock().unwrap();
+                (scheduler.due(), scheduler.next_wakeup())
+            };
+
+            for notification in &due {
+                hypr_notification::show(notification);
+            }
+
+            let sleep = next
+                .and_then(|at| (at - chrono::Utc::now()).to_std().ok())
+                .map_or(MAX_SLEEP, |d| d.min(MAX_SLEEP));
+
+            tokio::select! {
+                _ = tokio::time::sleep(sleep) => {}
+                _ = self.wake.notified() => {}
+            }
+        }
+    }
+}
@@ -5,6 +5,9 @@ const COMMANDS: &[&str] = &[
     "update_document",
     "update_documents",
     "remove_document",
+    "retrieve",
+    "update_chunks",
+    "remove_chunks",
 ];
 
 fn main() {
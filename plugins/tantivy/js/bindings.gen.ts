@@ -53,6 +53,30 @@ async removeDocument(id: string, collection: string | null) : Promise<Result<nul
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
+},
+async retrieve(request: RetrieveRequest) : Promise<Result<RetrieveResult, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:tantivy|retrieve", { request }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async updateChunks(sessionId: string, chunks: ChunkDocument[]) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:tantivy|update_chunks", { sessionId, chunks }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async removeChunks(sessionId: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:tantivy|remove_chunks", { sessionId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
 }
 }
 
@@ -66,8 +90,17 @@ async removeDocument(id: string, collection: string | null) : Promise<Result<nul
 
 /** user-defined types **/
 
+/**
+ * A passage of a session's notes or transcript, indexed separately so
+ * retrieval can point at where in the session an answer came from.
+ */
+export type ChunkDocument = { id: string; session_id: string; source: ChunkSource; title: string; content: string; created_at: number; start_ms?: number | null; end_ms?: number | null }
+export type ChunkSource = "note" | "transcript"
 export type CreatedAtFilter = { gte: number | null; lte: number | null; gt: number | null; lt: number | null; eq: number | null }
 export type HighlightRange = { start: number; end: number }
+export type RetrieveRequest = { query: string; session_id?: string | null; created_at?: CreatedAtFilter | null; limit?: number; token_budget?: number }
+export type RetrieveResult = { chunks: RetrievedChunk[]; token_count: number }
+export type RetrievedChunk = { score: number; chunk: ChunkDocument }
 export type SearchDocument = { id: string; doc_type: string; language: string | null; title: string; content: string; created_at: number; facets?: string[] }
 export type SearchFilters = { created_at: CreatedAtFilter | null; doc_type: string | null; facet: string | null }
 export type SearchHit = { score: number; document: SearchDocument; title_snippet: Snippet | null; content_snippet: Snippet | null }
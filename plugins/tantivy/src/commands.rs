@@ -1,4 +1,7 @@
-use crate::{SearchDocument, SearchRequest, SearchResult, TantivyPluginExt};
+use crate::{
+    ChunkDocument, RetrieveRequest, RetrieveResult, SearchDocument, SearchRequest, SearchResult,
+    TantivyPluginExt,
+};
 
 #[tauri::command]
 #[specta::specta]
@@ -75,3 +78,40 @@ pub(crate) async fn remove_document<R: tauri::Runtime>(
         .await
         .map_err(|e| e.to_string())
 }
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn retrieve<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    request: RetrieveRequest,
+) -> Result<RetrieveResult, String> {
+    app.tantivy()
+        .retrieve(request)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn update_chunks<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    session_id: String,
+    chunks: Vec<ChunkDocument>,
+) -> Result<(), String> {
+    app.tantivy()
+        .update_chunks(session_id, chunks)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub(crate) async fn remove_chunks<R: tauri::Runtime>(
+    app: tauri::AppHandle<R>,
+    session_id: String,
+) -> Result<(), String> {
+    app.tantivy()
+        .remove_chunks(session_id)
+        .await
+        .map_err(|e| e.to_string())
+}
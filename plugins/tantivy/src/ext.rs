@@ -8,11 +8,16 @@ use tantivy::{Index, ReloadPolicy, TantivyDocument, Term};
 use tauri_plugin_settings::SettingsPluginExt;
 
 use crate::query::build_created_at_range_query;
-use crate::schema::{extract_search_document, get_fields};
+use crate::retrieve::pack_chunks;
+use crate::schema::{
+    build_chunk_document, extract_chunk_document, extract_search_document, get_chunk_fields,
+    get_fields,
+};
 use crate::tokenizer::register_tokenizers;
 use crate::{
-    CollectionConfig, CollectionIndex, HighlightRange, IndexState, SearchDocument, SearchHit,
-    SearchRequest, SearchResult, Snippet,
+    CHUNK_COLLECTION, ChunkDocument, CollectionConfig, CollectionIndex, HighlightRange, IndexState,
+    RetrieveRequest, RetrieveResult, RetrievedChunk, SearchDocument, SearchHit, SearchRequest,
+    SearchResult, Snippet,
 };
 
 pub fn detect_language(text: &str) -> hypr_language::Language {
@@ -576,6 +581,113 @@ impl<'a, R: tauri::Runtime, M: tauri::Manager<R>> Tantivy<'a, R, M> {
 
         Ok(())
     }
+
+    /// Searches the chunk collection and packs the best passages into
+    /// `token_budget`, for use as retrieval context in chat.
+    pub async fn retrieve(&self, request: RetrieveRequest) -> Result<RetrieveResult, crate::Error> {
+        let state = self.manager.state::<IndexState>();
+        let guard = state.inner.read().await;
+
+        let collection_index = guard
+            .collections
+            .get(CHUNK_COLLECTION)
+            .ok_or_else(|| crate::Error::CollectionNotFound(CHUNK_COLLECTION.to_string()))?;
+
+        let fields = get_chunk_fields(&collection_index.schema);
+        let searcher = collection_index.reader.searcher();
+
+        // Questions are written in natural language, so syntax errors (stray
+        // quotes, colons, ...) are ignored instead of failing the whole search.
+        let query_parser =
+            QueryParser::for_index(&collection_index.index, vec![fields.title, fields.content]);
+        let (query, _errors) = query_parser.parse_query_lenient(&request.query);
+
+        let mut clauses: Vec<(Occur, Box<dyn Query>)> = vec![(Occur::Must, query)];
+
+        if let Some(ref created_at_filter) = request.created_at
+            && let Some(rq) = build_created_at_range_query(fields.created_at, created_at_filter)
+        {
+            clauses.push((Occur::Must, rq));
+        }
+
+        if let Some(ref session_id) = request.session_id {
+            let term = Term::from_field_text(fields.session_id, session_id);
+            clauses.push((
+                Occur::Must,
+                Box::new(TermQuery::new(term, IndexRecordOption::Basic)),
+            ));
+        }
+
+        let top_docs = searcher.search(
+            &BooleanQuery::new(clauses),
+            &TopDocs::with_limit(request.limit.max(1)),
+        )?;
+
+        let mut hits = Vec::new();
+        for (score, doc_address) in top_docs {
+            let retrieved_doc: TantivyDocument = searcher.doc(doc_address)?;
+            if let Some(chunk) = extract_chunk_document(&fields, &retrieved_doc) {
+                hits.push(RetrievedChunk { score, chunk });
+            }
+        }
+
+        let (chunks, token_count) = pack_chunks(hits, request.token_budget);
+        Ok(RetrieveResult {
+            chunks,
+            token_count,
+        })
+    }
+
+    /// Replaces every chunk of `session_id` with `chunks`.
+    pub async fn update_chunks(
+        &self,
+        session_id: String,
+        chunks: Vec<ChunkDocument>,
+    ) -> Result<(), crate::Error> {
+        let state = self.manager.state::<IndexState>();
+        let mut guard = state.inner.write().await;
+
+        let collection_index = guard
+            .collections
+            .get_mut(CHUNK_COLLECTION)
+            .ok_or_else(|| crate::Error::CollectionNotFound(CHUNK_COLLECTION.to_string()))?;
+
+        let fields = get_chunk_fields(&collection_index.schema);
+        let writer = &mut collection_index.writer;
+
+        writer.delete_term(Term::from_field_text(fields.session_id, &session_id));
+
+        let count = chunks.len();
+        for chunk in &chunks {
+            writer.add_document(build_chunk_document(&fields, chunk))?;
+        }
+
+        writer.commit()?;
+
+        tracing::debug!("Indexed {} chunks for session '{}'", count, session_id);
+
+        Ok(())
+    }
+
+    pub async fn remove_chunks(&self, session_id: String) -> Result<(), crate::Error> {
+        let state = self.manager.state::<IndexState>();
+        let mut guard = state.inner.write().await;
+
+        let collection_index = guard
+            .collections
+            .get_mut(CHUNK_COLLECTION)
+            .ok_or_else(|| crate::Error::CollectionNotFound(CHUNK_COLLECTION.to_string()))?;
+
+        let fields = get_chunk_fields(&collection_index.schema);
+        let writer = &mut collection_index.writer;
+
+        writer.delete_term(Term::from_field_text(fields.session_id, &session_id));
+        writer.commit()?;
+
+        tracing::debug!("Removed chunks of session '{}'", session_id);
+
+        Ok(())
+    }
 }
 
 pub trait TantivyPluginExt<R: tauri::Runtime> {
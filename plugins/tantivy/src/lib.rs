@@ -2,6 +2,7 @@ mod commands;
 mod error;
 mod ext;
 mod query;
+mod retrieve;
 mod schema;
 mod tokenizer;
 
@@ -14,7 +15,8 @@ use tokio::sync::RwLock;
 
 pub use error::{Error, Result};
 pub use ext::*;
-pub use schema::build_schema;
+pub use retrieve::pack_chunks;
+pub use schema::{build_chunk_schema, build_schema};
 pub use tokenizer::get_tokenizer_name_for_language;
 
 const PLUGIN_NAME: &str = "tantivy";
@@ -99,7 +101,82 @@ pub struct SearchRequest {
     pub options: SearchOptions,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+#[serde(rename_all = "snake_case")]
+pub enum ChunkSource {
+    Note,
+    Transcript,
+}
+
+impl ChunkSource {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Note => "note",
+            Self::Transcript => "transcript",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "note" => Some(Self::Note),
+            "transcript" => Some(Self::Transcript),
+            _ => None,
+        }
+    }
+}
+
+/// A passage of a session's notes or transcript, indexed separately so
+/// retrieval can point at where in the session an answer came from.
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
+pub struct ChunkDocument {
+    pub id: String,
+    pub session_id: String,
+    pub source: ChunkSource,
+    pub title: String,
+    pub content: String,
+    pub created_at: i64,
+    #[serde(default)]
+    pub start_ms: Option<i64>,
+    #[serde(default)]
+    pub end_ms: Option<i64>,
+}
+
+fn default_retrieve_limit() -> usize {
+    20
+}
+
+fn default_token_budget() -> usize {
+    2000
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
+pub struct RetrieveRequest {
+    pub query: String,
+    #[serde(default)]
+    pub session_id: Option<String>,
+    #[serde(default)]
+    pub created_at: Option<CreatedAtFilter>,
+    #[serde(default = "default_retrieve_limit")]
+    pub limit: usize,
+    #[serde(default = "default_token_budget")]
+    pub token_budget: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
+pub struct RetrievedChunk {
+    pub score: f32,
+    pub chunk: ChunkDocument,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
+pub struct RetrieveResult {
+    pub chunks: Vec<RetrievedChunk>,
+    pub token_count: usize,
+}
+
 pub const SCHEMA_VERSION: u32 = 1;
+pub const CHUNK_SCHEMA_VERSION: u32 = 1;
+pub const CHUNK_COLLECTION: &str = "chunks";
 
 pub struct CollectionConfig {
     pub name: String,
@@ -142,6 +219,9 @@ fn make_specta_builder<R: tauri::Runtime>() -> tauri_specta::Builder<R> {
             commands::update_document::<tauri::Wry>,
             commands::update_documents::<tauri::Wry>,
             commands::remove_document::<tauri::Wry>,
+            commands::retrieve::<tauri::Wry>,
+            commands::update_chunks::<tauri::Wry>,
+            commands::remove_chunks::<tauri::Wry>,
         ])
         .error_handling(tauri_specta::ErrorHandlingMode::Result)
 }
@@ -166,6 +246,17 @@ pub fn init() -> tauri::plugin::TauriPlugin<tauri::Wry> {
                 if let Err(e) = handle.tantivy().register_collection(config).await {
                     tracing::error!("Failed to register default collection: {}", e);
                 }
+
+                let config = CollectionConfig {
+                    name: CHUNK_COLLECTION.to_string(),
+                    path: "chunk_index".to_string(),
+                    schema_builder: schema::build_chunk_schema,
+                    schema_version: CHUNK_SCHEMA_VERSION,
+                };
+
+                if let Err(e) = handle.tantivy().register_collection(config).await {
+                    tracing::error!("Failed to register chunk collection: {}", e);
+                }
             });
 
             Ok(())
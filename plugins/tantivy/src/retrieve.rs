@@ -0,0 +1,80 @@
+use crate::RetrievedChunk;
+
+/// Rough average for English text with BPE tokenizers, matching the
+/// estimate `template-app` uses when chunking transcripts.
+const CHARS_PER_TOKEN: usize = 4;
+
+pub fn estimate_tokens(text: &str) -> usize {
+    text.chars().count().div_ceil(CHARS_PER_TOKEN)
+}
+
+/// Keeps the best-scoring chunks whose combined size fits in
+/// `token_budget`. A chunk that doesn't fit is skipped rather than ending
+/// the search, so a smaller, lower-ranked chunk can still use the space.
+/// Returns the packed chunks in score order and their token count.
+pub fn pack_chunks(
+    mut chunks: Vec<RetrievedChunk>,
+    token_budget: usize,
+) -> (Vec<RetrievedChunk>, usize) {
+    chunks.sort_by(|a, b| b.score.total_cmp(&a.score));
+
+    let mut used = 0;
+    let packed = chunks
+        .into_iter()
+        .filter(|hit| {
+            let tokens = estimate_tokens(&hit.chunk.content);
+            if used + tokens > token_budget {
+                return false;
+            }
+            used += tokens;
+            true
+        })
+        .collect();
+
+    (packed, used)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ChunkDocument, ChunkSource};
+
+    fn hit(id: &str, score: f32, chars: usize) -> RetrievedChunk {
+        RetrievedChunk {
+            score,
+            chunk: ChunkDocument {
+                id: id.to_string(),
+                session_id: "s1".to_string(),
+                source: ChunkSource::Note,
+                title: "Session".to_string(),
+                content: "a".repeat(chars),
+                created_at: 0,
+                start_ms: None,
+                end_ms: None,
+            },
+        }
+    }
+
+    #[test]
+    fn test_pack_chunks_fills_budget_by_score() {
+        let (packed, used) = pack_chunks(
+            vec![
+                hit("small", 1.0, 40),
+                hit("best", 3.0, 200),
+                hit("large", 2.0, 400),
+            ],
+            70,
+        );
+
+        let ids: Vec<_> = packed.iter().map(|h| h.chunk.id.as_str()).collect();
+        assert_eq!(ids, vec!["best", "small"]);
+        assert_eq!(used, 60);
+    }
+
+    #[test]
+    fn test_pack_chunks_empty_budget() {
+        let (packed, used) = pack_chunks(vec![hit("a", 1.0, 4)], 0);
+        assert!(packed.is_empty());
+        assert_eq!(used, 0);
+    }
+}
@@ -3,7 +3,7 @@ use tantivy::schema::{
     FAST, FacetOptions, Field, STORED, STRING, Schema, TextFieldIndexing, TextOptions, Value,
 };
 
-use crate::SearchDocument;
+use crate::{ChunkDocument, ChunkSource, SearchDocument};
 
 pub struct SchemaFields {
     pub id: Field,
@@ -15,18 +15,33 @@ pub struct SchemaFields {
     pub facets: Field,
 }
 
+pub struct ChunkFields {
+    pub id: Field,
+    pub session_id: Field,
+    pub source: Field,
+    pub title: Field,
+    pub content: Field,
+    pub created_at: Field,
+    pub start_ms: Field,
+    pub end_ms: Field,
+}
+
+fn text_options() -> TextOptions {
+    let text_indexing = TextFieldIndexing::default()
+        .set_tokenizer("multilang")
+        .set_index_option(tantivy::schema::IndexRecordOption::WithFreqsAndPositions);
+    TextOptions::default()
+        .set_indexing_options(text_indexing)
+        .set_stored()
+}
+
 pub fn build_schema() -> Schema {
     let mut schema_builder = Schema::builder();
     schema_builder.add_text_field("id", STRING | STORED);
     schema_builder.add_text_field("doc_type", STRING | STORED);
     schema_builder.add_text_field("language", STRING | STORED);
 
-    let text_indexing = TextFieldIndexing::default()
-        .set_tokenizer("multilang")
-        .set_index_option(tantivy::schema::IndexRecordOption::WithFreqsAndPositions);
-    let text_options = TextOptions::default()
-        .set_indexing_options(text_indexing)
-        .set_stored();
+    let text_options = text_options();
 
     schema_builder.add_text_field("title", text_options.clone());
     schema_builder.add_text_field("content", text_options);
@@ -35,6 +50,19 @@ pub fn build_schema() -> Schema {
     schema_builder.build()
 }
 
+pub fn build_chunk_schema() -> Schema {
+    let mut schema_builder = Schema::builder();
+    schema_builder.add_text_field("id", STRING | STORED);
+    schema_builder.add_text_field("session_id", STRING | STORED);
+    schema_builder.add_text_field("source", STRING | STORED);
+    schema_builder.add_text_field("title", text_options());
+    schema_builder.add_text_field("content", text_options());
+    schema_builder.add_i64_field("created_at", FAST | STORED);
+    schema_builder.add_i64_field("start_ms", STORED);
+    schema_builder.add_i64_field("end_ms", STORED);
+    schema_builder.build()
+}
+
 pub fn get_fields(schema: &Schema) -> SchemaFields {
     SchemaFields {
         id: schema.get_field("id").unwrap(),
@@ -78,6 +106,54 @@ pub fn extract_search_document(
     })
 }
 
+pub fn get_chunk_fields(schema: &Schema) -> ChunkFields {
+    ChunkFields {
+        id: schema.get_field("id").unwrap(),
+        session_id: schema.get_field("session_id").unwrap(),
+        source: schema.get_field("source").unwrap(),
+        title: schema.get_field("title").unwrap(),
+        content: schema.get_field("content").unwrap(),
+        created_at: schema.get_field("created_at").unwrap(),
+        start_ms: schema.get_field("start_ms").unwrap(),
+        end_ms: schema.get_field("end_ms").unwrap(),
+    }
+}
+
+pub fn build_chunk_document(fields: &ChunkFields, chunk: &ChunkDocument) -> TantivyDocument {
+    let mut doc = TantivyDocument::new();
+    doc.add_text(fields.id, &chunk.id);
+    doc.add_text(fields.session_id, &chunk.session_id);
+    doc.add_text(fields.source, chunk.source.as_str());
+    doc.add_text(fields.title, &chunk.title);
+    doc.add_text(fields.content, &chunk.content);
+    doc.add_i64(fields.created_at, chunk.created_at);
+    if let Some(start_ms) = chunk.start_ms {
+        doc.add_i64(fields.start_ms, start_ms);
+    }
+    if let Some(end_ms) = chunk.end_ms {
+        doc.add_i64(fields.end_ms, end_ms);
+    }
+    doc
+}
+
+pub fn extract_chunk_document(
+    fields: &ChunkFields,
+    doc: &TantivyDocument,
+) -> Option<ChunkDocument> {
+    let text = |field| doc.get_first(field)?.as_str().map(|s| s.to_string());
+
+    Some(ChunkDocument {
+        id: text(fields.id)?,
+        session_id: text(fields.session_id)?,
+        source: ChunkSource::parse(doc.get_first(fields.source)?.as_str()?)?,
+        title: text(fields.title)?,
+        content: text(fields.content)?,
+        created_at: doc.get_first(fields.created_at)?.as_i64()?,
+        start_ms: doc.get_first(fields.start_ms).and_then(|v| v.as_i64()),
+        end_ms: doc.get_first(fields.end_ms).and_then(|v| v.as_i64()),
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -98,4 +174,37 @@ mod tests {
             "Schema should have a content field"
         );
     }
+
+    #[test]
+    fn test_chunk_document_roundtrip() {
+        let schema = build_chunk_schema();
+        let fields = get_chunk_fields(&schema);
+
+        let chunk = ChunkDocument {
+            id: "s1:transcript:0".to_string(),
+            session_id: "s1".to_string(),
+            source: ChunkSource::Transcript,
+            title: "Pricing review".to_string(),
+            content: "We agreed to raise the Pro plan".to_string(),
+            created_at: 1_718_000_000_000,
+            start_ms: Some(754_000),
+            end_ms: Some(812_000),
+        };
+        let doc = build_chunk_document(&fields, &chunk);
+        let extracted = extract_chunk_document(&fields, &doc).unwrap();
+        assert_eq!(extracted.source, ChunkSource::Transcript);
+        assert_eq!(extracted.start_ms, Some(754_000));
+        assert_eq!(extracted.end_ms, Some(812_000));
+
+        let note = ChunkDocument {
+            source: ChunkSource::Note,
+            start_ms: None,
+            end_ms: None,
+            ..chunk
+        };
+        let doc = build_chunk_document(&fields, &note);
+        let extracted = extract_chunk_document(&fields, &doc).unwrap();
+        assert_eq!(extracted.source, ChunkSource::Note);
+        assert_eq!(extracted.start_ms, None);
+    }
 }
@@ -1,4 +1,13 @@
-const COMMANDS: &[&str] = &["render", "render_custom", "render_support"];
+const COMMANDS: &[&str] = &[
+    "render",
+    "render_custom",
+    "render_support",
+    "chunk_transcripts",
+    "collect_action_items",
+    "extraction_schema",
+    "resolve_extraction",
+    "parse_citations",
+];
 
 fn main() {
     tauri_plugin::Builder::new(COMMANDS).build();
@@ -29,6 +29,46 @@ async renderSupport(tpl: SupportTemplate) : Promise<Result<string, string>> {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
+},
+async chunkTranscripts(transcripts: Transcript[], options: ChunkOptions) : Promise<Result<Transcript[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:template|chunk_transcripts", { transcripts, options }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async collectActionItems(notes: string[]) : Promise<Result<string[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:template|collect_action_items", { notes }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async extractionSchema() : Promise<Result<JsonValue, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:template|extraction_schema") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async resolveExtraction(output: string, ctx: ExtractUser) : Promise<Result<Extraction, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:template|resolve_extraction", { output, ctx }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async parseCitations(answer: string, chunks: RetrievalChunk[]) : Promise<Result<Citation[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("plugin:template|parse_citations", { answer, chunks }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
 }
 }
 
@@ -43,29 +83,87 @@ async renderSupport(tpl: SupportTemplate) : Promise<Result<string, string>> {
 /** user-defined types **/
 
 export type AccountInfo = { userId: string; email: string | null; fullName: string | null; avatarUrl: string | null; stripeCustomerId: string | null }
+export type ActionItem = { text: string; assignee: Assignee | null; 
+/**
+ * Resolved deadline as `YYYY-MM-DD`.
+ */
+dueDate: string | null; 
+/**
+ * Deadline as it was said in the meeting.
+ */
+dueText: string | null; sourceMs: number | null }
+export type Assignee = { name: string; 
+/**
+ * Whether `name` matched one of the session participants.
+ */
+participant: boolean }
 export type BugReport = { description: string; platform: string; arch: string; osVersion: string; appVersion: string; source: string }
 export type ChatSystem = { language: string | null; context: SessionContext | null }
+export type ChunkOptions = { maxTokens: number; overlapTokens: number }
+export type Citation = { 
+/**
+ * The `[n]` marker used in the answer.
+ */
+marker: number; sessionId: string; sessionTitle: string | null; source: CitationSource; startMs: number | null; endMs: number | null }
+export type CitationSource = "note" | "transcript"
 export type DeviceInfo = { platform: string; arch: string; osVersion: string; appVersion: string; buildHash?: string | null; locale?: string | null }
+export type EnhanceChunkUser = { session: Session; participants: Participant[]; template: EnhanceTemplate | null; transcript: Transcript; chunkIndex: number; chunkCount: number }
+export type EnhanceReduceUser = { session: Session; participants: Participant[]; template: EnhanceTemplate | null; partialNotes: string[]; actionItems: string[] }
 export type EnhanceSystem = { language: string | null }
 export type EnhanceTemplate = { title: string; description: string | null; sections: TemplateSection[] }
 export type EnhanceUser = { session: Session; participants: Participant[]; template: EnhanceTemplate | null; transcripts: Transcript[] }
 export type Event = { name: string }
+export type ExtractSegment = { speaker: string; text: string; startMs: number | null }
+export type ExtractSystem = { language: string | null }
+export type ExtractUser = { session: Session; participants: Participant[]; segments: ExtractSegment[]; 
+/**
+ * Date the meeting took place on, as `YYYY-MM-DD`. Relative deadlines
+ * are resolved against it.
+ */
+eventDate: string | null }
+export type ExtractedItem = { text: string; sourceMs: number | null }
+export type Extraction = { actionItems: ActionItem[]; decisions: ExtractedItem[]; openQuestions: ExtractedItem[] }
 export type FeatureRequest = { description: string; platform: string; arch: string; osVersion: string; appVersion: string; source: string }
-export type Grammar = { task: "enhance"; sections: string[] | null } | { task: "title" } | { task: "tags" } | { task: "email-to-name" }
+export type FollowupEmailHtml = { session: Session; 
+/**
+ * Who the email is addressed to, for the greeting.
+ */
+recipients: Participant[]; senderName: string | null; 
+/**
+ * Markdown, as produced by enhancing.
+ */
+enhancedNote: string; actionItems: ActionItem[] }
+export type FollowupEmailText = { session: Session; recipients: Participant[]; senderName: string | null; enhancedNote: string; actionItems: ActionItem[] }
+export type Grammar = { task: "enhance"; sections: string[] | null } | { task: "title" } | { task: "tags" } | { task: "email-to-name" } | { task: "json-schema"; schema: JsonValue } | { task: "raw"; grammar: string }
 export type JsonValue = null | boolean | number | string | JsonValue[] | Partial<{ [key in string]: JsonValue }>
 export type LogAnalysis = { summarySection: string; tail: string }
 export type Participant = { name: string; jobTitle: string | null }
+export type RetrievalChunk = { sessionId: string; sessionTitle: string | null; source: CitationSource; text: string; 
+/**
+ * Session creation time, in milliseconds since the epoch.
+ */
+createdAt: number | null; 
+/**
+ * Offsets into the recording. Only set for transcript chunks.
+ */
+startMs: number | null; endMs: number | null }
 export type Segment = { text: string; speaker: string }
 export type Session = { title: string | null; startedAt: string | null; endedAt: string | null; event: Event | null }
 export type SessionContext = { title: string | null; date: string | null; rawContent: string | null; enhancedContent: string | null; transcript: Transcript | null; participants: Participant[]; event: Event | null }
 export type SupportContext = { account: AccountInfo | null; device: DeviceInfo }
 export type SupportTemplate = { supportContext: SupportContext } | { bugReport: BugReport } | { featureRequest: FeatureRequest } | { logAnalysis: LogAnalysis }
-export type Template = { enhanceSystem: EnhanceSystem } | { enhanceUser: EnhanceUser } | { titleSystem: TitleSystem } | { titleUser: TitleUser } | { chatSystem: ChatSystem } | { toolSearchSessions: ToolSearchSessions }
+export type Template = { enhanceSystem: EnhanceSystem } | { enhanceUser: EnhanceUser } | { enhanceChunkUser: EnhanceChunkUser } | { enhanceReduceUser: EnhanceReduceUser } | { extractSystem: ExtractSystem } | { extractUser: ExtractUser } | { followupEmailHtml: FollowupEmailHtml } | { followupEmailText: FollowupEmailText } | { titleSystem: TitleSystem } | { titleUser: TitleUser } | { chatSystem: ChatSystem } | { toolSearchSessions: ToolSearchSessions } | { toolSearchVault: ToolSearchVault }
 export type TemplateSection = { title: string; description: string | null }
 export type TitleSystem = { language: string | null }
 export type TitleUser = { enhancedNote: string }
 export type ToolSearchSessionItem = { id: string; title: string | null; excerpt: string | null; score: number; createdAt: number | null; sessionContext: SessionContext | null }
 export type ToolSearchSessions = { query: string; results: ToolSearchSessionItem[] }
+export type ToolSearchVault = { query: string; 
+/**
+ * Already ranked and packed to fit the prompt budget. Citation
+ * markers refer to positions in this list, starting at 1.
+ */
+chunks: RetrievalChunk[] }
 export type Transcript = { segments: Segment[]; startedAt: number | null; endedAt: number | null }
 
 /** tauri-specta globals **/
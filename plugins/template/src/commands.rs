@@ -27,3 +27,50 @@ pub async fn render_support<R: tauri::Runtime>(
 ) -> Result<String, String> {
     hypr_template_support::render(tpl).map_err(|e| e.to_string())
 }
+
+#[tauri::command]
+#[specta::specta]
+pub async fn chunk_transcripts<R: tauri::Runtime>(
+    _app: tauri::AppHandle<R>,
+    transcripts: Vec<hypr_template_app::Transcript>,
+    options: hypr_template_app::ChunkOptions,
+) -> Result<Vec<hypr_template_app::Transcript>, String> {
+    Ok(hypr_template_app::chunk_transcripts(&transcripts, &options))
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn collect_action_items<R: tauri::Runtime>(
+    _app: tauri::AppHandle<R>,
+    notes: Vec<String>,
+) -> Result<Vec<String>, String> {
+    Ok(hypr_template_app::collect_action_items(&notes))
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn extraction_schema<R: tauri::Runtime>(
+    _app: tauri::AppHandle<R>,
+) -> Result<serde_json::Value, String> {
+    Ok(hypr_template_app::extraction_output_schema())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn resolve_extraction<R: tauri::Runtime>(
+    _app: tauri::AppHandle<R>,
+    output: String,
+    ctx: hypr_template_app::ExtractUser,
+) -> Result<hypr_template_app::Extraction, String> {
+    hypr_template_app::resolve_extraction(&output, &ctx).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn parse_citations<R: tauri::Runtime>(
+    _app: tauri::AppHandle<R>,
+    answer: String,
+    chunks: Vec<hypr_template_app::RetrievalChunk>,
+) -> Result<Vec<hypr_template_app::Citation>, String> {
+    Ok(hypr_template_app::parse_citations(&answer, &chunks))
+}
@@ -15,6 +15,11 @@ fn make_specta_builder<R: tauri::Runtime>() -> tauri_specta::Builder<R> {
             commands::render::<Wry>,
             commands::render_custom::<Wry>,
             commands::render_support::<Wry>,
+            commands::chunk_transcripts::<Wry>,
+            commands::collect_action_items::<Wry>,
+            commands::extraction_schema::<Wry>,
+            commands::resolve_extraction::<Wry>,
+            commands::parse_citations::<Wry>,
         ])
         .typ::<hypr_gbnf::Grammar>()
         .error_handling(tauri_specta::ErrorHandlingMode::Result)
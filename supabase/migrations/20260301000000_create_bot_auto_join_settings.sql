@@ -0,0 +1,31 @@
+CREATE TABLE public.bot_auto_join_settings (
+  user_id uuid PRIMARY KEY,
+  enabled boolean NOT NULL DEFAULT false,
+  settings jsonb NOT NULL,
+  created_at timestamptz NOT NULL DEFAULT now(),
+  updated_at timestamptz NOT NULL DEFAULT now(),
+
+  CONSTRAINT bot_auto_join_settings_user_id_fk
+    FOREIGN KEY (user_id) REFERENCES auth.users(id) ON DELETE CASCADE
+);
+
+ALTER TABLE public.bot_auto_join_settings ENABLE ROW LEVEL SECURITY;
+
+CREATE POLICY "bot_auto_join_settings_select_owner"
+  ON public.bot_auto_join_settings AS PERMISSIVE
+  FOR SELECT TO authenticated
+  USING ((SELECT auth.uid()) = user_id);
+
+CREATE POLICY "bot_auto_join_settings_service_all"
+  ON public.bot_auto_join_settings AS PERMISSIVE
+  FOR ALL TO service_role
+  USING (true) WITH CHECK (true);
+
+CREATE INDEX bot_auto_join_settings_enabled_idx
+  ON public.bot_auto_join_settings (user_id)
+  WHERE enabled;
+
+CREATE TRIGGER bot_auto_join_settings_set_updated_at
+  BEFORE UPDATE ON public.bot_auto_join_settings
+  FOR EACH ROW
+  EXECUTE FUNCTION public.set_updated_at();
@@ -0,0 +1,22 @@
+CREATE TABLE public.bot_auto_join_claims (
+  user_id uuid NOT NULL,
+  occurrence_key text NOT NULL,
+  calendar_id text NOT NULL,
+  end_at timestamptz NOT NULL,
+  bot_id text,
+  created_at timestamptz NOT NULL DEFAULT now(),
+
+  CONSTRAINT bot_auto_join_claims_pkey PRIMARY KEY (user_id, occurrence_key),
+  CONSTRAINT bot_auto_join_claims_user_id_fk
+    FOREIGN KEY (user_id) REFERENCES auth.users(id) ON DELETE CASCADE
+);
+
+ALTER TABLE public.bot_auto_join_claims ENABLE ROW LEVEL SECURITY;
+
+CREATE POLICY "bot_auto_join_claims_service_all"
+  ON public.bot_auto_join_claims AS PERMISSIVE
+  FOR ALL TO service_role
+  USING (true) WITH CHECK (true);
+
+CREATE INDEX bot_auto_join_claims_end_at_idx
+  ON public.bot_auto_join_claims (user_id, end_at);
@@ -0,0 +1,52 @@
+CREATE TABLE public.bot_sessions (
+  bot_id text PRIMARY KEY,
+  user_id uuid NOT NULL,
+  ended_at timestamptz,
+  created_at timestamptz NOT NULL DEFAULT now(),
+
+  CONSTRAINT bot_sessions_user_id_fk
+    FOREIGN KEY (user_id) REFERENCES auth.users(id) ON DELETE CASCADE
+);
+
+CREATE TABLE public.bot_transcript_segments (
+  id bigint GENERATED ALWAYS AS IDENTITY PRIMARY KEY,
+  bot_id text NOT NULL,
+  segment jsonb NOT NULL,
+  created_at timestamptz NOT NULL DEFAULT now(),
+
+  CONSTRAINT bot_transcript_segments_bot_id_fk
+    FOREIGN KEY (bot_id) REFERENCES public.bot_sessions(bot_id) ON DELETE CASCADE
+);
+
+ALTER TABLE public.bot_sessions ENABLE ROW LEVEL SECURITY;
+ALTER TABLE public.bot_transcript_segments ENABLE ROW LEVEL SECURITY;
+
+CREATE POLICY "bot_sessions_select_owner"
+  ON public.bot_sessions AS PERMISSIVE
+  FOR SELECT TO authenticated
+  USING ((SELECT auth.uid()) = user_id);
+
+CREATE POLICY "bot_sessions_service_all"
+  ON public.bot_sessions AS PERMISSIVE
+  FOR ALL TO service_role
+  USING (true) WITH CHECK (true);
+
+CREATE POLICY "bot_transcript_segments_select_owner"
+  ON public.bot_transcript_segments AS PERMISSIVE
+  FOR SELECT TO authenticated
+  USING (EXISTS (
+    SELECT 1 FROM public.bot_sessions s
+    WHERE s.bot_id = bot_transcript_segments.bot_id
+      AND s.user_id = (SELECT auth.uid())
+  ));
+
+CREATE POLICY "bot_transcript_segments_service_all"
+  ON public.bot_transcript_segments AS PERMISSIVE
+  FOR ALL TO service_role
+  USING (true) WITH CHECK (true);
+
+CREATE INDEX bot_sessions_user_id_idx
+  ON public.bot_sessions (user_id);
+
+CREATE INDEX bot_transcript_segments_bot_id_idx
+  ON public.bot_transcript_segments (bot_id, id);
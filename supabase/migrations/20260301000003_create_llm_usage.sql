@@ -0,0 +1,48 @@
+CREATE TABLE public.llm_usage (
+  user_id uuid NOT NULL,
+  period text NOT NULL,
+  tokens bigint NOT NULL DEFAULT 0,
+  cost_usd double precision NOT NULL DEFAULT 0,
+  updated_at timestamptz NOT NULL DEFAULT now(),
+
+  CONSTRAINT llm_usage_pkey PRIMARY KEY (user_id, period),
+  CONSTRAINT llm_usage_user_id_fk
+    FOREIGN KEY (user_id) REFERENCES auth.users(id) ON DELETE CASCADE
+);
+
+ALTER TABLE public.llm_usage ENABLE ROW LEVEL SECURITY;
+
+CREATE POLICY "llm_usage_select_owner"
+  ON public.llm_usage AS PERMISSIVE
+  FOR SELECT TO authenticated
+  USING ((SELECT auth.uid()) = user_id);
+
+CREATE POLICY "llm_usage_service_all"
+  ON public.llm_usage AS PERMISSIVE
+  FOR ALL TO service_role
+  USING (true) WITH CHECK (true);
+
+-- Adds to every period in one statement, so concurrent requests on any
+-- instance never lose an increment.
+CREATE OR REPLACE FUNCTION public.add_llm_usage(
+  p_user_id uuid,
+  p_periods text[],
+  p_tokens bigint,
+  p_cost_usd double precision
+)
+RETURNS void
+LANGUAGE sql
+AS $$
+  INSERT INTO public.llm_usage (user_id, period, tokens, cost_usd)
+  SELECT p_user_id, period, p_tokens, p_cost_usd
+  FROM unnest(p_periods) AS period
+  ON CONFLICT (user_id, period) DO UPDATE
+  SET tokens = public.llm_usage.tokens + EXCLUDED.tokens,
+      cost_usd = public.llm_usage.cost_usd + EXCLUDED.cost_usd,
+      updated_at = now();
+$$;
+
+REVOKE EXECUTE ON FUNCTION public.add_llm_usage(uuid, text[], bigint, double precision)
+  FROM PUBLIC, anon, authenticated;
+GRANT EXECUTE ON FUNCTION public.add_llm_usage(uuid, text[], bigint, double precision)
+  TO service_role;